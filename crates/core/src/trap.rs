@@ -216,7 +216,14 @@ impl StdError for Trap {
 /// See [`Trap`] for details.
 ///
 /// [`Trap`]: struct.Trap.html
+///
+/// # Note
+///
+/// All traps raised by the Wasm execution of `wasmi` carry a [`TrapCode`].
+/// Matches on [`TrapCode`] must have a wildcard arm since new kinds of
+/// traps may be added without it being considered a breaking change.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum TrapCode {
     /// Wasm code executed `unreachable` opcode.
     ///
@@ -245,6 +252,13 @@ pub enum TrapCode {
     /// an uninitialized (i.e. `null`) table index.
     IndirectCallToNull,
 
+    /// Indicates that a `call_indirect` instruction called a function at
+    /// a table index that has never been initialized since its creation.
+    ///
+    /// This is only raised instead of [`TrapCode::IndirectCallToNull`] if
+    /// uninitialized table elements are tracked by the `wasmi::Config`.
+    UninitializedElement,
+
     /// Attempt to divide by zero.
     ///
     /// This trap typically can happen if `div` or `rem` is executed with
@@ -291,6 +305,14 @@ pub enum TrapCode {
     /// desire on the part of the embedder to trap the interpreter rather than
     /// merely fail the growth operation.
     GrowthOperationLimited,
+
+    /// Attempt to execute an atomic operation on an address that is
+    /// not aligned to the size of the accessed value.
+    UnalignedAtomic,
+
+    /// Attempt to execute an operation that requires a shared linear memory,
+    /// such as `memory.atomic.wait32`, on a linear memory that is not shared.
+    ExpectedSharedMemory,
 }
 
 impl TrapCode {
@@ -306,6 +328,7 @@ impl TrapCode {
             Self::MemoryOutOfBounds => "out of bounds memory access",
             Self::TableOutOfBounds => "undefined element: out of bounds table access",
             Self::IndirectCallToNull => "uninitialized element 2", // TODO: fixme, remove the trailing " 2" again
+            Self::UninitializedElement => "uninitialized table element",
             Self::IntegerDivisionByZero => "integer divide by zero",
             Self::IntegerOverflow => "integer overflow",
             Self::BadConversionToInteger => "invalid conversion to integer",
@@ -313,6 +336,8 @@ impl TrapCode {
             Self::BadSignature => "indirect call type mismatch",
             Self::OutOfFuel => "all fuel consumed by WebAssembly",
             Self::GrowthOperationLimited => "growth operation limited",
+            Self::UnalignedAtomic => "unaligned atomic",
+            Self::ExpectedSharedMemory => "expected shared memory",
         }
     }
 }
@@ -1054,6 +1054,36 @@ impl UntypedValue {
         self.execute_binary(other, <F64 as Float<F64>>::copysign)
     }
 
+    /// Canonicalizes `self` if it is an `f32` NaN value.
+    ///
+    /// # Note
+    ///
+    /// - Any `f32` NaN value is replaced by the canonical quiet NaN with positive sign.
+    /// - All other values are returned unchanged.
+    pub fn f32_canonicalize_nan(self) -> Self {
+        /// The bits of the canonical `f32` quiet NaN value.
+        const CANONICAL_NAN: u32 = 0x7FC0_0000;
+        match F32::from(self).is_nan() {
+            true => Self::from(F32::from_bits(CANONICAL_NAN)),
+            false => self,
+        }
+    }
+
+    /// Canonicalizes `self` if it is an `f64` NaN value.
+    ///
+    /// # Note
+    ///
+    /// - Any `f64` NaN value is replaced by the canonical quiet NaN with positive sign.
+    /// - All other values are returned unchanged.
+    pub fn f64_canonicalize_nan(self) -> Self {
+        /// The bits of the canonical `f64` quiet NaN value.
+        const CANONICAL_NAN: u64 = 0x7FF8_0000_0000_0000;
+        match F64::from(self).is_nan() {
+            true => Self::from(F64::from_bits(CANONICAL_NAN)),
+            false => self,
+        }
+    }
+
     /// Execute `i32.wrap_i64` Wasm operation.
     pub fn i32_wrap_i64(self) -> Self {
         self.execute_unary(<i64 as WrapInto<i32>>::wrap_into)
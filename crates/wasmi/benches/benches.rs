@@ -38,6 +38,8 @@ criterion_group!(
         bench_translate_erc20,
         bench_translate_erc721,
         bench_translate_erc1155,
+        bench_translate_many_copies,
+        bench_translate_many_preservations,
 );
 criterion_group!(
     name = bench_instantiate;
@@ -75,6 +77,7 @@ criterion_group! {
         bench_execute_regex_redux,
         bench_execute_count_until,
         bench_execute_br_table,
+        bench_execute_copy_span,
         bench_execute_trunc_f2i,
         bench_execute_global_bump,
         bench_execute_global_const,
@@ -89,6 +92,8 @@ criterion_group! {
         bench_execute_recursive_is_even,
         bench_execute_memory_sum,
         bench_execute_memory_fill,
+        bench_execute_bulk_memory_fill,
+        bench_execute_bulk_memory_copy,
         bench_execute_vec_add,
 }
 
@@ -233,6 +238,63 @@ fn bench_translate_erc1155(c: &mut Criterion) {
     bench_translate_for_all(c, "erc1155", "benches/wasm/erc1155.wasm");
 }
 
+/// Translates a synthetic function with hundreds of large copy groups.
+///
+/// Every Wasm `br` carries 16 values to its enclosing `block` which requires
+/// to copy them into the `block` results. Every other copy group is contiguous
+/// and can be encoded as `copy_span` whereas the others require `copy_many`.
+fn bench_translate_many_copies(c: &mut Criterion) {
+    const LEN_VALUES: usize = 16;
+    const LEN_BLOCKS: usize = 500;
+    let params = "i32 ".repeat(LEN_VALUES);
+    let drops = "(drop) ".repeat(LEN_VALUES);
+    let mut body = String::new();
+    for n in 0..LEN_BLOCKS {
+        body.push_str(&format!("(block (result {params})"));
+        for i in 0..LEN_VALUES {
+            let index = match n % 2 {
+                0 => i,
+                _ => (i * 7) % LEN_VALUES,
+            };
+            body.push_str(&format!(" (local.get {index})"));
+        }
+        body.push_str(&format!(" (br 0)) {drops}\n"));
+    }
+    let wat = format!("(module (func (export \"run\") (param {params}) {body}))");
+    let wasm = wat2wasm(wat.as_bytes());
+    c.bench_function("translate/many_copies", |b| {
+        let engine = Engine::new(&bench_config());
+        b.iter(|| {
+            _ = Module::new(&engine, &wasm[..]).unwrap();
+        })
+    });
+}
+
+/// Translates a synthetic function with thousands of `local.set` preservations.
+///
+/// Every step overwrites the local variable `$x` while its previous value
+/// is still on the stack which requires to preserve it. This stresses the
+/// defragmentation of the preservation register space after translation.
+fn bench_translate_many_preservations(c: &mut Criterion) {
+    const LEN_PRESERVATIONS: usize = 5_000;
+    let step = "
+        (local.get $x)
+        (local.set $x (i32.add (local.get $x) (i32.const 1)))
+        (local.set $acc (i32.add (local.get $acc)))
+    ";
+    let body = step.repeat(LEN_PRESERVATIONS);
+    let wat = format!(
+        "(module (func (export \"run\") (param $x i32) (result i32) (local $acc i32) {body} (local.get $acc)))"
+    );
+    let wasm = wat2wasm(wat.as_bytes());
+    c.bench_function("translate/many_preservations", |b| {
+        let engine = Engine::new(&bench_config());
+        b.iter(|| {
+            _ = Module::new(&engine, &wasm[..]).unwrap();
+        })
+    });
+}
+
 fn bench_instantiate_wasm_kernel(c: &mut Criterion) {
     c.bench_function("instantiate/wasm_kernel", |b| {
         let module = load_module_from_file(WASM_KERNEL);
@@ -584,6 +646,29 @@ fn bench_execute_br_table(c: &mut Criterion) {
     });
 }
 
+fn bench_execute_copy_span(c: &mut Criterion) {
+    const ROTATIONS: i32 = 100_000;
+    let (mut store, instance) = load_instance_from_wat(include_bytes!("wat/copy_span.wat"));
+    let rotate_3 = instance
+        .get_typed_func::<i32, (i64, i64, i64)>(&store, "rotate_3")
+        .unwrap();
+    let rotate_5 = instance
+        .get_typed_func::<i32, (i64, i64, i64, i64, i64)>(&store, "rotate_5")
+        .unwrap();
+    c.bench_function("execute/copy_span/small", |b| {
+        b.iter(|| {
+            let result = rotate_3.call(&mut store, ROTATIONS).unwrap();
+            assert_eq!(result, (2, 3, 1));
+        })
+    });
+    c.bench_function("execute/copy_span/generic", |b| {
+        b.iter(|| {
+            let result = rotate_5.call(&mut store, ROTATIONS).unwrap();
+            assert_eq!(result, (1, 2, 3, 4, 5));
+        })
+    });
+}
+
 fn bench_execute_trunc_f2i(c: &mut Criterion) {
     const ITERATIONS: i32 = 25_000;
     c.bench_function("execute/trunc_f2i", |b| {
@@ -1069,6 +1154,70 @@ fn bench_execute_memory_fill(c: &mut Criterion) {
     });
 }
 
+fn bench_execute_bulk_memory_fill(c: &mut Criterion) {
+    c.bench_function("execute/memory/bulk_fill_bytes", |b| {
+        let (mut store, instance) = load_instance_from_wat(include_bytes!("wat/memory-bulk.wat"));
+        let fill = instance
+            .get_export(&store, "fill_bytes")
+            .and_then(Extern::into_func)
+            .unwrap();
+        let mem = instance
+            .get_export(&store, "mem")
+            .and_then(Extern::into_memory)
+            .unwrap();
+        mem.grow(&mut store, Pages::new(1).unwrap()).unwrap();
+        let ptr = 0x100;
+        let len = 100_000;
+        let value = 0x42_u8;
+        mem.data_mut(&mut store)[ptr..(ptr + len)].fill(0x00);
+        let params = [
+            Value::I32(ptr as i32),
+            Value::I32(len as i32),
+            Value::I32(value as i32),
+        ];
+        b.iter(|| {
+            fill.call(&mut store, &params, &mut []).unwrap();
+        });
+        assert!(mem.data(&store)[ptr..(ptr + len)]
+            .iter()
+            .all(|byte| *byte == value));
+    });
+}
+
+fn bench_execute_bulk_memory_copy(c: &mut Criterion) {
+    c.bench_function("execute/memory/bulk_copy_bytes", |b| {
+        let (mut store, instance) = load_instance_from_wat(include_bytes!("wat/memory-bulk.wat"));
+        let copy = instance
+            .get_export(&store, "copy_bytes")
+            .and_then(Extern::into_func)
+            .unwrap();
+        let mem = instance
+            .get_export(&store, "mem")
+            .and_then(Extern::into_memory)
+            .unwrap();
+        mem.grow(&mut store, Pages::new(3).unwrap()).unwrap();
+        let src = 0x100;
+        let dst = 0x2_0000;
+        let len = 100_000;
+        for (n, byte) in mem.data_mut(&mut store)[src..(src + len)]
+            .iter_mut()
+            .enumerate()
+        {
+            *byte = (n % 256) as u8;
+        }
+        let params = [
+            Value::I32(dst as i32),
+            Value::I32(src as i32),
+            Value::I32(len as i32),
+        ];
+        b.iter(|| {
+            copy.call(&mut store, &params, &mut []).unwrap();
+        });
+        let data = mem.data(&store);
+        assert_eq!(data[src..(src + len)], data[dst..(dst + len)]);
+    });
+}
+
 fn bench_execute_vec_add(c: &mut Criterion) {
     fn test_for<A, B>(
         b: &mut Bencher,
@@ -0,0 +1,71 @@
+;; Exports functions that rotate 3 or 5 values `n` times.
+;;
+;; Each iteration the rotated values are returned by a call above
+;; an unrelated value and are then copied into the results of the
+;; enclosing block via a single overlapping `copy_span` instruction.
+(module
+    (func $rotate_3 (param i64 i64 i64) (result i64 i64 i64)
+        (local.get 1)
+        (local.get 2)
+        (local.get 0)
+    )
+    (func $rotate_5 (param i64 i64 i64 i64 i64) (result i64 i64 i64 i64 i64)
+        (local.get 1)
+        (local.get 2)
+        (local.get 3)
+        (local.get 4)
+        (local.get 0)
+    )
+    (func (export "rotate_3") (param $n i32) (result i64 i64 i64)
+        (local $a i64) (local $b i64) (local $c i64)
+        (local.set $a (i64.const 1))
+        (local.set $b (i64.const 2))
+        (local.set $c (i64.const 3))
+        (loop $continue
+            (block (result i64 i64 i64)
+                (i64.extend_i32_u (local.get $n))
+                (call $rotate_3 (local.get $a) (local.get $b) (local.get $c))
+                (br 0)
+            )
+            (local.set $c)
+            (local.set $b)
+            (local.set $a)
+            (br_if $continue
+                (local.tee $n (i32.sub (local.get $n) (i32.const 1)))
+            )
+        )
+        (local.get $a)
+        (local.get $b)
+        (local.get $c)
+    )
+    (func (export "rotate_5") (param $n i32) (result i64 i64 i64 i64 i64)
+        (local $a i64) (local $b i64) (local $c i64) (local $d i64) (local $e i64)
+        (local.set $a (i64.const 1))
+        (local.set $b (i64.const 2))
+        (local.set $c (i64.const 3))
+        (local.set $d (i64.const 4))
+        (local.set $e (i64.const 5))
+        (loop $continue
+            (block (result i64 i64 i64 i64 i64)
+                (i64.extend_i32_u (local.get $n))
+                (call $rotate_5
+                    (local.get $a) (local.get $b) (local.get $c) (local.get $d) (local.get $e)
+                )
+                (br 0)
+            )
+            (local.set $e)
+            (local.set $d)
+            (local.set $c)
+            (local.set $b)
+            (local.set $a)
+            (br_if $continue
+                (local.tee $n (i32.sub (local.get $n) (i32.const 1)))
+            )
+        )
+        (local.get $a)
+        (local.get $b)
+        (local.get $c)
+        (local.get $d)
+        (local.get $e)
+    )
+)
@@ -0,0 +1,24 @@
+;; Exports functions `fill_bytes` and `copy_bytes` that use the
+;; Wasm `bulk-memory` instructions `memory.fill` and `memory.copy`.
+;;
+;; # Note
+;;
+;; The `ptr`, `src`, `dst` and `len` parameters tell where to fill
+;; or copy contents within the linear memory.
+(module
+    (memory (export "mem") 1)
+    (func (export "fill_bytes") (param $ptr i32) (param $len i32) (param $value i32)
+        (memory.fill
+            (local.get $ptr)
+            (local.get $value)
+            (local.get $len)
+        )
+    )
+    (func (export "copy_bytes") (param $dst i32) (param $src i32) (param $len i32)
+        (memory.copy
+            (local.get $dst)
+            (local.get $src)
+            (local.get $len)
+        )
+    )
+)
@@ -0,0 +1,982 @@
+//! Encoding and decoding of [`Instruction`] words to and from bytes.
+//!
+//! # Encoding
+//!
+//! Every [`Instruction`] word is encoded into exactly [`Instruction::ENCODED_SIZE`] bytes:
+//!
+//! - The first 2 bytes store the little-endian opcode of the [`Instruction`] variant.
+//! - The remaining bytes store the little-endian encoded operands of the variant
+//!   in the order of their declaration followed by zero padding.
+//!
+//! Instructions that are encoded using multiple [`Instruction`] words,
+//! e.g. an [`Instruction::I32Load`] followed by its [`Instruction::Const32`]
+//! offset parameter, are encoded and decoded word by word. This is possible since
+//! all parameter words are [`Instruction`] variants themselves. Therefore
+//! a sequence of [`Instruction`] words round-trips by encoding and decoding
+//! each of them in order.
+//!
+//! # Note
+//!
+//! Opcodes are assigned in the order of the [`Instruction`] variant declarations.
+//! Therefore the encoding is not stable across `wasmi` versions.
+
+use super::{
+    AnyConst32,
+    BinInstr,
+    BinInstrImm,
+    BinInstrImm16,
+    BlockFuel,
+    BranchBinOpInstr,
+    BranchBinOpInstrImm,
+    BranchBinOpInstrImm16,
+    BranchOffset,
+    BranchOffset16,
+    CallIndirectParams,
+    Const16,
+    Const32,
+    DataSegmentIdx,
+    ElementSegmentIdx,
+    FuncIdx,
+    GlobalIdx,
+    Instruction,
+    LoadAtInstr,
+    LoadInstr,
+    LoadOffset16Instr,
+    LoadOpInstr,
+    MemoryIdx,
+    Register,
+    RegisterSpan,
+    RegisterSpanIter,
+    Sign,
+    SignatureIdx,
+    StoreAtInstr,
+    StoreInstr,
+    StoreOffset16Instr,
+    TableIdx,
+    UnaryInstr,
+};
+use crate::{engine::CompiledFunc, Error};
+use core::{
+    fmt::{self, Display},
+    num::{NonZeroI16, NonZeroI32, NonZeroI64, NonZeroU16, NonZeroU32, NonZeroU64},
+};
+use wasmi_arena::ArenaIndex;
+use wasmi_core::TrapCode;
+
+/// Errors that may occur upon decoding an [`Instruction`] from bytes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeError {
+    /// The buffer ended before a whole [`Instruction`] word could be decoded.
+    UnexpectedEnd,
+    /// The opcode does not belong to any [`Instruction`] variant.
+    UnknownOpcode(u16),
+    /// An operand of the [`Instruction`] has an invalid value.
+    InvalidOperand,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "unexpected end of encoded instruction"),
+            Self::UnknownOpcode(opcode) => write!(f, "unknown instruction opcode: {opcode}"),
+            Self::InvalidOperand => write!(f, "invalid encoded instruction operand"),
+        }
+    }
+}
+
+impl Instruction {
+    /// The number of bytes of an encoded [`Instruction`] word.
+    pub const ENCODED_SIZE: usize = 8;
+
+    /// Encodes the [`Instruction`] word into bytes.
+    ///
+    /// Use [`Instruction::decode`] to decode the returned bytes.
+    pub fn encode(&self) -> [u8; Self::ENCODED_SIZE] {
+        let mut encoder = Encoder::default();
+        self.encode_into(&mut encoder);
+        encoder.bytes
+    }
+
+    /// Decodes an [`Instruction`] word from the front of `bytes`.
+    ///
+    /// Returns the decoded [`Instruction`] and the number of bytes consumed.
+    ///
+    /// # Note
+    ///
+    /// Parameter words following an [`Instruction`], e.g. [`Instruction::Const32`],
+    /// are decoded as separate [`Instruction`] words by subsequent calls.
+    ///
+    /// # Errors
+    ///
+    /// - If `bytes` is shorter than [`Instruction::ENCODED_SIZE`].
+    /// - If `bytes` does not start with a valid encoded [`Instruction`] word.
+    pub fn decode(bytes: &[u8]) -> Result<(Self, usize), Error> {
+        let Some(bytes) = bytes.get(..Self::ENCODED_SIZE) else {
+            return Err(Error::from(DecodeError::UnexpectedEnd));
+        };
+        let mut decoder = Decoder { bytes, pos: 0 };
+        let instr = Self::decode_from(&mut decoder)?;
+        Ok((instr, Self::ENCODED_SIZE))
+    }
+}
+
+/// Writes the bytes of an encoded [`Instruction`] word.
+#[derive(Default)]
+struct Encoder {
+    /// The bytes of the encoded [`Instruction`] word.
+    bytes: [u8; Instruction::ENCODED_SIZE],
+    /// The number of bytes written so far.
+    pos: usize,
+}
+
+impl Encoder {
+    /// Writes `bytes` to the [`Encoder`].
+    ///
+    /// # Panics
+    ///
+    /// If the encoded [`Instruction`] word does not fit into [`Instruction::ENCODED_SIZE`].
+    fn write(&mut self, bytes: &[u8]) {
+        let end = self.pos + bytes.len();
+        self.bytes[self.pos..end].copy_from_slice(bytes);
+        self.pos = end;
+    }
+}
+
+/// Reads the bytes of an encoded [`Instruction`] word.
+struct Decoder<'a> {
+    /// The bytes of the encoded [`Instruction`] word.
+    bytes: &'a [u8],
+    /// The number of bytes read so far.
+    pos: usize,
+}
+
+impl Decoder<'_> {
+    /// Reads `N` bytes from the [`Decoder`].
+    fn read<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        let end = self.pos + N;
+        let bytes = self
+            .bytes
+            .get(self.pos..end)
+            .ok_or(DecodeError::UnexpectedEnd)?;
+        self.pos = end;
+        let mut array = [0x00; N];
+        array.copy_from_slice(bytes);
+        Ok(array)
+    }
+}
+
+/// Types that can be encoded as operands of an [`Instruction`] word.
+trait Operand: Sized {
+    /// Encodes `self` using the [`Encoder`].
+    fn encode(&self, encoder: &mut Encoder);
+
+    /// Decodes `Self` using the [`Decoder`].
+    fn decode(decoder: &mut Decoder) -> Result<Self, DecodeError>;
+}
+
+macro_rules! impl_operand_for_int {
+    ( $($ty:ty),* $(,)? ) => {
+        $(
+            impl Operand for $ty {
+                fn encode(&self, encoder: &mut Encoder) {
+                    encoder.write(&self.to_le_bytes())
+                }
+
+                fn decode(decoder: &mut Decoder) -> Result<Self, DecodeError> {
+                    decoder.read().map(<$ty>::from_le_bytes)
+                }
+            }
+        )*
+    };
+}
+impl_operand_for_int!(u8, i8, u16, i16, u32, i32);
+
+impl<const N: usize> Operand for [Register; N] {
+    fn encode(&self, encoder: &mut Encoder) {
+        for register in self {
+            register.encode(encoder);
+        }
+    }
+
+    fn decode(decoder: &mut Decoder) -> Result<Self, DecodeError> {
+        let mut registers = [Register::from_i16(0); N];
+        for register in &mut registers {
+            *register = Register::decode(decoder)?;
+        }
+        Ok(registers)
+    }
+}
+
+/// Implements [`Operand`] for types convertible from and to a primitive integer type.
+macro_rules! impl_operand_via {
+    ( $( $ty:ty => $repr:ty { $encode:expr, $decode:expr $(,)? } ),* $(,)? ) => {
+        $(
+            impl Operand for $ty {
+                fn encode(&self, encoder: &mut Encoder) {
+                    let encode: fn($ty) -> $repr = $encode;
+                    encode(*self).encode(encoder)
+                }
+
+                fn decode(decoder: &mut Decoder) -> Result<Self, DecodeError> {
+                    let decode: fn($repr) -> Option<$ty> = $decode;
+                    decode(<$repr>::decode(decoder)?).ok_or(DecodeError::InvalidOperand)
+                }
+            }
+        )*
+    };
+}
+impl_operand_via! {
+    Register => i16 { Register::to_i16, |value| Some(Register::from_i16(value)) },
+    RegisterSpan => i16 {
+        |span| span.head().to_i16(),
+        |value| Some(RegisterSpan::new(Register::from_i16(value))),
+    },
+    FuncIdx => u32 { FuncIdx::to_u32, |value| Some(FuncIdx::from(value)) },
+    SignatureIdx => u32 { SignatureIdx::to_u32, |value| Some(SignatureIdx::from(value)) },
+    GlobalIdx => u32 { GlobalIdx::to_u32, |value| Some(GlobalIdx::from(value)) },
+    TableIdx => u32 { TableIdx::to_u32, |value| Some(TableIdx::from(value)) },
+    MemoryIdx => u32 { MemoryIdx::to_u32, |value| Some(MemoryIdx::from(value)) },
+    DataSegmentIdx => u32 { DataSegmentIdx::to_u32, |value| Some(DataSegmentIdx::from(value)) },
+    ElementSegmentIdx => u32 {
+        ElementSegmentIdx::to_u32,
+        |value| Some(ElementSegmentIdx::from(value)),
+    },
+    CompiledFunc => u32 {
+        // Note: `CompiledFunc` indices are guaranteed to fit into 32 bits.
+        |func| func.into_usize() as u32,
+        |value| Some(CompiledFunc::from_usize(value as usize)),
+    },
+    AnyConst32 => u32 { u32::from, |value| Some(AnyConst32::from(value)) },
+    BranchOffset => i32 { BranchOffset::to_i32, |value| Some(BranchOffset::from(value)) },
+    BranchOffset16 => i16 {
+        BranchOffset16::to_i16,
+        |value| BranchOffset16::new(BranchOffset::from(i32::from(value))),
+    },
+    BlockFuel => u32 {
+        // Note: `BlockFuel` is guaranteed to fit into 32 bits.
+        |fuel| fuel.to_u64() as u32,
+        |value| BlockFuel::try_from(u64::from(value)).ok(),
+    },
+    Sign => u8 {
+        |sign| match sign {
+            Sign::Pos => 0,
+            Sign::Neg => 1,
+        },
+        |value| match value {
+            0 => Some(Sign::Pos),
+            1 => Some(Sign::Neg),
+            _ => None,
+        },
+    },
+    TrapCode => u8 {
+        |trap_code| TRAP_CODES.iter().position(|&code| code == trap_code).unwrap_or_else(|| {
+            unreachable!("missing encoding for trap code: {trap_code:?}")
+        }) as u8,
+        |value| TRAP_CODES.get(usize::from(value)).copied(),
+    },
+    Const16<i32> => i16 {
+        // Note: `Const16<i32>` is guaranteed to fit into 16 bits.
+        |value| i32::from(value) as i16,
+        |value| Some(Const16::from(value)),
+    },
+    Const16<i64> => i16 {
+        |value| i64::from(value) as i16,
+        |value| Some(Const16::from(value)),
+    },
+    Const16<u32> => u16 {
+        |value| u32::from(value) as u16,
+        |value| Some(Const16::from(value)),
+    },
+    Const16<u64> => u16 {
+        |value| u64::from(value) as u16,
+        |value| Some(Const16::from(value)),
+    },
+    Const16<NonZeroI32> => i16 {
+        |value| NonZeroI32::from(value).get() as i16,
+        |value| NonZeroI16::new(value).map(Const16::from),
+    },
+    Const16<NonZeroI64> => i16 {
+        |value| NonZeroI64::from(value).get() as i16,
+        |value| NonZeroI16::new(value).map(Const16::from),
+    },
+    Const16<NonZeroU32> => u16 {
+        |value| NonZeroU32::from(value).get() as u16,
+        |value| NonZeroU16::new(value).map(Const16::from),
+    },
+    Const16<NonZeroU64> => u16 {
+        |value| NonZeroU64::from(value).get() as u16,
+        |value| NonZeroU16::new(value).map(Const16::from),
+    },
+    Const32<u32> => u32 { u32::from, |value| Some(Const32::from(value)) },
+    Const32<i64> => i32 {
+        // Note: `Const32<i64>` is guaranteed to fit into 32 bits.
+        |value| i64::from(value) as i32,
+        |value| Some(Const32::from(value)),
+    },
+    Const32<f64> => u32 {
+        // Note: `Const32<f64>` is guaranteed to be losslessly representable as `f32`.
+        |value| (f64::from(value) as f32).to_bits(),
+        |value| Some(Const32::from(f32::from_bits(value))),
+    },
+}
+
+impl Operand for RegisterSpanIter {
+    fn encode(&self, encoder: &mut Encoder) {
+        self.span().encode(encoder);
+        self.len_as_u16().encode(encoder);
+    }
+
+    fn decode(decoder: &mut Decoder) -> Result<Self, DecodeError> {
+        let span = RegisterSpan::decode(decoder)?;
+        let len = u16::decode(decoder)?;
+        if span.head().to_i16().checked_add_unsigned(len).is_none() {
+            return Err(DecodeError::InvalidOperand);
+        }
+        Ok(span.iter_u16(len))
+    }
+}
+
+/// All [`TrapCode`]s in the order of their encoding.
+const TRAP_CODES: [TrapCode; 14] = [
+    TrapCode::UnreachableCodeReached,
+    TrapCode::MemoryOutOfBounds,
+    TrapCode::TableOutOfBounds,
+    TrapCode::IndirectCallToNull,
+    TrapCode::IntegerDivisionByZero,
+    TrapCode::IntegerOverflow,
+    TrapCode::BadConversionToInteger,
+    TrapCode::StackOverflow,
+    TrapCode::BadSignature,
+    TrapCode::OutOfFuel,
+    TrapCode::GrowthOperationLimited,
+    TrapCode::UnalignedAtomic,
+    TrapCode::ExpectedSharedMemory,
+    TrapCode::UninitializedElement,
+];
+
+/// Implements [`Operand`] for structs by encoding their fields in order.
+macro_rules! impl_operand_for_struct {
+    ( $( $name:ident $(<$param:ident>)? { $( $field:ident ),* $(,)? } ),* $(,)? ) => {
+        $(
+            impl$(<$param: Operand>)? Operand for $name$(<$param>)? {
+                fn encode(&self, encoder: &mut Encoder) {
+                    $( self.$field.encode(encoder); )*
+                }
+
+                fn decode(decoder: &mut Decoder) -> Result<Self, DecodeError> {
+                    Ok(Self {
+                        $( $field: Operand::decode(decoder)?, )*
+                    })
+                }
+            }
+        )*
+    };
+}
+impl_operand_for_struct! {
+    BinInstr { result, lhs, rhs },
+    BinInstrImm<T> { result, reg_in, imm_in },
+    UnaryInstr { result, input },
+    LoadInstr { result, ptr },
+    LoadAtInstr { result, address },
+    LoadOffset16Instr { result, ptr, offset },
+    LoadOpInstr { result, operand, ptr },
+    StoreInstr { ptr, offset },
+    StoreOffset16Instr<T> { ptr, offset, value },
+    StoreAtInstr<T> { address, value },
+    CallIndirectParams<T> { table, index },
+    BranchBinOpInstr { lhs, rhs, offset },
+    BranchBinOpInstrImm<T> { lhs, rhs, offset },
+}
+
+/// Expands to the `$binding` identifier for the operand of a tuple variant of type `$ty`.
+macro_rules! operand_binding {
+    ( $ty:ty, $binding:ident ) => {
+        $binding
+    };
+}
+
+/// Implements encoding and decoding of all [`Instruction`] variants.
+///
+/// # Note
+///
+/// The list of variants must be kept in sync with the [`Instruction`] declaration.
+/// Missing variants are detected by the compiler due to exhaustive matching.
+macro_rules! define_codec {
+    (
+        $(
+            $name:ident
+            $( ( $ty:ty ) )?
+            $( { $( $field:ident : $field_ty:ty ),* $(,)? } )?
+        ),* $(,)?
+    ) => {
+        /// The opcodes of all [`Instruction`] variants.
+        #[allow(non_upper_case_globals)]
+        mod opcode {
+            /// The [`Instruction`] variants in the order of their opcodes.
+            ///
+            /// [`Instruction`]: super::Instruction
+            #[repr(u16)]
+            enum Opcode {
+                $( $name ),*
+            }
+
+            $(
+                pub const $name: u16 = Opcode::$name as u16;
+            )*
+        }
+
+        impl Instruction {
+            /// Encodes the [`Instruction`] using the [`Encoder`].
+            fn encode_into(&self, encoder: &mut Encoder) {
+                match self {
+                    $(
+                        Self::$name
+                        $( ( operand_binding!($ty, operand) ) )?
+                        $( { $( $field ),* } )? => {
+                            opcode::$name.encode(encoder);
+                            $( <$ty as Operand>::encode(operand, encoder); )?
+                            $( $( <$field_ty as Operand>::encode($field, encoder); )* )?
+                        }
+                    )*
+                }
+            }
+
+            /// Decodes an [`Instruction`] using the [`Decoder`].
+            fn decode_from(decoder: &mut Decoder) -> Result<Self, DecodeError> {
+                let instr = match u16::decode(decoder)? {
+                    $(
+                        opcode::$name => Self::$name
+                            $( ( <$ty as Operand>::decode(decoder)? ) )?
+                            $( { $( $field: <$field_ty as Operand>::decode(decoder)? ),* } )?,
+                    )*
+                    unknown => return Err(DecodeError::UnknownOpcode(unknown)),
+                };
+                Ok(instr)
+            }
+        }
+    };
+}
+define_codec! {
+    TableIdx(TableIdx),
+    MemoryIdx(MemoryIdx),
+    DataSegmentIdx(DataSegmentIdx),
+    ElementSegmentIdx(ElementSegmentIdx),
+    Const32(AnyConst32),
+    I64Const32(Const32<i64>),
+    F64Const32(Const32<f64>),
+    Register(Register),
+    Register2([Register; 2]),
+    Register3([Register; 3]),
+    RegisterList([Register; 3]),
+    RegisterSpanList { values: RegisterSpan, len: u16 },
+    CallIndirectParams(CallIndirectParams<Register>),
+    CallIndirectParamsImm16(CallIndirectParams<Const16<u32>>),
+    Trap(TrapCode),
+    ConsumeFuel(BlockFuel),
+    Return,
+    ReturnReg { value: Register },
+    ReturnReg2 { values: [Register; 2] },
+    ReturnReg3 { values: [Register; 3] },
+    ReturnImm32 { value: AnyConst32 },
+    ReturnI64Imm32 { value: Const32<i64> },
+    ReturnF64Imm32 { value: Const32<f64> },
+    ReturnI64Imm,
+    ReturnF64Imm,
+    ReturnSpan { values: RegisterSpanIter },
+    ReturnMany { values: [Register; 3] },
+    ReturnNez { condition: Register },
+    ReturnNezReg { condition: Register, value: Register },
+    ReturnNezReg2 { condition: Register, values: [Register; 2] },
+    ReturnNezImm32 { condition: Register, value: AnyConst32 },
+    ReturnNezI64Imm32 { condition: Register, value: Const32<i64> },
+    ReturnNezF64Imm32 { condition: Register, value: Const32<f64> },
+    ReturnNezSpan { condition: Register, values: RegisterSpanIter },
+    ReturnNezMany { condition: Register, values: [Register; 2] },
+    Branch { offset: BranchOffset },
+    BranchI32And(BranchBinOpInstr),
+    BranchI32AndImm(BranchBinOpInstrImm16<i32>),
+    BranchI32Or(BranchBinOpInstr),
+    BranchI32OrImm(BranchBinOpInstrImm16<i32>),
+    BranchI32Xor(BranchBinOpInstr),
+    BranchI32XorImm(BranchBinOpInstrImm16<i32>),
+    BranchI32AndEqz(BranchBinOpInstr),
+    BranchI32AndEqzImm(BranchBinOpInstrImm16<i32>),
+    BranchI32OrEqz(BranchBinOpInstr),
+    BranchI32OrEqzImm(BranchBinOpInstrImm16<i32>),
+    BranchI32XorEqz(BranchBinOpInstr),
+    BranchI32XorEqzImm(BranchBinOpInstrImm16<i32>),
+    BranchI32Eq(BranchBinOpInstr),
+    BranchI32EqImm(BranchBinOpInstrImm16<i32>),
+    BranchI32Ne(BranchBinOpInstr),
+    BranchI32NeImm(BranchBinOpInstrImm16<i32>),
+    BranchI32LtS(BranchBinOpInstr),
+    BranchI32LtSImm(BranchBinOpInstrImm16<i32>),
+    BranchI32LtU(BranchBinOpInstr),
+    BranchI32LtUImm(BranchBinOpInstrImm16<u32>),
+    BranchI32LeS(BranchBinOpInstr),
+    BranchI32LeSImm(BranchBinOpInstrImm16<i32>),
+    BranchI32LeU(BranchBinOpInstr),
+    BranchI32LeUImm(BranchBinOpInstrImm16<u32>),
+    BranchI32GtS(BranchBinOpInstr),
+    BranchI32GtSImm(BranchBinOpInstrImm16<i32>),
+    BranchI32GtU(BranchBinOpInstr),
+    BranchI32GtUImm(BranchBinOpInstrImm16<u32>),
+    BranchI32GeS(BranchBinOpInstr),
+    BranchI32GeSImm(BranchBinOpInstrImm16<i32>),
+    BranchI32GeU(BranchBinOpInstr),
+    BranchI32GeUImm(BranchBinOpInstrImm16<u32>),
+    BranchI64Eq(BranchBinOpInstr),
+    BranchI64EqImm(BranchBinOpInstrImm16<i64>),
+    BranchI64Ne(BranchBinOpInstr),
+    BranchI64NeImm(BranchBinOpInstrImm16<i64>),
+    BranchI64LtS(BranchBinOpInstr),
+    BranchI64LtSImm(BranchBinOpInstrImm16<i64>),
+    BranchI64LtU(BranchBinOpInstr),
+    BranchI64LtUImm(BranchBinOpInstrImm16<u64>),
+    BranchI64LeS(BranchBinOpInstr),
+    BranchI64LeSImm(BranchBinOpInstrImm16<i64>),
+    BranchI64LeU(BranchBinOpInstr),
+    BranchI64LeUImm(BranchBinOpInstrImm16<u64>),
+    BranchI64GtS(BranchBinOpInstr),
+    BranchI64GtSImm(BranchBinOpInstrImm16<i64>),
+    BranchI64GtU(BranchBinOpInstr),
+    BranchI64GtUImm(BranchBinOpInstrImm16<u64>),
+    BranchI64GeS(BranchBinOpInstr),
+    BranchI64GeSImm(BranchBinOpInstrImm16<i64>),
+    BranchI64GeU(BranchBinOpInstr),
+    BranchI64GeUImm(BranchBinOpInstrImm16<u64>),
+    BranchF32Eq(BranchBinOpInstr),
+    BranchF32Ne(BranchBinOpInstr),
+    BranchF32Lt(BranchBinOpInstr),
+    BranchF32Le(BranchBinOpInstr),
+    BranchF32Gt(BranchBinOpInstr),
+    BranchF32Ge(BranchBinOpInstr),
+    BranchF64Eq(BranchBinOpInstr),
+    BranchF64Ne(BranchBinOpInstr),
+    BranchF64Lt(BranchBinOpInstr),
+    BranchF64Le(BranchBinOpInstr),
+    BranchF64Gt(BranchBinOpInstr),
+    BranchF64Ge(BranchBinOpInstr),
+    BranchTable { index: Register, len_targets: Const32<u32> },
+    Copy { result: Register, value: Register },
+    Copy2 { results: RegisterSpan, values: [Register; 2] },
+    CopyImm32 { result: Register, value: AnyConst32 },
+    CopyI64Imm32 { result: Register, value: Const32<i64> },
+    CopyF64Imm32 { result: Register, value: Const32<f64> },
+    CopyI64Imm { result: Register },
+    CopyF64Imm { result: Register },
+    CopySpan { results: RegisterSpan, values: RegisterSpan, len: u16 },
+    CopySpanNonOverlapping { results: RegisterSpan, values: RegisterSpan, len: u16 },
+    CopySpanSmall { results: RegisterSpan, values: RegisterSpan, len: u16 },
+    CopyMany { results: RegisterSpan, values: [Register; 2] },
+    CopyManyNonOverlapping { results: RegisterSpan, values: [Register; 2] },
+    ReturnCallInternal0 { func: CompiledFunc },
+    ReturnCallInternal { func: CompiledFunc },
+    ReturnCallImported0 { func: FuncIdx },
+    ReturnCallImported { func: FuncIdx },
+    ReturnCallIndirect0 { func_type: SignatureIdx },
+    ReturnCallIndirect { func_type: SignatureIdx },
+    CallInternal0 { results: RegisterSpan, func: CompiledFunc },
+    CallInternal { results: RegisterSpan, func: CompiledFunc },
+    CallImported0 { results: RegisterSpan, func: FuncIdx },
+    CallImported { results: RegisterSpan, func: FuncIdx },
+    CallIndirect0 { results: RegisterSpan, func_type: SignatureIdx },
+    CallIndirect { results: RegisterSpan, func_type: SignatureIdx },
+    Select { result: Register, condition: Register, lhs: Register },
+    SelectRev { result: Register, condition: Register, rhs: Register },
+    SelectImm { result: Register, condition: Register, lhs: i8, rhs: i8 },
+    SelectImm32 { result_or_condition: Register, lhs_or_rhs: AnyConst32 },
+    SelectI64Imm32 { result_or_condition: Register, lhs_or_rhs: Const32<i64> },
+    SelectF64Imm32 { result_or_condition: Register, lhs_or_rhs: Const32<f64> },
+    SelectI32Eq(BinInstr),
+    SelectI32Ne(BinInstr),
+    SelectI32LtS(BinInstr),
+    SelectI32LtU(BinInstr),
+    SelectI32LeS(BinInstr),
+    SelectI32LeU(BinInstr),
+    SelectI64Eq(BinInstr),
+    SelectI64Ne(BinInstr),
+    SelectI64LtS(BinInstr),
+    SelectI64LtU(BinInstr),
+    SelectI64LeS(BinInstr),
+    SelectI64LeU(BinInstr),
+    RefFunc { result: Register, func: FuncIdx },
+    TableGet { result: Register, index: Register },
+    TableGetImm { result: Register, index: Const32<u32> },
+    TableSize { result: Register, table: TableIdx },
+    TableSet { index: Register, value: Register },
+    TableSetAt { index: Const32<u32>, value: Register },
+    TableCopy { dst: Register, src: Register, len: Register },
+    TableCopyTo { dst: Const16<u32>, src: Register, len: Register },
+    TableCopyFrom { dst: Register, src: Const16<u32>, len: Register },
+    TableCopyFromTo { dst: Const16<u32>, src: Const16<u32>, len: Register },
+    TableCopyExact { dst: Register, src: Register, len: Const16<u32> },
+    TableCopyToExact { dst: Const16<u32>, src: Register, len: Const16<u32> },
+    TableCopyFromExact { dst: Register, src: Const16<u32>, len: Const16<u32> },
+    TableCopyFromToExact { dst: Const16<u32>, src: Const16<u32>, len: Const16<u32> },
+    TableInit { dst: Register, src: Register, len: Register },
+    TableInitTo { dst: Const16<u32>, src: Register, len: Register },
+    TableInitFrom { dst: Register, src: Const16<u32>, len: Register },
+    TableInitFromTo { dst: Const16<u32>, src: Const16<u32>, len: Register },
+    TableInitExact { dst: Register, src: Register, len: Const16<u32> },
+    TableInitToExact { dst: Const16<u32>, src: Register, len: Const16<u32> },
+    TableInitFromExact { dst: Register, src: Const16<u32>, len: Const16<u32> },
+    TableInitFromToExact { dst: Const16<u32>, src: Const16<u32>, len: Const16<u32> },
+    TableFill { dst: Register, len: Register, value: Register },
+    TableFillAt { dst: Const16<u32>, len: Register, value: Register },
+    TableFillExact { dst: Register, len: Const16<u32>, value: Register },
+    TableFillAtExact { dst: Const16<u32>, len: Const16<u32>, value: Register },
+    TableGrow { result: Register, delta: Register, value: Register },
+    TableGrowImm { result: Register, delta: Const16<u32>, value: Register },
+    ElemDrop(ElementSegmentIdx),
+    DataDrop(DataSegmentIdx),
+    MemorySize { result: Register },
+    MemoryGrow { result: Register, delta: Register },
+    MemoryGrowBy { result: Register, delta: Const16<u32> },
+    MemoryCopy { dst: Register, src: Register, len: Register },
+    MemoryCopyTo { dst: Const16<u32>, src: Register, len: Register },
+    MemoryCopyFrom { dst: Register, src: Const16<u32>, len: Register },
+    MemoryCopyFromTo { dst: Const16<u32>, src: Const16<u32>, len: Register },
+    MemoryCopyExact { dst: Register, src: Register, len: Const16<u32> },
+    MemoryCopyToExact { dst: Const16<u32>, src: Register, len: Const16<u32> },
+    MemoryCopyFromExact { dst: Register, src: Const16<u32>, len: Const16<u32> },
+    MemoryCopyFromToExact { dst: Const16<u32>, src: Const16<u32>, len: Const16<u32> },
+    MemoryFill { dst: Register, value: Register, len: Register },
+    MemoryFillAt { dst: Const16<u32>, value: Register, len: Register },
+    MemoryFillImm { dst: Register, value: u8, len: Register },
+    MemoryFillExact { dst: Register, value: Register, len: Const16<u32> },
+    MemoryFillAtImm { dst: Const16<u32>, value: u8, len: Register },
+    MemoryFillAtExact { dst: Const16<u32>, value: Register, len: Const16<u32> },
+    MemoryFillImmExact { dst: Register, value: u8, len: Const16<u32> },
+    MemoryFillAtImmExact { dst: Const16<u32>, value: u8, len: Const16<u32> },
+    MemoryInit { dst: Register, src: Register, len: Register },
+    MemoryInitTo { dst: Const16<u32>, src: Register, len: Register },
+    MemoryInitFrom { dst: Register, src: Const16<u32>, len: Register },
+    MemoryInitFromTo { dst: Const16<u32>, src: Const16<u32>, len: Register },
+    MemoryInitExact { dst: Register, src: Register, len: Const16<u32> },
+    MemoryInitToExact { dst: Const16<u32>, src: Register, len: Const16<u32> },
+    MemoryInitFromExact { dst: Register, src: Const16<u32>, len: Const16<u32> },
+    MemoryInitFromToExact { dst: Const16<u32>, src: Const16<u32>, len: Const16<u32> },
+    GlobalGet { result: Register, global: GlobalIdx },
+    GlobalSet { global: GlobalIdx, input: Register },
+    GlobalSetI32Imm16 { global: GlobalIdx, input: Const16<i32> },
+    GlobalSetI64Imm16 { global: GlobalIdx, input: Const16<i64> },
+    I32Load(LoadInstr),
+    I32LoadAt(LoadAtInstr),
+    I32LoadOffset16(LoadOffset16Instr),
+    I64Load(LoadInstr),
+    I64LoadAt(LoadAtInstr),
+    I64LoadOffset16(LoadOffset16Instr),
+    F32Load(LoadInstr),
+    F32LoadAt(LoadAtInstr),
+    F32LoadOffset16(LoadOffset16Instr),
+    F64Load(LoadInstr),
+    F64LoadAt(LoadAtInstr),
+    F64LoadOffset16(LoadOffset16Instr),
+    I32Load8s(LoadInstr),
+    I32Load8sAt(LoadAtInstr),
+    I32Load8sOffset16(LoadOffset16Instr),
+    I32Load8u(LoadInstr),
+    I32Load8uAt(LoadAtInstr),
+    I32Load8uOffset16(LoadOffset16Instr),
+    I32Load16s(LoadInstr),
+    I32Load16sAt(LoadAtInstr),
+    I32Load16sOffset16(LoadOffset16Instr),
+    I32Load16u(LoadInstr),
+    I32Load16uAt(LoadAtInstr),
+    I32Load16uOffset16(LoadOffset16Instr),
+    I64Load8s(LoadInstr),
+    I64Load8sAt(LoadAtInstr),
+    I64Load8sOffset16(LoadOffset16Instr),
+    I64Load8u(LoadInstr),
+    I64Load8uAt(LoadAtInstr),
+    I64Load8uOffset16(LoadOffset16Instr),
+    I64Load16s(LoadInstr),
+    I64Load16sAt(LoadAtInstr),
+    I64Load16sOffset16(LoadOffset16Instr),
+    I64Load16u(LoadInstr),
+    I64Load16uAt(LoadAtInstr),
+    I64Load16uOffset16(LoadOffset16Instr),
+    I64Load32s(LoadInstr),
+    I64Load32sAt(LoadAtInstr),
+    I64Load32sOffset16(LoadOffset16Instr),
+    I64Load32u(LoadInstr),
+    I64Load32uAt(LoadAtInstr),
+    I64Load32uOffset16(LoadOffset16Instr),
+    I32AddLoad(LoadOpInstr),
+    I32SubLoad(LoadOpInstr),
+    I32AndLoad(LoadOpInstr),
+    I32OrLoad(LoadOpInstr),
+    I32XorLoad(LoadOpInstr),
+    I64AddLoad(LoadOpInstr),
+    I64SubLoad(LoadOpInstr),
+    I64AndLoad(LoadOpInstr),
+    I64OrLoad(LoadOpInstr),
+    I64XorLoad(LoadOpInstr),
+    I32Store(StoreInstr),
+    I32StoreOffset16(StoreOffset16Instr<Register>),
+    I32StoreOffset16Imm16(StoreOffset16Instr<Const16<i32>>),
+    I32StoreAt(StoreAtInstr<Register>),
+    I32StoreAtImm16(StoreAtInstr<Const16<i32>>),
+    I32Store8(StoreInstr),
+    I32Store8Offset16(StoreOffset16Instr<Register>),
+    I32Store8Offset16Imm(StoreOffset16Instr<i8>),
+    I32Store8At(StoreAtInstr<Register>),
+    I32Store8AtImm(StoreAtInstr<i8>),
+    I32Store16(StoreInstr),
+    I32Store16Offset16(StoreOffset16Instr<Register>),
+    I32Store16Offset16Imm(StoreOffset16Instr<i16>),
+    I32Store16At(StoreAtInstr<Register>),
+    I32Store16AtImm(StoreAtInstr<i16>),
+    I64Store(StoreInstr),
+    I64StoreOffset16(StoreOffset16Instr<Register>),
+    I64StoreOffset16Imm16(StoreOffset16Instr<Const16<i64>>),
+    I64StoreAt(StoreAtInstr<Register>),
+    I64StoreAtImm16(StoreAtInstr<Const16<i64>>),
+    I64Store8(StoreInstr),
+    I64Store8Offset16(StoreOffset16Instr<Register>),
+    I64Store8Offset16Imm(StoreOffset16Instr<i8>),
+    I64Store8At(StoreAtInstr<Register>),
+    I64Store8AtImm(StoreAtInstr<i8>),
+    I64Store16(StoreInstr),
+    I64Store16Offset16(StoreOffset16Instr<Register>),
+    I64Store16Offset16Imm(StoreOffset16Instr<i16>),
+    I64Store16At(StoreAtInstr<Register>),
+    I64Store16AtImm(StoreAtInstr<i16>),
+    I64Store32(StoreInstr),
+    I64Store32Offset16(StoreOffset16Instr<Register>),
+    I64Store32Offset16Imm16(StoreOffset16Instr<Const16<i32>>),
+    I64Store32At(StoreAtInstr<Register>),
+    I64Store32AtImm16(StoreAtInstr<Const16<i32>>),
+    F32Store(StoreInstr),
+    F32StoreOffset16(StoreOffset16Instr<Register>),
+    F32StoreAt(StoreAtInstr<Register>),
+    F64Store(StoreInstr),
+    F64StoreOffset16(StoreOffset16Instr<Register>),
+    F64StoreAt(StoreAtInstr<Register>),
+    I32AtomicLoad(LoadInstr),
+    I64AtomicLoad(LoadInstr),
+    I32AtomicStore(StoreInstr),
+    I64AtomicStore(StoreInstr),
+    I32AtomicRmwAdd(LoadInstr),
+    I64AtomicRmwAdd(LoadInstr),
+    I32AtomicRmw8AddU(LoadInstr),
+    I32AtomicRmw16AddU(LoadInstr),
+    I64AtomicRmw8AddU(LoadInstr),
+    I64AtomicRmw16AddU(LoadInstr),
+    I64AtomicRmw32AddU(LoadInstr),
+    MemoryAtomicNotify(LoadInstr),
+    MemoryAtomicWait32(LoadInstr),
+    MemoryAtomicWait64(LoadInstr),
+    I32Eq(BinInstr),
+    I32EqImm16(BinInstrImm16<i32>),
+    I64Eq(BinInstr),
+    I64EqImm16(BinInstrImm16<i64>),
+    I32Ne(BinInstr),
+    I32NeImm16(BinInstrImm16<i32>),
+    I64Ne(BinInstr),
+    I64NeImm16(BinInstrImm16<i64>),
+    I32LtS(BinInstr),
+    I32LtU(BinInstr),
+    I32LtSImm16(BinInstrImm16<i32>),
+    I32LtUImm16(BinInstrImm16<u32>),
+    I64LtS(BinInstr),
+    I64LtU(BinInstr),
+    I64LtSImm16(BinInstrImm16<i64>),
+    I64LtUImm16(BinInstrImm16<u64>),
+    I32GtS(BinInstr),
+    I32GtU(BinInstr),
+    I32GtSImm16(BinInstrImm16<i32>),
+    I32GtUImm16(BinInstrImm16<u32>),
+    I64GtS(BinInstr),
+    I64GtU(BinInstr),
+    I64GtSImm16(BinInstrImm16<i64>),
+    I64GtUImm16(BinInstrImm16<u64>),
+    I32LeS(BinInstr),
+    I32LeU(BinInstr),
+    I32LeSImm16(BinInstrImm16<i32>),
+    I32LeUImm16(BinInstrImm16<u32>),
+    I64LeS(BinInstr),
+    I64LeU(BinInstr),
+    I64LeSImm16(BinInstrImm16<i64>),
+    I64LeUImm16(BinInstrImm16<u64>),
+    I32GeS(BinInstr),
+    I32GeU(BinInstr),
+    I32GeSImm16(BinInstrImm16<i32>),
+    I32GeUImm16(BinInstrImm16<u32>),
+    I64GeS(BinInstr),
+    I64GeU(BinInstr),
+    I64GeSImm16(BinInstrImm16<i64>),
+    I64GeUImm16(BinInstrImm16<u64>),
+    F32Eq(BinInstr),
+    F64Eq(BinInstr),
+    F32Ne(BinInstr),
+    F64Ne(BinInstr),
+    F32Lt(BinInstr),
+    F64Lt(BinInstr),
+    F32Le(BinInstr),
+    F64Le(BinInstr),
+    F32Gt(BinInstr),
+    F64Gt(BinInstr),
+    F32Ge(BinInstr),
+    F64Ge(BinInstr),
+    I32Clz(UnaryInstr),
+    I64Clz(UnaryInstr),
+    I32Ctz(UnaryInstr),
+    I64Ctz(UnaryInstr),
+    I32Popcnt(UnaryInstr),
+    I64Popcnt(UnaryInstr),
+    I32Add(BinInstr),
+    I64Add(BinInstr),
+    I32AddImm16(BinInstrImm16<i32>),
+    I64AddImm16(BinInstrImm16<i64>),
+    I32Sub(BinInstr),
+    I64Sub(BinInstr),
+    I32SubImm16(BinInstrImm16<i32>),
+    I64SubImm16(BinInstrImm16<i64>),
+    I32SubImm16Rev(BinInstrImm16<i32>),
+    I64SubImm16Rev(BinInstrImm16<i64>),
+    I32Mul(BinInstr),
+    I64Mul(BinInstr),
+    I32MulImm16(BinInstrImm16<i32>),
+    I64MulImm16(BinInstrImm16<i64>),
+    I32DivS(BinInstr),
+    I64DivS(BinInstr),
+    I32DivSImm16(BinInstrImm16<NonZeroI32>),
+    I64DivSImm16(BinInstrImm16<NonZeroI64>),
+    I32DivSImm16Rev(BinInstrImm16<i32>),
+    I64DivSImm16Rev(BinInstrImm16<i64>),
+    I32DivU(BinInstr),
+    I64DivU(BinInstr),
+    I32DivUImm16(BinInstrImm16<NonZeroU32>),
+    I64DivUImm16(BinInstrImm16<NonZeroU64>),
+    I32DivUImm16Rev(BinInstrImm16<u32>),
+    I64DivUImm16Rev(BinInstrImm16<u64>),
+    I32RemS(BinInstr),
+    I64RemS(BinInstr),
+    I32RemSImm16(BinInstrImm16<NonZeroI32>),
+    I64RemSImm16(BinInstrImm16<NonZeroI64>),
+    I32RemSImm16Rev(BinInstrImm16<i32>),
+    I64RemSImm16Rev(BinInstrImm16<i64>),
+    I32RemU(BinInstr),
+    I64RemU(BinInstr),
+    I32RemUImm16(BinInstrImm16<NonZeroU32>),
+    I64RemUImm16(BinInstrImm16<NonZeroU64>),
+    I32RemUImm16Rev(BinInstrImm16<u32>),
+    I64RemUImm16Rev(BinInstrImm16<u64>),
+    I32And(BinInstr),
+    I32AndEqz(BinInstr),
+    I32AndEqzImm16(BinInstrImm16<i32>),
+    I32AndImm16(BinInstrImm16<i32>),
+    I64And(BinInstr),
+    I64AndImm16(BinInstrImm16<i64>),
+    I32Or(BinInstr),
+    I32OrEqz(BinInstr),
+    I32OrEqzImm16(BinInstrImm16<i32>),
+    I32OrImm16(BinInstrImm16<i32>),
+    I64Or(BinInstr),
+    I64OrImm16(BinInstrImm16<i64>),
+    I32Xor(BinInstr),
+    I32XorEqz(BinInstr),
+    I32XorEqzImm16(BinInstrImm16<i32>),
+    I32XorImm16(BinInstrImm16<i32>),
+    I64Xor(BinInstr),
+    I64XorImm16(BinInstrImm16<i64>),
+    I32Shl(BinInstr),
+    I64Shl(BinInstr),
+    I32ShlImm(BinInstrImm16<i32>),
+    I64ShlImm(BinInstrImm16<i64>),
+    I32ShlImm16Rev(BinInstrImm16<i32>),
+    I64ShlImm16Rev(BinInstrImm16<i64>),
+    I32ShrU(BinInstr),
+    I64ShrU(BinInstr),
+    I32ShrUImm(BinInstrImm16<i32>),
+    I64ShrUImm(BinInstrImm16<i64>),
+    I32ShrUImm16Rev(BinInstrImm16<i32>),
+    I64ShrUImm16Rev(BinInstrImm16<i64>),
+    I32ShrS(BinInstr),
+    I64ShrS(BinInstr),
+    I32ShrSImm(BinInstrImm16<i32>),
+    I64ShrSImm(BinInstrImm16<i64>),
+    I32ShrSImm16Rev(BinInstrImm16<i32>),
+    I64ShrSImm16Rev(BinInstrImm16<i64>),
+    I32Rotl(BinInstr),
+    I64Rotl(BinInstr),
+    I32RotlImm(BinInstrImm16<i32>),
+    I64RotlImm(BinInstrImm16<i64>),
+    I32RotlImm16Rev(BinInstrImm16<i32>),
+    I64RotlImm16Rev(BinInstrImm16<i64>),
+    I32Rotr(BinInstr),
+    I64Rotr(BinInstr),
+    I32RotrImm(BinInstrImm16<i32>),
+    I64RotrImm(BinInstrImm16<i64>),
+    I32RotrImm16Rev(BinInstrImm16<i32>),
+    I64RotrImm16Rev(BinInstrImm16<i64>),
+    F32Abs(UnaryInstr),
+    F64Abs(UnaryInstr),
+    F32Neg(UnaryInstr),
+    F64Neg(UnaryInstr),
+    F32Ceil(UnaryInstr),
+    F64Ceil(UnaryInstr),
+    F32Floor(UnaryInstr),
+    F64Floor(UnaryInstr),
+    F32Trunc(UnaryInstr),
+    F64Trunc(UnaryInstr),
+    F32Nearest(UnaryInstr),
+    F64Nearest(UnaryInstr),
+    F32Sqrt(UnaryInstr),
+    F64Sqrt(UnaryInstr),
+    F32CanonicalizeNan(UnaryInstr),
+    F64CanonicalizeNan(UnaryInstr),
+    F32Add(BinInstr),
+    F64Add(BinInstr),
+    F32Sub(BinInstr),
+    F64Sub(BinInstr),
+    F32Mul(BinInstr),
+    F64Mul(BinInstr),
+    F32Div(BinInstr),
+    F64Div(BinInstr),
+    F32Min(BinInstr),
+    F64Min(BinInstr),
+    F32Max(BinInstr),
+    F64Max(BinInstr),
+    F32Copysign(BinInstr),
+    F64Copysign(BinInstr),
+    F32CopysignImm(BinInstrImm<Sign>),
+    F64CopysignImm(BinInstrImm<Sign>),
+    I32WrapI64(UnaryInstr),
+    I64ExtendI32S(UnaryInstr),
+    I64ExtendI32U(UnaryInstr),
+    I32TruncF32S(UnaryInstr),
+    I32TruncF32U(UnaryInstr),
+    I32TruncF64S(UnaryInstr),
+    I32TruncF64U(UnaryInstr),
+    I64TruncF32S(UnaryInstr),
+    I64TruncF32U(UnaryInstr),
+    I64TruncF64S(UnaryInstr),
+    I64TruncF64U(UnaryInstr),
+    I32TruncSatF32S(UnaryInstr),
+    I32TruncSatF32U(UnaryInstr),
+    I32TruncSatF64S(UnaryInstr),
+    I32TruncSatF64U(UnaryInstr),
+    I64TruncSatF32S(UnaryInstr),
+    I64TruncSatF32U(UnaryInstr),
+    I64TruncSatF64S(UnaryInstr),
+    I64TruncSatF64U(UnaryInstr),
+    I32Extend8S(UnaryInstr),
+    I32Extend16S(UnaryInstr),
+    I64Extend8S(UnaryInstr),
+    I64Extend16S(UnaryInstr),
+    I64Extend32S(UnaryInstr),
+    F32DemoteF64(UnaryInstr),
+    F64PromoteF32(UnaryInstr),
+    F32ConvertI32S(UnaryInstr),
+    F32ConvertI32U(UnaryInstr),
+    F32ConvertI64S(UnaryInstr),
+    F32ConvertI64U(UnaryInstr),
+    F64ConvertI32S(UnaryInstr),
+    F64ConvertI32U(UnaryInstr),
+    F64ConvertI64S(UnaryInstr),
+    F64ConvertI64U(UnaryInstr),
+}
@@ -19,6 +19,8 @@ use super::{
     LoadAtInstr,
     LoadInstr,
     LoadOffset16Instr,
+    LoadOpInstr,
+    MemoryIdx,
     Register,
     RegisterSpan,
     RegisterSpanIter,
@@ -29,6 +31,7 @@ use super::{
     TableIdx,
     UnaryInstr,
 };
+use crate::core::UntypedValue;
 use core::num::{NonZeroI32, NonZeroI64, NonZeroU32, NonZeroU64};
 
 macro_rules! constructor_for {
@@ -123,6 +126,12 @@ macro_rules! constructor_for {
             Self::$op_code(LoadOffset16Instr::new(result, ptr, offset))
         }
     };
+    ( @impl fn $fn_name:ident(load_op) -> Self::$op_code:ident ) => {
+        #[doc = concat!("Creates a new [`Instruction::", stringify!($op_code), "`].")]
+        pub fn $fn_name(result: Register, operand: Register, ptr: Register) -> Self {
+            Self::$op_code(LoadOpInstr::new(result, operand, ptr))
+        }
+    };
     ( @impl fn $fn_name:ident(store) -> Self::$op_code:ident ) => {
         #[doc = concat!("Creates a new [`Instruction::", stringify!($op_code), "`].")]
         pub fn $fn_name(ptr: Register, offset: Const32<u32>) -> Self {
@@ -351,6 +360,16 @@ impl Instruction {
         }
     }
 
+    /// Creates the two [`Instruction::Const32`] words encoding the 64-bit `value`.
+    ///
+    /// The first word holds the low 32 bits and the second word holds the high 32 bits.
+    pub fn const64(value: impl Into<UntypedValue>) -> [Self; 2] {
+        let bits = u64::from(value.into());
+        let lo = bits as u32;
+        let hi = (bits >> 32) as u32;
+        [Self::const32(lo), Self::const32(hi)]
+    }
+
     /// Creates a new [`Instruction::ReturnSpan`] from the given `values`.
     pub fn return_span(values: RegisterSpanIter) -> Self {
         Self::ReturnSpan { values }
@@ -512,9 +531,23 @@ impl Instruction {
         }
     }
 
+    /// Creates a new [`Instruction::CopyI64Imm`].
+    ///
+    /// Must be followed by the [`Instruction::const64`] words of the copied value.
+    pub fn copy_i64imm(result: Register) -> Self {
+        Self::CopyI64Imm { result }
+    }
+
+    /// Creates a new [`Instruction::CopyF64Imm`].
+    ///
+    /// Must be followed by the [`Instruction::const64`] words of the copied value.
+    pub fn copy_f64imm(result: Register) -> Self {
+        Self::CopyF64Imm { result }
+    }
+
     /// Creates a new [`Instruction::CopySpan`] copying multiple consecutive values.
     pub fn copy_span(results: RegisterSpan, values: RegisterSpan, len: u16) -> Self {
-        debug_assert!(RegisterSpanIter::has_overlapping_copies(
+        debug_assert!(RegisterSpanIter::overlaps_copy(
             results.iter_u16(len),
             values.iter_u16(len)
         ));
@@ -531,7 +564,7 @@ impl Instruction {
         values: RegisterSpan,
         len: u16,
     ) -> Self {
-        debug_assert!(!RegisterSpanIter::has_overlapping_copies(
+        debug_assert!(!RegisterSpanIter::overlaps_copy(
             results.iter_u16(len),
             values.iter_u16(len)
         ));
@@ -542,6 +575,16 @@ impl Instruction {
         }
     }
 
+    /// Creates a new [`Instruction::CopySpanSmall`] copying up to 4 consecutive values.
+    pub fn copy_span_small(results: RegisterSpan, values: RegisterSpan, len: u16) -> Self {
+        debug_assert!(len <= Self::COPY_SPAN_SMALL_MAX_LEN);
+        Self::CopySpanSmall {
+            results,
+            values,
+            len,
+        }
+    }
+
     /// Creates a new [`Instruction::CopyMany`].
     pub fn copy_many(
         results: RegisterSpan,
@@ -620,6 +663,16 @@ impl Instruction {
         }
     }
 
+    /// Creates a new [`Instruction::SelectImm`].
+    pub fn select_imm(result: Register, condition: Register, lhs: i8, rhs: i8) -> Self {
+        Self::SelectImm {
+            result,
+            condition,
+            lhs,
+            rhs,
+        }
+    }
+
     /// Creates a new [`Instruction::SelectImm32`].
     pub fn select_imm32(result_or_condition: Register, lhs_or_rhs: impl Into<AnyConst32>) -> Self {
         Self::SelectImm32 {
@@ -668,6 +721,11 @@ impl Instruction {
         Self::ElementSegmentIdx(index.into())
     }
 
+    /// Creates a new [`Instruction::MemoryIdx`] from the given `index`.
+    pub fn memory_idx(index: impl Into<MemoryIdx>) -> Self {
+        Self::MemoryIdx(index.into())
+    }
+
     /// Creates a new [`Instruction::TableIdx`] from the given `index`.
     pub fn table_idx(index: impl Into<TableIdx>) -> Self {
         Self::TableIdx(index.into())
@@ -1219,6 +1277,11 @@ impl Instruction {
         Self::RegisterList([reg0.into(), reg1.into(), reg2.into()])
     }
 
+    /// Creates a new [`Instruction::RegisterSpanList`] instruction parameter.
+    pub fn register_span_list(values: RegisterSpan, len: u16) -> Self {
+        Self::RegisterSpanList { values, len }
+    }
+
     /// Creates a new [`Instruction::CallIndirectParams`] for the given `index` and `table`.
     pub fn call_indirect_params(index: Register, table: impl Into<TableIdx>) -> Self {
         Self::CallIndirectParams(CallIndirectParams {
@@ -1373,6 +1436,19 @@ impl Instruction {
         fn f64_load_at(load_at) -> Self::F64LoadAt;
         fn f64_load_offset16(load_offset16) -> Self::F64LoadOffset16;
 
+        // Fused Load + Binary Operation
+
+        fn i32_add_load(load_op) -> Self::I32AddLoad;
+        fn i32_sub_load(load_op) -> Self::I32SubLoad;
+        fn i32_and_load(load_op) -> Self::I32AndLoad;
+        fn i32_or_load(load_op) -> Self::I32OrLoad;
+        fn i32_xor_load(load_op) -> Self::I32XorLoad;
+        fn i64_add_load(load_op) -> Self::I64AddLoad;
+        fn i64_sub_load(load_op) -> Self::I64SubLoad;
+        fn i64_and_load(load_op) -> Self::I64AndLoad;
+        fn i64_or_load(load_op) -> Self::I64OrLoad;
+        fn i64_xor_load(load_op) -> Self::I64XorLoad;
+
         // Store
 
         fn i32_store(store) -> Self::I32Store;
@@ -1425,6 +1501,23 @@ impl Instruction {
         fn f64_store_offset16(store_offset16) -> Self::F64StoreOffset16;
         fn f64_store_at(store_at) -> Self::F64StoreAt;
 
+        // Atomics
+
+        fn i32_atomic_load(load) -> Self::I32AtomicLoad;
+        fn i64_atomic_load(load) -> Self::I64AtomicLoad;
+        fn i32_atomic_store(store) -> Self::I32AtomicStore;
+        fn i64_atomic_store(store) -> Self::I64AtomicStore;
+        fn i32_atomic_rmw_add(load) -> Self::I32AtomicRmwAdd;
+        fn i64_atomic_rmw_add(load) -> Self::I64AtomicRmwAdd;
+        fn i32_atomic_rmw8_add_u(load) -> Self::I32AtomicRmw8AddU;
+        fn i32_atomic_rmw16_add_u(load) -> Self::I32AtomicRmw16AddU;
+        fn i64_atomic_rmw8_add_u(load) -> Self::I64AtomicRmw8AddU;
+        fn i64_atomic_rmw16_add_u(load) -> Self::I64AtomicRmw16AddU;
+        fn i64_atomic_rmw32_add_u(load) -> Self::I64AtomicRmw32AddU;
+        fn memory_atomic_notify(load) -> Self::MemoryAtomicNotify;
+        fn memory_atomic_wait32(load) -> Self::MemoryAtomicWait32;
+        fn memory_atomic_wait64(load) -> Self::MemoryAtomicWait64;
+
         // Integer Unary
 
         fn i32_clz(unary) -> Self::I32Clz;
@@ -1453,6 +1546,9 @@ impl Instruction {
         fn f64_nearest(unary) -> Self::F64Nearest;
         fn f64_sqrt(unary) -> Self::F64Sqrt;
 
+        fn f32_canonicalize_nan(unary) -> Self::F32CanonicalizeNan;
+        fn f64_canonicalize_nan(unary) -> Self::F64CanonicalizeNan;
+
         // Float Arithmetic
 
         fn f32_add(binary) -> Self::F32Add;
@@ -1547,6 +1643,22 @@ impl Instruction {
         fn f32_ge(binary) -> Self::F32Ge;
         fn f64_ge(binary) -> Self::F64Ge;
 
+        // Fused Comparison and Select
+
+        fn select_i32_eq(binary) -> Self::SelectI32Eq;
+        fn select_i32_ne(binary) -> Self::SelectI32Ne;
+        fn select_i32_lt_s(binary) -> Self::SelectI32LtS;
+        fn select_i32_lt_u(binary) -> Self::SelectI32LtU;
+        fn select_i32_le_s(binary) -> Self::SelectI32LeS;
+        fn select_i32_le_u(binary) -> Self::SelectI32LeU;
+
+        fn select_i64_eq(binary) -> Self::SelectI64Eq;
+        fn select_i64_ne(binary) -> Self::SelectI64Ne;
+        fn select_i64_lt_s(binary) -> Self::SelectI64LtS;
+        fn select_i64_lt_u(binary) -> Self::SelectI64LtU;
+        fn select_i64_le_s(binary) -> Self::SelectI64LeS;
+        fn select_i64_le_u(binary) -> Self::SelectI64LeU;
+
         // Integer Arithmetic
 
         fn i32_add(binary) -> Self::I32Add;
@@ -1,3 +1,4 @@
+mod codec;
 mod construct;
 mod immediate;
 mod provider;
@@ -7,6 +8,7 @@ mod utils;
 mod tests;
 
 pub(crate) use self::{
+    codec::DecodeError,
     immediate::{AnyConst16, AnyConst32, Const16, Const32},
     provider::{Provider, ProviderSliceStack, UntypedProvider},
     utils::{
@@ -27,6 +29,8 @@ pub(crate) use self::{
         LoadAtInstr,
         LoadInstr,
         LoadOffset16Instr,
+        LoadOpInstr,
+        MemoryIdx,
         Register,
         RegisterSpan,
         RegisterSpanIter,
@@ -74,6 +78,23 @@ pub enum Instruction {
     /// This [`Instruction`] only acts as a parameter to another
     /// one and will never be executed itself directly.
     TableIdx(TableIdx),
+    /// A [`MemoryIdx`] instruction parameter.
+    ///
+    /// # Note
+    ///
+    /// This [`Instruction`] only acts as a parameter to another
+    /// one and will never be executed itself directly.
+    ///
+    /// # Encoding
+    ///
+    /// Unlike other parameters this one is optional: it succeeds the other
+    /// instruction words of generic `load`, `store`, atomic and `memory.*`
+    /// instructions only if they operate on a linear memory other than the
+    /// default linear memory at index 0. For `memory.copy` it is encoded twice,
+    /// for the `dst` and `src` linear memory, if either of them is not the default.
+    /// The `load_at`, `store_at` and `offset16` variants always operate on the
+    /// default linear memory.
+    MemoryIdx(MemoryIdx),
     /// A [`DataSegmentIdx`] instruction parameter.
     ///
     /// # Note
@@ -145,6 +166,29 @@ pub enum Instruction {
     /// - [`Instruction::Register2`]
     /// - [`Instruction::Register3`]
     RegisterList([Register; 3]),
+    /// A [`RegisterSpan`] of `len` contiguous [`Register`] parameters.
+    ///
+    /// # Note
+    ///
+    /// This [`Instruction`] only acts as a parameter to another
+    /// one and will never be executed itself directly.
+    ///
+    /// This is a more compact alternative to a sequence of [`Instruction::RegisterList`]
+    /// for long runs of contiguous [`Register`] parameters.
+    ///
+    /// # Encoding
+    ///
+    /// Just like [`Instruction::RegisterList`] this must always be followed by one of
+    ///
+    /// - [`Instruction::Register`]
+    /// - [`Instruction::Register2`]
+    /// - [`Instruction::Register3`]
+    RegisterSpanList {
+        /// The first [`Register`] of the contiguous parameters.
+        values: RegisterSpan,
+        /// The number of contiguous [`Register`] parameters.
+        len: u16,
+    },
     /// Auxiliary [`Instruction`] to encode table access information for indirect call instructions.
     CallIndirectParams(CallIndirectParams<Register>),
     /// Variant of [`Instruction::CallIndirectParams`] for 16-bit constant `index` parameter.
@@ -230,6 +274,26 @@ pub enum Instruction {
     ///
     /// # Note
     ///
+    /// Returns a single 64-bit `i64` constant value.
+    ///
+    /// # Encoding
+    ///
+    /// Followed by two [`Instruction::Const32`] encoding the low and high 32 bits of the value.
+    ReturnI64Imm,
+    /// A Wasm `return` instruction.
+    ///
+    /// # Note
+    ///
+    /// Returns a single 64-bit `f64` constant value.
+    ///
+    /// # Encoding
+    ///
+    /// Followed by two [`Instruction::Const32`] encoding the low and high 32 bits of the value.
+    ReturnF64Imm,
+    /// A Wasm `return` instruction.
+    ///
+    /// # Note
+    ///
     /// Returns values as stored in the [`RegisterSpanIter`].
     ReturnSpan {
         /// Identifier for a [`Provider`] slice.
@@ -686,6 +750,36 @@ pub enum Instruction {
         /// The 32-bit encoded `i64` immediate value to copy.
         value: Const32<f64>,
     },
+    /// Copies a 64-bit `i64` immediate value to `result`.
+    ///
+    /// # Note
+    ///
+    /// - Variant of [`Instruction::Copy`] for `i64` immediate values that are not 32-bit encodable.
+    /// - Avoids allocating a function local constant for one-off constant values.
+    /// - Read [`Instruction::Copy`] for more information about this instruction.
+    ///
+    /// # Encoding
+    ///
+    /// Followed by two [`Instruction::Const32`] encoding the low and high 32 bits of the value.
+    CopyI64Imm {
+        /// The register holding the result of the instruction.
+        result: Register,
+    },
+    /// Copies a 64-bit `f64` immediate value to `result`.
+    ///
+    /// # Note
+    ///
+    /// - Variant of [`Instruction::Copy`] for `f64` immediate values that are not 32-bit encodable.
+    /// - Avoids allocating a function local constant for one-off constant values.
+    /// - Read [`Instruction::Copy`] for more information about this instruction.
+    ///
+    /// # Encoding
+    ///
+    /// Followed by two [`Instruction::Const32`] encoding the low and high 32 bits of the value.
+    CopyF64Imm {
+        /// The register holding the result of the instruction.
+        result: Register,
+    },
     /// Copies `len` contiguous `values` [`RegisterSpan`] into `results` [`RegisterSpan`].
     ///
     /// Copies registers: `registers[results..results+len] <- registers[values..values+len]`
@@ -711,6 +805,23 @@ pub enum Instruction {
         /// The amount of copied registers.
         len: u16,
     },
+    /// Variant of [`Instruction::CopySpan`] for spans of at most 4 registers.
+    ///
+    /// # Note
+    ///
+    /// - Short spans are common when shuffling call parameters and results.
+    /// - Upon execution all `values` are read before any of the `results` are written,
+    ///   therefore `results` and `values` are allowed to overlap.
+    CopySpanSmall {
+        /// The registers holding the result of this instruction.
+        results: RegisterSpan,
+        /// The contiguous registers holding the inputs of this instruction.
+        values: RegisterSpan,
+        /// The amount of copied registers.
+        ///
+        /// This is guaranteed to be at most 4.
+        len: u16,
+    },
     /// Copies some [`Register`] values into `results` [`RegisterSpan`].
     ///
     /// # Encoding
@@ -997,6 +1108,22 @@ pub enum Instruction {
         /// The register holding the `rhs` value.
         rhs: Register,
     },
+    /// Variant of [`Instruction::Select`] where `lhs` and `rhs` are 8-bit encoded `i32` constant values.
+    ///
+    /// # Note
+    ///
+    /// This is an optimization of [`Instruction::SelectImm32`] for small
+    /// `i32` constant values that is encoded as a single instruction word.
+    SelectImm {
+        /// The register holding the `result` value.
+        result: Register,
+        /// The register holding the `condition` value.
+        condition: Register,
+        /// The 8-bit encoded constant `lhs` value.
+        lhs: i8,
+        /// The 8-bit encoded constant `rhs` value.
+        rhs: i8,
+    },
     /// Variant of [`Instruction::Select`] where `lhs` and `rhs` are 32-bit constant values.
     ///
     /// # Encoding
@@ -1039,6 +1166,41 @@ pub enum Instruction {
         /// Either the constant 32-bit `f64` `lhs` or `rhs` value.
         lhs_or_rhs: Const32<f64>,
     },
+    /// A fused [`Instruction::I32Eq`] and [`Instruction::Select`] instruction.
+    ///
+    /// Inspect `lhs == rhs` and if the comparison holds:
+    ///
+    /// - `true` : store `true_val` into `result`
+    /// - `false`: store `false_val` into `result`
+    ///
+    /// # Encoding
+    ///
+    /// This [`Instruction`] and all other fused `cmp+select` instructions
+    /// must be followed by an [`Instruction::Register2`] encoding
+    /// `[true_val, false_val]`.
+    SelectI32Eq(BinInstr),
+    /// A fused [`Instruction::I32Ne`] and [`Instruction::Select`] instruction.
+    SelectI32Ne(BinInstr),
+    /// A fused [`Instruction::I32LtS`] and [`Instruction::Select`] instruction.
+    SelectI32LtS(BinInstr),
+    /// A fused [`Instruction::I32LtU`] and [`Instruction::Select`] instruction.
+    SelectI32LtU(BinInstr),
+    /// A fused [`Instruction::I32LeS`] and [`Instruction::Select`] instruction.
+    SelectI32LeS(BinInstr),
+    /// A fused [`Instruction::I32LeU`] and [`Instruction::Select`] instruction.
+    SelectI32LeU(BinInstr),
+    /// A fused [`Instruction::I64Eq`] and [`Instruction::Select`] instruction.
+    SelectI64Eq(BinInstr),
+    /// A fused [`Instruction::I64Ne`] and [`Instruction::Select`] instruction.
+    SelectI64Ne(BinInstr),
+    /// A fused [`Instruction::I64LtS`] and [`Instruction::Select`] instruction.
+    SelectI64LtS(BinInstr),
+    /// A fused [`Instruction::I64LtU`] and [`Instruction::Select`] instruction.
+    SelectI64LtU(BinInstr),
+    /// A fused [`Instruction::I64LeS`] and [`Instruction::Select`] instruction.
+    SelectI64LeS(BinInstr),
+    /// A fused [`Instruction::I64LeU`] and [`Instruction::Select`] instruction.
+    SelectI64LeU(BinInstr),
 
     /// A Wasm `ref.func` equivalent `wasmi` instruction.
     RefFunc {
@@ -2120,6 +2282,77 @@ pub enum Instruction {
     /// Variant of [`Instruction::I64Load32u`] for small offset values.
     I64Load32uOffset16(LoadOffset16Instr),
 
+    /// Fused Wasm `i32.load` + `i32.add` [`Instruction`]: `r0 = r1 + load(r2+offset)`
+    ///
+    /// # Encoding
+    ///
+    /// This [`Instruction`] must be followed by an [`Instruction::Const32`]
+    /// that represents the `offset` for the load operation.
+    I32AddLoad(LoadOpInstr),
+    /// Fused Wasm `i32.load` + `i32.sub` [`Instruction`]: `r0 = r1 - load(r2+offset)`
+    ///
+    /// # Encoding
+    ///
+    /// This [`Instruction`] must be followed by an [`Instruction::Const32`]
+    /// that represents the `offset` for the load operation.
+    I32SubLoad(LoadOpInstr),
+    /// Fused Wasm `i32.load` + `i32.and` [`Instruction`]: `r0 = r1 & load(r2+offset)`
+    ///
+    /// # Encoding
+    ///
+    /// This [`Instruction`] must be followed by an [`Instruction::Const32`]
+    /// that represents the `offset` for the load operation.
+    I32AndLoad(LoadOpInstr),
+    /// Fused Wasm `i32.load` + `i32.or` [`Instruction`]: `r0 = r1 | load(r2+offset)`
+    ///
+    /// # Encoding
+    ///
+    /// This [`Instruction`] must be followed by an [`Instruction::Const32`]
+    /// that represents the `offset` for the load operation.
+    I32OrLoad(LoadOpInstr),
+    /// Fused Wasm `i32.load` + `i32.xor` [`Instruction`]: `r0 = r1 ^ load(r2+offset)`
+    ///
+    /// # Encoding
+    ///
+    /// This [`Instruction`] must be followed by an [`Instruction::Const32`]
+    /// that represents the `offset` for the load operation.
+    I32XorLoad(LoadOpInstr),
+    /// Fused Wasm `i64.load` + `i64.add` [`Instruction`]: `r0 = r1 + load(r2+offset)`
+    ///
+    /// # Encoding
+    ///
+    /// This [`Instruction`] must be followed by an [`Instruction::Const32`]
+    /// that represents the `offset` for the load operation.
+    I64AddLoad(LoadOpInstr),
+    /// Fused Wasm `i64.load` + `i64.sub` [`Instruction`]: `r0 = r1 - load(r2+offset)`
+    ///
+    /// # Encoding
+    ///
+    /// This [`Instruction`] must be followed by an [`Instruction::Const32`]
+    /// that represents the `offset` for the load operation.
+    I64SubLoad(LoadOpInstr),
+    /// Fused Wasm `i64.load` + `i64.and` [`Instruction`]: `r0 = r1 & load(r2+offset)`
+    ///
+    /// # Encoding
+    ///
+    /// This [`Instruction`] must be followed by an [`Instruction::Const32`]
+    /// that represents the `offset` for the load operation.
+    I64AndLoad(LoadOpInstr),
+    /// Fused Wasm `i64.load` + `i64.or` [`Instruction`]: `r0 = r1 | load(r2+offset)`
+    ///
+    /// # Encoding
+    ///
+    /// This [`Instruction`] must be followed by an [`Instruction::Const32`]
+    /// that represents the `offset` for the load operation.
+    I64OrLoad(LoadOpInstr),
+    /// Fused Wasm `i64.load` + `i64.xor` [`Instruction`]: `r0 = r1 ^ load(r2+offset)`
+    ///
+    /// # Encoding
+    ///
+    /// This [`Instruction`] must be followed by an [`Instruction::Const32`]
+    /// that represents the `offset` for the load operation.
+    I64XorLoad(LoadOpInstr),
+
     /// Wasm `i32.store` equivalent `wasmi` instruction.
     ///
     /// # Encoding
@@ -2247,6 +2480,113 @@ pub enum Instruction {
     /// Variant of [`Instruction::F32Store`] for constant `address`.
     F64StoreAt(StoreAtInstr<Register>),
 
+    /// Wasm `i32.atomic.load` equivalent `wasmi` instruction.
+    ///
+    /// # Encoding
+    ///
+    /// This [`Instruction`] must be followed by an [`Instruction::Const32`]
+    /// that represents the `offset` for the atomic operation.
+    I32AtomicLoad(LoadInstr),
+    /// Wasm `i64.atomic.load` equivalent `wasmi` instruction.
+    ///
+    /// # Encoding
+    ///
+    /// This [`Instruction`] must be followed by an [`Instruction::Const32`]
+    /// that represents the `offset` for the atomic operation.
+    I64AtomicLoad(LoadInstr),
+
+    /// Wasm `i32.atomic.store` equivalent `wasmi` instruction.
+    ///
+    /// # Encoding
+    ///
+    /// Must be followed by an [`Instruction::Register`] to encode `value`.
+    I32AtomicStore(StoreInstr),
+    /// Wasm `i64.atomic.store` equivalent `wasmi` instruction.
+    ///
+    /// # Encoding
+    ///
+    /// Must be followed by an [`Instruction::Register`] to encode `value`.
+    I64AtomicStore(StoreInstr),
+
+    /// Wasm `i32.atomic.rmw.add` equivalent `wasmi` instruction.
+    ///
+    /// # Encoding
+    ///
+    /// This [`Instruction`] must be followed by
+    ///
+    /// 1. [`Instruction::Const32`]: the `offset` for the atomic operation
+    /// 2. [`Instruction::Register`]: the `value` that is added
+    I32AtomicRmwAdd(LoadInstr),
+    /// Wasm `i64.atomic.rmw.add` equivalent `wasmi` instruction.
+    ///
+    /// # Encoding
+    ///
+    /// Encoded the same way as [`Instruction::I32AtomicRmwAdd`].
+    I64AtomicRmwAdd(LoadInstr),
+    /// Wasm `i32.atomic.rmw8.add_u` equivalent `wasmi` instruction.
+    ///
+    /// # Encoding
+    ///
+    /// Encoded the same way as [`Instruction::I32AtomicRmwAdd`].
+    I32AtomicRmw8AddU(LoadInstr),
+    /// Wasm `i32.atomic.rmw16.add_u` equivalent `wasmi` instruction.
+    ///
+    /// # Encoding
+    ///
+    /// Encoded the same way as [`Instruction::I32AtomicRmwAdd`].
+    I32AtomicRmw16AddU(LoadInstr),
+    /// Wasm `i64.atomic.rmw8.add_u` equivalent `wasmi` instruction.
+    ///
+    /// # Encoding
+    ///
+    /// Encoded the same way as [`Instruction::I32AtomicRmwAdd`].
+    I64AtomicRmw8AddU(LoadInstr),
+    /// Wasm `i64.atomic.rmw16.add_u` equivalent `wasmi` instruction.
+    ///
+    /// # Encoding
+    ///
+    /// Encoded the same way as [`Instruction::I32AtomicRmwAdd`].
+    I64AtomicRmw16AddU(LoadInstr),
+    /// Wasm `i64.atomic.rmw32.add_u` equivalent `wasmi` instruction.
+    ///
+    /// # Encoding
+    ///
+    /// Encoded the same way as [`Instruction::I32AtomicRmwAdd`].
+    I64AtomicRmw32AddU(LoadInstr),
+
+    /// Wasm `memory.atomic.notify` equivalent `wasmi` instruction.
+    ///
+    /// # Note
+    ///
+    /// Since `wasmi` linear memories are never shared there are no waiters
+    /// that could be notified. Therefore the `count` operand is not encoded
+    /// and the result is always 0 if the access is aligned and in bounds.
+    ///
+    /// # Encoding
+    ///
+    /// This [`Instruction`] must be followed by an [`Instruction::Const32`]
+    /// that represents the `offset` for the atomic operation.
+    MemoryAtomicNotify(LoadInstr),
+    /// Wasm `memory.atomic.wait32` equivalent `wasmi` instruction.
+    ///
+    /// # Note
+    ///
+    /// Since `wasmi` linear memories are never shared this always traps
+    /// if the access is aligned and in bounds. Therefore the `expected`
+    /// and `timeout` operands are not encoded.
+    ///
+    /// # Encoding
+    ///
+    /// This [`Instruction`] must be followed by an [`Instruction::Const32`]
+    /// that represents the `offset` for the atomic operation.
+    MemoryAtomicWait32(LoadInstr),
+    /// Wasm `memory.atomic.wait64` equivalent `wasmi` instruction.
+    ///
+    /// # Note
+    ///
+    /// Works the same as [`Instruction::MemoryAtomicWait32`] for 64-bit values.
+    MemoryAtomicWait64(LoadInstr),
+
     /// `i32` equality comparison instruction: `r0 = r1 == r2`
     I32Eq(BinInstr),
     /// `i32` equality comparison instruction with immediate: `r0 = r1 == c0`
@@ -2958,6 +3298,18 @@ pub enum Instruction {
     F32Sqrt(UnaryInstr),
     /// Wasm `f64.sqrt` instruction.
     F64Sqrt(UnaryInstr),
+    /// Canonicalizes an `f32` NaN value: `r0 = canonicalize_nan(r1)`
+    ///
+    /// # Note
+    ///
+    /// This is a `wasmi` utility instruction used if NaN canonicalization is enabled.
+    F32CanonicalizeNan(UnaryInstr),
+    /// Canonicalizes an `f64` NaN value: `r0 = canonicalize_nan(r1)`
+    ///
+    /// # Note
+    ///
+    /// This is a `wasmi` utility instruction used if NaN canonicalization is enabled.
+    F64CanonicalizeNan(UnaryInstr),
 
     /// Wasm `f32.add` instruction: `r0 = r1 + r2`
     F32Add(BinInstr),
@@ -3126,6 +3478,9 @@ pub enum Instruction {
 }
 
 impl Instruction {
+    /// The maximum number of registers copied by an [`Instruction::CopySpanSmall`].
+    pub const COPY_SPAN_SMALL_MAX_LEN: u16 = 4;
+
     /// Convenience method to create a new [`Instruction::ConsumeFuel`].
     pub fn consume_fuel(amount: u64) -> Result<Self, Error> {
         let block_fuel = BlockFuel::try_from(amount)?;
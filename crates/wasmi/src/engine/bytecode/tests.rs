@@ -1,5 +1,6 @@
 use super::*;
-use std::mem::size_of;
+use core::num::{NonZeroI16, NonZeroU16};
+use std::{mem::size_of, vec::Vec};
 
 #[test]
 fn bytecode_size() {
@@ -12,13 +13,13 @@ fn bytecode_size() {
 }
 
 #[test]
-fn has_overlapping_copy_spans_works() {
+fn overlaps_copy_works() {
     fn span(register: impl Into<Register>) -> RegisterSpan {
         RegisterSpan::new(register.into())
     }
 
     fn has_overlapping_copy_spans(results: RegisterSpan, values: RegisterSpan, len: u16) -> bool {
-        RegisterSpanIter::has_overlapping_copies(results.iter_u16(len), values.iter_u16(len))
+        RegisterSpanIter::overlaps_copy(results.iter_u16(len), values.iter_u16(len))
     }
 
     // len == 0
@@ -49,3 +50,196 @@ fn has_overlapping_copy_spans_works() {
     assert!(has_overlapping_copy_spans(span(4), span(1), 4));
     assert!(has_overlapping_copy_spans(span(4), span(0), 5));
 }
+
+#[test]
+fn overlaps_with_providers_works() {
+    fn span(register: i16, len: u16) -> RegisterSpanIter {
+        RegisterSpan::new(Register::from_i16(register)).iter_u16(len)
+    }
+
+    fn reg(register: i16) -> Provider<i32> {
+        Provider::Register(Register::from_i16(register))
+    }
+
+    fn imm(value: i32) -> Provider<i32> {
+        Provider::Const(value)
+    }
+
+    assert!(!span(0, 0).overlaps_with_providers::<i32>(&[]));
+    assert!(!span(0, 2).overlaps_with_providers(&[reg(0), reg(1)]));
+    assert!(!span(0, 2).overlaps_with_providers(&[imm(10), imm(20)]));
+    assert!(span(0, 2).overlaps_with_providers(&[imm(10), reg(0)]));
+    assert!(span(0, 2).overlaps_with_providers(&[reg(0), reg(0)]));
+    assert!(span(3, 3).overlaps_with_providers(&[reg(2), reg(3), reg(2)]));
+    assert!(span(3, 4).overlaps_with_providers(&[reg(-1), reg(10), reg(2), reg(4)]));
+    // Examples from the documentation.
+    assert!(!span(0, 3).overlaps_with_providers(&[reg(1), reg(1), reg(4)]));
+    assert!(span(0, 2).overlaps_with_providers(&[reg(1), reg(0)]));
+    assert!(span(3, 3).overlaps_with_providers(&[reg(1), reg(2), reg(3)]));
+}
+
+#[test]
+fn encode_decode_roundtrip() {
+    fn reg(index: i16) -> Register {
+        Register::from_i16(index)
+    }
+
+    fn span(index: i16) -> RegisterSpan {
+        RegisterSpan::new(reg(index))
+    }
+
+    let instrs = [
+        Instruction::Trap(TrapCode::UnreachableCodeReached),
+        Instruction::Trap(TrapCode::ExpectedSharedMemory),
+        Instruction::ConsumeFuel(BlockFuel::try_from(u64::from(u32::MAX)).unwrap()),
+        Instruction::Return,
+        Instruction::return_reg(reg(-1)),
+        Instruction::ReturnI64Imm32 {
+            value: Const32::from(i32::MIN),
+        },
+        Instruction::ReturnSpan {
+            values: span(3).iter_u16(5),
+        },
+        Instruction::i32_add(reg(0), reg(1), reg(i16::MIN)),
+        Instruction::I32AddImm16(BinInstrImm16::new(reg(2), reg(1), Const16::from(-42_i16))),
+        Instruction::I32DivUImm16(BinInstrImm16::new(
+            reg(2),
+            reg(1),
+            Const16::from(NonZeroU16::new(u16::MAX).unwrap()),
+        )),
+        Instruction::I64DivSImm16(BinInstrImm16::new(
+            reg(2),
+            reg(1),
+            Const16::from(NonZeroI16::new(-1).unwrap()),
+        )),
+        Instruction::F32CopysignImm(BinInstrImm::new(reg(1), reg(0), Sign::Neg)),
+        Instruction::I64Load8sAt(LoadAtInstr::new(reg(1), Const32::from(u32::MAX))),
+        Instruction::I32StoreAt(StoreAtInstr::new(Const32::from(100_u32), reg(5))),
+        Instruction::I64Store16Offset16Imm(StoreOffset16Instr::new(
+            reg(1),
+            Const16::from(8_u16),
+            -1_i16,
+        )),
+        Instruction::I32Store8Offset16Imm(StoreOffset16Instr::new(
+            reg(1),
+            Const16::from(u16::MAX),
+            i8::MIN,
+        )),
+        Instruction::BranchI64Eq(BranchBinOpInstr::new(
+            reg(0),
+            reg(1),
+            BranchOffset16::from(-3_i16),
+        )),
+        Instruction::BranchI32EqImm(BranchBinOpInstrImm16::new(
+            reg(0),
+            Const16::from(7_i16),
+            BranchOffset16::from(i16::MAX),
+        )),
+        Instruction::branch(BranchOffset::from(i32::MIN)),
+        Instruction::BranchTable {
+            index: reg(0),
+            len_targets: Const32::from(3_u32),
+        },
+        Instruction::Copy2 {
+            results: span(2),
+            values: [reg(0), reg(-2)],
+        },
+        Instruction::CopyImm32 {
+            result: reg(1),
+            value: AnyConst32::from(-1.5_f32),
+        },
+        Instruction::CopySpan {
+            results: span(4),
+            values: span(0),
+            len: 4,
+        },
+        Instruction::CallInternal {
+            results: span(3),
+            func: CompiledFunc::from_u32(7),
+        },
+        Instruction::CallIndirect {
+            results: span(3),
+            func_type: SignatureIdx::from(2),
+        },
+        Instruction::CallIndirectParamsImm16(CallIndirectParams {
+            table: TableIdx::from(1),
+            index: Const16::from(10_u16),
+        }),
+        Instruction::RefFunc {
+            result: reg(0),
+            func: FuncIdx::from(u32::MAX),
+        },
+        Instruction::GlobalGet {
+            result: reg(0),
+            global: GlobalIdx::from(1),
+        },
+        Instruction::MemoryFillImm {
+            dst: reg(0),
+            value: 0xFF,
+            len: reg(1),
+        },
+        Instruction::MemoryGrowBy {
+            result: reg(0),
+            delta: Const16::from(1_u16),
+        },
+        Instruction::data_idx(3),
+        Instruction::elem_idx(4),
+        Instruction::memory_idx(1),
+        Instruction::const32(u32::MAX),
+        Instruction::F64Const32(Const32::from(0.5_f32)),
+        Instruction::Register3([reg(0), reg(1), reg(2)]),
+        Instruction::RegisterList([reg(3), reg(4), reg(5)]),
+    ];
+    let mut bytes = Vec::new();
+    for instr in instrs {
+        bytes.extend_from_slice(&instr.encode());
+    }
+    assert_eq!(bytes.len(), instrs.len() * Instruction::ENCODED_SIZE);
+    let mut decoded = Vec::new();
+    let mut remaining = &bytes[..];
+    while !remaining.is_empty() {
+        let (instr, consumed) = Instruction::decode(remaining).unwrap();
+        decoded.push(instr);
+        remaining = &remaining[consumed..];
+    }
+    assert_eq!(decoded, instrs);
+}
+
+#[test]
+fn decode_errors() {
+    use crate::errors::ErrorKind;
+
+    fn decode_error(bytes: &[u8]) -> DecodeError {
+        match Instruction::decode(bytes).unwrap_err().kind() {
+            ErrorKind::Decode(error) => *error,
+            error => panic!("expected decode error but found: {error:?}"),
+        }
+    }
+
+    let trap = Instruction::Trap(TrapCode::UnreachableCodeReached).encode();
+    assert_eq!(decode_error(&[]), DecodeError::UnexpectedEnd);
+    assert_eq!(decode_error(&trap[..7]), DecodeError::UnexpectedEnd);
+    assert_eq!(
+        decode_error(&[0xFF, 0xFF, 0, 0, 0, 0, 0, 0]),
+        DecodeError::UnknownOpcode(u16::MAX)
+    );
+    // Trap codes that do not exist.
+    let mut invalid_trap = trap;
+    invalid_trap[2] = 0xFF;
+    assert_eq!(decode_error(&invalid_trap), DecodeError::InvalidOperand);
+    // Zero divisors are not allowed for `I32DivUImm16`.
+    let mut div_by_zero = Instruction::I32DivUImm16(BinInstrImm16::new(
+        Register::from_i16(0),
+        Register::from_i16(1),
+        Const16::from(NonZeroU16::new(1).unwrap()),
+    ))
+    .encode();
+    div_by_zero[6..8].copy_from_slice(&[0, 0]);
+    assert_eq!(decode_error(&div_by_zero), DecodeError::InvalidOperand);
+    // Trailing bytes are not consumed.
+    let mut bytes = trap.to_vec();
+    bytes.extend_from_slice(&[0xAB; 3]);
+    let (instr, consumed) = Instruction::decode(&bytes).unwrap();
+    assert_eq!(instr, Instruction::Trap(TrapCode::UnreachableCodeReached));
+    assert_eq!(consumed, Instruction::ENCODED_SIZE);
+}
@@ -1,4 +1,4 @@
-use super::{Const16, Const32};
+use super::{Const16, Const32, Provider};
 use crate::{
     engine::{Instr, TranslationError},
     Error,
@@ -185,13 +185,20 @@ impl RegisterSpanIter {
 
     /// Returns `true` if `copy_span results <- values` has overlapping copies.
     ///
+    /// A copy overlaps if it reads from a [`Register`] that has already been
+    /// written to by a previous copy of the same `copy_span`.
+    ///
     /// # Examples
     ///
     /// - `[ ]`: empty never overlaps
     /// - `[ 1 <- 0 ]`: single element never overlaps
     /// - `[ 0 <- 1, 1 <- 2, 2 <- 3 ]``: no overlap
     /// - `[ 1 <- 0, 2 <- 1 ]`: overlaps!
-    pub fn has_overlapping_copies(results: Self, values: Self) -> bool {
+    ///
+    /// # Panics
+    ///
+    /// If `results` and `values` do not have the same length.
+    pub fn overlaps_copy(results: Self, values: Self) -> bool {
         assert_eq!(
             results.len_as_u16(),
             values.len_as_u16(),
@@ -214,6 +221,39 @@ impl RegisterSpanIter {
             .expect("span is non empty and thus must return");
         last_value >= first_result
     }
+
+    /// Returns `true` if `copy results <- values` has overlapping copies.
+    ///
+    /// A copy overlaps if it reads from a [`Register`] that has already been
+    /// written to by a previous copy. Copies of constant values never overlap.
+    ///
+    /// # Examples
+    ///
+    /// - The sequence `[ 0 <- 1, 1 <- 1, 2 <- 4 ]` has no overlapping copies.
+    /// - The sequence `[ 0 <- 1, 1 <- 0 ]` has overlapping copies since register `0`
+    ///   is written to in the first copy but read from in the next.
+    /// - The sequence `[ 3 <- 1, 4 <- 2, 5 <- 3 ]` has overlapping copies since register `3`
+    ///   is written to in the first copy but read from in the third.
+    pub fn overlaps_with_providers<T>(self, values: &[Provider<T>]) -> bool {
+        debug_assert_eq!(self.len(), values.len());
+        if self.is_empty() {
+            // Note: An empty set of copies can never have overlapping copies.
+            return false;
+        }
+        let result0 = self.span().head();
+        for (result, value) in self.zip(values) {
+            // Note: We only have to check the register case since constant value
+            //       copies can never overlap.
+            if let Provider::Register(value) = *value {
+                // If the register `value` index is within range of `result0..result`
+                // then its value has been overwritten by previous copies.
+                if result0 <= value && value < result {
+                    return true;
+                }
+            }
+        }
+        false
+    }
 }
 
 impl Iterator for RegisterSpanIter {
@@ -384,6 +424,37 @@ impl LoadOffset16Instr {
     }
 }
 
+/// A fused `load` and binary operation instruction: `result = operand <op> load(ptr+offset)`
+///
+/// # Encoding
+///
+/// This instruction stores its offset parameter in a
+/// separate [`Instruction::Const32`] instruction that must
+/// follow this [`Instruction`] immediately in the instruction
+/// sequence.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LoadOpInstr {
+    /// The register storing the result of the binary operation.
+    pub result: Register,
+    /// The register storing the left-hand side operand of the binary operation.
+    pub operand: Register,
+    /// The register storing the pointer of the `load` instruction.
+    ///
+    /// The loaded value is the right-hand side operand of the binary operation.
+    pub ptr: Register,
+}
+
+impl LoadOpInstr {
+    /// Create a new [`LoadOpInstr`].
+    pub fn new(result: Register, operand: Register, ptr: Register) -> Self {
+        Self {
+            result,
+            operand,
+            ptr,
+        }
+    }
+}
+
 /// A general `store` instruction.
 ///
 /// # Encoding
@@ -632,6 +703,24 @@ impl TableIdx {
     }
 }
 
+/// A linear memory index.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct MemoryIdx([u8; 4]);
+
+impl From<u32> for MemoryIdx {
+    fn from(index: u32) -> Self {
+        Self(index.to_ne_bytes())
+    }
+}
+
+impl MemoryIdx {
+    /// Returns the index value as `u32`.
+    pub fn to_u32(self) -> u32 {
+        u32::from_ne_bytes(self.0)
+    }
+}
+
 /// An index of a unique function signature.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(transparent)]
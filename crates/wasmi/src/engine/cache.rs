@@ -1,5 +1,12 @@
 use crate::{
-    engine::bytecode::{DataSegmentIdx, ElementSegmentIdx, FuncIdx, GlobalIdx, TableIdx},
+    engine::bytecode::{
+        DataSegmentIdx,
+        ElementSegmentIdx,
+        FuncIdx,
+        GlobalIdx,
+        MemoryIdx,
+        TableIdx,
+    },
     instance::InstanceEntity,
     memory::DataSegment,
     module::DEFAULT_MEMORY_INDEX,
@@ -110,18 +117,22 @@ impl InstanceCache {
 
     /// Loads the [`DataSegment`] at `index` of the currently used [`Instance`].
     ///
+    /// Returns its bytes together with the bytes of the linear memory at `memory`.
+    /// Uses the default linear memory if `memory` is `None`.
+    ///
     /// # Panics
     ///
     /// If there is no [`DataSegment`] for the [`Instance`] at the `index`.
     #[inline]
-    pub fn get_default_memory_and_data_segment<'a>(
+    pub fn get_memory_and_data_segment<'a>(
         &mut self,
         ctx: &'a mut StoreInner,
+        memory: Option<MemoryIdx>,
         segment: DataSegmentIdx,
     ) -> (&'a mut [u8], &'a [u8]) {
         let seg = self.get_data_segment(ctx, segment.to_u32());
-        let mem = self.default_memory(ctx);
-        let (memory, segment) = ctx.resolve_memory_mut_and_data_segment(mem, &seg);
+        let mem = self.get_memory(ctx, memory);
+        let (memory, segment) = ctx.resolve_memory_mut_and_data_segment(&mem, &seg);
         (memory.data_mut(), segment.bytes())
     }
 
@@ -178,6 +189,66 @@ impl InstanceCache {
         }
     }
 
+    /// Returns the linear [`Memory`] at `index` of the currently used [`Instance`].
+    ///
+    /// Returns the default linear [`Memory`] if `index` is `None`.
+    ///
+    /// # Panics
+    ///
+    /// If the currently used [`Instance`] does not have the linear memory.
+    #[inline]
+    pub fn get_memory(&mut self, ctx: &StoreInner, index: Option<MemoryIdx>) -> Memory {
+        match index {
+            None => *self.default_memory(ctx),
+            Some(index) => self.load_memory_at(ctx, index),
+        }
+    }
+
+    /// Loads the linear [`Memory`] at `index` of the currently used [`Instance`].
+    ///
+    /// # Note
+    ///
+    /// Only linear memories other than the default linear memory are
+    /// loaded this way which is why they are not cached.
+    ///
+    /// # Panics
+    ///
+    /// If the currently used [`Instance`] does not have the linear memory.
+    #[cold]
+    #[inline]
+    fn load_memory_at(&self, ctx: &StoreInner, index: MemoryIdx) -> Memory {
+        ctx.resolve_instance(self.instance())
+            .get_memory(index.to_u32())
+            .unwrap_or_else(|| {
+                unreachable!(
+                    "missing linear memory at index {index:?} for instance: {:?}",
+                    self.instance
+                )
+            })
+    }
+
+    /// Returns the bytes of the linear memory at `index` of the currently used [`Instance`].
+    ///
+    /// Returns the cached bytes of the default linear memory if `index` is `None`.
+    ///
+    /// # Panics
+    ///
+    /// If the currently used [`Instance`] does not have the linear memory.
+    #[inline(always)]
+    pub fn memory_bytes<'ctx>(
+        &mut self,
+        ctx: &'ctx mut StoreInner,
+        index: Option<MemoryIdx>,
+    ) -> &'ctx mut [u8] {
+        match index {
+            None => self.default_memory_bytes(ctx),
+            Some(index) => {
+                let memory = self.load_memory_at(ctx, index);
+                ctx.resolve_memory_mut(&memory).data_mut()
+            }
+        }
+    }
+
     /// Returns a cached default linear memory.
     ///
     /// # Note
@@ -5,7 +5,7 @@
 //! This is the data structure specialized to handle compiled
 //! register machine based bytecode functions.
 
-use super::{FuncTranslationDriver, FuncTranslator, ValidatingFuncTranslator};
+use super::Instr;
 use crate::{
     core::UntypedValue,
     engine::bytecode::Instruction,
@@ -99,6 +99,8 @@ impl InternalFuncEntity {
 pub struct UncompiledFuncEntity {
     /// The index of the function within the `module`.
     func_idx: FuncIdx,
+    /// The byte offset of the Wasm function body within the Wasm binary.
+    offset: usize,
     /// The Wasm binary bytes.
     bytes: SmallByteSlice,
     /// The Wasm module of the Wasm function.
@@ -116,6 +118,7 @@ impl fmt::Debug for UncompiledFuncEntity {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("UncompiledFuncEntity")
             .field("func_idx", &self.func_idx)
+            .field("offset", &self.offset)
             .field("bytes", &self.bytes)
             .field("module", &self.module)
             .field("validate", &self.func_to_validate.is_some())
@@ -186,9 +189,55 @@ impl<'a> From<&'a [u8]> for SmallByteSlice {
     }
 }
 
+/// Statistics about a compiled function for capacity planning and telemetry.
+///
+/// Queried via [`Module::func_metadata`].
+///
+/// [`Module::func_metadata`]: crate::Module::func_metadata
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FuncMetadata {
+    /// The number of registers the function requires upon execution.
+    max_registers: u16,
+    /// The number of function local constant values.
+    len_consts: usize,
+    /// The number of encoded instruction words.
+    len_instrs: usize,
+}
+
+impl FuncMetadata {
+    /// Returns the number of registers the function requires upon execution.
+    ///
+    /// This is the high-water mark of the register window of the function on the value stack
+    /// and includes its parameters, locals, temporaries and function local constants.
+    pub fn max_registers(&self) -> u16 {
+        self.max_registers
+    }
+
+    /// Returns the number of function local constant values.
+    ///
+    /// # Note
+    ///
+    /// Function local constants are constant values that cannot be encoded
+    /// as immediates of the instructions using them, for example most 64-bit values.
+    pub fn len_consts(&self) -> usize {
+        self.len_consts
+    }
+
+    /// Returns the number of encoded instruction words.
+    ///
+    /// # Note
+    ///
+    /// This includes instruction words that only carry parameters of other instructions.
+    pub fn len_instrs(&self) -> usize {
+        self.len_instrs
+    }
+}
+
 /// Meta information about a [`CompiledFunc`].
 #[derive(Debug)]
 pub struct CompiledFuncEntity {
+    /// The index of the function within its `module`.
+    func_idx: FuncIdx,
     /// The sequence of [`Instruction`] of the [`CompiledFuncEntity`].
     instrs: Box<[Instruction]>,
     /// The number of registers used by the [`CompiledFunc`] in total.
@@ -200,6 +249,48 @@ pub struct CompiledFuncEntity {
     len_registers: u16,
     /// The constant values local to the [`CompiledFunc`].
     consts: Box<[UntypedValue]>,
+    /// The Wasm byte offsets of the [`Instruction`] of the [`CompiledFunc`].
+    ///
+    /// # Note
+    ///
+    /// Each entry associates the first [`Instr`] of a run of [`Instruction`]
+    /// to the byte offset of the Wasm operator they were translated from.
+    /// This is empty unless [`Config::trap_offsets`] is enabled.
+    ///
+    /// [`Config::trap_offsets`]: crate::Config::trap_offsets
+    wasm_offsets: Box<[(Instr, usize)]>,
+}
+
+/// An [`Instruction`] of a [`CompiledFunc`] with its resolved branch target if any.
+///
+/// Yielded by [`CompiledFuncEntity::instructions`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ResolvedInstr {
+    /// The position of the [`Instruction`] within its [`CompiledFunc`].
+    instr: Instr,
+    /// The [`Instruction`] itself.
+    instruction: Instruction,
+    /// The branch target of the [`Instruction`] if it is a branch.
+    target: Option<Instr>,
+}
+
+impl ResolvedInstr {
+    /// Returns the position of the [`Instruction`] within its [`CompiledFunc`].
+    pub fn instr(&self) -> Instr {
+        self.instr
+    }
+
+    /// Returns the [`Instruction`].
+    pub fn instruction(&self) -> &Instruction {
+        &self.instruction
+    }
+
+    /// Returns the resolved branch target if the [`Instruction`] is a branch.
+    ///
+    /// Returns `None` otherwise.
+    pub fn target(&self) -> Option<Instr> {
+        self.target
+    }
 }
 
 impl CompiledFuncEntity {
@@ -209,7 +300,7 @@ impl CompiledFuncEntity {
     ///
     /// - If `instrs` is empty.
     /// - If `instrs` contains more than `u32::MAX` instructions.
-    pub fn new<I, C>(len_registers: u16, instrs: I, consts: C) -> Self
+    pub fn new<I, C>(func_idx: FuncIdx, len_registers: u16, instrs: I, consts: C) -> Self
     where
         I: IntoIterator<Item = Instruction>,
         C: IntoIterator<Item = UntypedValue>,
@@ -221,18 +312,34 @@ impl CompiledFuncEntity {
             "compiled functions must have at least one instruction"
         );
         Self {
+            func_idx,
             instrs,
             len_registers,
             consts,
+            wasm_offsets: [].into(),
         }
     }
 
+    /// Sets the Wasm byte offsets of the [`Instruction`] of the [`CompiledFuncEntity`].
+    ///
+    /// # Note
+    ///
+    /// Each item of `wasm_offsets` associates the first [`Instr`] of a run of
+    /// [`Instruction`] to the byte offset of the Wasm operator they were translated from.
+    /// Items must be sorted by their [`Instr`] in strictly ascending order.
+    pub fn with_wasm_offsets(mut self, wasm_offsets: Box<[(Instr, usize)]>) -> Self {
+        self.wasm_offsets = wasm_offsets;
+        self
+    }
+
     /// Create a new uninitialized [`CompiledFuncEntity`].
     fn uninit() -> Self {
         Self {
+            func_idx: FuncIdx::from(0),
             instrs: [].into(),
             len_registers: 0,
             consts: [].into(),
+            wasm_offsets: [].into(),
         }
     }
 
@@ -241,11 +348,68 @@ impl CompiledFuncEntity {
         !self.instrs.is_empty()
     }
 
+    /// Returns the index of the [`CompiledFunc`] within its `module`.
+    pub fn func_idx(&self) -> FuncIdx {
+        self.func_idx
+    }
+
     /// Returns the sequence of [`Instruction`] of the [`CompiledFunc`].
     pub fn instrs(&self) -> &[Instruction] {
         &self.instrs[..]
     }
 
+    /// Returns the byte offset of the Wasm operator from which `instr` was translated if any.
+    ///
+    /// # Note
+    ///
+    /// Returns `None` unless [`Config::trap_offsets`] was enabled during translation.
+    ///
+    /// [`Config::trap_offsets`]: crate::Config::trap_offsets
+    pub fn wasm_offset(&self, instr: Instr) -> Option<usize> {
+        let index = self
+            .wasm_offsets
+            .partition_point(|&(start, _)| start <= instr);
+        let (_, offset) = self.wasm_offsets.get(index.checked_sub(1)?)?;
+        Some(*offset)
+    }
+
+    /// Returns the byte offset of the Wasm operator from which the [`Instruction`] at `ip` was translated if any.
+    ///
+    /// Returns `None` if `ip` does not point into the [`Instruction`] of the [`CompiledFunc`].
+    pub fn wasm_offset_at(&self, ip: InstructionPtr) -> Option<usize> {
+        let index = ip.offset_from(self.instrs.as_ptr());
+        if index >= self.instrs.len() {
+            return None;
+        }
+        self.wasm_offset(Instr::from_usize(index))
+    }
+
+    /// Returns an iterator over the [`ResolvedInstr`] of the [`CompiledFunc`].
+    ///
+    /// # Note
+    ///
+    /// This is useful for analyses such as constructing the control flow graph
+    /// of the [`CompiledFunc`] since branch targets are resolved to [`Instr`].
+    pub fn instructions(&self) -> impl Iterator<Item = ResolvedInstr> + '_ {
+        self.instrs.iter().enumerate().map(|(index, instruction)| {
+            let instr = Instr::from_usize(index);
+            let target = instruction.branch_offset().map(|offset| {
+                let target = instr
+                    .into_u32()
+                    .checked_add_signed(offset.to_i32())
+                    .unwrap_or_else(|| {
+                        panic!("out of bounds branch target for {instruction:?} at {instr:?}")
+                    });
+                Instr::from_u32(target)
+            });
+            ResolvedInstr {
+                instr,
+                instruction: *instruction,
+                target,
+            }
+        })
+    }
+
     /// Returns the number of registers used by the [`CompiledFunc`].
     pub fn len_registers(&self) -> u16 {
         self.len_registers
@@ -272,6 +436,15 @@ impl CompiledFuncEntity {
     pub fn consts(&self) -> &[UntypedValue] {
         &self.consts
     }
+
+    /// Returns the [`FuncMetadata`] of the [`CompiledFunc`].
+    pub fn metadata(&self) -> FuncMetadata {
+        FuncMetadata {
+            max_registers: self.len_registers,
+            len_consts: self.consts.len(),
+            len_instrs: self.instrs.len(),
+        }
+    }
 }
 
 /// Datastructure to efficiently store information about compiled functions.
@@ -317,6 +490,7 @@ impl CodeMap {
         &mut self,
         func_idx: FuncIdx,
         func: CompiledFunc,
+        offset: usize,
         bytes: &[u8],
         module: &ModuleHeader,
         func_to_validate: Option<FuncToValidate<ValidatorResources>>,
@@ -329,6 +503,7 @@ impl CodeMap {
         let module = module.clone();
         *func = InternalFuncEntity::Uncompiled(UncompiledFuncEntity {
             func_idx,
+            offset,
             bytes,
             module,
             func_to_validate,
@@ -381,6 +556,7 @@ impl CodeMap {
                 unreachable!("function has unexpectedly already been compiled: {func:?}");
             };
             let func_idx = uncompiled.func_idx;
+            let offset = uncompiled.offset;
             let bytes = mem::take(&mut uncompiled.bytes);
             let module = uncompiled.module.clone();
             let Some(engine) = module.engine().upgrade() else {
@@ -389,30 +565,17 @@ impl CodeMap {
                     module.engine()
                 )
             };
-            match uncompiled.func_to_validate.take() {
-                Some(func_to_validate) => {
-                    let allocs = engine.get_allocs();
-                    let translator = FuncTranslator::new(func_idx, module, allocs.0)?;
-                    let validator = func_to_validate.into_validator(allocs.1);
-                    let translator = ValidatingFuncTranslator::new(validator, translator)?;
-                    let allocs = FuncTranslationDriver::new(0, &bytes[..], translator)?.translate(
-                        |compiled_func| {
-                            *func = InternalFuncEntity::Compiled(compiled_func);
-                        },
-                    )?;
-                    engine.recycle_allocs(allocs.translation, allocs.validation);
-                }
-                None => {
-                    let allocs = engine.get_translation_allocs();
-                    let translator = FuncTranslator::new(func_idx, module, allocs)?;
-                    let allocs = FuncTranslationDriver::new(0, &bytes[..], translator)?.translate(
-                        |compiled_func| {
-                            *func = InternalFuncEntity::Compiled(compiled_func);
-                        },
-                    )?;
-                    engine.recycle_translation_allocs(allocs);
-                }
-            }
+            let func_to_validate = uncompiled.func_to_validate.take();
+            engine.translate_func_eagerly(
+                func_idx,
+                offset,
+                &bytes[..],
+                module,
+                func_to_validate,
+                |compiled_func| {
+                    *func = InternalFuncEntity::Compiled(compiled_func);
+                },
+            )?;
             // TODO: In case translation of `func` fails it is going to be recompiled over and over again
             //       for every threads which might be very costly. A status flag that indicates compilation
             //       failure might be required to fix this.
@@ -473,6 +636,16 @@ impl InstructionPtr {
         self.ptr = unsafe { self.ptr.add(delta) };
     }
 
+    /// Returns the distance in number of [`Instruction`] words from `base` to the [`InstructionPtr`].
+    ///
+    /// # Note
+    ///
+    /// The `base` must point to the first [`Instruction`] of the compiled Wasm function
+    /// associated to the [`InstructionPtr`].
+    pub fn offset_from(&self, base: *const Instruction) -> usize {
+        (self.ptr as usize).wrapping_sub(base as usize) / mem::size_of::<Instruction>()
+    }
+
     /// Returns a shared reference to the currently pointed at [`Instruction`].
     ///
     /// # Safety
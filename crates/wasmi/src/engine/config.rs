@@ -1,7 +1,8 @@
 use super::StackLimits;
+use crate::memory::{MemoryBackend, VecMemoryBackend};
+use alloc::sync::Arc;
 use core::{mem::size_of, num::NonZeroU64};
 use wasmi_core::UntypedValue;
-use wasmparser::WasmFeatures;
 
 /// The default amount of stacks kept in the cache at most.
 const DEFAULT_CACHED_STACKS: usize = 2;
@@ -9,7 +10,7 @@ const DEFAULT_CACHED_STACKS: usize = 2;
 /// Configuration for an [`Engine`].
 ///
 /// [`Engine`]: [`crate::Engine`]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub struct Config {
     /// The limits set on the value stack and call stack.
     stack_limits: StackLimits,
@@ -31,8 +32,26 @@ pub struct Config {
     tail_call: bool,
     /// Is `true` if the [`extended-const`] Wasm proposal is enabled.
     extended_const: bool,
+    /// Is `true` if the [`threads`] Wasm proposal is enabled.
+    threads: bool,
+    /// Is `true` if the [`multi-memory`] Wasm proposal is enabled.
+    multi_memory: bool,
+    /// Is `true` if unaligned atomic accesses are performed instead of trapping.
+    relaxed_atomic_alignment: bool,
     /// Is `true` if Wasm instructions on `f32` and `f64` types are allowed.
     floats: bool,
+    /// Is `true` if `f32` and `f64` NaN results are canonicalized.
+    floats_canonicalize_nan: bool,
+    /// The maximum number of pages a linear memory may grow to via `memory.grow` if any.
+    max_memory_pages: Option<u32>,
+    /// The maximum number of elements a table may grow to via `table.grow` if any.
+    max_table_elements: Option<u32>,
+    /// Is `true` if calls to never initialized `funcref` table elements trap distinctly.
+    table_null_is_trap_sentinel: bool,
+    /// Is `true` if executions call the trace hook of the [`Store`](crate::Store).
+    tracing: bool,
+    /// Is `true` if compiled functions record the Wasm byte offsets of their instructions.
+    trap_offsets: bool,
     /// Is `true` if `wasmi` executions shall consume fuel.
     consume_fuel: bool,
     /// The fuel consumption mode of the `wasmi` [`Engine`](crate::Engine).
@@ -41,6 +60,16 @@ pub struct Config {
     fuel_costs: FuelCosts,
     /// The mode of Wasm to `wasmi` bytecode compilation.
     compilation_mode: CompilationMode,
+    /// Is `true` if Wasm function bodies are translated in parallel.
+    parallel_compilation: bool,
+    /// Is `true` if diagnostics are collected during Wasm function translation.
+    collect_translation_diagnostics: bool,
+    /// The maximum number of instruction words shifted by `local.set` preservations per function if any.
+    max_preservation_shift: Option<u32>,
+    /// The kinds of instruction fusion applied during Wasm function translation.
+    fusions: Fusions,
+    /// The backend allocating the bytes of linear memories.
+    memory_backend: Arc<dyn MemoryBackend>,
 }
 
 /// The fuel consumption mode of the `wasmi` [`Engine`].
@@ -91,7 +120,43 @@ pub enum FuelConsumptionMode {
     Eager,
 }
 
+/// The Wasm proposals enabled by a [`Config`].
+///
+/// Obtained via [`Config::features`] or [`Engine::features`].
+///
+/// [`Engine::features`]: crate::Engine::features
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct WasmFeatures {
+    /// Is `true` if the `mutable-global` Wasm proposal is enabled.
+    pub mutable_global: bool,
+    /// Is `true` if the `sign-extension` Wasm proposal is enabled.
+    pub sign_extension: bool,
+    /// Is `true` if the `saturating-float-to-int` Wasm proposal is enabled.
+    pub saturating_float_to_int: bool,
+    /// Is `true` if the `multi-value` Wasm proposal is enabled.
+    pub multi_value: bool,
+    /// Is `true` if the `bulk-memory` Wasm proposal is enabled.
+    pub bulk_memory: bool,
+    /// Is `true` if the `reference-types` Wasm proposal is enabled.
+    pub reference_types: bool,
+    /// Is `true` if the `tail-call` Wasm proposal is enabled.
+    pub tail_call: bool,
+    /// Is `true` if the `extended-const` Wasm proposal is enabled.
+    pub extended_const: bool,
+    /// Is `true` if the `threads` Wasm proposal is enabled.
+    pub threads: bool,
+    /// Is `true` if the `multi-memory` Wasm proposal is enabled.
+    pub multi_memory: bool,
+    /// Is `true` if Wasm instructions on `f32` and `f64` types are allowed.
+    pub floats: bool,
+}
+
 /// Type storing all kinds of fuel costs of instructions.
+///
+/// Use [`Config::set_fuel_costs`] to configure custom [`FuelCosts`] for an [`Engine`].
+///
+/// [`Engine`]: crate::Engine
 #[derive(Debug, Copy, Clone)]
 pub struct FuelCosts {
     /// The base fuel costs for all instructions.
@@ -103,6 +168,30 @@ pub struct FuelCosts {
 }
 
 impl FuelCosts {
+    /// Sets the base fuel costs for all `wasmi` IR instructions.
+    ///
+    /// Defaults to 1.
+    pub fn set_base(&mut self, base: u64) -> &mut Self {
+        self.base = base;
+        self
+    }
+
+    /// Sets the number of register copies that can be performed per unit of fuel.
+    ///
+    /// Defaults to 8.
+    pub fn set_copies_per_fuel(&mut self, copies_per_fuel: NonZeroU64) -> &mut Self {
+        self.copies_per_fuel = copies_per_fuel;
+        self
+    }
+
+    /// Sets the number of bytes that can be copied per unit of fuel.
+    ///
+    /// Defaults to 64.
+    pub fn set_bytes_per_fuel(&mut self, bytes_per_fuel: NonZeroU64) -> &mut Self {
+        self.bytes_per_fuel = bytes_per_fuel;
+        self
+    }
+
     /// Returns the base fuel costs for all `wasmi` IR instructions.
     pub fn base(&self) -> u64 {
         self.base
@@ -213,6 +302,58 @@ pub enum CompilationMode {
     Lazy,
 }
 
+/// A kind of instruction fusion applied during Wasm to `wasmi` bytecode translation.
+///
+/// Instruction fusion combines multiple Wasm instructions into a single
+/// `wasmi` instruction. All kinds are enabled by default.
+///
+/// Use [`Config::enable_fusion`] to enable or disable a kind of fusion.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FusionKind {
+    /// Fuses integer comparisons with a succeeding conditional branch, e.g. `br_if`.
+    CmpBranch,
+    /// Fuses integer comparisons with a succeeding `select`.
+    CmpSelect,
+    /// Fuses `i32.{and,or,xor}` with a succeeding `i32.eqz`.
+    EqzLogic,
+    /// Fuses `i32.load` and `i64.load` with a succeeding binary operation.
+    LoadOp,
+    /// Elides or merges copies due to `local.set` and `local.tee`.
+    ///
+    /// - Instead of copying the result of the preceding instruction into the local
+    ///   variable, the preceding instruction stores its result into it directly.
+    /// - Two adjacent copies into local variables are merged into a single instruction.
+    CopyElision,
+}
+
+/// The set of enabled [`FusionKind`]s.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct Fusions {
+    /// The bit set of disabled [`FusionKind`]s.
+    disabled: u8,
+}
+
+impl Fusions {
+    /// Returns the bit of the [`FusionKind`] in the bit set.
+    fn bit(kind: FusionKind) -> u8 {
+        1 << (kind as u8)
+    }
+
+    /// Enables or disables the [`FusionKind`].
+    fn set(&mut self, kind: FusionKind, enable: bool) {
+        match enable {
+            true => self.disabled &= !Self::bit(kind),
+            false => self.disabled |= Self::bit(kind),
+        }
+    }
+
+    /// Returns `true` if the [`FusionKind`] is enabled.
+    pub fn is_enabled(&self, kind: FusionKind) -> bool {
+        self.disabled & Self::bit(kind) == 0
+    }
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -226,11 +367,25 @@ impl Default for Config {
             reference_types: true,
             tail_call: false,
             extended_const: false,
+            threads: false,
+            multi_memory: false,
+            relaxed_atomic_alignment: false,
             floats: true,
+            floats_canonicalize_nan: false,
+            max_memory_pages: None,
+            max_table_elements: None,
+            table_null_is_trap_sentinel: false,
+            tracing: false,
+            trap_offsets: false,
             consume_fuel: false,
             fuel_costs: FuelCosts::default(),
             fuel_consumption_mode: FuelConsumptionMode::default(),
             compilation_mode: CompilationMode::default(),
+            parallel_compilation: false,
+            collect_translation_diagnostics: false,
+            max_preservation_shift: None,
+            fusions: Fusions::default(),
+            memory_backend: Arc::new(VecMemoryBackend),
         }
     }
 }
@@ -359,6 +514,67 @@ impl Config {
         self
     }
 
+    /// Enable or disable the [`threads`] Wasm proposal for the [`Config`].
+    ///
+    /// # Note
+    ///
+    /// Disabled by default.
+    ///
+    /// - `wasmi` does not support shared linear memories. Therefore atomic
+    ///   instructions operate on non-shared linear memories where they behave
+    ///   like their non-atomic counterparts with an additional alignment check.
+    /// - `memory.atomic.wait32` and `memory.atomic.wait64` always trap and
+    ///   `memory.atomic.notify` always returns 0 since there are no waiters.
+    /// - Only atomic loads and stores of full width and the `rmw.add` family
+    ///   are supported so far. Other atomic instructions fail to translate.
+    ///
+    /// [`threads`]: https://github.com/WebAssembly/threads
+    pub fn wasm_threads(&mut self, enable: bool) -> &mut Self {
+        self.threads = enable;
+        self
+    }
+
+    /// Enable or disable the [`multi-memory`] Wasm proposal for the [`Config`].
+    ///
+    /// # Note
+    ///
+    /// Disabled by default.
+    ///
+    /// Accesses to the default linear memory at index 0 are encoded as efficiently
+    /// as without this proposal while accesses to other linear memories use slightly
+    /// larger and slower instruction encodings.
+    ///
+    /// [`multi-memory`]: https://github.com/WebAssembly/multi-memory
+    pub fn wasm_multi_memory(&mut self, enable: bool) -> &mut Self {
+        self.multi_memory = enable;
+        self
+    }
+
+    /// Enables or disables relaxed alignment checks for atomic instructions.
+    ///
+    /// If enabled, atomic instructions with an unaligned effective address perform
+    /// their access unaligned instead of trapping with [`TrapCode::UnalignedAtomic`].
+    ///
+    /// Disabled by default.
+    ///
+    /// # Note
+    ///
+    /// This is **not** compliant with the Wasm [`threads`] proposal and only meant
+    /// for testing and benchmarking purposes. Wasm programs that rely on unaligned
+    /// atomic accesses to trap will behave differently.
+    ///
+    /// [`TrapCode::UnalignedAtomic`]: crate::core::TrapCode::UnalignedAtomic
+    /// [`threads`]: https://github.com/WebAssembly/threads
+    pub fn relaxed_atomic_alignment(&mut self, enable: bool) -> &mut Self {
+        self.relaxed_atomic_alignment = enable;
+        self
+    }
+
+    /// Returns `true` if the [`Config`] relaxes alignment checks of atomic instructions.
+    pub(crate) fn get_relaxed_atomic_alignment(&self) -> bool {
+        self.relaxed_atomic_alignment
+    }
+
     /// Enable or disable Wasm floating point (`f32` and `f64`) instructions and types.
     ///
     /// Enabled by default.
@@ -367,6 +583,176 @@ impl Config {
         self
     }
 
+    /// Enable or disable canonicalization of NaN results of Wasm floating point instructions.
+    ///
+    /// # Note
+    ///
+    /// The Wasm specification allows NaN results of floating point instructions
+    /// to carry arbitrary payloads which might differ between platforms.
+    /// If enabled, every floating point instruction that may produce a NaN result
+    /// yields the canonical quiet NaN with positive sign instead.
+    /// This makes `wasmi` executions fully deterministic at the cost of some performance.
+    ///
+    /// Disabled by default.
+    pub fn floats_canonicalize_nan(&mut self, enable: bool) -> &mut Self {
+        self.floats_canonicalize_nan = enable;
+        self
+    }
+
+    /// Returns `true` if the [`Config`] enables NaN canonicalization.
+    pub(crate) fn get_floats_canonicalize_nan(&self) -> bool {
+        self.floats_canonicalize_nan
+    }
+
+    /// Sets the maximum number of nested Wasm calls to `depth`.
+    ///
+    /// # Note
+    ///
+    /// - Executions trap with [`TrapCode::StackOverflow`] once the number of Wasm call
+    ///   frames would exceed `depth`.
+    /// - Call frames of outer executions count towards this limit if host functions
+    ///   re-enter Wasm execution. This protects against native stack exhaustion.
+    /// - This overwrites the maximum recursion depth of the configured [`StackLimits`].
+    ///
+    /// By default the maximum recursion depth of [`StackLimits::default`] is used.
+    ///
+    /// [`TrapCode::StackOverflow`]: crate::core::TrapCode::StackOverflow
+    pub fn max_call_depth(&mut self, depth: usize) -> &mut Self {
+        self.stack_limits.maximum_recursion_depth = depth;
+        self
+    }
+
+    /// Sets the maximum number of value stack slots to `slots`.
+    ///
+    /// # Note
+    ///
+    /// - Functions that require more than `slots` value stack slots for their
+    ///   registers fail to translate with an [`Error`].
+    /// - Executions trap with [`TrapCode::StackOverflow`] once the value stack slots
+    ///   of all active Wasm call frames would exceed `slots`.
+    /// - This overwrites the maximum value stack height of the configured [`StackLimits`]
+    ///   and lowers its initial value stack height to `slots` if necessary.
+    ///
+    /// By default the maximum value stack height of [`StackLimits::default`] is used.
+    ///
+    /// [`Error`]: crate::Error
+    /// [`TrapCode::StackOverflow`]: crate::core::TrapCode::StackOverflow
+    pub fn max_value_stack_height(&mut self, slots: usize) -> &mut Self {
+        self.stack_limits.maximum_value_stack_height = slots;
+        self.stack_limits.initial_value_stack_height =
+            self.stack_limits.initial_value_stack_height.min(slots);
+        self
+    }
+
+    /// Sets the maximum number of pages a linear memory may grow to via Wasm `memory.grow`.
+    ///
+    /// # Note
+    ///
+    /// - A Wasm `memory.grow` instruction that would grow a linear memory beyond
+    ///   this limit fails and returns `-1` without mutating the linear memory.
+    /// - This limit is enforced in addition to the maximum declared by the Wasm module.
+    ///
+    /// No limit by default.
+    pub fn max_memory_pages(&mut self, limit: u32) -> &mut Self {
+        self.max_memory_pages = Some(limit);
+        self
+    }
+
+    /// Returns the maximum number of pages a linear memory may grow to via `memory.grow` if any.
+    pub(crate) fn get_max_memory_pages(&self) -> Option<u32> {
+        self.max_memory_pages
+    }
+
+    /// Sets the maximum number of elements a table may grow to via Wasm `table.grow`.
+    ///
+    /// # Note
+    ///
+    /// - A Wasm `table.grow` instruction that would grow a table beyond
+    ///   this limit fails and returns `-1` without mutating the table.
+    /// - This limit is enforced in addition to the maximum declared by the Wasm module.
+    ///
+    /// No limit by default.
+    pub fn max_table_elements(&mut self, limit: u32) -> &mut Self {
+        self.max_table_elements = Some(limit);
+        self
+    }
+
+    /// Returns the maximum number of elements a table may grow to via `table.grow` if any.
+    pub(crate) fn get_max_table_elements(&self) -> Option<u32> {
+        self.max_table_elements
+    }
+
+    /// Enables or disables distinguishing never initialized `funcref` table elements from `null`.
+    ///
+    /// # Note
+    ///
+    /// - If enabled, `funcref` tables track which of their elements have never been
+    ///   written since their creation. A `call_indirect` to such an element traps with
+    ///   [`TrapCode::UninitializedElement`] instead of [`TrapCode::IndirectCallToNull`].
+    /// - Elements become initialized by any write, e.g. via `table.set`, `table.fill`,
+    ///   `table.copy`, `table.init`, `table.grow` or element segment initialization,
+    ///   even if the written value is `null`.
+    /// - This is useful for debugging but increases the memory consumption of tables.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`TrapCode::UninitializedElement`]: crate::core::TrapCode::UninitializedElement
+    /// [`TrapCode::IndirectCallToNull`]: crate::core::TrapCode::IndirectCallToNull
+    pub fn table_null_is_trap_sentinel(&mut self, enable: bool) -> &mut Self {
+        self.table_null_is_trap_sentinel = enable;
+        self
+    }
+
+    /// Returns `true` if never initialized `funcref` table elements trap distinctly.
+    pub(crate) fn get_table_null_is_trap_sentinel(&self) -> bool {
+        self.table_null_is_trap_sentinel
+    }
+
+    /// Enables or disables calling the trace hook of the [`Store`] before each executed instruction.
+    ///
+    /// # Note
+    ///
+    /// - Use [`Store::set_trace_hook`] to register the trace hook.
+    /// - Enabling tracing slows down execution even if no trace hook is registered.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`Store`]: crate::Store
+    /// [`Store::set_trace_hook`]: crate::Store::set_trace_hook
+    pub fn enable_tracing(&mut self, enable: bool) -> &mut Self {
+        self.tracing = enable;
+        self
+    }
+
+    /// Returns `true` if the [`Config`] enables calling the trace hook.
+    pub(crate) fn get_tracing(&self) -> bool {
+        self.tracing
+    }
+
+    /// Enables or disables reporting the Wasm byte offsets of trapping instructions.
+    ///
+    /// # Note
+    ///
+    /// - If enabled, each compiled function stores a side table mapping its
+    ///   `wasmi` bytecode instructions to the byte offsets of the Wasm operators
+    ///   they were translated from within the Wasm binary.
+    /// - Traps raised during Wasm execution report the byte offset of the
+    ///   trapping Wasm operator via [`Error::wasm_offset`].
+    /// - Enabling this slightly increases translation time and memory consumption.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`Error::wasm_offset`]: crate::Error::wasm_offset
+    pub fn trap_offsets(&mut self, enable: bool) -> &mut Self {
+        self.trap_offsets = enable;
+        self
+    }
+
+    /// Returns `true` if the [`Config`] enables reporting Wasm byte offsets of traps.
+    pub(crate) fn get_trap_offsets(&self) -> bool {
+        self.trap_offsets
+    }
+
     /// Configures whether `wasmi` will consume fuel during execution to either halt execution as desired.
     ///
     /// # Note
@@ -396,6 +782,18 @@ impl Config {
         self.consume_fuel
     }
 
+    /// Sets the [`FuelCosts`] charged for executed instructions by the [`Engine`].
+    ///
+    /// # Note
+    ///
+    /// This has no effect if fuel metering is disabled for the [`Engine`].
+    ///
+    /// [`Engine`]: crate::Engine
+    pub fn set_fuel_costs(&mut self, costs: FuelCosts) -> &mut Self {
+        self.fuel_costs = costs;
+        self
+    }
+
     /// Returns the configured [`FuelCosts`].
     pub(crate) fn fuel_costs(&self) -> &FuelCosts {
         &self.fuel_costs
@@ -438,9 +836,139 @@ impl Config {
         self.compilation_mode
     }
 
-    /// Returns the [`WasmFeatures`] represented by the [`Config`].
-    pub(crate) fn wasm_features(&self) -> WasmFeatures {
+    /// Enables or disables parallel translation of Wasm function bodies.
+    ///
+    /// When enabled, [`Module::new`] translates the Wasm function bodies of
+    /// the code section concurrently on the available threads.
+    ///
+    /// Disabled by default.
+    ///
+    /// # Note
+    ///
+    /// - The produced `wasmi` bytecode is identical to the bytecode of sequential translation.
+    /// - If multiple Wasm functions fail to translate the error of the first one is returned.
+    /// - Function bodies are translated sequentially if the `std` crate feature is disabled.
+    ///
+    /// [`Module::new`]: crate::Module::new
+    pub fn parallel_compilation(&mut self, enable: bool) -> &mut Self {
+        self.parallel_compilation = enable;
+        self
+    }
+
+    /// Returns `true` if Wasm function bodies are translated in parallel.
+    pub(crate) fn get_parallel_compilation(&self) -> bool {
+        self.parallel_compilation
+    }
+
+    /// Enables or disables collection of diagnostics during Wasm function translation.
+    ///
+    /// When enabled, the translator records a [`Diagnostic`] whenever a Wasm function
+    /// body triggers one of its fallbacks, e.g. large copy groups or constants that
+    /// do not fit into immediates. This is useful to tune the code generation of
+    /// Wasm producers. Use [`Module::translation_diagnostics`] to query them.
+    ///
+    /// Disabled by default.
+    ///
+    /// # Note
+    ///
+    /// - Collecting diagnostics never changes the produced `wasmi` bytecode.
+    /// - Only Wasm functions that are translated by [`Module::new`] are covered.
+    ///   Therefore no diagnostics are collected for lazy [`CompilationMode`]s.
+    ///
+    /// [`Diagnostic`]: crate::Diagnostic
+    /// [`Module::new`]: crate::Module::new
+    /// [`Module::translation_diagnostics`]: crate::Module::translation_diagnostics
+    pub fn collect_translation_diagnostics(&mut self, enable: bool) -> &mut Self {
+        self.collect_translation_diagnostics = enable;
+        self
+    }
+
+    /// Returns `true` if diagnostics are collected during Wasm function translation.
+    pub(crate) fn get_collect_translation_diagnostics(&self) -> bool {
+        self.collect_translation_diagnostics
+    }
+
+    /// Sets the maximum number of instruction words shifted per function due to `local.set` preservations.
+    ///
+    /// # Note
+    ///
+    /// - A `local.set` that overwrites a local variable whose value is still
+    ///   in use may be optimized by inserting a preserving copy before the
+    ///   instruction computing the new value. This shifts all following
+    ///   instruction words of the function under construction.
+    /// - Once the accumulated shifting of a function would exceed `limit` the
+    ///   preserving copy is appended instead which produces slightly larger bytecode.
+    /// - Setting `limit` to zero disables this optimization entirely.
+    ///
+    /// No limit by default.
+    pub fn max_preservation_shift(&mut self, limit: u32) -> &mut Self {
+        self.max_preservation_shift = Some(limit);
+        self
+    }
+
+    /// Returns the maximum number of instruction words shifted by `local.set` preservations if any.
+    pub(crate) fn get_max_preservation_shift(&self) -> Option<u32> {
+        self.max_preservation_shift
+    }
+
+    /// Enables or disables the [`FusionKind`] of instruction fusion.
+    ///
+    /// # Note
+    ///
+    /// - Disabling instruction fusion never changes the semantics of the translated
+    ///   Wasm functions but usually results in larger and slower `wasmi` bytecode.
+    /// - This is useful when post-processing `wasmi` bytecode that is expected to
+    ///   closely resemble the structure of the original Wasm function body.
+    ///
+    /// All kinds of instruction fusion are enabled by default.
+    pub fn enable_fusion(&mut self, kind: FusionKind, enable: bool) -> &mut Self {
+        self.fusions.set(kind, enable);
+        self
+    }
+
+    /// Returns the enabled kinds of instruction fusion.
+    pub(crate) fn get_fusions(&self) -> Fusions {
+        self.fusions
+    }
+
+    /// Sets the [`MemoryBackend`] allocating the bytes of linear memories.
+    ///
+    /// # Note
+    ///
+    /// - Only affects linear memories created after the [`Engine`] has been created.
+    /// - Linear memories are backed by [`VecMemoryBackend`] by default.
+    ///
+    /// [`Engine`]: crate::Engine
+    pub fn memory_backend(&mut self, backend: impl MemoryBackend + 'static) -> &mut Self {
+        self.memory_backend = Arc::new(backend);
+        self
+    }
+
+    /// Returns the [`MemoryBackend`] allocating the bytes of linear memories.
+    pub(crate) fn get_memory_backend(&self) -> Arc<dyn MemoryBackend> {
+        self.memory_backend.clone()
+    }
+
+    /// Returns the Wasm proposals enabled by the [`Config`].
+    pub fn features(&self) -> WasmFeatures {
         WasmFeatures {
+            mutable_global: self.mutable_global,
+            sign_extension: self.sign_extension,
+            saturating_float_to_int: self.saturating_float_to_int,
+            multi_value: self.multi_value,
+            bulk_memory: self.bulk_memory,
+            reference_types: self.reference_types,
+            tail_call: self.tail_call,
+            extended_const: self.extended_const,
+            threads: self.threads,
+            multi_memory: self.multi_memory,
+            floats: self.floats,
+        }
+    }
+
+    /// Returns the [`wasmparser::WasmFeatures`] represented by the [`Config`].
+    pub(crate) fn wasm_features(&self) -> wasmparser::WasmFeatures {
+        wasmparser::WasmFeatures {
             multi_value: self.multi_value,
             mutable_global: self.mutable_global,
             saturating_float_to_int: self.saturating_float_to_int,
@@ -453,8 +981,8 @@ impl Config {
             component_model: false,
             simd: false,
             relaxed_simd: false,
-            threads: false,
-            multi_memory: false,
+            threads: self.threads,
+            multi_memory: self.multi_memory,
             exceptions: false,
             memory64: false,
             memory_control: false,
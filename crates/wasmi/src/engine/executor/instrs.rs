@@ -1,4 +1,4 @@
-pub use self::call::CallKind;
+pub use self::call::{CallKind, HostCallBatch};
 use self::{call::CallOutcome, return_::ReturnOutcome};
 use crate::{
     core::{TrapCode, UntypedValue},
@@ -11,6 +11,7 @@ use crate::{
             Const16,
             FuncIdx,
             Instruction,
+            MemoryIdx,
             Register,
             RegisterSpan,
             UnaryInstr,
@@ -21,6 +22,7 @@ use crate::{
         executor::stack::{CallFrame, CallStack, ValueStack, ValueStackPtr},
         func_types::FuncTypeRegistry,
         CodeMap,
+        TraceEvent,
     },
     store::ResourceLimiterRef,
     Error,
@@ -30,6 +32,7 @@ use crate::{
     StoreInner,
 };
 
+mod atomic;
 mod binary;
 mod branch;
 mod call;
@@ -51,12 +54,14 @@ macro_rules! forward_call {
             results,
             host_func,
             call_kind,
+            batch,
         } = $expr?
         {
             return Ok(WasmOutcome::Call {
                 results,
                 host_func,
                 call_kind,
+                batch,
             });
         }
     }};
@@ -85,7 +90,14 @@ pub enum WasmOutcome {
         results: RegisterSpan,
         host_func: Func,
         call_kind: CallKind,
+        batch: Option<HostCallBatch>,
     },
+    /// The Wasm execution reached an `unreachable` instruction.
+    ///
+    /// The unreachable hook of the [`Store`] decides how to proceed.
+    ///
+    /// [`Store`]: crate::Store
+    Unreachable,
 }
 
 /// Executes compiled function instructions until either
@@ -107,8 +119,10 @@ pub fn execute_instrs<'ctx, 'engine>(
     func_types: &'engine FuncTypeRegistry,
     resource_limiter: &'ctx mut ResourceLimiterRef<'ctx>,
 ) -> Result<WasmOutcome, Error> {
-    Executor::new(ctx, cache, value_stack, call_stack, code_map, func_types)
+    let mut executor = Executor::new(ctx, cache, value_stack, call_stack, code_map, func_types);
+    executor
         .execute(resource_limiter)
+        .map_err(|error| executor.locate_trap(error))
 }
 
 /// An execution context for executing a `wasmi` function frame.
@@ -149,6 +163,12 @@ struct Executor<'ctx, 'engine> {
     ///
     /// This is used to lookup Wasm function information.
     func_types: &'engine FuncTypeRegistry,
+    /// Is `true` if the trace hook of the [`Store`] is called before each instruction.
+    ///
+    /// [`Store`]: crate::Store
+    tracing: bool,
+    /// Is `true` if unaligned atomic accesses are performed instead of trapping.
+    relaxed_atomic_alignment: bool,
 }
 
 impl<'ctx, 'engine> Executor<'ctx, 'engine> {
@@ -170,6 +190,8 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         //         valid for all register indices used by the associated function body.
         let sp = unsafe { value_stack.stack_ptr_at(frame.base_offset()) };
         let ip = frame.instr_ptr();
+        let tracing = ctx.engine().config().get_tracing();
+        let relaxed_atomic_alignment = ctx.engine().config().get_relaxed_atomic_alignment();
         Self {
             sp,
             ip,
@@ -179,19 +201,27 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
             call_stack,
             code_map,
             func_types,
+            tracing,
+            relaxed_atomic_alignment,
         }
     }
 
     /// Executes the function frame until it returns or traps.
     #[inline(always)]
     fn execute(
-        mut self,
+        &mut self,
         resource_limiter: &'ctx mut ResourceLimiterRef<'ctx>,
     ) -> Result<WasmOutcome, Error> {
         use Instruction as Instr;
         loop {
+            if self.tracing {
+                self.call_trace_hook()?;
+            }
+            #[cfg(feature = "debug")]
+            self.trace_instr();
             match *self.ip.get() {
                 Instr::TableIdx(_)
+                | Instr::MemoryIdx(_)
                 | Instr::DataSegmentIdx(_)
                 | Instr::ElementSegmentIdx(_)
                 | Instr::Const32(_)
@@ -201,8 +231,15 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                 | Instr::Register2(_)
                 | Instr::Register3(_)
                 | Instr::RegisterList(_)
+                | Instr::RegisterSpanList { .. }
                 | Instr::CallIndirectParams(_)
                 | Instr::CallIndirectParamsImm16(_) => self.invalid_instruction_word()?,
+                Instr::Trap(TrapCode::UnreachableCodeReached) => {
+                    // Note: the unreachable hook and trap offsets require the
+                    //       instruction pointer of the trapping function.
+                    self.update_instr_ptr_at(0);
+                    return Ok(WasmOutcome::Unreachable);
+                }
                 Instr::Trap(trap_code) => self.execute_trap(trap_code)?,
                 Instr::ConsumeFuel(block_fuel) => self.execute_consume_fuel(block_fuel)?,
                 Instr::Return => {
@@ -226,6 +263,9 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                 Instr::ReturnF64Imm32 { value } => {
                     forward_return!(self.execute_return_f64imm32(value))
                 }
+                Instr::ReturnI64Imm | Instr::ReturnF64Imm => {
+                    forward_return!(self.execute_return_imm64())
+                }
                 Instr::ReturnSpan { values } => {
                     forward_return!(self.execute_return_span(values))
                 }
@@ -329,6 +369,9 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                 Instr::CopyImm32 { result, value } => self.execute_copy_imm32(result, value),
                 Instr::CopyI64Imm32 { result, value } => self.execute_copy_i64imm32(result, value),
                 Instr::CopyF64Imm32 { result, value } => self.execute_copy_f64imm32(result, value),
+                Instr::CopyI64Imm { result } | Instr::CopyF64Imm { result } => {
+                    self.execute_copy_imm64(result)
+                }
                 Instr::CopySpan {
                     results,
                     values,
@@ -339,6 +382,11 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                     values,
                     len,
                 } => self.execute_copy_span_non_overlapping(results, values, len),
+                Instr::CopySpanSmall {
+                    results,
+                    values,
+                    len,
+                } => self.execute_copy_span_small(results, values, len),
                 Instr::CopyMany { results, values } => self.execute_copy_many(results, values),
                 Instr::CopyManyNonOverlapping { results, values } => {
                     self.execute_copy_many_non_overlapping(results, values)
@@ -385,6 +433,12 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                     condition,
                     rhs,
                 } => self.execute_select_rev(result, condition, rhs),
+                Instr::SelectImm {
+                    result,
+                    condition,
+                    lhs,
+                    rhs,
+                } => self.execute_select_imm(result, condition, lhs, rhs),
                 Instr::SelectImm32 {
                     result_or_condition,
                     lhs_or_rhs,
@@ -397,6 +451,18 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                     result_or_condition,
                     lhs_or_rhs,
                 } => self.execute_select_f64imm32(result_or_condition, lhs_or_rhs),
+                Instr::SelectI32Eq(instr) => self.execute_select_i32_eq(instr),
+                Instr::SelectI32Ne(instr) => self.execute_select_i32_ne(instr),
+                Instr::SelectI32LtS(instr) => self.execute_select_i32_lt_s(instr),
+                Instr::SelectI32LtU(instr) => self.execute_select_i32_lt_u(instr),
+                Instr::SelectI32LeS(instr) => self.execute_select_i32_le_s(instr),
+                Instr::SelectI32LeU(instr) => self.execute_select_i32_le_u(instr),
+                Instr::SelectI64Eq(instr) => self.execute_select_i64_eq(instr),
+                Instr::SelectI64Ne(instr) => self.execute_select_i64_ne(instr),
+                Instr::SelectI64LtS(instr) => self.execute_select_i64_lt_s(instr),
+                Instr::SelectI64LtU(instr) => self.execute_select_i64_lt_u(instr),
+                Instr::SelectI64LeS(instr) => self.execute_select_i64_le_s(instr),
+                Instr::SelectI64LeU(instr) => self.execute_select_i64_le_u(instr),
                 Instr::RefFunc { result, func } => self.execute_ref_func(result, func),
                 Instr::TableGet { result, index } => self.execute_table_get(result, index)?,
                 Instr::TableGetImm { result, index } => {
@@ -596,6 +662,16 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                 Instr::I64Load32u(instr) => self.execute_i64_load32_u(instr)?,
                 Instr::I64Load32uAt(instr) => self.execute_i64_load32_u_at(instr)?,
                 Instr::I64Load32uOffset16(instr) => self.execute_i64_load32_u_offset16(instr)?,
+                Instr::I32AddLoad(instr) => self.execute_i32_add_load(instr)?,
+                Instr::I32SubLoad(instr) => self.execute_i32_sub_load(instr)?,
+                Instr::I32AndLoad(instr) => self.execute_i32_and_load(instr)?,
+                Instr::I32OrLoad(instr) => self.execute_i32_or_load(instr)?,
+                Instr::I32XorLoad(instr) => self.execute_i32_xor_load(instr)?,
+                Instr::I64AddLoad(instr) => self.execute_i64_add_load(instr)?,
+                Instr::I64SubLoad(instr) => self.execute_i64_sub_load(instr)?,
+                Instr::I64AndLoad(instr) => self.execute_i64_and_load(instr)?,
+                Instr::I64OrLoad(instr) => self.execute_i64_or_load(instr)?,
+                Instr::I64XorLoad(instr) => self.execute_i64_xor_load(instr)?,
                 Instr::I32Store(instr) => self.execute_i32_store(instr)?,
                 Instr::I32StoreOffset16(instr) => self.execute_i32_store_offset16(instr)?,
                 Instr::I32StoreOffset16Imm16(instr) => {
@@ -651,6 +727,20 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                 Instr::F64Store(instr) => self.execute_f64_store(instr)?,
                 Instr::F64StoreOffset16(instr) => self.execute_f64_store_offset16(instr)?,
                 Instr::F64StoreAt(instr) => self.execute_f64_store_at(instr)?,
+                Instr::I32AtomicLoad(instr) => self.execute_i32_atomic_load(instr)?,
+                Instr::I64AtomicLoad(instr) => self.execute_i64_atomic_load(instr)?,
+                Instr::I32AtomicStore(instr) => self.execute_i32_atomic_store(instr)?,
+                Instr::I64AtomicStore(instr) => self.execute_i64_atomic_store(instr)?,
+                Instr::I32AtomicRmwAdd(instr) => self.execute_i32_atomic_rmw_add(instr)?,
+                Instr::I64AtomicRmwAdd(instr) => self.execute_i64_atomic_rmw_add(instr)?,
+                Instr::I32AtomicRmw8AddU(instr) => self.execute_i32_atomic_rmw8_add_u(instr)?,
+                Instr::I32AtomicRmw16AddU(instr) => self.execute_i32_atomic_rmw16_add_u(instr)?,
+                Instr::I64AtomicRmw8AddU(instr) => self.execute_i64_atomic_rmw8_add_u(instr)?,
+                Instr::I64AtomicRmw16AddU(instr) => self.execute_i64_atomic_rmw16_add_u(instr)?,
+                Instr::I64AtomicRmw32AddU(instr) => self.execute_i64_atomic_rmw32_add_u(instr)?,
+                Instr::MemoryAtomicNotify(instr) => self.execute_memory_atomic_notify(instr)?,
+                Instr::MemoryAtomicWait32(instr) => self.execute_memory_atomic_wait32(instr)?,
+                Instr::MemoryAtomicWait64(instr) => self.execute_memory_atomic_wait64(instr)?,
                 Instr::I32Eq(instr) => self.execute_i32_eq(instr),
                 Instr::I32EqImm16(instr) => self.execute_i32_eq_imm16(instr),
                 Instr::I32Ne(instr) => self.execute_i32_ne(instr),
@@ -809,6 +899,8 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                 Instr::F64Trunc(instr) => self.execute_f64_trunc(instr),
                 Instr::F64Nearest(instr) => self.execute_f64_nearest(instr),
                 Instr::F64Sqrt(instr) => self.execute_f64_sqrt(instr),
+                Instr::F32CanonicalizeNan(instr) => self.execute_f32_canonicalize_nan(instr),
+                Instr::F64CanonicalizeNan(instr) => self.execute_f64_canonicalize_nan(instr),
                 Instr::F32Add(instr) => self.execute_f32_add(instr),
                 Instr::F32Sub(instr) => self.execute_f32_sub(instr),
                 Instr::F32Mul(instr) => self.execute_f32_mul(instr),
@@ -879,9 +971,72 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
 
     /// Sets the [`Register`] value to `value`.
     fn set_register(&mut self, register: Register, value: impl Into<UntypedValue>) {
+        let value = value.into();
+        #[cfg(feature = "debug")]
+        self.trace_write(register, value);
         // Safety: TODO
         let cell = unsafe { self.sp.get_mut(register) };
-        *cell = value.into();
+        *cell = value;
+    }
+
+    /// Calls the trace hook of the [`Store`] for the currently executed [`Instruction`] if any.
+    ///
+    /// [`Store`]: crate::Store
+    #[cold]
+    #[inline(never)]
+    fn call_trace_hook(&mut self) -> Result<(), Error> {
+        let func = self
+            .call_stack
+            .peek()
+            .expect("must have call frame on the call stack")
+            .func();
+        let func = self.code_map.get(func)?;
+        // Note: Compiled functions have at most `u32::MAX` instructions.
+        let instr_offset = self.ip.offset_from(func.instrs().as_ptr()) as u32;
+        let event = TraceEvent::new(func.func_idx().into_u32(), instr_offset);
+        if let Some(hook) = self.ctx.trace_hook_mut() {
+            hook.call(&event);
+        }
+        Ok(())
+    }
+
+    /// Attaches the Wasm byte offset of the currently executed [`Instruction`] to a trap `error`.
+    ///
+    /// # Note
+    ///
+    /// Errors that are not traps are returned unchanged.
+    #[cold]
+    #[inline(never)]
+    fn locate_trap(&self, error: Error) -> Error {
+        if error.as_trap_code().is_none() {
+            return error;
+        }
+        let Some(frame) = self.call_stack.peek() else {
+            return error;
+        };
+        let Ok(func) = self.code_map.get(frame.func()) else {
+            return error;
+        };
+        error.with_wasm_offset(func.wasm_offset_at(self.ip))
+    }
+
+    /// Records the currently executed [`Instruction`] if tracing is enabled.
+    #[cfg(feature = "debug")]
+    #[inline(always)]
+    fn trace_instr(&mut self) {
+        let instr = *self.ip.get();
+        if let Some(trace) = self.ctx.trace_mut() {
+            trace.push_instr(instr);
+        }
+    }
+
+    /// Records a write of `value` to `register` if tracing is enabled.
+    #[cfg(feature = "debug")]
+    #[inline(always)]
+    fn trace_write(&mut self, register: Register, value: UntypedValue) {
+        if let Some(trace) = self.ctx.trace_mut() {
+            trace.push_write(register, value);
+        }
     }
 
     /// Shifts the instruction pointer to the next instruction.
@@ -1099,11 +1254,42 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         }
     }
 
+    /// Returns the 64-bit value encoded by the two [`Instruction::Const32`] parameters at `offset`.
+    ///
+    /// Read [`Instruction::const64`] for details about the encoding.
+    fn fetch_const64(&self, offset: usize) -> UntypedValue {
+        let lo = u64::from(u32::from(self.fetch_const32(offset)));
+        let hi = u64::from(u32::from(self.fetch_const32(offset + 1)));
+        UntypedValue::from(hi << 32 | lo)
+    }
+
     /// Returns the [`Instruction::Const32`] parameter for an [`Instruction`].
     fn fetch_address_offset(&self, offset: usize) -> u32 {
         u32::from(self.fetch_const32(offset))
     }
 
+    /// Returns the optional [`Instruction::MemoryIdx`] parameter for an [`Instruction`].
+    ///
+    /// Returns `None` if the parameter is missing in which case the
+    /// [`Instruction`] operates on the default linear memory.
+    ///
+    /// # Note
+    ///
+    /// If the parameter exists the instruction pointer is advanced past it.
+    /// Therefore it must be fetched after all other parameters of the [`Instruction`].
+    #[inline(always)]
+    fn fetch_optional_memory(&mut self, offset: usize) -> Option<MemoryIdx> {
+        let mut addr: InstructionPtr = self.ip;
+        addr.add(offset);
+        match *addr.get() {
+            Instruction::MemoryIdx(index) => {
+                self.ip.add(1);
+                Some(index)
+            }
+            _ => None,
+        }
+    }
+
     /// Executes a generic unary [`Instruction`].
     fn execute_unary(&mut self, instr: UnaryInstr, op: fn(UntypedValue) -> UntypedValue) {
         let value = self.get_register(instr.input);
@@ -1111,17 +1297,6 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         self.next_instr();
     }
 
-    /// Executes a fallible generic unary [`Instruction`].
-    fn try_execute_unary(
-        &mut self,
-        instr: UnaryInstr,
-        op: fn(UntypedValue) -> Result<UntypedValue, TrapCode>,
-    ) -> Result<(), Error> {
-        let value = self.get_register(instr.input);
-        self.set_register(instr.result, op(value)?);
-        self.try_next_instr()
-    }
-
     /// Executes a generic binary [`Instruction`].
     fn execute_binary(
         &mut self,
@@ -0,0 +1,234 @@
+use super::Executor;
+use crate::{
+    core::{TrapCode, UntypedValue},
+    engine::{
+        bytecode::{Instruction, LoadInstr, Register, StoreInstr},
+        code_map::InstructionPtr,
+    },
+    Error,
+};
+
+/// The function signature of Wasm load operations used by atomic instructions.
+type WasmLoadOp =
+    fn(memory: &[u8], address: UntypedValue, offset: u32) -> Result<UntypedValue, TrapCode>;
+
+/// The function signature of Wasm store operations used by atomic instructions.
+type WasmStoreOp = fn(
+    memory: &mut [u8],
+    address: UntypedValue,
+    offset: u32,
+    value: UntypedValue,
+) -> Result<(), TrapCode>;
+
+/// The function signature of the modify step of atomic read-modify-write instructions.
+type WasmRmwOp = fn(loaded: UntypedValue, value: UntypedValue) -> UntypedValue;
+
+/// Checks that the effective address `address+offset` is aligned to `size` bytes.
+///
+/// # Note
+///
+/// The effective address is computed without overflow so that an overflowing
+/// but otherwise aligned access traps with [`TrapCode::MemoryOutOfBounds`] later.
+///
+/// # Errors
+///
+/// Returns [`TrapCode::UnalignedAtomic`] if the effective address is unaligned.
+fn check_alignment(address: UntypedValue, offset: u32, size: u64) -> Result<(), TrapCode> {
+    let address = u64::from(u32::from(address)) + u64::from(offset);
+    if address % size != 0 {
+        return Err(TrapCode::UnalignedAtomic);
+    }
+    Ok(())
+}
+
+impl<'ctx, 'engine> Executor<'ctx, 'engine> {
+    /// Checks the alignment of an atomic access unless relaxed via [`Config::relaxed_atomic_alignment`].
+    ///
+    /// [`Config::relaxed_atomic_alignment`]: crate::Config::relaxed_atomic_alignment
+    fn check_atomic_alignment(
+        &self,
+        address: UntypedValue,
+        offset: u32,
+        size: u64,
+    ) -> Result<(), TrapCode> {
+        if self.relaxed_atomic_alignment {
+            return Ok(());
+        }
+        check_alignment(address, offset, size)
+    }
+
+    /// Returns the [`Instruction::Register`] parameter for an atomic [`Instruction`].
+    fn fetch_atomic_value(&self, offset: usize) -> Register {
+        let mut addr: InstructionPtr = self.ip;
+        addr.add(offset);
+        match *addr.get() {
+            Instruction::Register(register) => register,
+            _ => unreachable!("expected an Instruction::Register instruction word"),
+        }
+    }
+
+    /// Executes a generic atomic `load` [`Instruction`].
+    ///
+    /// # Note
+    ///
+    /// Since `wasmi` linear memories are never shared this behaves like a normal
+    /// `load` with an additional alignment check.
+    fn execute_atomic_load(
+        &mut self,
+        instr: LoadInstr,
+        size: u64,
+        load: WasmLoadOp,
+    ) -> Result<(), Error> {
+        let offset = self.fetch_address_offset(1);
+        let memory = self.fetch_optional_memory(2);
+        let address = self.get_register(instr.ptr);
+        self.check_atomic_alignment(address, offset, size)?;
+        let memory = self.cache.memory_bytes(self.ctx, memory);
+        let loaded = load(memory, address, offset)?;
+        self.set_register(instr.result, loaded);
+        self.try_next_instr_at(2)
+    }
+
+    /// Executes a generic atomic `store` [`Instruction`].
+    ///
+    /// # Note
+    ///
+    /// Since `wasmi` linear memories are never shared this behaves like a normal
+    /// `store` with an additional alignment check.
+    fn execute_atomic_store(
+        &mut self,
+        instr: StoreInstr,
+        size: u64,
+        store: WasmStoreOp,
+    ) -> Result<(), Error> {
+        let offset = u32::from(instr.offset);
+        let address = self.get_register(instr.ptr);
+        let value = self.get_register(self.fetch_atomic_value(1));
+        let memory = self.fetch_optional_memory(2);
+        self.check_atomic_alignment(address, offset, size)?;
+        let memory = self.cache.memory_bytes(self.ctx, memory);
+        store(memory, address, offset, value)?;
+        self.try_next_instr_at(2)
+    }
+
+    /// Executes a generic atomic read-modify-write [`Instruction`].
+    ///
+    /// Stores `op(loaded, value)` and returns the `loaded` value.
+    fn execute_atomic_rmw(
+        &mut self,
+        instr: LoadInstr,
+        size: u64,
+        load: WasmLoadOp,
+        op: WasmRmwOp,
+        store: WasmStoreOp,
+    ) -> Result<(), Error> {
+        let offset = self.fetch_address_offset(1);
+        let address = self.get_register(instr.ptr);
+        let value = self.get_register(self.fetch_atomic_value(2));
+        let memory = self.fetch_optional_memory(3);
+        self.check_atomic_alignment(address, offset, size)?;
+        let memory = self.cache.memory_bytes(self.ctx, memory);
+        let loaded = load(memory, address, offset)?;
+        store(memory, address, offset, op(loaded, value))?;
+        self.set_register(instr.result, loaded);
+        self.try_next_instr_at(3)
+    }
+
+    /// Checks that the atomic access of `size` bytes described by `instr` is aligned and in bounds.
+    fn check_atomic_access(
+        &mut self,
+        instr: LoadInstr,
+        size: u64,
+        load: WasmLoadOp,
+    ) -> Result<(), Error> {
+        let offset = self.fetch_address_offset(1);
+        let memory = self.fetch_optional_memory(2);
+        let address = self.get_register(instr.ptr);
+        self.check_atomic_alignment(address, offset, size)?;
+        let memory = self.cache.memory_bytes(self.ctx, memory);
+        load(memory, address, offset)?;
+        Ok(())
+    }
+
+    /// Executes an [`Instruction::I32AtomicLoad`].
+    #[inline(always)]
+    pub fn execute_i32_atomic_load(&mut self, instr: LoadInstr) -> Result<(), Error> {
+        self.execute_atomic_load(instr, 4, UntypedValue::i32_load)
+    }
+
+    /// Executes an [`Instruction::I64AtomicLoad`].
+    #[inline(always)]
+    pub fn execute_i64_atomic_load(&mut self, instr: LoadInstr) -> Result<(), Error> {
+        self.execute_atomic_load(instr, 8, UntypedValue::i64_load)
+    }
+
+    /// Executes an [`Instruction::I32AtomicStore`].
+    #[inline(always)]
+    pub fn execute_i32_atomic_store(&mut self, instr: StoreInstr) -> Result<(), Error> {
+        self.execute_atomic_store(instr, 4, UntypedValue::i32_store)
+    }
+
+    /// Executes an [`Instruction::I64AtomicStore`].
+    #[inline(always)]
+    pub fn execute_i64_atomic_store(&mut self, instr: StoreInstr) -> Result<(), Error> {
+        self.execute_atomic_store(instr, 8, UntypedValue::i64_store)
+    }
+
+    /// Executes an [`Instruction::MemoryAtomicNotify`].
+    ///
+    /// # Note
+    ///
+    /// There are no waiters on non-shared linear memories so this always returns 0.
+    #[inline(always)]
+    pub fn execute_memory_atomic_notify(&mut self, instr: LoadInstr) -> Result<(), Error> {
+        self.check_atomic_access(instr, 4, UntypedValue::i32_load)?;
+        self.set_register(instr.result, UntypedValue::from(0_i32));
+        self.try_next_instr_at(2)
+    }
+
+    /// Executes an [`Instruction::MemoryAtomicWait32`].
+    ///
+    /// # Note
+    ///
+    /// Waiting on a non-shared linear memory always traps.
+    #[inline(always)]
+    pub fn execute_memory_atomic_wait32(&mut self, instr: LoadInstr) -> Result<(), Error> {
+        self.check_atomic_access(instr, 4, UntypedValue::i32_load)?;
+        Err(Error::from(TrapCode::ExpectedSharedMemory))
+    }
+
+    /// Executes an [`Instruction::MemoryAtomicWait64`].
+    ///
+    /// # Note
+    ///
+    /// Waiting on a non-shared linear memory always traps.
+    #[inline(always)]
+    pub fn execute_memory_atomic_wait64(&mut self, instr: LoadInstr) -> Result<(), Error> {
+        self.check_atomic_access(instr, 8, UntypedValue::i64_load)?;
+        Err(Error::from(TrapCode::ExpectedSharedMemory))
+    }
+}
+
+macro_rules! impl_execute_atomic_rmw {
+    ( $( (Instruction::$var_name:ident, $fn_name:ident, $size:literal, $load:expr, $op:expr, $store:expr) ),* $(,)? ) => {
+        $(
+            #[doc = concat!("Executes an [`Instruction::", stringify!($var_name), "`].")]
+            #[inline(always)]
+            pub fn $fn_name(&mut self, instr: LoadInstr) -> Result<(), Error> {
+                self.execute_atomic_rmw(instr, $size, $load, $op, $store)
+            }
+        )*
+    };
+}
+
+impl<'ctx, 'engine> Executor<'ctx, 'engine> {
+    impl_execute_atomic_rmw! {
+        (Instruction::I32AtomicRmwAdd, execute_i32_atomic_rmw_add, 4, UntypedValue::i32_load, UntypedValue::i32_add, UntypedValue::i32_store),
+        (Instruction::I64AtomicRmwAdd, execute_i64_atomic_rmw_add, 8, UntypedValue::i64_load, UntypedValue::i64_add, UntypedValue::i64_store),
+        (Instruction::I32AtomicRmw8AddU, execute_i32_atomic_rmw8_add_u, 1, UntypedValue::i32_load8_u, UntypedValue::i32_add, UntypedValue::i32_store8),
+        (Instruction::I32AtomicRmw16AddU, execute_i32_atomic_rmw16_add_u, 2, UntypedValue::i32_load16_u, UntypedValue::i32_add, UntypedValue::i32_store16),
+        (Instruction::I64AtomicRmw8AddU, execute_i64_atomic_rmw8_add_u, 1, UntypedValue::i64_load8_u, UntypedValue::i64_add, UntypedValue::i64_store8),
+        (Instruction::I64AtomicRmw16AddU, execute_i64_atomic_rmw16_add_u, 2, UntypedValue::i64_load16_u, UntypedValue::i64_add, UntypedValue::i64_store16),
+        (Instruction::I64AtomicRmw32AddU, execute_i64_atomic_rmw32_add_u, 4, UntypedValue::i64_load32_u, UntypedValue::i64_add, UntypedValue::i64_store32),
+    }
+}
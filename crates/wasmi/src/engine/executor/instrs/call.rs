@@ -2,16 +2,24 @@ use super::Executor;
 use crate::{
     core::TrapCode,
     engine::{
-        bytecode::{FuncIdx, Instruction, Register, RegisterSpan, SignatureIdx, TableIdx},
+        bytecode::{
+            FuncIdx,
+            Instruction,
+            Register,
+            RegisterSpan,
+            RegisterSpanIter,
+            SignatureIdx,
+            TableIdx,
+        },
         code_map::InstructionPtr,
         executor::stack::{CallFrame, Stack, ValueStackPtr},
         CompiledFunc,
-        CompiledFuncEntity,
     },
     func::FuncEntity,
     Error,
     Func,
     FuncRef,
+    Instance,
 };
 use core::slice;
 
@@ -39,9 +47,59 @@ pub enum CallOutcome {
         results: RegisterSpan,
         host_func: Func,
         call_kind: CallKind,
+        batch: Option<HostCallBatch>,
     },
 }
 
+/// The maximum number of calls that are combined into a single [`HostCallBatch`].
+const MAX_BATCH_LEN: u32 = 64;
+
+/// Adjacent calls to the same batched host function executed as a single host call.
+///
+/// # Note
+///
+/// The parameters of all calls of the batch reside in consecutive chunks at the
+/// end of the value stack in the order of the calls. Each chunk is large enough
+/// to hold either the parameters or the results of a single call.
+#[derive(Debug, Copy, Clone)]
+pub struct HostCallBatch {
+    /// The first call [`Instruction`] of the batch.
+    first: InstructionPtr,
+    /// The number of calls in the batch.
+    len: u32,
+}
+
+impl HostCallBatch {
+    /// Returns the number of calls in the [`HostCallBatch`].
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Calls `f` with the result registers of all calls of the [`HostCallBatch`] in order.
+    pub fn for_each_results(&self, mut f: impl FnMut(RegisterSpan)) {
+        let mut ip = self.first;
+        for _ in 0..self.len {
+            let (results, params) = match *ip.get() {
+                Instruction::CallImported0 { results, .. } => (results, CallParams::None),
+                Instruction::CallImported { results, .. } => (results, CallParams::Some),
+                unexpected => {
+                    unreachable!("unexpected Instruction found in host call batch: {unexpected:?}")
+                }
+            };
+            f(results);
+            ip.add(1);
+            if matches!(params, CallParams::Some) {
+                while let Instruction::RegisterList(_) | Instruction::RegisterSpanList { .. } =
+                    ip.get()
+                {
+                    ip.add(1);
+                }
+                ip.add(1);
+            }
+        }
+    }
+}
+
 /// The kind of a function call.
 #[derive(Debug, Copy, Clone)]
 pub enum CallKind {
@@ -57,7 +115,7 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     /// # Note
     ///
     /// The `offset` denotes how many [`Instruction`] words make up the call instruction.
-    fn update_instr_ptr_at(&mut self, offset: usize) {
+    pub fn update_instr_ptr_at(&mut self, offset: usize) {
         // Note: we explicitly do not mutate `self.ip` since that would make
         // other parts of the code more fragile with respect to instruction ordering.
         let mut ip = self.ip;
@@ -99,15 +157,17 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         }
     }
 
-    /// Creates a [`CallFrame`] for calling the [`CompiledFunc`].
+    /// Creates a [`CallFrame`] for calling the [`CompiledFunc`] of `instance`.
     fn dispatch_compiled_func(
         &mut self,
         results: RegisterSpan,
-        func: &CompiledFuncEntity,
+        func: CompiledFunc,
+        instance: Instance,
     ) -> Result<CallFrame, Error> {
-        let instrs = func.instrs();
+        let entity = self.code_map.get(func)?;
+        let instrs = entity.instrs();
         let instr_ptr = InstructionPtr::new(instrs.as_ptr());
-        let (base_ptr, frame_ptr) = self.value_stack.alloc_call_frame(func)?;
+        let (base_ptr, frame_ptr) = self.value_stack.alloc_call_frame(entity)?;
         // We have to reinstantiate the `self.sp` [`ValueStackPtr`] since we just called
         // [`ValueStack::alloc_call_frame`] which might invalidate all live [`ValueStackPtr`].
         let caller = self
@@ -116,8 +176,7 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
             .expect("need to have a caller on the call stack");
         // Safety: We use the base offset of a live call frame on the call stack.
         self.sp = unsafe { self.value_stack.stack_ptr_at(caller.base_offset()) };
-        let instance = caller.instance();
-        let frame = CallFrame::new(instr_ptr, frame_ptr, base_ptr, results, *instance);
+        let frame = CallFrame::new(instr_ptr, frame_ptr, base_ptr, results, instance, func);
         Ok(frame)
     }
 
@@ -142,8 +201,16 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
             }
         };
         ip.add(1);
-        while let Instruction::RegisterList(values) = ip.get() {
-            copy_params(values);
+        loop {
+            match ip.get() {
+                Instruction::RegisterList(values) => copy_params(values),
+                Instruction::RegisterSpanList { values, len } => {
+                    for value in values.iter_u16(*len) {
+                        copy_params(slice::from_ref(&value));
+                    }
+                }
+                _ => break,
+            }
             ip.add(1);
         }
         let values = match ip.get() {
@@ -161,16 +228,21 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         ip
     }
 
-    /// Prepares a [`CompiledFunc`] call with optional [`CallParams`].
+    /// Prepares a [`CompiledFunc`] call of `instance` with optional [`CallParams`].
+    ///
+    /// # Note
+    ///
+    /// The `instance` of the called [`CompiledFunc`] differs from the
+    /// instance of its caller for calls to imported Wasm functions.
     fn prepare_compiled_func_call(
         &mut self,
         results: RegisterSpan,
         func: CompiledFunc,
+        instance: Instance,
         params: CallParams,
         call_kind: CallKind,
     ) -> Result<(), Error> {
-        let func = self.code_map.get(func)?;
-        let mut called = self.dispatch_compiled_func(results, func)?;
+        let mut called = self.dispatch_compiled_func(results, func, instance)?;
         if let CallParams::Some = params {
             let called_sp = self.frame_stack_ptr(&called);
             self.ip = self.copy_call_params(called_sp);
@@ -218,7 +290,8 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         params: CallParams,
     ) -> Result<(), Error> {
         let results = self.caller_results();
-        self.prepare_compiled_func_call(results, func, params, CallKind::Tail)
+        let instance = *self.cache.instance();
+        self.prepare_compiled_func_call(results, func, instance, params, CallKind::Tail)
     }
 
     /// Returns the `results` [`RegisterSpan`] of the top-most [`CallFrame`] on the [`CallStack`].
@@ -243,7 +316,8 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         results: RegisterSpan,
         func: CompiledFunc,
     ) -> Result<(), Error> {
-        self.prepare_compiled_func_call(results, func, CallParams::None, CallKind::Nested)
+        let instance = *self.cache.instance();
+        self.prepare_compiled_func_call(results, func, instance, CallParams::None, CallKind::Nested)
     }
 
     /// Executes an [`Instruction::CallInternal`].
@@ -253,7 +327,8 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         results: RegisterSpan,
         func: CompiledFunc,
     ) -> Result<(), Error> {
-        self.prepare_compiled_func_call(results, func, CallParams::Some, CallKind::Nested)
+        let instance = *self.cache.instance();
+        self.prepare_compiled_func_call(results, func, instance, CallParams::Some, CallKind::Nested)
     }
 
     /// Executes an [`Instruction::ReturnCallImported0`].
@@ -312,7 +387,13 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         match self.ctx.resolve_func(func) {
             FuncEntity::Wasm(func) => {
                 let instance = *func.instance();
-                self.prepare_compiled_func_call(results, func.func_body(), params, call_kind)?;
+                self.prepare_compiled_func_call(
+                    results,
+                    func.func_body(),
+                    instance,
+                    params,
+                    call_kind,
+                )?;
                 self.cache.update_instance(&instance);
                 Ok(CallOutcome::Continue)
             }
@@ -324,6 +405,8 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                 let len_params = input_types.len();
                 let len_results = output_types.len();
                 let max_inout = len_params.max(len_results);
+                let is_batched = host_func.is_batched();
+                let first = self.ip;
                 self.value_stack.reserve(max_inout)?;
                 // We have to reinstantiate the `self.sp` [`ValueStackPtr`] since we just called
                 // [`ValueStack::reserve`] which might invalidate all live [`ValueStackPtr`].
@@ -341,7 +424,12 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                         self.ip = new_ip;
                     }
                 }
+                let mut batch = None;
                 if matches!(call_kind, CallKind::Nested) {
+                    if is_batched && self.is_batching_enabled() {
+                        batch =
+                            self.collect_batched_calls(first, results, len_results, max_inout)?;
+                    }
                     self.update_instr_ptr_at(1);
                 }
                 self.cache.reset();
@@ -349,11 +437,135 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                     results,
                     host_func: *func,
                     call_kind,
+                    batch,
                 })
             }
         }
     }
 
+    /// Returns `true` if calls to batched host functions may be combined.
+    ///
+    /// # Note
+    ///
+    /// Batching is disabled while tracing since it skips over call [`Instruction`]s.
+    fn is_batching_enabled(&mut self) -> bool {
+        if self.tracing {
+            return false;
+        }
+        #[cfg(feature = "debug")]
+        if self.ctx.trace_mut().is_some() {
+            return false;
+        }
+        true
+    }
+
+    /// Collects the calls to the batched host function following the host call at `first`.
+    ///
+    /// Subsequent calls are collected as long as they are adjacent calls to the same
+    /// imported function whose parameters do not read the results of collected calls.
+    /// Their parameters are copied to the end of the value stack behind the parameters
+    /// of the call at `first` and the [`InstructionPtr`] is advanced accordingly.
+    ///
+    /// Returns `None` if no subsequent call could be collected.
+    fn collect_batched_calls(
+        &mut self,
+        first: InstructionPtr,
+        results: RegisterSpan,
+        len_results: usize,
+        max_inout: usize,
+    ) -> Result<Option<HostCallBatch>, Error> {
+        let func = match *first.get() {
+            Instruction::CallImported0 { func, .. } | Instruction::CallImported { func, .. } => {
+                func
+            }
+            _ => return Ok(None),
+        };
+        let mut written = results.iter(len_results);
+        let mut len = 1;
+        while len < MAX_BATCH_LEN {
+            let mut next = self.ip;
+            next.add(1);
+            let (next_results, params) = match *next.get() {
+                Instruction::CallImported0 {
+                    results,
+                    func: next_func,
+                } if next_func == func => (results, CallParams::None),
+                Instruction::CallImported {
+                    results,
+                    func: next_func,
+                } if next_func == func => (results, CallParams::Some),
+                _ => break,
+            };
+            if matches!(params, CallParams::Some) && Self::reads_any_of(next, &written) {
+                break;
+            }
+            self.value_stack.reserve(max_inout)?;
+            let caller = self
+                .call_stack
+                .peek()
+                .expect("need to have a caller on the call stack");
+            // Safety: We use the base offset of a live call frame on the call stack.
+            self.sp = unsafe { self.value_stack.stack_ptr_at(caller.base_offset()) };
+            let offset = self.value_stack.extend_zeros(max_inout);
+            let offset_sp = unsafe { self.value_stack.stack_ptr_at(offset) };
+            self.ip = next;
+            if matches!(params, CallParams::Some) {
+                self.ip = self.copy_call_params(offset_sp);
+            }
+            written = Self::union_spans(written, next_results.iter(len_results));
+            len += 1;
+        }
+        if len == 1 {
+            return Ok(None);
+        }
+        Ok(Some(HostCallBatch { first, len }))
+    }
+
+    /// Returns `true` if the parameters of the call [`Instruction`] at `ip` read any register of `span`.
+    fn reads_any_of(mut ip: InstructionPtr, span: &RegisterSpanIter) -> bool {
+        let reads = |values: &[Register]| values.iter().any(|value| span.contains(*value));
+        ip.add(1);
+        loop {
+            match ip.get() {
+                Instruction::RegisterList(values) => {
+                    if reads(values) {
+                        return true;
+                    }
+                }
+                Instruction::RegisterSpanList { values, len } => {
+                    if values.iter_u16(*len).any(|value| span.contains(value)) {
+                        return true;
+                    }
+                }
+                Instruction::Register(value) => return reads(slice::from_ref(value)),
+                Instruction::Register2(values) => return reads(values),
+                Instruction::Register3(values) => return reads(values),
+                unexpected => {
+                    unreachable!(
+                        "unexpected Instruction found while reading call parameters: {unexpected:?}"
+                    )
+                }
+            }
+            ip.add(1);
+        }
+    }
+
+    /// Returns the smallest [`RegisterSpanIter`] containing all registers of `lhs` and `rhs`.
+    fn union_spans(lhs: RegisterSpanIter, rhs: RegisterSpanIter) -> RegisterSpanIter {
+        if lhs.is_empty() {
+            return rhs;
+        }
+        if rhs.is_empty() {
+            return lhs;
+        }
+        let start = lhs.span().head().min(rhs.span().head());
+        let end = Register::from_i16(
+            (lhs.span().head().to_i16() + lhs.len_as_u16() as i16)
+                .max(rhs.span().head().to_i16() + rhs.len_as_u16() as i16),
+        );
+        RegisterSpanIter::from_raw_parts(start, end)
+    }
+
     /// Executes an [`Instruction::CallIndirect0`].
     #[inline(always)]
     pub fn execute_return_call_indirect_0(
@@ -437,13 +649,17 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         call_kind: CallKind,
     ) -> Result<CallOutcome, Error> {
         let table = self.cache.get_table(self.ctx, table);
-        let funcref = self
-            .ctx
-            .resolve_table(&table)
+        let table = self.ctx.resolve_table(&table);
+        let funcref = table
             .get_untyped(index)
             .map(FuncRef::from)
             .ok_or(TrapCode::TableOutOfBounds)?;
-        let func = funcref.func().ok_or(TrapCode::IndirectCallToNull)?;
+        let Some(func) = funcref.func() else {
+            if table.is_uninitialized(index) {
+                return Err(Error::from(TrapCode::UninitializedElement));
+            }
+            return Err(Error::from(TrapCode::IndirectCallToNull));
+        };
         let actual_signature = self.ctx.resolve_func(func).ty_dedup();
         let expected_signature = self
             .ctx
@@ -1,5 +1,11 @@
 use super::Executor;
-use crate::{core::UntypedValue, engine::bytecode::UnaryInstr, Error};
+use crate::{
+    core::{TrapCode, UntypedValue, ValueType},
+    engine::bytecode::UnaryInstr,
+    error::TruncError,
+    value::WithType,
+    Error,
+};
 
 #[cfg(doc)]
 use crate::engine::bytecode::Instruction;
@@ -17,12 +23,12 @@ macro_rules! impl_conversion_impls {
 }
 
 macro_rules! impl_fallible_conversion_impls {
-    ( $( (Instruction::$var_name:ident, $fn_name:ident, $op:expr) ),* $(,)? ) => {
+    ( $( (Instruction::$var_name:ident, $fn_name:ident, $op:expr, $input_ty:ident => $result_ty:ident, $signed:literal) ),* $(,)? ) => {
         $(
             #[doc = concat!("Executes an [`Instruction::", stringify!($var_name), "`].")]
             #[inline(always)]
             pub fn $fn_name(&mut self, instr: UnaryInstr) -> Result<(), Error> {
-                self.try_execute_unary(instr, $op)
+                self.try_execute_trunc(instr, $op, ValueType::$input_ty, ValueType::$result_ty, $signed)
             }
         )*
     };
@@ -63,13 +69,40 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     }
 
     impl_fallible_conversion_impls! {
-        (Instruction::I32TruncF32S, execute_i32_trunc_f32_s, UntypedValue::i32_trunc_f32_s),
-        (Instruction::I32TruncF32U, execute_i32_trunc_f32_u, UntypedValue::i32_trunc_f32_u),
-        (Instruction::I32TruncF64S, execute_i32_trunc_f64_s, UntypedValue::i32_trunc_f64_s),
-        (Instruction::I32TruncF64U, execute_i32_trunc_f64_u, UntypedValue::i32_trunc_f64_u),
-        (Instruction::I64TruncF32S, execute_i64_trunc_f32_s, UntypedValue::i64_trunc_f32_s),
-        (Instruction::I64TruncF32U, execute_i64_trunc_f32_u, UntypedValue::i64_trunc_f32_u),
-        (Instruction::I64TruncF64S, execute_i64_trunc_f64_s, UntypedValue::i64_trunc_f64_s),
-        (Instruction::I64TruncF64U, execute_i64_trunc_f64_u, UntypedValue::i64_trunc_f64_u),
+        (Instruction::I32TruncF32S, execute_i32_trunc_f32_s, UntypedValue::i32_trunc_f32_s, F32 => I32, true),
+        (Instruction::I32TruncF32U, execute_i32_trunc_f32_u, UntypedValue::i32_trunc_f32_u, F32 => I32, false),
+        (Instruction::I32TruncF64S, execute_i32_trunc_f64_s, UntypedValue::i32_trunc_f64_s, F64 => I32, true),
+        (Instruction::I32TruncF64U, execute_i32_trunc_f64_u, UntypedValue::i32_trunc_f64_u, F64 => I32, false),
+        (Instruction::I64TruncF32S, execute_i64_trunc_f32_s, UntypedValue::i64_trunc_f32_s, F32 => I64, true),
+        (Instruction::I64TruncF32U, execute_i64_trunc_f32_u, UntypedValue::i64_trunc_f32_u, F32 => I64, false),
+        (Instruction::I64TruncF64S, execute_i64_trunc_f64_s, UntypedValue::i64_trunc_f64_s, F64 => I64, true),
+        (Instruction::I64TruncF64U, execute_i64_trunc_f64_u, UntypedValue::i64_trunc_f64_u, F64 => I64, false),
+    }
+
+    /// Executes a fallible Wasm float-to-integer truncation [`Instruction`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TruncError`] holding the offending input if the truncation traps.
+    #[inline(always)]
+    fn try_execute_trunc(
+        &mut self,
+        instr: UnaryInstr,
+        op: fn(UntypedValue) -> Result<UntypedValue, TrapCode>,
+        input_ty: ValueType,
+        result_ty: ValueType,
+        signed: bool,
+    ) -> Result<(), Error> {
+        let input = self.get_register(instr.input);
+        match op(input) {
+            Ok(result) => self.set_register(instr.result, result),
+            Err(trap_code) => {
+                let input = input.with_type(input_ty);
+                return Err(Error::from(TruncError::new(
+                    trap_code, input, result_ty, signed,
+                )));
+            }
+        }
+        self.try_next_instr()
     }
 }
@@ -3,7 +3,7 @@ use crate::{
     core::UntypedValue,
     engine::bytecode::{AnyConst32, Const32, Instruction, Register, RegisterSpan},
 };
-use core::slice;
+use core::{array, slice};
 use smallvec::SmallVec;
 
 impl<'ctx, 'engine> Executor<'ctx, 'engine> {
@@ -61,6 +61,14 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         })
     }
 
+    /// Executes an [`Instruction::CopyI64Imm`] or [`Instruction::CopyF64Imm`].
+    #[inline(always)]
+    pub fn execute_copy_imm64(&mut self, result: Register) {
+        let value = self.fetch_const64(1);
+        self.set_register(result, value);
+        self.next_instr_at(3)
+    }
+
     /// Executes an [`Instruction::CopySpan`].
     ///
     /// # Note
@@ -104,6 +112,48 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         self.next_instr();
     }
 
+    /// Executes an [`Instruction::CopySpanSmall`].
+    ///
+    /// # Note
+    ///
+    /// Dispatches to an unrolled copy routine for each of the supported lengths.
+    #[inline(always)]
+    pub fn execute_copy_span_small(
+        &mut self,
+        results: RegisterSpan,
+        values: RegisterSpan,
+        len: u16,
+    ) {
+        match len {
+            0 => {}
+            1 => self.execute_copy_span_small_impl::<1>(results, values),
+            2 => self.execute_copy_span_small_impl::<2>(results, values),
+            3 => self.execute_copy_span_small_impl::<3>(results, values),
+            4 => self.execute_copy_span_small_impl::<4>(results, values),
+            _ => unreachable!("encountered Instruction::CopySpanSmall with invalid length: {len}"),
+        }
+        self.next_instr();
+    }
+
+    /// Copies `N` contiguous `values` into `results`.
+    ///
+    /// All `values` are read before any `results` are written so that
+    /// `results` and `values` are allowed to overlap.
+    #[inline(always)]
+    fn execute_copy_span_small_impl<const N: usize>(
+        &mut self,
+        results: RegisterSpan,
+        values: RegisterSpan,
+    ) {
+        let nth = |span: RegisterSpan, n: usize| {
+            Register::from_i16(span.head().to_i16().wrapping_add(n as i16))
+        };
+        let tmp: [UntypedValue; N] = array::from_fn(|n| self.get_register(nth(values, n)));
+        for (n, value) in tmp.into_iter().enumerate() {
+            self.set_register(nth(results, n), value);
+        }
+    }
+
     /// Executes an [`Instruction::CopyMany`].
     #[inline(always)]
     pub fn execute_copy_many(&mut self, results: RegisterSpan, values: [Register; 2]) {
@@ -112,8 +162,16 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         let mut ip = self.ip;
         tmp.extend(values.into_iter().map(|value| self.get_register(value)));
         ip.add(1);
-        while let Instruction::RegisterList(values) = ip.get() {
-            tmp.extend(values.iter().map(|value| self.get_register(*value)));
+        loop {
+            match ip.get() {
+                Instruction::RegisterList(values) => {
+                    tmp.extend(values.iter().map(|value| self.get_register(*value)));
+                }
+                Instruction::RegisterSpanList { values, len } => {
+                    tmp.extend(values.iter_u16(*len).map(|value| self.get_register(value)));
+                }
+                _ => break,
+            }
             ip.add(1);
         }
         let values = match ip.get() {
@@ -148,8 +206,16 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         };
         copy_values(&values);
         ip.add(1);
-        while let Instruction::RegisterList(values) = ip.get() {
-            copy_values(values);
+        loop {
+            match ip.get() {
+                Instruction::RegisterList(values) => copy_values(values),
+                Instruction::RegisterSpanList { values, len } => {
+                    for value in values.iter_u16(*len) {
+                        copy_values(slice::from_ref(&value));
+                    }
+                }
+                _ => break,
+            }
             ip.add(1);
         }
         let values = match ip.get() {
@@ -37,7 +37,21 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
 
     /// Executes a generic `global.set` instruction.
     fn execute_global_set_impl(&mut self, global: GlobalIdx, new_value: UntypedValue) {
+        if self.ctx.has_global_watches() {
+            self.notify_global_watches(global, new_value);
+        }
         self.cache.set_global(self.ctx, global, new_value);
         self.next_instr()
     }
+
+    /// Notifies the registered watches of the global variable at `index` about its `new_value`.
+    #[cold]
+    fn notify_global_watches(&mut self, index: GlobalIdx, new_value: UntypedValue) {
+        let global = self
+            .ctx
+            .resolve_instance(self.cache.instance())
+            .get_global(index.to_u32())
+            .unwrap_or_else(|| unreachable!("missing global variable at index {index:?}"));
+        self.ctx.notify_global_watches(&global, new_value);
+    }
 }
@@ -1,7 +1,14 @@
 use super::Executor;
 use crate::{
     core::{TrapCode, UntypedValue},
-    engine::bytecode::{LoadAtInstr, LoadInstr, LoadOffset16Instr, Register},
+    engine::bytecode::{
+        LoadAtInstr,
+        LoadInstr,
+        LoadOffset16Instr,
+        LoadOpInstr,
+        MemoryIdx,
+        Register,
+    },
     Error,
 };
 
@@ -26,15 +33,18 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     /// - `{i32, i64}.load16_u`
     /// - `i64.load32_s`
     /// - `i64.load32_u`
+    ///
+    /// Operates on the default linear memory if `memory` is `None`.
     #[inline(always)]
     fn execute_load_extend(
         &mut self,
+        memory: Option<MemoryIdx>,
         result: Register,
         address: UntypedValue,
         offset: u32,
         load_extend: WasmLoadOp,
     ) -> Result<(), Error> {
-        let memory = self.cache.default_memory_bytes(self.ctx);
+        let memory = self.cache.memory_bytes(self.ctx, memory);
         let loaded_value = load_extend(memory, address, offset)?;
         self.set_register(result, loaded_value);
         Ok(())
@@ -47,8 +57,9 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         load_extend: WasmLoadOp,
     ) -> Result<(), Error> {
         let offset = self.fetch_address_offset(1);
+        let memory = self.fetch_optional_memory(2);
         let address = self.get_register(instr.ptr);
-        self.execute_load_extend(instr.result, address, offset, load_extend)?;
+        self.execute_load_extend(memory, instr.result, address, offset, load_extend)?;
         self.try_next_instr_at(2)
     }
 
@@ -59,7 +70,13 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         load_extend: WasmLoadOp,
     ) -> Result<(), Error> {
         let offset = u32::from(instr.address);
-        self.execute_load_extend(instr.result, UntypedValue::from(0u32), offset, load_extend)?;
+        self.execute_load_extend(
+            None,
+            instr.result,
+            UntypedValue::from(0u32),
+            offset,
+            load_extend,
+        )?;
         self.try_next_instr()
     }
 
@@ -71,9 +88,30 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     ) -> Result<(), Error> {
         let offset = u32::from(instr.offset);
         let address = self.get_register(instr.ptr);
-        self.execute_load_extend(instr.result, address, offset, load_extend)?;
+        self.execute_load_extend(None, instr.result, address, offset, load_extend)?;
         self.try_next_instr()
     }
+
+    /// Executes a generic fused `load` and binary operation [`Instruction`].
+    ///
+    /// # Note
+    ///
+    /// The `load` is executed first so that its out of bounds trap
+    /// takes place before the binary operation is evaluated.
+    fn execute_load_op_impl(
+        &mut self,
+        instr: LoadOpInstr,
+        load_extend: WasmLoadOp,
+        op: fn(UntypedValue, UntypedValue) -> UntypedValue,
+    ) -> Result<(), Error> {
+        let offset = self.fetch_address_offset(1);
+        let address = self.get_register(instr.ptr);
+        let memory = self.cache.default_memory_bytes(self.ctx);
+        let loaded_value = load_extend(memory, address, offset)?;
+        let operand = self.get_register(instr.operand);
+        self.set_register(instr.result, op(operand, loaded_value));
+        self.try_next_instr_at(2)
+    }
 }
 
 macro_rules! impl_execute_load {
@@ -197,3 +235,30 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         ),
     }
 }
+
+macro_rules! impl_execute_load_op {
+    ( $( (Instruction::$var_name:ident, $fn_name:ident, $load:expr, $op:expr) ),* $(,)? ) => {
+        $(
+            #[doc = concat!("Executes an [`Instruction::", stringify!($var_name), "`].")]
+            #[inline(always)]
+            pub fn $fn_name(&mut self, instr: LoadOpInstr) -> Result<(), Error> {
+                self.execute_load_op_impl(instr, $load, $op)
+            }
+        )*
+    }
+}
+
+impl<'ctx, 'engine> Executor<'ctx, 'engine> {
+    impl_execute_load_op! {
+        (Instruction::I32AddLoad, execute_i32_add_load, UntypedValue::i32_load, UntypedValue::i32_add),
+        (Instruction::I32SubLoad, execute_i32_sub_load, UntypedValue::i32_load, UntypedValue::i32_sub),
+        (Instruction::I32AndLoad, execute_i32_and_load, UntypedValue::i32_load, UntypedValue::i32_and),
+        (Instruction::I32OrLoad, execute_i32_or_load, UntypedValue::i32_load, UntypedValue::i32_or),
+        (Instruction::I32XorLoad, execute_i32_xor_load, UntypedValue::i32_load, UntypedValue::i32_xor),
+        (Instruction::I64AddLoad, execute_i64_add_load, UntypedValue::i64_load, UntypedValue::i64_add),
+        (Instruction::I64SubLoad, execute_i64_sub_load, UntypedValue::i64_load, UntypedValue::i64_sub),
+        (Instruction::I64AndLoad, execute_i64_and_load, UntypedValue::i64_load, UntypedValue::i64_and),
+        (Instruction::I64OrLoad, execute_i64_or_load, UntypedValue::i64_load, UntypedValue::i64_or),
+        (Instruction::I64XorLoad, execute_i64_xor_load, UntypedValue::i64_load, UntypedValue::i64_xor),
+    }
+}
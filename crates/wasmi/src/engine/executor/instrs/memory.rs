@@ -4,7 +4,7 @@ use super::Executor;
 use crate::{
     core::TrapCode,
     engine::{
-        bytecode::{Const16, DataSegmentIdx, Instruction, Register},
+        bytecode::{Const16, DataSegmentIdx, Instruction, MemoryIdx, Register},
         code_map::InstructionPtr,
     },
     error::EntityGrowError,
@@ -36,8 +36,14 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     /// Executes an [`Instruction::MemorySize`].
     #[inline(always)]
     pub fn execute_memory_size(&mut self, result: Register) {
-        let memory = self.cache.default_memory(self.ctx);
-        let size: u32 = self.ctx.resolve_memory(memory).current_pages().into();
+        let memory = self.fetch_optional_memory(1);
+        self.execute_memory_size_impl(memory, result)
+    }
+
+    /// Executes a generic `memory.size` instruction.
+    fn execute_memory_size_impl(&mut self, memory: Option<MemoryIdx>, result: Register) {
+        let memory = self.cache.get_memory(self.ctx, memory);
+        let size: u32 = self.ctx.resolve_memory(&memory).current_pages().into();
         self.set_register(result, size);
         self.next_instr()
     }
@@ -73,9 +79,10 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         delta: u32,
         resource_limiter: &mut ResourceLimiterRef<'ctx>,
     ) -> Result<(), Error> {
+        let memory = self.fetch_optional_memory(1);
         if delta == 0 {
             // Case: growing by 0 pages means there is nothing to do
-            self.execute_memory_size(result);
+            self.execute_memory_size_impl(memory, result);
             return Ok(());
         }
         let delta = match Pages::new(delta) {
@@ -92,17 +99,24 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                 costs.fuel_for_bytes(delta_in_bytes)
             },
             |this| {
-                let memory = this.cache.default_memory(this.ctx);
-                let new_pages = this
-                    .ctx
-                    .resolve_memory_mut(memory)
-                    .grow(delta, resource_limiter)
-                    .map(u32::from)?;
+                let max_pages = this.ctx.engine().config().get_max_memory_pages();
+                let memory = this.cache.get_memory(this.ctx, memory);
+                let entity = this.ctx.resolve_memory_mut(&memory);
+                if let Some(max_pages) = max_pages {
+                    let current_pages = u32::from(entity.current_pages());
+                    if current_pages.saturating_add(u32::from(delta)) > max_pages {
+                        // Case: growing the memory would exceed the limit set by the `Config`.
+                        return Err(EntityGrowError::InvalidGrow);
+                    }
+                }
+                let old_pages = entity.grow(delta, resource_limiter)?;
+                let new_pages = entity.current_pages();
                 // The `memory.grow` operation might have invalidated the cached
                 // linear memory so we need to reset it in order for the cache to
                 // reload in case it is used again.
                 this.cache.reset_default_memory_bytes();
-                Ok(new_pages)
+                this.ctx.notify_memory_grow(memory, old_pages, new_pages);
+                Ok(u32::from(old_pages))
             },
         );
         let return_value = match return_value {
@@ -233,22 +247,34 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         src_index: u32,
         len: u32,
     ) -> Result<(), Error> {
+        // Note: The `dst` and `src` memory parameters are either both encoded or both missing.
+        let dst_memory = self.fetch_optional_memory(1);
+        let src_memory = match dst_memory {
+            Some(_) => self.fetch_optional_memory(1),
+            None => None,
+        };
         self.consume_fuel_with::<_, Error>(
             |costs| costs.fuel_for_bytes(u64::from(len)),
             |this| {
                 let len = len as usize;
                 let src_index = src_index as usize;
                 let dst_index = dst_index as usize;
-                let data = this.cache.default_memory_bytes(this.ctx);
-                // These accesses just perform the bounds checks required by the Wasm spec.
-                data.get(src_index..)
-                    .and_then(|memory| memory.get(..len))
-                    .ok_or(TrapCode::MemoryOutOfBounds)?;
-                data.get(dst_index..)
-                    .and_then(|memory| memory.get(..len))
-                    .ok_or(TrapCode::MemoryOutOfBounds)?;
-                data.copy_within(src_index..src_index.wrapping_add(len), dst_index);
-                Ok(())
+                if dst_memory == src_memory {
+                    let data = this.cache.memory_bytes(this.ctx, dst_memory);
+                    return memory_copy_within(data, dst_index, src_index, len);
+                }
+                let dst = this.cache.get_memory(this.ctx, dst_memory);
+                let src = this.cache.get_memory(this.ctx, src_memory);
+                match this.ctx.resolve_memory_pair_mut(&dst, &src) {
+                    Some((dst, src)) => {
+                        memory_copy(dst.data_mut(), dst_index, src.data(), src_index, len)
+                    }
+                    None => {
+                        // Case: both memory indices refer to the same imported linear memory.
+                        let data = this.ctx.resolve_memory_mut(&dst).data_mut();
+                        memory_copy_within(data, dst_index, src_index, len)
+                    }
+                }
             },
         )?;
         self.try_next_instr()
@@ -364,6 +390,7 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
 
     /// Executes a generic `memory.fill` instruction.
     fn execute_memory_fill_impl(&mut self, dst: u32, value: u8, len: u32) -> Result<(), Error> {
+        let memory = self.fetch_optional_memory(1);
         self.consume_fuel_with::<_, Error>(
             |costs| costs.fuel_for_bytes(u64::from(len)),
             |this| {
@@ -371,7 +398,7 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
                 let len = len as usize;
                 let memory = this
                     .cache
-                    .default_memory_bytes(this.ctx)
+                    .memory_bytes(this.ctx, memory)
                     .get_mut(dst..)
                     .and_then(|memory| memory.get_mut(..len))
                     .ok_or(TrapCode::MemoryOutOfBounds)?;
@@ -496,16 +523,20 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
 
     /// Executes a generic `memory.init` instruction.
     fn execute_memory_init_impl(&mut self, dst: u32, src: u32, len: u32) -> Result<(), Error> {
+        let data_index: DataSegmentIdx = self.fetch_data_segment_index(1);
+        let memory = self.fetch_optional_memory(2);
         self.consume_fuel_with::<_, Error>(
             |costs| costs.fuel_for_bytes(u64::from(len)),
             |this| {
                 let dst_index = dst as usize;
                 let src_index = src as usize;
                 let len = len as usize;
-                let data_index: DataSegmentIdx = this.fetch_data_segment_index(1);
                 let (memory, data) = this
                     .cache
-                    .get_default_memory_and_data_segment(this.ctx, data_index);
+                    .get_memory_and_data_segment(this.ctx, memory, data_index);
+                // Note: The Wasm spec mandates bounds checks for `dst` and `src`
+                //       even if `len` is zero. Offsets that are equal to the length
+                //       of the memory or data segment are valid in this case.
                 let memory = memory
                     .get_mut(dst_index..)
                     .and_then(|memory| memory.get_mut(..len))
@@ -521,3 +552,49 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         self.try_next_instr_at(2)
     }
 }
+
+/// Copies `len` bytes from `data[src_index..]` to `data[dst_index..]`.
+///
+/// # Errors
+///
+/// If either of the accessed ranges is out of bounds.
+fn memory_copy_within(
+    data: &mut [u8],
+    dst_index: usize,
+    src_index: usize,
+    len: usize,
+) -> Result<(), Error> {
+    // These accesses just perform the bounds checks required by the Wasm spec.
+    data.get(src_index..)
+        .and_then(|memory| memory.get(..len))
+        .ok_or(TrapCode::MemoryOutOfBounds)?;
+    data.get(dst_index..)
+        .and_then(|memory| memory.get(..len))
+        .ok_or(TrapCode::MemoryOutOfBounds)?;
+    data.copy_within(src_index..src_index.wrapping_add(len), dst_index);
+    Ok(())
+}
+
+/// Copies `len` bytes from `src[src_index..]` to `dst[dst_index..]`.
+///
+/// # Errors
+///
+/// If either of the accessed ranges is out of bounds.
+fn memory_copy(
+    dst: &mut [u8],
+    dst_index: usize,
+    src: &[u8],
+    src_index: usize,
+    len: usize,
+) -> Result<(), Error> {
+    let src = src
+        .get(src_index..)
+        .and_then(|memory| memory.get(..len))
+        .ok_or(TrapCode::MemoryOutOfBounds)?;
+    let dst = dst
+        .get_mut(dst_index..)
+        .and_then(|memory| memory.get_mut(..len))
+        .ok_or(TrapCode::MemoryOutOfBounds)?;
+    dst.copy_from_slice(src);
+    Ok(())
+}
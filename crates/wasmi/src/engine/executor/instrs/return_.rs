@@ -155,6 +155,12 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         self.execute_return_value(value, |_, value| f64::from(value).into())
     }
 
+    /// Execute an [`Instruction::ReturnI64Imm`] or [`Instruction::ReturnF64Imm`] returning a single 64-bit value.
+    #[inline(always)]
+    pub fn execute_return_imm64(&mut self) -> ReturnOutcome {
+        self.execute_return_value((), |this, _| this.fetch_const64(1))
+    }
+
     /// Execute an [`Instruction::ReturnSpan`] returning many values.
     #[inline(always)]
     pub fn execute_return_span(&mut self, values: RegisterSpanIter) -> ReturnOutcome {
@@ -196,8 +202,16 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         copy_results(values);
         let mut ip = self.ip;
         ip.add(1);
-        while let Instruction::RegisterList(values) = ip.get() {
-            copy_results(values);
+        loop {
+            match ip.get() {
+                Instruction::RegisterList(values) => copy_results(values),
+                Instruction::RegisterSpanList { values, len } => {
+                    for value in values.iter_u16(*len) {
+                        copy_results(slice::from_ref(&value));
+                    }
+                }
+                _ => break,
+            }
             ip.add(1);
         }
         let values = match ip.get() {
@@ -1,6 +1,6 @@
 use super::Executor;
 use crate::engine::{
-    bytecode::{AnyConst32, Const32, Instruction, Register},
+    bytecode::{AnyConst32, BinInstr, Const32, Instruction, Register},
     code_map::InstructionPtr,
 };
 use wasmi_core::UntypedValue;
@@ -23,6 +23,22 @@ macro_rules! fetch_select_imm_param {
     }};
 }
 
+/// Implements the execution of fused `cmp+select` instructions.
+macro_rules! impl_select_cmp {
+    ( $( (Instruction::$var_name:ident, $fn_name:ident, $op:expr) ),* $(,)? ) => {
+        $(
+            #[doc = concat!("Executes an [`Instruction::", stringify!($var_name), "`].")]
+            #[inline(always)]
+            pub fn $fn_name(&mut self, instr: BinInstr) {
+                let lhs = self.get_register(instr.lhs);
+                let rhs = self.get_register(instr.rhs);
+                let condition = bool::from($op(lhs, rhs));
+                self.execute_select_cmp_impl(instr.result, condition)
+            }
+        )*
+    };
+}
+
 impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     /// Returns the parameter of [`Instruction::Select`] or [`Instruction::SelectRev`] as [`UntypedValue`].
     fn fetch_select_param(&self) -> UntypedValue {
@@ -76,6 +92,17 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         })
     }
 
+    /// Executes an [`Instruction::SelectImm`].
+    pub fn execute_select_imm(&mut self, result: Register, condition: Register, lhs: i8, rhs: i8) {
+        let condition: bool = self.get_register_as(condition);
+        let selected = match condition {
+            true => lhs,
+            false => rhs,
+        };
+        self.set_register(result, i32::from(selected));
+        self.next_instr();
+    }
+
     /// Executes an [`Instruction::SelectImm32`].
     pub fn execute_select_imm32(&mut self, result: Register, lhs: AnyConst32) {
         let (condition, rhs) = fetch_select_imm_param!(self, SelectImm32);
@@ -93,4 +120,38 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         let (condition, rhs) = fetch_select_imm_param!(self, SelectF64Imm32);
         self.execute_select_impl(result, condition, |_| f64::from(lhs), |_| f64::from(rhs))
     }
+
+    /// Executes a fused `cmp+select` instruction given the evaluated `condition`.
+    fn execute_select_cmp_impl(&mut self, result: Register, condition: bool) {
+        let mut addr: InstructionPtr = self.ip;
+        addr.add(1);
+        let [true_val, false_val] = match *addr.get() {
+            Instruction::Register2(values) => values,
+            unexpected => unreachable!(
+                "expected Instruction::Register2 for fused cmp+select but found {unexpected:?}"
+            ),
+        };
+        let selected = match condition {
+            true => self.get_register(true_val),
+            false => self.get_register(false_val),
+        };
+        self.set_register(result, selected);
+        self.next_instr_at(2);
+    }
+
+    impl_select_cmp! {
+        (Instruction::SelectI32Eq, execute_select_i32_eq, UntypedValue::i32_eq),
+        (Instruction::SelectI32Ne, execute_select_i32_ne, UntypedValue::i32_ne),
+        (Instruction::SelectI32LtS, execute_select_i32_lt_s, UntypedValue::i32_lt_s),
+        (Instruction::SelectI32LtU, execute_select_i32_lt_u, UntypedValue::i32_lt_u),
+        (Instruction::SelectI32LeS, execute_select_i32_le_s, UntypedValue::i32_le_s),
+        (Instruction::SelectI32LeU, execute_select_i32_le_u, UntypedValue::i32_le_u),
+
+        (Instruction::SelectI64Eq, execute_select_i64_eq, UntypedValue::i64_eq),
+        (Instruction::SelectI64Ne, execute_select_i64_ne, UntypedValue::i64_ne),
+        (Instruction::SelectI64LtS, execute_select_i64_lt_s, UntypedValue::i64_lt_s),
+        (Instruction::SelectI64LtU, execute_select_i64_lt_u, UntypedValue::i64_lt_u),
+        (Instruction::SelectI64LeS, execute_select_i64_le_s, UntypedValue::i64_le_s),
+        (Instruction::SelectI64LeU, execute_select_i64_le_u, UntypedValue::i64_le_u),
+    }
 }
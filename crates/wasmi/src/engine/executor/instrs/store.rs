@@ -2,7 +2,15 @@ use super::Executor;
 use crate::{
     core::{TrapCode, UntypedValue},
     engine::{
-        bytecode::{Const16, Instruction, Register, StoreAtInstr, StoreInstr, StoreOffset16Instr},
+        bytecode::{
+            Const16,
+            Instruction,
+            MemoryIdx,
+            Register,
+            StoreAtInstr,
+            StoreInstr,
+            StoreOffset16Instr,
+        },
         code_map::InstructionPtr,
     },
     Error,
@@ -37,22 +45,27 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
     /// - `{i32, i64}.store8`
     /// - `{i32, i64}.store16`
     /// - `i64.store32`
+    ///
+    /// Operates on the default linear memory if `memory` is `None`.
     #[inline(always)]
     fn execute_store_wrap(
         &mut self,
+        memory: Option<MemoryIdx>,
         address: UntypedValue,
         offset: u32,
         value: UntypedValue,
         store_wrap: WasmStoreOp,
     ) -> Result<(), Error> {
-        let memory = self.cache.default_memory_bytes(self.ctx);
+        let memory = self.cache.memory_bytes(self.ctx, memory);
         store_wrap(memory, address, offset, value)?;
         Ok(())
     }
 
     fn execute_store(&mut self, instr: StoreInstr, store_op: WasmStoreOp) -> Result<(), Error> {
         let value = self.fetch_store_value(1);
+        let memory = self.fetch_optional_memory(2);
         self.execute_store_wrap(
+            memory,
             self.get_register(instr.ptr),
             u32::from(instr.offset),
             self.get_register(value),
@@ -67,6 +80,7 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         store_op: WasmStoreOp,
     ) -> Result<(), Error> {
         self.execute_store_wrap(
+            None,
             self.get_register(instr.ptr),
             u32::from(instr.offset),
             self.get_register(instr.value),
@@ -84,6 +98,7 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         T: From<V> + Into<UntypedValue>,
     {
         self.execute_store_wrap(
+            None,
             self.get_register(instr.ptr),
             u32::from(instr.offset),
             T::from(instr.value).into(),
@@ -98,6 +113,7 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         store_op: WasmStoreOp,
     ) -> Result<(), Error> {
         self.execute_store_wrap(
+            None,
             UntypedValue::from(0u32),
             u32::from(instr.address),
             self.get_register(instr.value),
@@ -115,6 +131,7 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         T: From<V> + Into<UntypedValue>,
     {
         self.execute_store_wrap(
+            None,
             UntypedValue::from(0u32),
             u32::from(instr.address),
             T::from(instr.value).into(),
@@ -506,11 +506,17 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         let return_value = self.consume_fuel_with(
             |costs| costs.fuel_for_copies(u64::from(delta)),
             |this| {
+                let max_elements = this.ctx.engine().config().get_max_table_elements();
                 let table = this.cache.get_table(this.ctx, table_index);
                 let value = this.get_register(value);
-                this.ctx
-                    .resolve_table_mut(&table)
-                    .grow_untyped(delta, value, resource_limiter)
+                let table = this.ctx.resolve_table_mut(&table);
+                if let Some(max_elements) = max_elements {
+                    if table.size().saturating_add(delta) > max_elements {
+                        // Case: growing the table would exceed the limit set by the `Config`.
+                        return Err(EntityGrowError::InvalidGrow);
+                    }
+                }
+                table.grow_untyped(delta, value, resource_limiter)
             },
         );
         let return_value = match return_value {
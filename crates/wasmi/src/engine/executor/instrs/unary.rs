@@ -41,5 +41,8 @@ impl<'ctx, 'engine> Executor<'ctx, 'engine> {
         (Instruction::F64Trunc, execute_f64_trunc, UntypedValue::f64_trunc),
         (Instruction::F64Nearest, execute_f64_nearest, UntypedValue::f64_nearest),
         (Instruction::F64Sqrt, execute_f64_sqrt, UntypedValue::f64_sqrt),
+
+        (Instruction::F32CanonicalizeNan, execute_f32_canonicalize_nan, UntypedValue::f32_canonicalize_nan),
+        (Instruction::F64CanonicalizeNan, execute_f64_canonicalize_nan, UntypedValue::f64_canonicalize_nan),
     }
 }
@@ -1,6 +1,6 @@
 pub(crate) use self::stack::Stack;
 use self::{
-    instrs::{execute_instrs, CallKind, WasmOutcome},
+    instrs::{execute_instrs, CallKind, HostCallBatch, WasmOutcome},
     stack::CallFrame,
     trap::TaggedTrap,
 };
@@ -25,7 +25,9 @@ use crate::{
     FuncEntity,
     Instance,
     StoreContextMut,
+    UnreachableAction,
 };
+use wasmi_core::TrapCode;
 
 #[cfg(doc)]
 use crate::{engine::StackLimits, Store};
@@ -195,6 +197,9 @@ impl<'engine> EngineExecutor<'engine> {
         Results: CallResults,
     {
         self.stack.reset();
+        self.stack
+            .calls
+            .set_base_depth(ctx.as_context().store.inner.call_depth());
         match ctx.as_context().store.inner.resolve_func(func) {
             FuncEntity::Wasm(wasm_func) => {
                 // We reserve space on the stack to write the results of the root function execution.
@@ -216,6 +221,7 @@ impl<'engine> EngineExecutor<'engine> {
                     base_ptr,
                     RegisterSpan::new(Register::from_i16(0)),
                     *instance,
+                    wasm_func.func_body(),
                 ))?;
                 self.execute_func(ctx.as_context_mut())?;
             }
@@ -239,7 +245,9 @@ impl<'engine> EngineExecutor<'engine> {
                     *value = param;
                 }
                 let host_func = *host_func;
-                self.dispatch_host_func(ctx.as_context_mut(), host_func, HostFuncCaller::Root)?;
+                self.catch_host_panic(ctx.as_context_mut(), |this, ctx| {
+                    this.dispatch_host_func(ctx, host_func, HostFuncCaller::Root)
+                })??;
             }
         };
         let results = self.write_results_back(results);
@@ -271,6 +279,7 @@ impl<'engine> EngineExecutor<'engine> {
             .calls
             .peek()
             .expect("must have caller call frame on stack upon function resumption");
+        let base_depth = ctx.as_context().store.inner.call_depth();
         let mut caller_sp = unsafe { self.stack.values.stack_ptr_at(caller.base_offset()) };
         let call_params = params.call_params();
         let len_params = call_params.len();
@@ -278,6 +287,7 @@ impl<'engine> EngineExecutor<'engine> {
             let cell = unsafe { caller_sp.get_mut(result) };
             *cell = param;
         }
+        self.stack.calls.set_base_depth(base_depth);
         self.execute_func(ctx.as_context_mut())?;
         let results = self.write_results_back(results);
         Ok(results)
@@ -308,6 +318,7 @@ impl<'engine> EngineExecutor<'engine> {
                     results,
                     ref host_func,
                     call_kind,
+                    batch,
                 } => {
                     let instance = *self
                         .stack
@@ -315,7 +326,20 @@ impl<'engine> EngineExecutor<'engine> {
                         .peek()
                         .expect("caller must be on the stack")
                         .instance();
-                    self.execute_host_func(&mut ctx, results, host_func, &instance, call_kind)?;
+                    match batch {
+                        Some(batch) => {
+                            self.execute_host_func_batch(&mut ctx, host_func, &instance, batch)?
+                        }
+                        None => self.execute_host_func(
+                            &mut ctx, results, host_func, &instance, call_kind,
+                        )?,
+                    }
+                }
+                WasmOutcome::Unreachable => {
+                    if self.execute_unreachable(&mut ctx, &mut cache)? {
+                        // In this case the root function has returned via the unreachable hook.
+                        return Ok(());
+                    }
                 }
             }
         }
@@ -335,11 +359,19 @@ impl<'engine> EngineExecutor<'engine> {
             }
             FuncEntity::Host(host_func) => *host_func,
         };
-        let result = self.dispatch_host_func(
-            ctx.as_context_mut(),
-            func_entity,
-            HostFuncCaller::wasm(results, instance),
-        );
+        // The host function observes the call frames of this execution as
+        // call stack depth and may re-enter Wasm execution on top of them.
+        let outer_depth = ctx.as_context().store.inner.call_depth();
+        ctx.as_context_mut()
+            .store
+            .inner
+            .set_call_depth(self.stack.calls.depth());
+        let result = self.catch_host_panic(ctx.as_context_mut(), |this, ctx| {
+            this.dispatch_host_func(ctx, func_entity, HostFuncCaller::wasm(results, instance))
+        });
+        ctx.as_context_mut().store.inner.set_call_depth(outer_depth);
+        // Note: Host function panics are not resumable.
+        let result = result.map_err(TaggedTrap::Wasm)?;
         if matches!(call_kind, CallKind::Tail) {
             self.stack.calls.pop();
         }
@@ -359,6 +391,170 @@ impl<'engine> EngineExecutor<'engine> {
         }
         Ok(())
     }
+
+    /// Executes the adjacent calls of `batch` to the batched host function `func` at once.
+    ///
+    /// # Note
+    ///
+    /// Errors of batched host function calls are not resumable since it is
+    /// ambiguous which of the batched calls failed.
+    fn execute_host_func_batch<T>(
+        &mut self,
+        ctx: &mut StoreContextMut<'_, T>,
+        func: &Func,
+        instance: &Instance,
+        batch: HostCallBatch,
+    ) -> Result<(), TaggedTrap> {
+        let func_entity = match ctx.as_context().store.inner.resolve_func(func) {
+            FuncEntity::Wasm(wasm_func) => {
+                unreachable!("expected a host function but found: {wasm_func:?}")
+            }
+            FuncEntity::Host(host_func) => *host_func,
+        };
+        let outer_depth = ctx.as_context().store.inner.call_depth();
+        ctx.as_context_mut()
+            .store
+            .inner
+            .set_call_depth(self.stack.calls.depth());
+        let result = self.catch_host_panic(ctx.as_context_mut(), |this, ctx| {
+            this.dispatch_host_func_batch(ctx, func_entity, instance, batch)
+        });
+        ctx.as_context_mut().store.inner.set_call_depth(outer_depth);
+        result.and_then(|result| result).map_err(TaggedTrap::Wasm)
+    }
+
+    /// Calls the batch trampoline of `host_func` for all calls of `batch`.
+    ///
+    /// The results of each call are written back to the result registers of its caller.
+    fn dispatch_host_func_batch<T>(
+        &mut self,
+        ctx: StoreContextMut<T>,
+        host_func: HostFuncEntity,
+        instance: &Instance,
+        batch: HostCallBatch,
+    ) -> Result<(), Error> {
+        let (input_types, output_types) = self
+            .res
+            .func_types
+            .resolve_func_type(host_func.ty_dedup())
+            .params_results();
+        let len_outputs = output_types.len();
+        let max_inout = input_types.len().max(len_outputs);
+        let len_batch = max_inout * batch.len();
+        let values = self.stack.values.as_slice_mut();
+        let params_results = values.split_at_mut(values.len() - len_batch).1;
+        let trampoline = ctx
+            .as_context()
+            .store
+            .resolve_trampoline(host_func.trampoline())
+            .clone();
+        if let Err(error) = trampoline.call_batch(ctx, Some(instance), params_results, batch.len())
+        {
+            self.stack.values.drop(len_batch);
+            return Err(error);
+        }
+        let caller_offset = self
+            .stack
+            .calls
+            .peek()
+            .expect("caller must be on the stack")
+            .base_offset();
+        // Safety: The batched calls have been issued by the caller frame whose
+        //         result registers are valid due to Wasm validation and translation.
+        let mut caller_sp = unsafe { self.stack.values.stack_ptr_at(caller_offset) };
+        // Safety: The chunks of all batched calls reside at the end of the value stack.
+        let callee_sp = unsafe { self.stack.values.stack_ptr_last_n(len_batch) };
+        let mut chunk = 0;
+        batch.for_each_results(|results| {
+            let values = RegisterSpan::new(Register::from_i16(chunk)).iter(len_outputs);
+            for (result, value) in results.iter(len_outputs).zip(values) {
+                // Safety: See above.
+                let result_cell = unsafe { caller_sp.get_mut(result) };
+                // Safety: See above.
+                let value_cell = unsafe { callee_sp.get(value) };
+                *result_cell = value_cell;
+            }
+            chunk += max_inout as i16;
+        });
+        self.stack.values.drop(len_batch);
+        Ok(())
+    }
+
+    /// Handles the execution of a Wasm `unreachable` instruction via the unreachable hook of the [`Store`].
+    ///
+    /// Returns `true` if the root function returned to the host.
+    ///
+    /// # Note
+    ///
+    /// Upon [`UnreachableAction::ContinueWith`] the Wasm function that executed the
+    /// `unreachable` instruction returns the provided values to its caller.
+    ///
+    /// # Errors
+    ///
+    /// - If the unreachable hook returns [`UnreachableAction::Trap`] or if there is no hook.
+    /// - If the values provided via [`UnreachableAction::ContinueWith`] do not match
+    ///   the result types of the returning Wasm function.
+    #[cold]
+    fn execute_unreachable<T>(
+        &mut self,
+        ctx: &mut StoreContextMut<'_, T>,
+        cache: &mut InstanceCache,
+    ) -> Result<bool, Error> {
+        let frame = self
+            .stack
+            .calls
+            .peek()
+            .expect("must have call frame on the call stack");
+        let instance = *frame.instance();
+        let compiled_func = frame.func();
+        let instr_ptr = frame.instr_ptr();
+        let values = match ctx.store.call_unreachable_hook(&instance) {
+            UnreachableAction::Trap => {
+                let wasm_offset = self
+                    .res
+                    .code_map
+                    .get(compiled_func)?
+                    .wasm_offset_at(instr_ptr);
+                return Err(
+                    Error::from(TrapCode::UnreachableCodeReached).with_wasm_offset(wasm_offset)
+                );
+            }
+            UnreachableAction::ContinueWith(values) => values,
+        };
+        let func_idx = self.res.code_map.get(compiled_func)?.func_idx();
+        let func = ctx
+            .store
+            .inner
+            .resolve_instance(&instance)
+            .get_func(func_idx.into_u32())
+            .expect("the returning Wasm function must exist in its instance");
+        func.ty(&*ctx).match_results(&values[..], true)?;
+        let callee = self
+            .stack
+            .calls
+            .pop()
+            .expect("must have call frame on the call stack");
+        let (mut caller_sp, results) = match self.stack.calls.peek() {
+            Some(caller) => {
+                cache.update_instance(caller.instance());
+                // Safety: The caller call frame is still live on the value stack.
+                let caller_sp = unsafe { self.stack.values.stack_ptr_at(caller.base_offset()) };
+                (caller_sp, callee.results())
+            }
+            None => (
+                self.stack.values.root_stack_ptr(),
+                RegisterSpan::new(Register::from_i16(0)),
+            ),
+        };
+        for (result, value) in results.iter(values.len()).zip(values) {
+            // Safety: The `callee.results()` refer to valid registers of the caller
+            //         call frame or to the root results of the value stack.
+            let cell = unsafe { caller_sp.get_mut(result) };
+            *cell = value.into();
+        }
+        self.stack.values.truncate(callee.frame_offset());
+        Ok(self.stack.calls.peek().is_none())
+    }
 }
 
 /// The caller of a host function call.
@@ -399,6 +595,25 @@ impl<'a> HostFuncCaller<'a> {
 }
 
 impl<'engine> EngineExecutor<'engine> {
+    /// Calls `f` which dispatches a host function call and returns its result.
+    ///
+    /// Panics of the host function are caught and returned as [`Error`]
+    /// if enabled via [`Store::with_host_trap`].
+    ///
+    /// [`Store::with_host_trap`]: crate::Store::with_host_trap
+    fn catch_host_panic<T, R>(
+        &mut self,
+        ctx: StoreContextMut<T>,
+        f: impl FnOnce(&mut Self, StoreContextMut<T>) -> R,
+    ) -> Result<R, Error> {
+        #[cfg(feature = "std")]
+        if ctx.as_context().store.inner.host_trap() {
+            return std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(self, ctx)))
+                .map_err(host_panic_error);
+        }
+        Ok(f(self, ctx))
+    }
+
     /// Dispatches a host function call and returns its result.
     fn dispatch_host_func<T>(
         &mut self,
@@ -527,3 +742,18 @@ impl<'engine> EngineExecutor<'engine> {
         results.call_results(&self.stack.values.as_slice()[..len_results])
     }
 }
+
+/// Creates the [`Error`] returned for a host function panic with the given `payload`.
+#[cfg(feature = "std")]
+fn host_panic_error(payload: std::boxed::Box<dyn core::any::Any + Send>) -> Error {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| {
+            payload
+                .downcast_ref::<std::string::String>()
+                .map(std::string::String::as_str)
+        })
+        .unwrap_or("Box<dyn Any>");
+    Error::new(std::format!("host function panicked: {message}"))
+}
@@ -1,6 +1,6 @@
 use super::{err_stack_overflow, BaseValueStackOffset, FrameValueStackOffset};
 use crate::{
-    engine::{bytecode::RegisterSpan, code_map::InstructionPtr},
+    engine::{bytecode::RegisterSpan, code_map::InstructionPtr, CompiledFunc},
     Instance,
 };
 use alloc::vec::Vec;
@@ -11,7 +11,6 @@ use crate::{
     engine::bytecode::Instruction,
     engine::bytecode::Register,
     engine::executor::stack::ValueStack,
-    engine::CompiledFunc,
     Global,
     Memory,
     Table,
@@ -28,6 +27,15 @@ pub struct CallStack {
     ///
     /// A [`TrapCode::StackOverflow`] is raised if the recursion limit is exceeded.
     recursion_limit: usize,
+    /// The number of call frames of outer executions of the same [`Store`].
+    ///
+    /// # Note
+    ///
+    /// Outer executions exist if a host function re-enters Wasm execution.
+    /// Their call frames count towards the recursion limit.
+    ///
+    /// [`Store`]: crate::Store
+    base_depth: usize,
 }
 
 impl CallStack {
@@ -39,6 +47,7 @@ impl CallStack {
         Self {
             calls: Vec::new(),
             recursion_limit,
+            base_depth: 0,
         }
     }
 
@@ -60,6 +69,20 @@ impl CallStack {
         self.calls.len()
     }
 
+    /// Sets the number of call frames of outer executions to `base_depth`.
+    ///
+    /// # Note
+    ///
+    /// This must be set before executing a function on the [`CallStack`].
+    pub fn set_base_depth(&mut self, base_depth: usize) {
+        self.base_depth = base_depth;
+    }
+
+    /// Returns the number of call frames including those of outer executions.
+    pub fn depth(&self) -> usize {
+        self.base_depth + self.len()
+    }
+
     /// Pushes a [`CallFrame`] onto the [`CallStack`].
     ///
     /// # Errors
@@ -67,7 +90,7 @@ impl CallStack {
     /// If the recursion limit has been reached.
     #[inline]
     pub fn push(&mut self, call: CallFrame) -> Result<(), TrapCode> {
-        if self.len() == self.recursion_limit {
+        if self.depth() >= self.recursion_limit {
             return Err(err_stack_overflow());
         }
         self.calls.push(call);
@@ -125,6 +148,8 @@ pub struct CallFrame {
     /// The [`Instance`] is used to inspect and manipulate data that is
     /// non-local to the function such as [`Memory`], [`Global`] and [`Table`].
     instance: Instance,
+    /// The compiled function executed by the [`CallFrame`].
+    func: CompiledFunc,
 }
 
 impl CallFrame {
@@ -135,6 +160,7 @@ impl CallFrame {
         base_ptr: BaseValueStackOffset,
         results: RegisterSpan,
         instance: Instance,
+        func: CompiledFunc,
     ) -> Self {
         Self {
             instr_ptr,
@@ -142,6 +168,7 @@ impl CallFrame {
             frame_ptr,
             results,
             instance,
+            func,
         }
     }
 
@@ -168,6 +195,11 @@ impl CallFrame {
         self.instr_ptr = new_instr_ptr;
     }
 
+    /// Returns the [`CompiledFunc`] executed by the [`CallFrame`].
+    pub fn func(&self) -> CompiledFunc {
+        self.func
+    }
+
     /// Returns the [`InstructionPtr`] of the [`CallFrame`].
     pub fn instr_ptr(&self) -> InstructionPtr {
         self.instr_ptr
@@ -1,8 +1,8 @@
 //! API using the Rust type system to guide host function trampoline execution.
 
-use crate::{value::WithType, Value};
+use crate::{value::WithType, Value, WasmTypeList};
 use core::cmp;
-use wasmi_core::{DecodeUntypedSlice, EncodeUntypedSlice, UntypedError, UntypedValue};
+use wasmi_core::{UntypedError, UntypedValue};
 
 /// Used to decode host function parameters.
 #[derive(Debug)]
@@ -40,10 +40,15 @@ impl<'a> FuncResults<'a> {
     /// If the number of results dictated by `T` does not match the expected amount.
     pub fn encode_results<T>(self, values: T) -> FuncFinished
     where
-        T: EncodeUntypedSlice,
+        T: WasmTypeList,
     {
-        UntypedValue::encode_slice::<T>(self.results, values)
-            .unwrap_or_else(|error| panic!("encountered unexpected invalid tuple length: {error}"));
+        assert_eq!(
+            self.results.len(),
+            <T as WasmTypeList>::LEN,
+            "encountered unexpected invalid tuple length",
+        );
+        self.results
+            .copy_from_slice(<T as WasmTypeList>::values(values).as_ref());
         FuncFinished {}
     }
 
@@ -103,10 +108,10 @@ impl<'a> FuncParams<'a> {
     /// If the number of function parameters dictated by `T` does not match.
     pub fn decode_params<T>(self) -> (T, FuncResults<'a>)
     where
-        T: DecodeUntypedSlice,
+        T: WasmTypeList,
     {
-        let decoded = UntypedValue::decode_slice::<T>(self.params())
-            .unwrap_or_else(|error| panic!("encountered unexpected invalid tuple length: {error}"));
+        let decoded = <T as WasmTypeList>::from_values(self.params())
+            .unwrap_or_else(|| panic!("encountered unexpected invalid tuple length"));
         let results = self.into_func_results();
         (decoded, results)
     }
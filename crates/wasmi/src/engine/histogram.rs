@@ -0,0 +1,69 @@
+use super::bytecode::Instruction;
+use alloc::{boxed::Box, collections::BTreeMap, string::String};
+use core::fmt::{self, Write};
+
+/// A histogram of the `wasmi` bytecode instructions of a compiled [`Module`].
+///
+/// Created via [`Engine::instruction_histogram`].
+///
+/// # Note
+///
+/// - Instructions are identified by the names of their variants, e.g. `"I32Add"`.
+/// - Instruction parameters that occupy their own instruction slot,
+///   e.g. `"Register2"` or `"Const32"`, are counted as well.
+/// - Instruction names are not stable across `wasmi` versions.
+///
+/// [`Module`]: crate::Module
+/// [`Engine::instruction_histogram`]: crate::Engine::instruction_histogram
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct InstructionHistogram {
+    /// The number of occurrences of each instruction by name.
+    counts: BTreeMap<Box<str>, usize>,
+}
+
+impl InstructionHistogram {
+    /// Records a single occurrence of `instr` in the [`InstructionHistogram`].
+    pub(crate) fn push(&mut self, instr: &Instruction) {
+        let mut name = VariantName::default();
+        // Note: `VariantName` aborts formatting after the variant name.
+        let _ = write!(name, "{instr:?}");
+        *self.counts.entry(name.name.into_boxed_str()).or_default() += 1;
+    }
+
+    /// Returns the number of occurrences of the instruction with the given `name`.
+    pub fn get(&self, name: &str) -> usize {
+        self.counts.get(name).copied().unwrap_or(0)
+    }
+
+    /// Returns the total number of recorded instructions.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Returns an iterator over the names and occurrences of all recorded instructions.
+    ///
+    /// The instructions are yielded in lexicographical order of their names.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.counts.iter().map(|(name, count)| (&**name, *count))
+    }
+}
+
+/// Extracts the variant name from the `Debug` output of an [`Instruction`].
+#[derive(Default)]
+struct VariantName {
+    name: String,
+}
+
+impl Write for VariantName {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = s
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .unwrap_or(s.len());
+        self.name.push_str(&s[..end]);
+        if end < s.len() {
+            // The variant name is complete so we can stop formatting.
+            return Err(fmt::Error);
+        }
+        Ok(())
+    }
+}
@@ -8,8 +8,12 @@ mod config;
 mod executor;
 mod func_args;
 mod func_types;
+mod histogram;
 mod limits;
 mod resumable;
+#[cfg(feature = "debug")]
+mod trace;
+mod trace_hook;
 mod traits;
 mod translator;
 
@@ -19,12 +23,16 @@ mod tests;
 #[cfg(test)]
 use self::bytecode::RegisterSpan;
 
+#[cfg(feature = "debug")]
+pub use self::trace::{ExecutionTrace, TraceEntry, TraceWrites};
 pub(crate) use self::{
     block_type::BlockType,
-    config::FuelCosts,
+    code_map::CompiledFuncEntity,
+    config::Fusions,
     executor::Stack,
     func_args::{FuncFinished, FuncParams, FuncResults},
     func_types::DedupFuncType,
+    trace_hook::TraceHook,
     translator::{
         FuncTranslationDriver,
         FuncTranslator,
@@ -34,24 +42,24 @@ pub(crate) use self::{
         WasmTranslator,
     },
 };
+use self::{code_map::CodeMap, func_types::FuncTypeRegistry, resumable::ResumableCallBase};
 pub use self::{
-    code_map::CompiledFunc,
-    config::{CompilationMode, Config, FuelConsumptionMode},
+    code_map::{CompiledFunc, FuncMetadata, ResolvedInstr},
+    config::{CompilationMode, Config, FuelConsumptionMode, FuelCosts, FusionKind, WasmFeatures},
+    histogram::InstructionHistogram,
     limits::StackLimits,
     resumable::{ResumableCall, ResumableInvocation, TypedResumableCall, TypedResumableInvocation},
+    trace_hook::TraceEvent,
     traits::{CallParams, CallResults},
-    translator::{Instr, TranslationError},
-};
-use self::{
-    code_map::{CodeMap, CompiledFuncEntity},
-    func_types::FuncTypeRegistry,
-    resumable::ResumableCallBase,
+    translator::{Diagnostic, DiagnosticKind, Instr, TranslationError},
 };
 use crate::{
+    error::ErrorKind,
     module::{FuncIdx, ModuleHeader},
     Error,
     Func,
     FuncType,
+    Module,
     StoreContextMut,
 };
 use alloc::{
@@ -166,11 +174,107 @@ impl Engine {
         self.inner.config()
     }
 
+    /// Returns the Wasm proposals enabled by the [`Config`] of the [`Engine`].
+    pub fn features(&self) -> WasmFeatures {
+        self.config().features()
+    }
+
     /// Returns `true` if both [`Engine`] references `a` and `b` refer to the same [`Engine`].
     pub fn same(a: &Engine, b: &Engine) -> bool {
         Arc::ptr_eq(&a.inner, &b.inner)
     }
 
+    /// Returns an [`InstructionHistogram`] of the compiled `wasmi` bytecode of the [`Module`].
+    ///
+    /// # Note
+    ///
+    /// - Imported functions are skipped since they have no `wasmi` bytecode.
+    /// - Functions that have not yet been translated due to lazy compilation
+    ///   are translated by this operation.
+    ///
+    /// # Errors
+    ///
+    /// If a lazily compiled function fails to translate to `wasmi` bytecode.
+    ///
+    /// # Panics
+    ///
+    /// If the [`Module`] has not been compiled by this [`Engine`].
+    pub fn instruction_histogram(&self, module: &Module) -> Result<InstructionHistogram, Error> {
+        assert!(
+            Engine::same(self, module.engine()),
+            "the module has not been compiled by this engine"
+        );
+        let mut histogram = InstructionHistogram::default();
+        for (_, compiled_func) in module.internal_funcs() {
+            self.resolve_func(compiled_func, |func| {
+                func.instrs().iter().for_each(|instr| histogram.push(instr))
+            })?;
+        }
+        Ok(histogram)
+    }
+
+    /// Compiles all Wasm `modules` and returns their compiled [`Module`]s in the same order.
+    ///
+    /// # Note
+    ///
+    /// - This is useful for hosts that are sensitive to startup latency and
+    ///   want to front-load the Wasm to `wasmi` bytecode translation.
+    /// - All modules share the translation and validation buffers of the [`Engine`].
+    /// - Use [`Engine::precompile_all_parallel`] to compile the modules concurrently.
+    ///
+    /// # Errors
+    ///
+    /// If any of the `modules` fails to compile. The first error is returned.
+    pub fn precompile_all<I>(&self, modules: I) -> Result<Vec<Module>, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        modules
+            .into_iter()
+            .map(|wasm| Module::new(self, wasm.as_ref()))
+            .collect()
+    }
+
+    /// Compiles all Wasm `modules` concurrently and returns their compiled [`Module`]s in the same order.
+    ///
+    /// # Note
+    ///
+    /// This is the parallel version of [`Engine::precompile_all`] which
+    /// spreads the compilation of the `modules` over the available threads.
+    ///
+    /// # Errors
+    ///
+    /// If any of the `modules` fails to compile. The error of the first failing module is returned.
+    #[cfg(feature = "std")]
+    pub fn precompile_all_parallel<M>(&self, modules: &[M]) -> Result<Vec<Module>, Error>
+    where
+        M: AsRef<[u8]> + Sync,
+    {
+        let len_threads = std::thread::available_parallelism()
+            .map(usize::from)
+            .unwrap_or(1)
+            .min(modules.len());
+        if len_threads <= 1 {
+            return self.precompile_all(modules);
+        }
+        let chunk_size = modules.len().div_ceil(len_threads);
+        std::thread::scope(|scope| {
+            let workers = modules
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || self.precompile_all(chunk)))
+                .collect::<Vec<_>>();
+            let mut compiled = Vec::with_capacity(modules.len());
+            for worker in workers {
+                let modules = worker
+                    .join()
+                    .unwrap_or_else(|error| std::panic::resume_unwind(error))?;
+                compiled.extend(modules);
+            }
+            Ok(compiled)
+        })
+    }
+
     /// Allocates a new function type to the [`Engine`].
     pub(super) fn alloc_func_type(&self, func_type: FuncType) -> DedupFuncType {
         self.inner.alloc_func_type(func_type)
@@ -224,26 +328,20 @@ impl Engine {
         func_to_validate: Option<FuncToValidate<ValidatorResources>>,
     ) -> Result<(), Error> {
         match (self.config().get_compilation_mode(), func_to_validate) {
-            (CompilationMode::Eager, Some(func_to_validate)) => {
-                let (translation_allocs, validation_allocs) = self.inner.get_allocs();
-                let validator = func_to_validate.into_validator(validation_allocs);
-                let translator = FuncTranslator::new(func_index, module, translation_allocs)?;
-                let translator = ValidatingFuncTranslator::new(validator, translator)?;
-                let allocs = FuncTranslationDriver::new(offset, bytes, translator)?
-                    .translate(|func_entity| self.inner.init_func(compiled_func, func_entity))?;
-                self.inner
-                    .recycle_allocs(allocs.translation, allocs.validation);
-            }
-            (CompilationMode::Eager, None) => {
-                let allocs = self.inner.get_translation_allocs();
-                let translator = FuncTranslator::new(func_index, module, allocs)?;
-                let allocs = FuncTranslationDriver::new(offset, bytes, translator)?
-                    .translate(|func_entity| self.inner.init_func(compiled_func, func_entity))?;
-                self.inner.recycle_translation_allocs(allocs);
+            (CompilationMode::Eager, func_to_validate) => {
+                self.translate_func_eagerly(
+                    func_index,
+                    offset,
+                    bytes,
+                    module,
+                    func_to_validate,
+                    |func_entity| self.inner.init_func(compiled_func, func_entity),
+                )?;
             }
             (CompilationMode::LazyTranslation, Some(func_to_validate)) => {
                 let allocs = self.inner.get_validation_allocs();
-                let translator = LazyFuncTranslator::new(func_index, compiled_func, module, None);
+                let translator =
+                    LazyFuncTranslator::new(func_index, compiled_func, offset, module, None);
                 let validator = func_to_validate.into_validator(allocs);
                 let translator = ValidatingFuncTranslator::new(validator, translator)?;
                 let allocs = FuncTranslationDriver::new(offset, bytes, translator)?
@@ -251,8 +349,13 @@ impl Engine {
                 self.inner.recycle_validation_allocs(allocs.validation);
             }
             (CompilationMode::Lazy | CompilationMode::LazyTranslation, func_to_validate) => {
-                let translator =
-                    LazyFuncTranslator::new(func_index, compiled_func, module, func_to_validate);
+                let translator = LazyFuncTranslator::new(
+                    func_index,
+                    compiled_func,
+                    offset,
+                    module,
+                    func_to_validate,
+                );
                 FuncTranslationDriver::new(offset, bytes, translator)?
                     .translate(|func_entity| self.inner.init_func(compiled_func, func_entity))?;
             }
@@ -260,6 +363,110 @@ impl Engine {
         Ok(())
     }
 
+    /// Eagerly translates the Wasm function using the [`Engine`].
+    ///
+    /// Calls `finalize` with the translated function upon success.
+    ///
+    /// # Note
+    ///
+    /// Conditional branches are encoded with 16-bit branch offsets by default.
+    /// If those turn out to be out of bounds the function is translated once more
+    /// with 32-bit branch offsets for all its conditional branches.
+    ///
+    /// # Errors
+    ///
+    /// - If function translation fails.
+    /// - If function validation fails.
+    pub(crate) fn translate_func_eagerly(
+        &self,
+        func_index: FuncIdx,
+        offset: usize,
+        bytes: &[u8],
+        module: ModuleHeader,
+        func_to_validate: Option<FuncToValidate<ValidatorResources>>,
+        finalize: impl FnOnce(CompiledFuncEntity),
+    ) -> Result<(), Error> {
+        let mut finalize = Some(finalize);
+        let mut finalize = |func_entity| {
+            let finalize = finalize
+                .take()
+                .expect("function has already been finalized");
+            finalize(func_entity)
+        };
+        let result = self.translate_func_eagerly_impl(
+            func_index,
+            offset,
+            bytes,
+            module.clone(),
+            func_to_validate,
+            false,
+            &mut finalize,
+        );
+        match result {
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    ErrorKind::Translation(TranslationError::BranchOffsetOutOfBounds)
+                ) =>
+            {
+                // Note: 16-bit branch offsets of forward branches are initialized only after
+                //       the entire function has been validated. Therefore we can skip
+                //       validation when translating the function once more.
+                self.translate_func_eagerly_impl(
+                    func_index,
+                    offset,
+                    bytes,
+                    module,
+                    None,
+                    true,
+                    &mut finalize,
+                )
+            }
+            result => result,
+        }
+    }
+
+    /// Eagerly translates the Wasm function with either 16-bit or 32-bit conditional branch offsets.
+    ///
+    /// See [`Engine::translate_func_eagerly`] for more information.
+    #[allow(clippy::too_many_arguments)]
+    fn translate_func_eagerly_impl(
+        &self,
+        func_index: FuncIdx,
+        offset: usize,
+        bytes: &[u8],
+        module: ModuleHeader,
+        func_to_validate: Option<FuncToValidate<ValidatorResources>>,
+        wide_branches: bool,
+        finalize: impl FnOnce(CompiledFuncEntity),
+    ) -> Result<(), Error> {
+        let with_wide_branches = |translator: FuncTranslator| match wide_branches {
+            true => translator.wide_branches(),
+            false => translator,
+        };
+        match func_to_validate {
+            Some(func_to_validate) => {
+                let (translation_allocs, validation_allocs) = self.get_allocs();
+                let validator = func_to_validate.into_validator(validation_allocs);
+                let translator = FuncTranslator::new(func_index, module, translation_allocs)?;
+                let translator = with_wide_branches(translator);
+                let translator = ValidatingFuncTranslator::new(validator, translator)?;
+                let allocs =
+                    FuncTranslationDriver::new(offset, bytes, translator)?.translate(finalize)?;
+                self.recycle_allocs(allocs.translation, allocs.validation);
+            }
+            None => {
+                let allocs = self.get_translation_allocs();
+                let translator = FuncTranslator::new(func_index, module, allocs)?;
+                let translator = with_wide_branches(translator);
+                let allocs =
+                    FuncTranslationDriver::new(offset, bytes, translator)?.translate(finalize)?;
+                self.recycle_translation_allocs(allocs);
+            }
+        }
+        Ok(())
+    }
+
     /// Returns reusable [`FuncTranslatorAllocations`] from the [`Engine`].
     pub(crate) fn get_translation_allocs(&self) -> FuncTranslatorAllocations {
         self.inner.get_translation_allocs()
@@ -299,12 +506,29 @@ impl Engine {
         &self,
         func_idx: FuncIdx,
         func: CompiledFunc,
+        offset: usize,
         bytes: &[u8],
         module: &ModuleHeader,
         func_to_validate: Option<FuncToValidate<ValidatorResources>>,
     ) {
         self.inner
-            .init_lazy_func(func_idx, func, bytes, module, func_to_validate)
+            .init_lazy_func(func_idx, func, offset, bytes, module, func_to_validate)
+    }
+
+    /// Resolves the [`CompiledFuncEntity`] of `func` and applies `f` to it.
+    ///
+    /// # Errors
+    ///
+    /// If the `func` fails Wasm to `wasmi` bytecode translation after it was lazily initialized.
+    ///
+    /// # Panics
+    ///
+    /// If the [`CompiledFunc`] is invalid for the [`Engine`].
+    pub(crate) fn resolve_func<F, R>(&self, func: CompiledFunc, f: F) -> Result<R, Error>
+    where
+        F: FnOnce(&CompiledFuncEntity) -> R,
+    {
+        self.inner.resolve_func(func, f)
     }
 
     /// Resolves the [`CompiledFunc`] to the underlying `wasmi` bytecode instructions.
@@ -498,16 +722,6 @@ pub struct ReusableAllocationStack {
     validation: Vec<FuncValidatorAllocations>,
 }
 
-impl Default for ReusableAllocationStack {
-    fn default() -> Self {
-        Self {
-            max_height: 1,
-            translation: Vec::new(),
-            validation: Vec::new(),
-        }
-    }
-}
-
 impl core::fmt::Debug for ReusableAllocationStack {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         f.debug_struct("ReusableAllocationStack")
@@ -521,6 +735,25 @@ impl core::fmt::Debug for ReusableAllocationStack {
 }
 
 impl ReusableAllocationStack {
+    /// Creates a new [`ReusableAllocationStack`] for the given [`Config`].
+    ///
+    /// # Note
+    ///
+    /// With parallel compilation enabled one set of allocations is kept
+    /// per translation worker thread so that all of them can reuse theirs.
+    fn new(config: &Config) -> Self {
+        let max_height = match config.get_parallel_compilation() {
+            #[cfg(feature = "std")]
+            true => std::thread::available_parallelism().map_or(1, usize::from),
+            _ => 1,
+        };
+        Self {
+            max_height,
+            translation: Vec::new(),
+            validation: Vec::new(),
+        }
+    }
+
     /// Returns reusable [`FuncTranslatorAllocations`] from the [`Engine`].
     pub fn get_translation_allocs(&mut self) -> FuncTranslatorAllocations {
         match self.translation.pop() {
@@ -599,9 +832,9 @@ impl EngineInner {
     /// Creates a new [`EngineInner`] with the given [`Config`].
     fn new(config: &Config) -> Self {
         Self {
-            config: *config,
+            config: config.clone(),
             res: RwLock::new(EngineResources::new()),
-            allocs: Mutex::new(ReusableAllocationStack::default()),
+            allocs: Mutex::new(ReusableAllocationStack::new(config)),
             stacks: Mutex::new(EngineStacks::new(config)),
         }
     }
@@ -719,14 +952,19 @@ impl EngineInner {
         &self,
         func_idx: FuncIdx,
         func: CompiledFunc,
+        offset: usize,
         bytes: &[u8],
         module: &ModuleHeader,
         func_to_validate: Option<FuncToValidate<ValidatorResources>>,
     ) {
-        self.res
-            .write()
-            .code_map
-            .init_lazy_func(func_idx, func, bytes, module, func_to_validate)
+        self.res.write().code_map.init_lazy_func(
+            func_idx,
+            func,
+            offset,
+            bytes,
+            module,
+            func_to_validate,
+        )
     }
 
     /// Resolves the [`InternalFuncEntity`] for [`CompiledFunc`] and applies `f` to it.
@@ -734,8 +972,7 @@ impl EngineInner {
     /// # Panics
     ///
     /// If [`CompiledFunc`] is invalid for [`Engine`].
-    #[cfg(test)]
-    pub(super) fn resolve_func<F, R>(&self, func: CompiledFunc, f: F) -> Result<R, Error>
+    fn resolve_func<F, R>(&self, func: CompiledFunc, f: F) -> Result<R, Error>
     where
         F: FnOnce(&CompiledFuncEntity) -> R,
     {
@@ -0,0 +1,89 @@
+//! Tests for resolving branch targets of compiled functions via `CompiledFuncEntity::instructions`.
+
+use crate::{
+    engine::{bytecode::Instruction, Instr, ResolvedInstr},
+    Engine,
+    Module,
+};
+use std::{collections::BTreeSet, vec::Vec};
+
+/// Returns the successors of `instr` in the control flow graph of its function.
+fn successors(instr: &ResolvedInstr) -> BTreeSet<Instr> {
+    let next = Instr::from_u32(instr.instr().into_u32() + 1);
+    match (instr.instruction(), instr.target()) {
+        (Instruction::Branch { .. }, Some(target)) => [target].into(),
+        (_, Some(target)) => [target, next].into(),
+        (
+            Instruction::Return
+            | Instruction::ReturnReg { .. }
+            | Instruction::ReturnImm32 { .. }
+            | Instruction::ReturnI64Imm32 { .. }
+            | Instruction::ReturnF64Imm32 { .. },
+            None,
+        ) => BTreeSet::new(),
+        (_, None) => [next].into(),
+    }
+}
+
+/// Returns the control flow graph edges of the single function of the `wat` module.
+fn cfg_edges(wat: &str) -> Vec<(u32, u32)> {
+    let wasm = wat::parse_str(wat).unwrap();
+    let engine = Engine::default();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let (_, compiled_func) = module.internal_funcs().next().unwrap();
+    engine
+        .resolve_func(compiled_func, |func| {
+            func.instructions()
+                .flat_map(|instr| {
+                    successors(&instr)
+                        .into_iter()
+                        .map(move |succ| (instr.instr().into_u32(), succ.into_u32()))
+                })
+                .collect()
+        })
+        .unwrap()
+}
+
+#[test]
+fn if_else_edges() {
+    let edges = cfg_edges(
+        r#"
+        (module
+            (func (param i32 i32) (result i32)
+                (if (result i32) (local.get 0)
+                    (then (i32.add (local.get 1) (i32.const 10)))
+                    (else (i32.mul (local.get 1) (i32.const 20)))
+                )
+                (i32.const 1)
+                (i32.add)
+            )
+        )"#,
+    );
+    // 0: branch to `else` if the condition is zero
+    // 1: `then` body
+    // 2: branch to the end of `if`
+    // 3: `else` body
+    // 4: instruction after the `if`
+    // 5: return
+    assert_eq!(edges, [(0, 1), (0, 3), (1, 2), (2, 4), (3, 4), (4, 5)]);
+}
+
+#[test]
+fn loop_edges() {
+    let edges = cfg_edges(
+        r#"
+        (module
+            (func (param i32) (result i32)
+                (loop $continue
+                    (local.set 0 (i32.sub (local.get 0) (i32.const 1)))
+                    (br_if $continue (local.get 0))
+                )
+                (local.get 0)
+            )
+        )"#,
+    );
+    // 0: loop body
+    // 1: branch back to the loop header if the condition is non-zero
+    // 2: return
+    assert_eq!(edges, [(0, 1), (1, 0), (1, 2)]);
+}
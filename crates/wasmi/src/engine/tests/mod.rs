@@ -1 +1,7 @@
 mod host_calls;
+mod instructions;
+mod parallel;
+mod streaming;
+#[cfg(feature = "debug")]
+mod trace;
+mod translation_allocs;
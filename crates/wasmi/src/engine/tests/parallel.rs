@@ -0,0 +1,91 @@
+//! Tests for parallel translation of Wasm function bodies via `Config::parallel_compilation`.
+
+use crate::{core::UntypedValue, engine::bytecode::Instruction, Config, Engine, Error, Module};
+use std::{fmt::Write as _, string::String, vec::Vec};
+
+/// The number of functions of the generated Wasm module.
+const LEN_FUNCS: usize = 200;
+
+/// Generates a large Wasm module with [`LEN_FUNCS`] different functions.
+///
+/// Each function of the module with index `n` calls its predecessor with index `n - 1`.
+/// If `invalid` contains `n` the `n`-th function body fails to validate.
+fn generate_wasm(invalid: &[usize]) -> Vec<u8> {
+    let mut wat = String::from("(module\n");
+    for n in 0..LEN_FUNCS {
+        let body = if invalid.contains(&n) {
+            String::from("(i32.const 0)")
+        } else if n == 0 {
+            String::from("(i64.add (i64.extend_i32_u (local.get 0)) (local.get 1))")
+        } else {
+            format!(
+                r#"
+                (local $i i32)
+                (local.set $i (i32.const {n}))
+                (block $exit
+                    (loop $continue
+                        (br_if $exit (i32.eqz (local.get $i)))
+                        (local.set 1 (i64.mul (local.get 1) (i64.const {large})))
+                        (local.set $i (i32.sub (local.get $i) (i32.const 1)))
+                        (br $continue)
+                    )
+                )
+                (call {callee} (i32.add (local.get 0) (i32.const {n})) (local.get 1))
+                "#,
+                large = (n as i64) << 40,
+                callee = n - 1,
+            )
+        };
+        writeln!(
+            wat,
+            "(func (export \"f{n}\") (param i32 i64) (result i64) {body})"
+        )
+        .unwrap();
+    }
+    wat.push(')');
+    wat::parse_str(wat).unwrap()
+}
+
+/// Compiles `wasm` with parallel compilation enabled or disabled.
+fn compile(wasm: &[u8], parallel: bool) -> Result<(Engine, Module), Error> {
+    let mut config = Config::default();
+    config.parallel_compilation(parallel);
+    let engine = Engine::new(&config);
+    let module = Module::new(&engine, wasm)?;
+    Ok((engine, module))
+}
+
+/// Returns the instructions and function local constants of all functions of `module`.
+fn bytecode(engine: &Engine, module: &Module) -> Vec<(Vec<Instruction>, Vec<UntypedValue>)> {
+    module
+        .internal_funcs()
+        .map(|(_, compiled_func)| {
+            engine
+                .resolve_func(compiled_func, |func| {
+                    (func.instrs().to_vec(), func.consts().to_vec())
+                })
+                .unwrap()
+        })
+        .collect()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn parallel_bytecode_matches_sequential() {
+    let wasm = generate_wasm(&[]);
+    let (seq_engine, seq_module) = compile(&wasm, false).unwrap();
+    let (par_engine, par_module) = compile(&wasm, true).unwrap();
+    let expected = bytecode(&seq_engine, &seq_module);
+    let actual = bytecode(&par_engine, &par_module);
+    assert_eq!(expected.len(), LEN_FUNCS);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn parallel_error_matches_sequential() {
+    let wasm = generate_wasm(&[LEN_FUNCS / 2, LEN_FUNCS - 1, 3]);
+    let expected = compile(&wasm, false).unwrap_err();
+    let actual = compile(&wasm, true).unwrap_err();
+    assert_eq!(actual.to_string(), expected.to_string());
+}
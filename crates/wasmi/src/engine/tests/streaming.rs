@@ -0,0 +1,88 @@
+//! Tests for incremental parsing of Wasm modules from small chunked byte streams.
+
+use crate::{core::UntypedValue, engine::bytecode::Instruction, Engine, Module};
+use std::{io, vec::Vec};
+
+/// A byte stream that yields at most `chunk_len` bytes per read.
+struct ChunkedReader<'a> {
+    bytes: &'a [u8],
+    chunk_len: usize,
+}
+
+impl io::Read for ChunkedReader<'_> {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        let len = self.bytes.len().min(buffer.len()).min(self.chunk_len);
+        let (chunk, rest) = self.bytes.split_at(len);
+        buffer[..len].copy_from_slice(chunk);
+        self.bytes = rest;
+        Ok(len)
+    }
+}
+
+/// Returns the instructions and function local constants of all functions of `module`.
+fn bytecode(engine: &Engine, module: &Module) -> Vec<(Vec<Instruction>, Vec<UntypedValue>)> {
+    module
+        .internal_funcs()
+        .map(|(_, compiled_func)| {
+            engine
+                .resolve_func(compiled_func, |func| {
+                    (func.instrs().to_vec(), func.consts().to_vec())
+                })
+                .unwrap()
+        })
+        .collect()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn chunked_stream_matches_one_shot() {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (import "env" "log" (func $log (param i32)))
+            (memory (export "mem") 1)
+            (global $g (mut i64) (i64.const 42))
+            (func $fib (export "fib") (param i64) (result i64)
+                (if (result i64) (i64.le_u (local.get 0) (i64.const 1))
+                    (then (local.get 0))
+                    (else
+                        (i64.add
+                            (call $fib (i64.sub (local.get 0) (i64.const 1)))
+                            (call $fib (i64.sub (local.get 0) (i64.const 2)))
+                        )
+                    )
+                )
+            )
+            (func (export "run") (param i32) (result i64)
+                (call $log (local.get 0))
+                (global.set $g (i64.add (global.get $g) (i64.const 0x1234_5678_9ABC)))
+                (call $fib (i64.extend_i32_u (local.get 0)))
+            )
+            (data (i32.const 8) "streaming")
+        )"#,
+    )
+    .unwrap();
+    let expected_engine = Engine::default();
+    let expected_module = Module::new(&expected_engine, &wasm[..]).unwrap();
+    let expected = bytecode(&expected_engine, &expected_module);
+    for chunk_len in 1..=7 {
+        // Note: we use a new engine per module so that compiled function indices are comparable.
+        let engine = Engine::default();
+        let reader = ChunkedReader {
+            bytes: &wasm[..],
+            chunk_len,
+        };
+        let module = Module::new(&engine, reader).unwrap();
+        assert_eq!(bytecode(&engine, &module), expected);
+        assert!(module
+            .imports()
+            .map(|import| (import.module(), import.name()))
+            .eq(expected_module
+                .imports()
+                .map(|import| (import.module(), import.name()))));
+        assert!(module
+            .exports()
+            .map(|export| export.name())
+            .eq(expected_module.exports().map(|export| export.name())));
+    }
+}
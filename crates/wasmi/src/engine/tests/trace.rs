@@ -0,0 +1,73 @@
+//! Tests for the `wasmi` execution tracer.
+
+use crate::{
+    core::UntypedValue,
+    engine::bytecode::{Const16, Instruction, Register},
+    Engine,
+    Linker,
+    Module,
+    Store,
+};
+
+#[test]
+fn trace_simple_func() {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (func (export "f") (param i32 i32) (result i32)
+                (local.get 0)
+                (local.get 1)
+                (i32.add)
+                (i32.const 1)
+                (i32.add)
+            )
+        )
+    "#,
+    )
+    .unwrap();
+    let engine = Engine::default();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let f = instance
+        .get_typed_func::<(i32, i32), i32>(&store, "f")
+        .unwrap();
+    // No trace is recorded while tracing is disabled.
+    assert_eq!(f.call(&mut store, (1, 2)).unwrap(), 4);
+    assert!(store.trace().is_none());
+    store.set_tracing(true);
+    assert_eq!(f.call(&mut store, (10, 20)).unwrap(), 31);
+    let trace = store.take_trace().unwrap();
+    let expected = [
+        (
+            Instruction::i32_add(
+                Register::from_i16(2),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            vec![(2, UntypedValue::from(30_i32))],
+        ),
+        (
+            Instruction::i32_add_imm16(
+                Register::from_i16(2),
+                Register::from_i16(2),
+                Const16::from(1_i16),
+            ),
+            vec![(2, UntypedValue::from(31_i32))],
+        ),
+        (Instruction::return_reg(Register::from_i16(2)), vec![]),
+    ];
+    assert_eq!(trace.len(), expected.len());
+    for (entry, (instr, writes)) in trace.entries().iter().zip(expected) {
+        assert_eq!(entry.instr(), &instr);
+        assert_eq!(entry.writes().collect::<Vec<_>>(), writes);
+    }
+    // The trace has been taken so the next trace starts out empty.
+    assert!(store.trace().unwrap().is_empty());
+    store.set_tracing(false);
+    assert!(store.trace().is_none());
+}
@@ -0,0 +1,105 @@
+//! Tests asserting that Wasm function translations reuse their allocations.
+
+use crate::{CompilationMode, Config, Engine, Module};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    string::String,
+    vec::Vec,
+};
+
+/// A global allocator that counts the allocations of the current thread.
+struct CountingAllocator;
+
+std::thread_local! {
+    /// The number of allocations and reallocations of the current thread.
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Bumps the number of allocations of the current thread.
+fn bump_allocations() {
+    // Note: thread local storage might be unavailable during thread teardown.
+    let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        bump_allocations();
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        bump_allocations();
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations of the current thread performed by `f`.
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+/// The number of functions of the generated Wasm module.
+const LEN_FUNCS: usize = 20;
+
+/// Generates a Wasm module with [`LEN_FUNCS`] functions with identical bodies.
+fn generate_wasm() -> Vec<u8> {
+    let mut wat = String::from("(module\n");
+    for _ in 0..LEN_FUNCS {
+        wat.push_str(
+            r#"
+            (func (param i32 i64) (result i64)
+                (local $i i32)
+                (local.set $i (local.get 0))
+                (block $exit
+                    (loop $continue
+                        (br_if $exit (i32.eqz (local.get $i)))
+                        (local.set 1 (i64.mul (local.get 1) (i64.const 0x1234_5678_9ABC)))
+                        (local.set $i (i32.sub (local.get $i) (i32.const 1)))
+                        (br $continue)
+                    )
+                )
+                (i64.add (local.get 1) (i64.extend_i32_u (local.get 0)))
+            )
+            "#,
+        );
+    }
+    wat.push(')');
+    wat::parse_str(wat).unwrap()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn translation_reuses_allocations() {
+    let mut config = Config::default();
+    config.compilation_mode(CompilationMode::Lazy);
+    let engine = Engine::new(&config);
+    let module = Module::new(&engine, &generate_wasm()[..]).unwrap();
+    // Note: lazy compilation translates each function upon its first use
+    //       which allows us to count the allocations of each translation.
+    let allocations: Vec<usize> = module
+        .internal_funcs()
+        .map(|(_, compiled_func)| {
+            count_allocations(|| engine.resolve_func(compiled_func, |_| ()).unwrap())
+        })
+        .collect();
+    assert_eq!(allocations.len(), LEN_FUNCS);
+    // The first translation allocates the reusable translation buffers.
+    // All following translations only allocate their translation results.
+    let (first, rest) = allocations.split_first().unwrap();
+    let steady = rest[0];
+    assert!(steady < *first, "allocations: {allocations:?}");
+    assert!(
+        rest.iter().all(|&allocations| allocations == steady),
+        "allocations: {allocations:?}"
+    );
+}
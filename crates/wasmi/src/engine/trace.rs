@@ -0,0 +1,119 @@
+use crate::{
+    core::UntypedValue,
+    engine::bytecode::{Instruction, Register},
+};
+use alloc::vec::Vec;
+use core::slice;
+
+/// A log of all `wasmi` bytecode instructions executed by a [`Store`].
+///
+/// # Note
+///
+/// This is a debugging utility and only recorded if tracing has been
+/// enabled via [`Store::set_tracing`]. Recording the trace heavily slows
+/// down execution and should never be used in production.
+///
+/// [`Store`]: crate::Store
+/// [`Store::set_tracing`]: crate::Store::set_tracing
+#[derive(Debug, Default, Clone)]
+pub struct ExecutionTrace {
+    /// The recorded entries in order of their execution.
+    entries: Vec<TraceEntry>,
+}
+
+/// A single executed `wasmi` bytecode instruction and its register writes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEntry {
+    /// The executed instruction.
+    instr: Instruction,
+    /// The register writes performed by the executed instruction.
+    writes: Vec<(Register, UntypedValue)>,
+}
+
+impl TraceEntry {
+    /// Creates a new [`TraceEntry`] for `instr` without any register writes.
+    fn new(instr: Instruction) -> Self {
+        Self {
+            instr,
+            writes: Vec::new(),
+        }
+    }
+
+    /// Returns the executed `wasmi` bytecode instruction.
+    pub fn instr(&self) -> &Instruction {
+        &self.instr
+    }
+
+    /// Returns an iterator over the register writes of the executed instruction.
+    ///
+    /// Yields pairs of register index and written value in order of their writes.
+    ///
+    /// # Note
+    ///
+    /// Only register writes to the call frame of the executed instruction are recorded.
+    pub fn writes(&self) -> TraceWrites<'_> {
+        TraceWrites {
+            iter: self.writes.iter(),
+        }
+    }
+}
+
+/// An iterator over the register writes of a [`TraceEntry`].
+#[derive(Debug)]
+pub struct TraceWrites<'a> {
+    iter: slice::Iter<'a, (Register, UntypedValue)>,
+}
+
+impl<'a> Iterator for TraceWrites<'a> {
+    type Item = (i16, UntypedValue);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(register, value)| (register.to_i16(), *value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for TraceWrites<'a> {}
+
+impl ExecutionTrace {
+    /// Records the execution of `instr`.
+    pub(crate) fn push_instr(&mut self, instr: Instruction) {
+        self.entries.push(TraceEntry::new(instr));
+    }
+
+    /// Records a write of `value` to `register` by the last executed instruction.
+    ///
+    /// # Note
+    ///
+    /// Does nothing if no instruction has been recorded, yet.
+    pub(crate) fn push_write(&mut self, register: Register, value: UntypedValue) {
+        if let Some(entry) = self.entries.last_mut() {
+            entry.writes.push((register, value));
+        }
+    }
+
+    /// Returns the recorded [`TraceEntry`] items in order of their execution.
+    pub fn entries(&self) -> &[TraceEntry] {
+        &self.entries[..]
+    }
+
+    /// Returns the number of recorded [`TraceEntry`] items.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no instruction has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all recorded [`TraceEntry`] items.
+    pub fn clear(&mut self) {
+        self.entries.clear()
+    }
+}
@@ -0,0 +1,79 @@
+use alloc::boxed::Box;
+use core::fmt;
+
+/// An event passed to the trace hook of a [`Store`] before an instruction is executed.
+///
+/// # Note
+///
+/// The trace hook is only called if tracing has been enabled via
+/// [`Config::enable_tracing`] and a trace hook has been registered
+/// via [`Store::set_trace_hook`].
+///
+/// [`Store`]: crate::Store
+/// [`Store::set_trace_hook`]: crate::Store::set_trace_hook
+/// [`Config::enable_tracing`]: crate::Config::enable_tracing
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TraceEvent {
+    /// The index of the executed function within its module.
+    func_index: u32,
+    /// The offset of the executed `wasmi` bytecode instruction within its function.
+    instr_offset: u32,
+}
+
+impl TraceEvent {
+    /// Creates a new [`TraceEvent`].
+    pub(crate) fn new(func_index: u32, instr_offset: u32) -> Self {
+        Self {
+            func_index,
+            instr_offset,
+        }
+    }
+
+    /// Returns the index of the executed function within its module.
+    ///
+    /// # Note
+    ///
+    /// Like in Wasm, imported functions come before internal functions in the function index space.
+    pub fn func_index(&self) -> u32 {
+        self.func_index
+    }
+
+    /// Returns the offset of the executed `wasmi` bytecode instruction within its function.
+    ///
+    /// # Note
+    ///
+    /// The offset refers to the compiled `wasmi` bytecode and not to the original Wasm binary.
+    pub fn instr_offset(&self) -> u32 {
+        self.instr_offset
+    }
+}
+
+/// The trace hook of a [`Store`] called before each executed instruction.
+///
+/// [`Store`]: crate::Store
+pub struct TraceHook {
+    hook: Box<dyn FnMut(&TraceEvent) + Send + Sync>,
+}
+
+impl fmt::Debug for TraceHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TraceHook").finish_non_exhaustive()
+    }
+}
+
+impl TraceHook {
+    /// Creates a new [`TraceHook`] from the given `hook` closure.
+    pub fn new<F>(hook: F) -> Self
+    where
+        F: FnMut(&TraceEvent) + Send + Sync + 'static,
+    {
+        Self {
+            hook: Box::new(hook),
+        }
+    }
+
+    /// Calls the [`TraceHook`] with the given `event`.
+    pub fn call(&mut self, event: &TraceEvent) {
+        (self.hook)(event)
+    }
+}
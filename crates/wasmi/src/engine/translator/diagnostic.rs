@@ -0,0 +1,67 @@
+use crate::module::FuncIdx;
+
+/// A diagnostic about a suboptimal construct encountered while translating a Wasm function.
+///
+/// Collected if enabled via [`Config::collect_translation_diagnostics`] and
+/// queried via [`Module::translation_diagnostics`].
+///
+/// # Note
+///
+/// Diagnostics are informational only and never affect the semantics of the
+/// translated function. The set of reported diagnostics is not stable across
+/// `wasmi` versions.
+///
+/// [`Config::collect_translation_diagnostics`]: crate::Config::collect_translation_diagnostics
+/// [`Module::translation_diagnostics`]: crate::Module::translation_diagnostics
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The index of the function that caused the diagnostic.
+    func: u32,
+    /// The category of the diagnostic.
+    kind: DiagnosticKind,
+}
+
+impl Diagnostic {
+    /// Creates a new [`Diagnostic`] of `kind` for the function at `func`.
+    pub(crate) fn new(func: FuncIdx, kind: DiagnosticKind) -> Self {
+        Self {
+            func: func.into_u32(),
+            kind,
+        }
+    }
+
+    /// Returns the index of the function that caused the [`Diagnostic`].
+    ///
+    /// # Note
+    ///
+    /// The index is within the function index space of the Wasm module
+    /// and thus accounts for imported functions.
+    pub fn func_index(&self) -> u32 {
+        self.func
+    }
+
+    /// Returns the [`DiagnosticKind`] of the [`Diagnostic`].
+    pub fn kind(&self) -> DiagnosticKind {
+        self.kind
+    }
+}
+
+/// The category of a translation [`Diagnostic`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DiagnosticKind {
+    /// A group of `len` values had to be copied at once.
+    ///
+    /// This usually happens when branching to or falling out of a control
+    /// block with 3 or more results that do not already reside in the
+    /// result registers of the block.
+    LargeCopyGroup {
+        /// The number of copied values.
+        len: usize,
+    },
+    /// A copied constant value did not fit into a 32-bit immediate.
+    ///
+    /// The constant is either copied from a function local constant register
+    /// or encoded via an additional instruction word.
+    ConstPoolOverflowToReg,
+}
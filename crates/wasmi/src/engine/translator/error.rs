@@ -7,6 +7,10 @@ pub enum TranslationError {
     UnsupportedBlockType(wasmparser::BlockType),
     /// Encountered an unsupported Wasm value type.
     UnsupportedValueType(wasmparser::ValType),
+    /// Encountered a Wasm operator that is valid but not yet supported by `wasmi`.
+    UnsupportedOperator(&'static str),
+    /// Encountered a shared linear memory which `wasmi` does not support.
+    UnsupportedSharedMemory,
     /// When using too many branch table targets.
     BranchTableTargetsOutOfBounds,
     /// Branching offset out of bounds.
@@ -17,6 +21,8 @@ pub enum TranslationError {
     AllocatedTooManyRegisters,
     /// Tried to use an out of bounds register index.
     RegisterOutOfBounds,
+    /// Tried to encode more instructions for a single function than possible.
+    TooManyInstructions,
     /// Pushed too many values on the emulated value stack during translation.
     EmulatedValueStackOverflow,
     /// Tried to allocate too many or large provider slices.
@@ -27,6 +33,15 @@ pub enum TranslationError {
     TooManyFunctionResults,
     /// Tried to define a function with too many function parameters.
     TooManyFunctionParams,
+    /// A function requires more value stack slots than allowed by the [`Config`].
+    ///
+    /// [`Config`]: crate::Config
+    TooManyValueStackSlots {
+        /// The number of value stack slots required by the function.
+        required: usize,
+        /// The maximum number of value stack slots allowed.
+        limit: usize,
+    },
 }
 
 impl TranslationError {
@@ -50,6 +65,12 @@ impl Display for TranslationError {
             Self::UnsupportedValueType(error) => {
                 write!(f, "encountered unsupported Wasm value type: {error:?}")
             }
+            Self::UnsupportedOperator(name) => {
+                write!(f, "encountered unsupported Wasm operator: {name}")
+            }
+            Self::UnsupportedSharedMemory => {
+                write!(f, "encountered unsupported shared linear memory")
+            }
             Self::BranchTableTargetsOutOfBounds => {
                 write!(
                     f,
@@ -74,6 +95,12 @@ impl Display for TranslationError {
             Self::RegisterOutOfBounds => {
                 write!(f, "tried to access out of bounds register index")
             }
+            Self::TooManyInstructions => {
+                write!(
+                    f,
+                    "translation requires more instructions for a function than available"
+                )
+            }
             Self::EmulatedValueStackOverflow => {
                 write!(f, "function requires value stack with out of bounds depth")
             }
@@ -92,6 +119,12 @@ impl Display for TranslationError {
             Self::TooManyFunctionParams => {
                 write!(f, "encountered function with too many function parameters")
             }
+            Self::TooManyValueStackSlots { required, limit } => {
+                write!(
+                    f,
+                    "function requires {required} value stack slots which exceeds the limit of {limit}"
+                )
+            }
         }
     }
 }
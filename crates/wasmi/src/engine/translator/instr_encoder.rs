@@ -1,9 +1,13 @@
 use super::{
+    utils::CopyInfo,
     visit_register::VisitInputRegisters,
+    DiagnosticKind,
     FuelInfo,
     LabelRef,
     LabelRegistry,
+    TranslationError,
     TypedProvider,
+    TypedValue,
 };
 use crate::{
     engine::{
@@ -22,6 +26,8 @@ use crate::{
         },
         translator::{stack::RegisterSpace, ValueStack},
         FuelCosts,
+        FusionKind,
+        Fusions,
     },
     module::ModuleHeader,
     Error,
@@ -94,6 +100,47 @@ pub struct InstrEncoder {
     /// defragmentation of the register space due to `local.set` register
     /// preservations.
     notified_preservation: Option<Instr>,
+    /// The last `local.set` or `local.tee` that might turn out to be a dead store.
+    ///
+    /// # Note
+    ///
+    /// This is used for dead-store elimination of `local.set` or `local.tee`
+    /// that are overwritten within the same basic block without being read.
+    last_local_set: Option<LocalSetInstr>,
+    /// Is `true` if [`DiagnosticKind`]s are recorded at translation fallbacks.
+    collect_diagnostics: bool,
+    /// The [`DiagnosticKind`]s recorded for the function under construction.
+    diagnostics: Vec<DiagnosticKind>,
+    /// Is `true` if conditional forward branches are encoded with 32-bit [`BranchOffset`]s.
+    wide_branches: bool,
+    /// The [`Instr`] to which the most recent label has been pinned if any.
+    ///
+    /// # Note
+    ///
+    /// Labels are pinned in ascending order of their [`Instr`] and therefore
+    /// no label is pinned beyond this [`Instr`].
+    last_pinned: Option<Instr>,
+    /// The last encoded single-word unconditional `return` [`Instruction`] if any.
+    ///
+    /// # Note
+    ///
+    /// This is used to merge identical `return` instructions of distinct control paths.
+    last_return: Option<Instr>,
+    /// The maximum number of instruction words shifted by `local.set` preservations if any.
+    max_preservation_shift: Option<u32>,
+    /// The number of instruction words shifted by `local.set` preservations so far.
+    preservation_shift: u32,
+    /// The enabled kinds of instruction fusion.
+    fusions: Fusions,
+}
+
+/// An [`Instruction`] storing to a local variable due to a `local.set` or `local.tee`.
+#[derive(Debug, Copy, Clone)]
+struct LocalSetInstr {
+    /// The local variable [`Register`] that is stored to.
+    local: Register,
+    /// The [`Instr`] storing to the `local` variable.
+    instr: Instr,
 }
 
 /// The sequence of encoded [`Instruction`].
@@ -101,12 +148,86 @@ pub struct InstrEncoder {
 pub struct InstrSequence {
     /// Already encoded [`Instruction`] words.
     instrs: Vec<Instruction>,
+    /// The Wasm byte offsets of the encoded [`Instruction`] words if recorded.
+    ///
+    /// Each entry associates the first [`Instr`] of a run of [`Instruction`]
+    /// words to the byte offset of the Wasm operator they were translated from.
+    /// Entries are sorted by their [`Instr`] in strictly ascending order.
+    offsets: Vec<(Instr, usize)>,
+    /// Is `true` if the Wasm byte offsets of encoded [`Instruction`] words are recorded.
+    record_offsets: bool,
+    /// The byte offset of the currently translated Wasm operator if any.
+    pos: Option<usize>,
 }
 
 impl InstrSequence {
     /// Resets the [`InstrSequence`].
     pub fn reset(&mut self) {
         self.instrs.clear();
+        self.offsets.clear();
+        self.pos = None;
+    }
+
+    /// Updates the byte offset of the currently translated Wasm operator.
+    fn update_pos(&mut self, pos: usize) {
+        self.pos = Some(pos);
+    }
+
+    /// Records the current Wasm byte offset for the [`Instruction`] word about to be pushed.
+    fn record_offset(&mut self) {
+        if !self.record_offsets {
+            return;
+        }
+        let Some(pos) = self.pos else { return };
+        if self
+            .offsets
+            .last()
+            .is_some_and(|&(_, offset)| offset == pos)
+        {
+            return;
+        }
+        let instr = self.next_instr();
+        match self.offsets.last_mut() {
+            Some(last) if last.0 == instr => last.1 = pos,
+            _ => self.offsets.push((instr, pos)),
+        }
+    }
+
+    /// Adjusts the recorded Wasm byte offsets after inserting an [`Instruction`] at `instr`.
+    ///
+    /// The inserted [`Instruction`] is associated to the same Wasm operator
+    /// as the [`Instruction`] previously located at `instr`.
+    fn shift_offsets_after_insert(&mut self, instr: Instr) {
+        for (start, _) in self.offsets.iter_mut().rev() {
+            if *start <= instr {
+                break;
+            }
+            *start = Instr::from_u32(start.into_u32() + 1);
+        }
+    }
+
+    /// Adjusts the recorded Wasm byte offsets after removing the [`Instruction`] at `instr`.
+    fn shift_offsets_after_remove(&mut self, instr: Instr) {
+        let mut len_shifted = 0;
+        for (start, _) in self.offsets.iter_mut().rev() {
+            if *start <= instr {
+                break;
+            }
+            *start = Instr::from_u32(start.into_u32() - 1);
+            len_shifted += 1;
+        }
+        // Note: a run that consisted of only the removed instruction is now empty.
+        let first_shifted = self.offsets.len() - len_shifted;
+        if len_shifted != 0
+            && first_shifted != 0
+            && self.offsets[first_shifted - 1].0 == self.offsets[first_shifted].0
+        {
+            self.offsets.remove(first_shifted - 1);
+        }
+        let end = Instr::from_usize(self.instrs.len());
+        if self.offsets.last().is_some_and(|&(start, _)| start == end) {
+            self.offsets.pop();
+        }
     }
 
     /// Returns the next [`Instr`].
@@ -120,11 +241,25 @@ impl InstrSequence {
     ///
     /// If there are too many instructions in the instruction sequence.
     fn push(&mut self, instruction: Instruction) -> Result<Instr, Error> {
+        self.ensure_capacity()?;
+        self.record_offset();
         let instr = self.next_instr();
         self.instrs.push(instruction);
         Ok(instr)
     }
 
+    /// Ensures that one more [`Instruction`] can be encoded into the instruction sequence.
+    ///
+    /// # Errors
+    ///
+    /// If the instruction sequence is full, i.e. the next [`Instr`] is not representable.
+    fn ensure_capacity(&self) -> Result<(), Error> {
+        if u32::try_from(self.instrs.len()).map_or(true, |len| len == u32::MAX) {
+            return Err(Error::from(TranslationError::TooManyInstructions));
+        }
+        Ok(())
+    }
+
     /// Pushes an [`Instruction`] before the [`Instruction`] at [`Instr`].
     ///
     /// Returns the [`Instr`] of the [`Instruction`] that was at [`Instr`] before this operation.
@@ -141,13 +276,30 @@ impl InstrSequence {
     ///
     /// If there are too many instructions in the instruction sequence.
     fn push_before(&mut self, instr: Instr, instruction: Instruction) -> Result<Instr, Error> {
+        self.ensure_capacity()?;
         self.instrs.insert(instr.into_usize(), instruction);
-        let shifted_instr = instr
-            .into_u32()
-            .checked_add(1)
-            .map(Instr::from_u32)
-            .unwrap_or_else(|| panic!("pushed to many instructions to a single function"));
-        Ok(shifted_instr)
+        self.shift_offsets_after_insert(instr);
+        // Note: `ensure_capacity` guarantees that `instr` is not the last representable `Instr`.
+        Ok(Instr::from_u32(instr.into_u32() + 1))
+    }
+
+    /// Removes the [`Instruction`] at [`Instr`] from the instruction sequence.
+    ///
+    /// # Note
+    ///
+    /// - This operation might be costly. Callers are advised to only remove
+    ///   instructions near the end of the sequence in order to avoid massive
+    ///   copy overhead since all following instructions are required to be
+    ///   shifted in memory.
+    /// - All [`Instr`] referring to following instructions are invalidated.
+    ///
+    /// # Panics
+    ///
+    /// If no [`Instruction`] is associated to the [`Instr`] for this [`InstrSequence`].
+    fn remove(&mut self, instr: Instr) -> Instruction {
+        let removed = self.instrs.remove(instr.into_usize());
+        self.shift_offsets_after_remove(instr);
+        removed
     }
 
     /// Returns the [`Instruction`] associated to the [`Instr`] for this [`InstrSequence`].
@@ -177,6 +329,15 @@ impl InstrSequence {
         self.instrs.drain(..)
     }
 
+    /// Return an iterator over the recorded Wasm byte offsets of the generated [`Instruction`].
+    ///
+    /// # Note
+    ///
+    /// Yields no items unless recording Wasm byte offsets is enabled.
+    pub fn drain_offsets(&mut self) -> Drain<'_, (Instr, usize)> {
+        self.offsets.drain(..)
+    }
+
     /// Returns a slice to the sequence of [`Instruction`] starting at `start`.
     ///
     /// # Panics
@@ -203,6 +364,56 @@ impl InstrEncoder {
         self.labels.reset();
         self.reset_last_instr();
         self.notified_preservation = None;
+        self.diagnostics.clear();
+        self.wide_branches = false;
+        self.last_pinned = None;
+        self.last_return = None;
+        self.preservation_shift = 0;
+    }
+
+    /// Enables or disables encoding conditional forward branches with 32-bit [`BranchOffset`]s.
+    ///
+    /// # Note
+    ///
+    /// Conditional branches are encoded with 16-bit [`BranchOffset16`]s by default.
+    /// Since the offsets of forward branches are unknown at the time of their encoding
+    /// this is used to translate a function again if one of them turned out to be out
+    /// of bounds for 16-bit offsets.
+    pub fn wide_branches(&mut self, enable: bool) {
+        self.wide_branches = enable;
+    }
+
+    /// Sets the maximum number of instruction words shifted by `local.set` preservations.
+    ///
+    /// # Note
+    ///
+    /// Once the limit is reached `local.set` preservations are encoded
+    /// by appending copy instructions instead of shifting instructions.
+    /// There is no limit if `limit` is `None`.
+    pub fn max_preservation_shift(&mut self, limit: Option<u32>) {
+        self.max_preservation_shift = limit;
+    }
+
+    /// Sets the enabled kinds of instruction fusion.
+    pub fn fusions(&mut self, fusions: Fusions) {
+        self.fusions = fusions;
+    }
+
+    /// Enables or disables recording of [`DiagnosticKind`]s at translation fallbacks.
+    pub fn collect_diagnostics(&mut self, enable: bool) {
+        self.collect_diagnostics = enable;
+    }
+
+    /// Records the [`DiagnosticKind`] if collection of diagnostics is enabled.
+    fn emit_diagnostic(&mut self, kind: DiagnosticKind) {
+        if self.collect_diagnostics {
+            self.diagnostics.push(kind);
+        }
+    }
+
+    /// Returns an iterator over the [`DiagnosticKind`]s recorded for the function under construction.
+    pub fn drain_diagnostics(&mut self) -> Drain<'_, DiagnosticKind> {
+        self.diagnostics.drain(..)
     }
 
     /// Resets the [`Instr`] last created via [`InstrEncoder::push_instr`].
@@ -218,6 +429,32 @@ impl InstrEncoder {
     /// not invalidly optimize across control flow boundaries.
     pub fn reset_last_instr(&mut self) {
         self.last_instr = None;
+        self.last_local_set = None;
+    }
+
+    /// Removes the [`Instruction`] last created via [`InstrEncoder::push_instr`] if `f` accepts it.
+    ///
+    /// Returns the result of `f` if the [`Instruction`] has been removed.
+    ///
+    /// # Note
+    ///
+    /// Only considers the last created [`Instruction`] if it is the last encoded
+    /// instruction word and thus is not followed by any parameter words.
+    pub fn try_remove_last_instr<T>(
+        &mut self,
+        f: impl FnOnce(&Instruction) -> Option<T>,
+    ) -> Option<T> {
+        let last_instr = self.last_instr?;
+        if last_instr.into_u32() + 1 != self.instrs.next_instr().into_u32() {
+            return None;
+        }
+        let value = f(self.instrs.get(last_instr))?;
+        self.instrs.remove(last_instr);
+        self.reset_last_instr();
+        if self.notified_preservation == Some(last_instr) {
+            self.notified_preservation = None;
+        }
+        Some(value)
     }
 
     /// Return an iterator over the sequence of generated [`Instruction`].
@@ -229,6 +466,26 @@ impl InstrEncoder {
         self.instrs.drain()
     }
 
+    /// Return an iterator over the recorded Wasm byte offsets of the generated [`Instruction`].
+    ///
+    /// # Note
+    ///
+    /// Each item associates the first [`Instr`] of a run of [`Instruction`]
+    /// words to the byte offset of the Wasm operator they were translated from.
+    pub fn drain_offsets(&mut self) -> Drain<'_, (Instr, usize)> {
+        self.instrs.drain_offsets()
+    }
+
+    /// Enables or disables recording the Wasm byte offsets of encoded [`Instruction`] words.
+    pub fn record_offsets(&mut self, enable: bool) {
+        self.instrs.record_offsets = enable;
+    }
+
+    /// Updates the byte offset of the currently translated Wasm operator.
+    pub fn update_pos(&mut self, pos: usize) {
+        self.instrs.update_pos(pos);
+    }
+
     /// Creates a new unresolved label and returns its [`LabelRef`].
     pub fn new_label(&mut self) -> LabelRef {
         self.labels.new_label()
@@ -244,6 +501,8 @@ impl InstrEncoder {
     /// the given label can be resolved properly.
     /// This usually takes place when encountering the Wasm `End` operand for example.
     pub fn pin_label_if_unpinned(&mut self, label: LabelRef) {
+        self.last_local_set = None;
+        self.last_pinned = Some(self.instrs.next_instr());
         self.labels.try_pin_label(label, self.instrs.next_instr())
     }
 
@@ -259,6 +518,8 @@ impl InstrEncoder {
     ///
     /// If the label has already been resolved.
     pub fn pin_label(&mut self, label: LabelRef) {
+        self.last_local_set = None;
+        self.last_pinned = Some(self.instrs.next_instr());
         self.labels
             .pin_label(label, self.instrs.next_instr())
             .unwrap_or_else(|err| panic!("failed to pin label: {err}"));
@@ -282,6 +543,8 @@ impl InstrEncoder {
         label: LabelRef,
         instr: Instr,
     ) -> Result<BranchOffset, Error> {
+        // Note: branches end the basic block and thus might observe stores to locals.
+        self.last_local_set = None;
         self.labels.try_resolve_label(label, instr)
     }
 
@@ -337,6 +600,24 @@ impl InstrEncoder {
             let cref = stack.alloc_const(value.into())?;
             Ok(Instruction::copy(result, cref))
         }
+        /// Convenience to create an [`Instruction::Copy`] to copy a 64-bit constant value.
+        ///
+        /// Encodes the `value` inline via `make_instr` unless it already has been
+        /// allocated as function local constant in which case it is copied from there.
+        fn copy_imm64(
+            stack: &ValueStack,
+            result: Register,
+            value: TypedValue,
+            make_instr: fn(Register) -> Instruction,
+            params: &mut Option<[Instruction; 2]>,
+        ) -> Instruction {
+            if let Some(cref) = stack.find_const(value) {
+                return Instruction::copy(result, cref);
+            }
+            *params = Some(Instruction::const64(value));
+            make_instr(result)
+        }
+        let mut params = None;
         let instr = match value {
             TypedProvider::Register(value) => {
                 if result == value {
@@ -350,18 +631,29 @@ impl InstrEncoder {
                 ValueType::F32 => Instruction::copy_imm32(result, f32::from(value)),
                 ValueType::I64 => match <Const32<i64>>::try_from(i64::from(value)).ok() {
                     Some(value) => Instruction::copy_i64imm32(result, value),
-                    None => copy_imm(stack, result, value)?,
+                    None => {
+                        self.emit_diagnostic(DiagnosticKind::ConstPoolOverflowToReg);
+                        copy_imm64(stack, result, value, Instruction::copy_i64imm, &mut params)
+                    }
                 },
                 ValueType::F64 => match <Const32<f64>>::try_from(f64::from(value)).ok() {
                     Some(value) => Instruction::copy_f64imm32(result, value),
-                    None => copy_imm(stack, result, value)?,
+                    None => {
+                        self.emit_diagnostic(DiagnosticKind::ConstPoolOverflowToReg);
+                        copy_imm64(stack, result, value, Instruction::copy_f64imm, &mut params)
+                    }
                 },
-                ValueType::FuncRef => copy_imm(stack, result, value)?,
-                ValueType::ExternRef => copy_imm(stack, result, value)?,
+                ValueType::FuncRef | ValueType::ExternRef => {
+                    self.emit_diagnostic(DiagnosticKind::ConstPoolOverflowToReg);
+                    copy_imm(stack, result, value)?
+                }
             },
         };
         self.bump_fuel_consumption(fuel_info, FuelCosts::base)?;
         let instr = self.push_instr(instr)?;
+        for param in params.into_iter().flatten() {
+            self.append_instr(param)?;
+        }
         Ok(Some(instr))
     }
 
@@ -413,6 +705,7 @@ impl InstrEncoder {
             }
             [v0, v1, rest @ ..] => {
                 debug_assert!(!rest.is_empty());
+                self.emit_diagnostic(DiagnosticKind::LargeCopyGroup { len: values.len() });
                 // Note: The fuel for copies might result in 0 charges if there aren't
                 //       enough copies to account for at least 1 fuel. Therefore we need
                 //       to also bump by `FuelCosts::base` to charge at least 1 fuel.
@@ -420,23 +713,23 @@ impl InstrEncoder {
                 self.bump_fuel_consumption(fuel_info, |costs| {
                     costs.fuel_for_copies(rest.len() as u64 + 3)
                 })?;
-                if let Some(values) = RegisterSpanIter::from_providers(values) {
-                    let make_instr = match Self::has_overlapping_copy_spans(
-                        results.span(),
-                        values.span(),
-                        values.len(),
-                    ) {
-                        true => Instruction::copy_span,
-                        false => Instruction::copy_span_non_overlapping,
+                let info = CopyInfo::new(results, values);
+                if let Some(values) = info.span {
+                    let len = values.len_as_u16();
+                    let make_instr = if len <= Instruction::COPY_SPAN_SMALL_MAX_LEN {
+                        // Note: small spans are copied via a temporary array on the
+                        //       machine stack which is cheap regardless of overlap.
+                        Instruction::copy_span_small
+                    } else {
+                        match info.overlapping {
+                            true => Instruction::copy_span,
+                            false => Instruction::copy_span_non_overlapping,
+                        }
                     };
-                    self.push_instr(make_instr(
-                        results.span(),
-                        values.span(),
-                        values.len_as_u16(),
-                    ))?;
+                    self.push_instr(make_instr(results.span(), values.span(), len))?;
                     return Ok(());
                 }
-                let make_instr = match Self::has_overlapping_copies(results, values) {
+                let make_instr = match info.overlapping {
                     true => Instruction::copy_many,
                     false => Instruction::copy_many_non_overlapping,
                 };
@@ -449,48 +742,6 @@ impl InstrEncoder {
         }
     }
 
-    /// Returns `true` if `copy_span results <- values` has overlapping copies.
-    ///
-    /// # Examples
-    ///
-    /// - `[ ]`: empty never overlaps
-    /// - `[ 1 <- 0 ]`: single element never overlaps
-    /// - `[ 0 <- 1, 1 <- 2, 2 <- 3 ]``: no overlap
-    /// - `[ 1 <- 0, 2 <- 1 ]`: overlaps!
-    fn has_overlapping_copy_spans(results: RegisterSpan, values: RegisterSpan, len: usize) -> bool {
-        RegisterSpanIter::has_overlapping_copies(results.iter(len), values.iter(len))
-    }
-
-    /// Returns `true` if the `copy results <- values` instruction has overlaps.
-    ///
-    /// # Examples
-    ///
-    /// - The sequence `[ 0 <- 1, 1 <- 1, 2 <- 4 ]` has no overlapping copies.
-    /// - The sequence `[ 0 <- 1, 1 <- 0 ]` has overlapping copies since register `0`
-    ///   is written to in the first copy but read from in the next.
-    /// - The sequence `[ 3 <- 1, 4 <- 2, 5 <- 3 ]` has overlapping copies since register `3`
-    ///   is written to in the first copy but read from in the third.
-    fn has_overlapping_copies(results: RegisterSpanIter, values: &[TypedProvider]) -> bool {
-        debug_assert_eq!(results.len(), values.len());
-        if results.is_empty() {
-            // Note: An empty set of copies can never have overlapping copies.
-            return false;
-        }
-        let result0 = results.span().head();
-        for (result, value) in results.zip(values) {
-            // Note: We only have to check the register case since constant value
-            //       copies can never overlap.
-            if let TypedProvider::Register(value) = *value {
-                // If the register `value` index is within range of `result0..result`
-                // then its value has been overwritten by previous copies.
-                if result0 <= value && value < result {
-                    return true;
-                }
-            }
-        }
-        false
-    }
-
     /// Bumps consumed fuel for [`Instruction::ConsumeFuel`] of `instr` by `delta`.
     ///
     /// # Errors
@@ -518,6 +769,23 @@ impl InstrEncoder {
         values: &[TypedProvider],
         fuel_info: FuelInfo,
     ) -> Result<(), Error> {
+        /// Convenience to create a `return` of a 64-bit constant value.
+        ///
+        /// Encodes the `value` inline via `instr` unless it already has been
+        /// allocated as function local constant in which case it is returned from there.
+        fn return_imm64(
+            stack: &ValueStack,
+            value: TypedValue,
+            instr: Instruction,
+            params: &mut Option<[Instruction; 2]>,
+        ) -> Instruction {
+            if let Some(cref) = stack.find_const(value) {
+                return Instruction::return_reg(cref);
+            }
+            *params = Some(Instruction::const64(value));
+            instr
+        }
+        let mut params = None;
         let instr = match values {
             [] => Instruction::Return,
             [TypedProvider::Register(reg)] => Instruction::return_reg(*reg),
@@ -525,12 +793,12 @@ impl InstrEncoder {
                 ValueType::I32 => Instruction::return_imm32(i32::from(*value)),
                 ValueType::I64 => match <Const32<i64>>::try_from(i64::from(*value)).ok() {
                     Some(value) => Instruction::return_i64imm32(value),
-                    None => Instruction::return_reg(stack.alloc_const(*value)?),
+                    None => return_imm64(stack, *value, Instruction::ReturnI64Imm, &mut params),
                 },
                 ValueType::F32 => Instruction::return_imm32(F32::from(*value)),
                 ValueType::F64 => match <Const32<f64>>::try_from(f64::from(*value)).ok() {
                     Some(value) => Instruction::return_f64imm32(value),
-                    None => Instruction::return_reg(stack.alloc_const(*value)?),
+                    None => return_imm64(stack, *value, Instruction::ReturnF64Imm, &mut params),
                 },
                 ValueType::FuncRef | ValueType::ExternRef => {
                     Instruction::return_reg(stack.alloc_const(*value)?)
@@ -569,10 +837,47 @@ impl InstrEncoder {
             }
         };
         self.bump_fuel_consumption(fuel_info, FuelCosts::base)?;
-        self.push_instr(instr)?;
+        if params.is_some() {
+            self.push_instr(instr)?;
+            for param in params.into_iter().flatten() {
+                self.append_instr(param)?;
+            }
+            return Ok(());
+        }
+        if !self.try_merge_return(instr) {
+            self.last_return = Some(self.push_instr(instr)?);
+        }
         Ok(())
     }
 
+    /// Tries to merge the single-word `return` instruction `instr` with an identical previous one.
+    ///
+    /// Returns `true` if `instr` has been merged and thus must not be encoded.
+    ///
+    /// # Note
+    ///
+    /// This is possible if the last encoded [`Instruction`] word is an equal `return` and
+    /// labels have been pinned to the current position. Since the previous `return`
+    /// does not fall through, the current position is only reachable via those labels
+    /// which are then re-pinned to the previous `return` instead.
+    ///
+    /// All users of the re-pinned labels are still awaiting deferred resolution
+    /// since no [`Instruction`] has been encoded since the labels have been pinned.
+    fn try_merge_return(&mut self, instr: Instruction) -> bool {
+        let next_instr = self.instrs.next_instr();
+        let Some(last_return) = self.last_return else {
+            return false;
+        };
+        if last_return.into_u32() + 1 != next_instr.into_u32()
+            || self.last_pinned != Some(next_instr)
+            || *self.instrs.get(last_return) != instr
+        {
+            return false;
+        }
+        self.labels.repin_labels(next_instr, last_return);
+        true
+    }
+
     /// Encodes an conditional `return` instruction.
     pub fn encode_return_nez(
         &mut self,
@@ -646,6 +951,9 @@ impl InstrEncoder {
 
     /// Encode the given slice of [`TypedProvider`] as a list of [`Register`].
     ///
+    /// Long runs of contiguous [`Register`] are encoded compactly
+    /// via [`Instruction::RegisterSpanList`] parameters.
+    ///
     /// # Note
     ///
     /// This is used for the following n-ary instructions:
@@ -687,6 +995,29 @@ impl InstrEncoder {
                     return Ok(());
                 }
                 [v0, v1, v2, rest @ ..] => {
+                    let len_run = Self::contiguous_registers(stack, remaining);
+                    let len_span = match len_run == remaining.len() {
+                        // Note: the list must always end with one of the non-list
+                        //       register parameters, therefore we split off the last 3.
+                        true => len_run - 3,
+                        false => len_run,
+                    };
+                    if len_span >= 4 {
+                        // Note: we only encode a span if it saves instruction words
+                        //       compared to a sequence of `register_list` parameters.
+                        let Provider::Register(head) = *v0 else {
+                            unreachable!("contiguous registers must start with a register")
+                        };
+                        let len = u16::try_from(len_span).unwrap_or_else(|_| {
+                            panic!("out of bounds length for register span: {len_span}")
+                        });
+                        self.instrs.push(Instruction::register_span_list(
+                            RegisterSpan::new(head),
+                            len,
+                        ))?;
+                        remaining = &remaining[len_span..];
+                        continue;
+                    }
                     let v0 = Self::provider2reg(stack, v0)?;
                     let v1 = Self::provider2reg(stack, v1)?;
                     let v2 = Self::provider2reg(stack, v2)?;
@@ -697,6 +1028,36 @@ impl InstrEncoder {
         }
     }
 
+    /// Returns the number of contiguous [`Register`] at the start of `providers`.
+    ///
+    /// # Note
+    ///
+    /// - Only [`Register`] of the same [`RegisterSpace`] are considered contiguous
+    ///   since register defragmentation might otherwise break up their contiguity.
+    /// - The returned number never exceeds `u16::MAX`.
+    fn contiguous_registers(stack: &ValueStack, providers: &[TypedProvider]) -> usize {
+        let [Provider::Register(head), rest @ ..] = providers else {
+            return 0;
+        };
+        let space = stack.get_register_space(*head);
+        let mut last = *head;
+        let mut len = 1;
+        for provider in rest {
+            match *provider {
+                Provider::Register(register)
+                    if register == last.next()
+                        && stack.get_register_space(register) == space
+                        && len < usize::from(u16::MAX) =>
+                {
+                    last = register;
+                    len += 1;
+                }
+                _ => break,
+            }
+        }
+        len
+    }
+
     /// Encode a `local.set` or `local.tee` instruction.
     ///
     /// This also applies an optimization in that the previous instruction
@@ -729,6 +1090,11 @@ impl InstrEncoder {
                 this.bump_fuel_consumption(fuel_info, FuelCosts::base)?;
                 let preserve_instr = this.push_instr(Instruction::copy(preserved, local))?;
                 this.notify_preserved_register(preserve_instr);
+            } else if let TypedProvider::Register(value) = value {
+                if this.try_fuse_copy2(stack, local, value) {
+                    this.bump_fuel_consumption(fuel_info, FuelCosts::base)?;
+                    return Ok(());
+                }
             }
             this.encode_copy(stack, local, value, fuel_info)?;
             Ok(())
@@ -750,6 +1116,9 @@ impl InstrEncoder {
             // is _NOT_ itself a local register due to observable behavior.
             return fallback_case(self, stack, local, value, preserved, fuel_info);
         }
+        if !self.fusions.is_enabled(FusionKind::CopyElision) {
+            return fallback_case(self, stack, local, value, preserved, fuel_info);
+        }
         let Some(last_instr) = self.last_instr else {
             // Can only apply the optimization if there is a previous instruction
             // to replace its result register instead of emitting a copy.
@@ -764,6 +1133,14 @@ impl InstrEncoder {
             // Thankfully most instructions are small enough.
             return fallback_case(self, stack, local, value, preserved, fuel_info);
         }
+        let shift = last_instr.distance(self.instrs.next_instr());
+        if preserved.is_some() && !self.can_shift_for_preservation(shift) {
+            // We avoid applying the optimization if it would exceed the configured
+            // maximum number of instruction words shifted for the function.
+            // This bounds the cost of translating functions with lots of preserved
+            // `local.set` instructions.
+            return fallback_case(self, stack, local, value, preserved, fuel_info);
+        }
         if !self
             .instrs
             .get_mut(last_instr)
@@ -777,6 +1154,7 @@ impl InstrEncoder {
             // Preservation requires the copy to be before the optimized last instruction.
             // Therefore we need to push the preservation `copy` instruction before it.
             self.bump_fuel_consumption(fuel_info, FuelCosts::base)?;
+            self.preservation_shift = self.preservation_shift.saturating_add(shift);
             let shifted_last_instr = self
                 .instrs
                 .push_before(last_instr, Instruction::copy(preserved, local))?;
@@ -786,6 +1164,127 @@ impl InstrEncoder {
         Ok(())
     }
 
+    /// Returns `true` if `shift` more instruction words may be shifted for `local.set` preservations.
+    fn can_shift_for_preservation(&self, shift: u32) -> bool {
+        match self.max_preservation_shift {
+            Some(limit) => self.preservation_shift.saturating_add(shift) <= limit,
+            None => true,
+        }
+    }
+
+    /// Tries to fuse `copy local <- value` with the last [`Instruction::Copy`] into an [`Instruction::Copy2`].
+    ///
+    /// Returns `true` if fusion was successful.
+    ///
+    /// # Note
+    ///
+    /// This collapses `local.tee` chains such as `local.tee $a` immediately followed
+    /// by `local.set $b` where the `local.tee` had to encode `copy $a <- t` since
+    /// its non-local value `t` was not the result of the last instruction.
+    /// Instead of encoding `copy $b <- $a` we replace both copies with
+    /// `copy2 [$a, $b] <- [t, t]`.
+    ///
+    /// Fusion is only applied if
+    ///
+    /// - `value` is the local `$a` stored to by the last [`Instruction::Copy`],
+    ///   which implies that `$a` has not been read since,
+    /// - `t` is not itself a local variable since its value might otherwise be observed
+    ///   differently, and
+    /// - `$a` and `local` are adjacent so that they form a [`RegisterSpan`].
+    ///
+    /// The caller is responsible to only fuse if `local` does not need to be preserved.
+    fn try_fuse_copy2(&mut self, stack: &ValueStack, local: Register, value: Register) -> bool {
+        if !self.fusions.is_enabled(FusionKind::CopyElision) {
+            return false;
+        }
+        let Some(last_instr) = self.last_instr else {
+            return false;
+        };
+        let Instruction::Copy {
+            result,
+            value: source,
+        } = *self.instrs.get(last_instr)
+        else {
+            return false;
+        };
+        if result != value
+            || !matches!(stack.get_register_space(value), RegisterSpace::Local)
+            || matches!(stack.get_register_space(source), RegisterSpace::Local)
+        {
+            return false;
+        }
+        let results = if local == value.next() {
+            RegisterSpan::new(value)
+        } else if local == value.prev() {
+            RegisterSpan::new(local)
+        } else {
+            return false;
+        };
+        *self.instrs.get_mut(last_instr) = Instruction::copy2(results, source, source);
+        true
+    }
+
+    /// Notifies the [`InstrEncoder`] that `local` has just been stored to by a `local.set` or `local.tee`.
+    ///
+    /// # Note
+    ///
+    /// This performs dead-store elimination within the current basic block:
+    /// if the previous store to `local` has not been read since, it is removed.
+    ///
+    /// The elimination is conservative and is only applied if
+    ///
+    /// - the previous store is a pure single-word [`Instruction`] that only writes to `local`, and
+    /// - all [`Instruction`] encoded since are pure single-word instructions not reading `local`.
+    ///
+    /// Control flow in between the stores is detected since it either pins or resolves labels,
+    /// or ends a control block which resets the dead-store elimination state.
+    pub fn notify_local_set(&mut self, local: Register) {
+        if let Some(prev) = self.last_local_set.take() {
+            if prev.local == local && self.is_dead_local_set(prev) {
+                self.instrs.remove(prev.instr);
+                let shift = |instr: &mut Instr| {
+                    if *instr > prev.instr {
+                        *instr = Instr::from_u32(instr.into_u32() - 1);
+                    }
+                };
+                if let Some(last_instr) = &mut self.last_instr {
+                    shift(last_instr);
+                }
+                if let Some(notified) = &mut self.notified_preservation {
+                    shift(notified);
+                }
+            }
+        }
+        let Some(last_instr) = self.last_instr else {
+            return;
+        };
+        if pure_instr_result(self.instrs.get(last_instr)) == Some(local) {
+            self.last_local_set = Some(LocalSetInstr {
+                local,
+                instr: last_instr,
+            });
+        }
+    }
+
+    /// Returns `true` if the store of `local_set` is dead and can be removed.
+    ///
+    /// # Note
+    ///
+    /// This is the case if the store is a pure [`Instruction`] that is followed
+    /// only by pure [`Instruction`] that do not read the stored local variable.
+    fn is_dead_local_set(&mut self, local_set: LocalSetInstr) -> bool {
+        let LocalSetInstr { local, instr } = local_set;
+        if pure_instr_result(self.instrs.get(instr)) != Some(local) {
+            return false;
+        }
+        let start = instr.into_usize() + 1;
+        self.instrs.instrs[start..].iter().all(|instr| {
+            let mut reads_local = false;
+            let is_pure = pure_instr_inputs(instr, |input| reads_local |= input == local);
+            is_pure && !reads_local
+        })
+    }
+
     /// Notifies the [`InstrEncoder`] that a local variable has been preserved.
     ///
     /// # Note
@@ -821,12 +1320,19 @@ impl InstrEncoder {
 
     /// Translates a Wasm `i32.eqz` instruction.
     ///
-    /// Tries to fuse `i32.eqz` with a previous `i32.{and,or,xor}` instruction if possible.
+    /// Tries to fuse `i32.eqz` of `input` with a previous `i32.{and,or,xor}` instruction if possible.
     /// Returns `true` if it was possible to fuse the `i32.eqz` instruction.
-    pub fn fuse_i32_eqz(&mut self, stack: &mut ValueStack) -> bool {
+    ///
+    /// # Note
+    ///
+    /// Fusion is only possible if `input` is the result of the previous instruction.
+    pub fn fuse_i32_eqz(&mut self, stack: &mut ValueStack, input: Register) -> bool {
         /// Fuse a `i32.{and,or,xor}` instruction with `i32.eqz`.
         macro_rules! fuse {
             ($instr:ident, $stack:ident, $make_fuse:expr) => {{
+                if $instr.result != input {
+                    return false;
+                }
                 if matches!(
                     $stack.get_register_space($instr.result),
                     RegisterSpace::Local
@@ -840,6 +1346,9 @@ impl InstrEncoder {
         /// Fuse a `i32.{and,or,xor}` instruction with 16-bit encoded immediate parameter with `i32.eqz`.
         macro_rules! fuse_imm16 {
             ($instr:ident, $stack:ident, $make_fuse:expr) => {{
+                if $instr.result != input {
+                    return false;
+                }
                 if matches!(
                     $stack.get_register_space($instr.result),
                     RegisterSpace::Local
@@ -852,6 +1361,9 @@ impl InstrEncoder {
             }};
         }
 
+        if !self.fusions.is_enabled(FusionKind::EqzLogic) {
+            return false;
+        }
         let Some(last_instr) = self.last_instr else {
             return false;
         };
@@ -874,12 +1386,281 @@ impl InstrEncoder {
         true
     }
 
+    /// Translates a Wasm binary operation of `lhs` and `rhs` fused with a previous `load` instruction.
+    ///
+    /// Tries to fuse the binary operation with a previous `i32.load` or `i64.load` instruction
+    /// of type `load_ty` into a single `make_instr` load-op instruction.
+    /// Returns `true` if it was possible to fuse the binary operation.
+    ///
+    /// # Note
+    ///
+    /// - Fusion is only possible if `rhs` is the result of the previous `load` instruction.
+    ///   If `commutative` is `true` fusion is also possible if `lhs` is its result.
+    /// - The fused instruction stores its result into a newly allocated dynamic register.
+    pub fn fuse_load_op(
+        &mut self,
+        stack: &mut ValueStack,
+        lhs: Register,
+        rhs: Register,
+        load_ty: ValueType,
+        commutative: bool,
+        make_instr: fn(result: Register, operand: Register, ptr: Register) -> Instruction,
+    ) -> Result<bool, Error> {
+        if !self.fusions.is_enabled(FusionKind::LoadOp) {
+            return Ok(false);
+        }
+        let Some(last_instr) = self.last_instr else {
+            return Ok(false);
+        };
+        let (loaded, ptr, offset16) = match (load_ty, *self.instrs.get(last_instr)) {
+            (ValueType::I32, Instruction::I32Load(instr))
+            | (ValueType::I64, Instruction::I64Load(instr)) => (instr.result, instr.ptr, None),
+            (ValueType::I32, Instruction::I32LoadOffset16(instr))
+            | (ValueType::I64, Instruction::I64LoadOffset16(instr)) => {
+                (instr.result, instr.ptr, Some(instr.offset))
+            }
+            _ => return Ok(false),
+        };
+        let operand = match (lhs == loaded, rhs == loaded) {
+            (false, true) => lhs,
+            (true, false) if commutative => rhs,
+            _ => return Ok(false),
+        };
+        if matches!(stack.get_register_space(loaded), RegisterSpace::Local) {
+            // Must not fuse instruction that store to local registers since
+            // this behavior is observable and would not be semantics preserving.
+            return Ok(false);
+        }
+        // The `offset` of the fused instruction is always encoded in a succeeding `Const32`.
+        let len_load = if offset16.is_some() { 1 } else { 2 };
+        if last_instr.distance(self.instrs.next_instr()) != len_load {
+            // The `load` instruction is not the last encoded instruction.
+            return Ok(false);
+        }
+        let result = stack.push_dynamic()?;
+        _ = mem::replace(
+            self.instrs.get_mut(last_instr),
+            make_instr(result, operand, ptr),
+        );
+        if let Some(offset) = offset16 {
+            self.append_instr(Instruction::const32(u32::from(offset)))?;
+        }
+        Ok(true)
+    }
+
+    /// Translates a Wasm `select` instruction with [`Register`] `lhs` and `rhs` operands.
+    ///
+    /// Tries to fuse `select` of `condition` with a previous integer comparison instruction if possible.
+    /// Returns `true` if it was possible to fuse the `select` instruction.
+    ///
+    /// # Note
+    ///
+    /// Fusion is only possible if `condition` is the result of the previous instruction.
+    pub fn fuse_select(
+        &mut self,
+        stack: &mut ValueStack,
+        result: Register,
+        condition: Register,
+        lhs: Register,
+        rhs: Register,
+    ) -> Result<bool, Error> {
+        /// Fuse an integer comparison instruction with `select`.
+        ///
+        /// Use `swap` to swap the operands of the comparison, e.g. to express `a > b` as `b < a`.
+        macro_rules! fuse {
+            ($instr:ident, $stack:ident, $make_fuse:expr) => {{
+                fuse!(@check $instr, $stack);
+                $make_fuse(result, $instr.lhs, $instr.rhs)
+            }};
+            ($instr:ident, $stack:ident, $make_fuse:expr, swap) => {{
+                fuse!(@check $instr, $stack);
+                $make_fuse(result, $instr.rhs, $instr.lhs)
+            }};
+            (@check $instr:ident, $stack:ident) => {{
+                if $instr.result != condition {
+                    return Ok(false);
+                }
+                if matches!(
+                    $stack.get_register_space($instr.result),
+                    RegisterSpace::Local
+                ) {
+                    // Must not fuse instruction that store to local registers since
+                    // this behavior is observable and would not be semantics preserving.
+                    return Ok(false);
+                }
+            }};
+        }
+
+        if !self.fusions.is_enabled(FusionKind::CmpSelect) {
+            return Ok(false);
+        }
+        let Some(last_instr) = self.last_instr else {
+            return Ok(false);
+        };
+        #[rustfmt::skip]
+        let fused_instr = match self.instrs.get(last_instr) {
+            Instruction::I32Eq(instr) => fuse!(instr, stack, Instruction::select_i32_eq),
+            Instruction::I32Ne(instr) => fuse!(instr, stack, Instruction::select_i32_ne),
+            Instruction::I32LtS(instr) => fuse!(instr, stack, Instruction::select_i32_lt_s),
+            Instruction::I32LtU(instr) => fuse!(instr, stack, Instruction::select_i32_lt_u),
+            Instruction::I32LeS(instr) => fuse!(instr, stack, Instruction::select_i32_le_s),
+            Instruction::I32LeU(instr) => fuse!(instr, stack, Instruction::select_i32_le_u),
+            Instruction::I32GtS(instr) => fuse!(instr, stack, Instruction::select_i32_lt_s, swap),
+            Instruction::I32GtU(instr) => fuse!(instr, stack, Instruction::select_i32_lt_u, swap),
+            Instruction::I32GeS(instr) => fuse!(instr, stack, Instruction::select_i32_le_s, swap),
+            Instruction::I32GeU(instr) => fuse!(instr, stack, Instruction::select_i32_le_u, swap),
+            Instruction::I64Eq(instr) => fuse!(instr, stack, Instruction::select_i64_eq),
+            Instruction::I64Ne(instr) => fuse!(instr, stack, Instruction::select_i64_ne),
+            Instruction::I64LtS(instr) => fuse!(instr, stack, Instruction::select_i64_lt_s),
+            Instruction::I64LtU(instr) => fuse!(instr, stack, Instruction::select_i64_lt_u),
+            Instruction::I64LeS(instr) => fuse!(instr, stack, Instruction::select_i64_le_s),
+            Instruction::I64LeU(instr) => fuse!(instr, stack, Instruction::select_i64_le_u),
+            Instruction::I64GtS(instr) => fuse!(instr, stack, Instruction::select_i64_lt_s, swap),
+            Instruction::I64GtU(instr) => fuse!(instr, stack, Instruction::select_i64_lt_u, swap),
+            Instruction::I64GeS(instr) => fuse!(instr, stack, Instruction::select_i64_le_s, swap),
+            Instruction::I64GeU(instr) => fuse!(instr, stack, Instruction::select_i64_le_u, swap),
+            _ => return Ok(false),
+        };
+        _ = mem::replace(self.instrs.get_mut(last_instr), fused_instr);
+        self.append_instr(Instruction::register2(lhs, rhs))?;
+        Ok(true)
+    }
+
+    /// Returns `true` if a conditional branch to `label` requires a 32-bit [`BranchOffset`].
+    ///
+    /// # Note
+    ///
+    /// The offset of a forward branch is unknown at this point and only
+    /// requires a 32-bit [`BranchOffset`] if wide branches are enabled.
+    fn requires_wide_branch(&self, label: LabelRef) -> Result<bool, Error> {
+        let Some(target) = self.labels.pinned_to(label) else {
+            return Ok(self.wide_branches);
+        };
+        // Note: fused branch instructions are located before the next instruction
+        //       and therefore their backward branch offsets are never larger.
+        let offset = BranchOffset::from_src_to_dst(self.instrs.next_instr(), target)?;
+        Ok(BranchOffset16::new(offset).is_none())
+    }
+
+    /// Encodes a conditional branch to `label` that requires a 32-bit [`BranchOffset`].
+    ///
+    /// Encodes `skip` as conditional branch with the inverted condition over an
+    /// unconditional [`Instruction::Branch`] with a 32-bit [`BranchOffset`] to `label`.
+    fn encode_wide_branch(
+        &mut self,
+        stack: &mut ValueStack,
+        condition: Register,
+        label: LabelRef,
+        skip: fn(&mut Self, &mut ValueStack, Register, LabelRef) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let skip_label = self.new_label();
+        skip(self, stack, condition, skip_label)?;
+        let offset = self.try_resolve_label(label)?;
+        self.push_instr(Instruction::branch(offset))?;
+        self.pin_label(skip_label);
+        Ok(())
+    }
+
     /// Encodes a `branch_eqz` instruction and tries to fuse it with a previous comparison instruction.
+    ///
+    /// Falls back to a 32-bit [`BranchOffset`] if the branch offset to `label` might be out of
+    /// bounds for a 16-bit [`BranchOffset16`] at the cost of an additional instruction.
     pub fn encode_branch_eqz(
         &mut self,
         stack: &mut ValueStack,
         condition: Register,
         label: LabelRef,
+    ) -> Result<(), Error> {
+        if self.requires_wide_branch(label)? {
+            return self.encode_wide_branch(stack, condition, label, Self::encode_branch_nez16);
+        }
+        self.encode_branch_eqz16(stack, condition, label)
+    }
+
+    /// Encodes a `branch_nez` instruction and tries to fuse it with a previous comparison instruction.
+    ///
+    /// Falls back to a 32-bit [`BranchOffset`] if the branch offset to `label` might be out of
+    /// bounds for a 16-bit [`BranchOffset16`] at the cost of an additional instruction.
+    pub fn encode_branch_nez(
+        &mut self,
+        stack: &mut ValueStack,
+        condition: Register,
+        label: LabelRef,
+    ) -> Result<(), Error> {
+        if self.requires_wide_branch(label)? {
+            return self.encode_wide_branch(stack, condition, label, Self::encode_branch_eqz16);
+        }
+        self.encode_branch_nez16(stack, condition, label)
+    }
+
+    /// Tries to move the [`Instruction`] computing `condition` right before a conditional branch.
+    ///
+    /// # Note
+    ///
+    /// Comparisons can only be fused with a conditional branch if they are the last
+    /// encoded [`Instruction`]. Therefore this swaps the last two [`Instruction`] if the
+    /// first computes `condition` and the second is independent of it. This is only
+    /// applied if it is safe to do so, that is if
+    ///
+    /// - both are pure single-word [`Instruction`] without side effects,
+    /// - no label has been pinned to either of them,
+    /// - the second does not read `condition`, and
+    /// - the second does not write `condition` or any [`Register`] read by the first.
+    fn try_schedule_condition(&mut self, stack: &ValueStack, condition: Register) {
+        if matches!(stack.get_register_space(condition), RegisterSpace::Local) {
+            // Note: conditions stored in local variables are never fused.
+            return;
+        }
+        let Some(last_instr) = self.last_instr else {
+            return;
+        };
+        if last_instr.into_u32() + 1 != self.instrs.next_instr().into_u32() {
+            return;
+        }
+        let Some(prev_instr) = last_instr.into_u32().checked_sub(1).map(Instr::from_u32) else {
+            return;
+        };
+        if self.last_pinned.is_some_and(|pinned| pinned > prev_instr) {
+            return;
+        }
+        let prev = *self.instrs.get(prev_instr);
+        let last = *self.instrs.get(last_instr);
+        if pure_instr_result(&prev) != Some(condition) {
+            return;
+        }
+        let Some(last_result) = pure_instr_result(&last) else {
+            return;
+        };
+        if last_result == condition {
+            return;
+        }
+        let mut reads_condition = false;
+        pure_instr_inputs(&last, |input| reads_condition |= input == condition);
+        let mut overwrites_input = false;
+        pure_instr_inputs(&prev, |input| overwrites_input |= input == last_result);
+        if reads_condition || overwrites_input {
+            return;
+        }
+        *self.instrs.get_mut(prev_instr) = last;
+        *self.instrs.get_mut(last_instr) = prev;
+        if let Some(local_set) = &mut self.last_local_set {
+            if local_set.instr == last_instr {
+                local_set.instr = prev_instr;
+            }
+        }
+        if self.notified_preservation == Some(last_instr) {
+            self.notified_preservation = Some(prev_instr);
+        }
+    }
+
+    /// Encodes a `branch_eqz` instruction with a 16-bit [`BranchOffset16`].
+    ///
+    /// Tries to fuse the `branch_eqz` with a previous comparison instruction.
+    fn encode_branch_eqz16(
+        &mut self,
+        stack: &mut ValueStack,
+        condition: Register,
+        label: LabelRef,
     ) -> Result<(), Error> {
         type BranchCmpConstructor = fn(Register, Register, BranchOffset16) -> Instruction;
         type BranchCmpImmConstructor<T> = fn(Register, Const16<T>, BranchOffset16) -> Instruction;
@@ -906,10 +1687,16 @@ impl InstrEncoder {
             this: &mut InstrEncoder,
             stack: &mut ValueStack,
             last_instr: Instr,
+            condition: Register,
             instr: BinInstr,
             label: LabelRef,
             make_instr: BranchCmpConstructor,
         ) -> Result<Option<Instruction>, Error> {
+            if instr.result != condition {
+                // The branch condition is not the result of the last instruction
+                // and therefore the last instruction must not be fused with the branch.
+                return Ok(None);
+            }
             if matches!(stack.get_register_space(instr.result), RegisterSpace::Local) {
                 // We need to filter out instructions that store their result
                 // into a local register slot because they introduce observable behavior
@@ -929,10 +1716,16 @@ impl InstrEncoder {
             this: &mut InstrEncoder,
             stack: &mut ValueStack,
             last_instr: Instr,
+            condition: Register,
             instr: BinInstrImm16<T>,
             label: LabelRef,
             make_instr: BranchCmpImmConstructor<T>,
         ) -> Result<Option<Instruction>, Error> {
+            if instr.result != condition {
+                // The branch condition is not the result of the last instruction
+                // and therefore the last instruction must not be fused with the branch.
+                return Ok(None);
+            }
             if matches!(stack.get_register_space(instr.result), RegisterSpace::Local) {
                 // We need to filter out instructions that store their result
                 // into a local register slot because they introduce observable behavior
@@ -946,13 +1739,17 @@ impl InstrEncoder {
         }
         use Instruction as I;
 
+        if !self.fusions.is_enabled(FusionKind::CmpBranch) {
+            return encode_branch_eqz_fallback(self, condition, label);
+        }
+        self.try_schedule_condition(stack, condition);
         let Some(last_instr) = self.last_instr else {
             return encode_branch_eqz_fallback(self, condition, label);
         };
 
         #[rustfmt::skip]
         let fused_instr = match *self.instrs.get(last_instr) {
-            I::I32EqImm16(instr) if instr.imm_in.is_zero() => {
+            I::I32EqImm16(instr) if instr.imm_in.is_zero() && instr.result == condition => {
                 match stack.get_register_space(instr.result) {
                     RegisterSpace::Local => None,
                     _ => {
@@ -962,7 +1759,7 @@ impl InstrEncoder {
                     }
                 }
             }
-            I::I64EqImm16(instr) if instr.imm_in.is_zero() => {
+            I::I64EqImm16(instr) if instr.imm_in.is_zero() && instr.result == condition => {
                 match stack.get_register_space(instr.result) {
                     RegisterSpace::Local => None,
                     _ => {
@@ -972,7 +1769,7 @@ impl InstrEncoder {
                     }
                 }
             }
-            I::I32NeImm16(instr) if instr.imm_in.is_zero() => {
+            I::I32NeImm16(instr) if instr.imm_in.is_zero() && instr.result == condition => {
                 match stack.get_register_space(instr.result) {
                     RegisterSpace::Local => None,
                     _ => {
@@ -982,7 +1779,7 @@ impl InstrEncoder {
                     }
                 }
             }
-            I::I64NeImm16(instr) if instr.imm_in.is_zero() => {
+            I::I64NeImm16(instr) if instr.imm_in.is_zero() && instr.result == condition => {
                 match stack.get_register_space(instr.result) {
                     RegisterSpace::Local => None,
                     _ => {
@@ -992,61 +1789,61 @@ impl InstrEncoder {
                     }
                 }
             }
-            I::I32And(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_and_eqz as _)?,
-            I::I32Or(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_or_eqz as _)?,
-            I::I32Xor(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_xor_eqz as _)?,
-            I::I32AndEqz(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_and as _)?,
-            I::I32OrEqz(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_or as _)?,
-            I::I32XorEqz(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_xor as _)?,
-            I::I32Eq(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_ne as _)?,
-            I::I32Ne(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_eq as _)?,
-            I::I32LtS(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_ge_s as _)?,
-            I::I32LtU(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_ge_u as _)?,
-            I::I32LeS(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_gt_s as _)?,
-            I::I32LeU(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_gt_u as _)?,
-            I::I32GtS(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_le_s as _)?,
-            I::I32GtU(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_le_u as _)?,
-            I::I32GeS(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_lt_s as _)?,
-            I::I32GeU(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_lt_u as _)?,
-            I::I64Eq(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_ne as _)?,
-            I::I64Ne(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_eq as _)?,
-            I::I64LtS(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_ge_s as _)?,
-            I::I64LtU(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_ge_u as _)?,
-            I::I64LeS(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_gt_s as _)?,
-            I::I64LeU(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_gt_u as _)?,
-            I::I64GtS(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_le_s as _)?,
-            I::I64GtU(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_le_u as _)?,
-            I::I64GeS(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_lt_s as _)?,
-            I::I64GeU(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_lt_u as _)?,
-            I::F32Eq(instr) => fuse(self, stack, last_instr, instr, label, I::branch_f32_ne as _)?,
-            I::F32Ne(instr) => fuse(self, stack, last_instr, instr, label, I::branch_f32_eq as _)?,
+            I::I32And(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_and_eqz as _)?,
+            I::I32Or(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_or_eqz as _)?,
+            I::I32Xor(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_xor_eqz as _)?,
+            I::I32AndEqz(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_and as _)?,
+            I::I32OrEqz(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_or as _)?,
+            I::I32XorEqz(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_xor as _)?,
+            I::I32Eq(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_ne as _)?,
+            I::I32Ne(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_eq as _)?,
+            I::I32LtS(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_ge_s as _)?,
+            I::I32LtU(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_ge_u as _)?,
+            I::I32LeS(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_gt_s as _)?,
+            I::I32LeU(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_gt_u as _)?,
+            I::I32GtS(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_le_s as _)?,
+            I::I32GtU(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_le_u as _)?,
+            I::I32GeS(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_lt_s as _)?,
+            I::I32GeU(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_lt_u as _)?,
+            I::I64Eq(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_ne as _)?,
+            I::I64Ne(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_eq as _)?,
+            I::I64LtS(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_ge_s as _)?,
+            I::I64LtU(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_ge_u as _)?,
+            I::I64LeS(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_gt_s as _)?,
+            I::I64LeU(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_gt_u as _)?,
+            I::I64GtS(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_le_s as _)?,
+            I::I64GtU(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_le_u as _)?,
+            I::I64GeS(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_lt_s as _)?,
+            I::I64GeU(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_lt_u as _)?,
+            I::F32Eq(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_f32_ne as _)?,
+            I::F32Ne(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_f32_eq as _)?,
             // Note: We cannot fuse cmp+branch for float comparison operators due to how NaN values are treated.
-            I::I32AndImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_and_eqz_imm as _)?,
-            I::I32OrImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_or_eqz_imm as _)?,
-            I::I32XorImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_xor_eqz_imm as _)?,
-            I::I32AndEqzImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_and_imm as _)?,
-            I::I32OrEqzImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_or_imm as _)?,
-            I::I32XorEqzImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_xor_imm as _)?,
-            I::I32EqImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_ne_imm as _)?,
-            I::I32NeImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_eq_imm as _)?,
-            I::I32LtSImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_ge_s_imm as _)?,
-            I::I32LtUImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_ge_u_imm as _)?,
-            I::I32LeSImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_gt_s_imm as _)?,
-            I::I32LeUImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_gt_u_imm as _)?,
-            I::I32GtSImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_le_s_imm as _)?,
-            I::I32GtUImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_le_u_imm as _)?,
-            I::I32GeSImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_lt_s_imm as _)?,
-            I::I32GeUImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_lt_u_imm as _)?,
-            I::I64EqImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_ne_imm as _)?,
-            I::I64NeImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_eq_imm as _)?,
-            I::I64LtSImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_ge_s_imm as _)?,
-            I::I64LtUImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_ge_u_imm as _)?,
-            I::I64LeSImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_gt_s_imm as _)?,
-            I::I64LeUImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_gt_u_imm as _)?,
-            I::I64GtSImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_le_s_imm as _)?,
-            I::I64GtUImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_le_u_imm as _)?,
-            I::I64GeSImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_lt_s_imm as _)?,
-            I::I64GeUImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_lt_u_imm as _)?,
+            I::I32AndImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_and_eqz_imm as _)?,
+            I::I32OrImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_or_eqz_imm as _)?,
+            I::I32XorImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_xor_eqz_imm as _)?,
+            I::I32AndEqzImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_and_imm as _)?,
+            I::I32OrEqzImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_or_imm as _)?,
+            I::I32XorEqzImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_xor_imm as _)?,
+            I::I32EqImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_ne_imm as _)?,
+            I::I32NeImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_eq_imm as _)?,
+            I::I32LtSImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_ge_s_imm as _)?,
+            I::I32LtUImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_ge_u_imm as _)?,
+            I::I32LeSImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_gt_s_imm as _)?,
+            I::I32LeUImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_gt_u_imm as _)?,
+            I::I32GtSImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_le_s_imm as _)?,
+            I::I32GtUImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_le_u_imm as _)?,
+            I::I32GeSImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_lt_s_imm as _)?,
+            I::I32GeUImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_lt_u_imm as _)?,
+            I::I64EqImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_ne_imm as _)?,
+            I::I64NeImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_eq_imm as _)?,
+            I::I64LtSImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_ge_s_imm as _)?,
+            I::I64LtUImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_ge_u_imm as _)?,
+            I::I64LeSImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_gt_s_imm as _)?,
+            I::I64LeUImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_gt_u_imm as _)?,
+            I::I64GtSImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_le_s_imm as _)?,
+            I::I64GtUImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_le_u_imm as _)?,
+            I::I64GeSImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_lt_s_imm as _)?,
+            I::I64GeUImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_lt_u_imm as _)?,
             _ => None,
         };
         if let Some(fused_instr) = fused_instr {
@@ -1056,8 +1853,10 @@ impl InstrEncoder {
         encode_branch_eqz_fallback(self, condition, label)
     }
 
-    /// Encodes a `branch_nez` instruction and tries to fuse it with a previous comparison instruction.
-    pub fn encode_branch_nez(
+    /// Encodes a `branch_nez` instruction with a 16-bit [`BranchOffset16`].
+    ///
+    /// Tries to fuse the `branch_nez` with a previous comparison instruction.
+    fn encode_branch_nez16(
         &mut self,
         stack: &mut ValueStack,
         condition: Register,
@@ -1088,10 +1887,16 @@ impl InstrEncoder {
             this: &mut InstrEncoder,
             stack: &mut ValueStack,
             last_instr: Instr,
+            condition: Register,
             instr: BinInstr,
             label: LabelRef,
             make_instr: BranchCmpConstructor,
         ) -> Result<Option<Instruction>, Error> {
+            if instr.result != condition {
+                // The branch condition is not the result of the last instruction
+                // and therefore the last instruction must not be fused with the branch.
+                return Ok(None);
+            }
             if matches!(stack.get_register_space(instr.result), RegisterSpace::Local) {
                 // We need to filter out instructions that store their result
                 // into a local register slot because they introduce observable behavior
@@ -1111,10 +1916,16 @@ impl InstrEncoder {
             this: &mut InstrEncoder,
             stack: &mut ValueStack,
             last_instr: Instr,
+            condition: Register,
             instr: BinInstrImm16<T>,
             label: LabelRef,
             make_instr: BranchCmpImmConstructor<T>,
         ) -> Result<Option<Instruction>, Error> {
+            if instr.result != condition {
+                // The branch condition is not the result of the last instruction
+                // and therefore the last instruction must not be fused with the branch.
+                return Ok(None);
+            }
             if matches!(stack.get_register_space(instr.result), RegisterSpace::Local) {
                 // We need to filter out instructions that store their result
                 // into a local register slot because they introduce observable behavior
@@ -1128,13 +1939,17 @@ impl InstrEncoder {
         }
         use Instruction as I;
 
+        if !self.fusions.is_enabled(FusionKind::CmpBranch) {
+            return encode_branch_nez_fallback(self, condition, label);
+        }
+        self.try_schedule_condition(stack, condition);
         let Some(last_instr) = self.last_instr else {
             return encode_branch_nez_fallback(self, condition, label);
         };
 
         #[rustfmt::skip]
         let fused_instr = match *self.instrs.get(last_instr) {
-            I::I32EqImm16(instr) if instr.imm_in.is_zero() => {
+            I::I32EqImm16(instr) if instr.imm_in.is_zero() && instr.result == condition => {
                 match stack.get_register_space(instr.result) {
                     RegisterSpace::Local => None,
                     _ => {
@@ -1144,7 +1959,7 @@ impl InstrEncoder {
                     }
                 }
             }
-            I::I64EqImm16(instr) if instr.imm_in.is_zero() => {
+            I::I64EqImm16(instr) if instr.imm_in.is_zero() && instr.result == condition => {
                 match stack.get_register_space(instr.result) {
                     RegisterSpace::Local => None,
                     _ => {
@@ -1154,7 +1969,7 @@ impl InstrEncoder {
                     }
                 }
             }
-            I::I32NeImm16(instr) if instr.imm_in.is_zero() => {
+            I::I32NeImm16(instr) if instr.imm_in.is_zero() && instr.result == condition => {
                 match stack.get_register_space(instr.result) {
                     RegisterSpace::Local => None,
                     _ => {
@@ -1164,7 +1979,7 @@ impl InstrEncoder {
                     }
                 }
             }
-            I::I64NeImm16(instr) if instr.imm_in.is_zero() => {
+            I::I64NeImm16(instr) if instr.imm_in.is_zero() && instr.result == condition => {
                 match stack.get_register_space(instr.result) {
                     RegisterSpace::Local => None,
                     _ => {
@@ -1174,70 +1989,70 @@ impl InstrEncoder {
                     }
                 }
             }
-            I::I32And(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_and as _)?,
-            I::I32Or(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_or as _)?,
-            I::I32Xor(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_xor as _)?,
-            I::I32AndEqz(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_and_eqz as _)?,
-            I::I32OrEqz(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_or_eqz as _)?,
-            I::I32XorEqz(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_xor_eqz as _)?,
-            I::I32Eq(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_eq as _)?,
-            I::I32Ne(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_ne as _)?,
-            I::I32LtS(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_lt_s as _)?,
-            I::I32LtU(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_lt_u as _)?,
-            I::I32LeS(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_le_s as _)?,
-            I::I32LeU(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_le_u as _)?,
-            I::I32GtS(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_gt_s as _)?,
-            I::I32GtU(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_gt_u as _)?,
-            I::I32GeS(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_ge_s as _)?,
-            I::I32GeU(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i32_ge_u as _)?,
-            I::I64Eq(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_eq as _)?,
-            I::I64Ne(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_ne as _)?,
-            I::I64LtS(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_lt_s as _)?,
-            I::I64LtU(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_lt_u as _)?,
-            I::I64LeS(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_le_s as _)?,
-            I::I64LeU(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_le_u as _)?,
-            I::I64GtS(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_gt_s as _)?,
-            I::I64GtU(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_gt_u as _)?,
-            I::I64GeS(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_ge_s as _)?,
-            I::I64GeU(instr) => fuse(self, stack, last_instr, instr, label, I::branch_i64_ge_u as _)?,
-            I::F32Eq(instr) => fuse(self, stack, last_instr, instr, label, I::branch_f32_eq as _)?,
-            I::F32Ne(instr) => fuse(self, stack, last_instr, instr, label, I::branch_f32_ne as _)?,
-            I::F32Lt(instr) => fuse(self, stack, last_instr, instr, label, I::branch_f32_lt as _)?,
-            I::F32Le(instr) => fuse(self, stack, last_instr, instr, label, I::branch_f32_le as _)?,
-            I::F32Gt(instr) => fuse(self, stack, last_instr, instr, label, I::branch_f32_gt as _)?,
-            I::F32Ge(instr) => fuse(self, stack, last_instr, instr, label, I::branch_f32_ge as _)?,
-            I::F64Eq(instr) => fuse(self, stack, last_instr, instr, label, I::branch_f64_eq as _)?,
-            I::F64Ne(instr) => fuse(self, stack, last_instr, instr, label, I::branch_f64_ne as _)?,
-            I::F64Lt(instr) => fuse(self, stack, last_instr, instr, label, I::branch_f64_lt as _)?,
-            I::F64Le(instr) => fuse(self, stack, last_instr, instr, label, I::branch_f64_le as _)?,
-            I::F64Gt(instr) => fuse(self, stack, last_instr, instr, label, I::branch_f64_gt as _)?,
-            I::F64Ge(instr) => fuse(self, stack, last_instr, instr, label, I::branch_f64_ge as _)?,
-            I::I32AndImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_and_imm as _)?,
-            I::I32OrImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_or_imm as _)?,
-            I::I32XorImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_xor_imm as _)?,
-            I::I32AndEqzImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_and_eqz_imm as _)?,
-            I::I32OrEqzImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_or_eqz_imm as _)?,
-            I::I32XorEqzImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_xor_eqz_imm as _)?,
-            I::I32EqImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_eq_imm as _)?,
-            I::I32NeImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_ne_imm as _)?,
-            I::I32LtSImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_lt_s_imm as _)?,
-            I::I32LtUImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_lt_u_imm as _)?,
-            I::I32LeSImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_le_s_imm as _)?,
-            I::I32LeUImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_le_u_imm as _)?,
-            I::I32GtSImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_gt_s_imm as _)?,
-            I::I32GtUImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_gt_u_imm as _)?,
-            I::I32GeSImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_ge_s_imm as _)?,
-            I::I32GeUImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i32_ge_u_imm as _)?,
-            I::I64EqImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_eq_imm as _)?,
-            I::I64NeImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_ne_imm as _)?,
-            I::I64LtSImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_lt_s_imm as _)?,
-            I::I64LtUImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_lt_u_imm as _)?,
-            I::I64LeSImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_le_s_imm as _)?,
-            I::I64LeUImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_le_u_imm as _)?,
-            I::I64GtSImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_gt_s_imm as _)?,
-            I::I64GtUImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_gt_u_imm as _)?,
-            I::I64GeSImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_ge_s_imm as _)?,
-            I::I64GeUImm16(instr) => fuse_imm(self, stack, last_instr, instr, label, I::branch_i64_ge_u_imm as _)?,
+            I::I32And(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_and as _)?,
+            I::I32Or(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_or as _)?,
+            I::I32Xor(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_xor as _)?,
+            I::I32AndEqz(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_and_eqz as _)?,
+            I::I32OrEqz(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_or_eqz as _)?,
+            I::I32XorEqz(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_xor_eqz as _)?,
+            I::I32Eq(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_eq as _)?,
+            I::I32Ne(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_ne as _)?,
+            I::I32LtS(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_lt_s as _)?,
+            I::I32LtU(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_lt_u as _)?,
+            I::I32LeS(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_le_s as _)?,
+            I::I32LeU(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_le_u as _)?,
+            I::I32GtS(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_gt_s as _)?,
+            I::I32GtU(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_gt_u as _)?,
+            I::I32GeS(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_ge_s as _)?,
+            I::I32GeU(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i32_ge_u as _)?,
+            I::I64Eq(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_eq as _)?,
+            I::I64Ne(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_ne as _)?,
+            I::I64LtS(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_lt_s as _)?,
+            I::I64LtU(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_lt_u as _)?,
+            I::I64LeS(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_le_s as _)?,
+            I::I64LeU(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_le_u as _)?,
+            I::I64GtS(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_gt_s as _)?,
+            I::I64GtU(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_gt_u as _)?,
+            I::I64GeS(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_ge_s as _)?,
+            I::I64GeU(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_i64_ge_u as _)?,
+            I::F32Eq(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_f32_eq as _)?,
+            I::F32Ne(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_f32_ne as _)?,
+            I::F32Lt(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_f32_lt as _)?,
+            I::F32Le(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_f32_le as _)?,
+            I::F32Gt(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_f32_gt as _)?,
+            I::F32Ge(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_f32_ge as _)?,
+            I::F64Eq(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_f64_eq as _)?,
+            I::F64Ne(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_f64_ne as _)?,
+            I::F64Lt(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_f64_lt as _)?,
+            I::F64Le(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_f64_le as _)?,
+            I::F64Gt(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_f64_gt as _)?,
+            I::F64Ge(instr) => fuse(self, stack, last_instr, condition, instr, label, I::branch_f64_ge as _)?,
+            I::I32AndImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_and_imm as _)?,
+            I::I32OrImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_or_imm as _)?,
+            I::I32XorImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_xor_imm as _)?,
+            I::I32AndEqzImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_and_eqz_imm as _)?,
+            I::I32OrEqzImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_or_eqz_imm as _)?,
+            I::I32XorEqzImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_xor_eqz_imm as _)?,
+            I::I32EqImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_eq_imm as _)?,
+            I::I32NeImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_ne_imm as _)?,
+            I::I32LtSImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_lt_s_imm as _)?,
+            I::I32LtUImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_lt_u_imm as _)?,
+            I::I32LeSImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_le_s_imm as _)?,
+            I::I32LeUImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_le_u_imm as _)?,
+            I::I32GtSImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_gt_s_imm as _)?,
+            I::I32GtUImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_gt_u_imm as _)?,
+            I::I32GeSImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_ge_s_imm as _)?,
+            I::I32GeUImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i32_ge_u_imm as _)?,
+            I::I64EqImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_eq_imm as _)?,
+            I::I64NeImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_ne_imm as _)?,
+            I::I64LtSImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_lt_s_imm as _)?,
+            I::I64LtUImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_lt_u_imm as _)?,
+            I::I64LeSImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_le_s_imm as _)?,
+            I::I64LeUImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_le_u_imm as _)?,
+            I::I64GtSImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_gt_s_imm as _)?,
+            I::I64GtUImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_gt_u_imm as _)?,
+            I::I64GeSImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_ge_s_imm as _)?,
+            I::I64GeUImm16(instr) => fuse_imm(self, stack, last_instr, condition, instr, label, I::branch_i64_ge_u_imm as _)?,
             _ => None,
         };
         if let Some(fused_instr) = fused_instr {
@@ -1327,57 +2142,249 @@ impl Instruction {
             _ => panic!("tried to update branch offset of a non-branch instruction: {self:?}"),
         }
     }
+
+    /// Returns the [`BranchOffset`] of `self` if it is a branch [`Instruction`].
+    ///
+    /// Returns `None` otherwise.
+    ///
+    /// # Note
+    ///
+    /// This is the inverse of [`Instruction::update_branch_offset`].
+    pub fn branch_offset(&self) -> Option<BranchOffset> {
+        match self {
+            Instruction::Branch { offset } => Some(*offset),
+            Instruction::BranchI32And(instr)
+            | Instruction::BranchI32Or(instr)
+            | Instruction::BranchI32Xor(instr)
+            | Instruction::BranchI32AndEqz(instr)
+            | Instruction::BranchI32OrEqz(instr)
+            | Instruction::BranchI32XorEqz(instr)
+            | Instruction::BranchI32Eq(instr)
+            | Instruction::BranchI32Ne(instr)
+            | Instruction::BranchI32LtS(instr)
+            | Instruction::BranchI32LtU(instr)
+            | Instruction::BranchI32LeS(instr)
+            | Instruction::BranchI32LeU(instr)
+            | Instruction::BranchI32GtS(instr)
+            | Instruction::BranchI32GtU(instr)
+            | Instruction::BranchI32GeS(instr)
+            | Instruction::BranchI32GeU(instr)
+            | Instruction::BranchI64Eq(instr)
+            | Instruction::BranchI64Ne(instr)
+            | Instruction::BranchI64LtS(instr)
+            | Instruction::BranchI64LtU(instr)
+            | Instruction::BranchI64LeS(instr)
+            | Instruction::BranchI64LeU(instr)
+            | Instruction::BranchI64GtS(instr)
+            | Instruction::BranchI64GtU(instr)
+            | Instruction::BranchI64GeS(instr)
+            | Instruction::BranchI64GeU(instr)
+            | Instruction::BranchF32Eq(instr)
+            | Instruction::BranchF32Ne(instr)
+            | Instruction::BranchF32Lt(instr)
+            | Instruction::BranchF32Le(instr)
+            | Instruction::BranchF32Gt(instr)
+            | Instruction::BranchF32Ge(instr)
+            | Instruction::BranchF64Eq(instr)
+            | Instruction::BranchF64Ne(instr)
+            | Instruction::BranchF64Lt(instr)
+            | Instruction::BranchF64Le(instr)
+            | Instruction::BranchF64Gt(instr)
+            | Instruction::BranchF64Ge(instr) => Some(BranchOffset::from(instr.offset)),
+            Instruction::BranchI32AndImm(instr)
+            | Instruction::BranchI32OrImm(instr)
+            | Instruction::BranchI32XorImm(instr)
+            | Instruction::BranchI32AndEqzImm(instr)
+            | Instruction::BranchI32OrEqzImm(instr)
+            | Instruction::BranchI32XorEqzImm(instr)
+            | Instruction::BranchI32EqImm(instr)
+            | Instruction::BranchI32NeImm(instr)
+            | Instruction::BranchI32LtSImm(instr)
+            | Instruction::BranchI32LeSImm(instr)
+            | Instruction::BranchI32GtSImm(instr)
+            | Instruction::BranchI32GeSImm(instr) => Some(BranchOffset::from(instr.offset)),
+            Instruction::BranchI32LtUImm(instr)
+            | Instruction::BranchI32LeUImm(instr)
+            | Instruction::BranchI32GtUImm(instr)
+            | Instruction::BranchI32GeUImm(instr) => Some(BranchOffset::from(instr.offset)),
+            Instruction::BranchI64EqImm(instr)
+            | Instruction::BranchI64NeImm(instr)
+            | Instruction::BranchI64LtSImm(instr)
+            | Instruction::BranchI64LeSImm(instr)
+            | Instruction::BranchI64GtSImm(instr)
+            | Instruction::BranchI64GeSImm(instr) => Some(BranchOffset::from(instr.offset)),
+            Instruction::BranchI64LtUImm(instr)
+            | Instruction::BranchI64LeUImm(instr)
+            | Instruction::BranchI64GtUImm(instr)
+            | Instruction::BranchI64GeUImm(instr) => Some(BranchOffset::from(instr.offset)),
+            _ => None,
+        }
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::engine::{bytecode::RegisterSpan, translator::typed_value::TypedValue};
+/// Returns the result [`Register`] of `instr` if it is a pure single-word [`Instruction`].
+///
+/// # Note
+///
+/// Pure [`Instruction`] have no side effects, cannot trap and only write to their result.
+fn pure_instr_result(instr: &Instruction) -> Option<Register> {
+    let mut result = None;
+    visit_pure_instr(instr, |register| result = Some(register), |_| ());
+    result
+}
 
-    #[test]
-    fn has_overlapping_copies_works() {
-        assert!(!InstrEncoder::has_overlapping_copies(
-            RegisterSpan::new(Register::from_i16(0)).iter(0),
-            &[],
-        ));
-        assert!(!InstrEncoder::has_overlapping_copies(
-            RegisterSpan::new(Register::from_i16(0)).iter(2),
-            &[TypedProvider::register(0), TypedProvider::register(1),],
-        ));
-        assert!(!InstrEncoder::has_overlapping_copies(
-            RegisterSpan::new(Register::from_i16(0)).iter(2),
-            &[
-                TypedProvider::Const(TypedValue::from(10_i32)),
-                TypedProvider::Const(TypedValue::from(20_i32)),
-            ],
-        ));
-        assert!(InstrEncoder::has_overlapping_copies(
-            RegisterSpan::new(Register::from_i16(0)).iter(2),
-            &[
-                TypedProvider::Const(TypedValue::from(10_i32)),
-                TypedProvider::register(0),
-            ],
-        ));
-        assert!(InstrEncoder::has_overlapping_copies(
-            RegisterSpan::new(Register::from_i16(0)).iter(2),
-            &[TypedProvider::register(0), TypedProvider::register(0),],
-        ));
-        assert!(InstrEncoder::has_overlapping_copies(
-            RegisterSpan::new(Register::from_i16(3)).iter(3),
-            &[
-                TypedProvider::register(2),
-                TypedProvider::register(3),
-                TypedProvider::register(2),
-            ],
-        ));
-        assert!(InstrEncoder::has_overlapping_copies(
-            RegisterSpan::new(Register::from_i16(3)).iter(4),
-            &[
-                TypedProvider::register(-1),
-                TypedProvider::register(10),
-                TypedProvider::register(2),
-                TypedProvider::register(4),
-            ],
-        ));
+/// Calls `f` on all input [`Register`] of `instr` if it is a pure single-word [`Instruction`].
+///
+/// Returns `false` if `instr` is not a pure single-word [`Instruction`].
+fn pure_instr_inputs(instr: &Instruction, f: impl FnMut(Register)) -> bool {
+    visit_pure_instr(instr, |_| (), f)
+}
+
+/// Visits the result and input [`Register`] of `instr` if it is a pure single-word [`Instruction`].
+///
+/// Returns `false` if `instr` is not a pure single-word [`Instruction`].
+#[rustfmt::skip]
+fn visit_pure_instr(
+    instr: &Instruction,
+    mut result: impl FnMut(Register),
+    mut input: impl FnMut(Register),
+) -> bool {
+    match *instr {
+        Instruction::Copy { result: r, value } => {
+            result(r);
+            input(value);
+        }
+        Instruction::CopyImm32 { result: r, .. } |
+        Instruction::CopyI64Imm32 { result: r, .. } |
+        Instruction::CopyF64Imm32 { result: r, .. } => result(r),
+        Instruction::I32Clz(instr) | Instruction::I64Clz(instr) |
+        Instruction::I32Ctz(instr) | Instruction::I64Ctz(instr) |
+        Instruction::I32Popcnt(instr) | Instruction::I64Popcnt(instr) |
+        Instruction::F32Abs(instr) | Instruction::F64Abs(instr) |
+        Instruction::F32Neg(instr) | Instruction::F64Neg(instr) |
+        Instruction::F32Ceil(instr) | Instruction::F64Ceil(instr) |
+        Instruction::F32Floor(instr) | Instruction::F64Floor(instr) |
+        Instruction::F32Trunc(instr) | Instruction::F64Trunc(instr) |
+        Instruction::F32Nearest(instr) | Instruction::F64Nearest(instr) |
+        Instruction::F32Sqrt(instr) | Instruction::F64Sqrt(instr) |
+        Instruction::I32WrapI64(instr) |
+        Instruction::I64ExtendI32S(instr) | Instruction::I64ExtendI32U(instr) |
+        Instruction::I32Extend8S(instr) | Instruction::I32Extend16S(instr) |
+        Instruction::I64Extend8S(instr) | Instruction::I64Extend16S(instr) |
+        Instruction::I64Extend32S(instr) => {
+            result(instr.result);
+            input(instr.input);
+        }
+        Instruction::I32Eq(instr) | Instruction::I64Eq(instr) |
+        Instruction::I32Ne(instr) | Instruction::I64Ne(instr) |
+        Instruction::I32LtS(instr) | Instruction::I32LtU(instr) |
+        Instruction::I64LtS(instr) | Instruction::I64LtU(instr) |
+        Instruction::I32GtS(instr) | Instruction::I32GtU(instr) |
+        Instruction::I64GtS(instr) | Instruction::I64GtU(instr) |
+        Instruction::I32LeS(instr) | Instruction::I32LeU(instr) |
+        Instruction::I64LeS(instr) | Instruction::I64LeU(instr) |
+        Instruction::I32GeS(instr) | Instruction::I32GeU(instr) |
+        Instruction::I64GeS(instr) | Instruction::I64GeU(instr) |
+        Instruction::F32Eq(instr) | Instruction::F64Eq(instr) |
+        Instruction::F32Ne(instr) | Instruction::F64Ne(instr) |
+        Instruction::F32Lt(instr) | Instruction::F64Lt(instr) |
+        Instruction::F32Le(instr) | Instruction::F64Le(instr) |
+        Instruction::F32Gt(instr) | Instruction::F64Gt(instr) |
+        Instruction::F32Ge(instr) | Instruction::F64Ge(instr) |
+        Instruction::I32Add(instr) | Instruction::I64Add(instr) |
+        Instruction::I32Sub(instr) | Instruction::I64Sub(instr) |
+        Instruction::I32Mul(instr) | Instruction::I64Mul(instr) |
+        Instruction::I32And(instr) | Instruction::I64And(instr) |
+        Instruction::I32Or(instr) | Instruction::I64Or(instr) |
+        Instruction::I32Xor(instr) | Instruction::I64Xor(instr) |
+        Instruction::I32AndEqz(instr) | Instruction::I32OrEqz(instr) |
+        Instruction::I32XorEqz(instr) |
+        Instruction::I32Shl(instr) | Instruction::I64Shl(instr) |
+        Instruction::I32ShrU(instr) | Instruction::I64ShrU(instr) |
+        Instruction::I32ShrS(instr) | Instruction::I64ShrS(instr) |
+        Instruction::I32Rotl(instr) | Instruction::I64Rotl(instr) |
+        Instruction::I32Rotr(instr) | Instruction::I64Rotr(instr) |
+        Instruction::F32Add(instr) | Instruction::F64Add(instr) |
+        Instruction::F32Sub(instr) | Instruction::F64Sub(instr) |
+        Instruction::F32Mul(instr) | Instruction::F64Mul(instr) |
+        Instruction::F32Div(instr) | Instruction::F64Div(instr) |
+        Instruction::F32Min(instr) | Instruction::F64Min(instr) |
+        Instruction::F32Max(instr) | Instruction::F64Max(instr) |
+        Instruction::F32Copysign(instr) | Instruction::F64Copysign(instr) => {
+            result(instr.result);
+            input(instr.lhs);
+            input(instr.rhs);
+        }
+        Instruction::I32EqImm16(instr) |
+        Instruction::I32NeImm16(instr) |
+        Instruction::I32LtSImm16(instr) |
+        Instruction::I32GtSImm16(instr) |
+        Instruction::I32LeSImm16(instr) |
+        Instruction::I32GeSImm16(instr) |
+        Instruction::I32AddImm16(instr) |
+        Instruction::I32SubImm16(instr) |
+        Instruction::I32SubImm16Rev(instr) |
+        Instruction::I32MulImm16(instr) |
+        Instruction::I32AndImm16(instr) |
+        Instruction::I32OrImm16(instr) |
+        Instruction::I32XorImm16(instr) |
+        Instruction::I32AndEqzImm16(instr) |
+        Instruction::I32OrEqzImm16(instr) |
+        Instruction::I32XorEqzImm16(instr) |
+        Instruction::I32ShlImm(instr) |
+        Instruction::I32ShrUImm(instr) |
+        Instruction::I32ShrSImm(instr) |
+        Instruction::I32RotlImm(instr) |
+        Instruction::I32RotrImm(instr) |
+        Instruction::I32ShlImm16Rev(instr) |
+        Instruction::I32ShrUImm16Rev(instr) |
+        Instruction::I32ShrSImm16Rev(instr) |
+        Instruction::I32RotlImm16Rev(instr) |
+        Instruction::I32RotrImm16Rev(instr) => {
+            result(instr.result);
+            input(instr.reg_in);
+        }
+        Instruction::I32LtUImm16(instr) |
+        Instruction::I32GtUImm16(instr) |
+        Instruction::I32LeUImm16(instr) |
+        Instruction::I32GeUImm16(instr) => {
+            result(instr.result);
+            input(instr.reg_in);
+        }
+        Instruction::I64EqImm16(instr) |
+        Instruction::I64NeImm16(instr) |
+        Instruction::I64LtSImm16(instr) |
+        Instruction::I64GtSImm16(instr) |
+        Instruction::I64LeSImm16(instr) |
+        Instruction::I64GeSImm16(instr) |
+        Instruction::I64AddImm16(instr) |
+        Instruction::I64SubImm16(instr) |
+        Instruction::I64SubImm16Rev(instr) |
+        Instruction::I64MulImm16(instr) |
+        Instruction::I64AndImm16(instr) |
+        Instruction::I64OrImm16(instr) |
+        Instruction::I64XorImm16(instr) |
+        Instruction::I64ShlImm(instr) |
+        Instruction::I64ShrUImm(instr) |
+        Instruction::I64ShrSImm(instr) |
+        Instruction::I64RotlImm(instr) |
+        Instruction::I64RotrImm(instr) |
+        Instruction::I64ShlImm16Rev(instr) |
+        Instruction::I64ShrUImm16Rev(instr) |
+        Instruction::I64ShrSImm16Rev(instr) |
+        Instruction::I64RotlImm16Rev(instr) |
+        Instruction::I64RotrImm16Rev(instr) => {
+            result(instr.result);
+            input(instr.reg_in);
+        }
+        Instruction::I64LtUImm16(instr) |
+        Instruction::I64GtUImm16(instr) |
+        Instruction::I64LeUImm16(instr) |
+        Instruction::I64GeUImm16(instr) => {
+            result(instr.result);
+            input(instr.reg_in);
+        }
+        _ => return false,
     }
+    true
 }
@@ -132,6 +132,21 @@ impl LabelRegistry {
         }
     }
 
+    /// Re-pins all labels pinned to `from` to the given `to` [`Instr`].
+    ///
+    /// # Note
+    ///
+    /// This must only be used while all users of the labels pinned to `from`
+    /// are still awaiting their deferred resolution.
+    pub fn repin_labels(&mut self, from: Instr, to: Instr) {
+        for label in &mut self.labels {
+            match label {
+                Label::Pinned(pinned) if *pinned == from => *pinned = to,
+                _ => {}
+            }
+        }
+    }
+
     /// Tries to resolve the `label`.
     ///
     /// Returns the proper `BranchOffset` in case the `label` has already been
@@ -154,6 +169,14 @@ impl LabelRegistry {
         Ok(offset)
     }
 
+    /// Returns the [`Instr`] to which `label` is pinned if any.
+    pub fn pinned_to(&self, label: LabelRef) -> Option<Instr> {
+        match self.get_label(label) {
+            Label::Pinned(instr) => Some(*instr),
+            Label::Unpinned => None,
+        }
+    }
+
     /// Resolves a `label` to its pinned [`Instr`].
     ///
     /// # Errors
@@ -2,6 +2,7 @@
 
 mod control_frame;
 mod control_stack;
+mod diagnostic;
 mod driver;
 mod error;
 mod instr_encoder;
@@ -26,13 +27,14 @@ use self::{
     },
     control_stack::AcquiredTarget,
     labels::{LabelRef, LabelRegistry},
-    stack::ValueStack,
+    stack::{RegisterSpace, ValueStack},
     typed_value::TypedValue,
     utils::{WasmFloat, WasmInteger},
 };
 pub use self::{
     control_frame::{ControlFrame, ControlFrameKind},
     control_stack::ControlStack,
+    diagnostic::{Diagnostic, DiagnosticKind},
     driver::FuncTranslationDriver,
     error::TranslationError,
     instr_encoder::{Instr, InstrEncoder},
@@ -51,12 +53,13 @@ use crate::{
             RegisterSpanIter,
             Sign,
             SignatureIdx,
+            UnaryInstr,
         },
-        config::FuelCosts,
+        config::{CompilationMode, FuelCosts},
         BlockType,
         CompiledFunc,
     },
-    module::{FuncIdx, FuncTypeIdx, ModuleHeader},
+    module::{FuncIdx, FuncTypeIdx, ModuleHeader, TableIdx, DEFAULT_MEMORY_INDEX},
     Engine,
     Error,
     FuncType,
@@ -245,6 +248,7 @@ where
 
     fn update_pos(&mut self, pos: usize) {
         self.pos = pos;
+        self.translator.update_pos(pos);
     }
 
     fn finish(
@@ -295,6 +299,9 @@ macro_rules! impl_visit_operator {
     ( @tail_call $($rest:tt)* ) => {
         impl_visit_operator!(@@supported $($rest)*);
     };
+    ( @threads $($rest:tt)* ) => {
+        impl_visit_operator!(@@supported $($rest)*);
+    };
     ( @@supported $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
         fn $visit(&mut self $($(,$arg: $argty)*)?) -> Self::Output {
             let offset = self.current_pos();
@@ -331,6 +338,8 @@ pub struct LazyFuncTranslator {
     func_idx: FuncIdx,
     /// The identifier of the to be compiled function.
     compiled_func: CompiledFunc,
+    /// The byte offset of the Wasm function body within the Wasm binary.
+    offset: usize,
     /// The Wasm module header information used for translation.
     module: ModuleHeader,
     /// Optional information about lazy Wasm validation.
@@ -353,12 +362,14 @@ impl LazyFuncTranslator {
     pub fn new(
         func_idx: FuncIdx,
         compiled_func: CompiledFunc,
+        offset: usize,
         module: ModuleHeader,
         func_to_validate: Option<FuncToValidate<ValidatorResources>>,
     ) -> Self {
         Self {
             func_idx,
             compiled_func,
+            offset,
             module,
             func_to_validate,
         }
@@ -381,6 +392,7 @@ impl<'parser> WasmTranslator<'parser> for LazyFuncTranslator {
             .init_lazy_func(
                 self.func_idx,
                 self.compiled_func,
+                self.offset,
                 bytes,
                 &self.module,
                 self.func_to_validate.take(),
@@ -483,7 +495,9 @@ impl<'parser> WasmTranslator<'parser> for FuncTranslator {
         Ok(())
     }
 
-    fn update_pos(&mut self, _pos: usize) {}
+    fn update_pos(&mut self, pos: usize) {
+        self.alloc.instr_encoder.update_pos(pos);
+    }
 
     fn finish(
         mut self,
@@ -494,6 +508,17 @@ impl<'parser> WasmTranslator<'parser> for FuncTranslator {
             .defrag_registers(&mut self.alloc.stack)?;
         self.alloc.instr_encoder.update_branch_offsets()?;
         let len_registers = self.alloc.stack.len_registers();
+        let limit = self
+            .engine()
+            .config()
+            .stack_limits()
+            .maximum_value_stack_height;
+        if usize::from(len_registers) > limit {
+            return Err(Error::from(TranslationError::TooManyValueStackSlots {
+                required: usize::from(len_registers),
+                limit,
+            }));
+        }
         if let Some(fuel_costs) = self.fuel_costs() {
             // Note: Fuel metering is enabled so we need to bump the fuel
             //       of the function enclosing Wasm `block` by an amount
@@ -509,9 +534,20 @@ impl<'parser> WasmTranslator<'parser> for FuncTranslator {
                     costs.fuel_for_copies(u64::from(len_registers))
                 })?;
         }
+        let func = self.func;
+        self.module.push_diagnostics(
+            self.alloc
+                .instr_encoder
+                .drain_diagnostics()
+                .map(|kind| Diagnostic::new(func, kind)),
+        );
         let func_consts = self.alloc.stack.func_local_consts();
+        let wasm_offsets = self.alloc.instr_encoder.drain_offsets().collect();
         let instrs = self.alloc.instr_encoder.drain_instrs();
-        finalize(CompiledFuncEntity::new(len_registers, instrs, func_consts));
+        finalize(
+            CompiledFuncEntity::new(self.func, len_registers, instrs, func_consts)
+                .with_wasm_offsets(wasm_offsets),
+        );
         Ok(self.into_allocations())
     }
 }
@@ -592,11 +628,39 @@ impl FuncTranslator {
     /// Initializes a newly constructed [`FuncTranslator`].
     fn init(mut self) -> Result<Self, Error> {
         self.alloc.reset();
+        let config = self.engine().config();
+        let collect_diagnostics = config.get_collect_translation_diagnostics()
+            && matches!(config.get_compilation_mode(), CompilationMode::Eager);
+        let max_preservation_shift = config.get_max_preservation_shift();
+        let fusions = config.get_fusions();
+        let trap_offsets = config.get_trap_offsets();
+        self.alloc
+            .instr_encoder
+            .collect_diagnostics(collect_diagnostics);
+        self.alloc
+            .instr_encoder
+            .max_preservation_shift(max_preservation_shift);
+        self.alloc.instr_encoder.fusions(fusions);
+        self.alloc.instr_encoder.record_offsets(trap_offsets);
         self.init_func_body_block()?;
         self.init_func_params()?;
         Ok(self)
     }
 
+    /// Encodes all conditional branches of the function with 32-bit [`BranchOffset`]s.
+    ///
+    /// # Note
+    ///
+    /// Used to translate a function again after its 16-bit [`BranchOffset16`]s
+    /// turned out to be out of bounds.
+    ///
+    /// [`BranchOffset`]: crate::engine::bytecode::BranchOffset
+    /// [`BranchOffset16`]: crate::engine::bytecode::BranchOffset16
+    pub fn wide_branches(mut self) -> Self {
+        self.alloc.instr_encoder.wide_branches(true);
+        self
+    }
+
     /// Registers the `block` control frame surrounding the entire function body.
     fn init_func_body_block(&mut self) -> Result<(), Error> {
         let func_type = self.module.get_type_of_func(self.func);
@@ -654,6 +718,40 @@ impl FuncTranslator {
             .resolve_func_type(dedup_func_type, Clone::clone)
     }
 
+    /// Tries to resolve the [`FuncIdx`] statically called by an indirect call.
+    ///
+    /// Upon success pops the constant table index of the indirect call from the stack.
+    ///
+    /// Returns `None` if the called function is not statically known or if its
+    /// function type does not match `func_type_index` in which case the indirect
+    /// call must trap at runtime.
+    ///
+    /// # Note
+    ///
+    /// Since an indirect call into a mutable table would require a runtime guard
+    /// as costly as the indirect call itself we only fold indirect calls into tables
+    /// that are neither imported, exported nor mutated by any Wasm instruction.
+    fn try_fold_indirect_call(
+        &mut self,
+        func_type_index: SignatureIdx,
+        table_index: u32,
+    ) -> Option<FuncIdx> {
+        let TypedProvider::Const(index) = self.alloc.stack.peek() else {
+            return None;
+        };
+        let func_idx = self
+            .module
+            .get_table_func_after_init(TableIdx::from(table_index), u32::from(index))?;
+        let expected = self
+            .module
+            .get_func_type(FuncTypeIdx::from(func_type_index.to_u32()));
+        if self.module.get_type_of_func(func_idx) != expected {
+            return None;
+        }
+        self.alloc.stack.pop();
+        Some(func_idx)
+    }
+
     /// Returns `true` if the code at the current translation position is reachable.
     fn is_reachable(&self) -> bool {
         self.reachable
@@ -1702,6 +1800,94 @@ impl FuncTranslator {
         }
     }
 
+    /// Translates a Wasm `f32.neg` or `f64.neg` instruction.
+    ///
+    /// # Note
+    ///
+    /// Eliminates double negations `neg(neg(x))` to `x` since float negation
+    /// only flips the sign bit and thus is its own inverse even for NaN values.
+    fn translate_fneg(
+        &mut self,
+        make_instr: fn(result: Register, input: Register) -> Instruction,
+        consteval: fn(input: TypedValue) -> TypedValue,
+        as_neg: fn(instr: &Instruction) -> Option<UnaryInstr>,
+    ) -> Result<(), Error> {
+        bail_unreachable!(self);
+        if let TypedProvider::Register(input) = self.alloc.stack.peek() {
+            if let Some(value) = self.try_eliminate_double_fneg(input, as_neg) {
+                self.alloc.stack.pop();
+                self.alloc.stack.push_register(value)?;
+                return Ok(());
+            }
+        }
+        self.translate_unary(make_instr, consteval)
+    }
+
+    /// Tries to remove the negation computing `input` if it was the last encoded instruction.
+    ///
+    /// Returns the [`Register`] storing the original non-negated value upon success.
+    fn try_eliminate_double_fneg(
+        &mut self,
+        input: Register,
+        as_neg: fn(instr: &Instruction) -> Option<UnaryInstr>,
+    ) -> Option<Register> {
+        if self.alloc.stack.get_register_space(input) != RegisterSpace::Dynamic {
+            // Case: `input` might be read elsewhere so we must keep its negation.
+            return None;
+        }
+        let stack = &self.alloc.stack;
+        self.alloc.instr_encoder.try_remove_last_instr(|instr| {
+            let UnaryInstr {
+                result,
+                input: value,
+            } = as_neg(instr)?;
+            if result != input {
+                return None;
+            }
+            match stack.get_register_space(value) {
+                // Note: a dynamic `value` has been overwritten by its negation
+                //       unless it shares its register with `input`.
+                RegisterSpace::Dynamic if value == input => Some(value),
+                RegisterSpace::Local => Some(value),
+                _ => None,
+            }
+        })
+    }
+
+    /// Translates the canonicalization of the `f32` result on top of the stack.
+    ///
+    /// # Note
+    ///
+    /// Does nothing unless NaN canonicalization is enabled via [`Config::floats_canonicalize_nan`].
+    ///
+    /// [`Config::floats_canonicalize_nan`]: crate::Config::floats_canonicalize_nan
+    fn translate_f32_canonicalize_nan(&mut self) -> Result<(), Error> {
+        if !self.engine().config().get_floats_canonicalize_nan() {
+            return Ok(());
+        }
+        self.translate_unary(
+            Instruction::f32_canonicalize_nan,
+            TypedValue::f32_canonicalize_nan,
+        )
+    }
+
+    /// Translates the canonicalization of the `f64` result on top of the stack.
+    ///
+    /// # Note
+    ///
+    /// Does nothing unless NaN canonicalization is enabled via [`Config::floats_canonicalize_nan`].
+    ///
+    /// [`Config::floats_canonicalize_nan`]: crate::Config::floats_canonicalize_nan
+    fn translate_f64_canonicalize_nan(&mut self) -> Result<(), Error> {
+        if !self.engine().config().get_floats_canonicalize_nan() {
+            return Ok(());
+        }
+        self.translate_unary(
+            Instruction::f64_canonicalize_nan,
+            TypedValue::f64_canonicalize_nan,
+        )
+    }
+
     /// Translates a fallible unary Wasm instruction to `wasmi` bytecode.
     fn translate_unary_fallible(
         &mut self,
@@ -1739,6 +1925,20 @@ impl FuncTranslator {
         })
     }
 
+    /// Encodes an [`Instruction::MemoryIdx`] parameter for the linear `memory` if required.
+    ///
+    /// # Note
+    ///
+    /// The parameter is omitted for the default linear memory at index 0.
+    fn append_memory_param(&mut self, memory: u32) -> Result<(), Error> {
+        if memory != DEFAULT_MEMORY_INDEX {
+            self.alloc
+                .instr_encoder
+                .append_instr(Instruction::memory_idx(memory))?;
+        }
+        Ok(())
+    }
+
     /// Calculates the effective address `ptr+offset` and calls `f(address)` if valid.
     ///
     /// Encodes a [`TrapCode::MemoryOutOfBounds`] trap instruction if the effective address is invalid.
@@ -1760,6 +1960,7 @@ impl FuncTranslator {
     ///
     /// This chooses the right encoding for the given `load` instruction.
     /// If `ptr+offset` is a constant value the address is pre-calculated.
+    /// Only the generic encoding is used for linear memories other than the default.
     ///
     /// # Usage
     ///
@@ -1780,6 +1981,9 @@ impl FuncTranslator {
         make_instr_at: fn(result: Register, address: Const32<u32>) -> Instruction,
     ) -> Result<(), Error> {
         bail_unreachable!(self);
+        if memarg.memory != DEFAULT_MEMORY_INDEX {
+            return self.translate_load_generic(memarg, make_instr);
+        }
         let offset = Self::memarg_offset(memarg);
         match self.alloc.stack.pop() {
             TypedProvider::Register(ptr) => {
@@ -1817,6 +2021,7 @@ impl FuncTranslator {
     ///
     /// This chooses the most efficient encoding for the given `store` instruction.
     /// If `ptr+offset` is a constant value the pointer address is pre-calculated.
+    /// Only the generic encoding is used for linear memories other than the default.
     ///
     /// # Usage
     ///
@@ -1837,6 +2042,9 @@ impl FuncTranslator {
         U: TryFrom<T>,
     {
         bail_unreachable!(self);
+        if memarg.memory != DEFAULT_MEMORY_INDEX {
+            return self.translate_store_generic(memarg, make_instr);
+        }
         let offset = Self::memarg_offset(memarg);
         match self.alloc.stack.pop2() {
             (TypedProvider::Register(ptr), TypedProvider::Register(value)) => {
@@ -1925,6 +2133,7 @@ impl FuncTranslator {
     ///
     /// This chooses the most efficient encoding for the given `store` instruction.
     /// If `ptr+offset` is a constant value the pointer address is pre-calculated.
+    /// Only the generic encoding is used for linear memories other than the default.
     ///
     /// # Usage
     ///
@@ -1939,6 +2148,9 @@ impl FuncTranslator {
         make_instr_at: fn(address: Const32<u32>, value: Register) -> Instruction,
     ) -> Result<(), Error> {
         bail_unreachable!(self);
+        if memarg.memory != DEFAULT_MEMORY_INDEX {
+            return self.translate_store_generic(memarg, make_instr);
+        }
         let offset = Self::memarg_offset(memarg);
         match self.alloc.stack.pop2() {
             (TypedProvider::Register(ptr), TypedProvider::Register(value)) => {
@@ -2005,6 +2217,109 @@ impl FuncTranslator {
         }
     }
 
+    /// Returns the [`Register`] of the operand `provider`.
+    ///
+    /// Allocates a function local constant value if `provider` is a constant.
+    /// Used for instructions that have no encodings with immediate operands.
+    fn operand_register(&mut self, provider: TypedProvider) -> Result<Register, Error> {
+        match provider {
+            TypedProvider::Register(register) => Ok(register),
+            TypedProvider::Const(value) => self.alloc.stack.alloc_const(value),
+        }
+    }
+
+    /// Translates a Wasm `load` instruction to its generic `wasmi` bytecode encoding.
+    ///
+    /// # Note
+    ///
+    /// Unlike [`FuncTranslator::translate_load`] there is only a single encoding.
+    /// This is required for atomic instructions since the alignment of the effective
+    /// address must be checked at runtime and for loads from linear memories other
+    /// than the default linear memory.
+    ///
+    /// # Usage
+    ///
+    /// Used for translating the following Wasm operators to `wasmi` bytecode:
+    ///
+    /// - `{i32, i64}.atomic.load`
+    /// - `memory.atomic.{notify, wait32, wait64}` after popping their non-address operands
+    /// - all `load` operators on linear memories other than the default linear memory
+    fn translate_load_generic(
+        &mut self,
+        memarg: MemArg,
+        make_instr: fn(result: Register, ptr: Register) -> Instruction,
+    ) -> Result<(), Error> {
+        bail_unreachable!(self);
+        let offset = Self::memarg_offset(memarg);
+        let ptr = self.alloc.stack.pop();
+        let ptr = self.operand_register(ptr)?;
+        let result = self.alloc.stack.push_dynamic()?;
+        self.push_fueled_instr(make_instr(result, ptr), FuelCosts::load)?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::const32(offset))?;
+        self.append_memory_param(memarg.memory)
+    }
+
+    /// Translates a Wasm `store` instruction to its generic `wasmi` bytecode encoding.
+    ///
+    /// # Note
+    ///
+    /// Read [`FuncTranslator::translate_load_generic`] for when this is required.
+    ///
+    /// # Usage
+    ///
+    /// Used for translating the following Wasm operators to `wasmi` bytecode:
+    ///
+    /// - `{i32, i64}.atomic.store`
+    /// - all `store` operators on linear memories other than the default linear memory
+    fn translate_store_generic(
+        &mut self,
+        memarg: MemArg,
+        make_instr: fn(ptr: Register, offset: Const32<u32>) -> Instruction,
+    ) -> Result<(), Error> {
+        bail_unreachable!(self);
+        let offset = Self::memarg_offset(memarg);
+        let (ptr, value) = self.alloc.stack.pop2();
+        let ptr = self.operand_register(ptr)?;
+        let value = self.operand_register(value)?;
+        self.push_fueled_instr(make_instr(ptr, Const32::from(offset)), FuelCosts::store)?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::Register(value))?;
+        self.append_memory_param(memarg.memory)
+    }
+
+    /// Translates a Wasm atomic read-modify-write instruction to `wasmi` bytecode.
+    ///
+    /// # Usage
+    ///
+    /// Used for translating the following Wasm operators to `wasmi` bytecode:
+    ///
+    /// - `{i32, i64}.atomic.rmw.add`
+    /// - `i32.atomic.{rmw8, rmw16}.add_u`
+    /// - `i64.atomic.{rmw8, rmw16, rmw32}.add_u`
+    fn translate_atomic_rmw(
+        &mut self,
+        memarg: MemArg,
+        make_instr: fn(result: Register, ptr: Register) -> Instruction,
+    ) -> Result<(), Error> {
+        bail_unreachable!(self);
+        let offset = Self::memarg_offset(memarg);
+        let (ptr, value) = self.alloc.stack.pop2();
+        let ptr = self.operand_register(ptr)?;
+        let value = self.operand_register(value)?;
+        let result = self.alloc.stack.push_dynamic()?;
+        self.push_fueled_instr(make_instr(result, ptr), FuelCosts::store)?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::const32(offset))?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::Register(value))?;
+        self.append_memory_param(memarg.memory)
+    }
+
     /// Translates a Wasm `select` or `select <ty>` instruction.
     ///
     /// # Note
@@ -2013,6 +2328,7 @@ impl FuncTranslator {
     /// - If both `lhs` and `rhs` are equal registers or constant values `lhs` is forwarded.
     /// - Properly chooses the correct `select` instruction encoding and optimizes for
     ///   cases with 32-bit constant values.
+    /// - Fuses `select` with a preceding integer comparison instruction producing its `condition`.
     fn translate_select(&mut self, type_hint: Option<ValueType>) -> Result<(), Error> {
         /// Convenience function to encode a `select` instruction.
         ///
@@ -2132,6 +2448,15 @@ impl FuncTranslator {
                             return Ok(());
                         }
                         let result = self.alloc.stack.push_dynamic()?;
+                        if self.alloc.instr_encoder.fuse_select(
+                            &mut self.alloc.stack,
+                            result,
+                            condition,
+                            lhs,
+                            rhs,
+                        )? {
+                            return Ok(());
+                        }
                         self.push_fueled_instr(
                             Instruction::select(result, condition, lhs),
                             FuelCosts::base,
@@ -2367,13 +2692,21 @@ impl FuncTranslator {
                         let result = self.alloc.stack.push_dynamic()?;
                         match lhs.ty() {
                             ValueType::I32 => {
-                                encode_select_imm32(
-                                    self,
-                                    result,
-                                    condition,
-                                    i32::from(lhs),
-                                    i32::from(rhs),
-                                )?;
+                                let lhs = i32::from(lhs);
+                                let rhs = i32::from(rhs);
+                                if let (Ok(lhs8), Ok(rhs8)) = (i8::try_from(lhs), i8::try_from(rhs))
+                                {
+                                    // # Optimization
+                                    //
+                                    // Both `lhs` and `rhs` fit into 8-bit and thus we
+                                    // can encode the `select` as a single instruction word.
+                                    self.push_fueled_instr(
+                                        Instruction::select_imm(result, condition, lhs8, rhs8),
+                                        FuelCosts::base,
+                                    )?;
+                                    return Ok(());
+                                }
+                                encode_select_imm32(self, result, condition, lhs, rhs)?;
                                 Ok(())
                             }
                             ValueType::F32 => {
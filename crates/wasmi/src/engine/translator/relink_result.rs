@@ -8,6 +8,7 @@ use crate::{
             LoadAtInstr,
             LoadInstr,
             LoadOffset16Instr,
+            LoadOpInstr,
             Register,
             RegisterSpan,
             SignatureIdx,
@@ -32,6 +33,7 @@ impl Instruction {
         use Instruction as I;
         match self {
             I::TableIdx(_)
+            | I::MemoryIdx(_)
             | I::DataSegmentIdx(_)
             | I::ElementSegmentIdx(_)
             | I::Const32(_)
@@ -41,6 +43,7 @@ impl Instruction {
             | I::Register2(_)
             | I::Register3(_)
             | I::RegisterList(_)
+            | I::RegisterSpanList { .. }
             | I::CallIndirectParams(_)
             | I::CallIndirectParamsImm16(_)
             | I::Trap(_)
@@ -52,6 +55,8 @@ impl Instruction {
             | I::ReturnImm32 { .. }
             | I::ReturnI64Imm32 { .. }
             | I::ReturnF64Imm32 { .. }
+            | I::ReturnI64Imm
+            | I::ReturnF64Imm
             | I::ReturnSpan { .. }
             | I::ReturnMany { .. }
             | I::ReturnNez { .. }
@@ -131,9 +136,12 @@ impl Instruction {
             I::Copy { result, .. }
             | I::CopyImm32 { result, .. }
             | I::CopyI64Imm32 { result, .. }
-            | I::CopyF64Imm32 { result, .. } => relink_simple(result, new_result, old_result),
+            | I::CopyF64Imm32 { result, .. }
+            | I::CopyI64Imm { result }
+            | I::CopyF64Imm { result } => relink_simple(result, new_result, old_result),
             I::CopySpan { .. }
             | I::CopySpanNonOverlapping { .. }
+            | I::CopySpanSmall { .. }
             | I::Copy2 { .. }
             | I::CopyMany { .. }
             | I::CopyManyNonOverlapping { .. }
@@ -154,6 +162,7 @@ impl Instruction {
             }
             I::Select { result, .. }
             | I::SelectRev { result, .. }
+            | I::SelectImm { result, .. }
             | I::SelectImm32 {
                 result_or_condition: result,
                 ..
@@ -171,6 +180,20 @@ impl Instruction {
                 //       word is given to this method.
                 relink_simple(result, new_result, old_result)
             }
+            I::SelectI32Eq(instr)
+            | I::SelectI32Ne(instr)
+            | I::SelectI32LtS(instr)
+            | I::SelectI32LtU(instr)
+            | I::SelectI32LeS(instr)
+            | I::SelectI32LeU(instr)
+            | I::SelectI64Eq(instr)
+            | I::SelectI64Ne(instr)
+            | I::SelectI64LtS(instr)
+            | I::SelectI64LtU(instr)
+            | I::SelectI64LeS(instr)
+            | I::SelectI64LeU(instr) => {
+                relink_simple(instr, new_result, old_result)
+            }
             I::RefFunc { result, .. }
             | I::TableGet { result, .. }
             | I::TableGetImm { result, .. }
@@ -274,6 +297,16 @@ impl Instruction {
             I::I64Load16uOffset16(instr) |
             I::I64Load32sOffset16(instr) |
             I::I64Load32uOffset16(instr) => relink_simple(instr, new_result, old_result),
+            I::I32AddLoad(instr) |
+            I::I32SubLoad(instr) |
+            I::I32AndLoad(instr) |
+            I::I32OrLoad(instr) |
+            I::I32XorLoad(instr) |
+            I::I64AddLoad(instr) |
+            I::I64SubLoad(instr) |
+            I::I64AndLoad(instr) |
+            I::I64OrLoad(instr) |
+            I::I64XorLoad(instr) => relink_simple(instr, new_result, old_result),
             I::I32Store(_)
             | I::I32StoreOffset16(_)
             | I::I32StoreOffset16Imm16(_)
@@ -315,6 +348,19 @@ impl Instruction {
             | I::F64Store(_)
             | I::F64StoreOffset16(_)
             | I::F64StoreAt(_) => Ok(false),
+            I::I32AtomicLoad(instr) |
+            I::I64AtomicLoad(instr) |
+            I::I32AtomicRmwAdd(instr) |
+            I::I64AtomicRmwAdd(instr) |
+            I::I32AtomicRmw8AddU(instr) |
+            I::I32AtomicRmw16AddU(instr) |
+            I::I64AtomicRmw8AddU(instr) |
+            I::I64AtomicRmw16AddU(instr) |
+            I::I64AtomicRmw32AddU(instr) |
+            I::MemoryAtomicNotify(instr) |
+            I::MemoryAtomicWait32(instr) |
+            I::MemoryAtomicWait64(instr) => relink_simple(instr, new_result, old_result),
+            I::I32AtomicStore(_) | I::I64AtomicStore(_) => Ok(false),
             I::I32Eq(instr) |
             I::I64Eq(instr) |
             I::I32Ne(instr) |
@@ -424,6 +470,9 @@ impl Instruction {
             I::F64Nearest(instr) |
             I::F64Sqrt(instr) => relink_simple(instr, new_result, old_result),
 
+            I::F32CanonicalizeNan(instr) |
+            I::F64CanonicalizeNan(instr) => relink_simple(instr, new_result, old_result),
+
             I::F32Add(instr) |
             I::F32Sub(instr) |
             I::F32Mul(instr) |
@@ -643,6 +692,12 @@ impl ResultMut for LoadOffset16Instr {
     }
 }
 
+impl ResultMut for LoadOpInstr {
+    fn result_mut(&mut self) -> &mut Register {
+        &mut self.result
+    }
+}
+
 impl ResultMut for UnaryInstr {
     fn result_mut(&mut self) -> &mut Register {
         &mut self.result
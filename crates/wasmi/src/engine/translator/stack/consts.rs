@@ -67,14 +67,15 @@ impl FuncLocalConsts {
     ///
     /// # Errors
     ///
-    /// If too many constant values have been allocated for this [`FuncLocalConsts`].
+    /// If too many constant values have been allocated for this [`FuncLocalConsts`]
+    /// and `value` does not already exist in it.
     pub fn alloc(&mut self, value: UntypedValue) -> Result<Register, Error> {
-        if self.next_idx == Self::last_index() {
-            return Err(Error::from(TranslationError::TooManyFuncLocalConstValues));
-        }
         match self.const2idx.entry(value) {
             btree_map::Entry::Occupied(entry) => Ok(*entry.get()),
             btree_map::Entry::Vacant(entry) => {
+                if self.next_idx == Self::last_index() {
+                    return Err(Error::from(TranslationError::TooManyFuncLocalConstValues));
+                }
                 let register = Register::from_i16(self.next_idx);
                 self.next_idx -= 1;
                 entry.insert(register);
@@ -84,6 +85,11 @@ impl FuncLocalConsts {
         }
     }
 
+    /// Returns the [`Register`] of the constant `value` if it has already been allocated.
+    pub fn find(&self, value: UntypedValue) -> Option<Register> {
+        self.const2idx.get(&value).copied()
+    }
+
     /// Returns the function local constant [`UntypedValue`] of the [`Register`] if any.
     pub fn get(&self, register: Register) -> Option<UntypedValue> {
         if !register.is_const() {
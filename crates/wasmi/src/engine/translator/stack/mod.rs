@@ -169,6 +169,14 @@ impl ValueStack {
         self.consts.alloc(value.into())
     }
 
+    /// Returns the [`Register`] of the function local constant `value` if it has already been allocated.
+    pub fn find_const<T>(&self, value: T) -> Option<Register>
+    where
+        T: Into<UntypedValue>,
+    {
+        self.consts.find(value.into())
+    }
+
     /// Returns the allocated function local constant values in reversed allocation order.
     ///
     /// # Note
@@ -114,7 +114,7 @@ enum AllocPhase {
 }
 
 /// The [`RegisterSpace`] of a [`Register`].
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum RegisterSpace {
     /// Function local constant values are assigned to this [`RegisterSpace`].
     Const,
@@ -0,0 +1,87 @@
+use crate::engine::{
+    bytecode::{Register, RegisterSpan, RegisterSpanIter},
+    translator::{utils::CopyInfo, TypedProvider, TypedValue},
+};
+
+/// Returns the [`CopyInfo`] as computed by separate passes over `values`.
+///
+/// This is used as reference for the single pass [`CopyInfo::new`].
+fn copy_info_reference(results: RegisterSpanIter, values: &[TypedProvider]) -> CopyInfo {
+    let span = RegisterSpanIter::from_providers(values);
+    let overlapping = match span {
+        Some(span) => {
+            let len = results.len_as_u16();
+            RegisterSpanIter::overlaps_copy(results.span().iter_u16(len), span)
+        }
+        None => results.overlaps_with_providers(values),
+    };
+    CopyInfo { span, overlapping }
+}
+
+/// Calls `f` with all sequences of `len` providers from the set of registers
+/// `-1..=6` and a constant value.
+fn for_each_values(len: usize, f: &mut impl FnMut(&[TypedProvider])) {
+    fn go(values: &mut Vec<TypedProvider>, len: usize, f: &mut impl FnMut(&[TypedProvider])) {
+        if values.len() == len {
+            return f(values);
+        }
+        let providers = (-1..=6)
+            .map(|index| TypedProvider::Register(Register::from_i16(index)))
+            .chain([TypedProvider::Const(TypedValue::from(42_i32))]);
+        for provider in providers {
+            values.push(provider);
+            go(values, len, f);
+            values.pop();
+        }
+    }
+    go(&mut Vec::with_capacity(len), len, f)
+}
+
+#[test]
+fn copy_info_matches_reference() {
+    for len in 3..=4 {
+        for head in 0..=6 {
+            let results = RegisterSpan::new(Register::from_i16(head)).iter(len);
+            for_each_values(len, &mut |values| {
+                assert_eq!(
+                    CopyInfo::new(results, values),
+                    copy_info_reference(results, values),
+                    "results = {results:?}, values = {values:?}",
+                );
+            });
+        }
+    }
+}
+
+#[test]
+fn copy_info_works() {
+    let reg = |index: i16| TypedProvider::Register(Register::from_i16(index));
+    let imm = |value: i32| TypedProvider::Const(TypedValue::from(value));
+    let results = |head: i16, len: usize| RegisterSpan::new(Register::from_i16(head)).iter(len);
+    let span = |head: i16, len: usize| Some(results(head, len));
+    let info = |span, overlapping| CopyInfo { span, overlapping };
+    assert_eq!(CopyInfo::new(results(0, 0), &[]), info(None, false));
+    assert_eq!(
+        CopyInfo::new(results(0, 3), &[reg(3), reg(4), reg(5)]),
+        info(span(3, 3), false),
+    );
+    assert_eq!(
+        CopyInfo::new(results(1, 3), &[reg(0), reg(1), reg(2)]),
+        info(span(0, 3), true),
+    );
+    assert_eq!(
+        CopyInfo::new(results(0, 3), &[reg(1), reg(0), reg(4)]),
+        info(None, true),
+    );
+    assert_eq!(
+        CopyInfo::new(results(0, 3), &[reg(1), imm(10), reg(2)]),
+        info(None, false),
+    );
+    assert_eq!(
+        CopyInfo::new(
+            results(3, 3),
+            &[reg(i16::MAX - 2), reg(i16::MAX - 1), reg(i16::MAX)]
+        ),
+        info(None, false),
+    );
+}
@@ -172,6 +172,12 @@ impl TranslationTest {
             cfg.wasm_tail_call(true);
             cfg
         };
+        Self::new_with_config(bytes, config)
+    }
+
+    /// Creates a new [`TranslationTest`] for the given Webassembly `bytes` and [`Config`].
+    #[must_use]
+    pub fn new_with_config(bytes: impl AsRef<[u8]>, config: Config) -> Self {
         Self {
             wasm: bytes.as_ref().into(),
             config,
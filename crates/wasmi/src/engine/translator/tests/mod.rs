@@ -1,9 +1,11 @@
 //! Tests for the register-machine `wasmi` engine translation implementation.
 
+mod copy_info;
 mod display_wasm;
 pub mod driver;
 mod op;
 mod regression;
+mod visit_register;
 pub mod wasm_type;
 
 use self::{
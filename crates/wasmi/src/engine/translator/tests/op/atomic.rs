@@ -0,0 +1,275 @@
+//! Translation tests for the supported Wasm `threads` proposal instructions.
+
+use super::*;
+use crate::{engine::bytecode::StoreInstr, Config};
+
+/// Returns a [`Config`] with the Wasm `threads` proposal enabled.
+fn threads_config() -> Config {
+    let mut config = Config::default();
+    config.wasm_threads(true);
+    config
+}
+
+fn test_atomic_load(
+    wasm_op: &str,
+    result_ty: &str,
+    make_instr: fn(result: Register, ptr: Register) -> Instruction,
+) {
+    let wasm = wat2wasm(&format!(
+        r#"
+        (module
+            (memory 1)
+            (func (param $ptr i32) (result {result_ty})
+                local.get $ptr
+                {wasm_op} offset=8
+            )
+        )
+    "#,
+    ));
+    TranslationTest::new_with_config(wasm, threads_config())
+        .expect_func_instrs([
+            make_instr(Register::from_i16(1), Register::from_i16(0)),
+            Instruction::const32(8_u32),
+            Instruction::return_reg(Register::from_i16(1)),
+        ])
+        .run();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn atomic_load() {
+    test_atomic_load("i32.atomic.load", "i32", Instruction::i32_atomic_load);
+    test_atomic_load("i64.atomic.load", "i64", Instruction::i64_atomic_load);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn atomic_load_const_ptr() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (memory 1)
+            (func (result i32)
+                i32.const 100
+                i32.atomic.load
+            )
+        )
+    "#,
+    );
+    TranslationTest::new_with_config(wasm, threads_config())
+        .expect_func(
+            ExpectedFunc::new([
+                Instruction::i32_atomic_load(Register::from_i16(0), Register::from_i16(-1)),
+                Instruction::const32(0_u32),
+                Instruction::return_reg(Register::from_i16(0)),
+            ])
+            .consts([100_i32]),
+        )
+        .run();
+}
+
+fn test_atomic_store(
+    wasm_op: &str,
+    value_ty: &str,
+    make_instr: fn(ptr: Register, offset: Const32<u32>) -> Instruction,
+) {
+    let wasm = wat2wasm(&format!(
+        r#"
+        (module
+            (memory 1)
+            (func (param $ptr i32) (param $value {value_ty})
+                local.get $ptr
+                local.get $value
+                {wasm_op} offset=8
+            )
+        )
+    "#,
+    ));
+    TranslationTest::new_with_config(wasm, threads_config())
+        .expect_func_instrs([
+            make_instr(Register::from_i16(0), Const32::from(8_u32)),
+            Instruction::Register(Register::from_i16(1)),
+            Instruction::Return,
+        ])
+        .run();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn atomic_store() {
+    test_atomic_store("i32.atomic.store", "i32", Instruction::i32_atomic_store);
+    test_atomic_store("i64.atomic.store", "i64", Instruction::i64_atomic_store);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn atomic_store_const_value() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (memory 1)
+            (func (param $ptr i32)
+                local.get $ptr
+                i64.const 42
+                i64.atomic.store
+            )
+        )
+    "#,
+    );
+    TranslationTest::new_with_config(wasm, threads_config())
+        .expect_func(
+            ExpectedFunc::new([
+                Instruction::I64AtomicStore(StoreInstr::new(
+                    Register::from_i16(0),
+                    Const32::from(0_u32),
+                )),
+                Instruction::Register(Register::from_i16(-1)),
+                Instruction::Return,
+            ])
+            .consts([42_i64]),
+        )
+        .run();
+}
+
+fn test_atomic_rmw(
+    wasm_op: &str,
+    ty: &str,
+    make_instr: fn(result: Register, ptr: Register) -> Instruction,
+) {
+    let wasm = wat2wasm(&format!(
+        r#"
+        (module
+            (memory 1)
+            (func (param $ptr i32) (param $value {ty}) (result {ty})
+                local.get $ptr
+                local.get $value
+                {wasm_op} offset=8
+            )
+        )
+    "#,
+    ));
+    TranslationTest::new_with_config(wasm, threads_config())
+        .expect_func_instrs([
+            make_instr(Register::from_i16(2), Register::from_i16(0)),
+            Instruction::const32(8_u32),
+            Instruction::Register(Register::from_i16(1)),
+            Instruction::return_reg(Register::from_i16(2)),
+        ])
+        .run();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn atomic_rmw_add() {
+    test_atomic_rmw("i32.atomic.rmw.add", "i32", Instruction::i32_atomic_rmw_add);
+    test_atomic_rmw("i64.atomic.rmw.add", "i64", Instruction::i64_atomic_rmw_add);
+    test_atomic_rmw(
+        "i32.atomic.rmw8.add_u",
+        "i32",
+        Instruction::i32_atomic_rmw8_add_u,
+    );
+    test_atomic_rmw(
+        "i32.atomic.rmw16.add_u",
+        "i32",
+        Instruction::i32_atomic_rmw16_add_u,
+    );
+    test_atomic_rmw(
+        "i64.atomic.rmw8.add_u",
+        "i64",
+        Instruction::i64_atomic_rmw8_add_u,
+    );
+    test_atomic_rmw(
+        "i64.atomic.rmw16.add_u",
+        "i64",
+        Instruction::i64_atomic_rmw16_add_u,
+    );
+    test_atomic_rmw(
+        "i64.atomic.rmw32.add_u",
+        "i64",
+        Instruction::i64_atomic_rmw32_add_u,
+    );
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn atomic_notify() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (memory 1)
+            (func (param $ptr i32) (param $count i32) (result i32)
+                local.get $ptr
+                local.get $count
+                memory.atomic.notify offset=8
+            )
+        )
+    "#,
+    );
+    TranslationTest::new_with_config(wasm, threads_config())
+        .expect_func_instrs([
+            Instruction::memory_atomic_notify(Register::from_i16(2), Register::from_i16(0)),
+            Instruction::const32(8_u32),
+            Instruction::return_reg(Register::from_i16(2)),
+        ])
+        .run();
+}
+
+fn test_atomic_wait(
+    wasm_op: &str,
+    expected_ty: &str,
+    make_instr: fn(result: Register, ptr: Register) -> Instruction,
+) {
+    let wasm = wat2wasm(&format!(
+        r#"
+        (module
+            (memory 1)
+            (func (param $ptr i32) (param $expected {expected_ty}) (param $timeout i64) (result i32)
+                local.get $ptr
+                local.get $expected
+                local.get $timeout
+                {wasm_op} offset=8
+            )
+        )
+    "#,
+    ));
+    TranslationTest::new_with_config(wasm, threads_config())
+        .expect_func_instrs([
+            make_instr(Register::from_i16(3), Register::from_i16(0)),
+            Instruction::const32(8_u32),
+            Instruction::return_reg(Register::from_i16(3)),
+        ])
+        .run();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn atomic_wait() {
+    test_atomic_wait(
+        "memory.atomic.wait32",
+        "i32",
+        Instruction::memory_atomic_wait32,
+    );
+    test_atomic_wait(
+        "memory.atomic.wait64",
+        "i64",
+        Instruction::memory_atomic_wait64,
+    );
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn atomic_fence() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (memory 1)
+            (func
+                atomic.fence
+            )
+        )
+    "#,
+    );
+    TranslationTest::new_with_config(wasm, threads_config())
+        .expect_func_instrs([Instruction::Return])
+        .run();
+}
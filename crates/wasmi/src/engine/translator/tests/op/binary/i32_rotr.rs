@@ -52,6 +52,26 @@ fn reg_1_after_mod32() {
     test_binary_reg_imm_with(WASM_OP, 65_i32, expected).run();
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn reg_large_count_after_mod32() {
+    fn test_for(count: i32, expected_count: i16) {
+        let expected = [
+            Instruction::i32_rotr_imm(
+                Register::from_i16(1),
+                Register::from_i16(0),
+                <Const16<i32>>::from(expected_count),
+            ),
+            Instruction::return_reg(1),
+        ];
+        test_binary_reg_imm_with(WASM_OP, count, expected).run();
+    }
+    test_for(-1, 31);
+    test_for(i32::MAX, 31);
+    test_for(i32::MIN + 1, 1);
+    test_for(0x1_0003_i32, 3);
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn zero_reg() {
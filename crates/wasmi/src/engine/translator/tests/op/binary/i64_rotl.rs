@@ -52,6 +52,26 @@ fn reg_1_after_mod32() {
     test_binary_reg_imm_with(WASM_OP, 129_i32, expected).run();
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn reg_large_count_after_mod64() {
+    fn test_for(count: i64, expected_count: i16) {
+        let expected = [
+            Instruction::i64_rotl_imm(
+                Register::from_i16(1),
+                Register::from_i16(0),
+                <Const16<i64>>::from(expected_count),
+            ),
+            Instruction::return_reg(1),
+        ];
+        test_binary_reg_imm_with(WASM_OP, count, expected).run();
+    }
+    test_for(-1, 63);
+    test_for(i64::MAX, 63);
+    test_for(i64::MIN + 1, 1);
+    test_for(0x1_0000_0003_i64, 3);
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn zero_reg() {
@@ -220,15 +220,15 @@ fn branched_block_1_imm_i64imm32() {
 #[cfg_attr(miri, ignore)]
 fn branched_block_1_imm_i64() {
     fn test_for_i64(value: i64) {
+        let [lo, hi] = Instruction::const64(value);
         testcase_branched_block_1_imm::<i64>(value)
-            .expect_func(
-                ExpectedFunc::new([
-                    Instruction::copy(Register::from_i16(0), Register::from_i16(-1)),
-                    Instruction::branch(BranchOffset::from(1)),
-                    Instruction::return_reg(Register::from_i16(0)),
-                ])
-                .consts([value]),
-            )
+            .expect_func_instrs([
+                Instruction::copy_i64imm(Register::from_i16(0)),
+                lo,
+                hi,
+                Instruction::branch(BranchOffset::from(1)),
+                Instruction::return_reg(Register::from_i16(0)),
+            ])
             .run();
     }
     test_for_i64(i64::from(i32::MIN) - 1);
@@ -286,15 +286,15 @@ fn branched_block_1_imm_f64imm32() {
 #[cfg_attr(miri, ignore)]
 fn branched_block_1_imm_f64() {
     fn test_for_f64(value: f64) {
+        let [lo, hi] = Instruction::const64(value);
         testcase_branched_block_1_imm::<f64>(value)
-            .expect_func(
-                ExpectedFunc::new([
-                    Instruction::copy(Register::from_i16(0), Register::from_i16(-1)),
-                    Instruction::branch(BranchOffset::from(1)),
-                    Instruction::return_reg(Register::from_i16(0)),
-                ])
-                .consts([value]),
-            )
+            .expect_func_instrs([
+                Instruction::copy_f64imm(Register::from_i16(0)),
+                lo,
+                hi,
+                Instruction::branch(BranchOffset::from(1)),
+                Instruction::return_reg(Register::from_i16(0)),
+            ])
             .run();
     }
     test_for_f64(0.3);
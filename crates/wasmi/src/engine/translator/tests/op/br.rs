@@ -65,10 +65,7 @@ fn as_return_1_imm() {
             )",
         ));
         TranslationTest::new(wasm)
-            .expect_func(
-                ExpectedFunc::new([Instruction::return_reg(Register::from_i16(-1))])
-                    .consts([value]),
-            )
+            .expect_func_instrs(<T as WasmType>::return_imm_instrs(&value))
             .run()
     }
     test_for::<i64>(i64::MIN);
@@ -8,7 +8,6 @@ use crate::engine::{
     },
 };
 use core::fmt::Display;
-use wasmi_core::UntypedValue;
 
 #[test]
 #[cfg_attr(miri, ignore)]
@@ -69,9 +68,9 @@ fn consteval_return_1_imm() {
         T: WasmType,
         DisplayWasm<T>: Display,
     {
-        let expected: UntypedValue = match condition {
-            true => if_true.into(),
-            false => if_false.into(),
+        let expected: T = match condition {
+            true => if_true,
+            false => if_false,
         };
         let condition = DisplayWasm::from(i32::from(condition));
         let display_ty = DisplayValueType::from(<T as WasmType>::VALUE_TYPE);
@@ -90,10 +89,7 @@ fn consteval_return_1_imm() {
             )",
         ));
         TranslationTest::new(wasm)
-            .expect_func(
-                ExpectedFunc::new([Instruction::return_reg(Register::from_i16(-1))])
-                    .consts([expected]),
-            )
+            .expect_func_instrs(<T as WasmType>::return_imm_instrs(&expected))
             .run()
     }
     /// Run the test for both sign polarities of the `br_if` condition.
@@ -279,6 +275,51 @@ fn consteval_branch_never() {
         .run()
 }
 
+/// Returns a function branching to its outer block with a constant-folded `br_if` condition.
+fn consteval_br_if_1_wasm(condition: bool) -> Vec<u8> {
+    let condition = DisplayWasm::from(i32::from(!condition));
+    wat2wasm(&format!(
+        r"
+        (module
+            (func (param i32 i32) (result i32)
+                (block (result i32)
+                    (block
+                        (local.get 0)
+                        (i32.eqz (i32.const {condition})) ;; br_if condition
+                        (br_if 1)
+                        (drop)
+                        (local.set 1 (i32.add (local.get 1) (i32.const 1)))
+                    )
+                    (local.get 1)
+                )
+            )
+        )",
+    ))
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn consteval_br_if_1_always() {
+    TranslationTest::new(consteval_br_if_1_wasm(true))
+        .expect_func_instrs([
+            Instruction::copy(Register::from_i16(2), Register::from_i16(0)),
+            Instruction::branch(BranchOffset::from(1)),
+            Instruction::return_reg(Register::from_i16(2)),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn consteval_br_if_1_never() {
+    TranslationTest::new(consteval_br_if_1_wasm(false))
+        .expect_func_instrs([
+            Instruction::i32_add_imm16(Register::from_i16(1), Register::from_i16(1), 1),
+            Instruction::return_reg(Register::from_i16(1)),
+        ])
+        .run()
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn return_if_results_0() {
@@ -243,9 +243,8 @@ fn params7_reg() {
     TranslationTest::new(wasm)
         .expect_func_instrs([
             Instruction::call_imported(RegisterSpan::new(Register::from_i16(7)), FuncIdx::from(0)),
-            Instruction::register_list(0, 1, 2),
-            Instruction::register_list(3, 4, 5),
-            Instruction::register(6),
+            Instruction::register_span_list(RegisterSpan::new(Register::from_i16(0)), 4),
+            Instruction::register3(4, 5, 6),
             Instruction::return_span(RegisterSpan::new(Register::from_i16(7)).iter(7)),
         ])
         .run();
@@ -346,9 +345,8 @@ fn params8_reg() {
     TranslationTest::new(wasm)
         .expect_func_instrs([
             Instruction::call_imported(RegisterSpan::new(Register::from_i16(8)), FuncIdx::from(0)),
-            Instruction::register_list(0, 1, 2),
-            Instruction::register_list(3, 4, 5),
-            Instruction::register2(6, 7),
+            Instruction::register_span_list(RegisterSpan::new(Register::from_i16(0)), 5),
+            Instruction::register3(5, 6, 7),
             Instruction::return_span(RegisterSpan::new(Register::from_i16(8)).iter(8)),
         ])
         .run();
@@ -452,8 +450,7 @@ fn params9_reg() {
     TranslationTest::new(wasm)
         .expect_func_instrs([
             Instruction::call_imported(RegisterSpan::new(Register::from_i16(9)), FuncIdx::from(0)),
-            Instruction::register_list(0, 1, 2),
-            Instruction::register_list(3, 4, 5),
+            Instruction::register_span_list(RegisterSpan::new(Register::from_i16(0)), 6),
             Instruction::register3(6, 7, 8),
             Instruction::return_span(RegisterSpan::new(Register::from_i16(9)).iter(9)),
         ])
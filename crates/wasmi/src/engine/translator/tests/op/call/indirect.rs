@@ -1,8 +1,10 @@
 use super::*;
 use crate::engine::{
     bytecode::{GlobalIdx, SignatureIdx, TableIdx},
+    CompiledFunc,
     RegisterSpan,
 };
+use wasmi_core::TrapCode;
 
 #[test]
 #[cfg_attr(miri, ignore)]
@@ -650,9 +652,8 @@ fn params7_reg_index_local() {
                 SignatureIdx::from(0),
             ),
             Instruction::call_indirect_params(Register::from_i16(0), TableIdx::from(0)),
-            Instruction::register_list(1, 2, 3),
-            Instruction::register_list(4, 5, 6),
-            Instruction::register(7),
+            Instruction::register_span_list(RegisterSpan::new(Register::from_i16(1)), 4),
+            Instruction::register3(5, 6, 7),
             Instruction::return_span(RegisterSpan::new(Register::from_i16(8)).iter(7)),
         ])
         .run();
@@ -728,9 +729,8 @@ fn params8_reg_index_local() {
                 SignatureIdx::from(0),
             ),
             Instruction::call_indirect_params(Register::from_i16(0), TableIdx::from(0)),
-            Instruction::register_list(1, 2, 3),
-            Instruction::register_list(4, 5, 6),
-            Instruction::register2(7, 8),
+            Instruction::register_span_list(RegisterSpan::new(Register::from_i16(1)), 5),
+            Instruction::register3(6, 7, 8),
             Instruction::return_span(RegisterSpan::new(Register::from_i16(9)).iter(8)),
         ])
         .run();
@@ -808,8 +808,7 @@ fn params9_reg_index_local() {
                 SignatureIdx::from(0),
             ),
             Instruction::call_indirect_params(Register::from_i16(0), TableIdx::from(0)),
-            Instruction::register_list(1, 2, 3),
-            Instruction::register_list(4, 5, 6),
+            Instruction::register_span_list(RegisterSpan::new(Register::from_i16(1)), 6),
             Instruction::register3(7, 8, 9),
             Instruction::return_span(RegisterSpan::new(Register::from_i16(10)).iter(9)),
         ])
@@ -935,3 +934,144 @@ fn regression_issue_768() {
         )
         .run();
 }
+
+/// Returns a Wasm module that indirectly calls `$f1` using a constant table index.
+///
+/// The `table` is used for the table declaration and `$caller_type` for the indirect call.
+/// The `mutator` is the body of a function that follows the calling function.
+fn const_index_wasm(table: &str, caller_type: &str, mutator: &str) -> Vec<u8> {
+    wat2wasm(&format!(
+        r#"
+        (module
+            (type $type (func (param i32) (result i32)))
+            (type $other (func (param i32) (result i64)))
+            {table}
+            (elem (i32.const 0) $f0 $f1)
+            (elem $passive func $f0)
+            (func $f0 (param i32) (result i32)
+                (local.get 0)
+            )
+            (func $f1 (param i32) (result i32)
+                (local.get 0)
+            )
+            (func (param $value i32)
+                (local.get $value)
+                (i32.const 1)
+                (call_indirect (type {caller_type}))
+                (drop)
+            )
+            (func
+                (unreachable)
+                {mutator}
+            )
+        )
+    "#,
+    ))
+}
+
+/// Returns the expected [`Instruction`]s of the function following the calling function.
+fn mutator_instrs() -> [Instruction; 1] {
+    [Instruction::Trap(TrapCode::UnreachableCodeReached)]
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn const_index_immutable_table() {
+    let wasm = const_index_wasm("(table $table 2 funcref)", "$type", "");
+    TranslationTest::new(wasm)
+        .expect_func_instrs([Instruction::return_reg(Register::from_i16(0))])
+        .expect_func_instrs([Instruction::return_reg(Register::from_i16(0))])
+        .expect_func_instrs([
+            Instruction::call_internal(
+                RegisterSpan::new(Register::from_i16(1)),
+                CompiledFunc::from_u32(1),
+            ),
+            Instruction::register(Register::from_i16(0)),
+            Instruction::Return,
+        ])
+        .expect_func_instrs(mutator_instrs())
+        .run();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn const_index_mutable_table() {
+    fn test_with(table: &str, mutator: &str) {
+        let wasm = const_index_wasm(table, "$type", mutator);
+        TranslationTest::new(wasm)
+            .expect_func_instrs([Instruction::return_reg(Register::from_i16(0))])
+            .expect_func_instrs([Instruction::return_reg(Register::from_i16(0))])
+            .expect_func_instrs([
+                Instruction::call_indirect(
+                    RegisterSpan::new(Register::from_i16(1)),
+                    SignatureIdx::from(0),
+                ),
+                Instruction::call_indirect_params_imm16(u32imm16(1), TableIdx::from(0)),
+                Instruction::register(Register::from_i16(0)),
+                Instruction::Return,
+            ])
+            .expect_func_instrs(mutator_instrs())
+            .run();
+    }
+    let table = "(table $table 2 funcref)";
+    // The table is mutated by Wasm instructions, even if they are unreachable.
+    test_with(table, "(table.set $table (i32.const 1) (ref.null func))");
+    test_with(
+        table,
+        "(table.fill $table (i32.const 1) (ref.null func) (i32.const 1))",
+    );
+    test_with(
+        table,
+        "(table.copy $table $table (i32.const 1) (i32.const 0) (i32.const 1))",
+    );
+    test_with(
+        table,
+        "(table.init $table $passive (i32.const 1) (i32.const 0) (i32.const 1))",
+    );
+    // The table may be mutated by the host.
+    test_with("(table $table (export \"table\") 2 funcref)", "");
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn const_index_grown_table() {
+    // Growing a table does not mutate its initialized elements.
+    let wasm = const_index_wasm(
+        "(table $table 2 funcref)",
+        "$type",
+        "(drop (table.grow $table (ref.null func) (i32.const 1)))",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([Instruction::return_reg(Register::from_i16(0))])
+        .expect_func_instrs([Instruction::return_reg(Register::from_i16(0))])
+        .expect_func_instrs([
+            Instruction::call_internal(
+                RegisterSpan::new(Register::from_i16(1)),
+                CompiledFunc::from_u32(1),
+            ),
+            Instruction::register(Register::from_i16(0)),
+            Instruction::Return,
+        ])
+        .expect_func_instrs(mutator_instrs())
+        .run();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn const_index_mismatching_type() {
+    let wasm = const_index_wasm("(table $table 2 funcref)", "$other", "");
+    TranslationTest::new(wasm)
+        .expect_func_instrs([Instruction::return_reg(Register::from_i16(0))])
+        .expect_func_instrs([Instruction::return_reg(Register::from_i16(0))])
+        .expect_func_instrs([
+            Instruction::call_indirect(
+                RegisterSpan::new(Register::from_i16(1)),
+                SignatureIdx::from(1),
+            ),
+            Instruction::call_indirect_params_imm16(u32imm16(1), TableIdx::from(0)),
+            Instruction::register(Register::from_i16(0)),
+            Instruction::Return,
+        ])
+        .expect_func_instrs(mutator_instrs())
+        .run();
+}
@@ -306,9 +306,8 @@ fn params7_reg() {
                 RegisterSpan::new(Register::from_i16(7)),
                 CompiledFunc::from_u32(0),
             ),
-            Instruction::register_list(0, 1, 2),
-            Instruction::register_list(3, 4, 5),
-            Instruction::register(6),
+            Instruction::register_span_list(RegisterSpan::new(Register::from_i16(0)), 4),
+            Instruction::register3(4, 5, 6),
             Instruction::return_span(RegisterSpan::new(Register::from_i16(7)).iter(7)),
         ])
         .run();
@@ -449,9 +448,8 @@ fn params8_reg() {
                 RegisterSpan::new(Register::from_i16(8)),
                 CompiledFunc::from_u32(0),
             ),
-            Instruction::register_list(0, 1, 2),
-            Instruction::register_list(3, 4, 5),
-            Instruction::register2(6, 7),
+            Instruction::register_span_list(RegisterSpan::new(Register::from_i16(0)), 5),
+            Instruction::register3(5, 6, 7),
             Instruction::return_span(RegisterSpan::new(Register::from_i16(8)).iter(8)),
         ])
         .run();
@@ -598,8 +596,7 @@ fn params9_reg() {
                 RegisterSpan::new(Register::from_i16(9)),
                 CompiledFunc::from_u32(0),
             ),
-            Instruction::register_list(0, 1, 2),
-            Instruction::register_list(3, 4, 5),
+            Instruction::register_span_list(RegisterSpan::new(Register::from_i16(0)), 6),
             Instruction::register3(6, 7, 8),
             Instruction::return_span(RegisterSpan::new(Register::from_i16(9)).iter(9)),
         ])
@@ -708,3 +705,71 @@ fn params9_imm() {
         )
         .run();
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn params16_reg() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (func $f (param i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32))
+            (func (param i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32)
+                (call $f
+                    (local.get 0) (local.get 1) (local.get 2) (local.get 3)
+                    (local.get 4) (local.get 5) (local.get 6) (local.get 7)
+                    (local.get 8) (local.get 9) (local.get 10) (local.get 11)
+                    (local.get 12) (local.get 13) (local.get 14) (local.get 15)
+                )
+            )
+        )
+    "#,
+    );
+    // Note: without spans the 16 contiguous parameters would be
+    //       encoded using 6 instruction words instead of just 2.
+    TranslationTest::new(wasm)
+        .expect_func_instrs([Instruction::Return])
+        .expect_func_instrs([
+            Instruction::call_internal(
+                RegisterSpan::new(Register::from_i16(16)),
+                CompiledFunc::from_u32(0),
+            ),
+            Instruction::register_span_list(RegisterSpan::new(Register::from_i16(0)), 13),
+            Instruction::register3(13, 14, 15),
+            Instruction::Return,
+        ])
+        .run();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn params12_reg_span_mid() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (func $f (param i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32))
+            (func (param i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32)
+                (call $f
+                    (local.get 11) (local.get 0) (local.get 1) (local.get 2)
+                    (local.get 3) (local.get 4) (local.get 5) (local.get 6)
+                    (local.get 7) (local.get 8) (local.get 9) (local.get 11)
+                )
+            )
+        )
+    "#,
+    );
+    // Note: without spans the 12 parameters would be encoded
+    //       using 4 instruction words instead of just 3.
+    TranslationTest::new(wasm)
+        .expect_func_instrs([Instruction::Return])
+        .expect_func_instrs([
+            Instruction::call_internal(
+                RegisterSpan::new(Register::from_i16(12)),
+                CompiledFunc::from_u32(0),
+            ),
+            Instruction::register_list(11, 0, 1),
+            Instruction::register_span_list(RegisterSpan::new(Register::from_i16(2)), 8),
+            Instruction::register(11),
+            Instruction::Return,
+        ])
+        .run();
+}
@@ -566,3 +566,180 @@ fn if_i64_eqz_fuse() {
         ])
         .run()
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn if_cmp_of_other_input_no_fuse() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param i32 i32 i32) (result i32)
+                (i32.lt_s (local.get 0) (local.get 1))
+                (if (local.get 2)
+                    (then)
+                )
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_lt_s(
+                Register::from_i16(3),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::branch_i32_eqz(Register::from_i16(2), BranchOffset16::from(1)),
+            Instruction::return_reg(3),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn br_if_cmp_of_other_input_no_fuse() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param i32 i32 i32) (result i32)
+                (i32.lt_s (local.get 0) (local.get 1))
+                (block
+                    (br_if 0 (local.get 2))
+                )
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_lt_s(
+                Register::from_i16(3),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::branch_i32_nez(Register::from_i16(2), BranchOffset16::from(1)),
+            Instruction::return_reg(3),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn br_if_cmp_scheduled_over_independent_instr() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param i32 i32 i32) (result i32)
+                (block
+                    (i32.lt_s (local.get 0) (local.get 1))
+                    (local.set 2 (i32.add (local.get 2) (i32.const 1)))
+                    (br_if 0)
+                )
+                (local.get 2)
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_add_imm16(Register::from_i16(2), Register::from_i16(2), 1),
+            Instruction::branch_i32_lt_s(
+                Register::from_i16(0),
+                Register::from_i16(1),
+                BranchOffset16::from(1),
+            ),
+            Instruction::return_reg(2),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn if_cmp_scheduled_over_independent_instr() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param i32 i32 i32) (result i32)
+                (i32.lt_s (local.get 0) (local.get 1))
+                (local.set 2 (i32.add (local.get 2) (i32.const 1)))
+                (if
+                    (then
+                        (local.set 2 (i32.const 0))
+                    )
+                )
+                (local.get 2)
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_add_imm16(Register::from_i16(2), Register::from_i16(2), 1),
+            Instruction::branch_i32_ge_s(
+                Register::from_i16(0),
+                Register::from_i16(1),
+                BranchOffset16::from(2),
+            ),
+            Instruction::copy_imm32(Register::from_i16(2), 0),
+            Instruction::return_reg(2),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn br_if_cmp_not_scheduled_over_dependent_instr() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param i32 i32 i32) (result i32)
+                (block
+                    (i32.lt_s (local.get 0) (local.get 1))
+                    (local.set 0 (i32.add (local.get 2) (i32.const 1)))
+                    (br_if 0)
+                )
+                (local.get 0)
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_lt_s(
+                Register::from_i16(3),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::i32_add_imm16(Register::from_i16(0), Register::from_i16(2), 1),
+            Instruction::branch_i32_nez(Register::from_i16(3), BranchOffset16::from(1)),
+            Instruction::return_reg(0),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn br_if_cmp_not_scheduled_over_pinned_label() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param i32 i32 i32) (result i32)
+                (block
+                    (i32.lt_s (local.get 0) (local.get 1))
+                    (loop
+                        (local.set 2 (i32.add (local.get 2) (i32.const 1)))
+                    )
+                    (br_if 0)
+                )
+                (local.get 2)
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_lt_s(
+                Register::from_i16(3),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::i32_add_imm16(Register::from_i16(2), Register::from_i16(2), 1),
+            Instruction::branch_i32_nez(Register::from_i16(3), BranchOffset16::from(1)),
+            Instruction::return_reg(2),
+        ])
+        .run()
+}
@@ -0,0 +1,193 @@
+use super::*;
+use crate::{core::ValueType, engine::translator::tests::display_wasm::DisplayValueType};
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn cmp_select() {
+    fn test_for(
+        ty: ValueType,
+        op: &str,
+        swap: bool,
+        expect_instr: fn(result: Register, lhs: Register, rhs: Register) -> Instruction,
+    ) {
+        let ty = DisplayValueType::from(ty);
+        let wasm = wat2wasm(&format!(
+            r"
+            (module
+                (func (param {ty} {ty} i32 i32) (result i32)
+                    (local.get 2)
+                    (local.get 3)
+                    (local.get 0)
+                    (local.get 1)
+                    ({ty}.{op})
+                    (select)
+                )
+            )",
+        ));
+        let (lhs, rhs) = match swap {
+            true => (Register::from_i16(1), Register::from_i16(0)),
+            false => (Register::from_i16(0), Register::from_i16(1)),
+        };
+        TranslationTest::new(wasm)
+            .expect_func_instrs([
+                expect_instr(Register::from_i16(4), lhs, rhs),
+                Instruction::register2(2, 3),
+                Instruction::return_reg(4),
+            ])
+            .run()
+    }
+
+    test_for(ValueType::I32, "eq", false, Instruction::select_i32_eq);
+    test_for(ValueType::I32, "ne", false, Instruction::select_i32_ne);
+    test_for(ValueType::I32, "lt_s", false, Instruction::select_i32_lt_s);
+    test_for(ValueType::I32, "lt_u", false, Instruction::select_i32_lt_u);
+    test_for(ValueType::I32, "le_s", false, Instruction::select_i32_le_s);
+    test_for(ValueType::I32, "le_u", false, Instruction::select_i32_le_u);
+    test_for(ValueType::I32, "gt_s", true, Instruction::select_i32_lt_s);
+    test_for(ValueType::I32, "gt_u", true, Instruction::select_i32_lt_u);
+    test_for(ValueType::I32, "ge_s", true, Instruction::select_i32_le_s);
+    test_for(ValueType::I32, "ge_u", true, Instruction::select_i32_le_u);
+
+    test_for(ValueType::I64, "eq", false, Instruction::select_i64_eq);
+    test_for(ValueType::I64, "ne", false, Instruction::select_i64_ne);
+    test_for(ValueType::I64, "lt_s", false, Instruction::select_i64_lt_s);
+    test_for(ValueType::I64, "lt_u", false, Instruction::select_i64_lt_u);
+    test_for(ValueType::I64, "le_s", false, Instruction::select_i64_le_s);
+    test_for(ValueType::I64, "le_u", false, Instruction::select_i64_le_u);
+    test_for(ValueType::I64, "gt_s", true, Instruction::select_i64_lt_s);
+    test_for(ValueType::I64, "gt_u", true, Instruction::select_i64_lt_u);
+    test_for(ValueType::I64, "ge_s", true, Instruction::select_i64_le_s);
+    test_for(ValueType::I64, "ge_u", true, Instruction::select_i64_le_u);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn cmp_select_local_set() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param i32 i32 i64 i64) (result i64)
+                (local i64)
+                (local.set 4
+                    (select
+                        (local.get 2)
+                        (local.get 3)
+                        (i32.lt_s (local.get 0) (local.get 1))
+                    )
+                )
+                (local.get 4)
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::select_i32_lt_s(
+                Register::from_i16(4),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::register2(2, 3),
+            Instruction::return_reg(4),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn cmp_select_no_fuse_local_condition() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param i32 i32 i32 i32) (result i32)
+                (local i32)
+                (select
+                    (local.get 2)
+                    (local.get 3)
+                    (local.tee 4 (i32.lt_s (local.get 0) (local.get 1)))
+                )
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_lt_s(
+                Register::from_i16(4),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::select(
+                Register::from_i16(5),
+                Register::from_i16(4),
+                Register::from_i16(2),
+            ),
+            Instruction::register(3),
+            Instruction::return_reg(5),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn cmp_select_no_fuse_other_condition() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param i32 i32 i32 i32) (result i32)
+                (select
+                    (local.get 2)
+                    (i32.lt_s (local.get 0) (local.get 1))
+                    (local.get 3)
+                )
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_lt_s(
+                Register::from_i16(4),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::select(
+                Register::from_i16(4),
+                Register::from_i16(3),
+                Register::from_i16(2),
+            ),
+            Instruction::register(4),
+            Instruction::return_reg(4),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn cmp_select_no_fuse_float() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param f32 f32 i32 i32) (result i32)
+                (select
+                    (local.get 2)
+                    (local.get 3)
+                    (f32.lt (local.get 0) (local.get 1))
+                )
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::f32_lt(
+                Register::from_i16(4),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::select(
+                Register::from_i16(4),
+                Register::from_i16(4),
+                Register::from_i16(2),
+            ),
+            Instruction::register(3),
+            Instruction::return_reg(4),
+        ])
+        .run()
+}
@@ -0,0 +1,257 @@
+//! Translation tests for enabling and disabling kinds of instruction fusion.
+
+use super::*;
+use crate::{engine::bytecode::BranchOffset16, Config, FusionKind};
+
+/// Returns a [`Config`] with all kinds of instruction fusion in `disabled` disabled.
+fn config_without(disabled: &[FusionKind]) -> Config {
+    let mut config = Config::default();
+    for &kind in disabled {
+        config.enable_fusion(kind, false);
+    }
+    config
+}
+
+/// Asserts that `wasm` translates to `fused` by default and to `unfused` without `kind`.
+fn test_toggle<const N: usize, const M: usize>(
+    wasm: &str,
+    kind: FusionKind,
+    fused: [Instruction; N],
+    unfused: [Instruction; M],
+) {
+    let wasm = wat2wasm(wasm);
+    TranslationTest::new(wasm.clone())
+        .expect_func_instrs(fused)
+        .run();
+    TranslationTest::new_with_config(wasm, config_without(&[kind]))
+        .expect_func_instrs(unfused)
+        .run();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn cmp_branch() {
+    test_toggle(
+        r"
+        (module
+            (func (param i32 i32)
+                (loop
+                    (local.get 0)
+                    (local.get 1)
+                    (i32.eq)
+                    (br_if 0)
+                )
+            )
+        )",
+        FusionKind::CmpBranch,
+        [
+            Instruction::branch_i32_eq(
+                Register::from_i16(0),
+                Register::from_i16(1),
+                BranchOffset16::from(0),
+            ),
+            Instruction::Return,
+        ],
+        [
+            Instruction::i32_eq(
+                Register::from_i16(2),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::branch_i32_nez(Register::from_i16(2), BranchOffset16::from(-1)),
+            Instruction::Return,
+        ],
+    )
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn cmp_select() {
+    test_toggle(
+        r"
+        (module
+            (func (param i32 i32 i32 i32) (result i32)
+                (local.get 2)
+                (local.get 3)
+                (local.get 0)
+                (local.get 1)
+                (i32.lt_s)
+                (select)
+            )
+        )",
+        FusionKind::CmpSelect,
+        [
+            Instruction::select_i32_lt_s(
+                Register::from_i16(4),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::register2(2, 3),
+            Instruction::return_reg(4),
+        ],
+        [
+            Instruction::i32_lt_s(
+                Register::from_i16(4),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::select(
+                Register::from_i16(4),
+                Register::from_i16(4),
+                Register::from_i16(2),
+            ),
+            Instruction::Register(Register::from_i16(3)),
+            Instruction::return_reg(4),
+        ],
+    )
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn eqz_logic() {
+    test_toggle(
+        r"
+        (module
+            (func (param i32 i32) (result i32)
+                (local.get 0)
+                (local.get 1)
+                (i32.and)
+                (i32.eqz)
+            )
+        )",
+        FusionKind::EqzLogic,
+        [
+            Instruction::i32_and_eqz(
+                Register::from_i16(2),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::return_reg(2),
+        ],
+        [
+            Instruction::i32_and(
+                Register::from_i16(2),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::i32_eq_imm16(Register::from_i16(2), Register::from_i16(2), 0),
+            Instruction::return_reg(2),
+        ],
+    )
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn load_op() {
+    test_toggle(
+        r"
+        (module
+            (memory 1)
+            (func (param $acc i32) (param $ptr i32) (result i32)
+                (local.get $acc)
+                (local.get $ptr)
+                (i32.load offset=8)
+                (i32.add)
+            )
+        )",
+        FusionKind::LoadOp,
+        [
+            Instruction::i32_add_load(
+                Register::from_i16(2),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::const32(8_u32),
+            Instruction::return_reg(2),
+        ],
+        [
+            Instruction::i32_load_offset16(
+                Register::from_i16(2),
+                Register::from_i16(1),
+                Const16::from(8_u16),
+            ),
+            Instruction::i32_add(
+                Register::from_i16(2),
+                Register::from_i16(0),
+                Register::from_i16(2),
+            ),
+            Instruction::return_reg(2),
+        ],
+    )
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn copy_elision() {
+    test_toggle(
+        r"
+        (module
+            (func (param i32 i32)
+                (local.set 0
+                    (i32.add
+                        (local.get 0)
+                        (local.get 1)
+                    )
+                )
+            )
+        )",
+        FusionKind::CopyElision,
+        [
+            Instruction::i32_add(
+                Register::from_i16(0),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::Return,
+        ],
+        [
+            Instruction::i32_add(
+                Register::from_i16(2),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::copy(Register::from_i16(0), Register::from_i16(2)),
+            Instruction::Return,
+        ],
+    )
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn disable_cmp_branch_keep_copy_elision() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param i32 i32)
+                (loop
+                    (local.set 0
+                        (i32.add
+                            (local.get 0)
+                            (local.get 1)
+                        )
+                    )
+                    (local.get 0)
+                    (local.get 1)
+                    (i32.lt_s)
+                    (br_if 0)
+                )
+            )
+        )",
+    );
+    TranslationTest::new_with_config(wasm, config_without(&[FusionKind::CmpBranch]))
+        .expect_func_instrs([
+            Instruction::i32_add(
+                Register::from_i16(0),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::i32_lt_s(
+                Register::from_i16(2),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::branch_i32_nez(Register::from_i16(2), BranchOffset16::from(-2)),
+            Instruction::Return,
+        ])
+        .run()
+}
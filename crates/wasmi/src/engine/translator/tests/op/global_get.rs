@@ -83,18 +83,9 @@ where
         )
     "#,
     ));
-    let mut testcase = TranslationTest::new(wasm);
-    let instr = <T as WasmType>::return_imm_instr(&value);
-    match instr {
-        Instruction::ReturnReg { value: register } => {
-            assert!(register.is_const());
-            testcase.expect_func(ExpectedFunc::new([instr]).consts([value]));
-        }
-        instr => {
-            testcase.expect_func_instrs([instr]);
-        }
-    }
-    testcase.run();
+    TranslationTest::new(wasm)
+        .expect_func_instrs(<T as WasmType>::return_imm_instrs(&value))
+        .run();
 }
 
 #[test]
@@ -1,4 +1,5 @@
 use super::*;
+use crate::engine::bytecode::BranchOffset16;
 
 #[test]
 #[cfg_attr(miri, ignore)]
@@ -67,3 +68,123 @@ fn binop_imm_i32_eqz() {
     test_for("or", Instruction::i32_or_eqz_imm16);
     test_for("xor", Instruction::i32_xor_eqz_imm16);
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn binop_i32_eq_zero() {
+    fn test_for(
+        op: &str,
+        expect_instr: fn(result: Register, lhs: Register, rhs: Register) -> Instruction,
+    ) {
+        let expected = [
+            expect_instr(
+                Register::from_i16(2),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::return_reg(2),
+        ];
+        for cmp in ["(i32.const 0) (i32.eq)", "(i32.eqz)"] {
+            let wasm = wat2wasm(&format!(
+                r"
+                (module
+                    (func (param i32 i32) (result i32)
+                        (local.get 0)
+                        (local.get 1)
+                        (i32.{op})
+                        {cmp}
+                    )
+                )",
+            ));
+            TranslationTest::new(wasm)
+                .expect_func_instrs(expected)
+                .run()
+        }
+    }
+    test_for("and", Instruction::i32_and_eqz);
+    test_for("or", Instruction::i32_or_eqz);
+    test_for("xor", Instruction::i32_xor_eqz);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn binop_i32_eqz_of_other_input() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param i32 i32 i32) (result i32 i32)
+                (local.get 0)
+                (local.get 1)
+                (i32.and)
+                (local.get 2)
+                (i32.eqz)
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_and(
+                Register::from_i16(3),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::i32_eq_imm16(Register::from_i16(4), Register::from_i16(2), 0),
+            Instruction::return_reg2(3, 4),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn if_i32_eqz_and_eq_zero_agree() {
+    fn test_for(input: &str, expected: &[Instruction]) {
+        for cond in [
+            format!("(i32.eqz {input})"),
+            format!("(i32.eq {input} (i32.const 0))"),
+            format!("(i32.eq (i32.const 0) {input})"),
+        ] {
+            let wasm = wat2wasm(&format!(
+                r"
+                (module
+                    (func (param i32 i32)
+                        (if {cond}
+                            (then)
+                        )
+                    )
+                )",
+            ));
+            TranslationTest::new(wasm)
+                .expect_func_instrs(expected.iter().copied())
+                .run()
+        }
+    }
+    test_for(
+        "(local.get 0)",
+        &[
+            Instruction::branch_i32_nez(Register::from_i16(0), BranchOffset16::from(1)),
+            Instruction::Return,
+        ],
+    );
+    test_for(
+        "(i32.and (local.get 0) (local.get 1))",
+        &[
+            Instruction::branch_i32_and(
+                Register::from_i16(0),
+                Register::from_i16(1),
+                BranchOffset16::from(1),
+            ),
+            Instruction::Return,
+        ],
+    );
+    test_for(
+        "(i32.or (local.get 0) (i32.const 1))",
+        &[
+            Instruction::branch_i32_or_imm(
+                Register::from_i16(0),
+                Const16::from(1),
+                BranchOffset16::from(1),
+            ),
+            Instruction::Return,
+        ],
+    );
+}
@@ -0,0 +1,225 @@
+//! Translation tests for fused `load` + binary operation instructions.
+
+use super::*;
+
+type MakeLoadOp = fn(result: Register, operand: Register, ptr: Register) -> Instruction;
+
+/// All fusable binary operations with their load-op constructors.
+const LOAD_OPS: &[(&str, &str, MakeLoadOp)] = &[
+    ("i32", "add", Instruction::i32_add_load),
+    ("i32", "sub", Instruction::i32_sub_load),
+    ("i32", "and", Instruction::i32_and_load),
+    ("i32", "or", Instruction::i32_or_load),
+    ("i32", "xor", Instruction::i32_xor_load),
+    ("i64", "add", Instruction::i64_add_load),
+    ("i64", "sub", Instruction::i64_sub_load),
+    ("i64", "and", Instruction::i64_and_load),
+    ("i64", "or", Instruction::i64_or_load),
+    ("i64", "xor", Instruction::i64_xor_load),
+];
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn reg_load() {
+    fn test_for(ty: &str, op: &str, offset: u32, make_instr: MakeLoadOp) {
+        let wasm = wat2wasm(&format!(
+            r"
+            (module
+                (memory 1)
+                (func (param $acc {ty}) (param $ptr i32) (result {ty})
+                    (local.get $acc)
+                    (local.get $ptr)
+                    ({ty}.load offset={offset})
+                    ({ty}.{op})
+                )
+            )",
+        ));
+        TranslationTest::new(wasm)
+            .expect_func_instrs([
+                make_instr(
+                    Register::from_i16(2),
+                    Register::from_i16(0),
+                    Register::from_i16(1),
+                ),
+                Instruction::const32(offset),
+                Instruction::return_reg(2),
+            ])
+            .run()
+    }
+    for &(ty, op, make_instr) in LOAD_OPS {
+        // Uses the `load` variant with 16-bit encoded offset.
+        test_for(ty, op, 8, make_instr);
+        // Uses the `load` variant with 32-bit encoded offset.
+        test_for(ty, op, u32::from(u16::MAX) + 1, make_instr);
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn load_reg_commutative() {
+    fn test_for(ty: &str, op: &str, make_instr: MakeLoadOp) {
+        let wasm = wat2wasm(&format!(
+            r"
+            (module
+                (memory 1)
+                (func (param $acc {ty}) (param $ptr i32) (result {ty})
+                    (local.get $ptr)
+                    ({ty}.load offset=4)
+                    (local.get $acc)
+                    ({ty}.{op})
+                )
+            )",
+        ));
+        TranslationTest::new(wasm)
+            .expect_func_instrs([
+                make_instr(
+                    Register::from_i16(2),
+                    Register::from_i16(0),
+                    Register::from_i16(1),
+                ),
+                Instruction::const32(4),
+                Instruction::return_reg(2),
+            ])
+            .run()
+    }
+    for &(ty, op, make_instr) in LOAD_OPS {
+        if op == "sub" {
+            continue;
+        }
+        test_for(ty, op, make_instr);
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn load_reg_sub_not_fused() {
+    fn test_for(
+        ty: &str,
+        make_load: fn(result: Register, ptr: Register, offset: Const16<u32>) -> Instruction,
+        make_sub: fn(result: Register, lhs: Register, rhs: Register) -> Instruction,
+    ) {
+        let wasm = wat2wasm(&format!(
+            r"
+            (module
+                (memory 1)
+                (func (param $acc {ty}) (param $ptr i32) (result {ty})
+                    (local.get $ptr)
+                    ({ty}.load offset=4)
+                    (local.get $acc)
+                    ({ty}.sub)
+                )
+            )",
+        ));
+        TranslationTest::new(wasm)
+            .expect_func_instrs([
+                make_load(
+                    Register::from_i16(2),
+                    Register::from_i16(1),
+                    <Const16<u32>>::from(4),
+                ),
+                make_sub(
+                    Register::from_i16(2),
+                    Register::from_i16(2),
+                    Register::from_i16(0),
+                ),
+                Instruction::return_reg(2),
+            ])
+            .run()
+    }
+    test_for("i32", Instruction::i32_load_offset16, Instruction::i32_sub);
+    test_for("i64", Instruction::i64_load_offset16, Instruction::i64_sub);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn accumulate_into_local() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (memory 1)
+            (func (param $acc i32) (param $ptr i32) (result i32)
+                (local.set $acc
+                    (i32.add
+                        (local.get $acc)
+                        (i32.load offset=4 (local.get $ptr))
+                    )
+                )
+                (local.get $acc)
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_add_load(
+                Register::from_i16(0),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::const32(4),
+            Instruction::return_reg(0),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn load_stored_to_local_not_fused() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (memory 1)
+            (func (param $acc i32) (param $ptr i32) (result i32)
+                (local $tmp i32)
+                (local.set $tmp (i32.load offset=4 (local.get $ptr)))
+                (i32.add (local.get $acc) (local.get $tmp))
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_load_offset16(
+                Register::from_i16(2),
+                Register::from_i16(1),
+                <Const16<u32>>::from(4),
+            ),
+            Instruction::i32_add(
+                Register::from_i16(3),
+                Register::from_i16(0),
+                Register::from_i16(2),
+            ),
+            Instruction::return_reg(3),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn narrow_load_not_fused() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (memory 1)
+            (func (param $acc i32) (param $ptr i32) (result i32)
+                (i32.add
+                    (local.get $acc)
+                    (i32.load8_u offset=4 (local.get $ptr))
+                )
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_load8_u_offset16(
+                Register::from_i16(2),
+                Register::from_i16(1),
+                <Const16<u32>>::from(4),
+            ),
+            Instruction::i32_add(
+                Register::from_i16(2),
+                Register::from_i16(0),
+                Register::from_i16(2),
+            ),
+            Instruction::return_reg(2),
+        ])
+        .run()
+}
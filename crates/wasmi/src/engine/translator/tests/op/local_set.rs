@@ -1,8 +1,13 @@
 use super::*;
-use crate::engine::{
-    bytecode::{FuncIdx, RegisterSpan, SignatureIdx, TableIdx},
-    CompiledFunc,
+use crate::{
+    core::UntypedValue,
+    engine::{
+        bytecode::{BranchOffset16, FuncIdx, GlobalIdx, RegisterSpan, SignatureIdx, TableIdx},
+        CompiledFunc,
+    },
+    Config,
 };
+use std::fmt::Display;
 
 #[test]
 #[cfg_attr(miri, ignore)]
@@ -167,8 +172,7 @@ fn overwrite_select_result_1() {
     );
     TranslationTest::new(wasm)
         .expect_func_instrs([
-            Instruction::select_imm32(Register::from_i16(0), 10_i32),
-            Instruction::select_imm32(Register::from_i16(0), 20_i32),
+            Instruction::select_imm(Register::from_i16(0), Register::from_i16(0), 10, 20),
             Instruction::return_reg(Register::from_i16(0)),
         ])
         .run()
@@ -308,6 +312,85 @@ fn preserve_result_2() {
         .run()
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn preserve_result_max_shift_0() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (func (param $lhs i32) (param $rhs i32) (result i32)
+                (local.get 0)
+                (local.set 0
+                    (i32.add
+                        (local.get $lhs)
+                        (local.get $rhs)
+                    )
+                )
+            )
+        )"#,
+    );
+    let mut config = Config::default();
+    config.max_preservation_shift(0);
+    TranslationTest::new_with_config(wasm, config)
+        .expect_func_instrs([
+            Instruction::i32_add(
+                Register::from_i16(2),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::copy(Register::from_i16(3), Register::from_i16(0)),
+            Instruction::copy(Register::from_i16(0), Register::from_i16(2)),
+            Instruction::return_reg(Register::from_i16(3)),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn preserve_result_max_shift_exceeded() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (func (param $lhs i32) (param $rhs i32) (result i32 i32)
+                (local.get 0)
+                (local.set 0
+                    (i32.add
+                        (local.get $lhs)
+                        (local.get $rhs)
+                    )
+                )
+                (local.get 1)
+                (local.set 1
+                    (i32.mul
+                        (local.get $lhs)
+                        (local.get $rhs)
+                    )
+                )
+            )
+        )"#,
+    );
+    let mut config = Config::default();
+    config.max_preservation_shift(1);
+    TranslationTest::new_with_config(wasm, config)
+        .expect_func_instrs([
+            Instruction::copy(Register::from_i16(4), Register::from_i16(0)),
+            Instruction::i32_add(
+                Register::from_i16(0),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::i32_mul(
+                Register::from_i16(2),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::copy(Register::from_i16(3), Register::from_i16(1)),
+            Instruction::copy(Register::from_i16(1), Register::from_i16(2)),
+            Instruction::return_reg2(4, 3),
+        ])
+        .run()
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn preserve_multiple_0() {
@@ -493,3 +576,329 @@ fn preserve_multiple_6() {
         ])
         .run()
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn dead_store() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param $a i32) (param $b i32) (result i32)
+                (local $x i32)
+                (local.set $x (i32.add (local.get $a) (local.get $b)))
+                (local.set $x (i32.const 10))
+                (local.get $x)
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::copy_imm32(Register::from_i16(2), 10_i32),
+            Instruction::return_reg(Register::from_i16(2)),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn dead_store_tee_dropped() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param $a i32) (param $b i32) (result i32)
+                (local $x i32)
+                (drop (local.tee $x (i32.mul (local.get $a) (local.get $b))))
+                (local.set $x (i32.add (local.get $a) (i32.const 1)))
+                (local.get $x)
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_add_imm16(Register::from_i16(2), Register::from_i16(0), 1),
+            Instruction::return_reg(Register::from_i16(2)),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn dead_store_read_in_between() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param $a i32) (param $b i32) (result i32)
+                (local $x i32)
+                (local.set $x (i32.add (local.get $a) (local.get $b)))
+                (local.set $x (i32.add (local.get $x) (i32.const 1)))
+                (local.get $x)
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_add(
+                Register::from_i16(2),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::i32_add_imm16(Register::from_i16(2), Register::from_i16(2), 1),
+            Instruction::return_reg(Register::from_i16(2)),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn dead_store_impure() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param $a i32) (param $b i32) (result i32)
+                (local $x i32)
+                (local.set $x (i32.div_s (local.get $a) (local.get $b)))
+                (local.set $x (i32.const 10))
+                (local.get $x)
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_div_s(
+                Register::from_i16(2),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::copy_imm32(Register::from_i16(2), 10_i32),
+            Instruction::return_reg(Register::from_i16(2)),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn dead_store_across_branch() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param $a i32) (param $b i32) (result i32)
+                (local $x i32)
+                (block
+                    (local.set $x (i32.add (local.get $a) (local.get $b)))
+                    (br_if 0 (local.get $a))
+                    (local.set $x (i32.const 10))
+                )
+                (local.get $x)
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_add(
+                Register::from_i16(2),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::branch_i32_nez(Register::from_i16(0), BranchOffset16::from(2)),
+            Instruction::copy_imm32(Register::from_i16(2), 10_i32),
+            Instruction::return_reg(Register::from_i16(2)),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn tee_chain_copy2() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (global $g (mut i32) (i32.const 0))
+            (func (param $x i32) (param $y i32) (result i32 i32)
+                (local $a i32)
+                (local $b i32)
+                (i32.add (local.get $x) (local.get $y))
+                (global.set $g (i32.const 1))
+                (local.tee $a)
+                (local.set $b)
+                (local.get $a)
+                (local.get $b)
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_add(
+                Register::from_i16(4),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::global_set_i32imm16(GlobalIdx::from(0), 1_i16),
+            Instruction::copy2(RegisterSpan::new(Register::from_i16(2)), 4, 4),
+            Instruction::return_reg2(2, 3),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn tee_chain_copy2_rev() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (global $g (mut i32) (i32.const 0))
+            (func (param $x i32) (param $y i32) (result i32 i32)
+                (local $a i32)
+                (local $b i32)
+                (i32.add (local.get $x) (local.get $y))
+                (global.set $g (i32.const 1))
+                (local.tee $b)
+                (local.set $a)
+                (local.get $a)
+                (local.get $b)
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_add(
+                Register::from_i16(4),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::global_set_i32imm16(GlobalIdx::from(0), 1_i16),
+            Instruction::copy2(RegisterSpan::new(Register::from_i16(2)), 4, 4),
+            Instruction::return_reg2(2, 3),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn tee_chain_with_reader() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (global $g (mut i32) (i32.const 0))
+            (func (param $x i32) (param $y i32) (result i32 i32)
+                (local $a i32)
+                (local $b i32)
+                (i32.add (local.get $x) (local.get $y))
+                (global.set $g (i32.const 1))
+                (local.tee $a)
+                (global.set $g (local.get $a))
+                (local.set $b)
+                (local.get $a)
+                (local.get $b)
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::i32_add(
+                Register::from_i16(4),
+                Register::from_i16(0),
+                Register::from_i16(1),
+            ),
+            Instruction::global_set_i32imm16(GlobalIdx::from(0), 1_i16),
+            Instruction::copy(Register::from_i16(2), Register::from_i16(4)),
+            Instruction::global_set(GlobalIdx::from(0), Register::from_i16(2)),
+            Instruction::copy(Register::from_i16(3), Register::from_i16(2)),
+            Instruction::return_reg2(2, 3),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn large_consts() {
+    fn test_for<T>(ty: &str, value: T)
+    where
+        T: Copy + Into<UntypedValue>,
+        DisplayWasm<T>: Display,
+    {
+        let value_str = DisplayWasm::from(value);
+        let wasm = wat2wasm(&format!(
+            r"
+            (module
+                (func (param {ty}) (result {ty} {ty} {ty} {ty})
+                    (local {ty} {ty} {ty})
+                    (local.set 0 ({ty}.const {value_str}))
+                    (local.set 1 ({ty}.const {value_str}))
+                    (local.set 2 ({ty}.const {value_str}))
+                    (local.set 3 ({ty}.const {value_str}))
+                    (local.get 0)
+                    (local.get 1)
+                    (local.get 2)
+                    (local.get 3)
+                )
+            )",
+        ));
+        let copy_imm = match ty {
+            "i64" => Instruction::copy_i64imm,
+            "f64" => Instruction::copy_f64imm,
+            _ => panic!("unexpected type: {ty}"),
+        };
+        let [lo, hi] = Instruction::const64(value);
+        TranslationTest::new(wasm)
+            .expect_func_instrs([
+                copy_imm(Register::from_i16(0)),
+                lo,
+                hi,
+                copy_imm(Register::from_i16(1)),
+                lo,
+                hi,
+                copy_imm(Register::from_i16(2)),
+                lo,
+                hi,
+                copy_imm(Register::from_i16(3)),
+                lo,
+                hi,
+                Instruction::return_span(RegisterSpan::new(Register::from_i16(0)).iter(4)),
+            ])
+            .run()
+    }
+    test_for("i64", 0x1234_5678_9ABC_DEF0_i64);
+    test_for("f64", 1.0e100_f64);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn dedup_large_consts() {
+    fn test_for<T>(
+        ty: &str,
+        value: T,
+        make_add: fn(result: Register, lhs: Register, rhs: Register) -> Instruction,
+    ) where
+        T: Copy + Into<UntypedValue>,
+        DisplayWasm<T>: Display,
+    {
+        let value_str = DisplayWasm::from(value);
+        let wasm = wat2wasm(&format!(
+            r"
+            (module
+                (func (param {ty} {ty}) (result {ty} {ty})
+                    (local.set 0 ({ty}.add (local.get 0) ({ty}.const {value_str})))
+                    (local.set 1 ({ty}.const {value_str}))
+                    (local.get 0)
+                    (local.get 1)
+                )
+            )",
+        ));
+        TranslationTest::new(wasm)
+            .expect_func(
+                ExpectedFunc::new([
+                    make_add(
+                        Register::from_i16(0),
+                        Register::from_i16(0),
+                        Register::from_i16(-1),
+                    ),
+                    Instruction::copy(Register::from_i16(1), Register::from_i16(-1)),
+                    Instruction::return_reg2(0, 1),
+                ])
+                .consts([value]),
+            )
+            .run()
+    }
+    test_for("i64", 0x1234_5678_9ABC_DEF0_i64, Instruction::i64_add);
+    test_for("f64", 1.0e100_f64, Instruction::f64_add);
+}
@@ -1,5 +1,8 @@
 use super::*;
-use crate::engine::bytecode::{BranchOffset, RegisterSpan};
+use crate::{
+    engine::bytecode::{BranchOffset, BranchOffset16, RegisterSpan},
+    Config,
+};
 
 #[test]
 #[cfg_attr(miri, ignore)]
@@ -127,6 +130,60 @@ fn identity_loop_2_nested() {
         .run()
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn identity_loop_3() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param i32 i32 i32) (result i32 i32 i32)
+                (local.get 0)
+                (local.get 1)
+                (local.get 2)
+                (loop (param i32 i32 i32) (result i32 i32 i32))
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::copy_span_small(
+                RegisterSpan::new(Register::from_i16(3)),
+                RegisterSpan::new(Register::from_i16(0)),
+                3,
+            ),
+            Instruction::return_reg3(3, 4, 5),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn identity_loop_5() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param i32 i32 i32 i32 i32) (result i32 i32 i32 i32 i32)
+                (local.get 0)
+                (local.get 1)
+                (local.get 2)
+                (local.get 3)
+                (local.get 4)
+                (loop (param i32 i32 i32 i32 i32) (result i32 i32 i32 i32 i32))
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::copy_span_non_overlapping(
+                RegisterSpan::new(Register::from_i16(5)),
+                RegisterSpan::new(Register::from_i16(0)),
+                5,
+            ),
+            Instruction::return_span(RegisterSpan::new(Register::from_i16(5)).iter(5)),
+        ])
+        .run()
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn repeat_loop() {
@@ -242,3 +299,36 @@ fn identity_loop_4_mixed_2() {
         ])
         .run()
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn counting_loop_with_fuel() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param $n i32) (result i32)
+                (local $i i32)
+                (loop $continue
+                    (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                    (br_if $continue (i32.lt_s (local.get $i) (local.get $n)))
+                )
+                (local.get $i)
+            )
+        )",
+    );
+    let mut config = Config::default();
+    config.consume_fuel(true);
+    TranslationTest::new_with_config(wasm, config)
+        .expect_func_instrs([
+            Instruction::consume_fuel(2).unwrap(),
+            Instruction::consume_fuel(3).unwrap(),
+            Instruction::i32_add_imm16(Register::from_i16(1), Register::from_i16(1), 1),
+            Instruction::branch_i32_lt_s(
+                Register::from_i16(1),
+                Register::from_i16(0),
+                BranchOffset16::from(-2),
+            ),
+            Instruction::return_reg(Register::from_i16(1)),
+        ])
+        .run()
+}
@@ -1,3 +1,4 @@
+mod atomic;
 mod binary;
 mod block;
 mod br;
@@ -6,14 +7,18 @@ mod br_table;
 mod call;
 mod cmp;
 mod cmp_br;
+mod cmp_select;
+mod fusion;
 mod global_get;
 mod global_set;
 mod i32_eqz;
 mod if_;
 mod load;
+mod load_op;
 mod local_set;
 mod loop_;
 mod memory;
+mod multi_memory;
 mod return_;
 mod return_call;
 mod select;
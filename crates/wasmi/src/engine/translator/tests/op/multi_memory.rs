@@ -0,0 +1,237 @@
+//! Translation tests for instructions operating on non-default linear memories.
+
+use super::*;
+use crate::Config;
+
+/// Returns a [`Config`] with the Wasm `multi-memory` proposal enabled.
+fn multi_memory_config() -> Config {
+    let mut config = Config::default();
+    config.wasm_multi_memory(true);
+    config
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn load_default_memory() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (memory $m0 1)
+            (memory $m1 1)
+            (func (param $ptr i32) (result i32)
+                local.get $ptr
+                i32.load $m0 offset=8
+            )
+        )
+    "#,
+    );
+    TranslationTest::new_with_config(wasm, multi_memory_config())
+        .expect_func_instrs([
+            Instruction::i32_load_offset16(
+                Register::from_i16(1),
+                Register::from_i16(0),
+                Const16::from(8_u16),
+            ),
+            Instruction::return_reg(Register::from_i16(1)),
+        ])
+        .run();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn load() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (memory $m0 1)
+            (memory $m1 1)
+            (func (param $ptr i32) (result i64)
+                local.get $ptr
+                i64.load $m1 offset=8
+            )
+        )
+    "#,
+    );
+    TranslationTest::new_with_config(wasm, multi_memory_config())
+        .expect_func_instrs([
+            Instruction::i64_load(Register::from_i16(1), Register::from_i16(0)),
+            Instruction::const32(8_u32),
+            Instruction::memory_idx(1),
+            Instruction::return_reg(Register::from_i16(1)),
+        ])
+        .run();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn load_const_ptr() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (memory $m0 1)
+            (memory $m1 1)
+            (func (result i32)
+                i32.const 100
+                i32.load8_u $m1
+            )
+        )
+    "#,
+    );
+    TranslationTest::new_with_config(wasm, multi_memory_config())
+        .expect_func(
+            ExpectedFunc::new([
+                Instruction::i32_load8_u(Register::from_i16(0), Register::from_i16(-1)),
+                Instruction::const32(0_u32),
+                Instruction::memory_idx(1),
+                Instruction::return_reg(Register::from_i16(0)),
+            ])
+            .consts([100_i32]),
+        )
+        .run();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn load_not_fused() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (memory $m0 1)
+            (memory $m1 1)
+            (func (param $ptr i32) (param $x i32) (result i32)
+                local.get $x
+                local.get $ptr
+                i32.load $m1
+                i32.add
+            )
+        )
+    "#,
+    );
+    TranslationTest::new_with_config(wasm, multi_memory_config())
+        .expect_func_instrs([
+            Instruction::i32_load(Register::from_i16(2), Register::from_i16(0)),
+            Instruction::const32(0_u32),
+            Instruction::memory_idx(1),
+            Instruction::i32_add(
+                Register::from_i16(2),
+                Register::from_i16(1),
+                Register::from_i16(2),
+            ),
+            Instruction::return_reg(Register::from_i16(2)),
+        ])
+        .run();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn store_imm() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (memory $m0 1)
+            (memory $m1 1)
+            (func (param $ptr i32)
+                local.get $ptr
+                i32.const 42
+                i32.store $m1 offset=4
+            )
+        )
+    "#,
+    );
+    TranslationTest::new_with_config(wasm, multi_memory_config())
+        .expect_func(
+            ExpectedFunc::new([
+                Instruction::i32_store(Register::from_i16(0), Const32::from(4_u32)),
+                Instruction::Register(Register::from_i16(-1)),
+                Instruction::memory_idx(1),
+                Instruction::Return,
+            ])
+            .consts([42_i32]),
+        )
+        .run();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn memory_size_and_grow() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (memory $m0 1)
+            (memory $m1 1)
+            (func (param $delta i32) (result i32 i32)
+                (memory.size $m1)
+                (memory.grow $m1 (local.get $delta))
+            )
+        )
+    "#,
+    );
+    TranslationTest::new_with_config(wasm, multi_memory_config())
+        .expect_func_instrs([
+            Instruction::memory_size(Register::from_i16(1)),
+            Instruction::memory_idx(1),
+            Instruction::memory_grow(Register::from_i16(2), Register::from_i16(0)),
+            Instruction::memory_idx(1),
+            Instruction::return_reg2(Register::from_i16(1), Register::from_i16(2)),
+        ])
+        .run();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn memory_copy() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (memory $m0 1)
+            (memory $m1 1)
+            (func (param $dst i32) (param $src i32) (param $len i32)
+                (memory.copy $m1 $m0 (local.get $dst) (local.get $src) (local.get $len))
+                (memory.copy $m0 $m0 (local.get $dst) (local.get $src) (local.get $len))
+            )
+        )
+    "#,
+    );
+    let dst = Register::from_i16(0);
+    let src = Register::from_i16(1);
+    let len = Register::from_i16(2);
+    TranslationTest::new_with_config(wasm, multi_memory_config())
+        .expect_func_instrs([
+            Instruction::memory_copy(dst, src, len),
+            Instruction::memory_idx(1),
+            Instruction::memory_idx(0),
+            Instruction::memory_copy(dst, src, len),
+            Instruction::Return,
+        ])
+        .run();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn memory_fill_and_init() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (memory $m0 1)
+            (memory $m1 1)
+            (data $d "abc")
+            (func (param $dst i32) (param $len i32)
+                (memory.fill $m1 (local.get $dst) (i32.const 7) (local.get $len))
+                (memory.init $m1 $d (local.get $dst) (i32.const 0) (local.get $len))
+            )
+        )
+    "#,
+    );
+    let dst = Register::from_i16(0);
+    let len = Register::from_i16(1);
+    TranslationTest::new_with_config(wasm, multi_memory_config())
+        .expect_func_instrs([
+            Instruction::memory_fill_imm(dst, 7, len),
+            Instruction::memory_idx(1),
+            Instruction::memory_init_from(dst, Const16::from(0_u16), len),
+            Instruction::data_idx(0),
+            Instruction::memory_idx(1),
+            Instruction::Return,
+        ])
+        .run();
+}
@@ -1,6 +1,6 @@
 use super::*;
 use crate::engine::{
-    bytecode::RegisterSpan,
+    bytecode::{BranchOffset16, RegisterSpan},
     translator::tests::{
         display_wasm::DisplayValueType,
         driver::ExpectedFunc,
@@ -62,10 +62,7 @@ fn return_1_imm() {
             )",
         ));
         TranslationTest::new(wasm)
-            .expect_func(
-                ExpectedFunc::new([Instruction::return_reg(Register::from_i16(-1))])
-                    .consts([value.into()]),
-            )
+            .expect_func_instrs(<T as WasmType>::return_imm_instrs(&value))
             .run()
     }
     test_for::<i64>(i64::MIN);
@@ -471,3 +468,79 @@ fn return_9() {
         ])
         .run()
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn merge_if_else_returns() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param i32 i32) (result i32)
+                (if (local.get 0)
+                    (then
+                        (return (local.get 1))
+                    )
+                    (else
+                        (return (local.get 1))
+                    )
+                )
+                (i32.const 0)
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::branch_i32_eq_imm(Register::from_i16(0), 0, BranchOffset16::from(1)),
+            Instruction::return_reg(Register::from_i16(1)),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn merge_block_returns() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param i32 i32) (result i32)
+                (block
+                    (br_if 0 (local.get 0))
+                    (local.set 1 (i32.add (local.get 1) (i32.const 1)))
+                    (return (local.get 1))
+                )
+                (return (local.get 1))
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::branch_i32_nez(Register::from_i16(0), BranchOffset16::from(2)),
+            Instruction::i32_add_imm16(Register::from_i16(1), Register::from_i16(1), 1),
+            Instruction::return_reg(Register::from_i16(1)),
+        ])
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn no_merge_different_returns() {
+    let wasm = wat2wasm(
+        r"
+        (module
+            (func (param i32 i32) (result i32)
+                (block
+                    (br_if 0 (local.get 0))
+                    (return (local.get 1))
+                )
+                (return (local.get 0))
+            )
+        )",
+    );
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::branch_i32_nez(Register::from_i16(0), BranchOffset16::from(2)),
+            Instruction::return_reg(Register::from_i16(1)),
+            Instruction::return_reg(Register::from_i16(0)),
+        ])
+        .run()
+}
@@ -1,5 +1,5 @@
 use super::*;
-use crate::engine::bytecode::FuncIdx;
+use crate::engine::{bytecode::FuncIdx, RegisterSpan};
 
 #[test]
 #[cfg_attr(miri, ignore)]
@@ -220,9 +220,8 @@ fn params7_reg() {
     TranslationTest::new(wasm)
         .expect_func_instrs([
             Instruction::return_call_imported(FuncIdx::from(0)),
-            Instruction::register_list(0, 1, 2),
-            Instruction::register_list(3, 4, 5),
-            Instruction::register(6),
+            Instruction::register_span_list(RegisterSpan::new(Register::from_i16(0)), 4),
+            Instruction::register3(4, 5, 6),
         ])
         .run();
 }
@@ -317,9 +316,8 @@ fn params8_reg() {
     TranslationTest::new(wasm)
         .expect_func_instrs([
             Instruction::return_call_imported(FuncIdx::from(0)),
-            Instruction::register_list(0, 1, 2),
-            Instruction::register_list(3, 4, 5),
-            Instruction::register2(6, 7),
+            Instruction::register_span_list(RegisterSpan::new(Register::from_i16(0)), 5),
+            Instruction::register3(5, 6, 7),
         ])
         .run();
 }
@@ -417,8 +415,7 @@ fn params9_reg() {
     TranslationTest::new(wasm)
         .expect_func_instrs([
             Instruction::return_call_imported(FuncIdx::from(0)),
-            Instruction::register_list(0, 1, 2),
-            Instruction::register_list(3, 4, 5),
+            Instruction::register_span_list(RegisterSpan::new(Register::from_i16(0)), 6),
             Instruction::register3(6, 7, 8),
         ])
         .run();
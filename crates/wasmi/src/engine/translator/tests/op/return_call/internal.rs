@@ -267,9 +267,8 @@ fn params7_reg() {
         )])
         .expect_func_instrs([
             Instruction::return_call_internal(CompiledFunc::from_u32(0)),
-            Instruction::register_list(0, 1, 2),
-            Instruction::register_list(3, 4, 5),
-            Instruction::register(6),
+            Instruction::register_span_list(RegisterSpan::new(Register::from_i16(0)), 4),
+            Instruction::register3(4, 5, 6),
         ])
         .run();
 }
@@ -398,9 +397,8 @@ fn params8_reg() {
         )])
         .expect_func_instrs([
             Instruction::return_call_internal(CompiledFunc::from_u32(0)),
-            Instruction::register_list(0, 1, 2),
-            Instruction::register_list(3, 4, 5),
-            Instruction::register2(6, 7),
+            Instruction::register_span_list(RegisterSpan::new(Register::from_i16(0)), 5),
+            Instruction::register3(5, 6, 7),
         ])
         .run();
 }
@@ -535,8 +533,7 @@ fn params9_reg() {
         )])
         .expect_func_instrs([
             Instruction::return_call_internal(CompiledFunc::from_u32(0)),
-            Instruction::register_list(0, 1, 2),
-            Instruction::register_list(3, 4, 5),
+            Instruction::register_span_list(RegisterSpan::new(Register::from_i16(0)), 6),
             Instruction::register3(6, 7, 8),
         ])
         .run();
@@ -244,13 +244,12 @@ fn same_imm() {
         T: WasmType,
         DisplayWasm<T>: Display,
     {
-        let instrs = [Instruction::return_reg(Register::from_i16(-1))];
-        let expected = ExpectedFunc::new(instrs).consts([value]);
+        let instrs = <T as WasmType>::return_imm_instrs(&value);
         test_same_imm(SelectKind::Select, value)
-            .expect_func(expected.clone())
+            .expect_func_instrs(instrs.clone())
             .run();
         test_same_imm(SelectKind::TypedSelect, value)
-            .expect_func(expected)
+            .expect_func_instrs(instrs)
             .run();
     }
 
@@ -687,8 +686,8 @@ fn both_imm32() {
         test_for_kind(SelectKind::TypedSelect, rhs, lhs);
     }
 
-    test_for::<i32>(0, 1);
-    test_for::<i32>(-5, 42);
+    test_for::<i32>(0, 128);
+    test_for::<i32>(-129, 42);
     test_for::<i32>(i32::MIN + 1, i32::MAX - 1);
     test_for::<i32>(i32::MIN, i32::MAX);
 
@@ -698,6 +697,34 @@ fn both_imm32() {
     test_for::<f32>(f32::NAN, f32::EPSILON);
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn both_imm8() {
+    fn test_for_kind(kind: SelectKind, lhs: i8, rhs: i8) {
+        let result = Register::from_i16(1);
+        let condition = Register::from_i16(0);
+        let expected = [
+            Instruction::select_imm(result, condition, lhs, rhs),
+            Instruction::return_reg(result),
+        ];
+        test_both_imm(kind, i32::from(lhs), i32::from(rhs))
+            .expect_func_instrs(expected)
+            .run();
+    }
+
+    fn test_for(lhs: i8, rhs: i8) {
+        test_for_kind(SelectKind::Select, lhs, rhs);
+        test_for_kind(SelectKind::Select, rhs, lhs);
+        test_for_kind(SelectKind::TypedSelect, lhs, rhs);
+        test_for_kind(SelectKind::TypedSelect, rhs, lhs);
+    }
+
+    test_for(0, 1);
+    test_for(-5, 42);
+    test_for(i8::MIN + 1, i8::MAX - 1);
+    test_for(i8::MIN, i8::MAX);
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn both_imm() {
@@ -799,3 +826,84 @@ fn both_f64imm32() {
     test_for(f64::NEG_INFINITY, f64::INFINITY);
     test_for(f64::NAN, f64::EPSILON);
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn const_condition_reg() {
+    fn test_for_kind(kind: SelectKind, result_ty: ValueType, condition: bool) {
+        let display_ty = DisplayValueType::from(result_ty);
+        let display_select = DisplaySelect::new(kind, result_ty);
+        let display_condition = DisplayWasm::from(i32::from(condition));
+        let wasm = wat2wasm(&format!(
+            r#"
+            (module
+                (func (param $lhs {display_ty}) (param $rhs {display_ty}) (result {display_ty})
+                    local.get $lhs
+                    local.get $rhs
+                    i32.const {display_condition}
+                    {display_select}
+                )
+            )
+        "#,
+        ));
+        let chosen = match condition {
+            true => Register::from_i16(0),
+            false => Register::from_i16(1),
+        };
+        TranslationTest::new(wasm)
+            .expect_func_instrs([Instruction::return_reg(chosen)])
+            .run();
+    }
+    fn test_for(kind: SelectKind, result_ty: ValueType) {
+        test_for_kind(kind, result_ty, true);
+        test_for_kind(kind, result_ty, false);
+    }
+    for ty in [
+        ValueType::I32,
+        ValueType::I64,
+        ValueType::F32,
+        ValueType::F64,
+    ] {
+        test_for(SelectKind::Select, ty);
+        test_for(SelectKind::TypedSelect, ty);
+    }
+    test_for(SelectKind::TypedSelect, ValueType::FuncRef);
+    test_for(SelectKind::TypedSelect, ValueType::ExternRef);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn const_condition_imm() {
+    fn test_for_kind(kind: SelectKind, lhs: i32, rhs: i32, condition: bool) {
+        let display_select = DisplaySelect::new(kind, ValueType::I32);
+        let display_condition = DisplayWasm::from(i32::from(condition));
+        let wasm = wat2wasm(&format!(
+            r#"
+            (module
+                (func (result i32)
+                    i32.const {lhs}
+                    i32.const {rhs}
+                    i32.const {display_condition}
+                    {display_select}
+                )
+            )
+        "#,
+        ));
+        let chosen = match condition {
+            true => lhs,
+            false => rhs,
+        };
+        TranslationTest::new(wasm)
+            .expect_func_instrs([Instruction::return_imm32(chosen)])
+            .run();
+    }
+    fn test_for(lhs: i32, rhs: i32) {
+        for kind in [SelectKind::Select, SelectKind::TypedSelect] {
+            test_for_kind(kind, lhs, rhs, true);
+            test_for_kind(kind, lhs, rhs, false);
+        }
+    }
+    test_for(0, 1);
+    test_for(-1, 42);
+    test_for(i32::MIN, i32::MAX);
+}
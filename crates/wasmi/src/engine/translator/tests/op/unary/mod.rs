@@ -4,7 +4,7 @@ mod conversion;
 mod op;
 
 use super::*;
-use crate::engine::translator::tests::driver::{ExpectedFunc, TranslationTest};
+use crate::engine::translator::tests::driver::TranslationTest;
 use std::fmt::Display;
 use wasm_type::WasmType;
 use wasmi_core::{TrapCode, UntypedValue};
@@ -56,6 +56,30 @@ where
     conversion_reg::<T, T>(wasm_op, make_instr)
 }
 
+/// Asserts that the function body `body` operating on `T` translates to the `expected` instructions.
+///
+/// The function has a single parameter and local variable of type `T` and returns a `T`.
+fn unary_body<T>(body: &str, expected: impl IntoIterator<Item = Instruction>)
+where
+    T: WasmType,
+{
+    let ty = <T as WasmType>::NAME;
+    let body = body.replace("{ty}", ty);
+    let wasm = wat2wasm(&format!(
+        r#"
+        (module
+            (func (param {ty}) (result {ty})
+                (local {ty})
+                {body}
+            )
+        )
+    "#,
+    ));
+    TranslationTest::new(wasm)
+        .expect_func_instrs(expected)
+        .run();
+}
+
 /// Asserts that the unary Wasm operator `wasm_op` translates properly to a unary `wasmi` instruction.
 fn conversion_imm<I, O>(wasm_op: &str, input: I, eval: fn(input: I) -> O)
 where
@@ -77,15 +101,9 @@ where
     "#,
     ));
     let result = eval(input);
-    let instr = <O as WasmType>::return_imm_instr(&result);
-    let mut testcase = TranslationTest::new(wasm);
-    if let Instruction::ReturnReg { value } = &instr {
-        assert!(value.is_const());
-        testcase.expect_func(ExpectedFunc::new([instr]).consts([result]));
-    } else {
-        testcase.expect_func_instrs([instr]);
-    }
-    testcase.run();
+    TranslationTest::new(wasm)
+        .expect_func_instrs(<O as WasmType>::return_imm_instrs(&result))
+        .run();
 }
 
 /// Asserts that the unary Wasm operator `wasm_op` translates properly to a unary `wasmi` instruction.
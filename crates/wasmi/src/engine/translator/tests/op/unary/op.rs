@@ -132,6 +132,59 @@ mod f32_neg {
         use core::ops::Neg as _;
         unary_imm::<f32>(OP_NAME, 42.5, f32::neg);
         unary_imm::<f32>(OP_NAME, -42.5, f32::neg);
+        unary_imm::<f32>(OP_NAME, f32::NAN, f32::neg);
+        unary_imm::<f32>(OP_NAME, -f32::NAN, f32::neg);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn double_neg_local() {
+        unary_body::<f32>(
+            "local.get 0 {ty}.neg {ty}.neg",
+            [Instruction::return_reg(Register::from_i16(0))],
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn double_neg_temp() {
+        unary_body::<f32>(
+            "local.get 0 local.get 0 {ty}.add {ty}.neg {ty}.neg",
+            [
+                Instruction::f32_add(
+                    Register::from_i16(2),
+                    Register::from_i16(0),
+                    Register::from_i16(0),
+                ),
+                Instruction::return_reg(Register::from_i16(2)),
+            ],
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn triple_neg() {
+        unary_body::<f32>(
+            "local.get 0 {ty}.neg {ty}.neg {ty}.neg",
+            [
+                Instruction::f32_neg(Register::from_i16(2), Register::from_i16(0)),
+                Instruction::return_reg(Register::from_i16(2)),
+            ],
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn double_neg_tee() {
+        // The first negation is stored into a local variable and therefore must be kept.
+        unary_body::<f32>(
+            "local.get 0 {ty}.neg local.tee 1 {ty}.neg",
+            [
+                Instruction::f32_neg(Register::from_i16(1), Register::from_i16(0)),
+                Instruction::f32_neg(Register::from_i16(2), Register::from_i16(1)),
+                Instruction::return_reg(Register::from_i16(2)),
+            ],
+        );
     }
 }
 
@@ -281,6 +334,59 @@ mod f64_neg {
         use core::ops::Neg as _;
         unary_imm::<f64>(OP_NAME, 42.5, f64::neg);
         unary_imm::<f64>(OP_NAME, -42.5, f64::neg);
+        unary_imm::<f64>(OP_NAME, f64::NAN, f64::neg);
+        unary_imm::<f64>(OP_NAME, -f64::NAN, f64::neg);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn double_neg_local() {
+        unary_body::<f64>(
+            "local.get 0 {ty}.neg {ty}.neg",
+            [Instruction::return_reg(Register::from_i16(0))],
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn double_neg_temp() {
+        unary_body::<f64>(
+            "local.get 0 local.get 0 {ty}.add {ty}.neg {ty}.neg",
+            [
+                Instruction::f64_add(
+                    Register::from_i16(2),
+                    Register::from_i16(0),
+                    Register::from_i16(0),
+                ),
+                Instruction::return_reg(Register::from_i16(2)),
+            ],
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn triple_neg() {
+        unary_body::<f64>(
+            "local.get 0 {ty}.neg {ty}.neg {ty}.neg",
+            [
+                Instruction::f64_neg(Register::from_i16(2), Register::from_i16(0)),
+                Instruction::return_reg(Register::from_i16(2)),
+            ],
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn double_neg_tee() {
+        // The first negation is stored into a local variable and therefore must be kept.
+        unary_body::<f64>(
+            "local.get 0 {ty}.neg local.tee 1 {ty}.neg",
+            [
+                Instruction::f64_neg(Register::from_i16(1), Register::from_i16(0)),
+                Instruction::f64_neg(Register::from_i16(2), Register::from_i16(1)),
+                Instruction::return_reg(Register::from_i16(2)),
+            ],
+        );
     }
 }
 
@@ -416,6 +522,9 @@ mod i32_extend8_s {
     fn imm() {
         let consteval = wrap_untyped!(i32_extend8_s);
         unary_imm::<i32>(OP_NAME, 0xFF, consteval);
+        unary_imm::<i32>(OP_NAME, 0x7F, consteval);
+        unary_imm::<i32>(OP_NAME, 0x80, consteval);
+        unary_imm::<i32>(OP_NAME, 0x1234_5680, consteval);
         unary_imm::<i32>(OP_NAME, 42, consteval);
         unary_imm::<i32>(OP_NAME, -42, consteval);
     }
@@ -437,6 +546,9 @@ mod i32_extend16_s {
     fn imm() {
         let consteval = wrap_untyped!(i32_extend16_s);
         unary_imm::<i32>(OP_NAME, 0xFFFF, consteval);
+        unary_imm::<i32>(OP_NAME, 0x7FFF, consteval);
+        unary_imm::<i32>(OP_NAME, 0x8000, consteval);
+        unary_imm::<i32>(OP_NAME, 0x1234_8000, consteval);
         unary_imm::<i32>(OP_NAME, 42, consteval);
         unary_imm::<i32>(OP_NAME, -42, consteval);
     }
@@ -458,6 +570,9 @@ mod i64_extend8_s {
     fn imm() {
         let consteval = wrap_untyped!(i64_extend8_s);
         unary_imm::<i64>(OP_NAME, 0xFF, consteval);
+        unary_imm::<i64>(OP_NAME, 0x7F, consteval);
+        unary_imm::<i64>(OP_NAME, 0x80, consteval);
+        unary_imm::<i64>(OP_NAME, 0x1234_5678_9ABC_DE80, consteval);
         unary_imm::<i64>(OP_NAME, 42, consteval);
         unary_imm::<i64>(OP_NAME, -42, consteval);
     }
@@ -479,6 +594,9 @@ mod i64_extend16_s {
     fn imm() {
         let consteval = wrap_untyped!(i64_extend16_s);
         unary_imm::<i64>(OP_NAME, 0xFFFF, consteval);
+        unary_imm::<i64>(OP_NAME, 0x7FFF, consteval);
+        unary_imm::<i64>(OP_NAME, 0x8000, consteval);
+        unary_imm::<i64>(OP_NAME, 0x1234_5678_9ABC_8000, consteval);
         unary_imm::<i64>(OP_NAME, 42, consteval);
         unary_imm::<i64>(OP_NAME, -42, consteval);
     }
@@ -500,6 +618,9 @@ mod i64_extend32_s {
     fn imm() {
         let consteval = wrap_untyped!(i64_extend32_s);
         unary_imm::<i64>(OP_NAME, 0xFFFF_FFFF, consteval);
+        unary_imm::<i64>(OP_NAME, 0x7FFF_FFFF, consteval);
+        unary_imm::<i64>(OP_NAME, 0x8000_0000, consteval);
+        unary_imm::<i64>(OP_NAME, 0x1234_5678_8000_0000, consteval);
         unary_imm::<i64>(OP_NAME, 42, consteval);
         unary_imm::<i64>(OP_NAME, -42, consteval);
     }
@@ -64,7 +64,7 @@ fn fuzz_regression_3() {
                 RegisterSpan::new(Register::from_i16(3)),
                 CompiledFunc::from_u32(0),
             ),
-            Instruction::copy_span_non_overlapping(
+            Instruction::copy_span_small(
                 RegisterSpan::new(Register::from_i16(0)),
                 RegisterSpan::new(Register::from_i16(2)),
                 3,
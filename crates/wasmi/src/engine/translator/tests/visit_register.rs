@@ -0,0 +1,63 @@
+use crate::engine::{
+    bytecode::{Instruction, Register, RegisterSpan},
+    translator::visit_register::VisitResultRegisters,
+};
+
+/// Returns all result [`Register`] of `instr` in the order they are visited.
+fn visited_results(mut instr: Instruction) -> Vec<Register> {
+    let mut visited = Vec::new();
+    instr.visit_result_registers(|reg| visited.push(*reg));
+    visited
+}
+
+/// Shifts all result [`Register`] of `instr` by `delta` and returns the updated [`Instruction`].
+fn shift_results(mut instr: Instruction, delta: i16) -> Instruction {
+    instr.visit_result_registers(|reg| *reg = Register::from_i16(reg.to_i16() + delta));
+    instr
+}
+
+#[test]
+fn copy2_visits_both_results() {
+    let results = RegisterSpan::new(Register::from_i16(3));
+    let instr = Instruction::copy2(results, Register::from_i16(0), Register::from_i16(1));
+    assert_eq!(
+        visited_results(instr),
+        [Register::from_i16(3), Register::from_i16(4)]
+    );
+    assert_eq!(
+        shift_results(instr, 10),
+        Instruction::copy2(
+            RegisterSpan::new(Register::from_i16(13)),
+            Register::from_i16(0),
+            Register::from_i16(1),
+        )
+    );
+}
+
+#[test]
+fn call_visits_result_span() {
+    let results = RegisterSpan::new(Register::from_i16(5));
+    let instr = Instruction::call_imported(results, 0);
+    assert_eq!(visited_results(instr), [Register::from_i16(5)]);
+    assert_eq!(
+        shift_results(instr, 2),
+        Instruction::call_imported(RegisterSpan::new(Register::from_i16(7)), 0),
+    );
+}
+
+#[test]
+fn single_result_is_visited() {
+    let instr = Instruction::i32_add(
+        Register::from_i16(2),
+        Register::from_i16(0),
+        Register::from_i16(1),
+    );
+    assert_eq!(visited_results(instr), [Register::from_i16(2)]);
+}
+
+#[test]
+fn no_results_are_visited() {
+    assert!(visited_results(Instruction::Return).is_empty());
+    assert!(visited_results(Instruction::return_reg(Register::from_i16(0))).is_empty());
+    assert!(visited_results(Instruction::global_set(0.into(), Register::from_i16(0))).is_empty());
+}
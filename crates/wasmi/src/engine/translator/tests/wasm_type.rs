@@ -2,23 +2,25 @@ use wasmi_core::ValueType;
 
 use crate::{
     core::{UntypedValue, F32},
-    engine::bytecode::{Const32, Instruction, Register},
+    engine::bytecode::{Const32, Instruction},
 };
+use alloc::{vec, vec::Vec};
 use core::fmt::Display;
 
 pub trait WasmType: Copy + Display + Into<UntypedValue> + From<UntypedValue> {
     const NAME: &'static str;
     const VALUE_TYPE: ValueType;
 
-    fn return_imm_instr(&self) -> Instruction;
+    /// Returns the instructions returning `self` as immediate value.
+    fn return_imm_instrs(&self) -> Vec<Instruction>;
 }
 
 impl WasmType for u32 {
     const NAME: &'static str = "i32";
     const VALUE_TYPE: ValueType = ValueType::I32;
 
-    fn return_imm_instr(&self) -> Instruction {
-        Instruction::return_imm32(*self)
+    fn return_imm_instrs(&self) -> Vec<Instruction> {
+        vec![Instruction::return_imm32(*self)]
     }
 }
 
@@ -26,8 +28,8 @@ impl WasmType for i32 {
     const NAME: &'static str = "i32";
     const VALUE_TYPE: ValueType = ValueType::I32;
 
-    fn return_imm_instr(&self) -> Instruction {
-        Instruction::return_imm32(*self)
+    fn return_imm_instrs(&self) -> Vec<Instruction> {
+        vec![Instruction::return_imm32(*self)]
     }
 }
 
@@ -35,10 +37,13 @@ impl WasmType for u64 {
     const NAME: &'static str = "i64";
     const VALUE_TYPE: ValueType = ValueType::I64;
 
-    fn return_imm_instr(&self) -> Instruction {
+    fn return_imm_instrs(&self) -> Vec<Instruction> {
         match <Const32<i64>>::try_from(*self as i64).ok() {
-            Some(value) => Instruction::return_i64imm32(value),
-            None => Instruction::return_reg(Register::from_i16(-1)),
+            Some(value) => vec![Instruction::return_i64imm32(value)],
+            None => {
+                let [lo, hi] = Instruction::const64(*self);
+                vec![Instruction::ReturnI64Imm, lo, hi]
+            }
         }
     }
 }
@@ -47,10 +52,13 @@ impl WasmType for i64 {
     const NAME: &'static str = "i64";
     const VALUE_TYPE: ValueType = ValueType::I64;
 
-    fn return_imm_instr(&self) -> Instruction {
+    fn return_imm_instrs(&self) -> Vec<Instruction> {
         match <Const32<i64>>::try_from(*self).ok() {
-            Some(value) => Instruction::return_i64imm32(value),
-            None => Instruction::return_reg(Register::from_i16(-1)),
+            Some(value) => vec![Instruction::return_i64imm32(value)],
+            None => {
+                let [lo, hi] = Instruction::const64(*self);
+                vec![Instruction::ReturnI64Imm, lo, hi]
+            }
         }
     }
 }
@@ -59,8 +67,8 @@ impl WasmType for f32 {
     const NAME: &'static str = "f32";
     const VALUE_TYPE: ValueType = ValueType::F32;
 
-    fn return_imm_instr(&self) -> Instruction {
-        Instruction::return_imm32(F32::from(*self))
+    fn return_imm_instrs(&self) -> Vec<Instruction> {
+        vec![Instruction::return_imm32(F32::from(*self))]
     }
 }
 
@@ -68,10 +76,13 @@ impl WasmType for f64 {
     const NAME: &'static str = "f64";
     const VALUE_TYPE: ValueType = ValueType::F64;
 
-    fn return_imm_instr(&self) -> Instruction {
+    fn return_imm_instrs(&self) -> Vec<Instruction> {
         match <Const32<f64>>::try_from(*self).ok() {
-            Some(value) => Instruction::return_f64imm32(value),
-            None => Instruction::return_reg(Register::from_i16(-1)),
+            Some(value) => vec![Instruction::return_f64imm32(value)],
+            None => {
+                let [lo, hi] = Instruction::const64(*self);
+                vec![Instruction::ReturnF64Imm, lo, hi]
+            }
         }
     }
 }
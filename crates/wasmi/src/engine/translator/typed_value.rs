@@ -304,6 +304,9 @@ impl TypedValue {
         fn f64_max(f64, f64) -> f64;
         fn f64_copysign(f64, f64) -> f64;
 
+        fn f32_canonicalize_nan(f32) -> f32;
+        fn f64_canonicalize_nan(f64) -> f64;
+
         // Conversions
 
         fn i32_wrap_i64(i64) -> i32;
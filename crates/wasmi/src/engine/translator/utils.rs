@@ -14,7 +14,8 @@ pub trait WasmInteger:
 {
     /// Returns the `i16` shift amount.
     ///
-    /// This computes `self % bitwsize<Self>` and returns the result as `i16` value.
+    /// This computes `self` modulo `bitwsize<Self>` and returns the result as `i16` value.
+    /// The result is always within `0..bitsize<Self>`, also for negative `self`.
     ///
     /// # Note
     ///
@@ -29,7 +30,7 @@ pub trait WasmInteger:
 
 impl WasmInteger for i32 {
     fn as_shift_amount(self) -> i16 {
-        (self % 32) as i16
+        self.rem_euclid(32) as i16
     }
 
     fn eq_zero(self) -> bool {
@@ -39,7 +40,7 @@ impl WasmInteger for i32 {
 
 impl WasmInteger for u32 {
     fn as_shift_amount(self) -> i16 {
-        (self % 32) as i16
+        self.rem_euclid(32) as i16
     }
 
     fn eq_zero(self) -> bool {
@@ -49,7 +50,7 @@ impl WasmInteger for u32 {
 
 impl WasmInteger for i64 {
     fn as_shift_amount(self) -> i16 {
-        (self % 64) as i16
+        self.rem_euclid(64) as i16
     }
 
     fn eq_zero(self) -> bool {
@@ -59,7 +60,7 @@ impl WasmInteger for i64 {
 
 impl WasmInteger for u64 {
     fn as_shift_amount(self) -> i16 {
-        (self % 64) as i16
+        self.rem_euclid(64) as i16
     }
 
     fn eq_zero(self) -> bool {
@@ -169,3 +170,73 @@ impl RegisterSpanIter {
         ))
     }
 }
+
+/// Information about a `copy results <- values` sequence.
+///
+/// # Note
+///
+/// This combines [`RegisterSpanIter::from_providers`] and
+/// [`RegisterSpanIter::overlaps_with_providers`] into a single pass
+/// over the `values` since both are required to encode the copies.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CopyInfo {
+    /// The `values` as [`RegisterSpanIter`] if they are all contiguous [`Register`]s.
+    pub span: Option<RegisterSpanIter>,
+    /// `true` if the `copy results <- values` sequence has overlapping copies.
+    pub overlapping: bool,
+}
+
+impl CopyInfo {
+    /// Creates a new [`CopyInfo`] for the `copy results <- values` sequence.
+    ///
+    /// # Note
+    ///
+    /// - `span` is `None` if `values` is empty.
+    /// - See [`RegisterSpanIter::overlaps_with_providers`] for details about overlapping copies.
+    pub fn new(results: RegisterSpanIter, values: &[TypedProvider]) -> Self {
+        debug_assert_eq!(results.len(), values.len());
+        let result0 = results.span().head();
+        let mut overlapping = false;
+        let mut contiguous = true;
+        let mut span: Option<(i16, i16)> = None;
+        for (result, value) in results.zip(values) {
+            match *value {
+                TypedProvider::Register(value) => {
+                    // If the register `value` index is within range of `result0..result`
+                    // then its value has been overwritten by previous copies.
+                    overlapping |= result0 <= value && value < result;
+                    if contiguous {
+                        let index = value.to_i16();
+                        span = match span {
+                            None => Some((index, index)),
+                            Some((first, last)) if index.checked_sub(last) == Some(1) => {
+                                Some((first, index))
+                            }
+                            Some(_) => {
+                                contiguous = false;
+                                None
+                            }
+                        };
+                    }
+                }
+                TypedProvider::Const(_) => {
+                    contiguous = false;
+                    span = None;
+                }
+            }
+            if overlapping && !contiguous {
+                // Both properties are determined so we can stop early.
+                break;
+            }
+        }
+        let span = span.and_then(|(first, last)| {
+            let end = last.checked_add(1)?;
+            Some(RegisterSpanIter::from_raw_parts(
+                Register::from_i16(first),
+                Register::from_i16(end),
+            ))
+        });
+        debug_assert_eq!(overlapping, results.overlaps_with_providers(values));
+        Self { span, overlapping }
+    }
+}
@@ -14,6 +14,7 @@ use super::{
     FuelInfo,
     FuncTranslator,
     LabelRef,
+    TranslationError,
     TypedValue,
 };
 use crate::{
@@ -23,7 +24,7 @@ use crate::{
         BlockType,
         FuelCosts,
     },
-    module::{self, FuncIdx, WasmiValueType},
+    module::{self, FuncIdx, WasmiValueType, DEFAULT_MEMORY_INDEX},
     Error,
     ExternRef,
     FuncRef,
@@ -62,6 +63,59 @@ macro_rules! impl_visit_operator {
     ( @tail_call $($rest:tt)* ) => {
         impl_visit_operator!(@@skipped $($rest)*);
     };
+    // We skip the atomic Wasm operators that we already implement manually.
+    ( @threads MemoryAtomicNotify $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads MemoryAtomicWait32 $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads MemoryAtomicWait64 $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads AtomicFence $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads I32AtomicLoad $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads I64AtomicLoad $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads I32AtomicStore $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads I64AtomicStore $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads I32AtomicRmwAdd $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads I64AtomicRmwAdd $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads I32AtomicRmw8AddU $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads I32AtomicRmw16AddU $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads I64AtomicRmw8AddU $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads I64AtomicRmw16AddU $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads I64AtomicRmw32AddU $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        // Atomic Wasm operators that are valid but not yet supported by `wasmi`.
+        fn $visit(&mut self $($(, _: $argty)*)?) -> Self::Output {
+            self.unsupported_atomic_operator(stringify!($op))
+        }
+        impl_visit_operator!($($rest)*);
+    };
     ( @@skipped $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
         // We skip Wasm operators that we already implement manually.
         impl_visit_operator!($($rest)*);
@@ -88,6 +142,18 @@ impl FuncTranslator {
     fn unsupported_operator(&self, name: &str) -> Result<(), Error> {
         panic!("tried to translate an unsupported Wasm operator: {name}")
     }
+
+    /// Called when translating a Wasm `threads` operator that `wasmi` does not yet support.
+    ///
+    /// # Note
+    ///
+    /// Unlike [`FuncTranslator::unsupported_operator`] this returns an error
+    /// since these operators pass validation if [`Config::wasm_threads`] is enabled.
+    ///
+    /// [`Config::wasm_threads`]: crate::Config::wasm_threads
+    fn unsupported_atomic_operator(&self, name: &'static str) -> Result<(), Error> {
+        Err(Error::from(TranslationError::UnsupportedOperator(name)))
+    }
 }
 
 impl<'a> VisitOperator<'a> for FuncTranslator {
@@ -178,6 +244,8 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
         //
         // Note: The fuel instruction for the loop must be encoded after the loop header is
         //       pinned so that loop iterations will properly consume fuel per iteration.
+        //       Placing it at the loop header rather than at the back-edge also allows a
+        //       `br_if` back-edge to fuse with the comparison that computes its condition.
         let consume_fuel = self.make_fuel_instr()?;
         // Finally create the loop control frame.
         self.alloc.control_stack.push_frame(LoopControlFrame::new(
@@ -675,8 +743,12 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
         _table_byte: u8,
     ) -> Self::Output {
         bail_unreachable!(self);
-        self.bump_fuel_consumption(FuelCosts::call)?;
         let type_index = SignatureIdx::from(type_index);
+        if let Some(func_idx) = self.try_fold_indirect_call(type_index, table_index) {
+            // Case: the called function is statically known so we can use a direct call.
+            return self.visit_call(func_idx.into_u32());
+        }
+        self.bump_fuel_consumption(FuelCosts::call)?;
         let func_type = self.func_type_at(type_index);
         let (params, results) = func_type.params_results();
         let index = self.alloc.stack.pop();
@@ -747,8 +819,12 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
 
     fn visit_return_call_indirect(&mut self, type_index: u32, table_index: u32) -> Self::Output {
         bail_unreachable!(self);
-        self.bump_fuel_consumption(FuelCosts::call)?;
         let type_index = SignatureIdx::from(type_index);
+        if let Some(func_idx) = self.try_fold_indirect_call(type_index, table_index) {
+            // Case: the called function is statically known so we can use a direct call.
+            return self.visit_return_call(func_idx.into_u32());
+        }
+        self.bump_fuel_consumption(FuelCosts::call)?;
         let func_type = self.func_type_at(type_index);
         let params = func_type.params();
         let index = self.alloc.stack.pop();
@@ -827,6 +903,7 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
             preserved,
             fuel_info,
         )?;
+        self.alloc.instr_encoder.notify_local_set(local);
         Ok(())
     }
 
@@ -1129,17 +1206,13 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
     }
 
     fn visit_memory_size(&mut self, mem: u32, _mem_byte: u8) -> Self::Output {
-        debug_assert_eq!(
-            mem, 0,
-            "wasmi does not yet support the multi-memory Wasm proposal"
-        );
         bail_unreachable!(self);
         let result = self.alloc.stack.push_dynamic()?;
         self.push_fueled_instr(Instruction::memory_size(result), FuelCosts::entity)?;
-        Ok(())
+        self.append_memory_param(mem)
     }
 
-    fn visit_memory_grow(&mut self, _mem: u32, _mem_byte: u8) -> Self::Output {
+    fn visit_memory_grow(&mut self, mem: u32, _mem_byte: u8) -> Self::Output {
         bail_unreachable!(self);
         let delta = self.alloc.stack.pop();
         let delta = <Provider<Const16<u32>>>::new(delta, &mut self.alloc.stack)?;
@@ -1157,7 +1230,7 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
             Provider::Const(delta) => Instruction::memory_grow_by(result, delta),
         };
         self.push_fueled_instr(instr, FuelCosts::entity)?;
-        Ok(())
+        self.append_memory_param(mem)
     }
 
     fn visit_i32_const(&mut self, value: i32) -> Self::Output {
@@ -1214,11 +1287,9 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
 
     fn visit_i32_eqz(&mut self) -> Self::Output {
         bail_unreachable!(self);
-        if self.alloc.instr_encoder.fuse_i32_eqz(&mut self.alloc.stack) {
-            // Optimization of `i32.eqz` was applied so we can bail out.
-            return Ok(());
-        }
         // Push a zero on the value stack so we can translate `i32.eqz` as `i32.eq(x, 0)`.
+        //
+        // Note: This way `i32.eqz` and `i32.eq(x, 0)` are always translated identically.
         self.alloc.stack.push_const(0_i32);
         self.visit_i32_eq()
     }
@@ -1236,7 +1307,19 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
                 }
                 Ok(false)
             },
-            Self::no_custom_opt,
+            |this, reg: Register, value: i32| {
+                if value == 0
+                    && this
+                        .alloc
+                        .instr_encoder
+                        .fuse_i32_eqz(&mut this.alloc.stack, reg)
+                {
+                    // Optimization: `x == 0` is fused with the `i32.{and,or,xor}` computing `x`.
+                    this.alloc.stack.push_register(reg)?;
+                    return Ok(true);
+                }
+                Ok(false)
+            },
         )
     }
 
@@ -2195,7 +2278,17 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
             Instruction::i32_add,
             Instruction::i32_add_imm16,
             TypedValue::i32_add,
-            Self::no_custom_opt,
+            |this, lhs: Register, rhs: Register| {
+                // Optimization: `x + i32.load` is fused into a single instruction.
+                this.alloc.instr_encoder.fuse_load_op(
+                    &mut this.alloc.stack,
+                    lhs,
+                    rhs,
+                    ValueType::I32,
+                    true,
+                    Instruction::i32_add_load,
+                )
+            },
             |this, reg: Register, value: i32| {
                 if value == 0 {
                     // Optimization: `add x + 0` is same as `x`
@@ -2219,7 +2312,15 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
                     this.alloc.stack.push_const(0_i32);
                     return Ok(true);
                 }
-                Ok(false)
+                // Optimization: `x - i32.load` is fused into a single instruction.
+                this.alloc.instr_encoder.fuse_load_op(
+                    &mut this.alloc.stack,
+                    lhs,
+                    rhs,
+                    ValueType::I32,
+                    false,
+                    Instruction::i32_sub_load,
+                )
             },
             |this, lhs: Register, rhs: i32| {
                 if rhs == 0 {
@@ -2338,7 +2439,15 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
                     this.alloc.stack.push_register(lhs)?;
                     return Ok(true);
                 }
-                Ok(false)
+                // Optimization: `x & i32.load` is fused into a single instruction.
+                this.alloc.instr_encoder.fuse_load_op(
+                    &mut this.alloc.stack,
+                    lhs,
+                    rhs,
+                    ValueType::I32,
+                    true,
+                    Instruction::i32_and_load,
+                )
             },
             |this, reg: Register, value: i32| {
                 if value == -1 {
@@ -2370,7 +2479,15 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
                     this.alloc.stack.push_register(lhs)?;
                     return Ok(true);
                 }
-                Ok(false)
+                // Optimization: `x | i32.load` is fused into a single instruction.
+                this.alloc.instr_encoder.fuse_load_op(
+                    &mut this.alloc.stack,
+                    lhs,
+                    rhs,
+                    ValueType::I32,
+                    true,
+                    Instruction::i32_or_load,
+                )
             },
             |this, reg: Register, value: i32| {
                 if value == -1 {
@@ -2402,7 +2519,15 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
                     this.alloc.stack.push_const(0_i32);
                     return Ok(true);
                 }
-                Ok(false)
+                // Optimization: `x ^ i32.load` is fused into a single instruction.
+                this.alloc.instr_encoder.fuse_load_op(
+                    &mut this.alloc.stack,
+                    lhs,
+                    rhs,
+                    ValueType::I32,
+                    true,
+                    Instruction::i32_xor_load,
+                )
             },
             |this, reg: Register, value: i32| {
                 if value == 0 {
@@ -2503,7 +2628,17 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
             Instruction::i64_add,
             Instruction::i64_add_imm16,
             TypedValue::i64_add,
-            Self::no_custom_opt,
+            |this, lhs: Register, rhs: Register| {
+                // Optimization: `x + i64.load` is fused into a single instruction.
+                this.alloc.instr_encoder.fuse_load_op(
+                    &mut this.alloc.stack,
+                    lhs,
+                    rhs,
+                    ValueType::I64,
+                    true,
+                    Instruction::i64_add_load,
+                )
+            },
             |this, reg: Register, value: i64| {
                 if value == 0 {
                     // Optimization: `add x + 0` is same as `x`
@@ -2527,7 +2662,15 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
                     this.alloc.stack.push_const(0_i64);
                     return Ok(true);
                 }
-                Ok(false)
+                // Optimization: `x - i64.load` is fused into a single instruction.
+                this.alloc.instr_encoder.fuse_load_op(
+                    &mut this.alloc.stack,
+                    lhs,
+                    rhs,
+                    ValueType::I64,
+                    false,
+                    Instruction::i64_sub_load,
+                )
             },
             |this, lhs: Register, rhs: i64| {
                 if rhs == 0 {
@@ -2646,7 +2789,15 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
                     this.alloc.stack.push_register(lhs)?;
                     return Ok(true);
                 }
-                Ok(false)
+                // Optimization: `x & i64.load` is fused into a single instruction.
+                this.alloc.instr_encoder.fuse_load_op(
+                    &mut this.alloc.stack,
+                    lhs,
+                    rhs,
+                    ValueType::I64,
+                    true,
+                    Instruction::i64_and_load,
+                )
             },
             |this, reg: Register, value: i64| {
                 if value == -1 {
@@ -2678,7 +2829,15 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
                     this.alloc.stack.push_register(lhs)?;
                     return Ok(true);
                 }
-                Ok(false)
+                // Optimization: `x | i64.load` is fused into a single instruction.
+                this.alloc.instr_encoder.fuse_load_op(
+                    &mut this.alloc.stack,
+                    lhs,
+                    rhs,
+                    ValueType::I64,
+                    true,
+                    Instruction::i64_or_load,
+                )
             },
             |this, reg: Register, value: i64| {
                 if value == -1 {
@@ -2710,7 +2869,15 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
                     this.alloc.stack.push_const(0_i64);
                     return Ok(true);
                 }
-                Ok(false)
+                // Optimization: `x ^ i64.load` is fused into a single instruction.
+                this.alloc.instr_encoder.fuse_load_op(
+                    &mut this.alloc.stack,
+                    lhs,
+                    rhs,
+                    ValueType::I64,
+                    true,
+                    Instruction::i64_xor_load,
+                )
             },
             |this, reg: Register, value: i64| {
                 if value == 0 {
@@ -2799,27 +2966,39 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
     }
 
     fn visit_f32_neg(&mut self) -> Self::Output {
-        self.translate_unary(Instruction::f32_neg, TypedValue::f32_neg)
+        self.translate_fneg(
+            Instruction::f32_neg,
+            TypedValue::f32_neg,
+            |instr| match instr {
+                Instruction::F32Neg(instr) => Some(*instr),
+                _ => None,
+            },
+        )
     }
 
     fn visit_f32_ceil(&mut self) -> Self::Output {
-        self.translate_unary(Instruction::f32_ceil, TypedValue::f32_ceil)
+        self.translate_unary(Instruction::f32_ceil, TypedValue::f32_ceil)?;
+        self.translate_f32_canonicalize_nan()
     }
 
     fn visit_f32_floor(&mut self) -> Self::Output {
-        self.translate_unary(Instruction::f32_floor, TypedValue::f32_floor)
+        self.translate_unary(Instruction::f32_floor, TypedValue::f32_floor)?;
+        self.translate_f32_canonicalize_nan()
     }
 
     fn visit_f32_trunc(&mut self) -> Self::Output {
-        self.translate_unary(Instruction::f32_trunc, TypedValue::f32_trunc)
+        self.translate_unary(Instruction::f32_trunc, TypedValue::f32_trunc)?;
+        self.translate_f32_canonicalize_nan()
     }
 
     fn visit_f32_nearest(&mut self) -> Self::Output {
-        self.translate_unary(Instruction::f32_nearest, TypedValue::f32_nearest)
+        self.translate_unary(Instruction::f32_nearest, TypedValue::f32_nearest)?;
+        self.translate_f32_canonicalize_nan()
     }
 
     fn visit_f32_sqrt(&mut self) -> Self::Output {
-        self.translate_unary(Instruction::f32_sqrt, TypedValue::f32_sqrt)
+        self.translate_unary(Instruction::f32_sqrt, TypedValue::f32_sqrt)?;
+        self.translate_f32_canonicalize_nan()
     }
 
     fn visit_f32_add(&mut self) -> Self::Output {
@@ -2828,7 +3007,8 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
             TypedValue::f32_add,
             Self::no_custom_opt,
             Self::no_custom_opt::<Register, f32>,
-        )
+        )?;
+        self.translate_f32_canonicalize_nan()
     }
 
     fn visit_f32_sub(&mut self) -> Self::Output {
@@ -2841,7 +3021,8 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
             // since the Wasm specification mandates different behavior in
             // dependence of `rhs` which we do not know at this point.
             Self::no_custom_opt,
-        )
+        )?;
+        self.translate_f32_canonicalize_nan()
     }
 
     fn visit_f32_mul(&mut self) -> Self::Output {
@@ -2853,7 +3034,8 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
             // since Wasm mandates different behaviors if `x` is infinite or
             // NaN in these cases.
             Self::no_custom_opt,
-        )
+        )?;
+        self.translate_f32_canonicalize_nan()
     }
 
     fn visit_f32_div(&mut self) -> Self::Output {
@@ -2863,7 +3045,8 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
             Self::no_custom_opt,
             Self::no_custom_opt,
             Self::no_custom_opt,
-        )
+        )?;
+        self.translate_f32_canonicalize_nan()
     }
 
     fn visit_f32_min(&mut self) -> Self::Output {
@@ -2879,7 +3062,8 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
                 }
                 Ok(false)
             },
-        )
+        )?;
+        self.translate_f32_canonicalize_nan()
     }
 
     fn visit_f32_max(&mut self) -> Self::Output {
@@ -2895,7 +3079,8 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
                 }
                 Ok(false)
             },
-        )
+        )?;
+        self.translate_f32_canonicalize_nan()
     }
 
     fn visit_f32_copysign(&mut self) -> Self::Output {
@@ -2911,27 +3096,39 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
     }
 
     fn visit_f64_neg(&mut self) -> Self::Output {
-        self.translate_unary(Instruction::f64_neg, TypedValue::f64_neg)
+        self.translate_fneg(
+            Instruction::f64_neg,
+            TypedValue::f64_neg,
+            |instr| match instr {
+                Instruction::F64Neg(instr) => Some(*instr),
+                _ => None,
+            },
+        )
     }
 
     fn visit_f64_ceil(&mut self) -> Self::Output {
-        self.translate_unary(Instruction::f64_ceil, TypedValue::f64_ceil)
+        self.translate_unary(Instruction::f64_ceil, TypedValue::f64_ceil)?;
+        self.translate_f64_canonicalize_nan()
     }
 
     fn visit_f64_floor(&mut self) -> Self::Output {
-        self.translate_unary(Instruction::f64_floor, TypedValue::f64_floor)
+        self.translate_unary(Instruction::f64_floor, TypedValue::f64_floor)?;
+        self.translate_f64_canonicalize_nan()
     }
 
     fn visit_f64_trunc(&mut self) -> Self::Output {
-        self.translate_unary(Instruction::f64_trunc, TypedValue::f64_trunc)
+        self.translate_unary(Instruction::f64_trunc, TypedValue::f64_trunc)?;
+        self.translate_f64_canonicalize_nan()
     }
 
     fn visit_f64_nearest(&mut self) -> Self::Output {
-        self.translate_unary(Instruction::f64_nearest, TypedValue::f64_nearest)
+        self.translate_unary(Instruction::f64_nearest, TypedValue::f64_nearest)?;
+        self.translate_f64_canonicalize_nan()
     }
 
     fn visit_f64_sqrt(&mut self) -> Self::Output {
-        self.translate_unary(Instruction::f64_sqrt, TypedValue::f64_sqrt)
+        self.translate_unary(Instruction::f64_sqrt, TypedValue::f64_sqrt)?;
+        self.translate_f64_canonicalize_nan()
     }
 
     fn visit_f64_add(&mut self) -> Self::Output {
@@ -2940,7 +3137,8 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
             TypedValue::f64_add,
             Self::no_custom_opt,
             Self::no_custom_opt::<Register, f64>,
-        )
+        )?;
+        self.translate_f64_canonicalize_nan()
     }
 
     fn visit_f64_sub(&mut self) -> Self::Output {
@@ -2953,7 +3151,8 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
             // since the Wasm specification mandates different behavior in
             // dependence of `rhs` which we do not know at this point.
             Self::no_custom_opt,
-        )
+        )?;
+        self.translate_f64_canonicalize_nan()
     }
 
     fn visit_f64_mul(&mut self) -> Self::Output {
@@ -2965,7 +3164,8 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
             // since Wasm mandates different behaviors if `x` is infinite or
             // NaN in these cases.
             Self::no_custom_opt,
-        )
+        )?;
+        self.translate_f64_canonicalize_nan()
     }
 
     fn visit_f64_div(&mut self) -> Self::Output {
@@ -2975,7 +3175,8 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
             Self::no_custom_opt,
             Self::no_custom_opt,
             Self::no_custom_opt,
-        )
+        )?;
+        self.translate_f64_canonicalize_nan()
     }
 
     fn visit_f64_min(&mut self) -> Self::Output {
@@ -2991,7 +3192,8 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
                 }
                 Ok(false)
             },
-        )
+        )?;
+        self.translate_f64_canonicalize_nan()
     }
 
     fn visit_f64_max(&mut self) -> Self::Output {
@@ -3007,7 +3209,8 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
                 }
                 Ok(false)
             },
-        )
+        )?;
+        self.translate_f64_canonicalize_nan()
     }
 
     fn visit_f64_copysign(&mut self) -> Self::Output {
@@ -3091,7 +3294,8 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
     }
 
     fn visit_f32_demote_f64(&mut self) -> Self::Output {
-        self.translate_unary(Instruction::f32_demote_f64, TypedValue::f32_demote_f64)
+        self.translate_unary(Instruction::f32_demote_f64, TypedValue::f32_demote_f64)?;
+        self.translate_f32_canonicalize_nan()
     }
 
     fn visit_f64_convert_i32_s(&mut self) -> Self::Output {
@@ -3123,7 +3327,8 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
     }
 
     fn visit_f64_promote_f32(&mut self) -> Self::Output {
-        self.translate_unary(Instruction::f64_promote_f32, TypedValue::f64_promote_f32)
+        self.translate_unary(Instruction::f64_promote_f32, TypedValue::f64_promote_f32)?;
+        self.translate_f64_canonicalize_nan()
     }
 
     fn visit_i32_reinterpret_f32(&mut self) -> Self::Output {
@@ -3218,7 +3423,7 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
         )
     }
 
-    fn visit_memory_init(&mut self, data_index: u32, _mem: u32) -> Self::Output {
+    fn visit_memory_init(&mut self, data_index: u32, mem: u32) -> Self::Output {
         bail_unreachable!(self);
         let (dst, src, len) = self.alloc.stack.pop3();
         let dst = <Provider<Const16<u32>>>::new(dst, &mut self.alloc.stack)?;
@@ -3254,7 +3459,7 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
         self.alloc
             .instr_encoder
             .append_instr(Instruction::data_idx(data_index))?;
-        Ok(())
+        self.append_memory_param(mem)
     }
 
     fn visit_data_drop(&mut self, data_index: u32) -> Self::Output {
@@ -3263,7 +3468,7 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
         Ok(())
     }
 
-    fn visit_memory_copy(&mut self, _dst_mem: u32, _src_mem: u32) -> Self::Output {
+    fn visit_memory_copy(&mut self, dst_mem: u32, src_mem: u32) -> Self::Output {
         bail_unreachable!(self);
         let (dst, src, len) = self.alloc.stack.pop3();
         let dst = <Provider<Const16<u32>>>::new(dst, &mut self.alloc.stack)?;
@@ -3296,10 +3501,19 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
             }
         };
         self.push_fueled_instr(instr, FuelCosts::entity)?;
+        if dst_mem != DEFAULT_MEMORY_INDEX || src_mem != DEFAULT_MEMORY_INDEX {
+            // Note: Both memory parameters are encoded if either is not the default.
+            self.alloc
+                .instr_encoder
+                .append_instr(Instruction::memory_idx(dst_mem))?;
+            self.alloc
+                .instr_encoder
+                .append_instr(Instruction::memory_idx(src_mem))?;
+        }
         Ok(())
     }
 
-    fn visit_memory_fill(&mut self, _mem: u32) -> Self::Output {
+    fn visit_memory_fill(&mut self, mem: u32) -> Self::Output {
         bail_unreachable!(self);
         let (dst, value, len) = self.alloc.stack.pop3();
         let dst = <Provider<Const16<u32>>>::new(dst, &mut self.alloc.stack)?;
@@ -3332,7 +3546,7 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
             }
         };
         self.push_fueled_instr(instr, FuelCosts::entity)?;
-        Ok(())
+        self.append_memory_param(mem)
     }
 
     fn visit_table_init(&mut self, elem_index: u32, table: u32) -> Self::Output {
@@ -3532,4 +3746,76 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
         self.push_fueled_instr(Instruction::table_size(result, table), FuelCosts::entity)?;
         Ok(())
     }
+
+    fn visit_memory_atomic_notify(&mut self, memarg: wasmparser::MemArg) -> Self::Output {
+        bail_unreachable!(self);
+        // The `count` operand is irrelevant since there are no waiters on non-shared memories.
+        self.alloc.stack.pop();
+        self.translate_load_generic(memarg, Instruction::memory_atomic_notify)
+    }
+
+    fn visit_memory_atomic_wait32(&mut self, memarg: wasmparser::MemArg) -> Self::Output {
+        bail_unreachable!(self);
+        // The `expected` and `timeout` operands are irrelevant since waiting
+        // on non-shared memories always traps.
+        self.alloc.stack.pop2();
+        self.translate_load_generic(memarg, Instruction::memory_atomic_wait32)
+    }
+
+    fn visit_memory_atomic_wait64(&mut self, memarg: wasmparser::MemArg) -> Self::Output {
+        bail_unreachable!(self);
+        // The `expected` and `timeout` operands are irrelevant since waiting
+        // on non-shared memories always traps.
+        self.alloc.stack.pop2();
+        self.translate_load_generic(memarg, Instruction::memory_atomic_wait64)
+    }
+
+    fn visit_atomic_fence(&mut self) -> Self::Output {
+        // Nothing to do since `wasmi` executes single threaded on non-shared memories.
+        Ok(())
+    }
+
+    fn visit_i32_atomic_load(&mut self, memarg: wasmparser::MemArg) -> Self::Output {
+        self.translate_load_generic(memarg, Instruction::i32_atomic_load)
+    }
+
+    fn visit_i64_atomic_load(&mut self, memarg: wasmparser::MemArg) -> Self::Output {
+        self.translate_load_generic(memarg, Instruction::i64_atomic_load)
+    }
+
+    fn visit_i32_atomic_store(&mut self, memarg: wasmparser::MemArg) -> Self::Output {
+        self.translate_store_generic(memarg, Instruction::i32_atomic_store)
+    }
+
+    fn visit_i64_atomic_store(&mut self, memarg: wasmparser::MemArg) -> Self::Output {
+        self.translate_store_generic(memarg, Instruction::i64_atomic_store)
+    }
+
+    fn visit_i32_atomic_rmw_add(&mut self, memarg: wasmparser::MemArg) -> Self::Output {
+        self.translate_atomic_rmw(memarg, Instruction::i32_atomic_rmw_add)
+    }
+
+    fn visit_i64_atomic_rmw_add(&mut self, memarg: wasmparser::MemArg) -> Self::Output {
+        self.translate_atomic_rmw(memarg, Instruction::i64_atomic_rmw_add)
+    }
+
+    fn visit_i32_atomic_rmw8_add_u(&mut self, memarg: wasmparser::MemArg) -> Self::Output {
+        self.translate_atomic_rmw(memarg, Instruction::i32_atomic_rmw8_add_u)
+    }
+
+    fn visit_i32_atomic_rmw16_add_u(&mut self, memarg: wasmparser::MemArg) -> Self::Output {
+        self.translate_atomic_rmw(memarg, Instruction::i32_atomic_rmw16_add_u)
+    }
+
+    fn visit_i64_atomic_rmw8_add_u(&mut self, memarg: wasmparser::MemArg) -> Self::Output {
+        self.translate_atomic_rmw(memarg, Instruction::i64_atomic_rmw8_add_u)
+    }
+
+    fn visit_i64_atomic_rmw16_add_u(&mut self, memarg: wasmparser::MemArg) -> Self::Output {
+        self.translate_atomic_rmw(memarg, Instruction::i64_atomic_rmw16_add_u)
+    }
+
+    fn visit_i64_atomic_rmw32_add_u(&mut self, memarg: wasmparser::MemArg) -> Self::Output {
+        self.translate_atomic_rmw(memarg, Instruction::i64_atomic_rmw32_add_u)
+    }
 }
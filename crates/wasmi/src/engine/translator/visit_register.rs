@@ -8,6 +8,7 @@ use crate::engine::bytecode::{
     LoadAtInstr,
     LoadInstr,
     LoadOffset16Instr,
+    LoadOpInstr,
     Register,
     RegisterSpan,
     RegisterSpanIter,
@@ -36,6 +37,7 @@ impl VisitInputRegisters for Instruction {
     fn visit_input_registers(&mut self, mut f: impl FnMut(&mut Register)) {
         match self {
             Instruction::TableIdx(_) |
+            Instruction::MemoryIdx(_) |
             Instruction::DataSegmentIdx(_) |
             Instruction::ElementSegmentIdx(_) |
             Instruction::Const32(_) |
@@ -45,6 +47,7 @@ impl VisitInputRegisters for Instruction {
             Instruction::Register2(registers) => registers.visit_input_registers(f),
             Instruction::Register3(registers) |
             Instruction::RegisterList(registers) => registers.visit_input_registers(f),
+            Instruction::RegisterSpanList { values, len: _ } => values.visit_input_registers(f),
             Instruction::Trap(_) |
             Instruction::ConsumeFuel(_) |
             Instruction::Return => {},
@@ -53,7 +56,9 @@ impl VisitInputRegisters for Instruction {
             Instruction::ReturnReg3 { values } => values.visit_input_registers(f),
             Instruction::ReturnImm32 { .. } |
             Instruction::ReturnI64Imm32 { .. } |
-            Instruction::ReturnF64Imm32 { .. } => {},
+            Instruction::ReturnF64Imm32 { .. } |
+            Instruction::ReturnI64Imm |
+            Instruction::ReturnF64Imm => {},
             Instruction::ReturnSpan { values } => {
                 values.visit_input_registers(f);
             }
@@ -161,13 +166,18 @@ impl VisitInputRegisters for Instruction {
             }
             Instruction::CopyImm32 { result: _, value: _ } |
             Instruction::CopyI64Imm32 { result: _, value: _ } |
-            Instruction::CopyF64Imm32 { result: _, value: _ } => {},
+            Instruction::CopyF64Imm32 { result: _, value: _ } |
+            Instruction::CopyI64Imm { result: _ } |
+            Instruction::CopyF64Imm { result: _ } => {},
             Instruction::CopySpan { results: _, values, len: _ } => {
                 values.visit_input_registers(f);
             }
             Instruction::CopySpanNonOverlapping { results: _, values, len: _ } => {
                 values.visit_input_registers(f);
             }
+            Instruction::CopySpanSmall { results: _, values, len: _ } => {
+                values.visit_input_registers(f);
+            }
             Instruction::CopyMany { results: _, values } => {
                 values.visit_input_registers(f);
             }
@@ -190,9 +200,22 @@ impl VisitInputRegisters for Instruction {
             Instruction::CallIndirect { .. } => {},
             Instruction::Select { condition, lhs, .. } => visit_registers!(f, condition, lhs),
             Instruction::SelectRev { condition, rhs, .. } => visit_registers!(f, condition, rhs),
+            Instruction::SelectImm { condition, .. } => f(condition),
             Instruction::SelectImm32 { result_or_condition, .. } |
             Instruction::SelectI64Imm32 { result_or_condition, .. } |
             Instruction::SelectF64Imm32 { result_or_condition, .. } => f(result_or_condition),
+            Instruction::SelectI32Eq(instr) => instr.visit_input_registers(f),
+            Instruction::SelectI32Ne(instr) => instr.visit_input_registers(f),
+            Instruction::SelectI32LtS(instr) => instr.visit_input_registers(f),
+            Instruction::SelectI32LtU(instr) => instr.visit_input_registers(f),
+            Instruction::SelectI32LeS(instr) => instr.visit_input_registers(f),
+            Instruction::SelectI32LeU(instr) => instr.visit_input_registers(f),
+            Instruction::SelectI64Eq(instr) => instr.visit_input_registers(f),
+            Instruction::SelectI64Ne(instr) => instr.visit_input_registers(f),
+            Instruction::SelectI64LtS(instr) => instr.visit_input_registers(f),
+            Instruction::SelectI64LtU(instr) => instr.visit_input_registers(f),
+            Instruction::SelectI64LeS(instr) => instr.visit_input_registers(f),
+            Instruction::SelectI64LeU(instr) => instr.visit_input_registers(f),
             Instruction::RefFunc { .. } |
             Instruction::TableGet { .. } |
             Instruction::TableGetImm { .. } |
@@ -296,6 +319,16 @@ impl VisitInputRegisters for Instruction {
             Instruction::I64Load32u(instr) => instr.visit_input_registers(f),
             Instruction::I64Load32uAt(instr) => instr.visit_input_registers(f),
             Instruction::I64Load32uOffset16(instr) => instr.visit_input_registers(f),
+            Instruction::I32AddLoad(instr) => instr.visit_input_registers(f),
+            Instruction::I32SubLoad(instr) => instr.visit_input_registers(f),
+            Instruction::I32AndLoad(instr) => instr.visit_input_registers(f),
+            Instruction::I32OrLoad(instr) => instr.visit_input_registers(f),
+            Instruction::I32XorLoad(instr) => instr.visit_input_registers(f),
+            Instruction::I64AddLoad(instr) => instr.visit_input_registers(f),
+            Instruction::I64SubLoad(instr) => instr.visit_input_registers(f),
+            Instruction::I64AndLoad(instr) => instr.visit_input_registers(f),
+            Instruction::I64OrLoad(instr) => instr.visit_input_registers(f),
+            Instruction::I64XorLoad(instr) => instr.visit_input_registers(f),
             Instruction::I32Store(instr) => instr.visit_input_registers(f),
             Instruction::I32StoreOffset16(instr) => instr.visit_input_registers(f),
             Instruction::I32StoreOffset16Imm16(instr) => instr.visit_input_registers(f),
@@ -337,6 +370,20 @@ impl VisitInputRegisters for Instruction {
             Instruction::F64Store(instr) => instr.visit_input_registers(f),
             Instruction::F64StoreOffset16(instr) => instr.visit_input_registers(f),
             Instruction::F64StoreAt(instr) => instr.visit_input_registers(f),
+            Instruction::I32AtomicLoad(instr) => instr.visit_input_registers(f),
+            Instruction::I64AtomicLoad(instr) => instr.visit_input_registers(f),
+            Instruction::I32AtomicStore(instr) => instr.visit_input_registers(f),
+            Instruction::I64AtomicStore(instr) => instr.visit_input_registers(f),
+            Instruction::I32AtomicRmwAdd(instr) => instr.visit_input_registers(f),
+            Instruction::I64AtomicRmwAdd(instr) => instr.visit_input_registers(f),
+            Instruction::I32AtomicRmw8AddU(instr) => instr.visit_input_registers(f),
+            Instruction::I32AtomicRmw16AddU(instr) => instr.visit_input_registers(f),
+            Instruction::I64AtomicRmw8AddU(instr) => instr.visit_input_registers(f),
+            Instruction::I64AtomicRmw16AddU(instr) => instr.visit_input_registers(f),
+            Instruction::I64AtomicRmw32AddU(instr) => instr.visit_input_registers(f),
+            Instruction::MemoryAtomicNotify(instr) => instr.visit_input_registers(f),
+            Instruction::MemoryAtomicWait32(instr) => instr.visit_input_registers(f),
+            Instruction::MemoryAtomicWait64(instr) => instr.visit_input_registers(f),
             Instruction::I32Eq(instr) => instr.visit_input_registers(f),
             Instruction::I32EqImm16(instr) => instr.visit_input_registers(f),
             Instruction::I64Eq(instr) => instr.visit_input_registers(f),
@@ -495,6 +542,8 @@ impl VisitInputRegisters for Instruction {
             Instruction::F64Nearest(instr) => instr.visit_input_registers(f),
             Instruction::F32Sqrt(instr) => instr.visit_input_registers(f),
             Instruction::F64Sqrt(instr) => instr.visit_input_registers(f),
+            Instruction::F32CanonicalizeNan(instr) => instr.visit_input_registers(f),
+            Instruction::F64CanonicalizeNan(instr) => instr.visit_input_registers(f),
             Instruction::F32Add(instr) => instr.visit_input_registers(f),
             Instruction::F64Add(instr) => instr.visit_input_registers(f),
             Instruction::F32Sub(instr) => instr.visit_input_registers(f),
@@ -575,6 +624,12 @@ impl LoadOffset16Instr {
     }
 }
 
+impl LoadOpInstr {
+    fn visit_input_registers(&mut self, mut f: impl FnMut(&mut Register)) {
+        visit_registers!(f, &mut self.operand, &mut self.ptr);
+    }
+}
+
 impl BranchBinOpInstr {
     fn visit_input_registers(&mut self, mut f: impl FnMut(&mut Register)) {
         visit_registers!(f, &mut self.lhs, &mut self.rhs);
@@ -685,3 +740,589 @@ impl VisitInputRegisters for RegisterSpanIter {
         *self = span.iter_u16(len);
     }
 }
+
+/// Trait implemented by types that allow to visit their result [`Register`] fields.
+#[allow(unused)] // Note: We allow this unused API to exist to uphold code symmetry.
+pub trait VisitResultRegisters {
+    /// Calls `f` on all result [`Register`].
+    ///
+    /// # Note
+    ///
+    /// For result [`RegisterSpan`] of known length every [`Register`] of the span
+    /// is visited in order after which the span is rebuilt from its visited head.
+    /// Spans of unknown length, e.g. the results of calls, only visit their head.
+    fn visit_result_registers(&mut self, f: impl FnMut(&mut Register));
+}
+
+impl VisitResultRegisters for Instruction {
+    #[rustfmt::skip]
+    fn visit_result_registers(&mut self, mut f: impl FnMut(&mut Register)) {
+        use Instruction as I;
+        match self {
+            I::TableIdx(_)
+            | I::MemoryIdx(_)
+            | I::DataSegmentIdx(_)
+            | I::ElementSegmentIdx(_)
+            | I::Const32(_)
+            | I::I64Const32(_)
+            | I::F64Const32(_)
+            | I::Register(_)
+            | I::Register2(_)
+            | I::Register3(_)
+            | I::RegisterList(_)
+            | I::RegisterSpanList { .. }
+            | I::CallIndirectParams(_)
+            | I::CallIndirectParamsImm16(_)
+            | I::Trap(_)
+            | I::ConsumeFuel(_)
+            | I::Return
+            | I::ReturnReg { .. }
+            | I::ReturnReg2 { .. }
+            | I::ReturnReg3 { .. }
+            | I::ReturnImm32 { .. }
+            | I::ReturnI64Imm32 { .. }
+            | I::ReturnF64Imm32 { .. }
+            | I::ReturnI64Imm
+            | I::ReturnF64Imm
+            | I::ReturnSpan { .. }
+            | I::ReturnMany { .. }
+            | I::ReturnNez { .. }
+            | I::ReturnNezReg { .. }
+            | I::ReturnNezReg2 { .. }
+            | I::ReturnNezImm32 { .. }
+            | I::ReturnNezI64Imm32 { .. }
+            | I::ReturnNezF64Imm32 { .. }
+            | I::ReturnNezSpan { .. }
+            | I::ReturnNezMany { .. }
+            | I::Branch { .. }
+            | I::BranchI32And(_)
+            | I::BranchI32AndImm(_)
+            | I::BranchI32Or(_)
+            | I::BranchI32OrImm(_)
+            | I::BranchI32Xor(_)
+            | I::BranchI32XorImm(_)
+            | I::BranchI32AndEqz(_)
+            | I::BranchI32AndEqzImm(_)
+            | I::BranchI32OrEqz(_)
+            | I::BranchI32OrEqzImm(_)
+            | I::BranchI32XorEqz(_)
+            | I::BranchI32XorEqzImm(_)
+            | I::BranchTable { .. }
+            | I::BranchI32Eq(_)
+            | I::BranchI32EqImm(_)
+            | I::BranchI32Ne(_)
+            | I::BranchI32NeImm(_)
+            | I::BranchI32LtS(_)
+            | I::BranchI32LtSImm(_)
+            | I::BranchI32LtU(_)
+            | I::BranchI32LtUImm(_)
+            | I::BranchI32LeS(_)
+            | I::BranchI32LeSImm(_)
+            | I::BranchI32LeU(_)
+            | I::BranchI32LeUImm(_)
+            | I::BranchI32GtS(_)
+            | I::BranchI32GtSImm(_)
+            | I::BranchI32GtU(_)
+            | I::BranchI32GtUImm(_)
+            | I::BranchI32GeS(_)
+            | I::BranchI32GeSImm(_)
+            | I::BranchI32GeU(_)
+            | I::BranchI32GeUImm(_)
+            | I::BranchI64Eq(_)
+            | I::BranchI64EqImm(_)
+            | I::BranchI64Ne(_)
+            | I::BranchI64NeImm(_)
+            | I::BranchI64LtS(_)
+            | I::BranchI64LtSImm(_)
+            | I::BranchI64LtU(_)
+            | I::BranchI64LtUImm(_)
+            | I::BranchI64LeS(_)
+            | I::BranchI64LeSImm(_)
+            | I::BranchI64LeU(_)
+            | I::BranchI64LeUImm(_)
+            | I::BranchI64GtS(_)
+            | I::BranchI64GtSImm(_)
+            | I::BranchI64GtU(_)
+            | I::BranchI64GtUImm(_)
+            | I::BranchI64GeS(_)
+            | I::BranchI64GeSImm(_)
+            | I::BranchI64GeU(_)
+            | I::BranchI64GeUImm(_)
+            | I::BranchF32Eq(_)
+            | I::BranchF32Ne(_)
+            | I::BranchF32Lt(_)
+            | I::BranchF32Le(_)
+            | I::BranchF32Gt(_)
+            | I::BranchF32Ge(_)
+            | I::BranchF64Eq(_)
+            | I::BranchF64Ne(_)
+            | I::BranchF64Lt(_)
+            | I::BranchF64Le(_)
+            | I::BranchF64Gt(_)
+            | I::BranchF64Ge(_) => {},
+            I::Copy { result, .. }
+            | I::CopyImm32 { result, .. }
+            | I::CopyI64Imm32 { result, .. }
+            | I::CopyF64Imm32 { result, .. }
+            | I::CopyI64Imm { result }
+            | I::CopyF64Imm { result } => f(result),
+            I::CopySpan { results, len, .. }
+            | I::CopySpanNonOverlapping { results, len, .. }
+            | I::CopySpanSmall { results, len, .. } => visit_result_span(results, *len, f),
+            I::Copy2 { results, .. } => visit_result_span(results, 2, f),
+            I::CopyMany { results, .. }
+            | I::CopyManyNonOverlapping { results, .. } => {
+                // Note: the number of results is encoded by the trailing register list
+                //       instruction words, therefore we can only visit the head of the span.
+                f(results.head_mut())
+            }
+            I::ReturnCallInternal0 { .. }
+            | I::ReturnCallInternal { .. }
+            | I::ReturnCallImported0 { .. }
+            | I::ReturnCallImported { .. }
+            | I::ReturnCallIndirect0 { .. }
+            | I::ReturnCallIndirect { .. } => {},
+            I::CallInternal0 { results, .. }
+            | I::CallInternal { results, .. }
+            | I::CallImported0 { results, .. }
+            | I::CallImported { results, .. }
+            | I::CallIndirect0 { results, .. }
+            | I::CallIndirect { results, .. } => {
+                // Note: the number of results depends on the function type of the callee
+                //       which is not encoded in the instruction, therefore we can only
+                //       visit the head of the span.
+                f(results.head_mut())
+            }
+            I::Select { result, .. }
+            | I::SelectRev { result, .. }
+            | I::SelectImm { result, .. }
+            | I::SelectImm32 {
+                result_or_condition: result,
+                ..
+            }
+            | I::SelectI64Imm32 {
+                result_or_condition: result,
+                ..
+            }
+            | I::SelectF64Imm32 {
+                result_or_condition: result,
+                ..
+            } => {
+                // Note: the `result_or_condition` field only refers to the `result`
+                //       register in the first instruction word of the encoded `select`.
+                f(result)
+            }
+            I::SelectI32Eq(instr)
+            | I::SelectI32Ne(instr)
+            | I::SelectI32LtS(instr)
+            | I::SelectI32LtU(instr)
+            | I::SelectI32LeS(instr)
+            | I::SelectI32LeU(instr)
+            | I::SelectI64Eq(instr)
+            | I::SelectI64Ne(instr)
+            | I::SelectI64LtS(instr)
+            | I::SelectI64LtU(instr)
+            | I::SelectI64LeS(instr)
+            | I::SelectI64LeU(instr) => {
+                f(&mut instr.result)
+            }
+            I::RefFunc { result, .. }
+            | I::TableGet { result, .. }
+            | I::TableGetImm { result, .. }
+            | I::TableSize { result, .. } => f(result),
+            I::TableSet { .. }
+            | I::TableSetAt { .. }
+            | I::TableCopy { .. }
+            | I::TableCopyTo { .. }
+            | I::TableCopyFrom { .. }
+            | I::TableCopyFromTo { .. }
+            | I::TableCopyExact { .. }
+            | I::TableCopyToExact { .. }
+            | I::TableCopyFromExact { .. }
+            | I::TableCopyFromToExact { .. }
+            | I::TableInit { .. }
+            | I::TableInitTo { .. }
+            | I::TableInitFrom { .. }
+            | I::TableInitFromTo { .. }
+            | I::TableInitExact { .. }
+            | I::TableInitToExact { .. }
+            | I::TableInitFromExact { .. }
+            | I::TableInitFromToExact { .. }
+            | I::TableFill { .. }
+            | I::TableFillAt { .. }
+            | I::TableFillExact { .. }
+            | I::TableFillAtExact { .. } => {},
+            I::TableGrow { result, .. } | I::TableGrowImm { result, .. } => {
+                f(result)
+            }
+            I::ElemDrop(_) | I::DataDrop(_) => {},
+            I::MemorySize { result }
+            | I::MemoryGrow { result, .. }
+            | I::MemoryGrowBy { result, .. } => f(result),
+            I::MemoryCopy { .. }
+            | I::MemoryCopyTo { .. }
+            | I::MemoryCopyFrom { .. }
+            | I::MemoryCopyFromTo { .. }
+            | I::MemoryCopyExact { .. }
+            | I::MemoryCopyToExact { .. }
+            | I::MemoryCopyFromExact { .. }
+            | I::MemoryCopyFromToExact { .. }
+            | I::MemoryFill { .. }
+            | I::MemoryFillAt { .. }
+            | I::MemoryFillImm { .. }
+            | I::MemoryFillExact { .. }
+            | I::MemoryFillAtImm { .. }
+            | I::MemoryFillAtExact { .. }
+            | I::MemoryFillImmExact { .. }
+            | I::MemoryFillAtImmExact { .. }
+            | I::MemoryInit { .. }
+            | I::MemoryInitTo { .. }
+            | I::MemoryInitFrom { .. }
+            | I::MemoryInitFromTo { .. }
+            | I::MemoryInitExact { .. }
+            | I::MemoryInitToExact { .. }
+            | I::MemoryInitFromExact { .. }
+            | I::MemoryInitFromToExact { .. } => {},
+            I::GlobalGet { result, .. } => f(result),
+            I::GlobalSet { .. } | I::GlobalSetI32Imm16 { .. } | I::GlobalSetI64Imm16 { .. } => {}
+            I::I32Load(instr) |
+            I::I64Load(instr) |
+            I::F32Load(instr) |
+            I::F64Load(instr) |
+            I::I32Load8s(instr) |
+            I::I32Load8u(instr) |
+            I::I32Load16s(instr) |
+            I::I32Load16u(instr) |
+            I::I64Load8s(instr) |
+            I::I64Load8u(instr) |
+            I::I64Load16s(instr) |
+            I::I64Load16u(instr) |
+            I::I64Load32s(instr) |
+            I::I64Load32u(instr) => f(&mut instr.result),
+            I::I32LoadAt(instr) |
+            I::I64LoadAt(instr) |
+            I::F32LoadAt(instr) |
+            I::F64LoadAt(instr) |
+            I::I32Load8sAt(instr) |
+            I::I32Load8uAt(instr) |
+            I::I32Load16sAt(instr) |
+            I::I32Load16uAt(instr) |
+            I::I64Load8sAt(instr) |
+            I::I64Load8uAt(instr) |
+            I::I64Load16sAt(instr) |
+            I::I64Load16uAt(instr) |
+            I::I64Load32sAt(instr) |
+            I::I64Load32uAt(instr) => f(&mut instr.result),
+            I::I32LoadOffset16(instr) |
+            I::I64LoadOffset16(instr) |
+            I::F32LoadOffset16(instr) |
+            I::F64LoadOffset16(instr) |
+            I::I32Load8sOffset16(instr) |
+            I::I32Load8uOffset16(instr) |
+            I::I32Load16sOffset16(instr) |
+            I::I32Load16uOffset16(instr) |
+            I::I64Load8sOffset16(instr) |
+            I::I64Load8uOffset16(instr) |
+            I::I64Load16sOffset16(instr) |
+            I::I64Load16uOffset16(instr) |
+            I::I64Load32sOffset16(instr) |
+            I::I64Load32uOffset16(instr) => f(&mut instr.result),
+            I::I32AddLoad(instr) |
+            I::I32SubLoad(instr) |
+            I::I32AndLoad(instr) |
+            I::I32OrLoad(instr) |
+            I::I32XorLoad(instr) |
+            I::I64AddLoad(instr) |
+            I::I64SubLoad(instr) |
+            I::I64AndLoad(instr) |
+            I::I64OrLoad(instr) |
+            I::I64XorLoad(instr) => f(&mut instr.result),
+            I::I32Store(_)
+            | I::I32StoreOffset16(_)
+            | I::I32StoreOffset16Imm16(_)
+            | I::I32StoreAt(_)
+            | I::I32StoreAtImm16(_)
+            | I::I32Store8(_)
+            | I::I32Store8Offset16(_)
+            | I::I32Store8Offset16Imm(_)
+            | I::I32Store8At(_)
+            | I::I32Store8AtImm(_)
+            | I::I32Store16(_)
+            | I::I32Store16Offset16(_)
+            | I::I32Store16Offset16Imm(_)
+            | I::I32Store16At(_)
+            | I::I32Store16AtImm(_)
+            | I::I64Store(_)
+            | I::I64StoreOffset16(_)
+            | I::I64StoreOffset16Imm16(_)
+            | I::I64StoreAt(_)
+            | I::I64StoreAtImm16(_)
+            | I::I64Store8(_)
+            | I::I64Store8Offset16(_)
+            | I::I64Store8Offset16Imm(_)
+            | I::I64Store8At(_)
+            | I::I64Store8AtImm(_)
+            | I::I64Store16(_)
+            | I::I64Store16Offset16(_)
+            | I::I64Store16Offset16Imm(_)
+            | I::I64Store16At(_)
+            | I::I64Store16AtImm(_)
+            | I::I64Store32(_)
+            | I::I64Store32Offset16(_)
+            | I::I64Store32Offset16Imm16(_)
+            | I::I64Store32At(_)
+            | I::I64Store32AtImm16(_)
+            | I::F32Store(_)
+            | I::F32StoreOffset16(_)
+            | I::F32StoreAt(_)
+            | I::F64Store(_)
+            | I::F64StoreOffset16(_)
+            | I::F64StoreAt(_) => {},
+            I::I32AtomicLoad(instr) |
+            I::I64AtomicLoad(instr) |
+            I::I32AtomicRmwAdd(instr) |
+            I::I64AtomicRmwAdd(instr) |
+            I::I32AtomicRmw8AddU(instr) |
+            I::I32AtomicRmw16AddU(instr) |
+            I::I64AtomicRmw8AddU(instr) |
+            I::I64AtomicRmw16AddU(instr) |
+            I::I64AtomicRmw32AddU(instr) |
+            I::MemoryAtomicNotify(instr) |
+            I::MemoryAtomicWait32(instr) |
+            I::MemoryAtomicWait64(instr) => f(&mut instr.result),
+            I::I32AtomicStore(_) | I::I64AtomicStore(_) => {},
+            I::I32Eq(instr) |
+            I::I64Eq(instr) |
+            I::I32Ne(instr) |
+            I::I64Ne(instr) |
+            I::I32LtS(instr) |
+            I::I64LtS(instr) |
+            I::I32LtU(instr) |
+            I::I64LtU(instr) |
+            I::I32LeS(instr) |
+            I::I64LeS(instr) |
+            I::I32LeU(instr) |
+            I::I64LeU(instr) |
+            I::I32GtS(instr) |
+            I::I64GtS(instr) |
+            I::I32GtU(instr) |
+            I::I64GtU(instr) |
+            I::I32GeS(instr) |
+            I::I64GeS(instr) |
+            I::I32GeU(instr) |
+            I::I64GeU(instr) |
+            I::F32Eq(instr) |
+            I::F32Ne(instr) |
+            I::F32Lt(instr) |
+            I::F32Le(instr) |
+            I::F32Gt(instr) |
+            I::F32Ge(instr) |
+            I::F64Eq(instr) |
+            I::F64Ne(instr) |
+            I::F64Lt(instr) |
+            I::F64Le(instr) |
+            I::F64Gt(instr) |
+            I::F64Ge(instr) => f(&mut instr.result),
+            I::I32EqImm16(instr) |
+            I::I32NeImm16(instr) |
+            I::I32LtSImm16(instr) |
+            I::I32LeSImm16(instr) |
+            I::I32GtSImm16(instr) |
+            I::I32GeSImm16(instr) => f(&mut instr.result),
+            I::I32LtUImm16(instr) |
+            I::I32LeUImm16(instr) |
+            I::I32GtUImm16(instr) |
+            I::I32GeUImm16(instr) => f(&mut instr.result),
+            I::I64EqImm16(instr) |
+            I::I64NeImm16(instr) |
+            I::I64LtSImm16(instr) |
+            I::I64LeSImm16(instr) |
+            I::I64GtSImm16(instr) |
+            I::I64GeSImm16(instr) => f(&mut instr.result),
+            I::I64LtUImm16(instr) |
+            I::I64LeUImm16(instr) |
+            I::I64GtUImm16(instr) |
+            I::I64GeUImm16(instr) => f(&mut instr.result),
+
+            I::I32Clz(instr) |
+            I::I32Ctz(instr) |
+            I::I32Popcnt(instr) |
+            I::I64Clz(instr) |
+            I::I64Ctz(instr) |
+            I::I64Popcnt(instr) => f(&mut instr.result),
+
+            I::I32Add(instr) |
+            I::I32Sub(instr) |
+            I::I32Mul(instr) |
+            I::I32DivS(instr) |
+            I::I32DivU(instr) |
+            I::I32RemS(instr) |
+            I::I32RemU(instr) |
+            I::I32And(instr) |
+            I::I32AndEqz(instr) |
+            I::I32Or(instr) |
+            I::I32OrEqz(instr) |
+            I::I32Xor(instr) |
+            I::I32XorEqz(instr) |
+            I::I32Shl(instr) |
+            I::I32ShrS(instr) |
+            I::I32ShrU(instr) |
+            I::I32Rotl(instr) |
+            I::I32Rotr(instr) |
+            I::I64Add(instr) |
+            I::I64Sub(instr) |
+            I::I64Mul(instr) |
+            I::I64DivS(instr) |
+            I::I64DivU(instr) |
+            I::I64RemS(instr) |
+            I::I64RemU(instr) |
+            I::I64And(instr) |
+            I::I64Or(instr) |
+            I::I64Xor(instr) |
+            I::I64Shl(instr) |
+            I::I64ShrS(instr) |
+            I::I64ShrU(instr) |
+            I::I64Rotl(instr) |
+            I::I64Rotr(instr) => f(&mut instr.result),
+
+            I::F32Abs(instr) |
+            I::F32Neg(instr) |
+            I::F32Ceil(instr) |
+            I::F32Floor(instr) |
+            I::F32Trunc(instr) |
+            I::F32Nearest(instr) |
+            I::F32Sqrt(instr) |
+            I::F64Abs(instr) |
+            I::F64Neg(instr) |
+            I::F64Ceil(instr) |
+            I::F64Floor(instr) |
+            I::F64Trunc(instr) |
+            I::F64Nearest(instr) |
+            I::F64Sqrt(instr) => f(&mut instr.result),
+
+            I::F32CanonicalizeNan(instr) |
+            I::F64CanonicalizeNan(instr) => f(&mut instr.result),
+
+            I::F32Add(instr) |
+            I::F32Sub(instr) |
+            I::F32Mul(instr) |
+            I::F32Div(instr) |
+            I::F32Min(instr) |
+            I::F32Max(instr) |
+            I::F32Copysign(instr) => f(&mut instr.result),
+            I::F64Add(instr) |
+            I::F64Sub(instr) |
+            I::F64Mul(instr) |
+            I::F64Div(instr) |
+            I::F64Min(instr) |
+            I::F64Max(instr) |
+            I::F64Copysign(instr) => f(&mut instr.result),
+
+            I::F32CopysignImm(instr) |
+            I::F64CopysignImm(instr) => f(&mut instr.result),
+
+            I::I32AddImm16(instr) |
+            I::I32SubImm16(instr) |
+            I::I32SubImm16Rev(instr) |
+            I::I32MulImm16(instr) => f(&mut instr.result),
+            I::I32DivSImm16(instr) => f(&mut instr.result),
+            I::I32DivSImm16Rev(instr) => f(&mut instr.result),
+            I::I32RemSImm16(instr) => f(&mut instr.result),
+            I::I32RemSImm16Rev(instr) |
+            I::I32AndEqzImm16(instr) |
+            I::I32AndImm16(instr) |
+            I::I32OrEqzImm16(instr) |
+            I::I32OrImm16(instr) |
+            I::I32XorEqzImm16(instr) |
+            I::I32XorImm16(instr) |
+            I::I32ShlImm(instr) |
+            I::I32ShlImm16Rev(instr) |
+            I::I32ShrSImm(instr) |
+            I::I32ShrSImm16Rev(instr) |
+            I::I32ShrUImm(instr) |
+            I::I32ShrUImm16Rev(instr) |
+            I::I32RotlImm(instr) |
+            I::I32RotlImm16Rev(instr) |
+            I::I32RotrImm(instr) |
+            I::I32RotrImm16Rev(instr) => f(&mut instr.result),
+            I::I32DivUImm16(instr) => f(&mut instr.result),
+            I::I32DivUImm16Rev(instr) => f(&mut instr.result),
+            I::I32RemUImm16(instr) => f(&mut instr.result),
+            I::I32RemUImm16Rev(instr) => f(&mut instr.result),
+
+            I::I64AddImm16(instr) |
+            I::I64SubImm16(instr) |
+            I::I64SubImm16Rev(instr) |
+            I::I64MulImm16(instr) => f(&mut instr.result),
+            I::I64DivSImm16(instr) => f(&mut instr.result),
+            I::I64DivSImm16Rev(instr) => f(&mut instr.result),
+            I::I64RemSImm16(instr) => f(&mut instr.result),
+            I::I64RemSImm16Rev(instr) |
+            I::I64AndImm16(instr) |
+            I::I64OrImm16(instr) |
+            I::I64XorImm16(instr) |
+            I::I64ShlImm(instr) |
+            I::I64ShlImm16Rev(instr) |
+            I::I64ShrSImm(instr) |
+            I::I64ShrSImm16Rev(instr) |
+            I::I64ShrUImm(instr) |
+            I::I64ShrUImm16Rev(instr) |
+            I::I64RotlImm(instr) |
+            I::I64RotlImm16Rev(instr) |
+            I::I64RotrImm(instr) |
+            I::I64RotrImm16Rev(instr) => f(&mut instr.result),
+            I::I64DivUImm16(instr) => f(&mut instr.result),
+            I::I64DivUImm16Rev(instr) => f(&mut instr.result),
+            I::I64RemUImm16(instr) => f(&mut instr.result),
+            I::I64RemUImm16Rev(instr) => f(&mut instr.result),
+
+            I::I32WrapI64(instr) |
+            I::I64ExtendI32S(instr) |
+            I::I64ExtendI32U(instr) |
+            I::I32TruncF32S(instr) |
+            I::I32TruncF32U(instr) |
+            I::I32TruncF64S(instr) |
+            I::I32TruncF64U(instr) |
+            I::I64TruncF32S(instr) |
+            I::I64TruncF32U(instr) |
+            I::I64TruncF64S(instr) |
+            I::I64TruncF64U(instr) |
+            I::I32TruncSatF32S(instr) |
+            I::I32TruncSatF32U(instr) |
+            I::I32TruncSatF64S(instr) |
+            I::I32TruncSatF64U(instr) |
+            I::I64TruncSatF32S(instr) |
+            I::I64TruncSatF32U(instr) |
+            I::I64TruncSatF64S(instr) |
+            I::I64TruncSatF64U(instr) |
+            I::I32Extend8S(instr) |
+            I::I32Extend16S(instr) |
+            I::I64Extend8S(instr) |
+            I::I64Extend16S(instr) |
+            I::I64Extend32S(instr) |
+            I::F32DemoteF64(instr) |
+            I::F64PromoteF32(instr) |
+            I::F32ConvertI32S(instr) |
+            I::F32ConvertI32U(instr) |
+            I::F32ConvertI64S(instr) |
+            I::F32ConvertI64U(instr) |
+            I::F64ConvertI32S(instr) |
+            I::F64ConvertI32U(instr) |
+            I::F64ConvertI64S(instr) |
+            I::F64ConvertI64U(instr) => f(&mut instr.result),
+        }
+    }
+}
+
+/// Visits the `len` result [`Register`] of the `results` [`RegisterSpan`].
+#[allow(unused)]
+fn visit_result_span(results: &mut RegisterSpan, len: u16, mut f: impl FnMut(&mut Register)) {
+    let mut head = None;
+    for mut register in results.iter_u16(len) {
+        f(&mut register);
+        head.get_or_insert(register);
+    }
+    if let Some(head) = head {
+        *results = RegisterSpan::new(head);
+    }
+}
@@ -2,6 +2,7 @@ use super::errors::{
     FuelError,
     FuncError,
     GlobalError,
+    InstanceError,
     InstantiationError,
     LinkerError,
     MemoryError,
@@ -9,18 +10,29 @@ use super::errors::{
 };
 use crate::{
     core::{HostError, TrapCode},
-    engine::TranslationError,
+    engine::{bytecode::DecodeError, TranslationError},
     module::ReadError,
+    Value,
 };
 use alloc::{boxed::Box, string::String};
 use core::{fmt, fmt::Display};
+use wasmi_core::ValueType;
 use wasmparser::BinaryReaderError as WasmError;
 
 /// The generic `wasmi` root error type.
 #[derive(Debug)]
 pub struct Error {
+    /// The underlying kind of the error and additional information.
+    inner: Box<ErrorInner>,
+}
+
+/// The boxed contents of an [`Error`].
+#[derive(Debug)]
+struct ErrorInner {
     /// The underlying kind of the error and its specific information.
-    kind: Box<ErrorKind>,
+    kind: ErrorKind,
+    /// The byte offset of the trapping Wasm operator within the Wasm binary if any.
+    wasm_offset: Option<usize>,
 }
 
 #[test]
@@ -33,7 +45,10 @@ impl Error {
     /// Creates a new [`Error`] from the [`ErrorKind`].
     fn from_kind(kind: ErrorKind) -> Self {
         Self {
-            kind: Box::new(kind),
+            inner: Box::new(ErrorInner {
+                kind,
+                wasm_offset: None,
+            }),
         }
     }
 
@@ -48,6 +63,14 @@ impl Error {
     }
 
     /// Creates a custom [`HostError`].
+    ///
+    /// # Note
+    ///
+    /// Host functions may return such an [`Error`] to trap the execution.
+    /// The [`HostError`] is propagated unchanged to the caller of the executed
+    /// [`Func`] and can be recovered via [`Error::downcast_ref`] and friends.
+    ///
+    /// [`Func`]: crate::Func
     #[inline]
     #[cold]
     pub fn host<E>(host_error: E) -> Self
@@ -70,7 +93,31 @@ impl Error {
 
     /// Returns the [`ErrorKind`] of the [`Error`].
     pub fn kind(&self) -> &ErrorKind {
-        &self.kind
+        &self.inner.kind
+    }
+
+    /// Returns the byte offset of the trapping Wasm operator within the Wasm binary if any.
+    ///
+    /// # Note
+    ///
+    /// This is only available for traps raised during Wasm execution
+    /// if [`Config::trap_offsets`] has been enabled.
+    ///
+    /// [`Config::trap_offsets`]: crate::Config::trap_offsets
+    pub fn wasm_offset(&self) -> Option<usize> {
+        self.inner.wasm_offset
+    }
+
+    /// Sets the byte offset of the trapping Wasm operator if none has been set, yet.
+    ///
+    /// # Note
+    ///
+    /// The innermost trapping Wasm operator determines the offset of a trap.
+    pub(crate) fn with_wasm_offset(mut self, wasm_offset: Option<usize>) -> Self {
+        if self.inner.wasm_offset.is_none() {
+            self.inner.wasm_offset = wasm_offset;
+        }
+        self
     }
 
     /// Returns a reference to [`TrapCode`] if [`Error`] is a [`TrapCode`].
@@ -78,6 +125,30 @@ impl Error {
         self.kind().as_trap_code()
     }
 
+    /// Returns `true` if the [`Error`] was caused by running out of fuel.
+    ///
+    /// # Note
+    ///
+    /// This is the case for [`TrapCode::OutOfFuel`] traps raised during Wasm execution
+    /// as well as for [`FuelError::OutOfFuel`] errors, e.g. raised by host functions
+    /// that consume fuel via [`Caller::consume_fuel`].
+    ///
+    /// [`Caller::consume_fuel`]: crate::Caller::consume_fuel
+    pub fn is_out_of_fuel(&self) -> bool {
+        self.kind().is_out_of_fuel()
+    }
+
+    /// Returns `true` if the [`Error`] is a custom [`HostError`].
+    ///
+    /// # Note
+    ///
+    /// Those errors are usually created via [`Error::host`] by host functions and
+    /// are propagated unchanged through the Wasm execution to the caller.
+    /// Errors created via [`Error::new`] or [`Error::i32_exit`] are not [`HostError`]s.
+    pub fn is_host_error(&self) -> bool {
+        self.kind().as_host().is_some()
+    }
+
     /// Returns the classic `i32` exit program code of a `Trap` if any.
     ///
     /// Otherwise returns `None`.
@@ -93,7 +164,8 @@ impl Error {
     where
         T: HostError,
     {
-        self.kind
+        self.inner
+            .kind
             .as_host()
             .and_then(<(dyn HostError + 'static)>::downcast_ref)
     }
@@ -106,7 +178,8 @@ impl Error {
     where
         T: HostError,
     {
-        self.kind
+        self.inner
+            .kind
             .as_host_mut()
             .and_then(<(dyn HostError + 'static)>::downcast_mut)
     }
@@ -119,7 +192,8 @@ impl Error {
     where
         T: HostError,
     {
-        self.kind
+        self.inner
+            .kind
             .into_host()
             .and_then(|error| error.downcast().ok())
             .map(|boxed| *boxed)
@@ -131,7 +205,7 @@ impl std::error::Error for Error {}
 
 impl Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        Display::fmt(&self.kind, f)
+        Display::fmt(&self.inner.kind, f)
     }
 }
 
@@ -141,6 +215,8 @@ impl Display for Error {
 pub enum ErrorKind {
     /// A trap code as defined by the WebAssembly specification.
     TrapCode(TrapCode),
+    /// A trapping float-to-integer truncation with information about its input.
+    Trunc(TruncError),
     /// A message ususally provided by Wasmi users of host function calls.
     Message(Box<str>),
     /// An `i32` exit status usually used by WASI applications.
@@ -157,6 +233,8 @@ pub enum ErrorKind {
     Linker(LinkerError),
     /// A module instantiation error.
     Instantiation(InstantiationError),
+    /// An instance error.
+    Instance(InstanceError),
     /// A fuel error.
     Fuel(FuelError),
     /// A function error.
@@ -167,17 +245,36 @@ pub enum ErrorKind {
     Wasm(WasmError),
     /// Encountered when there is a Wasm to `wasmi` translation error.
     Translation(TranslationError),
+    /// Encountered when decoding `wasmi` bytecode from bytes fails.
+    Decode(DecodeError),
 }
 
 impl ErrorKind {
     /// Returns a reference to [`TrapCode`] if [`ErrorKind`] is a [`TrapCode`].
+    ///
+    /// # Note
+    ///
+    /// This also returns the [`TrapCode`] of an [`ErrorKind::Trunc`].
     pub fn as_trap_code(&self) -> Option<TrapCode> {
         match self {
             Self::TrapCode(trap_code) => Some(*trap_code),
+            Self::Trunc(error) => Some(error.trap_code()),
             _ => None,
         }
     }
 
+    /// Returns `true` if [`ErrorKind`] signals that all fuel has been consumed.
+    ///
+    /// # Note
+    ///
+    /// This is the case for [`TrapCode::OutOfFuel`] and [`FuelError::OutOfFuel`].
+    pub fn is_out_of_fuel(&self) -> bool {
+        matches!(
+            self,
+            Self::TrapCode(TrapCode::OutOfFuel) | Self::Fuel(FuelError::OutOfFuel)
+        )
+    }
+
     /// Returns a [`i32`] if [`ErrorKind`] is an [`ErrorKind::I32ExitStatus`].
     pub fn as_i32_exit_status(&self) -> Option<i32> {
         match self {
@@ -218,6 +315,7 @@ impl Display for ErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::TrapCode(error) => Display::fmt(error, f),
+            Self::Trunc(error) => Display::fmt(error, f),
             Self::I32ExitStatus(status) => writeln!(f, "Exited with i32 exit status {status}"),
             Self::Message(message) => Display::fmt(message, f),
             Self::Host(error) => Display::fmt(error, f),
@@ -227,10 +325,12 @@ impl Display for ErrorKind {
             Self::Linker(error) => Display::fmt(error, f),
             Self::Func(error) => Display::fmt(error, f),
             Self::Instantiation(error) => Display::fmt(error, f),
+            Self::Instance(error) => Display::fmt(error, f),
             Self::Fuel(error) => Display::fmt(error, f),
             Self::Read(error) => Display::fmt(error, f),
             Self::Wasm(error) => Display::fmt(error, f),
             Self::Translation(error) => Display::fmt(error, f),
+            Self::Decode(error) => Display::fmt(error, f),
         }
     }
 }
@@ -250,18 +350,104 @@ macro_rules! impl_from {
 }
 impl_from! {
     impl From<TrapCode> for Error::TrapCode;
+    impl From<TruncError> for Error::Trunc;
     impl From<GlobalError> for Error::Global;
     impl From<MemoryError> for Error::Memory;
     impl From<TableError> for Error::Table;
     impl From<LinkerError> for Error::Linker;
     impl From<InstantiationError> for Error::Instantiation;
+    impl From<InstanceError> for Error::Instance;
     impl From<TranslationError> for Error::Translation;
+    impl From<DecodeError> for Error::Decode;
     impl From<WasmError> for Error::Wasm;
     impl From<ReadError> for Error::Read;
     impl From<FuelError> for Error::Fuel;
     impl From<FuncError> for Error::Func;
 }
 
+/// A trap raised by a Wasm float-to-integer truncation such as `i32.trunc_f64_s`.
+///
+/// # Note
+///
+/// Provides the offending input value and the target integer type of the
+/// truncation in addition to its [`TrapCode`] to help debugging guest bugs.
+/// The saturating `trunc_sat` variants never trap.
+#[derive(Debug, Clone)]
+pub struct TruncError {
+    /// The [`TrapCode`] of the truncation.
+    trap_code: TrapCode,
+    /// The `f32` or `f64` input value of the truncation.
+    input: Value,
+    /// The integer type that the `input` was truncated to.
+    result_ty: ValueType,
+    /// Whether the `input` was interpreted as signed integer.
+    signed: bool,
+}
+
+impl TruncError {
+    /// Creates a new [`TruncError`].
+    pub(crate) fn new(
+        trap_code: TrapCode,
+        input: Value,
+        result_ty: ValueType,
+        signed: bool,
+    ) -> Self {
+        Self {
+            trap_code,
+            input,
+            result_ty,
+            signed,
+        }
+    }
+
+    /// Returns the [`TrapCode`] of the trapping truncation.
+    ///
+    /// This is either [`TrapCode::IntegerOverflow`] or [`TrapCode::BadConversionToInteger`].
+    pub fn trap_code(&self) -> TrapCode {
+        self.trap_code
+    }
+
+    /// Returns the `f32` or `f64` input value of the trapping truncation.
+    pub fn input(&self) -> &Value {
+        &self.input
+    }
+
+    /// Returns the integer [`ValueType`] that the input was truncated to.
+    pub fn result_ty(&self) -> ValueType {
+        self.result_ty
+    }
+
+    /// Returns `true` if the truncation targeted a signed integer.
+    pub fn is_signed(&self) -> bool {
+        self.signed
+    }
+}
+
+impl Display for TruncError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let result_ty = match self.result_ty {
+            ValueType::I32 => "i32",
+            _ => "i64",
+        };
+        let sign = if self.signed { 's' } else { 'u' };
+        match &self.input {
+            Value::F32(input) => write!(
+                f,
+                "{}: {result_ty}.trunc_f32_{sign} of {}",
+                self.trap_code,
+                f32::from(*input)
+            ),
+            Value::F64(input) => write!(
+                f,
+                "{}: {result_ty}.trunc_f64_{sign} of {}",
+                self.trap_code,
+                f64::from(*input)
+            ),
+            input => unreachable!("truncation input must be f32 or f64 but found: {input:?}"),
+        }
+    }
+}
+
 /// An error that can occur upon `memory.grow` or `table.grow`.
 #[derive(Copy, Clone)]
 pub enum EntityGrowError {
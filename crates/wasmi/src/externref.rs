@@ -27,7 +27,10 @@ impl ArenaIndex for ExternObjectIdx {
 /// An externally defined object.
 #[derive(Debug)]
 pub struct ExternObjectEntity {
-    inner: Box<dyn 'static + Any + Send + Sync>,
+    /// The wrapped host object or `None` if it has been reclaimed by [`Store::gc`].
+    ///
+    /// [`Store::gc`]: crate::Store::gc
+    inner: Option<Box<dyn 'static + Any + Send + Sync>>,
 }
 
 impl ExternObjectEntity {
@@ -37,13 +40,22 @@ impl ExternObjectEntity {
         T: 'static + Any + Send + Sync,
     {
         Self {
-            inner: Box::new(object),
+            inner: Some(Box::new(object)),
         }
     }
 
     /// Returns a shared reference to the external object.
-    pub fn data(&self) -> &dyn Any {
-        &*self.inner
+    ///
+    /// Returns `None` if the external object has been reclaimed.
+    pub fn data(&self) -> Option<&dyn Any> {
+        self.inner.as_deref().map(|object| object as &dyn Any)
+    }
+
+    /// Drops the external object if it has not yet been reclaimed.
+    ///
+    /// Returns `true` if the external object has been dropped by this call.
+    pub fn reclaim(&mut self) -> bool {
+        self.inner.take().is_some()
     }
 }
 
@@ -76,10 +88,14 @@ impl ExternObject {
 
     /// Returns a shared reference to the underlying data for this [`ExternRef`].
     ///
+    /// Returns `None` if this [`ExternObject`] has been reclaimed by [`Store::gc`].
+    ///
     /// # Panics
     ///
     /// Panics if `ctx` does not own this [`ExternObject`].
-    pub fn data<'a, T: 'a>(&self, ctx: impl Into<StoreContext<'a, T>>) -> &'a dyn Any {
+    ///
+    /// [`Store::gc`]: crate::Store::gc
+    pub fn data<'a, T: 'a>(&self, ctx: impl Into<StoreContext<'a, T>>) -> Option<&'a dyn Any> {
         ctx.into().store.inner.resolve_external_object(self).data()
     }
 }
@@ -135,6 +151,10 @@ impl From<ExternRef> for UntypedValue {
 
 impl ExternRef {
     /// Creates a new [`ExternRef`] wrapping the given value.
+    ///
+    /// Read [`Store::wrap_externref`] for the guarantees about handle allocation.
+    ///
+    /// [`Store::wrap_externref`]: crate::Store::wrap_externref
     pub fn new<T>(ctx: impl AsContextMut, object: impl Into<Option<T>>) -> Self
     where
         T: 'static + Any + Send + Sync,
@@ -182,13 +202,40 @@ impl ExternRef {
         self.inner.is_none()
     }
 
+    /// Returns the [`ExternObject`] of the [`ExternRef`] if it is not `null`.
+    pub(crate) fn object(&self) -> Option<&ExternObject> {
+        self.inner.as_ref()
+    }
+
     /// Returns a shared reference to the underlying data for this [`ExternRef`].
     ///
+    /// Returns `None` if the [`ExternRef`] is `null` or if its external object
+    /// has been reclaimed by [`Store::gc`].
+    ///
     /// # Panics
     ///
     /// Panics if `ctx` does not own this [`ExternRef`].
+    ///
+    /// [`Store::gc`]: crate::Store::gc
     pub fn data<'a, T: 'a>(&self, ctx: impl Into<StoreContext<'a, T>>) -> Option<&'a dyn Any> {
-        self.inner.map(|object| object.data(ctx))
+        self.inner?.data(ctx)
+    }
+
+    /// Returns a shared reference to the underlying data of type `T` for this [`ExternRef`].
+    ///
+    /// Returns `None` if the [`ExternRef`] is `null`, if its data is not of type `T`
+    /// or if its external object has been reclaimed by [`Store::gc`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`ExternRef`].
+    ///
+    /// [`Store::gc`]: crate::Store::gc
+    pub fn downcast_ref<'a, T, U: 'a>(&self, ctx: impl Into<StoreContext<'a, U>>) -> Option<&'a T>
+    where
+        T: 'static,
+    {
+        self.data(ctx)?.downcast_ref::<T>()
     }
 }
 
@@ -196,6 +243,7 @@ impl ExternRef {
 mod tests {
     use super::*;
     use crate::{Engine, Store};
+    use alloc::vec::Vec;
 
     #[test]
     fn it_works() {
@@ -203,6 +251,60 @@ mod tests {
         let mut store = <Store<()>>::new(&engine, ());
         let value = 42_i32;
         let obj = ExternObject::new::<i32>(&mut store, value);
-        assert_eq!(obj.data(&store).downcast_ref::<i32>(), Some(&value),);
+        assert_eq!(
+            obj.data(&store).unwrap().downcast_ref::<i32>(),
+            Some(&value),
+        );
+    }
+
+    #[test]
+    fn downcast_ref_works() {
+        #[derive(Debug, PartialEq)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        let engine = Engine::default();
+        let mut store = <Store<()>>::new(&engine, ());
+        let point = store.wrap_externref(Point { x: 1, y: 2 });
+        assert_eq!(
+            point.downcast_ref::<Point, _>(&store),
+            Some(&Point { x: 1, y: 2 })
+        );
+        assert_eq!(point.downcast_ref::<i32, _>(&store), None);
+        assert_eq!(ExternRef::null().downcast_ref::<Point, _>(&store), None);
+    }
+
+    #[test]
+    fn handle_allocation_is_deterministic() {
+        /// Performs a fixed sequence of externref allocations and drops.
+        ///
+        /// Returns the handles assigned to all allocated objects in order.
+        fn allocate_handles(engine: &Engine) -> Vec<ExternObjectIdx> {
+            let mut store = <Store<()>>::new(engine, ());
+            let mut handles = Vec::new();
+            let mut record = |store: &Store<()>, externref: ExternRef| {
+                let object = externref.object().unwrap();
+                handles.push(store.inner.extern_object_idx(object));
+            };
+            for n in 0..4_i32 {
+                let kept = store.wrap_externref(n);
+                let dropped = ExternRef::new::<i64>(&mut store, i64::from(n));
+                record(&store, kept);
+                record(&store, dropped);
+                // Note: `dropped` goes out of scope without being stored anywhere.
+                // Null references never allocate a handle.
+                assert!(ExternRef::new::<i32>(&mut store, None).is_null());
+            }
+            handles
+        }
+
+        let engine = Engine::default();
+        let handles = allocate_handles(&engine);
+        assert_eq!(handles, allocate_handles(&engine));
+        assert_eq!(handles, allocate_handles(&Engine::default()));
+        // Handles are assigned in strictly monotonic allocation order.
+        assert!(handles.windows(2).all(|pair| pair[0] < pair[1]));
     }
 }
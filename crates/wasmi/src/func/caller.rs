@@ -49,6 +49,11 @@ impl<'a, T> Caller<'a, T> {
         self.ctx.store.engine()
     }
 
+    /// Returns the number of Wasm call frames of all ongoing executions of the [`Store`](crate::Store).
+    pub fn call_stack_depth(&self) -> usize {
+        self.ctx.store.call_stack_depth()
+    }
+
     /// Adds `delta` quantity of fuel to the remaining fuel.
     ///
     /// # Panics
@@ -69,6 +74,26 @@ impl<'a, T> Caller<'a, T> {
         self.ctx.store.fuel_consumed()
     }
 
+    /// Returns the remaining amount of fuel of the [`Store`](crate::Store).
+    ///
+    /// Returns `None` if fuel metering is disabled.
+    pub fn get_fuel(&self) -> Option<u64> {
+        self.ctx.store.get_fuel()
+    }
+
+    /// Sets the remaining amount of fuel of the [`Store`](crate::Store) to `fuel`.
+    ///
+    /// # Panics
+    ///
+    /// If the sum of `fuel` and the consumed fuel overflows the fuel counter.
+    ///
+    /// # Errors
+    ///
+    /// If fuel metering is disabled.
+    pub fn set_fuel(&mut self, fuel: u64) -> Result<(), FuelError> {
+        self.ctx.store.set_fuel(fuel)
+    }
+
     /// Synthetically consumes an amount of fuel for the [`Store`](crate::Store).
     ///
     /// Returns the remaining amount of fuel after this operation.
@@ -12,7 +12,7 @@ use crate::{
     FuncType,
 };
 use core::{array, iter::FusedIterator};
-use wasmi_core::{DecodeUntypedSlice, EncodeUntypedSlice, UntypedValue};
+use wasmi_core::UntypedValue;
 
 /// Closures and functions that can be used as host functions.
 pub trait IntoFunc<T, Params, Results>: Send + Sync + 'static {
@@ -157,10 +157,18 @@ macro_rules! impl_wasm_return_type {
 for_each_tuple!(impl_wasm_return_type);
 
 /// Types that can be used as parameters or results of host functions.
-pub trait WasmType: From<UntypedValue> + Into<UntypedValue> + Send {
+pub trait WasmType: Send {
     /// Returns the value type of the Wasm type.
     #[doc(hidden)]
     fn ty() -> ValueType;
+
+    /// Creates `Self` from the given [`UntypedValue`].
+    #[doc(hidden)]
+    fn from_untyped(value: UntypedValue) -> Self;
+
+    /// Converts `self` into an [`UntypedValue`].
+    #[doc(hidden)]
+    fn into_untyped(self) -> UntypedValue;
 }
 
 macro_rules! impl_wasm_type {
@@ -171,6 +179,16 @@ macro_rules! impl_wasm_type {
                 fn ty() -> ValueType {
                     ValueType::$wasmi_type
                 }
+
+                #[inline]
+                fn from_untyped(value: UntypedValue) -> Self {
+                    <$rust_type>::from(value)
+                }
+
+                #[inline]
+                fn into_untyped(self) -> UntypedValue {
+                    UntypedValue::from(self)
+                }
             }
         )*
     };
@@ -186,6 +204,32 @@ impl_wasm_type! {
     type ExternRef = ExternRef;
 }
 
+macro_rules! impl_wasm_type_for_nullable {
+    ( $( $ref_type:ident ),* $(,)? ) => {
+        $(
+            /// Nullable references are mapped to `None` if they are `null`.
+            impl WasmType for Option<$ref_type> {
+                #[inline]
+                fn ty() -> ValueType {
+                    ValueType::$ref_type
+                }
+
+                #[inline]
+                fn from_untyped(value: UntypedValue) -> Self {
+                    let reference = <$ref_type>::from(value);
+                    (!reference.is_null()).then_some(reference)
+                }
+
+                #[inline]
+                fn into_untyped(self) -> UntypedValue {
+                    UntypedValue::from(self.unwrap_or_else(<$ref_type>::null))
+                }
+            }
+        )*
+    };
+}
+impl_wasm_type_for_nullable!(FuncRef, ExternRef);
+
 /// A list of [`WasmType`] types.
 ///
 /// # Note
@@ -196,7 +240,7 @@ impl_wasm_type! {
 /// - Write host function results into a region of the value stack.
 /// - Iterate over the value types of the Wasm type sequence
 ///     - This is useful to construct host function signatures.
-pub trait WasmTypeList: DecodeUntypedSlice + EncodeUntypedSlice + Sized + Send {
+pub trait WasmTypeList: Sized + Send {
     /// The number of Wasm types in the list.
     #[doc(hidden)]
     const LEN: usize;
@@ -260,13 +304,13 @@ where
 
     #[inline]
     fn values(self) -> Self::Values {
-        [<T1 as Into<UntypedValue>>::into(self)]
+        [<T1 as WasmType>::into_untyped(self)]
     }
 
     #[inline]
     fn from_values(values: &[UntypedValue]) -> Option<Self> {
         if let [value] = *values {
-            return Some(value.into());
+            return Some(<T1 as WasmType>::from_untyped(value));
         }
         None
     }
@@ -299,7 +343,7 @@ macro_rules! impl_wasm_type_list {
             fn values(self) -> Self::Values {
                 let ($($tuple,)*) = self;
                 [$(
-                    <$tuple as Into<UntypedValue>>::into($tuple)
+                    <$tuple as WasmType>::into_untyped($tuple)
                 ),*]
             }
 
@@ -308,7 +352,7 @@ macro_rules! impl_wasm_type_list {
             fn from_values(values: &[UntypedValue]) -> Option<Self> {
                 if let [$($tuple),*] = *values {
                     return Some(
-                        ( $( Into::into($tuple), )* )
+                        ( $( <$tuple as WasmType>::from_untyped($tuple), )* )
                     )
                 }
                 None
@@ -364,5 +408,11 @@ mod tests {
         assert!(implements_wasm_results!(Result<i32, Error>));
         assert!(implements_wasm_results!(Result<(i32,), Error>));
         assert!(implements_wasm_results!(Result<(i32, u32, i64, u64, F32, F64), Error>));
+        assert!(implements_wasm_results!(Option<ExternRef>));
+        assert!(implements_wasm_results!((
+            Option<FuncRef>,
+            Option<ExternRef>
+        )));
+        assert!(implements_wasm_results!(Result<Option<ExternRef>, Error>));
     }
 }
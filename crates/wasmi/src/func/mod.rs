@@ -15,7 +15,7 @@ pub use self::{
     typed_func::{TypedFunc, WasmParams, WasmResults},
 };
 use super::{
-    engine::{CompiledFunc, DedupFuncType, FuncFinished, FuncParams},
+    engine::{CompiledFunc, DedupFuncType, FuncFinished, FuncParams, FuncResults},
     AsContext,
     AsContextMut,
     Instance,
@@ -23,9 +23,10 @@ use super::{
     Stored,
 };
 use crate::{engine::ResumableCall, Engine, Error, Value};
-use alloc::{boxed::Box, sync::Arc};
-use core::{fmt, fmt::Debug, num::NonZeroU32};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{fmt, fmt::Debug, num::NonZeroU32, slice};
 use wasmi_arena::ArenaIndex;
+use wasmi_core::UntypedValue;
 
 /// A raw index to a function entity.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -106,12 +107,36 @@ pub struct HostFuncEntity {
     ty: DedupFuncType,
     /// A reference to the trampoline of the host function.
     func: Trampoline,
+    /// Is `true` if adjacent calls to the host function may be batched.
+    batched: bool,
 }
 
 impl HostFuncEntity {
     /// Creates a new [`HostFuncEntity`].
     pub fn new(ty: DedupFuncType, func: Trampoline) -> Self {
-        Self { ty, func }
+        Self {
+            ty,
+            func,
+            batched: false,
+        }
+    }
+
+    /// Creates a new [`HostFuncEntity`] of which adjacent calls may be batched.
+    ///
+    /// # Note
+    ///
+    /// The trampoline of `func` must provide a batch trampoline.
+    pub fn new_batched(ty: DedupFuncType, func: Trampoline) -> Self {
+        Self {
+            ty,
+            func,
+            batched: true,
+        }
+    }
+
+    /// Returns `true` if adjacent calls to the host function may be batched.
+    pub fn is_batched(&self) -> bool {
+        self.batched
     }
 
     /// Returns the signature of the host function.
@@ -249,8 +274,17 @@ impl<T> HostFuncTrampolineEntity<T> {
 type TrampolineFn<T> =
     dyn Fn(Caller<T>, FuncParams) -> Result<FuncFinished, Error> + Send + Sync + 'static;
 
+/// Calls a host function for a batch of argument sets at once.
+///
+/// The buffer consists of one chunk of `max(len_params, len_results)` cells per call
+/// in the batch. Each chunk initially holds the parameters of its call and is
+/// overwritten with the results of its call.
+type BatchTrampolineFn<T> =
+    dyn Fn(Caller<T>, &mut [UntypedValue], usize) -> Result<(), Error> + Send + Sync + 'static;
+
 pub struct TrampolineEntity<T> {
     closure: Arc<TrampolineFn<T>>,
+    batch: Option<Arc<BatchTrampolineFn<T>>>,
 }
 
 impl<T> Debug for TrampolineEntity<T> {
@@ -267,9 +301,19 @@ impl<T> TrampolineEntity<T> {
     {
         Self {
             closure: Arc::new(trampoline),
+            batch: None,
         }
     }
 
+    /// Adds the batch trampoline `batch` to the [`TrampolineEntity`].
+    pub fn with_batch<F>(mut self, batch: F) -> Self
+    where
+        F: Fn(Caller<T>, &mut [UntypedValue], usize) -> Result<(), Error> + Send + Sync + 'static,
+    {
+        self.batch = Some(Arc::new(batch));
+        self
+    }
+
     /// Calls the host function trampoline with the given inputs.
     ///
     /// The result is written back into the `outputs` buffer.
@@ -282,12 +326,35 @@ impl<T> TrampolineEntity<T> {
         let caller = <Caller<T>>::new(&mut ctx, instance);
         (self.closure)(caller, params)
     }
+
+    /// Calls the batch trampoline of the host function for `len` argument sets.
+    ///
+    /// The results of each call are written back into its chunk of `params_results`.
+    ///
+    /// # Panics
+    ///
+    /// If the [`TrampolineEntity`] has no batch trampoline.
+    pub fn call_batch(
+        &self,
+        mut ctx: impl AsContextMut<UserState = T>,
+        instance: Option<&Instance>,
+        params_results: &mut [UntypedValue],
+        len: usize,
+    ) -> Result<(), Error> {
+        let batch = self
+            .batch
+            .as_ref()
+            .expect("batched host functions must have a batch trampoline");
+        let caller = <Caller<T>>::new(&mut ctx, instance);
+        batch(caller, params_results, len)
+    }
 }
 
 impl<T> Clone for TrampolineEntity<T> {
     fn clone(&self) -> Self {
         Self {
             closure: self.closure.clone(),
+            batch: self.batch.clone(),
         }
     }
 }
@@ -350,6 +417,42 @@ impl Func {
             .alloc_func(HostFuncEntity::new(ty_dedup, func).into())
     }
 
+    /// Creates a new [`Func`] from a closure that returns its results as a [`Vec`].
+    ///
+    /// This is useful for host functions with signatures that are only known at runtime.
+    ///
+    /// - `ty`: the signature that the given closure adheres to.
+    /// - `func`: the native code invoked whenever this Func will be called.
+    ///   The closure is provided a [`Caller`] as well as the parameters
+    ///   and returns the results of the host function.
+    ///
+    /// # Note
+    ///
+    /// Unlike with [`Func::new`] both the parameters and the results of `func`
+    /// are checked against `ty` upon every invocation which makes this method
+    /// the safer but slower alternative for fully dynamic host functions.
+    ///
+    /// # Errors
+    ///
+    /// Calling the returned [`Func`] results in a [`FuncError`] if:
+    ///
+    /// - the parameters passed to `func` do not match the parameters of `ty`.
+    /// - the results returned by `func` do not match the results of `ty`.
+    pub fn new_dynamic<T>(
+        ctx: impl AsContextMut<UserState = T>,
+        ty: FuncType,
+        func: impl Fn(Caller<'_, T>, &[Value]) -> Result<Vec<Value>, Error> + Send + Sync + 'static,
+    ) -> Self {
+        let func_type = ty.clone();
+        Self::new(ctx, ty, move |caller, params, results| {
+            func_type.match_params(params)?;
+            let values = func(caller, params)?;
+            func_type.match_results(&values[..], true)?;
+            results.clone_from_slice(&values[..]);
+            Ok(())
+        })
+    }
+
     /// Creates a new host function from the given closure.
     pub fn wrap<T, Params, Results>(
         mut ctx: impl AsContextMut<UserState = T>,
@@ -366,6 +469,71 @@ impl Func {
             .alloc_func(HostFuncEntity::new(ty_dedup, func).into())
     }
 
+    /// Creates a new host function from the given batched closure.
+    ///
+    /// The closure `func` receives a slice of argument sets and must return
+    /// exactly one result set per argument set in the same order.
+    ///
+    /// # Note
+    ///
+    /// When Wasm executes adjacent calls to the returned [`Func`] whose arguments
+    /// do not depend on the results of each other, `wasmi` may call `func` once
+    /// with all of their argument sets instead of once per call.
+    /// Other calls provide `func` with a single argument set.
+    ///
+    /// # Errors
+    ///
+    /// Calling the returned [`Func`] results in a [`FuncError`] if `func`
+    /// returns a different number of result sets than it was given argument sets.
+    ///
+    /// Errors of batched calls are not resumable since it is ambiguous
+    /// which of the batched calls failed.
+    pub fn wrap_batched<T, Params, Results>(
+        mut ctx: impl AsContextMut<UserState = T>,
+        func: impl Fn(Caller<'_, T>, &[Params]) -> Vec<Results> + Send + Sync + 'static,
+    ) -> Self
+    where
+        Params: WasmTypeList,
+        Results: WasmTypeList,
+    {
+        let func = Arc::new(func);
+        let batch_func = func.clone();
+        let trampoline = TrampolineEntity::new(
+            move |caller: Caller<T>, params_results: FuncParams| -> Result<FuncFinished, Error> {
+                let (params, func_results): (Params, FuncResults) = params_results.decode_params();
+                let results = func(caller, slice::from_ref(&params));
+                let [results] = <[Results; 1]>::try_from(results)
+                    .map_err(|_| Error::from(FuncError::MismatchingResultLen))?;
+                Ok(func_results.encode_results(results))
+            },
+        )
+        .with_batch(move |caller, params_results, len| {
+            let stride = Params::LEN.max(Results::LEN);
+            let params = (0..len)
+                .map(|n| {
+                    let values = &params_results[n * stride..][..Params::LEN];
+                    Params::from_values(values).expect("must have matching parameter types")
+                })
+                .collect::<Vec<Params>>();
+            let results = batch_func(caller, &params[..]);
+            if results.len() != len {
+                return Err(Error::from(FuncError::MismatchingResultLen));
+            }
+            for (n, results) in results.into_iter().enumerate() {
+                let cells = &mut params_results[n * stride..][..Results::LEN];
+                cells.copy_from_slice(results.values().as_ref());
+            }
+            Ok(())
+        });
+        let ty = FuncType::new(Params::types(), Results::types());
+        let ty_dedup = ctx.as_context().store.engine().alloc_func_type(ty);
+        let func = ctx.as_context_mut().store.alloc_trampoline(trampoline);
+        ctx.as_context_mut()
+            .store
+            .inner
+            .alloc_func(HostFuncEntity::new_batched(ty_dedup, func).into())
+    }
+
     /// Returns the signature of the function.
     pub(crate) fn ty_dedup<'a, T: 'a>(
         &self,
@@ -386,6 +554,13 @@ impl Func {
     ///
     /// The result is written back into the `outputs` buffer.
     ///
+    /// # Note
+    ///
+    /// The `outputs` buffer can be reused across calls. Once the [`Engine`]
+    /// has been warmed up by a first call, repeated calls do not perform heap
+    /// allocations on their own since the engine reuses its execution stacks.
+    /// The lengths of `inputs` and `outputs` are checked before execution.
+    ///
     /// # Errors
     ///
     /// - If the function returned a [`Error`].
@@ -412,6 +587,75 @@ impl Func {
         Ok(())
     }
 
+    /// Calls the Wasm or host function with the `inputs` yielded by an iterator.
+    ///
+    /// The result is written back into the `outputs` buffer.
+    ///
+    /// # Note
+    ///
+    /// This is a convenience API for calling a [`Func`] with dynamically built
+    /// arguments. The `inputs` are collected into a temporary buffer before the
+    /// call. Use [`Func::call`] with a slice of inputs in order to avoid this.
+    ///
+    /// # Errors
+    ///
+    /// - If the function returned a [`Error`].
+    /// - If the types of the `inputs` do not match the expected types for the
+    ///   function signature of `self`.
+    /// - If the number of input values does not match the expected number of
+    ///   inputs required by the function signature of `self`.
+    /// - If the number of output values does not match the expected number of
+    ///   outputs required by the function signature of `self`.
+    pub fn call_iter<T>(
+        &self,
+        ctx: impl AsContextMut<UserState = T>,
+        inputs: impl IntoIterator<Item = Value>,
+        outputs: &mut [Value],
+    ) -> Result<(), Error> {
+        let inputs: Vec<Value> = inputs.into_iter().collect();
+        self.call(ctx, &inputs[..], outputs)
+    }
+
+    /// Calls the Wasm or host function with the given untyped `params`.
+    ///
+    /// The results are written back into the `results` buffer.
+    ///
+    /// # Note
+    ///
+    /// Unlike [`Func::call`] this neither checks the number and types of `params`
+    /// and `results` against the function signature of `self` nor converts them
+    /// from and to [`Value`]. This is useful for embedders that have already
+    /// validated their call arguments, e.g. in hot dispatch loops.
+    ///
+    /// # Errors
+    ///
+    /// If the function returned a [`Error`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that
+    ///
+    /// - the length of `params` is equal to the number of parameters of `self`,
+    /// - the length of `results` is equal to the number of results of `self` and
+    /// - all `params` are valid values of their respective parameter types of `self`.
+    ///
+    /// Otherwise the behavior is undefined since the [`Engine`] might access its
+    /// value stack out of bounds or Wasm code might operate on invalid references.
+    pub unsafe fn call_unchecked<T>(
+        &self,
+        mut ctx: impl AsContextMut<UserState = T>,
+        params: &[UntypedValue],
+        results: &mut [UntypedValue],
+    ) -> Result<(), Error> {
+        // Note: Cloning an [`Engine`] is intentionally a cheap operation.
+        ctx.as_context().store.engine().clone().execute_func(
+            ctx.as_context_mut(),
+            self,
+            params,
+            results,
+        )
+    }
+
     /// Calls the Wasm or host function with the given inputs.
     ///
     /// The result is written back into the `outputs` buffer.
@@ -491,6 +735,10 @@ impl Func {
     /// to [`Func`] and `Results` as result types of [`Func`] so that those
     /// type checks can be avoided when calling the created [`TypedFunc`].
     ///
+    /// The type checks are performed anew upon every call to [`Func::typed`].
+    /// Since [`TypedFunc`] is a cheap [`Copy`] handle, hosts that repeatedly call
+    /// the same function should create the [`TypedFunc`] once and hold on to it.
+    ///
     /// # Errors
     ///
     /// If the function signature of `self` does not match `Params` and `Results`
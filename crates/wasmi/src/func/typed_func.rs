@@ -66,13 +66,19 @@ where
     /// If the provided static types `Params` and `Results` for the parameters
     /// and result types of `func` mismatch the signature of `func`.
     pub(crate) fn new(ctx: impl AsContext, func: Func) -> Result<Self, Error> {
-        let func_type = func.ty(&ctx);
         let (actual_params, actual_results) = (
             <Params as WasmTypeList>::types(),
             <Results as WasmTypeList>::types(),
         );
-        func_type.match_params(actual_params.as_ref())?;
-        func_type.match_results(actual_results.as_ref(), true)?;
+        // Note: we type check in place to avoid cloning the function type of `func`.
+        let fn_type = func.ty_dedup(ctx.as_context());
+        ctx.as_context()
+            .store
+            .inner
+            .resolve_func_type_with(fn_type, |func_type| {
+                func_type.match_params(actual_params.as_ref())?;
+                func_type.match_results(actual_results.as_ref(), true)
+            })?;
         Ok(Self {
             signature: PhantomData,
             func,
@@ -103,6 +109,46 @@ where
         )
     }
 
+    /// Calls this Wasm or host function with the specified parameters and a fuel budget of `fuel_limit`.
+    ///
+    /// Returns the results of the call together with the amount of fuel consumed by the call.
+    ///
+    /// # Note
+    ///
+    /// - The call may consume at most `fuel_limit` fuel or the remaining fuel
+    ///   of the store, whichever is smaller.
+    /// - Only the fuel actually consumed by the call is deducted from the store,
+    ///   regardless of whether the call succeeded or not.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`TypedFunc`].
+    ///
+    /// # Errors
+    ///
+    /// - If fuel metering is disabled.
+    /// - If the execution of the called Wasm function traps.
+    /// - If the call exceeds its fuel budget which traps with [`TrapCode::OutOfFuel`].
+    ///
+    /// [`TrapCode::OutOfFuel`]: crate::core::TrapCode::OutOfFuel
+    pub fn call_with_fuel(
+        &self,
+        mut ctx: impl AsContextMut,
+        params: Params,
+        fuel_limit: u64,
+    ) -> Result<(Results, u64), Error> {
+        let mut ctx = ctx.as_context_mut();
+        ctx.store.check_fuel_metering_enabled()?;
+        let fuel = ctx.store.inner.fuel_mut();
+        let withheld = fuel.withhold_above(fuel_limit);
+        let consumed_before = fuel.fuel_consumed();
+        let results = self.call(&mut ctx, params);
+        let fuel = ctx.store.inner.fuel_mut();
+        let fuel_used = fuel.fuel_consumed().wrapping_sub(consumed_before);
+        fuel.restore_withheld(withheld);
+        results.map(|results| (results, fuel_used))
+    }
+
     /// Calls this Wasm or host function with the specified parameters.
     ///
     /// Returns a resumable handle to the function invocation upon
@@ -1,5 +1,6 @@
 use super::{AsContext, AsContextMut, Stored};
 use crate::{core::ValueType, value::WithType, Value};
+use alloc::{boxed::Box, vec::Vec};
 use core::{fmt, fmt::Display, ptr::NonNull};
 use wasmi_arena::ArenaIndex;
 use wasmi_core::UntypedValue;
@@ -231,11 +232,22 @@ impl Global {
     }
 
     /// Creates a new global variable to the store.
+    ///
+    /// # Note
+    ///
+    /// Reference values keep referring to the same [`Func`] or [`ExternRef`] object
+    /// for the lifetime of the store.
+    ///
+    /// # Panics
+    ///
+    /// If `initial_value` is a reference that is not owned by `ctx`.
+    ///
+    /// [`Func`]: crate::Func
+    /// [`ExternRef`]: crate::ExternRef
     pub fn new(mut ctx: impl AsContextMut, initial_value: Value, mutability: Mutability) -> Self {
-        ctx.as_context_mut()
-            .store
-            .inner
-            .alloc_global(GlobalEntity::new(initial_value, mutability))
+        let inner = &mut ctx.as_context_mut().store.inner;
+        inner.assert_owns_value(&initial_value);
+        inner.alloc_global(GlobalEntity::new(initial_value, mutability))
     }
 
     /// Returns the [`GlobalType`] of the global variable.
@@ -252,13 +264,12 @@ impl Global {
     ///
     /// # Panics
     ///
-    /// Panics if `ctx` does not own this [`Global`].
+    /// - Panics if `ctx` does not own this [`Global`].
+    /// - Panics if `new_value` is a reference that is not owned by `ctx`.
     pub fn set(&self, mut ctx: impl AsContextMut, new_value: Value) -> Result<(), GlobalError> {
-        ctx.as_context_mut()
-            .store
-            .inner
-            .resolve_global_mut(self)
-            .set(new_value)
+        let inner = &mut ctx.as_context_mut().store.inner;
+        inner.assert_owns_value(&new_value);
+        inner.resolve_global_mut(self).set(new_value)
     }
 
     /// Returns the current value of the global variable.
@@ -270,3 +281,107 @@ impl Global {
         ctx.as_context().store.inner.resolve_global(self).get()
     }
 }
+
+/// A snapshot of the values of the mutable global variables of an [`Instance`].
+///
+/// Created via [`Store::snapshot_globals`] and restored via [`Store::restore_globals`].
+///
+/// [`Instance`]: crate::Instance
+/// [`Store::snapshot_globals`]: crate::Store::snapshot_globals
+/// [`Store::restore_globals`]: crate::Store::restore_globals
+#[derive(Debug, Clone)]
+pub struct GlobalsSnapshot {
+    /// The snapshotted mutable global variables and their values.
+    globals: Vec<(Global, UntypedValue)>,
+}
+
+impl GlobalsSnapshot {
+    /// Creates a new [`GlobalsSnapshot`] from the mutable global variables and their values.
+    pub(crate) fn new(globals: Vec<(Global, UntypedValue)>) -> Self {
+        Self { globals }
+    }
+
+    /// Returns the snapshotted mutable global variables and their values.
+    pub(crate) fn globals(&self) -> &[(Global, UntypedValue)] {
+        &self.globals[..]
+    }
+
+    /// Returns the number of snapshotted mutable global variables.
+    pub fn len(&self) -> usize {
+        self.globals.len()
+    }
+
+    /// Returns `true` if no mutable global variable has been snapshotted.
+    pub fn is_empty(&self) -> bool {
+        self.globals.is_empty()
+    }
+}
+
+/// A handle to a global variable watch registered via [`Store::watch_global`].
+///
+/// Pass it to [`Store::unwatch_global`] in order to unregister the watch.
+///
+/// [`Store::watch_global`]: crate::Store::watch_global
+/// [`Store::unwatch_global`]: crate::Store::unwatch_global
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct GlobalWatchHandle(u32);
+
+/// A callback invoked with the old and new values of a watched [`Global`].
+type GlobalWatchFn = Box<dyn FnMut(Value, Value) + Send + Sync>;
+
+/// The registered watches on global variables of a [`Store`].
+///
+/// [`Store`]: crate::Store
+#[derive(Default)]
+pub struct GlobalWatches {
+    /// The registered watches in order of their registration.
+    watches: Vec<(GlobalWatchHandle, Global, GlobalWatchFn)>,
+    /// The handle used for the next registered watch.
+    next_handle: u32,
+}
+
+impl fmt::Debug for GlobalWatches {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(
+                self.watches
+                    .iter()
+                    .map(|(handle, global, _)| (handle, global)),
+            )
+            .finish()
+    }
+}
+
+impl GlobalWatches {
+    /// Returns `true` if no watch is registered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.watches.is_empty()
+    }
+
+    /// Registers `callback` as watch for `global` and returns its [`GlobalWatchHandle`].
+    pub fn push(&mut self, global: Global, callback: GlobalWatchFn) -> GlobalWatchHandle {
+        let handle = GlobalWatchHandle(self.next_handle);
+        self.next_handle = self.next_handle.wrapping_add(1);
+        self.watches.push((handle, global, callback));
+        handle
+    }
+
+    /// Unregisters the watch associated to `handle`.
+    ///
+    /// Returns `true` if the watch was registered.
+    pub fn remove(&mut self, handle: GlobalWatchHandle) -> bool {
+        let len_watches = self.watches.len();
+        self.watches.retain(|(h, _, _)| *h != handle);
+        self.watches.len() != len_watches
+    }
+
+    /// Invokes all watches registered for `global` with its `old_value` and `new_value`.
+    pub fn notify(&mut self, global: &Global, old_value: &Value, new_value: &Value) {
+        for (_, watched, callback) in &mut self.watches {
+            if watched.as_inner() == global.as_inner() {
+                callback(old_value.clone(), new_value.clone())
+            }
+        }
+    }
+}
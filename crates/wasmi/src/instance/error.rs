@@ -0,0 +1,19 @@
+use core::{fmt, fmt::Display};
+
+/// Errors that may occur upon operating with [`Instance`](crate::Instance) entities.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum InstanceError {
+    /// Occurs when resolving an [`ExportHandle`](crate::ExportHandle) with another [`Instance`](crate::Instance).
+    ForeignExportHandle,
+}
+
+impl Display for InstanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ForeignExportHandle => {
+                write!(f, "tried to resolve an export handle of another instance")
+            }
+        }
+    }
+}
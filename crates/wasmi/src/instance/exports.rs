@@ -1,4 +1,15 @@
-use crate::{AsContext, Func, FuncType, Global, GlobalType, Memory, MemoryType, Table, TableType};
+use crate::{
+    AsContext,
+    Func,
+    FuncType,
+    Global,
+    GlobalType,
+    Instance,
+    Memory,
+    MemoryType,
+    Table,
+    TableType,
+};
 use alloc::{boxed::Box, collections::btree_map};
 use core::iter::FusedIterator;
 
@@ -231,6 +242,35 @@ impl<'instance> Export<'instance> {
     }
 }
 
+/// A handle to an export of an [`Instance`] that bypasses name resolution.
+///
+/// Created once via [`Instance::get_export_handle`] and then resolved cheaply and
+/// repeatedly via [`Instance::resolve_export_handle`] of the same [`Instance`].
+#[derive(Debug, Copy, Clone)]
+pub struct ExportHandle {
+    /// The [`Instance`] that created the [`ExportHandle`].
+    instance: Instance,
+    /// The exported item.
+    item: Extern,
+}
+
+impl ExportHandle {
+    /// Creates a new [`ExportHandle`] to the `item` exported by `instance`.
+    pub(super) fn new(instance: Instance, item: Extern) -> Self {
+        Self { instance, item }
+    }
+
+    /// Returns the [`Instance`] that created the [`ExportHandle`].
+    pub fn instance(&self) -> Instance {
+        self.instance
+    }
+
+    /// Returns the exported [`Extern`] item.
+    pub(super) fn item(&self) -> Extern {
+        self.item
+    }
+}
+
 /// An iterator over the [`Extern`] declarations of an [`Instance`](crate::Instance).
 #[derive(Debug)]
 pub struct ExportsIter<'instance> {
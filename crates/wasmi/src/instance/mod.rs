@@ -1,5 +1,8 @@
 pub(crate) use self::builder::InstanceEntityBuilder;
-pub use self::exports::{Export, ExportsIter, Extern, ExternType};
+pub use self::{
+    error::InstanceError,
+    exports::{Export, ExportHandle, ExportsIter, Extern, ExternType},
+};
 use super::{
     engine::DedupFuncType,
     AsContext,
@@ -24,6 +27,7 @@ use alloc::{boxed::Box, collections::BTreeMap, sync::Arc};
 use wasmi_arena::ArenaIndex;
 
 mod builder;
+mod error;
 mod exports;
 
 /// A raw index to a module instance entity.
@@ -98,6 +102,11 @@ impl InstanceEntity {
         self.globals.get(index as usize).copied()
     }
 
+    /// Returns the global variables of the [`Instance`].
+    pub fn globals(&self) -> &[Global] {
+        &self.globals[..]
+    }
+
     /// Returns the function at the `index` if any.
     pub fn get_func(&self, index: u32) -> Option<Func> {
         self.funcs.get(index as usize).copied()
@@ -191,6 +200,44 @@ impl Instance {
             .get_export(name)
     }
 
+    /// Returns an [`ExportHandle`] to the value exported to the given `name` if any.
+    ///
+    /// # Note
+    ///
+    /// Resolving the returned [`ExportHandle`] via [`Instance::resolve_export_handle`]
+    /// bypasses the name resolution of [`Instance::get_export`]. This is useful for
+    /// hosts that repeatedly access the same exports.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this [`Instance`].
+    pub fn get_export_handle(&self, store: impl AsContext, name: &str) -> Option<ExportHandle> {
+        let item = self.get_export(store, name)?;
+        Some(ExportHandle::new(*self, item))
+    }
+
+    /// Returns the value referred to by the [`ExportHandle`].
+    ///
+    /// # Errors
+    ///
+    /// If `handle` has not been created by this [`Instance`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this [`Instance`].
+    pub fn resolve_export_handle(
+        &self,
+        store: impl AsContext,
+        handle: ExportHandle,
+    ) -> Result<Extern, Error> {
+        // Note: resolving the instance asserts that `store` owns it.
+        store.as_context().store.inner.resolve_instance(self);
+        if handle.instance() != *self {
+            return Err(Error::from(InstanceError::ForeignExportHandle));
+        }
+        Ok(handle.item())
+    }
+
     /// Looks up an exported [`Func`] value by `name`.
     ///
     /// Returns `None` if there was no export named `name`,
@@ -76,7 +76,7 @@
     clippy::default_trait_access,
     clippy::items_after_statements
 )]
-#![recursion_limit = "750"]
+#![recursion_limit = "1000"]
 
 #[cfg(not(feature = "std"))]
 #[macro_use]
@@ -109,9 +109,10 @@ pub use wasmi_core as core;
 /// Defines some errors that may occur upon interaction with `wasmi`.
 pub mod errors {
     pub use super::{
-        error::ErrorKind,
+        error::{ErrorKind, TruncError},
         func::FuncError,
         global::GlobalError,
+        instance::InstanceError,
         linker::LinkerError,
         memory::MemoryError,
         module::InstantiationError,
@@ -120,17 +121,29 @@ pub mod errors {
     };
 }
 
+#[cfg(feature = "debug")]
+pub use self::engine::{ExecutionTrace, TraceEntry, TraceWrites};
+#[cfg(all(feature = "guard-pages", unix))]
+pub use self::memory::GuardPagesMemoryBackend;
 pub use self::{
     engine::{
         CompilationMode,
         Config,
+        Diagnostic,
+        DiagnosticKind,
         Engine,
         FuelConsumptionMode,
+        FuelCosts,
+        FuncMetadata,
+        FusionKind,
+        InstructionHistogram,
         ResumableCall,
         ResumableInvocation,
         StackLimits,
+        TraceEvent,
         TypedResumableCall,
         TypedResumableInvocation,
+        WasmFeatures,
     },
     error::Error,
     externref::ExternRef,
@@ -147,11 +160,11 @@ pub use self::{
         WasmType,
         WasmTypeList,
     },
-    global::{Global, GlobalType, Mutability},
-    instance::{Export, ExportsIter, Extern, ExternType, Instance},
+    global::{Global, GlobalType, GlobalWatchHandle, GlobalsSnapshot, Mutability},
+    instance::{Export, ExportHandle, ExportsIter, Extern, ExternType, Instance},
     limits::{ResourceLimiter, StoreLimits, StoreLimitsBuilder},
-    linker::Linker,
-    memory::{Memory, MemoryType},
+    linker::{LinkedModule, Linker},
+    memory::{Memory, MemoryBackend, MemoryBuffer, MemorySnapshot, MemoryType, VecMemoryBackend},
     module::{
         ExportType,
         ImportType,
@@ -161,13 +174,13 @@ pub use self::{
         ModuleImportsIter,
         Read,
     },
-    store::{AsContext, AsContextMut, Store, StoreContext, StoreContextMut},
+    store::{AsContext, AsContextMut, Store, StoreContext, StoreContextMut, UnreachableAction},
     table::{Table, TableType},
     value::Value,
 };
 use self::{
     func::{FuncEntity, FuncIdx},
-    global::{GlobalEntity, GlobalIdx},
+    global::{GlobalEntity, GlobalIdx, GlobalWatches},
     instance::{InstanceEntity, InstanceEntityBuilder, InstanceIdx},
     memory::{DataSegmentEntity, DataSegmentIdx, MemoryEntity, MemoryIdx},
     store::{StoreInner, Stored},
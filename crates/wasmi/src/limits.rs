@@ -37,6 +37,9 @@ pub trait ResourceLimiter {
     /// The `current` and `desired` amounts are guaranteed to always be
     /// multiples of the WebAssembly page size, 64KiB.
     ///
+    /// The `desired` size is less than the `current` size if the linear memory
+    /// is shrunk by [`Memory::restore`](crate::Memory::restore).
+    ///
     /// ## Return Value
     ///
     /// If `Ok(true)` is returned from this function then the growth operation
@@ -11,6 +11,7 @@ use crate::{
     Func,
     FuncType,
     GlobalType,
+    Instance,
     InstancePre,
     IntoFunc,
     MemoryType,
@@ -19,6 +20,8 @@ use crate::{
     Value,
 };
 use alloc::{
+    borrow::Cow,
+    boxed::Box,
     collections::{btree_map::Entry, BTreeMap},
     sync::Arc,
     vec::Vec,
@@ -38,6 +41,11 @@ pub enum LinkerError {
         /// The duplicate import name of the definition.
         import_name: ImportName,
     },
+    /// Encountered when aliasing a name for which no definition exists.
+    UndefinedAliasTarget {
+        /// The name of the missing definition.
+        name: ImportName,
+    },
     /// Encountered when no definition for an import is found.
     MissingDefinition {
         /// The name of the import for which no definition was found.
@@ -159,6 +167,9 @@ impl Display for LinkerError {
                     "encountered duplicate definition with name `{import_name}`",
                 )
             }
+            Self::UndefinedAliasTarget { name } => {
+                write!(f, "cannot alias missing definition {name}")
+            }
             Self::MissingDefinition { name, ty } => {
                 write!(
                     f,
@@ -170,7 +181,10 @@ impl Display for LinkerError {
                 expected,
                 found,
             } => {
-                write!(f, "found definition for import {name} with type {expected:?} but found type {found:?}")
+                write!(
+                    f,
+                    "expected definition for import {name} with type {expected:?} but found type {found:?}"
+                )
             }
             Self::FuncTypeMismatch {
                 name,
@@ -428,6 +442,14 @@ impl<'a, T> Debug for DebugHostFuncEntity<'a, T> {
     }
 }
 
+/// A fallback resolver for imports that are not explicitly defined in a [`Linker`].
+///
+/// # Note
+///
+/// This wrapper exists to make types a little easier to read.
+type UnknownImportsResolver =
+    dyn Fn(&str, &str, ExternType) -> Option<Extern> + Send + Sync + 'static;
+
 /// A linker used to define module imports and instantiate module instances.
 pub struct Linker<T> {
     /// The underlying [`Engine`] for the [`Linker`].
@@ -441,6 +463,8 @@ pub struct Linker<T> {
     strings: StringInterner,
     /// Stores the definitions given their names.
     definitions: BTreeMap<ImportKey, Definition<T>>,
+    /// Resolves imports that have no definition in `definitions` if any.
+    unknown_imports: Option<Arc<UnknownImportsResolver>>,
 }
 
 impl<T> Debug for Linker<T> {
@@ -448,6 +472,7 @@ impl<T> Debug for Linker<T> {
         f.debug_struct("Linker")
             .field("strings", &self.strings)
             .field("definitions", &DebugDefinitions::new(self))
+            .field("unknown_imports", &self.unknown_imports.is_some())
             .finish()
     }
 }
@@ -458,6 +483,7 @@ impl<T> Clone for Linker<T> {
             engine: self.engine.clone(),
             strings: self.strings.clone(),
             definitions: self.definitions.clone(),
+            unknown_imports: self.unknown_imports.clone(),
         }
     }
 }
@@ -475,6 +501,7 @@ impl<T> Linker<T> {
             engine: engine.clone(),
             strings: StringInterner::default(),
             definitions: BTreeMap::default(),
+            unknown_imports: None,
         }
     }
 
@@ -499,6 +526,96 @@ impl<T> Linker<T> {
         Ok(self)
     }
 
+    /// Defines all exports of `instance` under the `module` namespace in this [`Linker`].
+    ///
+    /// This allows instances of other [`Module`]s to import the exports of `instance`
+    /// via `module` as their import module name.
+    ///
+    /// # Note
+    ///
+    /// No items are defined if any of the exports of `instance` collides with
+    /// an existing definition of this [`Linker`].
+    ///
+    /// # Panics
+    ///
+    /// If the [`Engine`] of this [`Linker`] and the [`Engine`] of `context` are not the same.
+    ///
+    /// # Errors
+    ///
+    /// If there already is a definition under the same name as any of the exports of `instance`.
+    pub fn instance(
+        &mut self,
+        context: impl AsContext<UserState = T>,
+        module: &str,
+        instance: Instance,
+    ) -> Result<&mut Self, LinkerError> {
+        assert!(Engine::same(
+            context.as_context().store.engine(),
+            self.engine()
+        ));
+        let exports = instance
+            .exports(context.as_context())
+            .map(|export| (self.import_key(module, export.name()), export.into_extern()))
+            .collect::<Vec<_>>();
+        if let Some((key, _)) = exports
+            .iter()
+            .find(|(key, _)| self.definitions.contains_key(key))
+        {
+            return Err(self.duplicate_definition(*key));
+        }
+        for (key, item) in exports {
+            self.insert(key, Definition::Extern(item))?;
+        }
+        Ok(self)
+    }
+
+    /// Defines the existing definition `module::name` additionally as `as_module::as_name`.
+    ///
+    /// This allows to resolve imports of [`Module`]s with mismatching import names
+    /// to existing definitions of this [`Linker`] without defining them again.
+    ///
+    /// # Errors
+    ///
+    /// - If there is no definition for `module::name` in this [`Linker`].
+    /// - If there already is a definition for `as_module::as_name` in this [`Linker`].
+    pub fn alias(
+        &mut self,
+        module: &str,
+        name: &str,
+        as_module: &str,
+        as_name: &str,
+    ) -> Result<&mut Self, LinkerError> {
+        let Some(item) = self.lookup_definition(module, name).cloned() else {
+            return Err(LinkerError::UndefinedAliasTarget {
+                name: ImportName::new(module, name),
+            });
+        };
+        let key = self.import_key(as_module, as_name);
+        self.insert(key, item)?;
+        Ok(self)
+    }
+
+    /// Defines a fallback `resolver` for imports that are not defined in this [`Linker`].
+    ///
+    /// Upon instantiation the `resolver` is queried with the module name, item name
+    /// and expected [`ExternType`] of every import that has no explicit definition.
+    /// This allows to lazily provide definitions, e.g. to emulate dynamic linking.
+    ///
+    /// # Note
+    ///
+    /// - Explicit definitions of the [`Linker`] always take precedence over `resolver`.
+    /// - If `resolver` returns `None` the import is treated as missing.
+    /// - The [`Extern`] returned by `resolver` must match the expected [`ExternType`],
+    ///   otherwise instantiation fails with a type mismatch error.
+    /// - Defining a new `resolver` replaces the previous one.
+    pub fn define_unknown_imports_with(
+        &mut self,
+        resolver: impl Fn(&str, &str, ExternType) -> Option<Extern> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.unknown_imports = Some(Arc::from(Box::new(resolver) as Box<UnknownImportsResolver>));
+        self
+    }
+
     /// Creates a new named [`Func::new`]-style host [`Func`] for this [`Linker`].
     ///
     /// For more information see [`Linker::func_wrap`].
@@ -578,11 +695,7 @@ impl<T> Linker<T> {
     fn insert(&mut self, key: ImportKey, item: Definition<T>) -> Result<(), LinkerError> {
         match self.definitions.entry(key) {
             Entry::Occupied(_) => {
-                let (module_name, field_name) = self
-                    .resolve_import_key(key)
-                    .unwrap_or_else(|| panic!("encountered missing import names for key {key:?}"));
-                let import_name = ImportName::new(module_name, field_name);
-                return Err(LinkerError::DuplicateDefinition { import_name });
+                return Err(self.duplicate_definition(key));
             }
             Entry::Vacant(v) => {
                 v.insert(item);
@@ -591,6 +704,15 @@ impl<T> Linker<T> {
         Ok(())
     }
 
+    /// Returns a [`LinkerError::DuplicateDefinition`] for the import key.
+    fn duplicate_definition(&self, key: ImportKey) -> LinkerError {
+        let (module_name, field_name) = self
+            .resolve_import_key(key)
+            .unwrap_or_else(|| panic!("encountered missing import names for key {key:?}"));
+        let import_name = ImportName::new(module_name, field_name);
+        LinkerError::DuplicateDefinition { import_name }
+    }
+
     /// Looks up a defined [`Extern`] by name in this [`Linker`].
     ///
     /// - Returns `None` if this name was not previously defined in this [`Linker`].
@@ -628,6 +750,13 @@ impl<T> Linker<T> {
             context.as_context().store.engine(),
             self.engine()
         ));
+        self.lookup_definition(module, name)
+    }
+
+    /// Looks up a [`Definition`] by name in this [`Linker`].
+    ///
+    /// Returns `None` if this name was not previously defined in this [`Linker`].
+    fn lookup_definition(&self, module: &str, name: &str) -> Option<&Definition<T>> {
         let key = ImportKey {
             module: self.strings.get(module)?,
             name: self.strings.get(name)?,
@@ -635,6 +764,14 @@ impl<T> Linker<T> {
         self.definitions.get(&key)
     }
 
+    /// Queries the fallback resolver of the [`Linker`] for the `import` if any.
+    ///
+    /// Returns `None` if there is no fallback resolver or if it cannot resolve the `import`.
+    fn resolve_unknown_import(&self, import: &ImportType) -> Option<Definition<T>> {
+        let resolver = self.unknown_imports.as_deref()?;
+        resolver(import.module(), import.name(), import.ty().clone()).map(Definition::Extern)
+    }
+
     /// Instantiates the given [`Module`] using the definitions in the [`Linker`].
     ///
     /// # Panics
@@ -660,6 +797,84 @@ impl<T> Linker<T> {
         module.instantiate(context, externals)
     }
 
+    /// Resolves and type checks the imports of the given [`Module`] once for many instantiations.
+    ///
+    /// The returned [`LinkedModule`] can be instantiated many times via [`LinkedModule::instantiate`]
+    /// without looking up the definitions of the [`Linker`] again. This mirrors Wasmtime's
+    /// `InstancePre` in contrast to `wasmi`'s [`InstancePre`] which is not yet started.
+    ///
+    /// # Note
+    ///
+    /// - Imports resolved to host functions defined via the [`Linker`] are type checked once
+    ///   and the resulting [`LinkedModule`] can be instantiated in any [`Store`].
+    /// - Imports resolved to [`Extern`] items are owned by a particular [`Store`] and thus are
+    ///   type checked upon every instantiation. Those can only be instantiated in their [`Store`].
+    ///
+    /// # Panics
+    ///
+    /// If the [`Engine`] of the [`Linker`] and the [`Module`] are not the same.
+    ///
+    /// # Errors
+    ///
+    /// - If the linker does not define imports of the [`Module`].
+    /// - If any imported host function does not satisfy its type requirements.
+    ///
+    /// [`Store`]: crate::Store
+    pub fn instantiate_pre(&self, module: &Module) -> Result<LinkedModule<T>, Error> {
+        assert!(Engine::same(self.engine(), module.engine()));
+        let mut prechecked = true;
+        let imports = module
+            .imports()
+            .map(|import| {
+                let definition = self.resolve_import_pre(&import)?;
+                prechecked &= matches!(definition, Definition::HostFunc(_));
+                Ok(definition)
+            })
+            .collect::<Result<Box<[Definition<T>]>, Error>>()?;
+        Ok(LinkedModule {
+            module: module.clone(),
+            imports: imports.into(),
+            prechecked,
+        })
+    }
+
+    /// Resolves a single [`Module`] import for [`Linker::instantiate_pre`].
+    ///
+    /// # Errors
+    ///
+    /// - If the import cannot be resolved.
+    /// - If the import resolves to a host function that does not satisfy its type.
+    fn resolve_import_pre(&self, import: &ImportType) -> Result<Definition<T>, Error> {
+        let resolved = self
+            .lookup_definition(import.module(), import.name())
+            .cloned()
+            .or_else(|| self.resolve_unknown_import(import))
+            .ok_or_else(|| LinkerError::missing_definition(import))?;
+        if let Definition::HostFunc(host_func) = &resolved {
+            let found_type = self
+                .engine()
+                .resolve_func_type(host_func.ty_dedup(), FuncType::clone);
+            match import.ty() {
+                ExternType::Func(expected_type) => {
+                    if &found_type != expected_type {
+                        return Err(Error::from(LinkerError::func_type_mismatch(
+                            import.import_name(),
+                            expected_type,
+                            &found_type,
+                        )));
+                    }
+                }
+                _ => {
+                    return Err(Error::from(LinkerError::invalid_type_definition(
+                        import,
+                        &ExternType::Func(found_type),
+                    )))
+                }
+            }
+        }
+        Ok(resolved)
+    }
+
     /// Processes a single [`Module`] import.
     ///
     /// # Panics
@@ -680,6 +895,8 @@ impl<T> Linker<T> {
         let field_name = import.name();
         let resolved = self
             .get_definition(context.as_context(), module_name, field_name)
+            .map(Cow::Borrowed)
+            .or_else(|| self.resolve_unknown_import(&import).map(Cow::Owned))
             .ok_or_else(|| LinkerError::missing_definition(&import))?;
         let invalid_type = || LinkerError::invalid_type_definition(&import, &resolved.ty(&context));
         match import.ty() {
@@ -709,7 +926,7 @@ impl<T> Linker<T> {
                     .ok_or_else(invalid_type)?;
                 let found_type = table.dynamic_ty(context);
                 found_type.is_subtype_or_err(expected_type).map_err(|_| {
-                    LinkerError::table_type_mismatch(import_name, expected_type, &found_type)
+                    LinkerError::table_type_mismatch(import_name, &found_type, expected_type)
                 })?;
                 Ok(Extern::Table(table))
             }
@@ -721,7 +938,7 @@ impl<T> Linker<T> {
                     .ok_or_else(invalid_type)?;
                 let found_type = memory.dynamic_ty(context);
                 found_type.is_subtype_or_err(expected_type).map_err(|_| {
-                    LinkerError::invalid_memory_subtype(import_name, expected_type, &found_type)
+                    LinkerError::invalid_memory_subtype(import_name, &found_type, expected_type)
                 })?;
                 Ok(Extern::Memory(memory))
             }
@@ -745,12 +962,97 @@ impl<T> Linker<T> {
     }
 }
 
+/// A [`Module`] with imports resolved by a [`Linker`] that can be instantiated many times.
+///
+/// Created via [`Linker::instantiate_pre`].
+///
+/// # Note
+///
+/// Cloning a [`LinkedModule`] is cheap since its [`Module`] and resolved imports are shared.
+pub struct LinkedModule<T> {
+    /// The [`Module`] to instantiate.
+    module: Module,
+    /// The resolved definitions of the [`Module`] imports in their order.
+    imports: Arc<[Definition<T>]>,
+    /// Is `true` if all `imports` have been type checked upon creation.
+    prechecked: bool,
+}
+
+impl<T> Clone for LinkedModule<T> {
+    fn clone(&self) -> Self {
+        Self {
+            module: self.module.clone(),
+            imports: self.imports.clone(),
+            prechecked: self.prechecked,
+        }
+    }
+}
+
+impl<T> Debug for LinkedModule<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LinkedModule")
+            .field("module", &self.module)
+            .field("imports", &self.imports.len())
+            .field("prechecked", &self.prechecked)
+            .finish()
+    }
+}
+
+impl<T> LinkedModule<T> {
+    /// Returns the [`Module`] of the [`LinkedModule`].
+    pub fn module(&self) -> &Module {
+        &self.module
+    }
+
+    /// Instantiates the [`Module`] using the imports resolved upon creation.
+    ///
+    /// # Note
+    ///
+    /// Each instantiation allocates its own functions, tables, memories and globals
+    /// and initializes them with the element and data segments of the [`Module`].
+    ///
+    /// # Panics
+    ///
+    /// If the [`Engine`] of the [`LinkedModule`] and `context` are not the same.
+    ///
+    /// # Errors
+    ///
+    /// - If any resolved [`Extern`] import does not satisfy its type requirements.
+    /// - If the instantiation exceeds resource limits of the [`Store`].
+    ///
+    /// [`Store`]: crate::Store
+    pub fn instantiate(
+        &self,
+        mut context: impl AsContextMut<UserState = T>,
+    ) -> Result<InstancePre, Error> {
+        assert!(Engine::same(
+            self.module.engine(),
+            context.as_context().engine()
+        ));
+        let externals = self
+            .imports
+            .iter()
+            .map(|definition| match definition {
+                Definition::Extern(external) => *external,
+                Definition::HostFunc(_) => definition
+                    .as_func(&mut context)
+                    .map(Extern::Func)
+                    .expect("host function definitions are functions"),
+            })
+            .collect::<Vec<Extern>>();
+        match self.prechecked {
+            true => self.module.instantiate_prechecked(context, externals),
+            false => self.module.instantiate(context, externals),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use wasmi_core::ValueType;
 
     use super::*;
-    use crate::Store;
+    use crate::{Memory, Store};
 
     struct HostState {
         a: i32,
@@ -851,4 +1153,245 @@ mod tests {
         wasm_set_b.call(&mut store, 200).unwrap();
         assert_eq!(wasm_get_b.call(&mut store, ()).unwrap(), 200);
     }
+
+    #[test]
+    fn linker_instance_links_modules() {
+        let engine = Engine::default();
+        let mut store = <Store<()>>::new(&engine, ());
+        let mut linker = <Linker<()>>::new(&engine);
+        let wasm_a = wat::parse_str(
+            r#"
+                (module
+                    (memory (export "mem") 1)
+                    (func (export "add") (param i32 i32) (result i32)
+                        (i32.add (local.get 0) (local.get 1))
+                    )
+                )
+            "#,
+        )
+        .unwrap();
+        let wasm_b = wat::parse_str(
+            r#"
+                (module
+                    (import "a" "add" (func $add (param i32 i32) (result i32)))
+                    (import "a" "mem" (memory 1))
+                    (func (export "add_and_store") (param i32 i32) (result i32)
+                        (i32.store (i32.const 0) (call $add (local.get 0) (local.get 1)))
+                        (i32.load (i32.const 0))
+                    )
+                )
+            "#,
+        )
+        .unwrap();
+        let module_a = Module::new(&engine, &mut &wasm_a[..]).unwrap();
+        let instance_a = linker
+            .instantiate(&mut store, &module_a)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        linker.instance(&store, "a", instance_a).unwrap();
+        let module_b = Module::new(&engine, &mut &wasm_b[..]).unwrap();
+        let instance_b = linker
+            .instantiate(&mut store, &module_b)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let add_and_store = instance_b
+            .get_typed_func::<(i32, i32), i32>(&store, "add_and_store")
+            .unwrap();
+        assert_eq!(add_and_store.call(&mut store, (1, 2)).unwrap(), 3);
+        // Module `b` stored its result into the shared linear memory of module `a`.
+        let memory = instance_a.get_memory(&store, "mem").unwrap();
+        assert_eq!(memory.data(&store)[0..4], 3_i32.to_le_bytes());
+        // Defining the exports of the same namespace twice is an error.
+        let error = linker.instance(&store, "a", instance_a).unwrap_err();
+        assert!(matches!(error, LinkerError::DuplicateDefinition { .. }));
+    }
+
+    #[test]
+    fn linker_alias_resolves_imports() {
+        let engine = Engine::default();
+        let mut store = <Store<()>>::new(&engine, ());
+        let mut linker = <Linker<()>>::new(&engine);
+        linker
+            .func_wrap("host", "print", |value: i32| value + 1)
+            .unwrap();
+        linker.alias("host", "print", "env", "log").unwrap();
+        let wasm = wat::parse_str(
+            r#"
+                (module
+                    (import "env" "log" (func $log (param i32) (result i32)))
+                    (func (export "run") (param i32) (result i32)
+                        (call $log (local.get 0))
+                    )
+                )
+            "#,
+        )
+        .unwrap();
+        let module = Module::new(&engine, &mut &wasm[..]).unwrap();
+        let instance = linker
+            .instantiate(&mut store, &module)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let run = instance.get_typed_func::<i32, i32>(&store, "run").unwrap();
+        assert_eq!(run.call(&mut store, 41).unwrap(), 42);
+        // Aliasing a missing definition is an error.
+        let error = linker.alias("host", "missing", "env", "x").unwrap_err();
+        assert!(matches!(error, LinkerError::UndefinedAliasTarget { .. }));
+        assert!(linker.get(&store, "env", "x").is_none());
+        // Aliases must not collide with existing definitions.
+        let error = linker.alias("env", "log", "host", "print").unwrap_err();
+        assert!(matches!(error, LinkerError::DuplicateDefinition { .. }));
+    }
+
+    #[test]
+    fn linker_instance_rejects_collisions_atomically() {
+        let engine = Engine::default();
+        let mut store = <Store<()>>::new(&engine, ());
+        let mut linker = <Linker<()>>::new(&engine);
+        let wasm = wat::parse_str(
+            r#"
+                (module
+                    (func (export "f"))
+                    (func (export "g"))
+                )
+            "#,
+        )
+        .unwrap();
+        let module = Module::new(&engine, &mut &wasm[..]).unwrap();
+        let instance = linker
+            .instantiate(&mut store, &module)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let host_g = Func::wrap(&mut store, || ());
+        linker.define("m", "g", host_g).unwrap();
+        let error = linker.instance(&store, "m", instance).unwrap_err();
+        assert!(matches!(error, LinkerError::DuplicateDefinition { .. }));
+        // No export of the colliding instance has been defined.
+        assert!(linker.get(&store, "m", "f").is_none());
+        linker.instance(&store, "n", instance).unwrap();
+        assert!(linker.get(&store, "n", "f").is_some());
+        assert!(linker.get(&store, "n", "g").is_some());
+    }
+
+    #[test]
+    fn linker_resolves_unknown_imports() {
+        let engine = Engine::default();
+        let mut store = <Store<()>>::new(&engine, ());
+        let host_add = Func::wrap(&mut store, |a: i32, b: i32| a + b);
+        let mut linker = <Linker<()>>::new(&engine);
+        linker.define_unknown_imports_with(move |module, name, ty| match (module, name) {
+            ("env", "add") => {
+                assert!(matches!(ty, ExternType::Func(_)));
+                Some(Extern::Func(host_add))
+            }
+            _ => None,
+        });
+        let wat = r#"
+                (module
+                    (import "env" "add" (func $add (param i32 i32) (result i32)))
+                    (func (export "wasm_add") (param i32 i32) (result i32)
+                        (call $add (local.get 0) (local.get 1))
+                    )
+                )
+            "#;
+        let wasm = wat::parse_str(wat).unwrap();
+        let module = Module::new(&engine, &mut &wasm[..]).unwrap();
+        let instance = linker
+            .instantiate(&mut store, &module)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let wasm_add = instance
+            .get_typed_func::<(i32, i32), i32>(&store, "wasm_add")
+            .unwrap();
+        assert_eq!(wasm_add.call(&mut store, (1, 2)).unwrap(), 3);
+    }
+
+    #[test]
+    fn linker_rejects_mistyped_unknown_imports() {
+        let engine = Engine::default();
+        let mut store = <Store<()>>::new(&engine, ());
+        let host_neg = Func::wrap(&mut store, |a: i32| -a);
+        let mut linker = <Linker<()>>::new(&engine);
+        linker.define_unknown_imports_with(move |_module, _name, _ty| Some(Extern::Func(host_neg)));
+        let wat = r#"
+                (module
+                    (import "env" "add" (func $add (param i32 i32) (result i32)))
+                )
+            "#;
+        let wasm = wat::parse_str(wat).unwrap();
+        let module = Module::new(&engine, &mut &wasm[..]).unwrap();
+        let error = linker.instantiate(&mut store, &module).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            crate::error::ErrorKind::Linker(LinkerError::FuncTypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn linker_reports_import_func_type_mismatch() {
+        let engine = Engine::default();
+        let mut store = <Store<()>>::new(&engine, ());
+        let mut linker = <Linker<()>>::new(&engine);
+        linker
+            .func_wrap("env", "add", |lhs: i64, rhs: i64| lhs + rhs)
+            .unwrap();
+        let wat = r#"
+                (module
+                    (import "env" "add" (func $add (param i32 i32) (result i32)))
+                )
+            "#;
+        let wasm = wat::parse_str(wat).unwrap();
+        let module = Module::new(&engine, &mut &wasm[..]).unwrap();
+        let error = linker.instantiate(&mut store, &module).unwrap_err();
+        let crate::error::ErrorKind::Linker(LinkerError::FuncTypeMismatch {
+            name,
+            expected,
+            found,
+        }) = error.kind()
+        else {
+            panic!("expected a function type mismatch but found: {error:?}")
+        };
+        assert_eq!(name.module(), "env");
+        assert_eq!(name.name(), "add");
+        assert_eq!(
+            expected,
+            &FuncType::new([ValueType::I32; 2], [ValueType::I32])
+        );
+        assert_eq!(found, &FuncType::new([ValueType::I64; 2], [ValueType::I64]));
+    }
+
+    #[test]
+    fn linker_reports_import_memory_type_mismatch() {
+        let engine = Engine::default();
+        let mut store = <Store<()>>::new(&engine, ());
+        let memory = Memory::new(&mut store, MemoryType::new(1, Some(4)).unwrap()).unwrap();
+        let mut linker = <Linker<()>>::new(&engine);
+        linker.define("env", "memory", memory).unwrap();
+        let wat = r#"
+                (module
+                    (import "env" "memory" (memory 2 3))
+                )
+            "#;
+        let wasm = wat::parse_str(wat).unwrap();
+        let module = Module::new(&engine, &mut &wasm[..]).unwrap();
+        let error = linker.instantiate(&mut store, &module).unwrap_err();
+        let crate::error::ErrorKind::Linker(LinkerError::InvalidMemorySubtype { name, ty, other }) =
+            error.kind()
+        else {
+            panic!("expected a memory type mismatch but found: {error:?}")
+        };
+        assert_eq!(name.module(), "env");
+        assert_eq!(name.name(), "memory");
+        // The provided memory type is not a subtype of the expected memory type.
+        assert_eq!(ty, &MemoryType::new(1, Some(4)).unwrap());
+        assert_eq!(other, &MemoryType::new(2, Some(3)).unwrap());
+        assert_eq!(
+            error.to_string(),
+            format!("import env::memory: memory type {ty:?} is not a subtype of {other:?}"),
+        );
+    }
 }
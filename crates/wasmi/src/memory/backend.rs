@@ -0,0 +1,83 @@
+use super::{buffer::ByteBuffer, MemoryError};
+use alloc::boxed::Box;
+use core::fmt;
+
+/// The byte buffer backing the bytes of a linear memory.
+///
+/// Created by a [`MemoryBackend`] upon allocation of a linear memory.
+pub trait MemoryBuffer: fmt::Debug + Send + Sync {
+    /// Grows the byte buffer to `new_len` bytes.
+    ///
+    /// The newly added bytes must be initialized to zero.
+    ///
+    /// # Note
+    ///
+    /// - `new_len` is never smaller than the current length of the byte buffer.
+    /// - `new_len` never exceeds the `maximum_len` given upon allocation if any.
+    ///
+    /// # Errors
+    ///
+    /// If the byte buffer cannot be grown to `new_len` bytes.
+    /// In this case the byte buffer must remain unchanged.
+    fn grow(&mut self, new_len: usize) -> Result<(), MemoryError>;
+
+    /// Returns a shared slice to the bytes of the byte buffer.
+    fn data(&self) -> &[u8];
+
+    /// Returns an exclusive slice to the bytes of the byte buffer.
+    fn data_mut(&mut self) -> &mut [u8];
+}
+
+/// Allocates the byte buffers that back the linear memories of a [`Store`].
+///
+/// Configured via [`Config::memory_backend`].
+///
+/// [`Store`]: crate::Store
+/// [`Config::memory_backend`]: crate::Config::memory_backend
+pub trait MemoryBackend: fmt::Debug + Send + Sync {
+    /// Allocates a new zero-initialized byte buffer with `initial_len` bytes.
+    ///
+    /// The byte buffer is never going to be grown beyond `maximum_len` bytes if any.
+    ///
+    /// # Errors
+    ///
+    /// If the byte buffer cannot be allocated.
+    fn alloc(
+        &self,
+        initial_len: usize,
+        maximum_len: Option<usize>,
+    ) -> Result<Box<dyn MemoryBuffer>, MemoryError>;
+}
+
+/// The default [`MemoryBackend`] backing linear memories with a `Vec<u8>`.
+///
+/// # Note
+///
+/// This is available on all platforms including `no_std` ones.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct VecMemoryBackend;
+
+impl MemoryBackend for VecMemoryBackend {
+    fn alloc(
+        &self,
+        initial_len: usize,
+        _maximum_len: Option<usize>,
+    ) -> Result<Box<dyn MemoryBuffer>, MemoryError> {
+        Ok(Box::new(ByteBuffer::new(initial_len)))
+    }
+}
+
+impl MemoryBuffer for ByteBuffer {
+    fn grow(&mut self, new_len: usize) -> Result<(), MemoryError> {
+        ByteBuffer::grow(self, new_len);
+        Ok(())
+    }
+
+    fn data(&self) -> &[u8] {
+        ByteBuffer::data(self)
+    }
+
+    fn data_mut(&mut self) -> &mut [u8] {
+        ByteBuffer::data_mut(self)
+    }
+}
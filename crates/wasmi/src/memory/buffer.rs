@@ -27,6 +27,8 @@ impl ByteBuffer {
 
     /// Grows the byte buffer to the given `new_size`.
     ///
+    /// The newly added bytes are zero-initialized.
+    ///
     /// # Panics
     ///
     /// If the current size of the [`ByteBuffer`] is larger than `new_size`.
@@ -0,0 +1,153 @@
+use super::{MemoryBackend, MemoryBuffer, MemoryError};
+use alloc::boxed::Box;
+use core::{ptr::NonNull, slice};
+
+/// The default length of the inaccessible guard region in bytes.
+const DEFAULT_GUARD_LEN: usize = 1 << 31;
+
+/// The flags used to reserve the virtual memory of a [`GuardPagesBuffer`].
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const RESERVE_FLAGS: libc::c_int = libc::MAP_PRIVATE | libc::MAP_ANONYMOUS | libc::MAP_NORESERVE;
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+const RESERVE_FLAGS: libc::c_int = libc::MAP_PRIVATE | libc::MAP_ANONYMOUS;
+
+/// A [`MemoryBackend`] that backs linear memories with OS provided virtual memory.
+///
+/// Upon allocation the maximum size of the linear memory is reserved up front,
+/// followed by an inaccessible guard region. Only the bytes in use by the linear
+/// memory are accessible; growing a linear memory never moves its bytes.
+///
+/// # Note
+///
+/// - Accesses into the guard region fault in hardware.
+/// - `wasmi` still performs explicit bounds checks for all linear memory accesses.
+/// - Linear memories without a representable maximum size cannot be allocated.
+#[derive(Debug, Copy, Clone)]
+pub struct GuardPagesMemoryBackend {
+    /// The length of the inaccessible guard region in bytes.
+    guard_len: usize,
+}
+
+impl Default for GuardPagesMemoryBackend {
+    fn default() -> Self {
+        Self::new(DEFAULT_GUARD_LEN)
+    }
+}
+
+impl GuardPagesMemoryBackend {
+    /// Creates a new [`GuardPagesMemoryBackend`] with `guard_len` bytes of guard region.
+    ///
+    /// By default 2 GiB of guard region are reserved.
+    pub fn new(guard_len: usize) -> Self {
+        Self { guard_len }
+    }
+}
+
+impl MemoryBackend for GuardPagesMemoryBackend {
+    fn alloc(
+        &self,
+        initial_len: usize,
+        maximum_len: Option<usize>,
+    ) -> Result<Box<dyn MemoryBuffer>, MemoryError> {
+        let reserved_len = maximum_len
+            .and_then(|maximum_len| maximum_len.checked_add(self.guard_len))
+            .ok_or(MemoryError::OutOfBoundsAllocation)?;
+        let mut buffer = GuardPagesBuffer::reserve(reserved_len)?;
+        buffer.grow(initial_len)?;
+        Ok(Box::new(buffer))
+    }
+}
+
+/// A byte buffer in a reserved region of OS provided virtual memory.
+#[derive(Debug)]
+struct GuardPagesBuffer {
+    /// The start of the reserved virtual memory region.
+    ptr: NonNull<u8>,
+    /// The number of accessible bytes.
+    len: usize,
+    /// The number of reserved bytes.
+    reserved_len: usize,
+}
+
+// Safety: `GuardPagesBuffer` exclusively owns its reserved virtual memory
+//         region and only hands out references to it that are bound to its
+//         own lifetime, just like a `Vec<u8>`.
+unsafe impl Send for GuardPagesBuffer {}
+unsafe impl Sync for GuardPagesBuffer {}
+
+impl GuardPagesBuffer {
+    /// Reserves `reserved_len` bytes of inaccessible virtual memory.
+    fn reserve(reserved_len: usize) -> Result<Self, MemoryError> {
+        if reserved_len == 0 {
+            return Err(MemoryError::OutOfBoundsAllocation);
+        }
+        // Safety: we request a fresh anonymous mapping that does not alias any memory.
+        let ptr = unsafe {
+            libc::mmap(
+                core::ptr::null_mut(),
+                reserved_len,
+                libc::PROT_NONE,
+                RESERVE_FLAGS,
+                -1,
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(MemoryError::OutOfBoundsAllocation);
+        }
+        let ptr = NonNull::new(ptr.cast::<u8>()).ok_or(MemoryError::OutOfBoundsAllocation)?;
+        Ok(Self {
+            ptr,
+            len: 0,
+            reserved_len,
+        })
+    }
+}
+
+impl MemoryBuffer for GuardPagesBuffer {
+    fn grow(&mut self, new_len: usize) -> Result<(), MemoryError> {
+        assert!(new_len >= self.len);
+        if new_len > self.reserved_len {
+            return Err(MemoryError::OutOfBoundsGrowth);
+        }
+        if new_len == self.len {
+            return Ok(());
+        }
+        // Note: the bytes in `[ptr + len, ptr + new_len)` are still zero-initialized by `mmap`
+        //       since a `GuardPagesBuffer` never shrinks and thus they have never been accessible.
+        //
+        // Safety: `[ptr + len, ptr + new_len)` lies within the reserved region and is
+        //         page aligned since linear memories grow in multiples of 64 KiB.
+        let result = unsafe {
+            libc::mprotect(
+                self.ptr.as_ptr().add(self.len).cast(),
+                new_len - self.len,
+                libc::PROT_READ | libc::PROT_WRITE,
+            )
+        };
+        if result != 0 {
+            return Err(MemoryError::OutOfBoundsGrowth);
+        }
+        self.len = new_len;
+        Ok(())
+    }
+
+    fn data(&self) -> &[u8] {
+        // Safety: the first `len` bytes are accessible and zero-initialized by `mmap`.
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    fn data_mut(&mut self) -> &mut [u8] {
+        // Safety: the first `len` bytes are accessible and zero-initialized by `mmap`.
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl Drop for GuardPagesBuffer {
+    fn drop(&mut self) {
+        // Safety: the region has been reserved by `mmap` and is no longer referenced.
+        unsafe {
+            libc::munmap(self.ptr.as_ptr().cast(), self.reserved_len);
+        }
+    }
+}
@@ -1,17 +1,25 @@
+mod backend;
 mod buffer;
 mod data;
 mod error;
+#[cfg(all(feature = "guard-pages", unix))]
+mod guard_pages;
+mod snapshot;
 
 #[cfg(test)]
 mod tests;
 
-use self::buffer::ByteBuffer;
+#[cfg(all(feature = "guard-pages", unix))]
+pub use self::guard_pages::GuardPagesMemoryBackend;
 pub use self::{
+    backend::{MemoryBackend, MemoryBuffer, VecMemoryBackend},
     data::{DataSegment, DataSegmentEntity, DataSegmentIdx},
     error::MemoryError,
+    snapshot::MemorySnapshot,
 };
 use super::{AsContext, AsContextMut, StoreContext, StoreContextMut, Stored};
 use crate::{error::EntityGrowError, store::ResourceLimiterRef};
+use alloc::boxed::Box;
 use wasmi_arena::ArenaIndex;
 use wasmi_core::{Pages, TrapCode};
 
@@ -121,15 +129,18 @@ impl MemoryType {
 /// A linear memory entity.
 #[derive(Debug)]
 pub struct MemoryEntity {
-    bytes: ByteBuffer,
+    bytes: Box<dyn MemoryBuffer>,
     memory_type: MemoryType,
     current_pages: Pages,
 }
 
 impl MemoryEntity {
     /// Creates a new memory entity with the given memory type.
+    ///
+    /// The bytes of the memory entity are allocated via the given [`MemoryBackend`].
     pub fn new(
         memory_type: MemoryType,
+        backend: &dyn MemoryBackend,
         limiter: &mut ResourceLimiterRef<'_>,
     ) -> Result<Self, MemoryError> {
         let initial_pages = memory_type.initial_pages();
@@ -146,19 +157,25 @@ impl MemoryEntity {
             }
         }
 
-        if let Some(initial_len) = initial_len {
-            let memory = Self {
-                bytes: ByteBuffer::new(initial_len),
-                memory_type,
-                current_pages: initial_pages,
-            };
-            Ok(memory)
-        } else {
-            let err = MemoryError::OutOfBoundsAllocation;
-            if let Some(limiter) = limiter.as_resource_limiter() {
-                limiter.memory_grow_failed(&err)
+        let bytes = match initial_len {
+            Some(initial_len) => backend.alloc(initial_len, maximum_len),
+            None => Err(MemoryError::OutOfBoundsAllocation),
+        };
+        match bytes {
+            Ok(bytes) => {
+                let memory = Self {
+                    bytes,
+                    memory_type,
+                    current_pages: initial_pages,
+                };
+                Ok(memory)
+            }
+            Err(err) => {
+                if let Some(limiter) = limiter.as_resource_limiter() {
+                    limiter.memory_grow_failed(&err)
+                }
+                Err(err)
             }
-            Err(err)
         }
     }
 
@@ -229,9 +246,10 @@ impl MemoryEntity {
                 if let Some(new_size) = new_pages.to_bytes() {
                     // At this point it is okay to grow the underlying virtual memory
                     // by the given amount of additional pages.
-                    self.bytes.grow(new_size);
-                    self.current_pages = new_pages;
-                    ret = Ok(current_pages)
+                    if self.bytes.grow(new_size).is_ok() {
+                        self.current_pages = new_pages;
+                        ret = Ok(current_pages)
+                    }
                 }
             }
         }
@@ -246,6 +264,73 @@ impl MemoryEntity {
         ret
     }
 
+    /// Returns a [`MemorySnapshot`] of the current size and contents of the linear memory.
+    pub fn snapshot(&self) -> MemorySnapshot {
+        MemorySnapshot::new(self.current_pages(), self.data())
+    }
+
+    /// Restores the size and contents of the linear memory from the `snapshot`.
+    ///
+    /// The linear memory is grown or shrunk to the size of the `snapshot` as needed.
+    /// Shrinking reallocates the bytes of the linear memory via the given [`MemoryBackend`].
+    /// Both are reported to the [`ResourceLimiter`] via [`ResourceLimiter::memory_growing`].
+    ///
+    /// # Errors
+    ///
+    /// - If the `snapshot` size is less than the minimum size of the linear memory.
+    /// - If the linear memory cannot be grown to the size of the `snapshot`.
+    /// - If the linear memory cannot be reallocated with the size of the `snapshot`.
+    /// - If the [`ResourceLimiter`] denies the reallocation.
+    ///
+    /// The linear memory remains unchanged upon errors.
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    /// [`ResourceLimiter::memory_growing`]: crate::ResourceLimiter::memory_growing
+    pub fn restore(
+        &mut self,
+        snapshot: &MemorySnapshot,
+        backend: &dyn MemoryBackend,
+        limiter: &mut ResourceLimiterRef<'_>,
+    ) -> Result<(), MemoryError> {
+        let pages = snapshot.pages();
+        if pages < self.ty().initial_pages() {
+            return Err(MemoryError::OutOfBoundsGrowth);
+        }
+        match pages.checked_sub(self.current_pages()) {
+            Some(additional) => {
+                self.grow(additional, limiter)
+                    .map_err(|_| MemoryError::OutOfBoundsGrowth)?;
+            }
+            None => {
+                // Linear memories cannot shrink in place so we reallocate instead.
+                //
+                // Note: this also guarantees that bytes beyond the size of the `snapshot`
+                //       are zero-initialized again once the linear memory grows.
+                let maximum_pages = self.ty().maximum_pages().unwrap_or_else(Pages::max);
+                let maximum_size = maximum_pages.to_bytes();
+                let desired_size = snapshot.data().len();
+                if let Some(limiter) = limiter.as_resource_limiter() {
+                    let current_size = self.current_pages().to_bytes().unwrap_or(usize::MAX);
+                    if !limiter.memory_growing(current_size, desired_size, maximum_size)? {
+                        return Err(MemoryError::OutOfBoundsAllocation);
+                    }
+                }
+                match backend.alloc(desired_size, maximum_size) {
+                    Ok(bytes) => self.bytes = bytes,
+                    Err(err) => {
+                        if let Some(limiter) = limiter.as_resource_limiter() {
+                            limiter.memory_grow_failed(&err)
+                        }
+                        return Err(err);
+                    }
+                }
+                self.current_pages = pages;
+            }
+        }
+        self.data_mut().copy_from_slice(snapshot.data());
+        Ok(())
+    }
+
     /// Returns a shared slice to the bytes underlying to the byte buffer.
     pub fn data(&self) -> &[u8] {
         self.bytes.data()
@@ -256,6 +341,34 @@ impl MemoryEntity {
         self.bytes.data_mut()
     }
 
+    /// Returns a shared slice to the bytes of `memory[offset..offset+len]`.
+    ///
+    /// # Errors
+    ///
+    /// If this operation accesses out of bounds linear memory.
+    pub fn read_slice(&self, offset: usize, len: usize) -> Result<&[u8], MemoryError> {
+        let end = offset
+            .checked_add(len)
+            .ok_or(MemoryError::OutOfBoundsAccess)?;
+        self.data()
+            .get(offset..end)
+            .ok_or(MemoryError::OutOfBoundsAccess)
+    }
+
+    /// Returns an exclusive slice to the bytes of `memory[offset..offset+len]`.
+    ///
+    /// # Errors
+    ///
+    /// If this operation accesses out of bounds linear memory.
+    pub fn write_slice(&mut self, offset: usize, len: usize) -> Result<&mut [u8], MemoryError> {
+        let end = offset
+            .checked_add(len)
+            .ok_or(MemoryError::OutOfBoundsAccess)?;
+        self.data_mut()
+            .get_mut(offset..end)
+            .ok_or(MemoryError::OutOfBoundsAccess)
+    }
+
     /// Reads `n` bytes from `memory[offset..offset+n]` into `buffer`
     /// where `n` is the length of `buffer`.
     ///
@@ -311,12 +424,11 @@ impl Memory {
     ///
     /// If more than [`u32::MAX`] much linear memory is allocated.
     pub fn new(mut ctx: impl AsContextMut, ty: MemoryType) -> Result<Self, MemoryError> {
-        let (inner, mut resource_limiter) = ctx
-            .as_context_mut()
-            .store
-            .store_inner_and_resource_limiter_ref();
+        let ctx = ctx.as_context_mut();
+        let backend = ctx.store.engine().config().get_memory_backend();
+        let (inner, mut resource_limiter) = ctx.store.store_inner_and_resource_limiter_ref();
 
-        let entity = MemoryEntity::new(ty, &mut resource_limiter)?;
+        let entity = MemoryEntity::new(ty, &*backend, &mut resource_limiter)?;
         let memory = inner.alloc_memory(entity);
         Ok(memory)
     }
@@ -382,14 +494,75 @@ impl Memory {
             .as_context_mut()
             .store
             .store_inner_and_resource_limiter_ref();
-        inner
+        let old_pages = inner
             .resolve_memory_mut(self)
             .grow(additional, &mut limiter)
-            .map_err(|_| MemoryError::OutOfBoundsGrowth)
+            .map_err(|_| MemoryError::OutOfBoundsGrowth)?;
+        if additional != Pages::from(0) {
+            let new_pages = inner.resolve_memory(self).current_pages();
+            inner.notify_memory_grow(*self, old_pages, new_pages);
+        }
+        Ok(old_pages)
+    }
+
+    /// Returns a [`MemorySnapshot`] of the current size and contents of the [`Memory`].
+    ///
+    /// The [`MemorySnapshot`] can later be used to roll back the [`Memory`] via [`Memory::restore`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Memory`].
+    pub fn snapshot(&self, ctx: impl AsContext) -> MemorySnapshot {
+        ctx.as_context().store.inner.resolve_memory(self).snapshot()
+    }
+
+    /// Restores the size and contents of the [`Memory`] from the `snapshot`.
+    ///
+    /// # Note
+    ///
+    /// - The [`Memory`] is grown or shrunk to the size of the `snapshot` as needed.
+    /// - Both growing and shrinking are reported to the [`ResourceLimiter`] of the [`Store`]
+    ///   via [`ResourceLimiter::memory_growing`]. When shrinking the `desired` size is less
+    ///   than the `current` size.
+    /// - The `snapshot` may have been taken from another [`Memory`].
+    ///
+    /// # Errors
+    ///
+    /// - If the `snapshot` size is less than the minimum size of the [`Memory`].
+    /// - If the [`Memory`] cannot be grown to the size of the `snapshot`.
+    /// - If the [`Memory`] cannot be reallocated with the size of the `snapshot`.
+    /// - If the [`ResourceLimiter`] of the [`Store`] denies the resizing.
+    ///
+    /// The [`Memory`] remains unchanged upon errors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Memory`].
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    /// [`ResourceLimiter::memory_growing`]: crate::ResourceLimiter::memory_growing
+    /// [`Store`]: crate::Store
+    pub fn restore(
+        &self,
+        mut ctx: impl AsContextMut,
+        snapshot: &MemorySnapshot,
+    ) -> Result<(), MemoryError> {
+        let ctx = ctx.as_context_mut();
+        let backend = ctx.store.engine().config().get_memory_backend();
+        let (inner, mut limiter) = ctx.store.store_inner_and_resource_limiter_ref();
+        inner
+            .resolve_memory_mut(self)
+            .restore(snapshot, &*backend, &mut limiter)
     }
 
     /// Returns a shared slice to the bytes underlying the [`Memory`].
     ///
+    /// # Note
+    ///
+    /// The returned slice borrows the store and thus cannot outlive
+    /// a subsequent mutation of the [`Memory`], e.g. via `memory.grow`,
+    /// which might reallocate the bytes underlying the [`Memory`].
+    ///
     /// # Panics
     ///
     /// Panics if `ctx` does not own this [`Memory`].
@@ -399,6 +572,12 @@ impl Memory {
 
     /// Returns an exclusive slice to the bytes underlying the [`Memory`].
     ///
+    /// # Note
+    ///
+    /// The returned slice borrows the store and thus cannot outlive
+    /// a subsequent mutation of the [`Memory`], e.g. via `memory.grow`,
+    /// which might reallocate the bytes underlying the [`Memory`].
+    ///
     /// # Panics
     ///
     /// Panics if `ctx` does not own this [`Memory`].
@@ -420,6 +599,64 @@ impl Memory {
         (memory.data_mut(), store)
     }
 
+    /// Returns a shared slice to the bytes of `memory[offset..offset+len]`.
+    ///
+    /// This is a zero-copy alternative to [`Memory::read`].
+    ///
+    /// # Note
+    ///
+    /// The returned slice borrows the store and thus cannot outlive
+    /// a subsequent mutation of the [`Memory`], e.g. via `memory.grow`.
+    ///
+    /// # Errors
+    ///
+    /// If this operation accesses out of bounds linear memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Memory`].
+    pub fn read_slice<'a, T: 'a>(
+        &self,
+        ctx: impl Into<StoreContext<'a, T>>,
+        offset: usize,
+        len: usize,
+    ) -> Result<&'a [u8], MemoryError> {
+        ctx.into()
+            .store
+            .inner
+            .resolve_memory(self)
+            .read_slice(offset, len)
+    }
+
+    /// Returns an exclusive slice to the bytes of `memory[offset..offset+len]`.
+    ///
+    /// This is a zero-copy alternative to [`Memory::write`].
+    ///
+    /// # Note
+    ///
+    /// The returned slice borrows the store and thus cannot outlive
+    /// a subsequent mutation of the [`Memory`], e.g. via `memory.grow`.
+    ///
+    /// # Errors
+    ///
+    /// If this operation accesses out of bounds linear memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Memory`].
+    pub fn write_slice<'a, T: 'a>(
+        &self,
+        ctx: impl Into<StoreContextMut<'a, T>>,
+        offset: usize,
+        len: usize,
+    ) -> Result<&'a mut [u8], MemoryError> {
+        ctx.into()
+            .store
+            .inner
+            .resolve_memory_mut(self)
+            .write_slice(offset, len)
+    }
+
     /// Reads `n` bytes from `memory[offset..offset+n]` into `buffer`
     /// where `n` is the length of `buffer`.
     ///
@@ -0,0 +1,43 @@
+use alloc::sync::Arc;
+use wasmi_core::Pages;
+
+/// A snapshot of the size and contents of a linear memory.
+///
+/// Created via [`Memory::snapshot`] and restored via [`Memory::restore`].
+///
+/// # Note
+///
+/// Cloning a [`MemorySnapshot`] is cheap since the captured bytes are shared
+/// between all clones. A [`MemorySnapshot`] is independent of the [`Memory`]
+/// it was taken from and thus stays valid if the [`Memory`] is mutated.
+///
+/// [`Memory`]: crate::Memory
+/// [`Memory::snapshot`]: crate::Memory::snapshot
+/// [`Memory::restore`]: crate::Memory::restore
+#[derive(Debug, Clone)]
+pub struct MemorySnapshot {
+    /// The amount of pages of the linear memory at the time of the snapshot.
+    pages: Pages,
+    /// The bytes of the linear memory at the time of the snapshot.
+    bytes: Arc<[u8]>,
+}
+
+impl MemorySnapshot {
+    /// Creates a new [`MemorySnapshot`] of `pages` with the given `bytes`.
+    pub(super) fn new(pages: Pages, bytes: &[u8]) -> Self {
+        Self {
+            pages,
+            bytes: bytes.into(),
+        }
+    }
+
+    /// Returns the amount of pages captured by the [`MemorySnapshot`].
+    pub fn pages(&self) -> Pages {
+        self.pages
+    }
+
+    /// Returns the bytes captured by the [`MemorySnapshot`].
+    pub fn data(&self) -> &[u8] {
+        &self.bytes[..]
+    }
+}
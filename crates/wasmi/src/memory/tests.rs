@@ -1,4 +1,5 @@
 use super::*;
+use crate::{Engine, Store};
 
 fn memory_type(minimum: u32, maximum: impl Into<Option<u32>>) -> MemoryType {
     MemoryType::new(minimum, maximum.into()).unwrap()
@@ -14,3 +15,87 @@ fn subtyping_works() {
     assert!(memory_type(0, 1).is_subtype_of(&memory_type(0, None)));
     assert!(!memory_type(0, None).is_subtype_of(&memory_type(0, 1)));
 }
+
+/// Creates a new [`Store`] with a [`Memory`] of `pages` pages.
+fn memory_setup(pages: u32) -> (Store<()>, Memory) {
+    let mut store = Store::new(&Engine::default(), ());
+    let memory = Memory::new(&mut store, memory_type(pages, None)).unwrap();
+    (store, memory)
+}
+
+#[test]
+fn write_and_read_slice_works() {
+    /// A struct-sized buffer as it could be marshalled by a host function.
+    #[derive(Debug, PartialEq, Eq)]
+    struct Header {
+        magic: u32,
+        len: u64,
+        flags: u16,
+    }
+    const SIZE: usize = 14;
+    impl Header {
+        fn to_bytes(&self) -> [u8; SIZE] {
+            let mut bytes = [0x00; SIZE];
+            bytes[0..4].copy_from_slice(&self.magic.to_le_bytes());
+            bytes[4..12].copy_from_slice(&self.len.to_le_bytes());
+            bytes[12..14].copy_from_slice(&self.flags.to_le_bytes());
+            bytes
+        }
+
+        fn from_bytes(bytes: &[u8]) -> Self {
+            Self {
+                magic: u32::from_le_bytes(bytes[0..4].try_into().unwrap()),
+                len: u64::from_le_bytes(bytes[4..12].try_into().unwrap()),
+                flags: u16::from_le_bytes(bytes[12..14].try_into().unwrap()),
+            }
+        }
+    }
+    let (mut store, memory) = memory_setup(1);
+    let header = Header {
+        magic: 0xCAFE_BABE,
+        len: 0x0123_4567_89AB_CDEF,
+        flags: 0b1010,
+    };
+    let offset = 100;
+    memory
+        .write_slice(&mut store, offset, SIZE)
+        .unwrap()
+        .copy_from_slice(&header.to_bytes());
+    let bytes = memory.read_slice(&store, offset, SIZE).unwrap();
+    assert_eq!(Header::from_bytes(bytes), header);
+    assert_eq!(&memory.data(&store)[offset..offset + SIZE], bytes);
+    // The slices are consistent with the copying APIs.
+    let mut buffer = [0x00; SIZE];
+    memory.read(&store, offset, &mut buffer).unwrap();
+    assert_eq!(buffer, header.to_bytes());
+}
+
+#[test]
+fn read_and_write_slice_out_of_bounds() {
+    let (mut store, memory) = memory_setup(1);
+    let len = memory.data(&store).len();
+    assert!(memory.read_slice(&store, 0, len).is_ok());
+    assert!(memory.read_slice(&store, len, 0).is_ok());
+    assert!(matches!(
+        memory.read_slice(&store, len - 1, 2),
+        Err(MemoryError::OutOfBoundsAccess)
+    ));
+    assert!(matches!(
+        memory.read_slice(&store, len + 1, 0),
+        Err(MemoryError::OutOfBoundsAccess)
+    ));
+    assert!(matches!(
+        memory.read_slice(&store, usize::MAX, 2),
+        Err(MemoryError::OutOfBoundsAccess)
+    ));
+    assert!(matches!(
+        memory.write_slice(&mut store, len - 1, 2),
+        Err(MemoryError::OutOfBoundsAccess)
+    ));
+    assert!(matches!(
+        memory.write_slice(&mut store, usize::MAX, 2),
+        Err(MemoryError::OutOfBoundsAccess)
+    ));
+    // Out of bounds accesses do not mutate the memory.
+    assert!(memory.data(&store).iter().all(|byte| *byte == 0x00));
+}
@@ -4,6 +4,7 @@ use super::{
     ConstExpr,
     DataSegment,
     ElementSegment,
+    ElementSegmentKind,
     ExternTypeIdx,
     FuncIdx,
     Global,
@@ -25,6 +26,7 @@ use crate::{
     TableType,
 };
 use alloc::{boxed::Box, collections::BTreeMap, sync::Arc, vec::Vec};
+use spin::Mutex;
 
 /// A builder for a WebAssembly [`Module`].
 #[derive(Debug)]
@@ -49,6 +51,7 @@ pub struct ModuleHeaderBuilder {
     pub compiled_funcs: Vec<CompiledFunc>,
     pub compiled_funcs_idx: BTreeMap<CompiledFunc, FuncIdx>,
     pub element_segments: Vec<ElementSegment>,
+    pub immutable_tables: Vec<bool>,
 }
 
 impl ModuleHeaderBuilder {
@@ -68,9 +71,41 @@ impl ModuleHeaderBuilder {
             compiled_funcs: Vec::new(),
             compiled_funcs_idx: BTreeMap::new(),
             element_segments: Vec::new(),
+            immutable_tables: Vec::new(),
         }
     }
 
+    /// Returns for every table if it might be immutable after instantiation.
+    ///
+    /// # Note
+    ///
+    /// A table is a candidate if it is neither imported nor exported since
+    /// otherwise the host could mutate it. Furthermore a candidate table must
+    /// be initialized by at least one active element segment since otherwise
+    /// none of its elements are statically known.
+    ///
+    /// The caller is responsible to rule out tables that are mutated by Wasm
+    /// instructions such as `table.set`.
+    pub fn immutable_table_candidates(&self) -> Vec<bool> {
+        let len_imported = self.imports.tables.len();
+        let mut candidates = vec![false; self.tables.len()];
+        for segment in &self.element_segments {
+            let ElementSegmentKind::Active(segment) = segment.kind() else {
+                continue;
+            };
+            let table = segment.table_index().into_u32() as usize;
+            if table >= len_imported {
+                candidates[table] = true;
+            }
+        }
+        for export in self.exports.values() {
+            if let ExternIdx::Table(table) = export {
+                candidates[table.into_u32() as usize] = false;
+            }
+        }
+        candidates
+    }
+
     /// Finishes construction of [`ModuleHeader`].
     pub fn finish(self) -> ModuleHeader {
         ModuleHeader {
@@ -88,6 +123,8 @@ impl ModuleHeaderBuilder {
                 compiled_funcs: self.compiled_funcs.into(),
                 compiled_funcs_idx: self.compiled_funcs_idx,
                 element_segments: self.element_segments.into(),
+                immutable_tables: self.immutable_tables.into(),
+                diagnostics: Mutex::new(Vec::new()),
             }),
         }
     }
@@ -394,6 +431,7 @@ impl ModuleBuilder {
     pub fn finish(self, engine: &Engine) -> Module {
         Module {
             engine: engine.clone(),
+            diagnostics: self.header.take_diagnostics(),
             header: self.header,
             data_segments: self.data_segments.into(),
         }
@@ -133,6 +133,11 @@ impl ElementSegment {
         self.ty
     }
 
+    /// Returns a shared reference to the element items of the [`ElementSegment`].
+    pub fn items(&self) -> &[ConstExpr] {
+        self.items.items()
+    }
+
     /// Returns the element items of the [`ElementSegment`].
     pub fn items_cloned(&self) -> ElementSegmentItems {
         self.items.clone()
@@ -48,11 +48,59 @@ impl Module {
     /// [`Func`]: [`crate::Func`]
     pub(crate) fn instantiate<I>(
         &self,
-        mut context: impl AsContextMut,
+        context: impl AsContextMut,
         externals: I,
     ) -> Result<InstancePre, Error>
     where
         I: IntoIterator<Item = Extern>,
+    {
+        self.instantiate_with(context, |module, context, builder| {
+            module.extract_imports(context, builder, externals)
+        })
+    }
+
+    /// Instantiates a new [`Instance`] from the given compiled [`Module`].
+    ///
+    /// Same as [`Module::instantiate`] but without checking the `externals` against
+    /// the imports of the [`Module`] since this already happened beforehand.
+    ///
+    /// # Note
+    ///
+    /// The caller is responsible to provide exactly one external value per import
+    /// that satisfies the import's type and that is owned by `context`.
+    pub(crate) fn instantiate_prechecked<I>(
+        &self,
+        context: impl AsContextMut,
+        externals: I,
+    ) -> Result<InstancePre, Error>
+    where
+        I: IntoIterator<Item = Extern>,
+    {
+        self.instantiate_with(context, |_module, _context, builder| {
+            for external in externals {
+                match external {
+                    Extern::Func(func) => builder.push_func(func),
+                    Extern::Table(table) => builder.push_table(table),
+                    Extern::Memory(memory) => builder.push_memory(memory),
+                    Extern::Global(global) => builder.push_global(global),
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Instantiates a new [`Instance`] using `extract_imports` to populate its imports.
+    fn instantiate_with<C>(
+        &self,
+        mut context: C,
+        extract_imports: impl FnOnce(
+            &Self,
+            &C,
+            &mut InstanceEntityBuilder,
+        ) -> Result<(), InstantiationError>,
+    ) -> Result<InstancePre, Error>
+    where
+        C: AsContextMut,
     {
         context
             .as_context_mut()
@@ -61,7 +109,7 @@ impl Module {
         let handle = context.as_context_mut().store.inner.alloc_instance();
         let mut builder = InstanceEntity::build(self);
 
-        self.extract_imports(&context, &mut builder, externals)?;
+        extract_imports(self, &context, &mut builder)?;
         self.extract_functions(&mut context, &mut builder, handle);
         self.extract_tables(&mut context, &mut builder)?;
         self.extract_memories(&mut context, &mut builder)?;
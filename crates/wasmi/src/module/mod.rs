@@ -8,6 +8,7 @@ mod init_expr;
 mod instantiate;
 mod parser;
 mod read;
+mod text;
 pub(crate) mod utils;
 
 use self::{
@@ -31,7 +32,8 @@ pub use self::{
     read::{Read, ReadError},
 };
 use crate::{
-    engine::{CompiledFunc, DedupFuncType, EngineWeak},
+    engine::{CompiledFunc, CompiledFuncEntity, DedupFuncType, EngineWeak, FuncMetadata},
+    Diagnostic,
     Engine,
     Error,
     ExternType,
@@ -40,16 +42,24 @@ use crate::{
     MemoryType,
     TableType,
 };
-use alloc::{boxed::Box, collections::BTreeMap, sync::Arc};
-use core::{iter, slice::Iter as SliceIter};
+use alloc::{boxed::Box, collections::BTreeMap, sync::Arc, vec::Vec};
+use core::{iter, mem, slice::Iter as SliceIter};
+use spin::Mutex;
 use wasmparser::{FuncValidatorAllocations, Parser, ValidPayload, Validator};
 
 /// A parsed and validated WebAssembly module.
-#[derive(Debug)]
+///
+/// # Note
+///
+/// Cloning a [`Module`] is cheap since its compiled functions, element and data
+/// segments are shared between all clones. Therefore a [`Module`] can be shared
+/// efficiently by many instantiations, even across threads.
+#[derive(Debug, Clone)]
 pub struct Module {
     engine: Engine,
     header: ModuleHeader,
-    data_segments: Box<[DataSegment]>,
+    data_segments: Arc<[DataSegment]>,
+    diagnostics: Arc<[Diagnostic]>,
 }
 
 /// A parsed and validated WebAssembly module header.
@@ -73,6 +83,8 @@ struct ModuleHeaderInner {
     compiled_funcs: Box<[CompiledFunc]>,
     compiled_funcs_idx: BTreeMap<CompiledFunc, FuncIdx>,
     element_segments: Box<[ElementSegment]>,
+    immutable_tables: Box<[bool]>,
+    diagnostics: Mutex<Vec<Diagnostic>>,
 }
 
 impl ModuleHeader {
@@ -113,6 +125,27 @@ impl ModuleHeader {
         self.inner.compiled_funcs_idx.get(&func).copied()
     }
 
+    /// Records the translation [`Diagnostic`]s of a single Wasm function.
+    pub fn push_diagnostics(&self, diagnostics: impl IntoIterator<Item = Diagnostic>) {
+        let mut diagnostics = diagnostics.into_iter().peekable();
+        if diagnostics.peek().is_none() {
+            return;
+        }
+        self.inner.diagnostics.lock().extend(diagnostics);
+    }
+
+    /// Takes all recorded translation [`Diagnostic`]s ordered by their function index.
+    ///
+    /// # Note
+    ///
+    /// Wasm functions might be translated in any order if parallel compilation
+    /// is enabled. Sorting restores the order of the Wasm code section.
+    pub fn take_diagnostics(&self) -> Arc<[Diagnostic]> {
+        let mut diagnostics = mem::take(&mut *self.inner.diagnostics.lock());
+        diagnostics.sort_by_key(Diagnostic::func_index);
+        diagnostics.into()
+    }
+
     /// Returns the global variable type and optional initial value.
     pub fn get_global(&self, global_idx: GlobalIdx) -> (&GlobalType, Option<&ConstExpr>) {
         let index = global_idx.into_u32() as usize;
@@ -127,6 +160,46 @@ impl ModuleHeader {
             (global_type, Some(init_expr))
         }
     }
+
+    /// Returns the [`FuncIdx`] stored at `index` of the table at `table_idx` after instantiation.
+    ///
+    /// Returns `None` if the stored function is not statically known.
+    ///
+    /// # Note
+    ///
+    /// This only considers tables that cannot be mutated after instantiation
+    /// and whose active element segments all have constant offsets.
+    pub fn get_table_func_after_init(&self, table_idx: TableIdx, index: u32) -> Option<FuncIdx> {
+        let table = table_idx.into_u32();
+        let is_immutable = self
+            .inner
+            .immutable_tables
+            .get(table as usize)
+            .copied()
+            .unwrap_or(false);
+        if !is_immutable {
+            // Case: the table may be mutated by the host or by Wasm instructions.
+            return None;
+        }
+        let mut func = None;
+        for segment in &self.inner.element_segments[..] {
+            let ElementSegmentKind::Active(segment_kind) = segment.kind() else {
+                continue;
+            };
+            if segment_kind.table_index().into_u32() != table {
+                continue;
+            }
+            // Note: later active element segments overwrite the items of earlier ones.
+            let offset = u32::from(segment_kind.offset().eval_const()?);
+            let Some(position) = index.checked_sub(offset) else {
+                continue;
+            };
+            if let Some(item) = segment.items().get(position as usize) {
+                func = Some(item.funcref()?);
+            }
+        }
+        func
+    }
 }
 
 /// The index of the default Wasm linear memory.
@@ -189,17 +262,24 @@ impl Module {
     ///
     /// # Note
     ///
-    /// This parses, validates and translates the Wasm bytecode yielded by `stream`.
+    /// - This parses, validates and translates the Wasm bytecode yielded by `stream`.
+    /// - The `stream` is consumed incrementally and never buffered as a whole.
+    ///   Sections are parsed as soon as their bytes arrived and Wasm function bodies
+    ///   are translated one by one while the code section is streamed in.
+    ///   With [`Config::parallel_compilation`] enabled the bytes of the code section
+    ///   are buffered until all of its function bodies have been received.
     ///
     /// # Errors
     ///
     /// - If the `stream` cannot be parsed as a valid Wasm module.
+    ///   For truncated streams the error reports the offset of the incomplete item.
     /// - If the Wasm bytecode yielded by `stream` is not valid.
     /// - If the Wasm bytecode yielded by `stream` violates restrictions
     ///   set in the [`Config`] used by the `engine`.
     /// - If `wasmi` cannot translate the Wasm bytecode yielded by `stream`.
     ///
     /// [`Config`]: crate::Config
+    /// [`Config::parallel_compilation`]: crate::Config::parallel_compilation
     pub fn new(engine: &Engine, stream: impl Read) -> Result<Self, Error> {
         parse(engine, stream).map_err(Into::into)
     }
@@ -235,10 +315,60 @@ impl Module {
         &self.engine
     }
 
+    /// Returns the [`Diagnostic`]s collected while translating the [`Module`].
+    ///
+    /// # Note
+    ///
+    /// - Diagnostics are only collected if enabled via [`Config::collect_translation_diagnostics`].
+    /// - The diagnostics are ordered by the index of the function that caused them.
+    ///
+    /// [`Config::collect_translation_diagnostics`]: crate::Config::collect_translation_diagnostics
+    pub fn translation_diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// Returns the number of registers the function at `index` requires upon execution.
+    ///
+    /// This is the size of the register window of the function on the value stack
+    /// and includes its parameters, locals, temporaries and function local constants.
+    ///
+    /// # Note
+    ///
+    /// - The `index` is within the function index space of the [`Module`]
+    ///   and thus accounts for imported functions.
+    /// - Lazily compiled functions are compiled upon the first query.
+    ///
+    /// Returns `None` if `index` is out of bounds, refers to an imported
+    /// function or if the lazy compilation of the function fails.
+    pub fn func_max_registers(&self, index: u32) -> Option<u16> {
+        self.func_metadata(index)
+            .map(|metadata| metadata.max_registers())
+    }
+
+    /// Returns the [`FuncMetadata`] of the function at `index`.
+    ///
+    /// # Note
+    ///
+    /// - The `index` is within the function index space of the [`Module`]
+    ///   and thus accounts for imported functions.
+    /// - Lazily compiled functions are compiled upon the first query.
+    ///
+    /// Returns `None` if `index` is out of bounds, refers to an imported
+    /// function or if the lazy compilation of the function fails.
+    pub fn func_metadata(&self, index: u32) -> Option<FuncMetadata> {
+        if index as usize >= self.header.inner.funcs.len() {
+            return None;
+        }
+        let compiled_func = self.header.get_compiled_func(FuncIdx::from(index))?;
+        self.engine
+            .resolve_func(compiled_func, CompiledFuncEntity::metadata)
+            .ok()
+    }
+
     /// Validates `wasm` as a WebAssembly binary given the configuration (via [`Config`]) in `engine`.
     ///
     /// This function performs Wasm validation of the binary input WebAssembly module and
-    /// returns either `Ok`` or `Err`` depending on the results of the validation.
+    /// returns either `Ok` or `Err` depending on the results of the validation.
     /// The [`Config`] of the `engine` is used for Wasm validation which indicates which WebAssembly
     /// features are valid and invalid for the validation.
     ///
@@ -253,6 +383,7 @@ impl Module {
     /// # Errors
     ///
     /// If Wasm validation for `wasm` fails for the given [`Config`] provided via `engine`.
+    /// The returned error is the first validation error and includes its byte offset in `wasm`.
     ///
     /// [`Config`]: crate::Config
     pub fn validate(engine: &Engine, wasm: &[u8]) -> Result<(), Error> {
@@ -11,27 +11,53 @@ use super::{
     ModuleHeader,
     Read,
 };
-use crate::{engine::CompiledFunc, Engine, Error, FuncType, MemoryType, TableType};
+use crate::{
+    engine::{CompiledFunc, TranslationError},
+    Engine,
+    Error,
+    FuncType,
+    MemoryType,
+    TableType,
+};
 use alloc::{boxed::Box, vec::Vec};
 use core::ops::Range;
 use wasmparser::{
+    BinaryReader,
+    BinaryReaderError,
     Chunk,
     DataSectionReader,
     ElementSectionReader,
     Encoding,
     ExportSectionReader,
+    FuncToValidate,
     FunctionBody,
     FunctionSectionReader,
     GlobalSectionReader,
     ImportSectionReader,
     MemorySectionReader,
+    Operator,
     Parser as WasmParser,
     Payload,
     TableSectionReader,
+    TypeRef,
     TypeSectionReader,
     Validator,
+    ValidatorResources,
 };
 
+/// Returns an error if `memory_type` describes a shared linear memory.
+///
+/// # Note
+///
+/// Shared linear memories pass validation if the `threads` Wasm proposal
+/// is enabled but are not supported by `wasmi`.
+fn ensure_unshared_memory(memory_type: &wasmparser::MemoryType) -> Result<(), Error> {
+    if memory_type.shared {
+        return Err(Error::from(TranslationError::UnsupportedSharedMemory));
+    }
+    Ok(())
+}
+
 /// Parse, validate and translate the Wasm bytecode stream into Wasm IR bytecode.
 ///
 /// - Returns the fully compiled and validated Wasm [`Module`] upon success.
@@ -194,9 +220,10 @@ impl ModuleParser {
                         Payload::DataCountSection { count, range } => {
                             self.process_data_count(count, range)
                         }
-                        Payload::CodeSectionStart { count, range, .. } => {
+                        Payload::CodeSectionStart { count, range, size } => {
                             self.process_code_start(count, range)?;
                             buffer.drain(..consumed);
+                            self.find_immutable_tables(count, size, stream, buffer, &mut header)?;
                             break;
                         }
                         Payload::DataSection(_) => break,
@@ -233,6 +260,11 @@ impl ModuleParser {
         buffer: &mut Vec<u8>,
         header: ModuleHeader,
     ) -> Result<ModuleBuilder, Error> {
+        let mut deferred = self
+            .engine
+            .config()
+            .get_parallel_compilation()
+            .then(DeferredFuncs::default);
         loop {
             match self.parser.parse(&buffer[..], self.eof)? {
                 Chunk::NeedMoreData(hint) => {
@@ -248,7 +280,21 @@ impl ModuleParser {
                             let remaining = func_body.get_binary_reader().bytes_remaining();
                             let start = consumed - remaining;
                             let bytes = &buffer[start..consumed];
-                            self.process_code_entry(func_body, validation_mode, bytes, &header)?;
+                            match &mut deferred {
+                                Some(deferred) => self.defer_code_entry(
+                                    func_body,
+                                    validation_mode,
+                                    bytes,
+                                    &header,
+                                    deferred,
+                                )?,
+                                None => self.process_code_entry(
+                                    func_body,
+                                    validation_mode,
+                                    bytes,
+                                    &header,
+                                )?,
+                            }
                         }
                         Payload::CustomSection { .. } => {}
                         Payload::UnknownSection { id, range, .. } => {
@@ -261,6 +307,9 @@ impl ModuleParser {
                 }
             }
         }
+        if let Some(deferred) = deferred {
+            deferred.translate(&self.engine, &header)?;
+        }
         Ok(ModuleBuilder::new(header))
     }
 
@@ -378,9 +427,13 @@ impl ModuleParser {
         header: &mut ModuleHeaderBuilder,
     ) -> Result<(), Error> {
         self.validator.import_section(&section)?;
-        let imports = section
-            .into_iter()
-            .map(|import| import.map(Import::from).map_err(Error::from));
+        let imports = section.into_iter().map(|import| {
+            let import = import?;
+            if let TypeRef::Memory(memory_type) = &import.ty {
+                ensure_unshared_memory(memory_type)?;
+            }
+            Ok(Import::from(import))
+        });
         header.push_imports(imports)?;
         Ok(())
     }
@@ -459,9 +512,11 @@ impl ModuleParser {
         header: &mut ModuleHeaderBuilder,
     ) -> Result<(), Error> {
         self.validator.memory_section(&section)?;
-        let memories = section
-            .into_iter()
-            .map(|memory| memory.map(MemoryType::from_wasmparser).map_err(Error::from));
+        let memories = section.into_iter().map(|memory| {
+            let memory = memory?;
+            ensure_unshared_memory(&memory)?;
+            Ok(MemoryType::from_wasmparser(memory))
+        });
         header.push_memories(memories)?;
         Ok(())
     }
@@ -603,10 +658,6 @@ impl ModuleParser {
     ///
     /// # Note
     ///
-    /// This currently does not do a lot but it might become important in the
-    /// future if we add parallel translation of function bodies to prepare for
-    /// the translation.
-    ///
     /// # Errors
     ///
     /// If the code start section fails to validate.
@@ -615,6 +666,82 @@ impl ModuleParser {
         Ok(())
     }
 
+    /// Finds all tables that cannot be mutated after instantiation.
+    ///
+    /// # Note
+    ///
+    /// Indirect calls with constant indices into immutable tables are translated
+    /// into direct calls. Since any function body might mutate a table via
+    /// `table.set`, `table.fill`, `table.copy` or `table.init` the entire
+    /// code section is scanned before any of its function bodies is translated.
+    /// For this the code section is buffered, but only if the Wasm module
+    /// has tables that are candidates for being immutable.
+    ///
+    /// # Errors
+    ///
+    /// If reading from the Wasm bytecode stream fails.
+    fn find_immutable_tables(
+        &mut self,
+        count: u32,
+        size: u32,
+        stream: &mut impl Read,
+        buffer: &mut Vec<u8>,
+        header: &mut ModuleHeaderBuilder,
+    ) -> Result<(), Error> {
+        let mut immutable_tables = header.immutable_table_candidates();
+        if !immutable_tables.contains(&true) {
+            return Ok(());
+        }
+        let size = size as usize;
+        while buffer.len() < size && !self.eof {
+            let hint = (size - buffer.len()) as u64;
+            self.eof = Self::pull_bytes(buffer, hint, stream)?;
+        }
+        let Some(code) = buffer.get(..size) else {
+            // Note: the truncated code section is reported once it is parsed.
+            return Ok(());
+        };
+        if Self::scan_mutated_tables(count, code, &mut immutable_tables).is_err() {
+            // Note: malformed function bodies are reported once they are parsed.
+            return Ok(());
+        }
+        header.immutable_tables = immutable_tables;
+        Ok(())
+    }
+
+    /// Marks all tables in `immutable_tables` as mutable that are mutated by the `code`.
+    ///
+    /// The `code` holds all `count` function bodies of the Wasm code section.
+    ///
+    /// # Errors
+    ///
+    /// If any of the function bodies is malformed.
+    fn scan_mutated_tables(
+        count: u32,
+        code: &[u8],
+        immutable_tables: &mut [bool],
+    ) -> Result<(), BinaryReaderError> {
+        let mut reader = BinaryReader::new(code);
+        for _ in 0..count {
+            let size = reader.read_var_u32()? as usize;
+            let body = FunctionBody::new(0, reader.read_bytes(size)?);
+            let mut operators = body.get_operators_reader()?;
+            while !operators.eof() {
+                let table = match operators.read()? {
+                    Operator::TableSet { table }
+                    | Operator::TableFill { table }
+                    | Operator::TableInit { table, .. } => table,
+                    Operator::TableCopy { dst_table, .. } => dst_table,
+                    _ => continue,
+                };
+                if let Some(immutable) = immutable_tables.get_mut(table as usize) {
+                    *immutable = false;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the next `FuncIdx` for processing of its function body.
     fn next_func(&mut self, header: &ModuleHeader) -> (FuncIdx, CompiledFunc) {
         let index = self.compiled_funcs;
@@ -658,6 +785,35 @@ impl ModuleParser {
         Ok(())
     }
 
+    /// Defers translation of a single module code section entry.
+    ///
+    /// # Note
+    ///
+    /// This is used instead of [`ModuleParser::process_code_entry`] if
+    /// parallel compilation is enabled. The function body is validated
+    /// and translated once the entire code section has been parsed.
+    ///
+    /// # Errors
+    ///
+    /// If the function body fails to validate.
+    fn defer_code_entry(
+        &mut self,
+        func_body: FunctionBody,
+        validation_mode: ValidationMode,
+        bytes: &[u8],
+        header: &ModuleHeader,
+        deferred: &mut DeferredFuncs,
+    ) -> Result<(), Error> {
+        let (func, compiled_func) = self.next_func(header);
+        let offset = func_body.get_binary_reader().original_position();
+        let func_to_validate = match validation_mode {
+            ValidationMode::All => Some(self.validator.code_section_entry(&func_body)?),
+            ValidationMode::HeaderOnly => None,
+        };
+        deferred.push(func, compiled_func, offset, bytes, func_to_validate);
+        Ok(())
+    }
+
     /// Process an unknown Wasm module section.
     ///
     /// # Note
@@ -669,3 +825,112 @@ impl ModuleParser {
             .map_err(Into::into)
     }
 }
+
+/// A Wasm function body of which the translation has been deferred.
+struct DeferredFunc {
+    /// The index of the function within its Wasm module.
+    func: FuncIdx,
+    /// The index of the function in the [`Engine`].
+    compiled_func: CompiledFunc,
+    /// The global offset of the function body within the Wasm binary.
+    offset: usize,
+    /// The range of the function body within [`DeferredFuncs::bytes`].
+    bytes: Range<usize>,
+    /// Optionally validates the function body.
+    func_to_validate: Option<FuncToValidate<ValidatorResources>>,
+}
+
+/// The Wasm function bodies of a code section that are translated in parallel.
+#[derive(Default)]
+struct DeferredFuncs {
+    /// The deferred functions in the order of the code section.
+    funcs: Vec<DeferredFunc>,
+    /// The concatenated bytes of all deferred function bodies.
+    bytes: Vec<u8>,
+}
+
+impl DeferredFuncs {
+    /// Pushes a Wasm function body for deferred translation.
+    fn push(
+        &mut self,
+        func: FuncIdx,
+        compiled_func: CompiledFunc,
+        offset: usize,
+        bytes: &[u8],
+        func_to_validate: Option<FuncToValidate<ValidatorResources>>,
+    ) {
+        let start = self.bytes.len();
+        self.bytes.extend_from_slice(bytes);
+        self.funcs.push(DeferredFunc {
+            func,
+            compiled_func,
+            offset,
+            bytes: start..self.bytes.len(),
+            func_to_validate,
+        });
+    }
+
+    /// Translates all deferred Wasm function bodies.
+    ///
+    /// # Note
+    ///
+    /// Since every [`CompiledFunc`] has been allocated up front the
+    /// resulting `wasmi` bytecode does not depend on the order in
+    /// which the function bodies are translated.
+    ///
+    /// # Errors
+    ///
+    /// If any of the function bodies fails to validate or translate.
+    /// The error of the first failing function in code section order is returned.
+    fn translate(mut self, engine: &Engine, header: &ModuleHeader) -> Result<(), Error> {
+        #[cfg(feature = "std")]
+        {
+            let len_threads = std::thread::available_parallelism()
+                .map(usize::from)
+                .unwrap_or(1)
+                .min(self.funcs.len());
+            if len_threads > 1 {
+                let chunk_size = self.funcs.len().div_ceil(len_threads);
+                let bytes = &self.bytes[..];
+                return std::thread::scope(|scope| {
+                    let workers = self
+                        .funcs
+                        .chunks_mut(chunk_size)
+                        .map(|chunk| {
+                            scope.spawn(move || Self::translate_all(engine, header, bytes, chunk))
+                        })
+                        .collect::<Vec<_>>();
+                    // Workers are joined in code section order so that the
+                    // first encountered error belongs to the first failing function.
+                    for worker in workers {
+                        worker
+                            .join()
+                            .unwrap_or_else(|error| std::panic::resume_unwind(error))?;
+                    }
+                    Ok(())
+                });
+            }
+        }
+        Self::translate_all(engine, header, &self.bytes, &mut self.funcs)
+    }
+
+    /// Sequentially translates all `funcs` and stops at the first error.
+    fn translate_all(
+        engine: &Engine,
+        header: &ModuleHeader,
+        bytes: &[u8],
+        funcs: &mut [DeferredFunc],
+    ) -> Result<(), Error> {
+        for func in funcs {
+            engine.translate_func(
+                func.func,
+                func.compiled_func,
+                func.offset,
+                &bytes[func.bytes.clone()],
+                header.clone(),
+                func.func_to_validate.take(),
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+use super::Module;
+use crate::{
+    core::{UntypedValue, ValueType},
+    engine::ResolvedInstr,
+    Error,
+    FuncType,
+};
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+impl Module {
+    /// Writes a WAT-like textual dump of the compiled `wasmi` bytecode of the [`Module`] to `writer`.
+    ///
+    /// # Note
+    ///
+    /// - Every internally defined function is written with its index, its
+    ///   signature, its number of registers, its function local constant
+    ///   values and its disassembled `wasmi` bytecode instructions.
+    /// - Branch instructions are annotated with the index of their branch target.
+    /// - Imported functions are skipped since they have no `wasmi` bytecode.
+    /// - Functions that have not yet been translated due to lazy compilation
+    ///   are translated by this operation.
+    /// - The format is intended for debugging and documentation purposes and
+    ///   is not stable across `wasmi` versions.
+    ///
+    /// # Errors
+    ///
+    /// - If a lazily compiled function fails to translate to `wasmi` bytecode.
+    /// - If writing to `writer` fails.
+    pub fn serialize_text<W>(&self, writer: &mut W) -> Result<(), Error>
+    where
+        W: Write,
+    {
+        let write_error = |_| Error::new("failed to write textual module dump");
+        let engine = self.engine();
+        let len_imported = self.header.inner.imports.len_funcs;
+        writeln!(writer, "(module").map_err(write_error)?;
+        for (n, (func_type, compiled_func)) in self.internal_funcs().enumerate() {
+            let func_type = engine.resolve_func_type(&func_type, FuncType::clone);
+            let func = engine.resolve_func(compiled_func, |func| FuncText {
+                index: len_imported + n,
+                func_type,
+                len_registers: func.len_registers(),
+                // Function local constants are stored in reverse order of their indices.
+                consts: func.consts().iter().rev().copied().collect(),
+                instrs: func.instructions().collect(),
+            })?;
+            write!(writer, "{func}").map_err(write_error)?;
+        }
+        writeln!(writer, ")").map_err(write_error)
+    }
+}
+
+/// The textual representation of a single compiled function.
+struct FuncText {
+    /// The index of the function within its [`Module`].
+    index: usize,
+    /// The signature of the function.
+    func_type: FuncType,
+    /// The number of registers used by the function.
+    len_registers: u16,
+    /// The function local constant values in order of their indices.
+    consts: Vec<UntypedValue>,
+    /// The `wasmi` bytecode instructions of the function with resolved branch targets.
+    instrs: Vec<ResolvedInstr>,
+}
+
+impl fmt::Display for FuncText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "  (func (;{};)", self.index)?;
+        write_func_type(f, &self.func_type)?;
+        writeln!(f)?;
+        writeln!(f, "    (registers {})", self.len_registers)?;
+        for (n, value) in self.consts.iter().enumerate() {
+            let register = -1 - n as i64;
+            writeln!(f, "    (const (;{register};) 0x{:016X})", u64::from(*value))?;
+        }
+        for instr in &self.instrs {
+            let n = instr.instr().into_u32();
+            write!(f, "    {n:4}: {:?}", instr.instruction())?;
+            if let Some(target) = instr.target() {
+                write!(f, " (;-> {};)", target.into_u32())?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f, "  )")
+    }
+}
+
+/// Writes the parameter and result types of `func_type` in WAT-like syntax to `writer`.
+fn write_func_type<W>(writer: &mut W, func_type: &FuncType) -> fmt::Result
+where
+    W: Write,
+{
+    if !func_type.params().is_empty() {
+        write!(writer, " (param")?;
+        for param in func_type.params() {
+            write!(writer, " {}", value_type_name(*param))?;
+        }
+        write!(writer, ")")?;
+    }
+    if !func_type.results().is_empty() {
+        write!(writer, " (result")?;
+        for result in func_type.results() {
+            write!(writer, " {}", value_type_name(*result))?;
+        }
+        write!(writer, ")")?;
+    }
+    Ok(())
+}
+
+/// Returns the WAT name of the [`ValueType`].
+fn value_type_name(ty: ValueType) -> &'static str {
+    match ty {
+        ValueType::I32 => "i32",
+        ValueType::I64 => "i64",
+        ValueType::F32 => "f32",
+        ValueType::F64 => "f64",
+        ValueType::FuncRef => "funcref",
+        ValueType::ExternRef => "externref",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Engine;
+    use alloc::string::String;
+
+    #[test]
+    fn serialize_text_works() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+                (import "env" "f" (func (param i32)))
+                (func (param i32 i32) (result i32)
+                    (i32.add (local.get 0) (local.get 1))
+                )
+                (func (param i64) (result i64)
+                    (i64.add (local.get 0) (i64.const 100000))
+                )
+                (func)
+            )
+            "#,
+        )
+        .unwrap();
+        let engine = Engine::default();
+        let module = Module::new(&engine, &wasm[..]).unwrap();
+        let mut text = String::new();
+        module.serialize_text(&mut text).unwrap();
+        let expected = "\
+(module
+  (func (;1;) (param i32 i32) (result i32)
+    (registers 3)
+       0: I32Add(BinInstr { result: Register(2), lhs: Register(0), rhs: Register(1) })
+       1: ReturnReg { value: Register(2) }
+  )
+  (func (;2;) (param i64) (result i64)
+    (registers 3)
+    (const (;-1;) 0x00000000000186A0)
+       0: I64Add(BinInstr { result: Register(1), lhs: Register(0), rhs: Register(-1) })
+       1: ReturnReg { value: Register(1) }
+  )
+  (func (;3;)
+    (registers 0)
+       0: Return
+  )
+)
+";
+        assert_eq!(text, expected);
+    }
+
+    #[test]
+    fn serialize_text_branch_targets() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+                (func (param i32)
+                    (loop $continue
+                        (br_if $continue (local.get 0))
+                    )
+                )
+            )
+            "#,
+        )
+        .unwrap();
+        let engine = Engine::default();
+        let module = Module::new(&engine, &wasm[..]).unwrap();
+        let mut text = String::new();
+        module.serialize_text(&mut text).unwrap();
+        let expected = "\
+(module
+  (func (;0;) (param i32)
+    (registers 1)
+       0: BranchI32NeImm(BranchBinOpInstrImm { lhs: Register(0), rhs: Const16 { inner: AnyConst16(0), marker: PhantomData<fn() -> i32> }, offset: BranchOffset16(0) }) (;-> 0;)
+       1: Return
+  )
+)
+";
+        assert_eq!(text, expected);
+    }
+}
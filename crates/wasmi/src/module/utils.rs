@@ -30,7 +30,7 @@ impl MemoryType {
         );
         assert!(
             !memory_type.shared,
-            "wasmi does not support the `threads` Wasm proposal"
+            "wasmi does not support shared linear memories"
         );
         let initial: u32 = memory_type
             .initial
@@ -1,16 +1,19 @@
 use crate::{
-    engine::DedupFuncType,
+    engine::{DedupFuncType, TraceEvent, TraceHook},
     externref::{ExternObject, ExternObjectEntity, ExternObjectIdx},
     func::{Trampoline, TrampolineEntity, TrampolineIdx},
     memory::{DataSegment, MemoryError},
     module::InstantiationError,
     table::TableError,
+    value::WithType,
+    Caller,
     DataSegmentEntity,
     DataSegmentIdx,
     ElementSegment,
     ElementSegmentEntity,
     ElementSegmentIdx,
     Engine,
+    ExternRef,
     Func,
     FuncEntity,
     FuncIdx,
@@ -18,6 +21,9 @@ use crate::{
     Global,
     GlobalEntity,
     GlobalIdx,
+    GlobalWatchHandle,
+    GlobalWatches,
+    GlobalsSnapshot,
     Instance,
     InstanceEntity,
     InstanceIdx,
@@ -28,14 +34,19 @@ use crate::{
     Table,
     TableEntity,
     TableIdx,
+    Value,
 };
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec, vec::Vec};
 use core::{
+    any::Any,
     fmt::{self, Debug},
     sync::atomic::{AtomicU32, Ordering},
 };
 use wasmi_arena::{Arena, ArenaIndex, GuardedEntity};
-use wasmi_core::TrapCode;
+use wasmi_core::{Pages, TrapCode, UntypedValue, ValueType};
+
+#[cfg(feature = "debug")]
+use crate::engine::ExecutionTrace;
 
 /// A unique store index.
 ///
@@ -101,6 +112,50 @@ impl<T> Debug for ResourceLimiterQuery<T> {
     }
 }
 
+/// The action taken after a Wasm `unreachable` instruction has been executed.
+///
+/// Returned by the unreachable hook registered via [`Store::on_unreachable`].
+#[derive(Debug, Clone, Default)]
+pub enum UnreachableAction {
+    /// The execution traps with [`TrapCode::UnreachableCodeReached`].
+    ///
+    /// This is the default behavior when no unreachable hook is registered.
+    #[default]
+    Trap,
+    /// The Wasm function executing the `unreachable` instruction returns `values` to its caller.
+    ///
+    /// The `values` must match the result types of the Wasm function.
+    ContinueWith(Vec<Value>),
+}
+
+type UnreachableHookFn<T> = dyn FnMut(&Caller<'_, T>) -> UnreachableAction + Send + Sync;
+
+/// A wrapper around a boxed `dyn FnMut(&Caller<T>)` returning an [`UnreachableAction`].
+///
+/// This wrapper exists to provide a `Debug` impl so that
+/// `#[derive(Debug)]` works on structs that contain it.
+struct UnreachableHook<T>(Box<UnreachableHookFn<T>>);
+
+impl<T> Debug for UnreachableHook<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "UnreachableHook(...)")
+    }
+}
+
+type MemoryGrowHookFn = dyn FnMut(Memory, Pages, Pages) + Send + Sync;
+
+/// A wrapper around a boxed `dyn FnMut(Memory, Pages, Pages)` called after a [`Memory`] has grown.
+///
+/// This wrapper exists to provide a `Debug` impl so that
+/// `#[derive(Debug)]` works on structs that contain it.
+struct MemoryGrowHook(Box<MemoryGrowHookFn>);
+
+impl Debug for MemoryGrowHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MemoryGrowHook(...)")
+    }
+}
+
 /// The store that owns all data associated to Wasm modules.
 #[derive(Debug)]
 pub struct Store<T> {
@@ -117,6 +172,8 @@ pub struct Store<T> {
     data: T,
     /// User provided hook to retrieve a [`ResourceLimiter`].
     limiter: Option<ResourceLimiterQuery<T>>,
+    /// User provided hook called upon executing a Wasm `unreachable` instruction.
+    unreachable_hook: Option<UnreachableHook<T>>,
 }
 
 /// The inner store that owns all data not associated to the host state.
@@ -150,6 +207,24 @@ pub struct StoreInner {
     engine: Engine,
     /// The fuel of the [`Store`].
     fuel: Fuel,
+    /// The registered watches on global variables.
+    global_watches: GlobalWatches,
+    /// The trace hook called before each executed instruction if tracing is enabled.
+    trace_hook: Option<TraceHook>,
+    /// The recorded execution trace if tracing is enabled.
+    #[cfg(feature = "debug")]
+    trace: Option<ExecutionTrace>,
+    /// The number of Wasm call frames of all ongoing executions of the [`Store`].
+    ///
+    /// # Note
+    ///
+    /// This is only updated upon calling host functions from Wasm.
+    call_depth: usize,
+    /// Whether panics of host functions are converted into traps.
+    #[cfg(feature = "std")]
+    host_trap: bool,
+    /// User provided hook called after a linear memory has been grown.
+    memory_grow_hook: Option<MemoryGrowHook>,
 }
 
 #[test]
@@ -235,6 +310,26 @@ impl Fuel {
         self.total.wrapping_sub(self.remaining)
     }
 
+    /// Returns the remaining amount of [`Fuel`].
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Sets the remaining [`Fuel`] to `fuel`.
+    ///
+    /// The amount of consumed [`Fuel`] is unaffected by this operation.
+    ///
+    /// # Panics
+    ///
+    /// If this overflows the [`Fuel`] counter.
+    pub fn set_fuel(&mut self, fuel: u64) {
+        let consumed = self.fuel_consumed();
+        self.total = consumed.checked_add(fuel).unwrap_or_else(|| {
+            panic!("encountered total fuel overflow: consumed = {consumed}, fuel = {fuel}")
+        });
+        self.remaining = fuel;
+    }
+
     /// Returns `Ok` if enough fuel is remaining to satisfy `delta` fuel consumption.
     ///
     /// Returns a [`TrapCode::OutOfFuel`] error otherwise.
@@ -255,6 +350,29 @@ impl Fuel {
             .ok_or(TrapCode::OutOfFuel)?;
         Ok(self.remaining)
     }
+
+    /// Limits the remaining [`Fuel`] to at most `limit` and returns the withheld amount of fuel.
+    ///
+    /// # Note
+    ///
+    /// The withheld fuel is not counted as consumed and must be given back
+    /// via [`Fuel::restore_withheld`] once the limit no longer applies.
+    pub fn withhold_above(&mut self, limit: u64) -> u64 {
+        let withheld = self.remaining.saturating_sub(limit);
+        // No need to check since `self.total >= self.remaining >= withheld`.
+        self.remaining = self.remaining.wrapping_sub(withheld);
+        self.total = self.total.wrapping_sub(withheld);
+        withheld
+    }
+
+    /// Gives back the `withheld` fuel of a previous [`Fuel::withhold_above`].
+    ///
+    /// # Panics
+    ///
+    /// If this overflows the [`Fuel`] counter.
+    pub fn restore_withheld(&mut self, withheld: u64) {
+        self.add_fuel(withheld)
+    }
 }
 
 impl StoreInner {
@@ -272,6 +390,14 @@ impl StoreInner {
             elems: Arena::new(),
             extern_objects: Arena::new(),
             fuel: Fuel::default(),
+            global_watches: GlobalWatches::default(),
+            trace_hook: None,
+            #[cfg(feature = "debug")]
+            trace: None,
+            call_depth: 0,
+            #[cfg(feature = "std")]
+            host_trap: false,
+            memory_grow_hook: None,
         }
     }
 
@@ -290,6 +416,61 @@ impl StoreInner {
         &mut self.fuel
     }
 
+    /// Returns an exclusive reference to the [`ExecutionTrace`] if tracing is enabled.
+    #[cfg(feature = "debug")]
+    #[inline]
+    pub fn trace_mut(&mut self) -> Option<&mut ExecutionTrace> {
+        self.trace.as_mut()
+    }
+
+    /// Returns the number of Wasm call frames of all ongoing executions of the [`Store`].
+    pub fn call_depth(&self) -> usize {
+        self.call_depth
+    }
+
+    /// Sets the number of Wasm call frames of all ongoing executions of the [`Store`].
+    pub fn set_call_depth(&mut self, depth: usize) {
+        self.call_depth = depth;
+    }
+
+    /// Returns `true` if panics of host functions are converted into traps.
+    #[cfg(feature = "std")]
+    pub fn host_trap(&self) -> bool {
+        self.host_trap
+    }
+
+    /// Returns an exclusive reference to the [`TraceHook`] if any.
+    #[inline]
+    pub fn trace_hook_mut(&mut self) -> Option<&mut TraceHook> {
+        self.trace_hook.as_mut()
+    }
+
+    /// Calls the memory grow hook if any after `memory` has grown from `old_pages` to `new_pages`.
+    pub fn notify_memory_grow(&mut self, memory: Memory, old_pages: Pages, new_pages: Pages) {
+        if let Some(hook) = &mut self.memory_grow_hook {
+            (hook.0)(memory, old_pages, new_pages);
+        }
+    }
+
+    /// Returns `true` if at least one global variable watch is registered.
+    #[inline]
+    pub fn has_global_watches(&self) -> bool {
+        !self.global_watches.is_empty()
+    }
+
+    /// Notifies all watches of `global` that its value is about to change to `new_value`.
+    ///
+    /// # Panics
+    ///
+    /// - If the [`Global`] does not originate from this [`Store`].
+    /// - If the [`Global`] cannot be resolved to its entity.
+    pub fn notify_global_watches(&mut self, global: &Global, new_value: UntypedValue) {
+        let entity = self.resolve(global.as_inner(), &self.globals);
+        let old_value = entity.get();
+        let new_value = new_value.with_type(entity.ty().content());
+        self.global_watches.notify(global, &old_value, &new_value);
+    }
+
     /// Wraps an entity `Idx` (index type) as a [`Stored<Idx>`] type.
     ///
     /// # Note
@@ -351,11 +532,64 @@ impl StoreInner {
     }
 
     /// Allocates a new [`ExternObjectEntity`] and returns a [`ExternObject`] reference to it.
+    ///
+    /// # Note
+    ///
+    /// Extern object indices are never reused while the [`Store`] is alive,
+    /// not even after [`Store::gc`] reclaimed their host objects.
+    /// Therefore their indices are assigned in strictly monotonic allocation order
+    /// which keeps them independent of hashing or memory addresses.
     pub(super) fn alloc_extern_object(&mut self, object: ExternObjectEntity) -> ExternObject {
         let object = self.extern_objects.alloc(object);
         ExternObject::from_inner(self.wrap_stored(object))
     }
 
+    /// Drops all external objects that are no longer reachable from any
+    /// [`Global`] or [`Table`] of the [`Store`].
+    ///
+    /// Returns the number of reclaimed external objects.
+    ///
+    /// # Panics
+    ///
+    /// If a [`Global`] or [`Table`] of the [`Store`] holds an [`ExternRef`]
+    /// that does not originate from this [`Store`].
+    pub fn gc(&mut self) -> usize {
+        let mut reachable = vec![false; self.extern_objects.len()];
+        let mut mark = |value: UntypedValue| {
+            if let Some(object) = ExternRef::from(value).object() {
+                let idx = self.unwrap_stored(object.as_inner());
+                reachable[idx.into_usize()] = true;
+            }
+        };
+        for (_, global) in self.globals.iter() {
+            if global.ty().content() == ValueType::ExternRef {
+                mark(global.get_untyped());
+            }
+        }
+        for (_, table) in self.tables.iter() {
+            if table.ty().element() == ValueType::ExternRef {
+                table.elements().iter().copied().for_each(&mut mark);
+            }
+        }
+        let mut reclaimed = 0;
+        for (idx, object) in self.extern_objects.iter_mut() {
+            if !reachable[idx.into_usize()] && object.reclaim() {
+                reclaimed += 1;
+            }
+        }
+        reclaimed
+    }
+
+    /// Returns the [`ExternObjectIdx`] of the given [`ExternObject`].
+    ///
+    /// # Panics
+    ///
+    /// If the [`ExternObject`] does not originate from this [`Store`].
+    #[cfg(test)]
+    pub(super) fn extern_object_idx(&self, object: &ExternObject) -> ExternObjectIdx {
+        self.unwrap_stored(object.as_inner())
+    }
+
     /// Allocates a new uninitialized [`InstanceEntity`] and returns an [`Instance`] reference to it.
     ///
     /// # Note
@@ -630,6 +864,30 @@ impl StoreInner {
         Self::resolve_mut(idx, &mut self.memories)
     }
 
+    /// Returns exclusive references to the [`MemoryEntity`]s associated to `fst` and `snd`.
+    ///
+    /// Returns `None` if `fst` and `snd` refer to the same [`MemoryEntity`].
+    ///
+    /// # Panics
+    ///
+    /// - If either [`Memory`] does not originate from this [`Store`].
+    /// - If either [`Memory`] cannot be resolved to its entity.
+    pub fn resolve_memory_pair_mut(
+        &mut self,
+        fst: &Memory,
+        snd: &Memory,
+    ) -> Option<(&mut MemoryEntity, &mut MemoryEntity)> {
+        let fst = self.unwrap_stored(fst.as_inner());
+        let snd = self.unwrap_stored(snd.as_inner());
+        if fst == snd {
+            return None;
+        }
+        let pair = self.memories.get_pair_mut(fst, snd).unwrap_or_else(|| {
+            panic!("failed to resolve stored pair of entities: {fst:?} and {snd:?}")
+        });
+        Some(pair)
+    }
+
     /// Returns a pair of:
     ///
     /// - An exclusive reference to the [`MemoryEntity`] associated to the given [`Memory`].
@@ -700,6 +958,31 @@ impl StoreInner {
         self.resolve(object.as_inner(), &self.extern_objects)
     }
 
+    /// Asserts that the reference held by `value` originates from this [`Store`].
+    ///
+    /// # Note
+    ///
+    /// This is a no-op for non-reference and `null` reference values.
+    ///
+    /// # Panics
+    ///
+    /// If `value` holds a reference that does not originate from this [`Store`].
+    pub fn assert_owns_value(&self, value: &Value) {
+        match value {
+            Value::FuncRef(funcref) => {
+                if let Some(func) = funcref.func() {
+                    self.resolve_func(func);
+                }
+            }
+            Value::ExternRef(externref) => {
+                if let Some(object) = externref.object() {
+                    self.resolve_external_object(object);
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Allocates a new Wasm or host [`FuncEntity`] and returns a [`Func`] reference to it.
     pub fn alloc_func(&mut self, func: FuncEntity) -> Func {
         let idx = self.funcs.alloc(func);
@@ -728,6 +1011,7 @@ impl<T> Store<T> {
             trampolines: Arena::new(),
             data,
             limiter: None,
+            unreachable_hook: None,
         }
     }
 
@@ -761,6 +1045,58 @@ impl<T> Store<T> {
         self.limiter = Some(ResourceLimiterQuery(Box::new(limiter)))
     }
 
+    /// Registers `hook` to be called whenever Wasm code executes an `unreachable` instruction.
+    ///
+    /// The hook is provided a [`Caller`] for the [`Instance`] of the Wasm function
+    /// that executed the `unreachable` instruction and decides via the returned
+    /// [`UnreachableAction`] whether the execution traps or recovers.
+    /// This replaces any previously registered unreachable hook.
+    ///
+    /// # Note
+    ///
+    /// - Without a registered hook executing `unreachable` traps as usual.
+    /// - If [`UnreachableAction::ContinueWith`] provides values that do not match
+    ///   the result types of the Wasm function the execution fails with a [`FuncError`].
+    ///
+    /// [`FuncError`]: crate::errors::FuncError
+    pub fn on_unreachable(
+        &mut self,
+        hook: impl FnMut(&Caller<'_, T>) -> UnreachableAction + Send + Sync + 'static,
+    ) {
+        self.unreachable_hook = Some(UnreachableHook(Box::new(hook)));
+    }
+
+    /// Calls the unreachable hook of the [`Store`] for the Wasm function of `instance`.
+    ///
+    /// Returns [`UnreachableAction::Trap`] if no unreachable hook has been registered.
+    pub(crate) fn call_unreachable_hook(&mut self, instance: &Instance) -> UnreachableAction {
+        let Some(mut hook) = self.unreachable_hook.take() else {
+            return UnreachableAction::Trap;
+        };
+        let action = (hook.0)(&Caller::new(self, Some(instance)));
+        self.unreachable_hook = Some(hook);
+        action
+    }
+
+    /// Registers `hook` to be called whenever a [`Memory`] of the [`Store`] has grown.
+    ///
+    /// The hook is provided the grown [`Memory`] as well as its number of pages
+    /// before and after growing. This replaces any previously registered memory grow hook.
+    ///
+    /// # Note
+    ///
+    /// - The hook is called after successfully executing a Wasm `memory.grow`
+    ///   instruction as well as after a successful [`Memory::grow`].
+    /// - The hook is not called upon failed growths or growths by zero pages.
+    /// - Growing a [`Memory`] may reallocate its underlying bytes which invalidates
+    ///   raw pointers into them such as those derived from [`Memory::data_mut`].
+    pub fn on_memory_grow(
+        &mut self,
+        hook: impl FnMut(Memory, Pages, Pages) + Send + Sync + 'static,
+    ) {
+        self.inner.memory_grow_hook = Some(MemoryGrowHook(Box::new(hook)));
+    }
+
     pub(crate) fn check_new_instances_limit(
         &mut self,
         num_new_instances: usize,
@@ -818,7 +1154,7 @@ impl<T> Store<T> {
     /// Returns `Ok` if fuel metering has been enabled.
     ///
     /// Otherwise returns the respective [`FuelError`].
-    fn check_fuel_metering_enabled(&self) -> Result<(), FuelError> {
+    pub(crate) fn check_fuel_metering_enabled(&self) -> Result<(), FuelError> {
         if !self.is_fuel_metering_enabled() {
             return Err(FuelError::fuel_metering_disabled());
         }
@@ -848,6 +1184,35 @@ impl<T> Store<T> {
         Some(self.inner.fuel.fuel_consumed())
     }
 
+    /// Returns the remaining amount of fuel of the [`Store`].
+    ///
+    /// This is the same fuel counter that is consumed by Wasm executions.
+    ///
+    /// Returns `None` if fuel metering is disabled.
+    pub fn get_fuel(&self) -> Option<u64> {
+        self.check_fuel_metering_enabled().ok()?;
+        Some(self.inner.fuel.remaining())
+    }
+
+    /// Sets the remaining amount of fuel of the [`Store`] to `fuel`.
+    ///
+    /// # Note
+    ///
+    /// The amount of fuel consumed so far is unaffected by this operation.
+    ///
+    /// # Panics
+    ///
+    /// If the sum of `fuel` and the consumed fuel overflows the fuel counter.
+    ///
+    /// # Errors
+    ///
+    /// If fuel metering is disabled.
+    pub fn set_fuel(&mut self, fuel: u64) -> Result<(), FuelError> {
+        self.check_fuel_metering_enabled()?;
+        self.inner.fuel.set_fuel(fuel);
+        Ok(())
+    }
+
     /// Synthetically consumes an amount of fuel for the [`Store`].
     ///
     /// Returns the remaining amount of fuel after this operation.
@@ -868,6 +1233,210 @@ impl<T> Store<T> {
             .map_err(|_error| FuelError::out_of_fuel())
     }
 
+    /// Returns the number of Wasm call frames of all ongoing executions of the [`Store`].
+    ///
+    /// # Note
+    ///
+    /// - This is useful for host functions to inspect the depth of the Wasm call stack
+    ///   that called them, including call frames of outer executions if host
+    ///   functions re-entered Wasm execution.
+    /// - Returns 0 if no Wasm execution is ongoing.
+    /// - Executions trap with [`TrapCode::StackOverflow`] if the call stack depth
+    ///   would exceed [`Config::max_call_depth`].
+    ///
+    /// [`Config::max_call_depth`]: crate::Config::max_call_depth
+    pub fn call_stack_depth(&self) -> usize {
+        self.inner.call_depth()
+    }
+
+    /// Configures whether panics of host functions are converted into traps.
+    ///
+    /// If enabled a panic inside a host function called by Wasm or the host
+    /// is caught and returned as an [`Error`] instead of unwinding through the
+    /// `wasmi` interpreter. The [`Store`] remains usable afterwards.
+    ///
+    /// Disabled by default.
+    ///
+    /// # Note
+    ///
+    /// - The panic message is part of the returned [`Error`] if available.
+    /// - The panic hook is still invoked upon host function panics.
+    /// - Host functions must not leave their own state inconsistent upon panics.
+    ///
+    /// [`Error`]: crate::Error
+    #[cfg(feature = "std")]
+    pub fn with_host_trap(mut self, enable: bool) -> Self {
+        self.inner.host_trap = enable;
+        self
+    }
+
+    /// Registers `hook` as the trace hook of the [`Store`].
+    ///
+    /// The trace hook is called with a [`TraceEvent`] before each `wasmi` bytecode
+    /// instruction is executed, including instructions of nested Wasm function calls
+    /// and instructions that trap. This replaces any previously registered trace hook.
+    ///
+    /// # Note
+    ///
+    /// The trace hook is only called if tracing is enabled via [`Config::enable_tracing`].
+    ///
+    /// [`Config::enable_tracing`]: crate::Config::enable_tracing
+    pub fn set_trace_hook<F>(&mut self, hook: F)
+    where
+        F: FnMut(&TraceEvent) + Send + Sync + 'static,
+    {
+        self.inner.trace_hook = Some(TraceHook::new(hook));
+    }
+
+    /// Unregisters the trace hook of the [`Store`] if any.
+    pub fn clear_trace_hook(&mut self) {
+        self.inner.trace_hook = None;
+    }
+
+    /// Wraps the host `value` into a new [`ExternRef`] owned by the [`Store`].
+    ///
+    /// The wrapped `value` can be passed through Wasm code as `externref`
+    /// and retrieved again via [`ExternRef::downcast_ref`].
+    ///
+    /// # Determinism
+    ///
+    /// Every call allocates the next handle in strictly monotonic order.
+    /// Wrapped values live as long as the [`Store`] unless reclaimed via [`Store::gc`],
+    /// and handles are never reused, not even after their values have been reclaimed.
+    /// Therefore two [`Store`] instances performing the same sequence of allocations
+    /// assign the same handles to their objects regardless of how the resulting
+    /// [`ExternRef`] values are used, dropped or garbage collected.
+    pub fn wrap_externref<V>(&mut self, value: V) -> ExternRef
+    where
+        V: 'static + Any + Send + Sync,
+    {
+        ExternRef::new::<V>(self, value)
+    }
+
+    /// Drops all host objects wrapped by [`ExternRef`] values that are no longer
+    /// reachable from any [`Global`] or [`Table`] of the [`Store`].
+    ///
+    /// Returns the number of reclaimed host objects.
+    ///
+    /// # Note
+    ///
+    /// - Garbage collection is opt-in and never runs implicitly.
+    /// - Since this requires exclusive access to the [`Store`] no Wasm function
+    ///   can be executing on it, so there are no live call frames to consider.
+    ///   However, values on the call stack of a suspended [`ResumableInvocation`]
+    ///   are not taken into account. Do not call this while such an invocation
+    ///   is pending if it might still use its `externref` values.
+    /// - [`ExternRef`] values only held by the host are not taken into account either.
+    ///   After their host objects have been reclaimed [`ExternRef::data`]
+    ///   and [`ExternRef::downcast_ref`] return `None`.
+    /// - Handles of reclaimed [`ExternRef`] values are never reused.
+    ///
+    /// [`ResumableInvocation`]: crate::ResumableInvocation
+    pub fn gc(&mut self) -> usize {
+        self.inner.gc()
+    }
+
+    /// Enables or disables execution tracing for the [`Store`].
+    ///
+    /// While enabled every executed `wasmi` bytecode instruction and its register
+    /// writes are recorded into the [`ExecutionTrace`] of the [`Store`].
+    /// Enabling tracing starts with an empty [`ExecutionTrace`] and disabling
+    /// tracing discards the recorded [`ExecutionTrace`].
+    ///
+    /// # Note
+    ///
+    /// This is a debugging utility that heavily slows down execution.
+    #[cfg(feature = "debug")]
+    pub fn set_tracing(&mut self, enable: bool) {
+        self.inner.trace = enable.then(ExecutionTrace::default);
+    }
+
+    /// Returns the recorded [`ExecutionTrace`] of the [`Store`].
+    ///
+    /// Returns `None` if tracing is disabled.
+    #[cfg(feature = "debug")]
+    pub fn trace(&self) -> Option<&ExecutionTrace> {
+        self.inner.trace.as_ref()
+    }
+
+    /// Takes the recorded [`ExecutionTrace`] of the [`Store`] and starts a new empty one.
+    ///
+    /// Returns `None` if tracing is disabled.
+    #[cfg(feature = "debug")]
+    pub fn take_trace(&mut self) -> Option<ExecutionTrace> {
+        self.inner.trace.as_mut().map(core::mem::take)
+    }
+
+    /// Registers `callback` to be invoked whenever Wasm code changes the value of `global`.
+    ///
+    /// The `callback` receives the old and the new value of `global` in this order
+    /// and is invoked by every executed `global.set` on `global`.
+    /// Returns a [`GlobalWatchHandle`] that unregisters the watch via [`Store::unwatch_global`].
+    ///
+    /// # Note
+    ///
+    /// - Changes of `global` via [`Global::set`] by the host are not reported.
+    /// - Execution is only slowed down while at least one watch is registered.
+    ///
+    /// # Panics
+    ///
+    /// If `global` does not originate from this [`Store`].
+    pub fn watch_global<F>(&mut self, global: Global, callback: F) -> GlobalWatchHandle
+    where
+        F: FnMut(Value, Value) + Send + Sync + 'static,
+    {
+        self.inner.unwrap_stored(global.as_inner());
+        self.inner.global_watches.push(global, Box::new(callback))
+    }
+
+    /// Unregisters the global variable watch associated to `handle`.
+    ///
+    /// Returns `true` if the watch was still registered.
+    pub fn unwatch_global(&mut self, handle: GlobalWatchHandle) -> bool {
+        self.inner.global_watches.remove(handle)
+    }
+
+    /// Returns a snapshot of the values of all mutable global variables of `instance`.
+    ///
+    /// The snapshot can be restored via [`Store::restore_globals`] in order to roll back
+    /// the mutable global state of `instance`, e.g. after a failed transaction.
+    ///
+    /// # Note
+    ///
+    /// Mutable global variables imported by `instance` are part of the snapshot.
+    ///
+    /// # Panics
+    ///
+    /// If `instance` does not originate from this [`Store`].
+    pub fn snapshot_globals(&self, instance: &Instance) -> GlobalsSnapshot {
+        let globals = self
+            .inner
+            .resolve_instance(instance)
+            .globals()
+            .iter()
+            .map(|global| (global, self.inner.resolve_global(global)))
+            .filter(|(_, entity)| entity.ty().mutability().is_mut())
+            .map(|(global, entity)| (*global, entity.get_untyped()))
+            .collect();
+        GlobalsSnapshot::new(globals)
+    }
+
+    /// Restores the values of the mutable global variables captured by `snapshot`.
+    ///
+    /// # Note
+    ///
+    /// Restoring global variable values does not notify watches registered
+    /// via [`Store::watch_global`].
+    ///
+    /// # Panics
+    ///
+    /// If `snapshot` does not originate from this [`Store`].
+    pub fn restore_globals(&mut self, snapshot: &GlobalsSnapshot) {
+        for (global, value) in snapshot.globals() {
+            self.inner.resolve_global_mut(global).set_untyped(*value);
+        }
+    }
+
     /// Allocates a new [`TrampolineEntity`] and returns a [`Trampoline`] reference to it.
     pub(super) fn alloc_trampoline(&mut self, func: TrampolineEntity<T>) -> Trampoline {
         let idx = self.trampolines.alloc(func);
@@ -145,6 +145,10 @@ impl TableType {
 pub struct TableEntity {
     ty: TableType,
     elements: Vec<UntypedValue>,
+    /// Stores for each element if it has been written since the creation of the table.
+    ///
+    /// This is `None` unless uninitialized elements are tracked.
+    initialized: Option<Vec<bool>>,
 }
 
 impl TableEntity {
@@ -174,7 +178,52 @@ impl TableEntity {
         }
 
         let elements = vec![init.into(); ty.minimum() as usize];
-        Ok(Self { ty, elements })
+        Ok(Self {
+            ty,
+            elements,
+            initialized: None,
+        })
+    }
+
+    /// Makes the [`TableEntity`] track which of its elements have never been written.
+    ///
+    /// # Note
+    ///
+    /// - This only affects `funcref` tables.
+    /// - Elements that are `null` at this point are considered uninitialized.
+    pub fn track_uninitialized(&mut self) {
+        if self.ty.element() != ValueType::FuncRef {
+            return;
+        }
+        let initialized = self
+            .elements
+            .iter()
+            .map(|&element| !FuncRef::from(element).is_null())
+            .collect();
+        self.initialized = Some(initialized);
+    }
+
+    /// Returns `true` if the element at `index` has never been written since the creation of the table.
+    ///
+    /// # Note
+    ///
+    /// Always returns `false` unless uninitialized elements are tracked.
+    pub fn is_uninitialized(&self, index: u32) -> bool {
+        self.initialized
+            .as_ref()
+            .and_then(|initialized| initialized.get(index as usize))
+            .is_some_and(|initialized| !initialized)
+    }
+
+    /// Marks `len` elements starting at `index` as initialized if uninitialized elements are tracked.
+    ///
+    /// # Note
+    ///
+    /// The range must have been bounds checked before.
+    fn mark_initialized(&mut self, index: usize, len: usize) {
+        if let Some(initialized) = &mut self.initialized {
+            initialized[index..index + len].fill(true);
+        }
     }
 
     /// Returns the resizable limits of the table.
@@ -197,6 +246,11 @@ impl TableEntity {
         self.elements.len() as u32
     }
 
+    /// Returns the untyped elements of the [`TableEntity`].
+    pub(crate) fn elements(&self) -> &[UntypedValue] {
+        &self.elements
+    }
+
     /// Grows the table by the given amount of elements.
     ///
     /// Returns the old size of the [`Table`] upon success.
@@ -256,6 +310,9 @@ impl TableEntity {
         if let Some(desired) = desired {
             if desired <= maximum {
                 self.elements.resize(desired as usize, init);
+                if let Some(initialized) = &mut self.initialized {
+                    initialized.resize(desired as usize, true);
+                }
                 return Ok(current);
             }
         }
@@ -322,6 +379,7 @@ impl TableEntity {
                     offset: index,
                 })?;
         *untyped = value;
+        self.mark_initialized(index as usize, 1);
         Ok(())
     }
 
@@ -394,6 +452,7 @@ impl TableEntity {
             }
             _ => panic!("table.init currently only works on reftypes"),
         };
+        self.mark_initialized(dst_index, len);
         Ok(())
     }
 
@@ -428,6 +487,7 @@ impl TableEntity {
             .ok_or(TrapCode::TableOutOfBounds)?;
         // Finally, copy elements in-place for the table.
         dst_items.copy_from_slice(src_items);
+        dst_table.mark_initialized(dst_index, len);
         Ok(())
     }
 
@@ -455,6 +515,7 @@ impl TableEntity {
         // Finally, copy elements in-place for the table.
         self.elements
             .copy_within(src_index..src_index.wrapping_add(len), dst_index);
+        self.mark_initialized(dst_index, len);
         Ok(())
     }
 
@@ -502,6 +563,7 @@ impl TableEntity {
             .and_then(|elements| elements.get_mut(..len))
             .ok_or(TrapCode::TableOutOfBounds)?;
         dst.fill(val);
+        self.mark_initialized(dst_index, len);
         Ok(())
     }
 }
@@ -532,7 +594,10 @@ impl Table {
             .as_context_mut()
             .store
             .store_inner_and_resource_limiter_ref();
-        let entity = TableEntity::new(ty, init, &mut resource_limiter)?;
+        let mut entity = TableEntity::new(ty, init, &mut resource_limiter)?;
+        if inner.engine().config().get_table_null_is_trap_sentinel() {
+            entity.track_uninitialized();
+        }
         let table = inner.alloc_table(entity);
         Ok(table)
     }
@@ -600,6 +665,7 @@ impl Table {
             .store
             .store_inner_and_resource_limiter_ref();
         let table = inner.resolve_table_mut(self);
+        table.ty().matches_element_type(init.ty())?;
         let current = table.size();
         let maximum = table.ty().maximum().unwrap_or(u32::MAX);
         table
@@ -0,0 +1,375 @@
+//! Tests for the Wasm `threads` proposal instructions on non-shared linear memories.
+
+use wasmi::{
+    core::TrapCode,
+    errors::ErrorKind,
+    Config,
+    Engine,
+    Instance,
+    Linker,
+    Memory,
+    Module,
+    Store,
+    WasmParams,
+    WasmResults,
+};
+
+/// Creates a [`Config`] with the Wasm `threads` proposal enabled.
+fn test_config() -> Config {
+    let mut config = Config::default();
+    config.wasm_threads(true);
+    config
+}
+
+/// The test module exporting a function for each supported atomic instruction.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (memory (export "mem") 1)
+        (func (export "i32.atomic.load") (param $ptr i32) (result i32)
+            (i32.atomic.load (local.get $ptr))
+        )
+        (func (export "i64.atomic.load") (param $ptr i32) (result i64)
+            (i64.atomic.load (local.get $ptr))
+        )
+        (func (export "i32.atomic.store") (param $ptr i32) (param $value i32)
+            (i32.atomic.store (local.get $ptr) (local.get $value))
+        )
+        (func (export "i64.atomic.store") (param $ptr i32) (param $value i64)
+            (i64.atomic.store (local.get $ptr) (local.get $value))
+        )
+        (func (export "i32.atomic.rmw.add") (param $ptr i32) (param $value i32) (result i32)
+            (i32.atomic.rmw.add (local.get $ptr) (local.get $value))
+        )
+        (func (export "i64.atomic.rmw.add") (param $ptr i32) (param $value i64) (result i64)
+            (i64.atomic.rmw.add (local.get $ptr) (local.get $value))
+        )
+        (func (export "i32.atomic.rmw8.add_u") (param $ptr i32) (param $value i32) (result i32)
+            (i32.atomic.rmw8.add_u (local.get $ptr) (local.get $value))
+        )
+        (func (export "i32.atomic.rmw16.add_u") (param $ptr i32) (param $value i32) (result i32)
+            (i32.atomic.rmw16.add_u (local.get $ptr) (local.get $value))
+        )
+        (func (export "i64.atomic.rmw8.add_u") (param $ptr i32) (param $value i64) (result i64)
+            (i64.atomic.rmw8.add_u (local.get $ptr) (local.get $value))
+        )
+        (func (export "i64.atomic.rmw16.add_u") (param $ptr i32) (param $value i64) (result i64)
+            (i64.atomic.rmw16.add_u (local.get $ptr) (local.get $value))
+        )
+        (func (export "i64.atomic.rmw32.add_u") (param $ptr i32) (param $value i64) (result i64)
+            (i64.atomic.rmw32.add_u (local.get $ptr) (local.get $value))
+        )
+        (func (export "memory.atomic.notify") (param $ptr i32) (param $count i32) (result i32)
+            (memory.atomic.notify (local.get $ptr) (local.get $count))
+        )
+        (func (export "memory.atomic.wait32") (param $ptr i32) (param $expected i32) (result i32)
+            (memory.atomic.wait32 (local.get $ptr) (local.get $expected) (i64.const -1))
+        )
+        (func (export "memory.atomic.wait64") (param $ptr i32) (param $expected i64) (result i32)
+            (memory.atomic.wait64 (local.get $ptr) (local.get $expected) (i64.const -1))
+        )
+        (func (export "atomic.fence")
+            (atomic.fence)
+        )
+    )"#
+}
+
+/// Instantiates the test module and returns its exported linear memory.
+fn test_setup() -> (Store<()>, Instance, Memory) {
+    test_setup_with(&test_config())
+}
+
+/// Instantiates the test module using `config` and returns its exported linear memory.
+fn test_setup_with(config: &Config) -> (Store<()>, Instance, Memory) {
+    let engine = Engine::new(config);
+    let mut store = Store::new(&engine, ());
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let memory = instance.get_memory(&store, "mem").unwrap();
+    (store, instance, memory)
+}
+
+/// Calls the exported function `name` of `instance` with `params`.
+fn call<Params, Results>(
+    store: &mut Store<()>,
+    instance: Instance,
+    name: &str,
+    params: Params,
+) -> Result<Results, wasmi::Error>
+where
+    Params: WasmParams,
+    Results: WasmResults,
+{
+    instance
+        .get_typed_func::<Params, Results>(&*store, name)
+        .unwrap()
+        .call(store, params)
+}
+
+/// Asserts that `result` is a trap with the `expected` [`TrapCode`].
+#[track_caller]
+fn assert_trap<T>(result: Result<T, wasmi::Error>, expected: TrapCode) {
+    match result {
+        Ok(_) => panic!("expected a trap with {expected:?}"),
+        Err(error) => assert_eq!(error.as_trap_code(), Some(expected)),
+    }
+}
+
+#[test]
+fn atomic_load_store_works() {
+    let (mut store, instance, memory) = test_setup();
+    call::<_, ()>(
+        &mut store,
+        instance,
+        "i32.atomic.store",
+        (8, 0x1234_5678_i32),
+    )
+    .unwrap();
+    assert_eq!(memory.data(&store)[8..12], 0x1234_5678_i32.to_le_bytes());
+    assert_eq!(
+        call::<_, i32>(&mut store, instance, "i32.atomic.load", 8).unwrap(),
+        0x1234_5678
+    );
+    call::<_, ()>(&mut store, instance, "i64.atomic.store", (16, -2_i64)).unwrap();
+    assert_eq!(memory.data(&store)[16..24], (-2_i64).to_le_bytes());
+    assert_eq!(
+        call::<_, i64>(&mut store, instance, "i64.atomic.load", 16).unwrap(),
+        -2
+    );
+}
+
+#[test]
+fn atomic_rmw_add_works() {
+    let (mut store, instance, memory) = test_setup();
+    memory.data_mut(&mut store)[0..8].copy_from_slice(&u64::MAX.to_le_bytes());
+    // The `rmw8` variant only modifies a single byte and wraps on overflow.
+    let old = call::<_, i32>(&mut store, instance, "i32.atomic.rmw8.add_u", (0, 2)).unwrap();
+    assert_eq!(old, 0xFF);
+    assert_eq!(memory.data(&store)[0..2], [0x01, 0xFF]);
+    let old = call::<_, i32>(&mut store, instance, "i32.atomic.rmw16.add_u", (2, 3)).unwrap();
+    assert_eq!(old, 0xFFFF);
+    assert_eq!(memory.data(&store)[2..5], [0x02, 0x00, 0xFF]);
+    let old = call::<_, i64>(&mut store, instance, "i64.atomic.rmw8.add_u", (4, 1_i64)).unwrap();
+    assert_eq!(old, 0xFF);
+    let old = call::<_, i64>(&mut store, instance, "i64.atomic.rmw16.add_u", (6, 1_i64)).unwrap();
+    assert_eq!(old, 0xFFFF);
+    assert_eq!(memory.data(&store)[4..8], [0x00, 0xFF, 0x00, 0x00]);
+    let old = call::<_, i64>(&mut store, instance, "i64.atomic.rmw32.add_u", (0, 1_i64)).unwrap();
+    assert_eq!(old, 0x0002_FF01);
+    assert_eq!(
+        call::<_, i32>(&mut store, instance, "i32.atomic.load", 0).unwrap(),
+        0x0002_FF02
+    );
+    let old = call::<_, i32>(&mut store, instance, "i32.atomic.rmw.add", (8, 5)).unwrap();
+    assert_eq!(old, 0);
+    let old = call::<_, i32>(&mut store, instance, "i32.atomic.rmw.add", (8, -1)).unwrap();
+    assert_eq!(old, 5);
+    assert_eq!(
+        call::<_, i32>(&mut store, instance, "i32.atomic.load", 8).unwrap(),
+        4
+    );
+    let old = call::<_, i64>(&mut store, instance, "i64.atomic.rmw.add", (16, i64::MAX)).unwrap();
+    assert_eq!(old, 0);
+    let old = call::<_, i64>(&mut store, instance, "i64.atomic.rmw.add", (16, 1_i64)).unwrap();
+    assert_eq!(old, i64::MAX);
+    assert_eq!(
+        call::<_, i64>(&mut store, instance, "i64.atomic.load", 16).unwrap(),
+        i64::MIN
+    );
+}
+
+#[test]
+fn unaligned_access_traps() {
+    let (mut store, instance, memory) = test_setup();
+    assert_trap(
+        call::<_, i32>(&mut store, instance, "i32.atomic.load", 2),
+        TrapCode::UnalignedAtomic,
+    );
+    assert_trap(
+        call::<_, i64>(&mut store, instance, "i64.atomic.load", 4),
+        TrapCode::UnalignedAtomic,
+    );
+    assert_trap(
+        call::<_, ()>(&mut store, instance, "i32.atomic.store", (1, -1)),
+        TrapCode::UnalignedAtomic,
+    );
+    assert_trap(
+        call::<_, ()>(&mut store, instance, "i64.atomic.store", (12, -1_i64)),
+        TrapCode::UnalignedAtomic,
+    );
+    assert_trap(
+        call::<_, i32>(&mut store, instance, "i32.atomic.rmw.add", (6, 1)),
+        TrapCode::UnalignedAtomic,
+    );
+    assert_trap(
+        call::<_, i32>(&mut store, instance, "i32.atomic.rmw16.add_u", (3, 1)),
+        TrapCode::UnalignedAtomic,
+    );
+    assert_trap(
+        call::<_, i64>(&mut store, instance, "i64.atomic.rmw32.add_u", (2, 1_i64)),
+        TrapCode::UnalignedAtomic,
+    );
+    assert_trap(
+        call::<_, i32>(&mut store, instance, "memory.atomic.notify", (1, 1)),
+        TrapCode::UnalignedAtomic,
+    );
+    // Trapping atomic instructions must not modify the linear memory.
+    assert!(memory.data(&store)[0..32].iter().all(|&byte| byte == 0));
+}
+
+#[test]
+fn relaxed_alignment_performs_unaligned_access() {
+    let mut config = test_config();
+    config.relaxed_atomic_alignment(true);
+    let (mut store, instance, memory) = test_setup_with(&config);
+    call::<_, ()>(
+        &mut store,
+        instance,
+        "i32.atomic.store",
+        (1, 0x1234_5678_i32),
+    )
+    .unwrap();
+    assert_eq!(memory.data(&store)[1..5], 0x1234_5678_i32.to_le_bytes());
+    assert_eq!(
+        call::<_, i32>(&mut store, instance, "i32.atomic.load", 1).unwrap(),
+        0x1234_5678
+    );
+    call::<_, ()>(&mut store, instance, "i64.atomic.store", (12, -2_i64)).unwrap();
+    assert_eq!(memory.data(&store)[12..20], (-2_i64).to_le_bytes());
+    assert_eq!(
+        call::<_, i64>(&mut store, instance, "i64.atomic.load", 12).unwrap(),
+        -2
+    );
+    let old = call::<_, i32>(&mut store, instance, "i32.atomic.rmw.add", (1, 1)).unwrap();
+    assert_eq!(old, 0x1234_5678);
+    let old = call::<_, i32>(&mut store, instance, "i32.atomic.rmw16.add_u", (3, 1)).unwrap();
+    assert_eq!(old, 0x1234);
+    assert_eq!(
+        call::<_, i32>(&mut store, instance, "i32.atomic.load", 1).unwrap(),
+        0x1235_5679
+    );
+    assert_eq!(
+        call::<_, i32>(&mut store, instance, "memory.atomic.notify", (1, 1)).unwrap(),
+        0
+    );
+    // Relaxed alignment checks do not relax bounds checks.
+    assert_trap(
+        call::<_, ()>(&mut store, instance, "i64.atomic.store", (65532, 1_i64)),
+        TrapCode::MemoryOutOfBounds,
+    );
+    // The default remains spec compliant.
+    let (mut store, instance, _memory) = test_setup();
+    assert_trap(
+        call::<_, i32>(&mut store, instance, "i32.atomic.load", 1),
+        TrapCode::UnalignedAtomic,
+    );
+}
+
+#[test]
+fn out_of_bounds_access_traps() {
+    let (mut store, instance, _memory) = test_setup();
+    let end = 65536;
+    assert_trap(
+        call::<_, i32>(&mut store, instance, "i32.atomic.load", end),
+        TrapCode::MemoryOutOfBounds,
+    );
+    assert_trap(
+        call::<_, ()>(&mut store, instance, "i64.atomic.store", (end - 4, 1_i64)),
+        TrapCode::UnalignedAtomic,
+    );
+    assert_trap(
+        call::<_, ()>(&mut store, instance, "i64.atomic.store", (end, 1_i64)),
+        TrapCode::MemoryOutOfBounds,
+    );
+    assert_trap(
+        call::<_, i32>(&mut store, instance, "i32.atomic.rmw8.add_u", (end, 1)),
+        TrapCode::MemoryOutOfBounds,
+    );
+    assert_trap(
+        call::<_, i32>(&mut store, instance, "memory.atomic.notify", (end, 1)),
+        TrapCode::MemoryOutOfBounds,
+    );
+}
+
+#[test]
+fn notify_returns_zero() {
+    let (mut store, instance, _memory) = test_setup();
+    assert_eq!(
+        call::<_, i32>(&mut store, instance, "memory.atomic.notify", (0, 10)).unwrap(),
+        0
+    );
+}
+
+#[test]
+fn wait_on_unshared_memory_traps() {
+    let (mut store, instance, _memory) = test_setup();
+    assert_trap(
+        call::<_, i32>(&mut store, instance, "memory.atomic.wait32", (0, 0)),
+        TrapCode::ExpectedSharedMemory,
+    );
+    assert_trap(
+        call::<_, i32>(&mut store, instance, "memory.atomic.wait64", (0, 0_i64)),
+        TrapCode::ExpectedSharedMemory,
+    );
+    assert_trap(
+        call::<_, i32>(&mut store, instance, "memory.atomic.wait64", (4, 0_i64)),
+        TrapCode::UnalignedAtomic,
+    );
+}
+
+#[test]
+fn fence_works() {
+    let (mut store, instance, _memory) = test_setup();
+    call::<_, ()>(&mut store, instance, "atomic.fence", ()).unwrap();
+}
+
+#[test]
+fn threads_disabled_fails_to_validate() {
+    let engine = Engine::default();
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let error = Module::new(&engine, &wasm[..]).unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::Wasm(_)));
+}
+
+#[test]
+fn shared_memory_is_unsupported() {
+    let engine = Engine::new(&test_config());
+    for wat in [
+        r#"(module (memory 1 1 shared))"#,
+        r#"(module (import "env" "mem" (memory 1 1 shared)))"#,
+    ] {
+        let wasm = wat::parse_str(wat).unwrap();
+        let error = Module::new(&engine, &wasm[..]).unwrap_err();
+        assert!(matches!(error.kind(), ErrorKind::Translation(_)));
+        assert!(
+            error.to_string().contains("shared linear memory"),
+            "unexpected error message: {error}"
+        );
+    }
+}
+
+#[test]
+fn unsupported_atomic_operator_fails_to_translate() {
+    let engine = Engine::new(&test_config());
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (memory 1)
+            (func (param i32 i32) (result i32)
+                (i32.atomic.rmw.sub (local.get 0) (local.get 1))
+            )
+        )"#,
+    )
+    .unwrap();
+    let error = Module::new(&engine, &wasm[..]).unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::Translation(_)));
+    assert!(
+        error.to_string().contains("I32AtomicRmwSub"),
+        "unexpected error message: {error}"
+    );
+}
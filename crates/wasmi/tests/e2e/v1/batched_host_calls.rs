@@ -0,0 +1,137 @@
+//! Tests to check that batched host function calls behave the same as individual host function calls.
+
+use wasmi::{Caller, Engine, Func, Instance, Linker, Module, Store};
+
+/// The test module calling the imported host functions multiple times in a row.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (import "host" "combine" (func $combine (param i32 i32) (result i32)))
+        (import "host" "next" (func $next (result i64)))
+        (func (export "combine") (param $a i32) (param $b i32) (result i32)
+            (call $combine (local.get $a) (i32.const 1))
+            (call $combine (local.get $b) (i32.const 2))
+            (call $combine (local.get $a) (local.get $b))
+            (call $combine (local.get $b) (i32.const 4))
+            ;; The next call depends on the result of the previous call.
+            (i32.const 5)
+            (call $combine)
+            (i32.add)
+            (i32.add)
+            (i32.add)
+        )
+        (func (export "next") (result i64)
+            (call $next)
+            (call $next)
+            (call $next)
+            (i64.const 100)
+            (i64.mul)
+            (i64.add)
+            (i64.const 100)
+            (i64.mul)
+            (i64.add)
+        )
+    )"#
+}
+
+/// The host state recording the number of argument sets of each host call.
+#[derive(Default)]
+struct HostState {
+    batches: Vec<usize>,
+    counter: i64,
+}
+
+/// Combines `lhs` and `rhs` in an order dependent way.
+fn combine(lhs: i32, rhs: i32) -> i32 {
+    lhs.wrapping_mul(31).wrapping_add(rhs)
+}
+
+/// Instantiates the test module with batched or individual host functions.
+fn test_setup(batched: bool) -> (Store<HostState>, Instance) {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, HostState::default());
+    let (host_combine, host_next) = if batched {
+        let host_combine = Func::wrap_batched(
+            &mut store,
+            |mut caller: Caller<HostState>, args: &[(i32, i32)]| {
+                caller.data_mut().batches.push(args.len());
+                args.iter()
+                    .map(|&(lhs, rhs)| combine(lhs, rhs))
+                    .collect::<Vec<i32>>()
+            },
+        );
+        let host_next =
+            Func::wrap_batched(&mut store, |mut caller: Caller<HostState>, args: &[()]| {
+                caller.data_mut().batches.push(args.len());
+                args.iter()
+                    .map(|_| {
+                        caller.data_mut().counter += 1;
+                        caller.data().counter
+                    })
+                    .collect::<Vec<i64>>()
+            });
+        (host_combine, host_next)
+    } else {
+        let host_combine = Func::wrap(
+            &mut store,
+            |mut caller: Caller<HostState>, lhs: i32, rhs: i32| {
+                caller.data_mut().batches.push(1);
+                combine(lhs, rhs)
+            },
+        );
+        let host_next = Func::wrap(&mut store, |mut caller: Caller<HostState>| {
+            caller.data_mut().batches.push(1);
+            caller.data_mut().counter += 1;
+            caller.data().counter
+        });
+        (host_combine, host_next)
+    };
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut linker = <Linker<HostState>>::new(&engine);
+    linker.define("host", "combine", host_combine).unwrap();
+    linker.define("host", "next", host_next).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+#[test]
+fn batched_matches_individual_calls() {
+    let (mut batched, batched_instance) = test_setup(true);
+    let (mut individual, individual_instance) = test_setup(false);
+    let batched_combine = batched_instance
+        .get_typed_func::<(i32, i32), i32>(&batched, "combine")
+        .unwrap();
+    let individual_combine = individual_instance
+        .get_typed_func::<(i32, i32), i32>(&individual, "combine")
+        .unwrap();
+    for (a, b) in [(0, 0), (1, 2), (-7, 13), (i32::MAX, i32::MIN)] {
+        assert_eq!(
+            batched_combine.call(&mut batched, (a, b)).unwrap(),
+            individual_combine.call(&mut individual, (a, b)).unwrap(),
+        );
+    }
+    // The independent adjacent calls are batched while the dependent call is not.
+    assert_eq!(batched.data().batches, [4, 1].repeat(4));
+    assert_eq!(individual.data().batches, [1; 20]);
+}
+
+#[test]
+fn batched_calls_preserve_order() {
+    let (mut batched, batched_instance) = test_setup(true);
+    let (mut individual, individual_instance) = test_setup(false);
+    let batched_next = batched_instance
+        .get_typed_func::<(), i64>(&batched, "next")
+        .unwrap();
+    let individual_next = individual_instance
+        .get_typed_func::<(), i64>(&individual, "next")
+        .unwrap();
+    assert_eq!(individual_next.call(&mut individual, ()).unwrap(), 30_201);
+    assert_eq!(batched_next.call(&mut batched, ()).unwrap(), 30_201);
+    assert_eq!(batched_next.call(&mut batched, ()).unwrap(), 60_504);
+    assert_eq!(batched.data().batches, [3, 3]);
+}
@@ -0,0 +1,118 @@
+//! Tests to check if wasmi's `memory.copy` and `memory.fill` work as intended.
+
+use wasmi::{core::TrapCode, Engine, Instance, Linker, Memory, Module, Store};
+
+/// The size of a single linear memory page in bytes.
+const PAGE_SIZE: usize = 0x1_0000;
+
+/// The test module exporting its memory and functions using `memory.copy` and `memory.fill`.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (memory (export "mem") 2)
+        (func (export "copy") (param $dst i32) (param $src i32) (param $len i32)
+            (memory.copy (local.get $dst) (local.get $src) (local.get $len))
+        )
+        (func (export "fill") (param $dst i32) (param $value i32) (param $len i32)
+            (memory.fill (local.get $dst) (local.get $value) (local.get $len))
+        )
+    )"#
+}
+
+/// Instantiates the test module and returns its exported [`Memory`].
+fn test_setup() -> (Store<()>, Instance, Memory) {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let memory = instance.get_memory(&store, "mem").unwrap();
+    (store, instance, memory)
+}
+
+/// Calls the exported `name` function of `instance` with the `(u32, u32, u32)` `params`.
+fn call(
+    store: &mut Store<()>,
+    instance: Instance,
+    name: &str,
+    params: (u32, u32, u32),
+) -> Result<(), wasmi::Error> {
+    instance
+        .get_typed_func::<(u32, u32, u32), ()>(&*store, name)
+        .unwrap()
+        .call(store, params)
+}
+
+/// Writes the bytes `0, 1, 2, ...` into `memory` at `offset` with `len` bytes.
+fn write_sequence(store: &mut Store<()>, memory: Memory, offset: usize, len: usize) {
+    for (n, byte) in memory.data_mut(store)[offset..(offset + len)]
+        .iter_mut()
+        .enumerate()
+    {
+        *byte = n as u8;
+    }
+}
+
+#[test]
+fn copy_overlapping_forward() {
+    let (mut store, instance, memory) = test_setup();
+    write_sequence(&mut store, memory, 0, 8);
+    // Copies `[0..8)` to `[4..12)` where `dst > src` overlaps the source region.
+    call(&mut store, instance, "copy", (4, 0, 8)).unwrap();
+    assert_eq!(
+        &memory.data(&store)[..12],
+        &[0, 1, 2, 3, 0, 1, 2, 3, 4, 5, 6, 7]
+    );
+}
+
+#[test]
+fn copy_overlapping_backward() {
+    let (mut store, instance, memory) = test_setup();
+    write_sequence(&mut store, memory, 4, 8);
+    // Copies `[4..12)` to `[0..8)` where `dst < src` overlaps the source region.
+    call(&mut store, instance, "copy", (0, 4, 8)).unwrap();
+    assert_eq!(
+        &memory.data(&store)[..12],
+        &[0, 1, 2, 3, 4, 5, 6, 7, 4, 5, 6, 7]
+    );
+}
+
+#[test]
+fn copy_out_of_bounds_traps_without_mutation() {
+    let (mut store, instance, memory) = test_setup();
+    let len = 2 * PAGE_SIZE;
+    write_sequence(&mut store, memory, 0, 16);
+    let before = memory.data(&store).to_vec();
+    let dst = (len - 4) as u32;
+    let error = call(&mut store, instance, "copy", (dst, 0, 8)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::MemoryOutOfBounds));
+    let src = (len - 4) as u32;
+    let error = call(&mut store, instance, "copy", (0, src, 8)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::MemoryOutOfBounds));
+    assert_eq!(memory.data(&store), &before[..]);
+}
+
+#[test]
+fn fill_spanning_page_boundary() {
+    let (mut store, instance, memory) = test_setup();
+    let dst = PAGE_SIZE - 16;
+    let len = 32;
+    call(&mut store, instance, "fill", (dst as u32, 0x42, len as u32)).unwrap();
+    let data = memory.data(&store);
+    assert_eq!(data[dst - 1], 0x00);
+    assert!(data[dst..(dst + len)].iter().all(|byte| *byte == 0x42));
+    assert_eq!(data[dst + len], 0x00);
+}
+
+#[test]
+fn fill_out_of_bounds_traps_without_mutation() {
+    let (mut store, instance, memory) = test_setup();
+    let dst = (2 * PAGE_SIZE - 4) as u32;
+    let error = call(&mut store, instance, "fill", (dst, 0x42, 8)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::MemoryOutOfBounds));
+    assert!(memory.data(&store).iter().all(|byte| *byte == 0x00));
+}
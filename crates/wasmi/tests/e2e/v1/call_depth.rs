@@ -0,0 +1,118 @@
+//! Tests for `Config::max_call_depth` and `Store::call_stack_depth`.
+
+use wasmi::{core::TrapCode, Caller, Config, Engine, Extern, Func, Linker, Module, Store};
+
+/// The maximum call depth used throughout the tests.
+const MAX_DEPTH: usize = 50;
+
+/// Creates a [`Store`] whose [`Engine`] limits the call depth to [`MAX_DEPTH`].
+fn test_store() -> Store<Vec<usize>> {
+    let mut config = Config::default();
+    config.max_call_depth(MAX_DEPTH);
+    let engine = Engine::new(&config);
+    Store::new(&engine, Vec::new())
+}
+
+#[test]
+fn unbounded_recursion_traps() {
+    let mut store = test_store();
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (func (export "run")
+                (call 0)
+            )
+        )"#,
+    )
+    .unwrap();
+    let module = Module::new(store.engine(), &wasm[..]).unwrap();
+    let instance = Linker::new(store.engine())
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance.get_typed_func::<(), ()>(&store, "run").unwrap();
+    let error = run.call(&mut store, ()).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::StackOverflow));
+    assert_eq!(store.call_stack_depth(), 0);
+}
+
+#[test]
+fn unbounded_host_reentrance_traps() {
+    let mut store = test_store();
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (import "env" "reenter" (func $reenter))
+            (func (export "run")
+                (call $reenter)
+            )
+        )"#,
+    )
+    .unwrap();
+    let module = Module::new(store.engine(), &wasm[..]).unwrap();
+    let reenter = Func::wrap(
+        &mut store,
+        |mut caller: Caller<Vec<usize>>| -> Result<(), wasmi::Error> {
+            let depth = caller.call_stack_depth();
+            caller.data_mut().push(depth);
+            let Some(Extern::Func(run)) = caller.get_export("run") else {
+                panic!("missing exported `run` function")
+            };
+            run.call(&mut caller, &[], &mut [])
+        },
+    );
+    let mut linker = <Linker<Vec<usize>>>::new(store.engine());
+    linker.define("env", "reenter", reenter).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance.get_typed_func::<(), ()>(&store, "run").unwrap();
+    let error = run.call(&mut store, ()).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::StackOverflow));
+    assert_eq!(store.call_stack_depth(), 0);
+    // Each re-entrance of the host function observes one more Wasm call frame.
+    let expected = (1..=MAX_DEPTH).collect::<Vec<_>>();
+    assert_eq!(store.data(), &expected);
+}
+
+#[test]
+fn call_stack_depth_works() {
+    let mut store = test_store();
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (import "env" "record" (func $record))
+            (func $f1
+                (call $record)
+                (call $f2)
+            )
+            (func $f2
+                (call $record)
+            )
+            (func (export "run")
+                (call $f1)
+                (call $record)
+            )
+        )"#,
+    )
+    .unwrap();
+    let module = Module::new(store.engine(), &wasm[..]).unwrap();
+    let record = Func::wrap(&mut store, |mut caller: Caller<Vec<usize>>| {
+        let depth = caller.call_stack_depth();
+        caller.data_mut().push(depth);
+    });
+    let mut linker = <Linker<Vec<usize>>>::new(store.engine());
+    linker.define("env", "record", record).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance.get_typed_func::<(), ()>(&store, "run").unwrap();
+    run.call(&mut store, ()).unwrap();
+    assert_eq!(store.data(), &[2, 3, 1]);
+    assert_eq!(store.call_stack_depth(), 0);
+}
@@ -0,0 +1,118 @@
+//! Tests for calling functions with untyped parameters and results via `Func::call_unchecked`.
+
+use wasmi::{
+    core::{UntypedValue, F64},
+    Caller,
+    Engine,
+    Func,
+    Instance,
+    Linker,
+    Module,
+    Store,
+    Value,
+};
+
+/// The test module with functions of various parameter and result types.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (import "env" "host_mul" (func $host_mul (param i64 i64) (result i64)))
+        (func (export "add") (param i32 i32) (result i32)
+            (i32.add (local.get 0) (local.get 1))
+        )
+        (func (export "swap") (param f64 i64) (result i64 f64)
+            (local.get 1)
+            (local.get 0)
+        )
+        (func (export "mul") (param i64 i64) (result i64)
+            (call $host_mul (local.get 0) (local.get 1))
+        )
+        (func (export "nop"))
+    )"#
+}
+
+/// Instantiates the test module with a host function import.
+fn test_setup() -> (Store<()>, Instance) {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut linker = <Linker<()>>::new(&engine);
+    linker
+        .func_wrap("env", "host_mul", |_caller: Caller<()>, a: i64, b: i64| {
+            a.wrapping_mul(b)
+        })
+        .unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Calls `func` via [`Func::call_unchecked`] and [`Func::call`] and asserts equal results.
+fn assert_call(store: &mut Store<()>, func: Func, params: &[Value]) {
+    let ty = func.ty(&*store);
+    let mut expected = ty
+        .results()
+        .iter()
+        .copied()
+        .map(Value::default)
+        .collect::<Vec<_>>();
+    func.call(&mut *store, params, &mut expected).unwrap();
+    let untyped_params = params
+        .iter()
+        .cloned()
+        .map(UntypedValue::from)
+        .collect::<Vec<_>>();
+    let mut results = vec![UntypedValue::default(); ty.results().len()];
+    // Safety: the parameters and results match the function type of `func`.
+    unsafe {
+        func.call_unchecked(&mut *store, &untyped_params, &mut results)
+            .unwrap()
+    };
+    let expected = expected
+        .into_iter()
+        .map(UntypedValue::from)
+        .collect::<Vec<_>>();
+    assert_eq!(results, expected);
+}
+
+#[test]
+fn call_unchecked_matches_call() {
+    let (mut store, instance) = test_setup();
+    let add = instance.get_func(&store, "add").unwrap();
+    let swap = instance.get_func(&store, "swap").unwrap();
+    let mul = instance.get_func(&store, "mul").unwrap();
+    let nop = instance.get_func(&store, "nop").unwrap();
+    let host_mul = Func::wrap(&mut store, |a: i32, b: i32| a.wrapping_mul(b));
+    assert_call(&mut store, add, &[Value::I32(1), Value::I32(2)]);
+    assert_call(&mut store, add, &[Value::I32(i32::MAX), Value::I32(1)]);
+    assert_call(
+        &mut store,
+        swap,
+        &[Value::F64(F64::from(2.5)), Value::I64(-7)],
+    );
+    assert_call(&mut store, mul, &[Value::I64(6), Value::I64(-7)]);
+    assert_call(&mut store, nop, &[]);
+    assert_call(&mut store, host_mul, &[Value::I32(3), Value::I32(5)]);
+}
+
+#[test]
+fn call_unchecked_results() {
+    let (mut store, instance) = test_setup();
+    let swap = instance.get_func(&store, "swap").unwrap();
+    let params = [
+        UntypedValue::from(F64::from(2.5)),
+        UntypedValue::from(-7_i64),
+    ];
+    let mut results = [UntypedValue::default(); 2];
+    // Safety: the parameters and results match the `(f64, i64) -> (i64, f64)` function type.
+    unsafe {
+        swap.call_unchecked(&mut store, &params, &mut results)
+            .unwrap()
+    };
+    assert_eq!(i64::from(results[0]), -7);
+    assert_eq!(f64::from(results[1]), 2.5);
+}
@@ -0,0 +1,43 @@
+//! Tests for the execution of contiguous register copies.
+
+use wasmi::{Engine, Linker, Module, Store};
+
+/// A test module rotating 3 or 5 loop parameters `n` times.
+///
+/// The loop parameters are updated via a copy of contiguous registers
+/// which is encoded as a small copy for 3 values and a generic copy for 5 values.
+fn test_module() -> &'static str {
+    include_str!("../../../benches/wat/copy_span.wat")
+}
+
+#[test]
+fn copy_span_small_and_generic_agree() {
+    let engine = Engine::default();
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let histogram = engine.instruction_histogram(&module).unwrap();
+    assert!(histogram.get("CopySpanSmall") > 0);
+    assert!(histogram.get("CopySpan") + histogram.get("CopySpanNonOverlapping") > 0);
+    let mut store = Store::new(&engine, ());
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let rotate_3 = instance
+        .get_typed_func::<i32, (i64, i64, i64)>(&store, "rotate_3")
+        .unwrap();
+    let rotate_5 = instance
+        .get_typed_func::<i32, (i64, i64, i64, i64, i64)>(&store, "rotate_5")
+        .unwrap();
+    for n in 1..=16 {
+        let expected = |len: i64| {
+            let n = i64::from(n);
+            (0..len).map(|i| (i + n) % len + 1).collect::<Vec<_>>()
+        };
+        let (a, b, c) = rotate_3.call(&mut store, n).unwrap();
+        assert_eq!([a, b, c], expected(3)[..]);
+        let (a, b, c, d, e) = rotate_5.call(&mut store, n).unwrap();
+        assert_eq!([a, b, c, d, e], expected(5)[..]);
+    }
+}
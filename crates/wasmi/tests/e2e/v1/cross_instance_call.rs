@@ -0,0 +1,52 @@
+//! Tests for calls to Wasm functions imported from another instance.
+
+use wasmi::{Engine, Linker, Module, Store};
+
+#[test]
+fn imported_wasm_func_uses_own_instance() {
+    let engine = Engine::default();
+    let mut store = <Store<()>>::new(&engine, ());
+    let mut linker = <Linker<()>>::new(&engine);
+    let wasm_a = wat::parse_str(
+        r#"
+        (module
+            (global $id (mut i32) (i32.const 1))
+            (func $nop)
+            (func (export "id") (result i32)
+                ;; The nested call must not alter the instance of `id`.
+                (call $nop)
+                (global.get $id)
+            )
+        )"#,
+    )
+    .unwrap();
+    let wasm_b = wat::parse_str(
+        r#"
+        (module
+            (import "a" "id" (func $id (result i32)))
+            (global $id (mut i32) (i32.const 2))
+            (func (export "run") (result i32)
+                (i32.add
+                    (i32.mul (call $id) (i32.const 10))
+                    (global.get $id)
+                )
+            )
+        )"#,
+    )
+    .unwrap();
+    let module_a = Module::new(&engine, &wasm_a[..]).unwrap();
+    let instance_a = linker
+        .instantiate(&mut store, &module_a)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    linker.instance(&store, "a", instance_a).unwrap();
+    let module_b = Module::new(&engine, &wasm_b[..]).unwrap();
+    let instance_b = linker
+        .instantiate(&mut store, &module_b)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance_b.get_typed_func::<(), i32>(&store, "run").unwrap();
+    assert_eq!(run.call(&mut store, ()).unwrap(), 12);
+}
@@ -0,0 +1,38 @@
+//! Tests for `Engine::features` and `Config::features`.
+
+use wasmi::{Config, Engine};
+
+#[test]
+fn default_features() {
+    let features = Engine::default().features();
+    assert!(features.mutable_global);
+    assert!(features.sign_extension);
+    assert!(features.saturating_float_to_int);
+    assert!(features.multi_value);
+    assert!(features.bulk_memory);
+    assert!(features.reference_types);
+    assert!(features.floats);
+    assert!(!features.tail_call);
+    assert!(!features.extended_const);
+    assert!(!features.threads);
+    assert!(!features.multi_memory);
+}
+
+#[test]
+fn toggled_features() {
+    let mut config = Config::default();
+    config
+        .wasm_tail_call(true)
+        .wasm_threads(true)
+        .wasm_bulk_memory(false)
+        .floats(false);
+    let expected = config.features();
+    assert!(expected.tail_call);
+    assert!(expected.threads);
+    assert!(!expected.bulk_memory);
+    assert!(!expected.floats);
+    assert!(!expected.extended_const);
+    let engine = Engine::new(&config);
+    assert_eq!(engine.features(), expected);
+    assert_ne!(engine.features(), Engine::default().features());
+}
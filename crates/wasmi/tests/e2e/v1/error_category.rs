@@ -0,0 +1,143 @@
+//! Tests to check that Wasm traps, fuel exhaustion and host errors can be told apart.
+
+use core::fmt;
+use wasmi::{
+    core::{HostError, TrapCode},
+    Caller,
+    Config,
+    Engine,
+    Error,
+    Instance,
+    Linker,
+    Module,
+    Store,
+};
+
+/// A custom host error returned by the `env.fail` host function.
+#[derive(Debug)]
+struct HostFailure;
+
+impl fmt::Display for HostFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "host failure")
+    }
+}
+
+impl HostError for HostFailure {}
+
+/// The test module provoking each category of error.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (import "env" "fail" (func $fail))
+        (import "env" "burn" (func $burn))
+        (memory 1)
+        (func (export "unreachable")
+            (unreachable)
+        )
+        (func (export "out_of_bounds")
+            (drop (i32.load (i32.const 0x1_0000)))
+        )
+        (func (export "spin")
+            (loop $continue
+                (br $continue)
+            )
+        )
+        (func (export "fail")
+            (call $fail)
+        )
+        (func (export "burn")
+            (call $burn)
+        )
+    )"#
+}
+
+/// Instantiates the test module with fuel metering enabled and a small amount of fuel.
+fn test_setup() -> (Store<()>, Instance) {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let mut config = Config::default();
+    config.consume_fuel(true);
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, ());
+    store.set_fuel(1_000).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut linker = <Linker<()>>::new(&engine);
+    linker
+        .func_wrap("env", "fail", || -> Result<(), Error> {
+            Err(Error::host(HostFailure))
+        })
+        .unwrap();
+    linker
+        .func_wrap(
+            "env",
+            "burn",
+            |mut caller: Caller<()>| -> Result<(), Error> {
+                caller.consume_fuel(u64::MAX)?;
+                Ok(())
+            },
+        )
+        .unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Calls the exported function `name` of the test module and returns its error.
+fn call_error(name: &str) -> Error {
+    let (mut store, instance) = test_setup();
+    instance
+        .get_typed_func::<(), ()>(&store, name)
+        .unwrap()
+        .call(&mut store, ())
+        .unwrap_err()
+}
+
+#[test]
+fn wasm_traps() {
+    for (name, trap_code) in [
+        ("unreachable", TrapCode::UnreachableCodeReached),
+        ("out_of_bounds", TrapCode::MemoryOutOfBounds),
+    ] {
+        let error = call_error(name);
+        assert_eq!(error.as_trap_code(), Some(trap_code));
+        assert!(!error.is_out_of_fuel());
+        assert!(!error.is_host_error());
+    }
+}
+
+#[test]
+fn out_of_fuel_during_execution() {
+    let error = call_error("spin");
+    assert_eq!(error.as_trap_code(), Some(TrapCode::OutOfFuel));
+    assert!(error.is_out_of_fuel());
+    assert!(!error.is_host_error());
+}
+
+#[test]
+fn out_of_fuel_in_host_function() {
+    let error = call_error("burn");
+    assert_eq!(error.as_trap_code(), None);
+    assert!(error.is_out_of_fuel());
+    assert!(!error.is_host_error());
+}
+
+#[test]
+fn host_error() {
+    let error = call_error("fail");
+    assert_eq!(error.as_trap_code(), None);
+    assert!(!error.is_out_of_fuel());
+    assert!(error.is_host_error());
+    assert!(error.downcast_ref::<HostFailure>().is_some());
+}
+
+#[test]
+fn other_errors() {
+    for error in [Error::new("message"), Error::i32_exit(1)] {
+        assert_eq!(error.as_trap_code(), None);
+        assert!(!error.is_out_of_fuel());
+        assert!(!error.is_host_error());
+    }
+}
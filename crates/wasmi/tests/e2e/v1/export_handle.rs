@@ -0,0 +1,79 @@
+//! Tests for resolving exports of an `Instance` via `ExportHandle`.
+
+use wasmi::{
+    errors::{ErrorKind, InstanceError},
+    Engine,
+    Instance,
+    Linker,
+    Module,
+    Store,
+};
+
+/// The test module exporting a function and a global variable.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (global $counter (export "counter") (mut i32) (i32.const 0))
+        (func (export "inc") (param i32) (result i32)
+            (global.set $counter (i32.add (global.get $counter) (local.get 0)))
+            (global.get $counter)
+        )
+    )"#
+}
+
+/// Instantiates the test module `n` times within the same [`Store`].
+fn test_setup(n: usize) -> (Store<()>, Vec<Instance>) {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let linker = <Linker<()>>::new(&engine);
+    let instances = (0..n)
+        .map(|_| {
+            linker
+                .instantiate(&mut store, &module)
+                .unwrap()
+                .start(&mut store)
+                .unwrap()
+        })
+        .collect();
+    (store, instances)
+}
+
+#[test]
+fn cached_handle_calls_correctly() {
+    let (mut store, instances) = test_setup(1);
+    let instance = instances[0];
+    let inc = instance.get_export_handle(&store, "inc").unwrap();
+    let counter = instance.get_export_handle(&store, "counter").unwrap();
+    assert_eq!(inc.instance(), instance);
+    assert!(instance.get_export_handle(&store, "missing").is_none());
+    for n in 1..=10 {
+        let func = instance
+            .resolve_export_handle(&store, inc)
+            .unwrap()
+            .into_func()
+            .unwrap()
+            .typed::<i32, i32>(&store)
+            .unwrap();
+        assert_eq!(func.call(&mut store, 2).unwrap(), 2 * n);
+    }
+    let counter = instance
+        .resolve_export_handle(&store, counter)
+        .unwrap()
+        .into_global()
+        .unwrap();
+    assert_eq!(counter.get(&store).i32(), Some(20));
+}
+
+#[test]
+fn handle_of_other_instance_errors() {
+    let (store, instances) = test_setup(2);
+    let inc = instances[0].get_export_handle(&store, "inc").unwrap();
+    let error = instances[1].resolve_export_handle(&store, inc).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Instance(InstanceError::ForeignExportHandle)
+    ));
+    assert!(instances[0].resolve_export_handle(&store, inc).is_ok());
+}
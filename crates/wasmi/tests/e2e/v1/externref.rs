@@ -0,0 +1,92 @@
+//! Tests to check that host data wrapped into an `externref` survives a round-trip through Wasm.
+
+use super::instantiate_wat;
+use wasmi::{ExternRef, FuncRef};
+
+/// A host defined type that is passed through Wasm as `externref`.
+#[derive(Debug, PartialEq)]
+struct HostData {
+    name: &'static str,
+    value: i64,
+}
+
+/// The test module stashing an `externref` in a global variable.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (global $stash (mut externref) (ref.null extern))
+        (func (export "stash") (param externref)
+            (global.set $stash (local.get 0))
+        )
+        (func (export "unstash") (result externref)
+            (global.get $stash)
+        )
+    )"#
+}
+
+#[test]
+fn externref_round_trip_via_global() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let stash = instance
+        .get_typed_func::<ExternRef, ()>(&store, "stash")
+        .unwrap();
+    let unstash = instance
+        .get_typed_func::<(), ExternRef>(&store, "unstash")
+        .unwrap();
+    assert!(unstash.call(&mut store, ()).unwrap().is_null());
+    let data = store.wrap_externref(HostData {
+        name: "answer",
+        value: 42,
+    });
+    stash.call(&mut store, data).unwrap();
+    let returned = unstash.call(&mut store, ()).unwrap();
+    assert_eq!(
+        returned.downcast_ref::<HostData, _>(&store),
+        Some(&HostData {
+            name: "answer",
+            value: 42,
+        })
+    );
+    // Downcasting to the wrong type fails gracefully.
+    assert_eq!(returned.downcast_ref::<i64, _>(&store), None);
+}
+
+#[test]
+fn externref_as_option() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let stash = instance
+        .get_typed_func::<Option<ExternRef>, ()>(&store, "stash")
+        .unwrap();
+    let unstash = instance
+        .get_typed_func::<(), Option<ExternRef>>(&store, "unstash")
+        .unwrap();
+    // A `null` reference returned by Wasm is observed as `None`.
+    assert!(unstash.call(&mut store, ()).unwrap().is_none());
+    let data = store.wrap_externref(HostData {
+        name: "answer",
+        value: 42,
+    });
+    stash.call(&mut store, Some(data)).unwrap();
+    let returned = unstash.call(&mut store, ()).unwrap().unwrap();
+    assert_eq!(
+        returned.downcast_ref::<HostData, _>(&store),
+        Some(&HostData {
+            name: "answer",
+            value: 42,
+        })
+    );
+    // Passing `None` to Wasm stores a `null` reference.
+    stash.call(&mut store, None).unwrap();
+    assert!(unstash.call(&mut store, ()).unwrap().is_none());
+}
+
+#[test]
+fn externref_as_option_type_mismatch() {
+    let (store, instance) = instantiate_wat(test_module());
+    assert!(instance
+        .get_typed_func::<(), Option<FuncRef>>(&store, "unstash")
+        .is_err());
+    assert!(instance
+        .get_typed_func::<(), i32>(&store, "unstash")
+        .is_err());
+}
@@ -0,0 +1,146 @@
+//! Tests for reclaiming unreachable `externref` host objects via `Store::gc`.
+
+use super::instantiate_wat;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use wasmi::ExternRef;
+
+/// A host object that counts how many of its instances have been dropped.
+#[derive(Debug)]
+struct Tracked {
+    value: u32,
+    drops: Arc<AtomicUsize>,
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.drops.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// The test module storing `externref` values in a table and a global variable.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (table $refs 100 externref)
+        (global $stash (mut externref) (ref.null extern))
+        (func (export "set") (param i32 externref)
+            (table.set $refs (local.get 0) (local.get 1))
+        )
+        (func (export "get") (param i32) (result externref)
+            (table.get $refs (local.get 0))
+        )
+        (func (export "clear") (param i32)
+            (table.set $refs (local.get 0) (ref.null extern))
+        )
+        (func (export "stash") (param externref)
+            (global.set $stash (local.get 0))
+        )
+    )"#
+}
+
+#[test]
+fn gc_drops_unreachable_host_objects() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let set = instance
+        .get_typed_func::<(i32, ExternRef), ()>(&store, "set")
+        .unwrap();
+    let get = instance
+        .get_typed_func::<i32, ExternRef>(&store, "get")
+        .unwrap();
+    let clear = instance.get_typed_func::<i32, ()>(&store, "clear").unwrap();
+    let stash = instance
+        .get_typed_func::<ExternRef, ()>(&store, "stash")
+        .unwrap();
+    let drops = Arc::new(AtomicUsize::new(0));
+    for i in 0..100 {
+        let object = store.wrap_externref(Tracked {
+            value: i,
+            drops: drops.clone(),
+        });
+        set.call(&mut store, (i as i32, object)).unwrap();
+    }
+    // Nothing is reclaimed while all objects are still referenced by the table.
+    assert_eq!(store.gc(), 0);
+    assert_eq!(drops.load(Ordering::SeqCst), 0);
+    // Move the object at index 1 from the table into the global variable.
+    let stashed = get.call(&mut store, 1).unwrap();
+    stash.call(&mut store, stashed).unwrap();
+    // Drop the guest references to all objects with an odd index.
+    for i in (1..100).step_by(2) {
+        clear.call(&mut store, i).unwrap();
+    }
+    // Dropping guest references alone does not reclaim anything.
+    assert_eq!(drops.load(Ordering::SeqCst), 0);
+    assert_eq!(store.gc(), 49);
+    assert_eq!(drops.load(Ordering::SeqCst), 49);
+    // Objects that are still reachable can still be accessed.
+    for i in (0..100).step_by(2) {
+        let object = get.call(&mut store, i).unwrap();
+        let tracked = object.downcast_ref::<Tracked, _>(&store).unwrap();
+        assert_eq!(tracked.value, i as u32);
+    }
+    assert_eq!(
+        stashed
+            .downcast_ref::<Tracked, _>(&store)
+            .map(|tracked| tracked.value),
+        Some(1)
+    );
+    // Host held references to reclaimed objects no longer resolve.
+    let reclaimed = {
+        let object = store.wrap_externref(Tracked {
+            value: 1000,
+            drops: drops.clone(),
+        });
+        set.call(&mut store, (3, object)).unwrap();
+        clear.call(&mut store, 3).unwrap();
+        object
+    };
+    // Reclaiming is idempotent.
+    assert_eq!(store.gc(), 1);
+    assert_eq!(store.gc(), 0);
+    assert_eq!(drops.load(Ordering::SeqCst), 50);
+    assert!(reclaimed.data(&store).is_none());
+    assert!(reclaimed.downcast_ref::<Tracked, _>(&store).is_none());
+    // Objects allocated after reclaiming others are unaffected.
+    let fresh = store.wrap_externref(Tracked {
+        value: 2000,
+        drops: drops.clone(),
+    });
+    set.call(&mut store, (5, fresh)).unwrap();
+    assert_eq!(store.gc(), 0);
+    assert_eq!(
+        get.call(&mut store, 5)
+            .unwrap()
+            .downcast_ref::<Tracked, _>(&store)
+            .map(|tracked| tracked.value),
+        Some(2000)
+    );
+}
+
+#[test]
+fn gc_never_runs_implicitly() {
+    let (mut store, _instance) = instantiate_wat(test_module());
+    let drops = Arc::new(AtomicUsize::new(0));
+    let objects = (0..10)
+        .map(|value| {
+            store.wrap_externref(Tracked {
+                value,
+                drops: drops.clone(),
+            })
+        })
+        .collect::<Vec<_>>();
+    // None of the objects are reachable from Wasm but they are
+    // kept alive until `Store::gc` is called explicitly.
+    for object in &objects {
+        assert!(object.data(&store).is_some());
+    }
+    assert_eq!(drops.load(Ordering::SeqCst), 0);
+    assert_eq!(store.gc(), 10);
+    assert_eq!(drops.load(Ordering::SeqCst), 10);
+    // Dropping the store does not drop reclaimed objects twice.
+    drop(store);
+    assert_eq!(drops.load(Ordering::SeqCst), 10);
+}
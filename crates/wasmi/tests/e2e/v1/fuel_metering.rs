@@ -1,7 +1,19 @@
 //! Tests to check if wasmi's fuel metering works as intended.
 
 use std::fmt::Debug;
-use wasmi::{core::TrapCode, Config, Engine, Error, Func, Linker, Module, Store};
+use wasmi::{
+    core::TrapCode,
+    errors::FuelError,
+    Caller,
+    Config,
+    Engine,
+    Error,
+    FuelCosts,
+    Func,
+    Linker,
+    Module,
+    Store,
+};
 
 /// Setup [`Engine`] and [`Store`] for fuel metering.
 fn test_setup() -> (Store<()>, Linker<()>) {
@@ -92,3 +104,255 @@ fn metered_i32_add() {
     assert_success(func.call(&mut store, (1, 2)));
     assert_eq!(store.fuel_consumed(), Some(3));
 }
+
+#[test]
+fn metered_call_with_fuel() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (func (export "test") (param $n i32) (result i32)
+                (loop $continue
+                    (br_if $continue
+                        (local.tee $n (i32.sub (local.get $n) (i32.const 1)))
+                    )
+                )
+                (local.get $n)
+            )
+        )
+    "#,
+    );
+    let (mut store, func) = default_test_setup(&wasm);
+    let func = func.typed::<i32, i32>(&store).unwrap();
+    store.add_fuel(1_000).unwrap();
+    // The per-call fuel limit is too tight for the loop so the call traps.
+    assert_out_of_fuel(func.call_with_fuel(&mut store, 100, 10));
+    let consumed = store.fuel_consumed().unwrap();
+    assert!(consumed <= 10);
+    // Only the fuel consumed by the trapped call has been deducted from the store.
+    assert_eq!(store.consume_fuel(0).unwrap(), 1_000 - consumed);
+    // A later call with a higher per-call fuel limit succeeds.
+    let (result, fuel_used) = func.call_with_fuel(&mut store, 5, 100).unwrap();
+    assert_eq!(result, 0);
+    assert!(fuel_used > 0 && fuel_used <= 100);
+    assert_eq!(store.fuel_consumed(), Some(consumed + fuel_used));
+    assert_eq!(store.consume_fuel(0).unwrap(), 1_000 - consumed - fuel_used);
+    // The per-call fuel limit cannot exceed the remaining fuel of the store.
+    let remaining = store.consume_fuel(0).unwrap();
+    assert_out_of_fuel(func.call_with_fuel(&mut store, i32::MAX, u64::MAX));
+    assert!(store.consume_fuel(0).unwrap() <= remaining);
+}
+
+#[test]
+fn call_with_fuel_requires_fuel_metering() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (func (export "test"))
+        )
+    "#,
+    );
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let module = create_module(&store, &wasm);
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let func = instance.get_typed_func::<(), ()>(&store, "test").unwrap();
+    assert!(func.call_with_fuel(&mut store, (), 100).is_err());
+}
+
+#[test]
+fn metered_nested_loops() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (func (export "test") (param $n i32) (param $m i32)
+                (local $j i32)
+                (loop $outer
+                    (local.set $j (local.get $m))
+                    (loop $inner
+                        (br_if $inner
+                            (local.tee $j (i32.sub (local.get $j) (i32.const 1)))
+                        )
+                    )
+                    (br_if $outer
+                        (local.tee $n (i32.sub (local.get $n) (i32.const 1)))
+                    )
+                )
+            )
+        )
+    "#,
+    );
+    let (mut store, func) = default_test_setup(&wasm);
+    let func = func.typed::<(i32, i32), ()>(&store).unwrap();
+    store.add_fuel(1_000_000).unwrap();
+    let mut fuel_for = |n: i32, m: i32| -> u64 {
+        let before = store.fuel_consumed().unwrap();
+        func.call(&mut store, (n, m)).unwrap();
+        store.fuel_consumed().unwrap() - before
+    };
+    // Each loop has its own fuel checkpoint at its header so that every
+    // iteration of the inner loop is charged independently of the outer loop:
+    //
+    // - `overhead`: fuel for the function entry outside of both loops.
+    // - `outer`: fuel per iteration of the outer loop excluding the inner loop.
+    // - `inner`: fuel per iteration of the inner loop.
+    let overhead = 2;
+    let outer = 3;
+    let inner = 2;
+    for (n, m) in [(1, 1), (1, 2), (2, 1), (3, 5), (10, 10), (7, 100)] {
+        let expected = overhead + n as u64 * outer + (n * m) as u64 * inner;
+        assert_eq!(fuel_for(n, m), expected, "n = {n}, m = {m}");
+    }
+}
+
+/// Returns a Wasm module with a `test` function that loops `$n` times.
+fn loop_module() -> Vec<u8> {
+    wat2wasm(
+        r#"
+        (module
+            (func (export "test") (param $n i32)
+                (loop $continue
+                    (br_if $continue
+                        (local.tee $n (i32.sub (local.get $n) (i32.const 1)))
+                    )
+                )
+            )
+        )
+    "#,
+    )
+}
+
+#[test]
+fn set_fuel_lets_trapping_loop_complete() {
+    let wasm = loop_module();
+    let (mut store, func) = default_test_setup(&wasm);
+    let func = func.typed::<i32, ()>(&store).unwrap();
+    assert_eq!(store.get_fuel(), Some(0));
+    store.set_fuel(10).unwrap();
+    assert_out_of_fuel(func.call(&mut store, 100));
+    let remaining = store.get_fuel().unwrap();
+    assert!(remaining < 10);
+    assert_eq!(store.fuel_consumed(), Some(10 - remaining));
+    // Top up the fuel so that the previously trapping loop completes.
+    store.set_fuel(1_000).unwrap();
+    let consumed_before = store.fuel_consumed().unwrap();
+    assert_success(func.call(&mut store, 100));
+    let remaining = store.get_fuel().unwrap();
+    let consumed = store.fuel_consumed().unwrap() - consumed_before;
+    assert!(consumed > 0);
+    assert_eq!(remaining + consumed, 1_000);
+    // Host side consumption draws from the same fuel counter.
+    assert_eq!(store.consume_fuel(remaining).unwrap(), 0);
+    assert_eq!(store.get_fuel(), Some(0));
+    assert_out_of_fuel(func.call(&mut store, 1));
+}
+
+#[test]
+fn host_and_guest_share_fuel() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (import "env" "charge" (func $charge (param i64)))
+            (func (export "test") (param $amount i64)
+                (call $charge (local.get $amount))
+            )
+        )
+    "#,
+    );
+    let (mut store, mut linker) = test_setup();
+    linker
+        .func_wrap("env", "charge", |mut caller: Caller<()>, amount: i64| {
+            let before = caller.get_fuel().unwrap();
+            let remaining = caller.consume_fuel(amount as u64)?;
+            assert_eq!(caller.get_fuel(), Some(remaining));
+            assert_eq!(before - remaining, amount as u64);
+            Ok(())
+        })
+        .unwrap();
+    let module = create_module(&store, &wasm);
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let func = instance.get_typed_func::<i64, ()>(&store, "test").unwrap();
+    store.set_fuel(100).unwrap();
+    func.call(&mut store, 50).unwrap();
+    let remaining = store.get_fuel().unwrap();
+    assert!(remaining < 50, "guest execution must consume fuel as well");
+    assert_eq!(store.fuel_consumed(), Some(100 - remaining));
+    // The host function fails to consume more fuel than remaining.
+    let error = func.call(&mut store, 100).unwrap_err();
+    assert!(
+        error.to_string().contains("all fuel consumed"),
+        "unexpected error message: {error}"
+    );
+}
+
+#[test]
+fn fuel_api_requires_fuel_metering() {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    assert_eq!(store.get_fuel(), None);
+    assert!(matches!(
+        store.set_fuel(100),
+        Err(FuelError::FuelMeteringDisabled)
+    ));
+    assert!(matches!(
+        store.consume_fuel(1),
+        Err(FuelError::FuelMeteringDisabled)
+    ));
+}
+
+#[test]
+fn custom_fuel_costs() {
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (func (export "test") (param $n i32) (result i32)
+                (local $acc i32)
+                (loop $continue
+                    (local.set $acc (i32.add (local.get $acc) (local.get $n)))
+                    (local.tee $n (i32.sub (local.get $n) (i32.const 1)))
+                    (br_if $continue)
+                )
+                (local.get $acc)
+            )
+        )
+    "#,
+    );
+    /// Returns the result and consumed fuel of running the test function on an [`Engine`] of `config`.
+    fn run(config: &Config, wasm: &[u8]) -> (i32, u64) {
+        let engine = Engine::new(config);
+        let mut store = Store::new(&engine, ());
+        let module = Module::new(&engine, wasm).unwrap();
+        let func = Linker::new(&engine)
+            .instantiate(&mut store, &module)
+            .unwrap()
+            .start(&mut store)
+            .unwrap()
+            .get_typed_func::<i32, i32>(&store, "test")
+            .unwrap();
+        store.set_fuel(10_000).unwrap();
+        let result = func.call(&mut store, 10).unwrap();
+        (result, store.fuel_consumed().unwrap())
+    }
+    let mut config = Config::default();
+    config.consume_fuel(true);
+    let (default_result, default_consumed) = run(&config, &wasm);
+    let mut costs = FuelCosts::default();
+    costs.set_base(10);
+    config.set_fuel_costs(costs);
+    // Engines created from the same `Config` behave identically.
+    let (result_a, consumed_a) = run(&config, &wasm);
+    let (result_b, consumed_b) = run(&config, &wasm);
+    assert_eq!(default_result, 55);
+    assert_eq!(
+        (result_a, consumed_a),
+        (default_result, default_consumed * 10)
+    );
+    assert_eq!((result_a, consumed_a), (result_b, consumed_b));
+}
@@ -72,6 +72,38 @@ fn dynamic_add2_works() {
     }
 }
 
+#[test]
+fn dynamic_add2_iter_works() {
+    let (mut store, add2, add2_dyn) = setup_add2();
+    for a in 0..10 {
+        for b in 0..10 {
+            let params = vec![Value::I32(a), Value::I32(b)];
+            let expected = a + b;
+            let mut result = Value::I32(0);
+            // Call with the inputs yielded by a `Vec` iterator.
+            add2.call_iter(&mut store, params.clone(), slice::from_mut(&mut result))
+                .unwrap();
+            assert_eq!(result.i32(), Some(expected));
+            // Reset result before execution.
+            result = Value::I32(0);
+            // Call with the inputs yielded by a slice iterator.
+            add2_dyn
+                .call_iter(
+                    &mut store,
+                    params[..].iter().cloned(),
+                    slice::from_mut(&mut result),
+                )
+                .unwrap();
+            assert_eq!(result.i32(), Some(expected));
+        }
+    }
+    // Calling with a mismatching number of inputs fails.
+    let mut result = Value::I32(0);
+    assert!(add2
+        .call_iter(&mut store, [Value::I32(1)], slice::from_mut(&mut result))
+        .is_err());
+}
+
 #[test]
 fn static_add2_works() {
     let (mut store, add2, add2_dyn) = setup_add2();
@@ -531,3 +563,147 @@ fn static_type_check_works() {
         ErrorKind::Func(FuncError::MismatchingResultType)
     );
 }
+
+#[test]
+fn static_typed_twice_works() {
+    let (mut store, func, func_dyn) = setup_add2();
+    // Case: A mismatching signature errors upon the first `typed` call.
+    assert_matches!(
+        func_dyn
+            .typed::<(i32, i32), i64>(&store)
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Func(FuncError::MismatchingResultType)
+    );
+    let typed0 = func.typed::<(i32, i32), i32>(&store).unwrap();
+    let typed1 = func.typed::<(i32, i32), i32>(&store).unwrap();
+    for (lhs, rhs) in [(1, 2), (-5, 3), (i32::MIN, 7)] {
+        assert_eq!(
+            typed0.call(&mut store, (lhs, rhs)).unwrap(),
+            typed1.call(&mut store, (lhs, rhs)).unwrap(),
+        );
+    }
+    // Case: A mismatching signature errors even after successful `typed` calls.
+    assert_matches!(
+        func.typed::<(i32, i64), i32>(&store).unwrap_err().kind(),
+        ErrorKind::Func(FuncError::MismatchingParameterType)
+    );
+    assert_matches!(
+        func.typed::<(i32, i32), ()>(&store).unwrap_err().kind(),
+        ErrorKind::Func(FuncError::MismatchingResultLen)
+    );
+}
+
+#[test]
+fn static_twelve_params_works() {
+    let mut store = test_setup();
+    let func = Func::wrap(
+        &mut store,
+        |v0: i32,
+         v1: i64,
+         v2: F32,
+         v3: F64,
+         v4: i32,
+         v5: i64,
+         v6: F32,
+         v7: F64,
+         v8: i32,
+         v9: i64,
+         v10: F32,
+         v11: F64| {
+            F64::from(
+                f64::from(v0 + v4 + v8)
+                    + (v1 + v5 + v9) as f64
+                    + f64::from(f32::from(v2) + f32::from(v6) + f32::from(v10))
+                    + f64::from(v3)
+                    + f64::from(v7)
+                    + f64::from(v11),
+            )
+        },
+    );
+    assert_eq!(func.ty(&store).params().len(), 12);
+    let typed_func = func
+        .typed::<(i32, i64, F32, F64, i32, i64, F32, F64, i32, i64, F32, F64), F64>(&mut store)
+        .unwrap();
+    let inputs = (
+        1,
+        2,
+        3.0.into(),
+        4.0.into(),
+        5,
+        6,
+        7.0.into(),
+        8.0.into(),
+        9,
+        10,
+        11.0.into(),
+        12.0.into(),
+    );
+    assert_eq!(
+        typed_func.call(&mut store, inputs).unwrap(),
+        F64::from(78.0)
+    );
+}
+
+/// Returns a dynamically typed [`Func`] that adds two `i32` values.
+///
+/// The returned function returns an `i64` result instead of an `i32` result if `lhs` is negative.
+fn setup_dynamic_add2() -> (Store<()>, Func) {
+    let mut store = test_setup();
+    let add2 = Func::new_dynamic(
+        &mut store,
+        FuncType::new([ValueType::I32, ValueType::I32], [ValueType::I32]),
+        |_caller, inputs: &[Value]| {
+            let lhs = inputs[0].i32().unwrap();
+            let rhs = inputs[1].i32().unwrap();
+            match lhs.is_negative() {
+                true => Ok(vec![Value::I64(i64::from(lhs + rhs))]),
+                false => Ok(vec![Value::I32(lhs + rhs)]),
+            }
+        },
+    );
+    (store, add2)
+}
+
+#[test]
+fn new_dynamic_works() {
+    let (mut store, add2) = setup_dynamic_add2();
+    let mut result = Value::I32(0);
+    add2.call(
+        &mut store,
+        &[Value::I32(1), Value::I32(2)],
+        slice::from_mut(&mut result),
+    )
+    .unwrap();
+    assert_eq!(result.i32(), Some(3));
+    let typed_add2 = add2.typed::<(i32, i32), i32>(&store).unwrap();
+    assert_eq!(typed_add2.call(&mut store, (3, 4)).unwrap(), 7);
+}
+
+#[test]
+fn new_dynamic_type_check_works() {
+    let (mut store, add2) = setup_dynamic_add2();
+    let mut result = Value::I32(0);
+    // Case: Mismatching type given as input to function.
+    assert_matches!(
+        add2.call(
+            &mut store,
+            &[Value::I32(1), Value::I64(2)],
+            slice::from_mut(&mut result),
+        )
+        .unwrap_err()
+        .kind(),
+        ErrorKind::Func(FuncError::MismatchingParameterType)
+    );
+    // Case: Mismatching type returned from the dynamic closure.
+    assert_matches!(
+        add2.call(
+            &mut store,
+            &[Value::I32(-1), Value::I32(2)],
+            slice::from_mut(&mut result),
+        )
+        .unwrap_err()
+        .kind(),
+        ErrorKind::Func(FuncError::MismatchingResultType)
+    );
+}
@@ -0,0 +1,109 @@
+//! Tests to check that repeated calls via `Func::call` do not allocate after warmup.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+use wasmi::{Caller, Engine, Func, Linker, Module, Store, Value};
+
+/// A global allocator that counts the allocations of the current thread.
+///
+/// # Note
+///
+/// Allocations are counted per thread since tests are run concurrently.
+struct CountingAllocator;
+
+thread_local! {
+    /// The number of allocations performed by the current thread.
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations performed by the current thread so far.
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+#[test]
+fn repeated_calls_do_not_allocate() {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (import "env" "host" (func $host (param i64) (result i64)))
+            (func (export "wasm") (param i32 i64) (result i64 i32)
+                (call $host (local.get 1))
+                (local.get 0)
+            )
+        )"#,
+    )
+    .unwrap();
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut linker = <Linker<()>>::new(&engine);
+    let host = Func::wrap(&mut store, |value: i64| value.wrapping_add(1));
+    linker.define("env", "host", host).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let wasm = instance.get_func(&store, "wasm").unwrap();
+    let inputs = [Value::I32(42), Value::I64(1)];
+    let mut outputs = [Value::I32(0), Value::I32(0)];
+    // Warmup: the first calls may allocate engine stacks and compile functions lazily.
+    for _ in 0..2 {
+        wasm.call(&mut store, &inputs, &mut outputs).unwrap();
+        host.call(&mut store, &inputs[1..], &mut outputs[..1])
+            .unwrap();
+    }
+    let before = allocations();
+    for _ in 0..100 {
+        wasm.call(&mut store, &inputs, &mut outputs).unwrap();
+        host.call(&mut store, &inputs[1..], &mut outputs[..1])
+            .unwrap();
+    }
+    assert_eq!(allocations(), before);
+    assert_eq!(outputs[0].i64(), Some(2));
+}
+
+#[test]
+fn mismatching_results_len_fails_before_execution() {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, 0_u32);
+    let func = Func::wrap(&mut store, |mut caller: Caller<'_, u32>| {
+        *caller.data_mut() += 1;
+        1_i32
+    });
+    assert!(func.call(&mut store, &[], &mut []).is_err());
+    assert!(func
+        .call(&mut store, &[], &mut [Value::I32(0), Value::I32(0)])
+        .is_err());
+    assert_eq!(*store.data(), 0);
+    let mut outputs = [Value::I32(0)];
+    func.call(&mut store, &[], &mut outputs).unwrap();
+    assert_eq!(*store.data(), 1);
+}
@@ -0,0 +1,55 @@
+//! Tests for querying the register counts of compiled functions via `Module::func_max_registers`.
+
+use wasmi::{CompilationMode, Config, Engine, Module};
+
+/// The test module with small functions of known register counts.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (import "host" "f" (func))
+        (func (export "add") (param i32 i32) (result i32)
+            (i32.add (local.get 0) (local.get 1))
+        )
+        (func (export "add_large_const") (param i32) (result i64)
+            (local i64)
+            (local.set 1
+                (i64.add (i64.extend_i32_s (local.get 0)) (i64.const 0x1_0000_0000))
+            )
+            (local.get 1)
+        )
+        (func (export "nop"))
+    )"#
+}
+
+/// Compiles the test module using `mode`.
+fn compile(mode: CompilationMode) -> Module {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let mut config = Config::default();
+    config.compilation_mode(mode);
+    let engine = Engine::new(&config);
+    Module::new(&engine, &wasm[..]).unwrap()
+}
+
+/// Asserts the register counts of the functions of the test module compiled with `mode`.
+fn assert_max_registers(mode: CompilationMode) {
+    let module = compile(mode);
+    // The imported function has no register window.
+    assert_eq!(module.func_max_registers(0), None);
+    // 2 parameters + 1 temporary result.
+    assert_eq!(module.func_max_registers(1), Some(3));
+    // 1 parameter + 1 local + 1 temporary + 1 function local constant.
+    assert_eq!(module.func_max_registers(2), Some(4));
+    assert_eq!(module.func_max_registers(3), Some(0));
+    assert_eq!(module.func_max_registers(4), None);
+    assert_eq!(module.func_max_registers(u32::MAX), None);
+}
+
+#[test]
+fn eager() {
+    assert_max_registers(CompilationMode::Eager)
+}
+
+#[test]
+fn lazy() {
+    assert_max_registers(CompilationMode::Lazy)
+}
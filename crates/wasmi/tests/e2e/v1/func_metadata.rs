@@ -0,0 +1,66 @@
+//! Tests for querying statistics of compiled functions via `Module::func_metadata`.
+
+use wasmi::{CompilationMode, Config, Engine, Module};
+
+/// The test module with functions of known register, constant and instruction counts.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (import "host" "f" (func))
+        (func (export "add") (param i32 i32) (result i32)
+            (i32.add (local.get 0) (local.get 1))
+        )
+        (func (export "large_consts") (param i64 f64) (result i64)
+            (i64.add (local.get 0) (i64.const 0x1_0000_0000))
+            (i64.mul (i64.const 0x2_0000_0000))
+            (i64.xor (i64.const 0x1_0000_0000))
+            (i64.trunc_f64_s (f64.add (local.get 1) (f64.const 0.1)))
+            (i64.add)
+        )
+    )"#
+}
+
+/// Compiles the test module using `mode`.
+fn compile(mode: CompilationMode) -> Module {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let mut config = Config::default();
+    config.compilation_mode(mode);
+    let engine = Engine::new(&config);
+    Module::new(&engine, &wasm[..]).unwrap()
+}
+
+/// Asserts the metadata of the functions of the test module compiled with `mode`.
+fn assert_func_metadata(mode: CompilationMode) {
+    let module = compile(mode);
+    // The imported function has no metadata.
+    assert!(module.func_metadata(0).is_none());
+    let add = module.func_metadata(1).unwrap();
+    // 2 parameters + 1 temporary result.
+    assert_eq!(add.max_registers(), 3);
+    assert_eq!(add.len_consts(), 0);
+    // i32.add + return
+    assert_eq!(add.len_instrs(), 2);
+    let large_consts = module.func_metadata(2).unwrap();
+    // 2 parameters + 3 function local constants + 2 temporaries.
+    assert_eq!(large_consts.max_registers(), 7);
+    // The duplicate `i64.const 0x1_0000_0000` is deduplicated.
+    assert_eq!(large_consts.len_consts(), 3);
+    // i64.{add, mul, xor} + f64.add + i64.trunc_f64_s + i64.add + return
+    assert_eq!(large_consts.len_instrs(), 7);
+    assert_eq!(
+        module.func_max_registers(2),
+        Some(large_consts.max_registers())
+    );
+    assert!(module.func_metadata(3).is_none());
+    assert!(module.func_metadata(u32::MAX).is_none());
+}
+
+#[test]
+fn eager() {
+    assert_func_metadata(CompilationMode::Eager)
+}
+
+#[test]
+fn lazy() {
+    assert_func_metadata(CompilationMode::Lazy)
+}
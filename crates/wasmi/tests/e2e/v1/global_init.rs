@@ -0,0 +1,81 @@
+//! Tests for global initializer expressions that depend on imported globals.
+
+use wasmi::{
+    core::ValueType,
+    Config,
+    Engine,
+    Global,
+    Instance,
+    Linker,
+    Module,
+    Mutability,
+    Store,
+    Value,
+};
+
+/// The test module importing `env.g` and `env.k` and defining globals depending on them.
+///
+/// The start function stores the value of `$h` observed upon start into `$seen`.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (import "env" "g" (global $g i32))
+        (import "env" "k" (global $k i32))
+        (global $h (export "h") i32 (i32.add (global.get $g) (i32.const 1)))
+        (global $i (export "i") i32 (i32.sub (global.get $k) (global.get $g)))
+        (global $seen (export "seen") (mut i32) (i32.const 0))
+        (func $start
+            (global.set $seen (global.get $h))
+        )
+        (start $start)
+    )"#
+}
+
+/// Instantiates the test module with the imported globals `env.g` and `env.k` set to `g` and `k`.
+fn test_setup(g: i32, k: i32) -> (Store<()>, Instance) {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let mut config = Config::default();
+    config.wasm_extended_const(true);
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut linker = <Linker<()>>::new(&engine);
+    let g = Global::new(&mut store, Value::I32(g), Mutability::Const);
+    let k = Global::new(&mut store, Value::I32(k), Mutability::Const);
+    linker.define("env", "g", g).unwrap();
+    linker.define("env", "k", k).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Returns the `i32` value of the global exported as `name`.
+fn get_i32(store: &Store<()>, instance: Instance, name: &str) -> i32 {
+    let global = instance.get_global(store, name).unwrap();
+    assert_eq!(global.ty(store).content(), ValueType::I32);
+    global.get(store).i32().unwrap()
+}
+
+#[test]
+fn init_from_imported_global() {
+    let (store, instance) = test_setup(42, 100);
+    assert_eq!(get_i32(&store, instance, "h"), 43);
+    assert_eq!(get_i32(&store, instance, "i"), 58);
+    // The start function observes the initialized globals.
+    assert_eq!(get_i32(&store, instance, "seen"), 43);
+}
+
+#[test]
+fn init_per_instantiation() {
+    let (store, instance) = test_setup(-1, 1);
+    assert_eq!(get_i32(&store, instance, "h"), 0);
+    assert_eq!(get_i32(&store, instance, "i"), 2);
+    assert_eq!(get_i32(&store, instance, "seen"), 0);
+    let (store, instance) = test_setup(i32::MAX, 0);
+    assert_eq!(get_i32(&store, instance, "h"), i32::MIN);
+    assert_eq!(get_i32(&store, instance, "i"), -i32::MAX);
+    assert_eq!(get_i32(&store, instance, "seen"), i32::MIN);
+}
@@ -0,0 +1,166 @@
+//! Tests to check that `funcref` and `externref` global variables keep their references intact.
+
+use wasmi::{
+    errors::GlobalError,
+    Engine,
+    ExternRef,
+    Func,
+    FuncRef,
+    Global,
+    Linker,
+    Module,
+    Mutability,
+    Store,
+    Value,
+};
+
+/// A host defined type that is referenced by `externref` global variables.
+#[derive(Debug, PartialEq)]
+struct HostData(&'static str);
+
+/// Instantiates the test module importing the `funcref` and `externref` global variables.
+fn test_setup(store: &mut Store<()>, funcref: Global, externref: Global) -> wasmi::Instance {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (import "env" "funcref" (global $funcref (mut funcref)))
+            (import "env" "externref" (global $externref (mut externref)))
+            (table $t 1 funcref)
+            (func (export "get_externref") (result externref)
+                (global.get $externref)
+            )
+            (func (export "set_externref") (param externref)
+                (global.set $externref (local.get 0))
+            )
+            (func (export "call_funcref") (result i32)
+                (table.set $t (i32.const 0) (global.get $funcref))
+                (call_indirect $t (result i32) (i32.const 0))
+            )
+        )"#,
+    )
+    .unwrap();
+    let module = Module::new(store.engine(), &wasm[..]).unwrap();
+    let mut linker = <Linker<()>>::new(store.engine());
+    linker.define("env", "funcref", funcref).unwrap();
+    linker.define("env", "externref", externref).unwrap();
+    linker
+        .instantiate(&mut *store, &module)
+        .unwrap()
+        .start(&mut *store)
+        .unwrap()
+}
+
+/// Returns the [`HostData`] referenced by `value` if any.
+fn host_data<'a>(store: &'a Store<()>, value: &Value) -> Option<&'a HostData> {
+    match value {
+        Value::ExternRef(externref) => externref.downcast_ref::<HostData, _>(store),
+        _ => panic!("expected an externref value but found: {value:?}"),
+    }
+}
+
+#[test]
+fn externref_global_survives_store_mutation() {
+    let mut store = <Store<()>>::new(&Engine::default(), ());
+    let data = ExternRef::new(&mut store, HostData("original"));
+    let global = Global::new(&mut store, Value::ExternRef(data), Mutability::Var);
+    // Drop the original handle and mutate the store by allocating more objects.
+    #[allow(dropping_copy_types)]
+    drop(data);
+    for n in 0..100 {
+        let other = ExternRef::new(&mut store, HostData("other"));
+        Global::new(&mut store, Value::ExternRef(other), Mutability::Const);
+        Global::new(&mut store, Value::I32(n), Mutability::Var);
+    }
+    assert_eq!(
+        host_data(&store, &global.get(&store)),
+        Some(&HostData("original"))
+    );
+    // Reading the global variable from Wasm yields the same reference.
+    let funcref = Global::new(&mut store, Value::FuncRef(FuncRef::null()), Mutability::Var);
+    let instance = test_setup(&mut store, funcref, global);
+    let get = instance
+        .get_typed_func::<(), ExternRef>(&store, "get_externref")
+        .unwrap();
+    let set = instance
+        .get_typed_func::<ExternRef, ()>(&store, "set_externref")
+        .unwrap();
+    let returned = get.call(&mut store, ()).unwrap();
+    assert_eq!(
+        returned.downcast_ref::<HostData, _>(&store),
+        Some(&HostData("original"))
+    );
+    // Setting the global variable from Wasm is observable by the host.
+    let updated = ExternRef::new(&mut store, HostData("updated"));
+    set.call(&mut store, updated).unwrap();
+    assert_eq!(
+        host_data(&store, &global.get(&store)),
+        Some(&HostData("updated"))
+    );
+    set.call(&mut store, ExternRef::null()).unwrap();
+    assert_eq!(host_data(&store, &global.get(&store)), None);
+}
+
+#[test]
+fn funcref_global_keeps_identity() {
+    let mut store = <Store<()>>::new(&Engine::default(), ());
+    let f1 = Func::wrap(&mut store, || 1_i32);
+    let f2 = Func::wrap(&mut store, || 2_i32);
+    let funcref = Global::new(
+        &mut store,
+        Value::FuncRef(FuncRef::new(f1)),
+        Mutability::Var,
+    );
+    let externref = Global::new(
+        &mut store,
+        Value::ExternRef(ExternRef::null()),
+        Mutability::Var,
+    );
+    let instance = test_setup(&mut store, funcref, externref);
+    let call = instance
+        .get_typed_func::<(), i32>(&store, "call_funcref")
+        .unwrap();
+    assert_eq!(call.call(&mut store, ()).unwrap(), 1);
+    funcref
+        .set(&mut store, Value::FuncRef(FuncRef::new(f2)))
+        .unwrap();
+    assert_eq!(call.call(&mut store, ()).unwrap(), 2);
+    let Value::FuncRef(value) = funcref.get(&store) else {
+        panic!("expected a funcref value")
+    };
+    let func = value.func().unwrap().typed::<(), i32>(&store).unwrap();
+    assert_eq!(func.call(&mut store, ()).unwrap(), 2);
+    // Setting a reference of the wrong type fails.
+    assert!(matches!(
+        funcref.set(&mut store, Value::ExternRef(ExternRef::null())),
+        Err(GlobalError::TypeMismatch { .. })
+    ));
+}
+
+#[test]
+#[should_panic(expected = "does not belong to store")]
+fn externref_global_of_foreign_store() {
+    let engine = Engine::default();
+    let mut store = <Store<()>>::new(&engine, ());
+    let mut other = <Store<()>>::new(&engine, ());
+    let data = ExternRef::new(&mut other, HostData("foreign"));
+    let global = Global::new(
+        &mut store,
+        Value::ExternRef(ExternRef::null()),
+        Mutability::Var,
+    );
+    let _ = global.set(&mut store, Value::ExternRef(data));
+}
+
+#[test]
+#[should_panic(expected = "does not belong to store")]
+fn funcref_global_of_foreign_store() {
+    let engine = Engine::default();
+    let mut store = <Store<()>>::new(&engine, ());
+    let mut other = <Store<()>>::new(&engine, ());
+    let func = Func::wrap(&mut other, || ());
+    Global::new(
+        &mut store,
+        Value::FuncRef(FuncRef::new(func)),
+        Mutability::Const,
+    );
+}
@@ -0,0 +1,80 @@
+//! Tests to check that [`Store::snapshot_globals`] and [`Store::restore_globals`]
+//! properly roll back the state of mutable global variables.
+
+use wasmi::{Engine, Global, Instance, Linker, Module, Mutability, Store, Value};
+
+/// Instantiates the test module importing `imported` as mutable global variable.
+fn test_setup() -> (Store<()>, Instance, Global) {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (import "env" "imported" (global $imported (mut i64)))
+            (global $counter (export "counter") (mut i32) (i32.const 0))
+            (global $other (export "other") (mut f32) (f32.const 1.5))
+            (global $const (export "const") i32 (i32.const 42))
+            (func (export "mutate")
+                (global.set $counter (i32.add (global.get $counter) (i32.const 1)))
+                (global.set $other (f32.const -2.5))
+                (global.set $imported (i64.const 100))
+            )
+        )"#,
+    )
+    .unwrap();
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut linker = <Linker<()>>::new(&engine);
+    let imported = Global::new(&mut store, Value::I64(7), Mutability::Var);
+    linker.define("env", "imported", imported).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance, imported)
+}
+
+/// Returns the values of the `counter`, `other` and `imported` global variables.
+fn global_values(store: &Store<()>, instance: Instance, imported: Global) -> (i32, f32, i64) {
+    let get = |name| instance.get_global(store, name).unwrap().get(store);
+    (
+        get("counter").i32().unwrap(),
+        get("other").f32().unwrap().into(),
+        imported.get(store).i64().unwrap(),
+    )
+}
+
+#[test]
+fn restore_globals_works() {
+    let (mut store, instance, imported) = test_setup();
+    let mutate = instance.get_typed_func::<(), ()>(&store, "mutate").unwrap();
+    let snapshot = store.snapshot_globals(&instance);
+    // Only the 3 mutable global variables are part of the snapshot.
+    assert_eq!(snapshot.len(), 3);
+    assert_eq!(global_values(&store, instance, imported), (0, 1.5, 7));
+    mutate.call(&mut store, ()).unwrap();
+    mutate.call(&mut store, ()).unwrap();
+    assert_eq!(global_values(&store, instance, imported), (2, -2.5, 100));
+    store.restore_globals(&snapshot);
+    assert_eq!(global_values(&store, instance, imported), (0, 1.5, 7));
+    // Snapshots can be restored multiple times.
+    mutate.call(&mut store, ()).unwrap();
+    store.restore_globals(&snapshot);
+    assert_eq!(global_values(&store, instance, imported), (0, 1.5, 7));
+    // Host mutations are rolled back as well.
+    imported.set(&mut store, Value::I64(-1)).unwrap();
+    store.restore_globals(&snapshot);
+    assert_eq!(global_values(&store, instance, imported), (0, 1.5, 7));
+    // Immutable global variables are unaffected.
+    let value = instance.get_global(&store, "const").unwrap().get(&store);
+    assert_eq!(value.i32(), Some(42));
+}
+
+#[test]
+#[should_panic]
+fn restore_globals_from_other_store_panics() {
+    let (store, instance, _) = test_setup();
+    let snapshot = store.snapshot_globals(&instance);
+    let (mut other, _, _) = test_setup();
+    other.restore_globals(&snapshot);
+}
@@ -0,0 +1,113 @@
+//! Tests to check that [`Store::watch_global`] reports guest mutations of global variables.
+
+use super::instantiate_wat;
+use std::sync::{Arc, Mutex};
+use wasmi::{Global, GlobalWatchHandle, Instance, Store, Value};
+
+/// The test module with two mutable global variables and functions mutating them.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (global $counter (export "counter") (mut i32) (i32.const 0))
+        (global $other (export "other") (mut i64) (i64.const 0))
+        (func (export "inc")
+            (global.set $counter (i32.add (global.get $counter) (i32.const 1)))
+        )
+        (func (export "reset")
+            (global.set $counter (i32.const 0))
+        )
+        (func (export "set_other") (param i64)
+            (global.set $other (local.get 0))
+        )
+    )"#
+}
+
+/// A log of all old and new values reported by a global variable watch.
+type WatchLog = Arc<Mutex<Vec<(Value, Value)>>>;
+
+/// Calls the exported function `name` of `instance` with `params`.
+fn call(store: &mut Store<()>, instance: Instance, name: &str, params: &[Value]) {
+    instance
+        .get_func(&mut *store, name)
+        .unwrap()
+        .call(&mut *store, params, &mut [])
+        .unwrap();
+}
+
+/// Returns the exported global variable `name` of `instance`.
+fn global(store: &Store<()>, instance: Instance, name: &str) -> Global {
+    instance.get_global(store, name).unwrap()
+}
+
+/// Registers a watch on `global` that records all changes into the returned log.
+fn watch(store: &mut Store<()>, global: Global) -> (GlobalWatchHandle, WatchLog) {
+    let log = WatchLog::default();
+    let handle = store.watch_global(global, {
+        let log = log.clone();
+        move |old, new| log.lock().unwrap().push((old, new))
+    });
+    (handle, log)
+}
+
+/// Returns the recorded changes of `log` as `i64` pairs.
+fn changes(log: &Mutex<Vec<(Value, Value)>>) -> Vec<(i64, i64)> {
+    let as_i64 = |value: &Value| match value {
+        Value::I32(value) => i64::from(*value),
+        Value::I64(value) => *value,
+        _ => panic!("unexpected global value: {value:?}"),
+    };
+    log.lock()
+        .unwrap()
+        .iter()
+        .map(|(old, new)| (as_i64(old), as_i64(new)))
+        .collect()
+}
+
+#[test]
+fn watch_reports_old_and_new_values() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let counter = global(&store, instance, "counter");
+    let (_handle, log) = watch(&mut store, counter);
+    call(&mut store, instance, "inc", &[]);
+    call(&mut store, instance, "inc", &[]);
+    call(&mut store, instance, "inc", &[]);
+    call(&mut store, instance, "reset", &[]);
+    assert_eq!(changes(&log), [(0, 1), (1, 2), (2, 3), (3, 0)]);
+    assert_eq!(counter.get(&store).i32(), Some(0));
+}
+
+#[test]
+fn watch_ignores_other_globals() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let counter = global(&store, instance, "counter");
+    let other = global(&store, instance, "other");
+    let (_handle, counter_log) = watch(&mut store, counter);
+    let (_handle, other_log) = watch(&mut store, other);
+    call(&mut store, instance, "set_other", &[Value::I64(42)]);
+    call(&mut store, instance, "set_other", &[Value::I64(-1)]);
+    assert_eq!(changes(&counter_log), []);
+    assert_eq!(changes(&other_log), [(0, 42), (42, -1)]);
+}
+
+#[test]
+fn watch_ignores_host_mutation() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let counter = global(&store, instance, "counter");
+    let (_handle, log) = watch(&mut store, counter);
+    counter.set(&mut store, Value::I32(10)).unwrap();
+    call(&mut store, instance, "inc", &[]);
+    assert_eq!(changes(&log), [(10, 11)]);
+}
+
+#[test]
+fn unwatch_works() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let counter = global(&store, instance, "counter");
+    let (handle, log) = watch(&mut store, counter);
+    call(&mut store, instance, "inc", &[]);
+    assert!(store.unwatch_global(handle));
+    assert!(!store.unwatch_global(handle));
+    call(&mut store, instance, "inc", &[]);
+    assert_eq!(changes(&log), [(0, 1)]);
+    assert_eq!(counter.get(&store).i32(), Some(2));
+}
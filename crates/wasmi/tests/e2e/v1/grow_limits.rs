@@ -0,0 +1,84 @@
+//! Tests to check if `memory.grow` and `table.grow` respect declared and configured limits.
+
+use wasmi::{Config, Engine, Instance, Linker, Module, Store};
+
+/// The test module with a linear memory and a table with declared maximum sizes.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (memory 1 10)
+        (table 1 10 funcref)
+        (func (export "memory.grow") (param i32) (result i32)
+            (memory.grow (local.get 0))
+        )
+        (func (export "memory.size") (result i32)
+            (memory.size)
+        )
+        (func (export "table.grow") (param i32) (result i32)
+            (table.grow (ref.null func) (local.get 0))
+        )
+        (func (export "table.size") (result i32)
+            (table.size)
+        )
+    )"#
+}
+
+/// Instantiates the test module with the given `config`.
+fn test_setup(config: &Config) -> (Store<()>, Instance) {
+    let engine = Engine::new(config);
+    let mut store = Store::new(&engine, ());
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Grows the entity using the exported `{name}.grow` function by `delta`.
+///
+/// Returns the result of the `{name}.grow` and the `{name}.size` after the operation.
+fn grow(store: &mut Store<()>, instance: Instance, name: &str, delta: u32) -> (i32, u32) {
+    let grow = instance
+        .get_typed_func::<u32, i32>(&*store, &format!("{name}.grow"))
+        .unwrap();
+    let size = instance
+        .get_typed_func::<(), u32>(&*store, &format!("{name}.size"))
+        .unwrap();
+    let result = grow.call(&mut *store, delta).unwrap();
+    (result, size.call(store, ()).unwrap())
+}
+
+#[test]
+fn grow_within_limits() {
+    let (mut store, instance) = test_setup(&Config::default());
+    for name in ["memory", "table"] {
+        assert_eq!(grow(&mut store, instance, name, 2), (1, 3));
+        assert_eq!(grow(&mut store, instance, name, 7), (3, 10));
+        assert_eq!(grow(&mut store, instance, name, 0), (10, 10));
+    }
+}
+
+#[test]
+fn grow_past_declared_maximum() {
+    let (mut store, instance) = test_setup(&Config::default());
+    for name in ["memory", "table"] {
+        assert_eq!(grow(&mut store, instance, name, 10), (-1, 1));
+        assert_eq!(grow(&mut store, instance, name, u32::MAX), (-1, 1));
+    }
+}
+
+#[test]
+fn grow_past_configured_maximum() {
+    let mut config = Config::default();
+    config.max_memory_pages(4).max_table_elements(5);
+    let (mut store, instance) = test_setup(&config);
+    assert_eq!(grow(&mut store, instance, "memory", 4), (-1, 1));
+    assert_eq!(grow(&mut store, instance, "memory", 3), (1, 4));
+    assert_eq!(grow(&mut store, instance, "memory", 1), (-1, 4));
+    assert_eq!(grow(&mut store, instance, "table", 5), (-1, 1));
+    assert_eq!(grow(&mut store, instance, "table", 4), (1, 5));
+    assert_eq!(grow(&mut store, instance, "table", 1), (-1, 5));
+}
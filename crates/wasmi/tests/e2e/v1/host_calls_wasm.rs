@@ -1,7 +1,7 @@
 //! Test to assert that host functions that call back into
 //! Wasm works correctly.
 
-use wasmi::{Caller, Engine, Extern, Func, Linker, Module, Store};
+use wasmi::{Caller, Engine, Extern, Func, Instance, Linker, Module, Store};
 
 fn test_setup() -> (Store<()>, Linker<()>) {
     let engine = Engine::default();
@@ -57,3 +57,120 @@ fn host_calls_wasm() {
     let result = wasm_fn.call(&mut store, input).unwrap();
     assert_eq!(result, expected);
 }
+
+/// Instantiates a module that imports `env.host_fn` and exports `add`, `malloc` and `memory`.
+///
+/// The exported `add` function adds `bias` to the sum of its parameters which
+/// allows to tell apart the [`Instance`] that has been called back into.
+fn instantiate_guest(store: &mut Store<()>, linker: &Linker<()>, bias: i32) -> Instance {
+    let wasm = wat::parse_str(format!(
+        r#"
+        (module
+            (import "env" "host_fn" (func $host_fn (param i32 i32) (result i32)))
+            (memory (export "memory") 1)
+            (global $heap (mut i32) (i32.const 16))
+            (func (export "wasm_fn") (param i32 i32) (result i32)
+                (call $host_fn (local.get 0) (local.get 1))
+            )
+            (func (export "add") (param i32 i32) (result i32)
+                (i32.add
+                    (i32.add (local.get 0) (local.get 1))
+                    (i32.const {bias})
+                )
+            )
+            (func (export "malloc") (param $size i32) (result i32)
+                (global.get $heap)
+                (global.set $heap (i32.add (global.get $heap) (local.get $size)))
+            )
+            (func (export "load") (param $ptr i32) (result i32)
+                (i32.load (local.get $ptr))
+            )
+        )
+        "#,
+    ))
+    .unwrap();
+    let module = Module::new(store.engine(), &wasm[..]).unwrap();
+    linker
+        .instantiate(&mut *store, &module)
+        .unwrap()
+        .start(&mut *store)
+        .unwrap()
+}
+
+/// Host function calling back into the `add` export of the calling [`Instance`].
+fn host_add(mut caller: Caller<()>, lhs: i32, rhs: i32) -> i32 {
+    let Some(Extern::Func(add)) = caller.get_export("add") else {
+        return -1;
+    };
+    add.typed::<(i32, i32), i32>(&caller)
+        .unwrap()
+        .call(&mut caller, (lhs, rhs))
+        .unwrap()
+}
+
+#[test]
+fn host_calls_export_of_calling_instance() {
+    let (mut store, mut linker) = test_setup();
+    let host_fn = Func::wrap(&mut store, host_add);
+    linker.define("env", "host_fn", host_fn).unwrap();
+    let instance_a = instantiate_guest(&mut store, &linker, 100);
+    let instance_b = instantiate_guest(&mut store, &linker, 200);
+    let wasm_fn_a = instance_a
+        .get_typed_func::<(i32, i32), i32>(&store, "wasm_fn")
+        .unwrap();
+    let wasm_fn_b = instance_b
+        .get_typed_func::<(i32, i32), i32>(&store, "wasm_fn")
+        .unwrap();
+    assert_eq!(wasm_fn_a.call(&mut store, (1, 2)).unwrap(), 103);
+    assert_eq!(wasm_fn_b.call(&mut store, (1, 2)).unwrap(), 203);
+    // Calling the host function directly from the host has no calling instance.
+    let host_fn = host_fn.typed::<(i32, i32), i32>(&store).unwrap();
+    assert_eq!(host_fn.call(&mut store, (1, 2)).unwrap(), -1);
+}
+
+#[test]
+fn host_calls_guest_allocator() {
+    let (mut store, mut linker) = test_setup();
+    let host_fn = Func::wrap(
+        &mut store,
+        |mut caller: Caller<()>, value: i32, depth: i32| -> i32 {
+            let malloc = caller
+                .get_export("malloc")
+                .and_then(Extern::into_func)
+                .unwrap()
+                .typed::<i32, i32>(&caller)
+                .unwrap();
+            let ptr = malloc.call(&mut caller, 4).unwrap();
+            let memory = caller
+                .get_export("memory")
+                .and_then(Extern::into_memory)
+                .unwrap();
+            memory
+                .write(&mut caller, ptr as usize, &value.to_le_bytes())
+                .unwrap();
+            if depth > 0 {
+                // Re-enter the guest which in turn calls this host function again.
+                let wasm_fn = caller
+                    .get_export("wasm_fn")
+                    .and_then(Extern::into_func)
+                    .unwrap()
+                    .typed::<(i32, i32), i32>(&caller)
+                    .unwrap();
+                let nested = wasm_fn.call(&mut caller, (value + 1, depth - 1)).unwrap();
+                assert_eq!(nested, ptr + 4);
+            }
+            ptr
+        },
+    );
+    linker.define("env", "host_fn", host_fn).unwrap();
+    let instance = instantiate_guest(&mut store, &linker, 0);
+    let wasm_fn = instance
+        .get_typed_func::<(i32, i32), i32>(&store, "wasm_fn")
+        .unwrap();
+    let load = instance.get_typed_func::<i32, i32>(&store, "load").unwrap();
+    let ptr = wasm_fn.call(&mut store, (42, 3)).unwrap();
+    assert_eq!(ptr, 16);
+    for (n, ptr) in (ptr..).step_by(4).take(4).enumerate() {
+        assert_eq!(load.call(&mut store, ptr).unwrap(), 42 + n as i32);
+    }
+}
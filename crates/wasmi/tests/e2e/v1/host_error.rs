@@ -0,0 +1,148 @@
+//! Tests to check that custom [`HostError`]s returned by host functions reach the caller.
+
+use core::fmt;
+use wasmi::{
+    core::HostError,
+    Caller,
+    Engine,
+    Error,
+    Extern,
+    Instance,
+    Linker,
+    Module,
+    Store,
+    Value,
+};
+
+/// A custom host error carrying structured information.
+#[derive(Debug, PartialEq, Eq)]
+struct MyHostError {
+    code: i32,
+}
+
+impl fmt::Display for MyHostError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "my host error: {}", self.code)
+    }
+}
+
+impl HostError for MyHostError {}
+
+/// Another custom host error that must not be confused with [`MyHostError`].
+#[derive(Debug)]
+struct OtherHostError;
+
+impl fmt::Display for OtherHostError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "other host error")
+    }
+}
+
+impl HostError for OtherHostError {}
+
+/// Instantiates the test module.
+///
+/// - `env.fail` returns a [`MyHostError`] with its parameter as `code` if it is not `0`.
+/// - `env.reenter` calls back into the exported `middle` function and forwards its result.
+fn test_setup() -> (Store<()>, Instance) {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (import "env" "fail" (func $fail (param i32) (result i32)))
+            (import "env" "reenter" (func $reenter (param i32) (result i32)))
+            (func $middle (export "middle") (param i32) (result i32)
+                (i32.add (call $fail (local.get 0)) (i32.const 1))
+            )
+            (func (export "outer") (param i32) (result i32)
+                (i32.add (call $middle (local.get 0)) (i32.const 1))
+            )
+            (func (export "outer_reenter") (param i32) (result i32)
+                (i32.add (call $reenter (local.get 0)) (i32.const 1))
+            )
+        )"#,
+    )
+    .unwrap();
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut linker = <Linker<()>>::new(&engine);
+    linker
+        .func_wrap("env", "fail", |_caller: Caller<()>, code: i32| {
+            if code != 0 {
+                return Err(Error::host(MyHostError { code }));
+            }
+            Ok(code)
+        })
+        .unwrap();
+    linker
+        .func_wrap(
+            "env",
+            "reenter",
+            |mut caller: Caller<()>, code: i32| -> Result<i32, Error> {
+                let middle = caller
+                    .get_export("middle")
+                    .and_then(Extern::into_func)
+                    .unwrap()
+                    .typed::<i32, i32>(&caller)
+                    .unwrap();
+                middle.call(&mut caller, code)
+            },
+        )
+        .unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+#[test]
+fn host_error_through_wasm_frames() {
+    let (mut store, instance) = test_setup();
+    let outer = instance
+        .get_typed_func::<i32, i32>(&store, "outer")
+        .unwrap();
+    assert_eq!(outer.call(&mut store, 0).unwrap(), 2);
+    let error = outer.call(&mut store, 42).unwrap_err();
+    assert_eq!(error.as_trap_code(), None);
+    assert_eq!(
+        error.downcast_ref::<MyHostError>(),
+        Some(&MyHostError { code: 42 })
+    );
+    assert!(error.downcast_ref::<OtherHostError>().is_none());
+    assert_eq!(error.to_string(), "my host error: 42");
+    assert_eq!(
+        error.downcast::<MyHostError>(),
+        Some(MyHostError { code: 42 })
+    );
+}
+
+#[test]
+fn host_error_through_reentrant_host_frames() {
+    let (mut store, instance) = test_setup();
+    let outer = instance
+        .get_typed_func::<i32, i32>(&store, "outer_reenter")
+        .unwrap();
+    assert_eq!(outer.call(&mut store, 0).unwrap(), 2);
+    let error = outer.call(&mut store, -1).unwrap_err();
+    assert_eq!(error.as_trap_code(), None);
+    assert_eq!(
+        error.downcast_ref::<MyHostError>(),
+        Some(&MyHostError { code: -1 })
+    );
+}
+
+#[test]
+fn host_error_via_untyped_call() {
+    let (mut store, instance) = test_setup();
+    let outer = instance.get_func(&store, "outer").unwrap();
+    let mut results = [Value::I32(0)];
+    let error = outer
+        .call(&mut store, &[Value::I32(7)], &mut results)
+        .unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<MyHostError>(),
+        Some(&MyHostError { code: 7 })
+    );
+}
@@ -0,0 +1,79 @@
+//! Tests to check that `Store::with_host_trap` converts host function panics into traps.
+
+use wasmi::{Caller, Engine, Func, Instance, Linker, Module, Store, TypedResumableCall};
+
+/// Instantiates the test module whose `run` function calls the host function `env.check`.
+///
+/// The host function `env.check` panics if its parameter is `0`.
+fn test_setup(host_trap: bool) -> (Store<()>, Instance) {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (import "env" "check" (func $check (param i32)))
+            (func (export "run") (param i32) (result i32)
+                (call $check (local.get 0))
+                (i32.add (local.get 0) (i32.const 1))
+            )
+        )"#,
+    )
+    .unwrap();
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ()).with_host_trap(host_trap);
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut linker = <Linker<()>>::new(&engine);
+    linker
+        .func_wrap("env", "check", |_caller: Caller<()>, value: i32| {
+            assert_ne!(value, 0, "invalid value");
+        })
+        .unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+#[test]
+fn host_panic_traps() {
+    let (mut store, instance) = test_setup(true);
+    let run = instance.get_typed_func::<i32, i32>(&store, "run").unwrap();
+    let error = run.call(&mut store, 0).unwrap_err();
+    assert!(error.to_string().contains("host function panicked"));
+    assert!(error.to_string().contains("invalid value"));
+    // The store remains usable after the host function panicked.
+    assert_eq!(run.call(&mut store, 1).unwrap(), 2);
+    assert!(run.call(&mut store, 0).is_err());
+    assert_eq!(run.call(&mut store, 41).unwrap(), 42);
+}
+
+#[test]
+fn host_panic_traps_from_host() {
+    let (mut store, _instance) = test_setup(true);
+    let func = Func::wrap(&mut store, |value: i32| -> i32 {
+        assert_ne!(value, 0, "invalid value");
+        value
+    });
+    let func = func.typed::<i32, i32>(&store).unwrap();
+    assert!(func.call(&mut store, 0).is_err());
+    assert_eq!(func.call(&mut store, 1).unwrap(), 1);
+}
+
+#[test]
+fn host_panic_is_not_resumable() {
+    let (mut store, instance) = test_setup(true);
+    let run = instance.get_typed_func::<i32, i32>(&store, "run").unwrap();
+    assert!(run.call_resumable(&mut store, 0).is_err());
+    match run.call_resumable(&mut store, 1).unwrap() {
+        TypedResumableCall::Finished(result) => assert_eq!(result, 2),
+        TypedResumableCall::Resumable(_) => panic!("expected a finished call"),
+    }
+}
+
+#[test]
+#[should_panic(expected = "invalid value")]
+fn host_panic_unwinds_by_default() {
+    let (mut store, instance) = test_setup(false);
+    let run = instance.get_typed_func::<i32, i32>(&store, "run").unwrap();
+    let _ = run.call(&mut store, 0);
+}
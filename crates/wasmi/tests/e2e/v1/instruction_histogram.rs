@@ -0,0 +1,60 @@
+//! Tests for `Engine::instruction_histogram`.
+
+use wasmi::{CompilationMode, Config, Engine, Module};
+
+/// The test module with a known mix of `wasmi` bytecode instructions.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (import "env" "f" (func $f (param i32) (result i32)))
+        (func (param i32 i32) (result i32)
+            (i32.mul
+                (i32.add (local.get 0) (local.get 1))
+                (local.get 1)
+            )
+        )
+        (func (param i32) (result i32)
+            (i32.add (local.get 0) (i32.const 1))
+        )
+    )"#
+}
+
+/// Asserts that the instruction histogram of the test module under `config` is as expected.
+fn assert_histogram(config: &Config) {
+    let engine = Engine::new(config);
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let histogram = engine.instruction_histogram(&module).unwrap();
+    assert_eq!(
+        histogram.iter().collect::<Vec<_>>(),
+        [
+            ("I32Add", 1),
+            ("I32AddImm16", 1),
+            ("I32Mul", 1),
+            ("ReturnReg", 2),
+        ]
+    );
+    assert_eq!(histogram.get("ReturnReg"), 2);
+    assert_eq!(histogram.get("I32Sub"), 0);
+    assert_eq!(histogram.total(), 5);
+}
+
+#[test]
+fn instruction_histogram_works() {
+    assert_histogram(&Config::default());
+}
+
+#[test]
+fn instruction_histogram_translates_lazily_compiled_funcs() {
+    let mut config = Config::default();
+    config.compilation_mode(CompilationMode::Lazy);
+    assert_histogram(&config);
+}
+
+#[test]
+#[should_panic]
+fn instruction_histogram_of_foreign_module() {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let module = Module::new(&Engine::default(), &wasm[..]).unwrap();
+    let _ = Engine::default().instruction_histogram(&module);
+}
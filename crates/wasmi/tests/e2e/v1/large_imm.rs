@@ -0,0 +1,82 @@
+//! Tests for the execution of 64-bit immediate values that are not 32-bit encodable.
+
+use core::fmt::Debug;
+use wasmi::{core::F64, Engine, Instance, Linker, Module, Store, WasmResults};
+
+/// Instantiates the `wat` test module.
+fn test_setup(wat: &str) -> (Store<()>, Instance) {
+    let wasm = wat::parse_str(wat).unwrap();
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Asserts that returning and copying the 64-bit immediate `value` yields `value`.
+///
+/// - `ty` is the Wasm type of the value.
+/// - `display` is the Wasm text format representation of `value`.
+/// - `zero` is the zero value of the Wasm type.
+fn assert_imm64<T>(ty: &str, display: &str, value: T, zero: T)
+where
+    T: WasmResults + PartialEq + Debug,
+{
+    let (mut store, instance) = test_setup(&format!(
+        r#"
+        (module
+            (func (export "return") (result {ty})
+                ({ty}.const {display})
+            )
+            (func (export "copy") (param $condition i32) (result {ty})
+                (block (result {ty})
+                    ({ty}.const {display})
+                    (br_if 0 (local.get $condition))
+                    (drop)
+                    ({ty}.const 0)
+                )
+            )
+            (func (export "local") (result {ty})
+                (local {ty})
+                (local.set 0 ({ty}.const {display}))
+                (local.get 0)
+            )
+        )"#,
+    ));
+    let return_ = instance.get_typed_func::<(), T>(&store, "return").unwrap();
+    let copy = instance.get_typed_func::<i32, T>(&store, "copy").unwrap();
+    let local = instance.get_typed_func::<(), T>(&store, "local").unwrap();
+    assert_eq!(return_.call(&mut store, ()).unwrap(), value);
+    assert_eq!(copy.call(&mut store, 1).unwrap(), value);
+    assert_eq!(copy.call(&mut store, 0).unwrap(), zero);
+    assert_eq!(local.call(&mut store, ()).unwrap(), value);
+}
+
+#[test]
+fn large_i64_imm() {
+    for value in [
+        i64::MIN,
+        i64::MAX,
+        i64::from(i32::MIN) - 1,
+        i64::from(i32::MAX) + 1,
+        0x1234_5678_9ABC_DEF0,
+    ] {
+        assert_imm64("i64", &value.to_string(), value, 0);
+    }
+}
+
+#[test]
+fn large_f64_imm() {
+    for value in [0.3_f64, -0.123456789, 1.0e100, f64::MAX, f64::MIN_POSITIVE] {
+        assert_imm64(
+            "f64",
+            &format!("{value:e}"),
+            F64::from(value),
+            F64::from(0.0),
+        );
+    }
+}
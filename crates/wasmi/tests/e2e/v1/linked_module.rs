@@ -0,0 +1,156 @@
+//! Tests for `Linker::instantiate_pre` and `LinkedModule::instantiate`.
+
+use wasmi::{
+    errors::{ErrorKind, LinkerError},
+    Caller,
+    Engine,
+    Global,
+    Linker,
+    Module,
+    Mutability,
+    Store,
+    Value,
+};
+
+/// Compiles the test module importing the host function `env.count`.
+fn test_module(engine: &Engine) -> Module {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (import "env" "count" (func $count))
+            (memory (export "memory") 1)
+            (data (i32.const 0) "\2A")
+            (func (export "set") (param i32)
+                (call $count)
+                (i32.store (i32.const 4) (local.get 0))
+            )
+            (func (export "get") (result i32)
+                (i32.load (i32.const 4))
+            )
+        )"#,
+    )
+    .unwrap();
+    Module::new(engine, &wasm[..]).unwrap()
+}
+
+/// Returns a [`Linker`] defining the host function `env.count`.
+fn test_linker(engine: &Engine) -> Linker<u32> {
+    let mut linker = <Linker<u32>>::new(engine);
+    linker
+        .func_wrap("env", "count", |mut caller: Caller<u32>| {
+            *caller.data_mut() += 1;
+        })
+        .unwrap();
+    linker
+}
+
+#[test]
+fn many_instances_are_isolated() {
+    const N: i32 = 1000;
+    let engine = Engine::default();
+    let module = test_module(&engine);
+    let linked = test_linker(&engine).instantiate_pre(&module).unwrap();
+    let mut store = Store::new(&engine, 0);
+    let instances = (0..N)
+        .map(|_| {
+            linked
+                .instantiate(&mut store)
+                .unwrap()
+                .start(&mut store)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    for (n, instance) in (0..N).zip(&instances) {
+        let set = instance.get_typed_func::<i32, ()>(&store, "set").unwrap();
+        set.call(&mut store, n).unwrap();
+    }
+    assert_eq!(*store.data(), N as u32);
+    for (n, instance) in (0..N).zip(&instances) {
+        let get = instance.get_typed_func::<(), i32>(&store, "get").unwrap();
+        assert_eq!(get.call(&mut store, ()).unwrap(), n);
+        // Data segments are initialized for every instance.
+        let memory = instance.get_memory(&store, "memory").unwrap();
+        assert_eq!(memory.data(&store)[0], 0x2A);
+    }
+}
+
+#[test]
+fn instantiate_in_many_stores() {
+    let engine = Engine::default();
+    let module = test_module(&engine);
+    let linked = test_linker(&engine).instantiate_pre(&module).unwrap();
+    for n in 0..10 {
+        let mut store = Store::new(&engine, 0);
+        let instance = linked
+            .clone()
+            .instantiate(&mut store)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let set = instance.get_typed_func::<i32, ()>(&store, "set").unwrap();
+        let get = instance.get_typed_func::<(), i32>(&store, "get").unwrap();
+        set.call(&mut store, n).unwrap();
+        assert_eq!(get.call(&mut store, ()).unwrap(), n);
+        assert_eq!(*store.data(), 1);
+    }
+}
+
+#[test]
+fn instantiate_pre_checks_imports() {
+    let engine = Engine::default();
+    let module = test_module(&engine);
+    // Missing definition for `env.count`.
+    let error = <Linker<u32>>::new(&engine)
+        .instantiate_pre(&module)
+        .unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Linker(LinkerError::MissingDefinition { .. })
+    ));
+    // Mismatching type for `env.count`.
+    let mut linker = <Linker<u32>>::new(&engine);
+    linker
+        .func_wrap("env", "count", |_caller: Caller<u32>, _: i32| {})
+        .unwrap();
+    let error = linker.instantiate_pre(&module).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Linker(LinkerError::FuncTypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn instantiate_with_extern_definitions() {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (import "env" "g" (global $g i32))
+            (func (export "get") (result i32)
+                (global.get $g)
+            )
+        )"#,
+    )
+    .unwrap();
+    let engine = Engine::default();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, 0);
+    let mut linker = <Linker<u32>>::new(&engine);
+    let global = Global::new(&mut store, Value::I32(42), Mutability::Const);
+    linker.define("env", "g", global).unwrap();
+    let linked = linker.instantiate_pre(&module).unwrap();
+    for _ in 0..3 {
+        let instance = linked
+            .instantiate(&mut store)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let get = instance.get_typed_func::<(), i32>(&store, "get").unwrap();
+        assert_eq!(get.call(&mut store, ()).unwrap(), 42);
+    }
+    // Extern definitions are still type checked upon instantiation.
+    let mut linker = <Linker<u32>>::new(&engine);
+    let global = Global::new(&mut store, Value::I64(42), Mutability::Const);
+    linker.define("env", "g", global).unwrap();
+    let linked = linker.instantiate_pre(&module).unwrap();
+    assert!(linked.instantiate(&mut store).is_err());
+}
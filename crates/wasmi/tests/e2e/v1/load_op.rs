@@ -0,0 +1,135 @@
+//! Tests for the execution of fused `load` + binary operation instructions.
+
+use super::instantiate_wat;
+use wasmi::{core::TrapCode, Store};
+
+/// The test module with functions that translate to fused load-op instructions.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (memory (export "mem") 1)
+        (global $acc (export "acc") (mut i32) (i32.const 0))
+        (data (i32.const 0) "\01\00\00\00\02\00\00\00\03\00\00\00\04\00\00\00")
+        (data (i32.const 16) "\ff\00\00\00\00\00\00\80\0f\00\00\00\00\00\00\00")
+        (func (export "sum_i32") (param $ptr i32) (param $len i32) (result i32)
+            (local $acc i32)
+            (block $exit
+                (loop $continue
+                    (br_if $exit (i32.eqz (local.get $len)))
+                    (local.set $acc
+                        (i32.add (local.get $acc) (i32.load (local.get $ptr)))
+                    )
+                    (local.set $ptr (i32.add (local.get $ptr) (i32.const 4)))
+                    (local.set $len (i32.sub (local.get $len) (i32.const 1)))
+                    (br $continue)
+                )
+            )
+            (local.get $acc)
+        )
+        (func (export "i32_sub") (param $lhs i32) (param $ptr i32) (result i32)
+            (i32.sub (local.get $lhs) (i32.load offset=4 (local.get $ptr)))
+        )
+        (func (export "i32_and") (param $lhs i32) (param $ptr i32) (result i32)
+            (i32.and (i32.load offset=16 (local.get $ptr)) (local.get $lhs))
+        )
+        (func (export "i32_or") (param $lhs i32) (param $ptr i32) (result i32)
+            (i32.or (local.get $lhs) (i32.load offset=65536 (local.get $ptr)))
+        )
+        (func (export "i32_xor") (param $lhs i32) (param $ptr i32) (result i32)
+            (i32.xor (local.get $lhs) (i32.load offset=8 (local.get $ptr)))
+        )
+        (func (export "i64_add") (param $lhs i64) (param $ptr i32) (result i64)
+            (i64.add (local.get $lhs) (i64.load offset=16 (local.get $ptr)))
+        )
+        (func (export "i64_sub") (param $lhs i64) (param $ptr i32) (result i64)
+            (i64.sub (local.get $lhs) (i64.load offset=24 (local.get $ptr)))
+        )
+        (func (export "i64_and") (param $lhs i64) (param $ptr i32) (result i64)
+            (i64.and (local.get $lhs) (i64.load offset=16 (local.get $ptr)))
+        )
+        (func (export "i64_or") (param $lhs i64) (param $ptr i32) (result i64)
+            (i64.or (i64.load (local.get $ptr)) (local.get $lhs))
+        )
+        (func (export "i64_xor") (param $lhs i64) (param $ptr i32) (result i64)
+            (i64.xor (local.get $lhs) (i64.load offset=16 (local.get $ptr)))
+        )
+        (func (export "accumulate_global") (param $ptr i32)
+            (global.set $acc
+                (i32.add (global.get $acc) (i32.load (local.get $ptr)))
+            )
+        )
+        (func (export "accumulate_local") (param $acc i32) (param $ptr i32) (result i32)
+            (local.set $acc
+                (i32.add (local.get $acc) (i32.load (local.get $ptr)))
+            )
+            (local.get $acc)
+        )
+    )"#
+}
+
+#[test]
+fn fused_load_ops_compute_correct_results() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let sum_i32 = instance
+        .get_typed_func::<(i32, i32), i32>(&store, "sum_i32")
+        .unwrap();
+    assert_eq!(sum_i32.call(&mut store, (0, 4)).unwrap(), 1 + 2 + 3 + 4);
+    assert_eq!(sum_i32.call(&mut store, (4, 2)).unwrap(), 2 + 3);
+    assert_eq!(sum_i32.call(&mut store, (0, 0)).unwrap(), 0);
+
+    let i32_op = |store: &mut Store<()>, name: &str, lhs: i32, ptr: i32| -> i32 {
+        instance
+            .get_typed_func::<(i32, i32), i32>(&*store, name)
+            .unwrap()
+            .call(store, (lhs, ptr))
+            .unwrap()
+    };
+    assert_eq!(i32_op(&mut store, "i32_sub", 10, 0), 10 - 2);
+    assert_eq!(i32_op(&mut store, "i32_and", 0x0f, 0), 0xff & 0x0f);
+    assert_eq!(i32_op(&mut store, "i32_xor", 0b101, 0), 0b101 ^ 3);
+
+    let i64_op = |store: &mut Store<()>, name: &str, lhs: i64, ptr: i32| -> i64 {
+        instance
+            .get_typed_func::<(i64, i32), i64>(&*store, name)
+            .unwrap()
+            .call(store, (lhs, ptr))
+            .unwrap()
+    };
+    let loaded = i64::from_le_bytes([0xff, 0, 0, 0, 0, 0, 0, 0x80]);
+    assert_eq!(i64_op(&mut store, "i64_add", 1, 0), loaded.wrapping_add(1));
+    assert_eq!(i64_op(&mut store, "i64_sub", 20, 0), 20 - 15);
+    assert_eq!(i64_op(&mut store, "i64_and", -1, 0), loaded);
+    assert_eq!(
+        i64_op(&mut store, "i64_or", 0x100, 0),
+        0x100 | i64::from_le_bytes([1, 0, 0, 0, 2, 0, 0, 0])
+    );
+    assert_eq!(i64_op(&mut store, "i64_xor", loaded, 0), 0);
+}
+
+#[test]
+fn fused_load_ops_trap_out_of_bounds() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let accumulate_global = instance
+        .get_typed_func::<i32, ()>(&store, "accumulate_global")
+        .unwrap();
+    let accumulate_local = instance
+        .get_typed_func::<(i32, i32), i32>(&store, "accumulate_local")
+        .unwrap();
+    let acc = instance.get_global(&store, "acc").unwrap();
+    accumulate_global.call(&mut store, 4).unwrap();
+    accumulate_global.call(&mut store, 8).unwrap();
+    assert_eq!(acc.get(&store).i32(), Some(2 + 3));
+    // The bounds check of the `load` still traps and the result is not written.
+    let error = accumulate_global.call(&mut store, 65534).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::MemoryOutOfBounds));
+    assert_eq!(acc.get(&store).i32(), Some(2 + 3));
+    assert_eq!(accumulate_local.call(&mut store, (10, 12)).unwrap(), 10 + 4);
+    let error = accumulate_local.call(&mut store, (10, -1)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::MemoryOutOfBounds));
+    // The `offset` of the fused instruction participates in the bounds check.
+    let i32_or = instance
+        .get_typed_func::<(i32, i32), i32>(&store, "i32_or")
+        .unwrap();
+    let error = i32_or.call(&mut store, (0, 0)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::MemoryOutOfBounds));
+}
@@ -0,0 +1,99 @@
+//! Tests for calls and returns with many contiguous register parameters.
+//!
+//! Those are encoded compactly by `wasmi` using register spans which
+//! needs to be properly decoded during execution.
+
+use wasmi::{Engine, Func, FuncType, Linker, Module, Store, Value};
+use wasmi_core::ValueType;
+
+/// Returns the expected results of the test functions for the given `inputs`.
+fn expected(inputs: &[i32]) -> Vec<i32> {
+    let mut results = vec![inputs[11]];
+    results.extend_from_slice(&inputs[0..10]);
+    results.push(inputs[11]);
+    results
+}
+
+#[test]
+fn many_params_works() {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (import "env" "host" (func $host
+                (param i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32)
+                (result i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32)
+            ))
+            (func $wasm
+                (param i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32)
+                (result i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32)
+                (return
+                    (local.get 11) (local.get 0) (local.get 1) (local.get 2)
+                    (local.get 3) (local.get 4) (local.get 5) (local.get 6)
+                    (local.get 7) (local.get 8) (local.get 9) (local.get 11)
+                )
+            )
+            (func (export "call_wasm")
+                (param i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32)
+                (result i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32)
+                (call $wasm
+                    (local.get 0) (local.get 1) (local.get 2) (local.get 3)
+                    (local.get 4) (local.get 5) (local.get 6) (local.get 7)
+                    (local.get 8) (local.get 9) (local.get 10) (local.get 11)
+                )
+            )
+            (func (export "call_host")
+                (param i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32)
+                (result i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32)
+                (call $host
+                    (local.get 0) (local.get 1) (local.get 2) (local.get 3)
+                    (local.get 4) (local.get 5) (local.get 6) (local.get 7)
+                    (local.get 8) (local.get 9) (local.get 10) (local.get 11)
+                )
+            )
+            (func (export "copy")
+                (param i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32)
+                (result i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32)
+                (block (result i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32)
+                    (local.get 11) (local.get 0) (local.get 1) (local.get 2)
+                    (local.get 3) (local.get 4) (local.get 5) (local.get 6)
+                    (local.get 7) (local.get 8) (local.get 9) (local.get 11)
+                    (br_if 0 (local.get 10))
+                    (return)
+                )
+            )
+        )
+        "#,
+    )
+    .unwrap();
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut linker = <Linker<()>>::new(&engine);
+    let host = Func::new(
+        &mut store,
+        FuncType::new([ValueType::I32; 12], [ValueType::I32; 12]),
+        |_caller, inputs, outputs| {
+            let inputs = inputs.iter().map(|value| value.i32().unwrap());
+            let results = expected(&inputs.collect::<Vec<_>>());
+            for (output, result) in outputs.iter_mut().zip(results) {
+                *output = Value::I32(result);
+            }
+            Ok(())
+        },
+    );
+    linker.define("env", "host", host).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let inputs: Vec<i32> = (1..=12).map(|n| n * 10).collect();
+    let params: Vec<Value> = inputs.iter().copied().map(Value::I32).collect();
+    for name in ["call_wasm", "call_host", "copy"] {
+        let func = instance.get_func(&store, name).unwrap();
+        let mut results = vec![Value::I32(0); 12];
+        func.call(&mut store, &params, &mut results).unwrap();
+        let results: Vec<i32> = results.iter().map(|value| value.i32().unwrap()).collect();
+        assert_eq!(results, expected(&inputs), "mismatch for {name}");
+    }
+}
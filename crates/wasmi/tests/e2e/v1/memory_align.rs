@@ -0,0 +1,122 @@
+//! Tests that the alignment hints of Wasm `load` and `store` instructions do not affect semantics.
+
+use super::instantiate_wat;
+use wasmi::core::F64;
+
+/// The test module storing and loading values with natural and minimal alignment hints.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (memory (export "mem") 1)
+        (func (export "i32_natural") (param $ptr i32) (param $value i32) (result i32)
+            (i32.store align=4 (local.get $ptr) (local.get $value))
+            (i32.load align=4 (local.get $ptr))
+        )
+        (func (export "i32_unaligned") (param $ptr i32) (param $value i32) (result i32)
+            (i32.store align=1 (local.get $ptr) (local.get $value))
+            (i32.load align=1 (local.get $ptr))
+        )
+        (func (export "i64_natural") (param $ptr i32) (param $value i64) (result i64)
+            (i64.store offset=8 align=8 (local.get $ptr) (local.get $value))
+            (i64.load offset=8 align=8 (local.get $ptr))
+        )
+        (func (export "i64_unaligned") (param $ptr i32) (param $value i64) (result i64)
+            (i64.store offset=8 align=1 (local.get $ptr) (local.get $value))
+            (i64.load offset=8 align=1 (local.get $ptr))
+        )
+        (func (export "i32_load16_natural") (param $ptr i32) (result i32)
+            (i32.load16_u align=2 (local.get $ptr))
+        )
+        (func (export "f64_natural") (param $ptr i32) (param $value f64) (result f64)
+            (f64.store align=8 (local.get $ptr) (local.get $value))
+            (f64.load align=8 (local.get $ptr))
+        )
+    )"#
+}
+
+#[test]
+fn natural_alignment_hints() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let i32_natural = instance
+        .get_typed_func::<(i32, i32), i32>(&store, "i32_natural")
+        .unwrap();
+    let i64_natural = instance
+        .get_typed_func::<(i32, i64), i64>(&store, "i64_natural")
+        .unwrap();
+    let f64_natural = instance
+        .get_typed_func::<(i32, F64), F64>(&store, "f64_natural")
+        .unwrap();
+    let memory = instance.get_memory(&store, "mem").unwrap();
+    for ptr in (0..64).step_by(8) {
+        assert_eq!(
+            i32_natural.call(&mut store, (ptr, 0x1234_5678)).unwrap(),
+            0x1234_5678
+        );
+        assert_eq!(
+            i64_natural
+                .call(&mut store, (ptr, -0x0123_4567_89ab_cdef))
+                .unwrap(),
+            -0x0123_4567_89ab_cdef
+        );
+        assert_eq!(
+            f64_natural.call(&mut store, (ptr, F64::from(1.5))).unwrap(),
+            F64::from(1.5)
+        );
+    }
+    // Values are stored in little endian byte order.
+    i32_natural.call(&mut store, (64, 0x0403_0201)).unwrap();
+    assert_eq!(&memory.data(&store)[64..68], &[1, 2, 3, 4]);
+}
+
+#[test]
+fn misaligned_access_with_natural_alignment_hints() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let i32_natural = instance
+        .get_typed_func::<(i32, i32), i32>(&store, "i32_natural")
+        .unwrap();
+    let i64_natural = instance
+        .get_typed_func::<(i32, i64), i64>(&store, "i64_natural")
+        .unwrap();
+    let i32_load16_natural = instance
+        .get_typed_func::<i32, i32>(&store, "i32_load16_natural")
+        .unwrap();
+    let memory = instance.get_memory(&store, "mem").unwrap();
+    // Alignment hints are not guaranteed to hold, so misaligned accesses must still succeed.
+    for ptr in 1..8 {
+        assert_eq!(
+            i32_natural.call(&mut store, (ptr, 0x1234_5678)).unwrap(),
+            0x1234_5678
+        );
+        assert_eq!(
+            i64_natural
+                .call(&mut store, (ptr, 0x0102_0304_0506_0708))
+                .unwrap(),
+            0x0102_0304_0506_0708
+        );
+        let offset = ptr as usize + 8;
+        assert_eq!(
+            &memory.data(&store)[offset..offset + 8],
+            &[8, 7, 6, 5, 4, 3, 2, 1]
+        );
+    }
+    memory.data_mut(&mut store)[101..103].copy_from_slice(&[0xcd, 0xab]);
+    assert_eq!(i32_load16_natural.call(&mut store, 101).unwrap(), 0xabcd);
+}
+
+#[test]
+fn minimal_alignment_hints() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let i32_unaligned = instance
+        .get_typed_func::<(i32, i32), i32>(&store, "i32_unaligned")
+        .unwrap();
+    let i64_unaligned = instance
+        .get_typed_func::<(i32, i64), i64>(&store, "i64_unaligned")
+        .unwrap();
+    for ptr in 0..16 {
+        assert_eq!(i32_unaligned.call(&mut store, (ptr, -1)).unwrap(), -1);
+        assert_eq!(
+            i64_unaligned.call(&mut store, (ptr, i64::MIN + 1)).unwrap(),
+            i64::MIN + 1
+        );
+    }
+}
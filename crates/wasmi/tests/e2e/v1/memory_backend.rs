@@ -0,0 +1,175 @@
+//! Tests to check that linear memories are allocated via the configured `MemoryBackend`.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use wasmi::{
+    errors::MemoryError,
+    Config,
+    Engine,
+    Instance,
+    Linker,
+    MemoryBackend,
+    MemoryBuffer,
+    Module,
+    Store,
+};
+
+/// The byte capacity of the [`TestBackend`] shared by all its byte buffers.
+const CAPACITY: usize = 3 * 0x1_0000;
+
+/// A [`MemoryBackend`] that tracks its allocations and limits their total size.
+#[derive(Debug, Default, Clone)]
+struct TestBackend {
+    /// The number of allocated byte buffers.
+    allocs: Arc<AtomicUsize>,
+    /// The number of bytes in use by all allocated byte buffers.
+    used: Arc<AtomicUsize>,
+}
+
+/// A byte buffer allocated by the [`TestBackend`].
+#[derive(Debug)]
+struct TestBuffer {
+    bytes: Vec<u8>,
+    used: Arc<AtomicUsize>,
+}
+
+impl TestBackend {
+    /// Reserves `delta` bytes from the capacity of the [`TestBackend`].
+    fn reserve(used: &AtomicUsize, delta: usize) -> Result<(), MemoryError> {
+        used.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |used| {
+            used.checked_add(delta).filter(|&used| used <= CAPACITY)
+        })
+        .map(|_| ())
+        .map_err(|_| MemoryError::OutOfBoundsAllocation)
+    }
+}
+
+impl MemoryBackend for TestBackend {
+    fn alloc(
+        &self,
+        initial_len: usize,
+        _maximum_len: Option<usize>,
+    ) -> Result<Box<dyn MemoryBuffer>, MemoryError> {
+        Self::reserve(&self.used, initial_len)?;
+        self.allocs.fetch_add(1, Ordering::SeqCst);
+        Ok(Box::new(TestBuffer {
+            bytes: vec![0x00; initial_len],
+            used: self.used.clone(),
+        }))
+    }
+}
+
+impl MemoryBuffer for TestBuffer {
+    fn grow(&mut self, new_len: usize) -> Result<(), MemoryError> {
+        TestBackend::reserve(&self.used, new_len - self.bytes.len())?;
+        self.bytes.resize(new_len, 0x00);
+        Ok(())
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.bytes[..]
+    }
+
+    fn data_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes[..]
+    }
+}
+
+/// Instantiates the test module with linear memory of `initial` pages using `backend`.
+fn test_setup(backend: &TestBackend, initial: u32) -> Result<(Store<()>, Instance), wasmi::Error> {
+    let mut config = Config::default();
+    config.memory_backend(backend.clone());
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, ());
+    let wasm = wat::parse_str(format!(
+        r#"
+        (module
+            (memory (export "memory") {initial})
+            (func (export "memory.grow") (param i32) (result i32)
+                (memory.grow (local.get 0))
+            )
+            (func (export "store") (param i32 i32)
+                (i32.store (local.get 0) (local.get 1))
+            )
+            (func (export "load") (param i32) (result i32)
+                (i32.load (local.get 0))
+            )
+        )"#
+    ))
+    .unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)?
+        .start(&mut store)?;
+    Ok((store, instance))
+}
+
+#[test]
+fn memory_is_allocated_via_backend() {
+    let backend = TestBackend::default();
+    let (mut store, instance) = test_setup(&backend, 1).unwrap();
+    assert_eq!(backend.allocs.load(Ordering::SeqCst), 1);
+    assert_eq!(backend.used.load(Ordering::SeqCst), 0x1_0000);
+    let store_fn = instance
+        .get_typed_func::<(i32, i32), ()>(&store, "store")
+        .unwrap();
+    let load_fn = instance.get_typed_func::<i32, i32>(&store, "load").unwrap();
+    store_fn.call(&mut store, (8, 42)).unwrap();
+    assert_eq!(load_fn.call(&mut store, 8).unwrap(), 42);
+    let memory = instance.get_memory(&store, "memory").unwrap();
+    assert_eq!(memory.data(&store)[8..12], 42_i32.to_le_bytes());
+}
+
+#[test]
+fn memory_grow_via_backend() {
+    let backend = TestBackend::default();
+    let (mut store, instance) = test_setup(&backend, 1).unwrap();
+    let grow = instance
+        .get_typed_func::<i32, i32>(&store, "memory.grow")
+        .unwrap();
+    let store_fn = instance
+        .get_typed_func::<(i32, i32), ()>(&store, "store")
+        .unwrap();
+    assert_eq!(grow.call(&mut store, 2).unwrap(), 1);
+    assert_eq!(backend.used.load(Ordering::SeqCst), CAPACITY);
+    store_fn.call(&mut store, (0x2_0000, 1)).unwrap();
+    // The backend refuses to grow beyond its capacity.
+    assert_eq!(grow.call(&mut store, 1).unwrap(), -1);
+    let memory = instance.get_memory(&store, "memory").unwrap();
+    assert_eq!(memory.data(&store).len(), CAPACITY);
+}
+
+#[test]
+fn memory_alloc_fails_via_backend() {
+    let backend = TestBackend::default();
+    assert!(test_setup(&backend, 4).is_err());
+    assert_eq!(backend.allocs.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+#[cfg(all(feature = "guard-pages", unix))]
+fn guard_pages_backend_works() {
+    let mut config = Config::default();
+    config.memory_backend(wasmi::GuardPagesMemoryBackend::default());
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, ());
+    let memory_type = wasmi::MemoryType::new(1, Some(3)).unwrap();
+    let memory = wasmi::Memory::new(&mut store, memory_type).unwrap();
+    memory.write(&mut store, 0xFFFC, &[1, 2, 3, 4]).unwrap();
+    assert_eq!(
+        memory
+            .grow(&mut store, wasmi::core::Pages::from(2))
+            .unwrap(),
+        1.into()
+    );
+    assert_eq!(memory.data(&store).len(), 3 * 0x1_0000);
+    assert_eq!(memory.data(&store)[0xFFFC..0x1_0000], [1, 2, 3, 4]);
+    assert!(memory.data(&store)[0x1_0000..]
+        .iter()
+        .all(|&byte| byte == 0x00));
+    assert!(memory
+        .grow(&mut store, wasmi::core::Pages::from(1))
+        .is_err());
+}
@@ -0,0 +1,78 @@
+//! Tests to check that the memory grow hook of the `Store` is called after successful growths.
+
+use std::sync::{Arc, Mutex};
+use wasmi::{core::Pages, Engine, Instance, Linker, Memory, Module, Store};
+
+/// The test module with a growable linear memory of at most 3 pages.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (memory (export "memory") 1 3)
+        (func (export "grow") (param $delta i32) (result i32)
+            (memory.grow (local.get $delta))
+        )
+    )"#
+}
+
+/// A memory growth recorded by the memory grow hook.
+type Growth = (Memory, Pages, Pages);
+
+/// Instantiates the test module and registers a memory grow hook recording all growths.
+fn test_setup() -> (Store<()>, Instance, Arc<Mutex<Vec<Growth>>>) {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let growths = <Arc<Mutex<Vec<Growth>>>>::default();
+    let recorded = growths.clone();
+    store.on_memory_grow(move |memory, old_pages, new_pages| {
+        recorded
+            .lock()
+            .unwrap()
+            .push((memory, old_pages, new_pages));
+    });
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance, growths)
+}
+
+/// Returns the recorded growths as `(old_pages, new_pages)` and asserts that they grew `memory`.
+fn take_growths(
+    store: &Store<()>,
+    memory: Memory,
+    growths: &Mutex<Vec<Growth>>,
+) -> Vec<(u32, u32)> {
+    growths
+        .lock()
+        .unwrap()
+        .drain(..)
+        .map(|(grown, old_pages, new_pages)| {
+            assert_eq!(grown.current_pages(store), memory.current_pages(store));
+            (u32::from(old_pages), u32::from(new_pages))
+        })
+        .collect()
+}
+
+#[test]
+fn memory_grow_hook() {
+    let (mut store, instance, growths) = test_setup();
+    let memory = instance.get_memory(&store, "memory").unwrap();
+    let grow = instance.get_typed_func::<i32, i32>(&store, "grow").unwrap();
+    assert_eq!(grow.call(&mut store, 1).unwrap(), 1);
+    assert_eq!(take_growths(&store, memory, &growths), [(1, 2)]);
+    // Growing by zero pages or beyond the maximum does not call the hook.
+    assert_eq!(grow.call(&mut store, 0).unwrap(), 2);
+    assert_eq!(grow.call(&mut store, 5).unwrap(), -1);
+    assert_eq!(take_growths(&store, memory, &growths), []);
+    // Growing the memory from the host calls the hook as well.
+    assert_eq!(
+        memory.grow(&mut store, Pages::from(1)).unwrap(),
+        Pages::from(2)
+    );
+    assert_eq!(take_growths(&store, memory, &growths), [(2, 3)]);
+    assert!(memory.grow(&mut store, Pages::from(1)).is_err());
+    assert_eq!(take_growths(&store, memory, &growths), []);
+}
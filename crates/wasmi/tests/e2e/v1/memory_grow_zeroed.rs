@@ -0,0 +1,90 @@
+//! Tests to check that pages added by `memory.grow` are always zero-initialized.
+//!
+//! This is especially important after a linear memory has been shrunk via
+//! [`Memory::restore`] since its previously used bytes must not be exposed again.
+
+use wasmi::{Config, Engine, Instance, Linker, Memory, Module, Store};
+
+/// The size of a single linear memory page in bytes.
+const PAGE_SIZE: usize = 0x1_0000;
+
+/// Instantiates the test module using `config`.
+fn test_setup(config: &Config) -> (Store<()>, Instance, Memory) {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (memory (export "memory") 1 4)
+            (func (export "grow_and_fill") (param $value i32) (result i32)
+                (local $page i32)
+                (local.set $page (memory.grow (i32.const 1)))
+                (memory.fill
+                    (i32.mul (local.get $page) (i32.const 0x1_0000))
+                    (local.get $value)
+                    (i32.const 0x1_0000)
+                )
+                (local.get $page)
+            )
+            (func (export "grow") (result i32)
+                (memory.grow (i32.const 1))
+            )
+        )"#,
+    )
+    .unwrap();
+    let engine = Engine::new(config);
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let memory = instance.get_memory(&store, "memory").unwrap();
+    (store, instance, memory)
+}
+
+/// Returns `true` if all bytes of the `page` of `memory` are zero.
+fn is_zeroed(store: &Store<()>, memory: Memory, page: usize) -> bool {
+    memory.data(store)[page * PAGE_SIZE..(page + 1) * PAGE_SIZE]
+        .iter()
+        .all(|&byte| byte == 0x00)
+}
+
+/// Writes to grown pages, shrinks the linear memory and grows it again.
+fn run_test(config: &Config) {
+    let (mut store, instance, memory) = test_setup(config);
+    let grow_and_fill = instance
+        .get_typed_func::<i32, i32>(&store, "grow_and_fill")
+        .unwrap();
+    let grow = instance.get_typed_func::<(), i32>(&store, "grow").unwrap();
+    memory.data_mut(&mut store)[..4].copy_from_slice(&[1, 2, 3, 4]);
+    let snapshot = memory.snapshot(&store);
+    for round in 0..3 {
+        assert_eq!(grow_and_fill.call(&mut store, 0xFF).unwrap(), 1);
+        assert_eq!(grow_and_fill.call(&mut store, 0xAB).unwrap(), 2);
+        assert!(!is_zeroed(&store, memory, 1));
+        assert!(!is_zeroed(&store, memory, 2));
+        // Shrink the linear memory back to a single page.
+        memory.restore(&mut store, &snapshot).unwrap();
+        assert_eq!(memory.data(&store).len(), PAGE_SIZE);
+        assert_eq!(memory.data(&store)[..4], [1, 2, 3, 4]);
+        // Pages grown again must not expose the previously written bytes.
+        assert_eq!(grow.call(&mut store, ()).unwrap(), 1);
+        assert_eq!(grow.call(&mut store, ()).unwrap(), 2);
+        assert!(is_zeroed(&store, memory, 1), "round {round}");
+        assert!(is_zeroed(&store, memory, 2), "round {round}");
+        memory.restore(&mut store, &snapshot).unwrap();
+    }
+}
+
+#[test]
+fn grown_pages_are_zeroed() {
+    run_test(&Config::default())
+}
+
+#[test]
+#[cfg(all(feature = "guard-pages", unix))]
+fn grown_pages_are_zeroed_with_guard_pages() {
+    let mut config = Config::default();
+    config.memory_backend(wasmi::GuardPagesMemoryBackend::default());
+    run_test(&config)
+}
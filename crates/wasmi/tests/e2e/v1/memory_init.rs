@@ -0,0 +1,164 @@
+//! Tests to check if wasmi's `memory.init` and `data.drop` behave as the Wasm spec dictates.
+
+use super::instantiate_wat;
+use wasmi::{core::TrapCode, Error, Instance, Linker, Module, Store};
+
+/// The test module with a single page linear memory, a passive and an active data segment.
+///
+/// # Note
+///
+/// The functions suffixed with `_const` use constant operands in order to
+/// test all the different `wasmi` bytecode encodings of `memory.init`.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (memory (export "mem") 1)
+        (data $passive "\01\02\03\04")
+        (data $active (i32.const 0) "\05\06")
+        (func (export "init_passive") (param $dst i32) (param $src i32) (param $len i32)
+            (memory.init $passive (local.get $dst) (local.get $src) (local.get $len))
+        )
+        (func (export "init_active") (param $dst i32) (param $src i32) (param $len i32)
+            (memory.init $active (local.get $dst) (local.get $src) (local.get $len))
+        )
+        (func (export "drop_passive")
+            (data.drop $passive)
+        )
+        (func (export "init_passive_const_ok")
+            (memory.init $passive (i32.const 65536) (i32.const 4) (i32.const 0))
+        )
+        (func (export "init_passive_const_trap")
+            (memory.init $passive (i32.const 65537) (i32.const 0) (i32.const 0))
+        )
+    )"#
+}
+
+/// Instantiates `module` within `store`.
+fn instantiate(store: &mut Store<()>, module: &Module) -> Instance {
+    Linker::new(store.engine())
+        .instantiate(&mut *store, module)
+        .unwrap()
+        .start(&mut *store)
+        .unwrap()
+}
+
+/// Calls the exported `memory.init` function `name` with `dst`, `src` and `len`.
+fn init(
+    store: &mut Store<()>,
+    instance: Instance,
+    name: &str,
+    dst: u32,
+    src: u32,
+    len: u32,
+) -> Result<(), Error> {
+    instance
+        .get_typed_func::<(u32, u32, u32), ()>(&*store, name)
+        .unwrap()
+        .call(store, (dst, src, len))
+}
+
+/// Calls the exported function `name` without parameters and results.
+fn call(store: &mut Store<()>, instance: Instance, name: &str) -> Result<(), Error> {
+    instance
+        .get_typed_func::<(), ()>(&*store, name)
+        .unwrap()
+        .call(store, ())
+}
+
+/// Asserts that `result` is a [`TrapCode::MemoryOutOfBounds`] trap.
+fn assert_out_of_bounds(result: Result<(), Error>) {
+    assert_eq!(
+        result.unwrap_err().as_trap_code(),
+        Some(TrapCode::MemoryOutOfBounds)
+    );
+}
+
+#[test]
+fn zero_len_init_in_bounds() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    // Offsets right at the end of the memory and data segment are in bounds.
+    init(&mut store, instance, "init_passive", 0, 0, 0).unwrap();
+    init(&mut store, instance, "init_passive", 65536, 0, 0).unwrap();
+    init(&mut store, instance, "init_passive", 0, 4, 0).unwrap();
+    init(&mut store, instance, "init_passive", 65536, 4, 0).unwrap();
+    call(&mut store, instance, "init_passive_const_ok").unwrap();
+}
+
+#[test]
+fn zero_len_init_out_of_bounds() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    // Offsets past the end of the memory or data segment trap even for zero length.
+    assert_out_of_bounds(init(&mut store, instance, "init_passive", 65537, 0, 0));
+    assert_out_of_bounds(init(&mut store, instance, "init_passive", 0, 5, 0));
+    assert_out_of_bounds(init(&mut store, instance, "init_passive", u32::MAX, 0, 0));
+    assert_out_of_bounds(init(&mut store, instance, "init_passive", 0, u32::MAX, 0));
+    assert_out_of_bounds(call(&mut store, instance, "init_passive_const_trap"));
+}
+
+#[test]
+fn zero_len_init_dropped_segment() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    call(&mut store, instance, "drop_passive").unwrap();
+    // Dropped data segments behave like empty data segments.
+    init(&mut store, instance, "init_passive", 0, 0, 0).unwrap();
+    init(&mut store, instance, "init_passive", 65536, 0, 0).unwrap();
+    assert_out_of_bounds(init(&mut store, instance, "init_passive", 0, 1, 0));
+    assert_out_of_bounds(init(&mut store, instance, "init_passive", 65537, 0, 0));
+    // Active data segments are dropped after instantiation.
+    init(&mut store, instance, "init_active", 0, 0, 0).unwrap();
+    assert_out_of_bounds(init(&mut store, instance, "init_active", 0, 1, 0));
+}
+
+#[test]
+fn zero_len_init_does_not_mutate() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let memory = instance.get_memory(&store, "mem").unwrap();
+    let before = memory.data(&store).to_vec();
+    init(&mut store, instance, "init_passive", 10, 0, 0).unwrap();
+    init(&mut store, instance, "init_passive", 10, 4, 0).unwrap();
+    assert_eq!(memory.data(&store), &before[..]);
+    // Non-zero length `memory.init` still copies the data segment contents.
+    init(&mut store, instance, "init_passive", 10, 1, 3).unwrap();
+    assert_eq!(&memory.data(&store)[10..13], &[0x02, 0x03, 0x04]);
+}
+
+#[test]
+fn init_passive_then_drop() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let memory = instance.get_memory(&store, "mem").unwrap();
+    // Passive data segments persist across multiple `memory.init` until dropped.
+    init(&mut store, instance, "init_passive", 100, 0, 4).unwrap();
+    init(&mut store, instance, "init_passive", 200, 2, 2).unwrap();
+    assert_eq!(&memory.data(&store)[100..104], &[0x01, 0x02, 0x03, 0x04]);
+    assert_eq!(&memory.data(&store)[200..202], &[0x03, 0x04]);
+    call(&mut store, instance, "drop_passive").unwrap();
+    assert_out_of_bounds(init(&mut store, instance, "init_passive", 300, 0, 4));
+    assert_out_of_bounds(init(&mut store, instance, "init_passive", 300, 0, 1));
+    assert_eq!(&memory.data(&store)[300..304], &[0x00; 4]);
+    // Dropping an already dropped data segment is a no-op.
+    call(&mut store, instance, "drop_passive").unwrap();
+}
+
+#[test]
+fn init_active_traps() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let memory = instance.get_memory(&store, "mem").unwrap();
+    // Active data segments are applied at instantiation and dropped afterwards.
+    assert_eq!(&memory.data(&store)[..2], &[0x05, 0x06]);
+    assert_out_of_bounds(init(&mut store, instance, "init_active", 100, 0, 2));
+    assert_out_of_bounds(init(&mut store, instance, "init_active", 100, 0, 1));
+    assert_eq!(&memory.data(&store)[100..102], &[0x00; 2]);
+}
+
+#[test]
+fn drop_is_per_instance() {
+    let (mut store, instance_a) = instantiate_wat(test_module());
+    let module = Module::new(store.engine(), &wat::parse_str(test_module()).unwrap()[..]).unwrap();
+    let instance_b = instantiate(&mut store, &module);
+    let memory_b = instance_b.get_memory(&store, "mem").unwrap();
+    // Dropping a data segment of one instance does not affect other instances.
+    call(&mut store, instance_a, "drop_passive").unwrap();
+    assert_out_of_bounds(init(&mut store, instance_a, "init_passive", 100, 0, 4));
+    init(&mut store, instance_b, "init_passive", 100, 0, 4).unwrap();
+    assert_eq!(&memory_b.data(&store)[100..104], &[0x01, 0x02, 0x03, 0x04]);
+}
@@ -0,0 +1,161 @@
+//! Tests to check that [`Memory::snapshot`] and [`Memory::restore`]
+//! properly roll back the size and contents of linear memories.
+
+use wasmi::{
+    core::Pages,
+    errors::{MemoryError, TableError},
+    Engine,
+    Instance,
+    Linker,
+    Memory,
+    MemoryType,
+    Module,
+    ResourceLimiter,
+    Store,
+};
+
+/// Instantiates the test module with a linear memory of 1 page and at most 3 pages.
+fn test_setup() -> (Store<()>, Instance, Memory) {
+    let engine = Engine::default();
+    test_setup_with(Store::new(&engine, ()))
+}
+
+/// Instantiates the test module within the given [`Store`].
+fn test_setup_with<T>(mut store: Store<T>) -> (Store<T>, Instance, Memory) {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (memory (export "memory") 1 3)
+            (data (i32.const 0) "\01\02\03\04")
+            (func (export "mutate")
+                (i32.store (i32.const 0) (i32.const -1))
+                (drop (memory.grow (i32.const 2)))
+                (i32.store (i32.const 0x2_0000) (i32.const 42))
+            )
+        )"#,
+    )
+    .unwrap();
+    let module = Module::new(store.engine(), &wasm[..]).unwrap();
+    let instance = <Linker<T>>::new(store.engine())
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let memory = instance.get_memory(&store, "memory").unwrap();
+    (store, instance, memory)
+}
+
+#[test]
+fn restore_memory_works() {
+    let (mut store, instance, memory) = test_setup();
+    let mutate = instance.get_typed_func::<(), ()>(&store, "mutate").unwrap();
+    let snapshot = memory.snapshot(&store);
+    assert_eq!(snapshot.pages(), Pages::from(1));
+    assert_eq!(snapshot.data()[..4], [1, 2, 3, 4]);
+    mutate.call(&mut store, ()).unwrap();
+    assert_eq!(memory.current_pages(&store), Pages::from(3));
+    assert_eq!(memory.data(&store)[..4], [0xFF; 4]);
+    // Restoring shrinks the linear memory back to its original size.
+    memory.restore(&mut store, &snapshot).unwrap();
+    assert_eq!(memory.current_pages(&store), Pages::from(1));
+    assert_eq!(memory.data(&store), snapshot.data());
+    assert_eq!(memory.data(&store)[..4], [1, 2, 3, 4]);
+    // Snapshots can be restored multiple times.
+    mutate.call(&mut store, ()).unwrap();
+    memory.restore(&mut store, &snapshot).unwrap();
+    assert_eq!(memory.current_pages(&store), Pages::from(1));
+    assert_eq!(memory.data(&store)[..4], [1, 2, 3, 4]);
+}
+
+#[test]
+fn restore_memory_grows() {
+    let (mut store, instance, memory) = test_setup();
+    let mutate = instance.get_typed_func::<(), ()>(&store, "mutate").unwrap();
+    let initial = memory.snapshot(&store);
+    mutate.call(&mut store, ()).unwrap();
+    let mutated = memory.snapshot(&store);
+    memory.restore(&mut store, &initial).unwrap();
+    // Restoring grows the linear memory to the size of the snapshot.
+    memory.restore(&mut store, &mutated).unwrap();
+    assert_eq!(memory.current_pages(&store), Pages::from(3));
+    assert_eq!(memory.data(&store)[..4], [0xFF; 4]);
+    assert_eq!(
+        memory.data(&store)[0x2_0000..0x2_0004],
+        42_i32.to_le_bytes()
+    );
+}
+
+#[test]
+fn restore_memory_out_of_bounds() {
+    let (mut store, instance, memory) = test_setup();
+    let mutate = instance.get_typed_func::<(), ()>(&store, "mutate").unwrap();
+    mutate.call(&mut store, ()).unwrap();
+    let snapshot = memory.snapshot(&store);
+    // The snapshot exceeds the maximum size of the other linear memory.
+    let other = Memory::new(&mut store, MemoryType::new(1, Some(2)).unwrap()).unwrap();
+    other.write(&mut store, 0, &[5, 6, 7, 8]).unwrap();
+    assert!(other.restore(&mut store, &snapshot).is_err());
+    assert_eq!(other.current_pages(&store), Pages::from(1));
+    assert_eq!(other.data(&store)[..4], [5, 6, 7, 8]);
+    // The snapshot is smaller than the minimum size of the other linear memory.
+    let other = Memory::new(&mut store, MemoryType::new(4, None).unwrap()).unwrap();
+    assert!(other.restore(&mut store, &snapshot).is_err());
+    assert_eq!(other.current_pages(&store), Pages::from(4));
+}
+
+/// A [`ResourceLimiter`] that records all linear memory resizings it is notified about.
+#[derive(Default)]
+struct RecordingLimiter {
+    /// The `(current, desired)` sizes in bytes of all notified linear memory resizings.
+    resizings: Vec<(usize, usize)>,
+    /// If `true` all linear memory resizings are denied.
+    deny: bool,
+}
+
+impl ResourceLimiter for RecordingLimiter {
+    fn memory_growing(
+        &mut self,
+        current: usize,
+        desired: usize,
+        _maximum: Option<usize>,
+    ) -> Result<bool, MemoryError> {
+        self.resizings.push((current, desired));
+        Ok(!self.deny)
+    }
+
+    fn table_growing(
+        &mut self,
+        _current: u32,
+        _desired: u32,
+        _maximum: Option<u32>,
+    ) -> Result<bool, TableError> {
+        Ok(true)
+    }
+}
+
+#[test]
+fn restore_memory_shrinks_with_limiter() {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, RecordingLimiter::default());
+    store.limiter(|limiter| limiter);
+    let (mut store, instance, memory) = test_setup_with(store);
+    let mutate = instance.get_typed_func::<(), ()>(&store, "mutate").unwrap();
+    let snapshot = memory.snapshot(&store);
+    mutate.call(&mut store, ()).unwrap();
+    store.data_mut().resizings.clear();
+    // The linear memory remains unchanged if the limiter denies shrinking it.
+    store.data_mut().deny = true;
+    assert!(memory.restore(&mut store, &snapshot).is_err());
+    assert_eq!(memory.current_pages(&store), Pages::from(3));
+    assert_eq!(memory.data(&store)[..4], [0xFF; 4]);
+    assert_eq!(store.data().resizings, [(0x3_0000, 0x1_0000)]);
+    // The limiter is notified about the new size of the shrunk linear memory.
+    store.data_mut().deny = false;
+    memory.restore(&mut store, &snapshot).unwrap();
+    assert_eq!(memory.current_pages(&store), Pages::from(1));
+    assert_eq!(memory.data(&store)[..4], [1, 2, 3, 4]);
+    assert_eq!(
+        store.data().resizings,
+        [(0x3_0000, 0x1_0000), (0x3_0000, 0x1_0000)]
+    );
+}
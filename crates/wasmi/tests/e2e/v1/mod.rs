@@ -1,6 +1,85 @@
+mod atomic;
+mod batched_host_calls;
+mod bulk_memory;
+mod call_depth;
+mod call_unchecked;
+mod copy_span;
+mod cross_instance_call;
+mod engine_features;
+mod error_category;
+mod export_handle;
+mod externref;
+mod externref_gc;
 mod fuel_consumption_mode;
 mod fuel_metering;
 mod func;
+mod func_call_alloc;
+mod func_max_registers;
+mod func_metadata;
+mod global_init;
+mod global_ref;
+mod global_snapshot;
+mod global_watch;
+mod grow_limits;
 mod host_calls_wasm;
+mod host_error;
+mod host_trap;
+mod instruction_histogram;
+mod large_imm;
+mod linked_module;
+mod load_op;
+mod many_params;
+mod memory_align;
+mod memory_backend;
+mod memory_grow_hook;
+mod memory_grow_zeroed;
+mod memory_init;
+mod memory_snapshot;
+mod module_streaming;
+mod module_validate;
+mod multi_memory;
+mod nan_canonicalization;
+mod precompile;
+mod preservation_shift;
+mod ref_const_expr;
+mod register_space;
 mod resource_limiter;
 mod resumable_call;
+mod select;
+mod shared_memory_import;
+mod sign_extension;
+mod store_data;
+mod table_bulk;
+mod table_host_api;
+mod table_size;
+mod table_uninit;
+mod trace_hook;
+mod translation_diagnostics;
+mod trap_codes;
+mod trap_offsets;
+mod trapping_drops;
+mod trunc_trap;
+mod unreachable_hook;
+mod value_stack_limit;
+mod wide_branch;
+
+use wasmi::{Config, Engine, Instance, Linker, Module, Store};
+
+/// Instantiates the Wasm module of the `wat` source within a new [`Store`].
+fn instantiate_wat(wat: &str) -> (Store<()>, Instance) {
+    instantiate_wat_with_config(&Config::default(), wat)
+}
+
+/// Instantiates the Wasm module of the `wat` source within a new [`Store`] using `config`.
+fn instantiate_wat_with_config(config: &Config, wat: &str) -> (Store<()>, Instance) {
+    let wasm = wat::parse_str(wat).unwrap();
+    let engine = Engine::new(config);
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
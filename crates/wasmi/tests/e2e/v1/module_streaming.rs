@@ -0,0 +1,76 @@
+//! Tests for `Module::new` parsing Wasm modules from chunked byte streams.
+
+use std::io;
+use wasmi::{errors::ErrorKind, Engine, Module};
+
+/// A byte stream that yields at most `chunk_len` bytes per read.
+struct ChunkedReader<'a> {
+    bytes: &'a [u8],
+    chunk_len: usize,
+}
+
+impl<'a> ChunkedReader<'a> {
+    fn new(bytes: &'a [u8], chunk_len: usize) -> Self {
+        Self { bytes, chunk_len }
+    }
+}
+
+impl io::Read for ChunkedReader<'_> {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        let len = self.bytes.len().min(buffer.len()).min(self.chunk_len);
+        let (chunk, rest) = self.bytes.split_at(len);
+        buffer[..len].copy_from_slice(chunk);
+        self.bytes = rest;
+        Ok(len)
+    }
+}
+
+fn test_wasm() -> Vec<u8> {
+    wat::parse_str(
+        r#"
+        (module
+            (memory 1)
+            (func (export "inc") (param i32) (result i32)
+                (i32.add (local.get 0) (i32.const 1))
+            )
+            (func (export "two") (result i32)
+                (i32.const 2)
+            )
+            (data (i32.const 0) "hello")
+        )"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn chunked_stream_works() {
+    let engine = Engine::default();
+    let wasm = test_wasm();
+    for chunk_len in [1, 2, 3, 5, 8, 64] {
+        let module = Module::new(&engine, ChunkedReader::new(&wasm, chunk_len)).unwrap();
+        let exports: Vec<_> = module.exports().map(|export| export.name()).collect();
+        assert_eq!(exports, ["inc", "two"]);
+    }
+}
+
+#[test]
+fn truncated_stream_fails_with_offset() {
+    let engine = Engine::default();
+    let wasm = test_wasm();
+    // Cut the stream within the code section as well as within the data section.
+    for cut in [wasm.len() - 20, wasm.len() - 1] {
+        let truncated = &wasm[..cut];
+        let error = Module::new(&engine, ChunkedReader::new(truncated, 3)).unwrap_err();
+        let ErrorKind::Wasm(wasm_error) = error.kind() else {
+            panic!("expected a Wasm parse error but found: {error}")
+        };
+        assert!(wasm_error.offset() > 0 && wasm_error.offset() < cut);
+        assert!(
+            error.to_string().contains("unexpected end-of-file"),
+            "unexpected error message: {error}"
+        );
+        // The same error is reported when parsing the truncated bytes in one go.
+        let expected = Module::new(&engine, truncated).unwrap_err();
+        assert_eq!(error.to_string(), expected.to_string());
+    }
+}
@@ -0,0 +1,72 @@
+//! Tests for `Module::validate`.
+
+use wasmi::{errors::ErrorKind, Config, Engine, Module};
+
+/// A Wasm module using the `tail-call` Wasm proposal.
+fn tail_call_module() -> Vec<u8> {
+    wat::parse_str(
+        r#"
+        (module
+            (func $f (result i32)
+                (i32.const 42)
+            )
+            (func (export "run") (result i32)
+                (return_call $f)
+            )
+        )"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn valid_module_passes() {
+    let engine = Engine::default();
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (import "env" "log" (func $log (param i32)))
+            (memory (export "mem") 1)
+            (func (export "run") (param i32) (result i32)
+                (call $log (local.get 0))
+                (i32.add (local.get 0) (i32.const 1))
+            )
+        )"#,
+    )
+    .unwrap();
+    Module::validate(&engine, &wasm[..]).unwrap();
+}
+
+#[test]
+fn disabled_proposal_fails() {
+    let engine = Engine::default();
+    let wasm = tail_call_module();
+    let error = Module::validate(&engine, &wasm[..]).unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::Wasm(_)));
+    assert!(
+        error
+            .to_string()
+            .contains("tail calls support is not enabled"),
+        "unexpected error message: {error}"
+    );
+    // The same module is valid once the proposal is enabled.
+    let mut config = Config::default();
+    config.wasm_tail_call(true);
+    let engine = Engine::new(&config);
+    Module::validate(&engine, &wasm[..]).unwrap();
+}
+
+#[test]
+fn truncated_module_fails_with_offset() {
+    let engine = Engine::default();
+    let wasm = tail_call_module();
+    let truncated = &wasm[..wasm.len() - 3];
+    let error = Module::validate(&engine, truncated).unwrap_err();
+    let ErrorKind::Wasm(wasm_error) = error.kind() else {
+        panic!("expected a Wasm validation error but found: {error}")
+    };
+    assert!(wasm_error.offset() > 0 && wasm_error.offset() <= truncated.len());
+    assert!(
+        error.to_string().contains("offset"),
+        "unexpected error message: {error}"
+    );
+}
@@ -0,0 +1,162 @@
+//! Tests for modules using multiple linear memories via the Wasm `multi-memory` proposal.
+
+use wasmi::{Config, Engine, Instance, Linker, Memory, MemoryType, Module, Store};
+
+/// The test module defining two linear memories and accessors for both.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (import "env" "shared0" (memory $s0 1))
+        (import "env" "shared1" (memory $s1 1))
+        (memory $m0 (export "m0") 1 4)
+        (memory $m1 (export "m1") 1 4)
+        (data $d "wasmi")
+        (func (export "store0") (param $ptr i32) (param $value i32)
+            (i32.store $m0 (local.get $ptr) (local.get $value))
+        )
+        (func (export "store1") (param $ptr i32) (param $value i32)
+            (i32.store $m1 (local.get $ptr) (local.get $value))
+        )
+        (func (export "load0") (param $ptr i32) (result i32)
+            (i32.load $m0 (local.get $ptr))
+        )
+        (func (export "load1") (param $ptr i32) (result i32)
+            (i32.load $m1 (local.get $ptr))
+        )
+        (func (export "size1") (result i32)
+            (memory.size $m1)
+        )
+        (func (export "grow1") (param $delta i32) (result i32)
+            (memory.grow $m1 (local.get $delta))
+        )
+        (func (export "copy_1_to_0") (param $dst i32) (param $src i32) (param $len i32)
+            (memory.copy $m0 $m1 (local.get $dst) (local.get $src) (local.get $len))
+        )
+        (func (export "fill1") (param $dst i32) (param $value i32) (param $len i32)
+            (memory.fill $m1 (local.get $dst) (local.get $value) (local.get $len))
+        )
+        (func (export "init1") (param $dst i32)
+            (memory.init $m1 $d (local.get $dst) (i32.const 0) (i32.const 5))
+        )
+        (func (export "copy_shared") (param $dst i32) (param $src i32) (param $len i32)
+            (memory.copy $s0 $s1 (local.get $dst) (local.get $src) (local.get $len))
+        )
+    )"#
+}
+
+/// Instantiates the test module importing `shared` as both of its imported memories.
+fn test_setup() -> (Store<()>, Instance, Memory) {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let mut config = Config::default();
+    config.wasm_multi_memory(true);
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let shared = Memory::new(&mut store, MemoryType::new(1, None).unwrap()).unwrap();
+    let mut linker = <Linker<()>>::new(&engine);
+    linker.define("env", "shared0", shared).unwrap();
+    linker.define("env", "shared1", shared).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance, shared)
+}
+
+/// Returns the exported memory `name` of `instance`.
+fn memory(store: &Store<()>, instance: Instance, name: &str) -> Memory {
+    instance.get_memory(store, name).unwrap()
+}
+
+#[test]
+fn multi_memory_disabled_by_default() {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let engine = Engine::default();
+    assert!(Module::new(&engine, &wasm[..]).is_err());
+}
+
+#[test]
+fn load_and_store() {
+    let (mut store, instance, _) = test_setup();
+    let store0 = instance
+        .get_typed_func::<(i32, i32), ()>(&store, "store0")
+        .unwrap();
+    let store1 = instance
+        .get_typed_func::<(i32, i32), ()>(&store, "store1")
+        .unwrap();
+    let load0 = instance
+        .get_typed_func::<i32, i32>(&store, "load0")
+        .unwrap();
+    let load1 = instance
+        .get_typed_func::<i32, i32>(&store, "load1")
+        .unwrap();
+    store1.call(&mut store, (8, 42)).unwrap();
+    // The store to memory 1 must not affect memory 0.
+    assert_eq!(load0.call(&mut store, 8).unwrap(), 0);
+    assert_eq!(load1.call(&mut store, 8).unwrap(), 42);
+    assert_eq!(memory(&store, instance, "m0").data(&store)[8..12], [0; 4]);
+    assert_eq!(
+        memory(&store, instance, "m1").data(&store)[8..12],
+        [42, 0, 0, 0]
+    );
+    store0.call(&mut store, (8, -1)).unwrap();
+    assert_eq!(load0.call(&mut store, 8).unwrap(), -1);
+    assert_eq!(load1.call(&mut store, 8).unwrap(), 42);
+    // Out of bounds accesses trap with respect to the accessed memory.
+    assert!(load1.call(&mut store, 0x1_0000).is_err());
+}
+
+#[test]
+fn size_and_grow() {
+    let (mut store, instance, _) = test_setup();
+    let size1 = instance.get_typed_func::<(), i32>(&store, "size1").unwrap();
+    let grow1 = instance
+        .get_typed_func::<i32, i32>(&store, "grow1")
+        .unwrap();
+    let load0 = instance
+        .get_typed_func::<i32, i32>(&store, "load0")
+        .unwrap();
+    let load1 = instance
+        .get_typed_func::<i32, i32>(&store, "load1")
+        .unwrap();
+    assert_eq!(size1.call(&mut store, ()).unwrap(), 1);
+    assert_eq!(grow1.call(&mut store, 2).unwrap(), 1);
+    assert_eq!(size1.call(&mut store, ()).unwrap(), 3);
+    assert_eq!(grow1.call(&mut store, 2).unwrap(), -1);
+    assert_eq!(memory(&store, instance, "m0").data(&store).len(), 0x1_0000);
+    assert_eq!(load1.call(&mut store, 0x2_0000).unwrap(), 0);
+    assert!(load0.call(&mut store, 0x2_0000).is_err());
+}
+
+#[test]
+fn bulk_memory() {
+    let (mut store, instance, _) = test_setup();
+    let fill1 = instance
+        .get_typed_func::<(i32, i32, i32), ()>(&store, "fill1")
+        .unwrap();
+    let init1 = instance.get_typed_func::<i32, ()>(&store, "init1").unwrap();
+    let copy = instance
+        .get_typed_func::<(i32, i32, i32), ()>(&store, "copy_1_to_0")
+        .unwrap();
+    fill1.call(&mut store, (0, 0xAB, 4)).unwrap();
+    init1.call(&mut store, 4).unwrap();
+    copy.call(&mut store, (100, 0, 9)).unwrap();
+    let m0 = memory(&store, instance, "m0");
+    let m1 = memory(&store, instance, "m1");
+    assert_eq!(&m1.data(&store)[..9], b"\xAB\xAB\xAB\xABwasmi");
+    assert_eq!(&m0.data(&store)[100..109], b"\xAB\xAB\xAB\xABwasmi");
+    assert_eq!(&m0.data(&store)[..9], [0; 9]);
+    assert!(copy.call(&mut store, (0, 0xFFFF, 2)).is_err());
+}
+
+#[test]
+fn copy_between_aliased_memories() {
+    let (mut store, instance, shared) = test_setup();
+    let copy = instance
+        .get_typed_func::<(i32, i32, i32), ()>(&store, "copy_shared")
+        .unwrap();
+    shared.data_mut(&mut store)[..4].copy_from_slice(&[1, 2, 3, 4]);
+    copy.call(&mut store, (2, 0, 4)).unwrap();
+    assert_eq!(shared.data(&store)[..6], [1, 2, 1, 2, 3, 4]);
+}
@@ -0,0 +1,135 @@
+//! Tests to check if wasmi's NaN canonicalization works as intended.
+
+use wasmi::{Config, Engine, Instance, Linker, Module, Store};
+
+/// The bits of the canonical `f32` quiet NaN value.
+const F32_CANONICAL_NAN: u32 = 0x7FC0_0000;
+
+/// The bits of the canonical `f64` quiet NaN value.
+const F64_CANONICAL_NAN: u64 = 0x7FF8_0000_0000_0000;
+
+/// The test module exporting functions that may produce NaN results.
+///
+/// # Note
+///
+/// All functions operate on the raw bits of their floating point inputs
+/// and return the raw bits of their floating point outputs so that NaN
+/// payloads are observable.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (func (export "f32.add") (param i32 i32) (result i32)
+            (i32.reinterpret_f32
+                (f32.add
+                    (f32.reinterpret_i32 (local.get 0))
+                    (f32.reinterpret_i32 (local.get 1))
+                )
+            )
+        )
+        (func (export "f32.sqrt") (param i32) (result i32)
+            (i32.reinterpret_f32
+                (f32.sqrt (f32.reinterpret_i32 (local.get 0)))
+            )
+        )
+        (func (export "f32.const_add") (result i32)
+            (i32.reinterpret_f32
+                (f32.add
+                    (f32.const nan:0x200001)
+                    (f32.const -nan:0x7)
+                )
+            )
+        )
+        (func (export "f64.mul") (param i64 i64) (result i64)
+            (i64.reinterpret_f64
+                (f64.mul
+                    (f64.reinterpret_i64 (local.get 0))
+                    (f64.reinterpret_i64 (local.get 1))
+                )
+            )
+        )
+        (func (export "f64.promote_f32") (param i32) (result i64)
+            (i64.reinterpret_f64
+                (f64.promote_f32 (f32.reinterpret_i32 (local.get 0)))
+            )
+        )
+    )"#
+}
+
+/// Instantiates the test module with the given NaN canonicalization setting.
+fn test_setup(canonicalize_nan: bool) -> (Store<()>, Instance) {
+    let mut config = Config::default();
+    config.floats_canonicalize_nan(canonicalize_nan);
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, ());
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Some `f32` NaN values with differing signs and payloads.
+const F32_NANS: [u32; 4] = [0x7FC0_0000, 0xFFC0_0000, 0x7FC0_1234, 0xFFA0_0001];
+
+/// Some `f64` NaN values with differing signs and payloads.
+const F64_NANS: [u64; 4] = [
+    0x7FF8_0000_0000_0000,
+    0xFFF8_0000_0000_0000,
+    0x7FF8_0000_0000_1234,
+    0xFFF4_0000_0000_0001,
+];
+
+#[test]
+fn canonicalize_f32_nan() {
+    let (mut store, instance) = test_setup(true);
+    let add = instance
+        .get_typed_func::<(u32, u32), u32>(&store, "f32.add")
+        .unwrap();
+    let sqrt = instance
+        .get_typed_func::<u32, u32>(&store, "f32.sqrt")
+        .unwrap();
+    let one = 1.0_f32.to_bits();
+    for nan in F32_NANS {
+        assert_eq!(add.call(&mut store, (nan, one)).unwrap(), F32_CANONICAL_NAN);
+        assert_eq!(add.call(&mut store, (one, nan)).unwrap(), F32_CANONICAL_NAN);
+        assert_eq!(sqrt.call(&mut store, nan).unwrap(), F32_CANONICAL_NAN);
+    }
+    // `sqrt` of a negative number produces a NaN value.
+    let minus_one = (-1.0_f32).to_bits();
+    assert_eq!(sqrt.call(&mut store, minus_one).unwrap(), F32_CANONICAL_NAN);
+    // Non-NaN results are not affected by NaN canonicalization.
+    assert_eq!(add.call(&mut store, (one, one)).unwrap(), 2.0_f32.to_bits());
+}
+
+#[test]
+fn canonicalize_f64_nan() {
+    let (mut store, instance) = test_setup(true);
+    let mul = instance
+        .get_typed_func::<(u64, u64), u64>(&store, "f64.mul")
+        .unwrap();
+    let promote = instance
+        .get_typed_func::<u32, u64>(&store, "f64.promote_f32")
+        .unwrap();
+    let two = 2.0_f64.to_bits();
+    for nan in F64_NANS {
+        assert_eq!(mul.call(&mut store, (nan, two)).unwrap(), F64_CANONICAL_NAN);
+        assert_eq!(mul.call(&mut store, (two, nan)).unwrap(), F64_CANONICAL_NAN);
+    }
+    for nan in F32_NANS {
+        assert_eq!(promote.call(&mut store, nan).unwrap(), F64_CANONICAL_NAN);
+    }
+    // Non-NaN results are not affected by NaN canonicalization.
+    assert_eq!(mul.call(&mut store, (two, two)).unwrap(), 4.0_f64.to_bits());
+}
+
+#[test]
+fn canonicalize_const_nan() {
+    let (mut store, instance) = test_setup(true);
+    let const_add = instance
+        .get_typed_func::<(), u32>(&store, "f32.const_add")
+        .unwrap();
+    assert_eq!(const_add.call(&mut store, ()).unwrap(), F32_CANONICAL_NAN);
+}
@@ -0,0 +1,75 @@
+//! Tests to check if precompiling a batch of Wasm modules via the [`Engine`] works as intended.
+
+use wasmi::{Engine, Linker, Module, Store};
+
+/// Returns three different Wasm test modules each exporting a `"test"` function.
+///
+/// The `"test"` function of the `n`-th module returns `n`.
+fn test_modules() -> Vec<Vec<u8>> {
+    [
+        r#"
+        (module
+            (func (export "test") (result i32)
+                (i32.const 0)
+            )
+        )"#,
+        r#"
+        (module
+            (memory 1)
+            (func (export "test") (result i32)
+                (i32.store (i32.const 0) (i32.const 1))
+                (i32.load (i32.const 0))
+            )
+        )"#,
+        r#"
+        (module
+            (global $g (mut i32) (i32.const 1))
+            (func (export "test") (result i32)
+                (global.set $g (i32.add (global.get $g) (i32.const 1)))
+                (global.get $g)
+            )
+        )"#,
+    ]
+    .into_iter()
+    .map(|wat| wat::parse_str(wat).unwrap())
+    .collect()
+}
+
+/// Instantiates all `modules` and asserts that their `"test"` function returns their index.
+fn assert_instantiate_all(engine: &Engine, modules: &[Module]) {
+    assert_eq!(modules.len(), 3);
+    let linker = <Linker<()>>::new(engine);
+    let mut store = Store::new(engine, ());
+    for (n, module) in modules.iter().enumerate() {
+        let instance = linker
+            .instantiate(&mut store, module)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let test = instance.get_typed_func::<(), i32>(&store, "test").unwrap();
+        assert_eq!(test.call(&mut store, ()).unwrap(), n as i32);
+    }
+}
+
+#[test]
+fn precompile_all_works() {
+    let engine = Engine::default();
+    let modules = engine.precompile_all(test_modules()).unwrap();
+    assert_instantiate_all(&engine, &modules);
+}
+
+#[test]
+fn precompile_all_parallel_works() {
+    let engine = Engine::default();
+    let modules = engine.precompile_all_parallel(&test_modules()).unwrap();
+    assert_instantiate_all(&engine, &modules);
+}
+
+#[test]
+fn precompile_all_fails() {
+    let engine = Engine::default();
+    let mut modules = test_modules();
+    modules.insert(1, b"invalid".to_vec());
+    assert!(engine.precompile_all(&modules).is_err());
+    assert!(engine.precompile_all_parallel(&modules).is_err());
+}
@@ -0,0 +1,66 @@
+//! Tests to check that limiting the shifting due to `local.set` preservations preserves semantics.
+
+use wasmi::{Config, Engine, Linker, Module, Store};
+
+/// The number of preserved `local.set` instructions in the test function.
+const LEN_PRESERVATIONS: usize = 1_000;
+
+/// Returns a test module with a function containing lots of preserved `local.set` instructions.
+///
+/// Each step increments `$x` while its previous value is still in use
+/// and accumulates all previous values of `$x` in `$acc`.
+fn test_module() -> String {
+    let step = r#"
+        (local.get $x)
+        (local.set $x (i32.add (local.get $x) (i32.const 1)))
+        (local.set $acc (i32.add (local.get $acc)))
+    "#;
+    format!(
+        r#"
+        (module
+            (func (export "run") (param $x i32) (result i32)
+                (local $acc i32)
+                {}
+                (i32.add (local.get $acc) (local.get $x))
+            )
+        )"#,
+        step.repeat(LEN_PRESERVATIONS)
+    )
+}
+
+/// Translates and runs the test function using `config`.
+fn run(config: &Config, x: i32) -> i32 {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let engine = Engine::new(config);
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap()
+        .get_typed_func::<i32, i32>(&store, "run")
+        .unwrap()
+        .call(&mut store, x)
+        .unwrap()
+}
+
+/// Returns the expected result of the test function for `x`.
+fn expected(x: i32) -> i32 {
+    let len = LEN_PRESERVATIONS as i32;
+    let acc = (0..len).fold(0_i32, |acc, i| acc.wrapping_add(x.wrapping_add(i)));
+    acc.wrapping_add(x.wrapping_add(len))
+}
+
+#[test]
+fn max_preservation_shift() {
+    for limit in [None, Some(0), Some(1), Some(100)] {
+        let mut config = Config::default();
+        if let Some(limit) = limit {
+            config.max_preservation_shift(limit);
+        }
+        for x in [0, -5, i32::MAX] {
+            assert_eq!(run(&config, x), expected(x), "limit = {limit:?}, x = {x}");
+        }
+    }
+}
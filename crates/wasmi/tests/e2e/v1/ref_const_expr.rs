@@ -0,0 +1,93 @@
+//! Tests for `ref.func` and `ref.null` constant expressions of globals and element segments.
+
+use wasmi::{errors::ErrorKind, Engine, Func, Instance, Linker, Module, Store, Value};
+
+/// Instantiates the Wasm module given by `wat`.
+fn instantiate(wat: &str) -> (Store<()>, Instance) {
+    let wasm = wat::parse_str(wat).unwrap();
+    let engine = Engine::default();
+    let mut store = <Store<()>>::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Returns the [`Func`] referenced by `value` or `None` if `value` is a null `funcref`.
+fn funcref(value: Value) -> Option<Func> {
+    match value {
+        Value::FuncRef(funcref) => funcref.func().copied(),
+        value => panic!("expected a funcref value but found: {value:?}"),
+    }
+}
+
+/// Calls `func` without parameters and returns its single `i32` result.
+fn call_i32(store: &mut Store<()>, func: Func) -> i32 {
+    func.typed::<(), i32>(&*store)
+        .unwrap()
+        .call(&mut *store, ())
+        .unwrap()
+}
+
+#[test]
+fn global_ref_func_works() {
+    let (mut store, instance) = instantiate(
+        r#"
+        (module
+            (func $f (export "f") (result i32) (i32.const 42))
+            (global (export "g") funcref (ref.func $f))
+            (global (export "null") funcref (ref.null func))
+            (global (export "mut") (mut funcref) (ref.func $f))
+        )"#,
+    );
+    for name in ["g", "mut"] {
+        let global = instance.get_global(&store, name).unwrap();
+        let func = funcref(global.get(&store)).unwrap();
+        assert_eq!(call_i32(&mut store, func), 42);
+    }
+    let null = instance.get_global(&store, "null").unwrap();
+    assert!(funcref(null.get(&store)).is_none());
+}
+
+#[test]
+fn elem_ref_null_works() {
+    let (mut store, instance) = instantiate(
+        r#"
+        (module
+            (func $f (result i32) (i32.const 7))
+            (table (export "table") 4 funcref)
+            (elem (i32.const 0) funcref (ref.null func) (ref.func $f) (ref.func $f) (ref.null func))
+        )"#,
+    );
+    let table = instance.get_table(&store, "table").unwrap();
+    let elems: Vec<_> = (0..4)
+        .map(|index| funcref(table.get(&store, index).unwrap()))
+        .collect();
+    assert!(elems[0].is_none());
+    assert!(elems[3].is_none());
+    let f1 = elems[1].unwrap();
+    let f2 = elems[2].unwrap();
+    assert_eq!(call_i32(&mut store, f1), 7);
+    assert_eq!(call_i32(&mut store, f2), 7);
+}
+
+#[test]
+fn ref_func_out_of_bounds_fails() {
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (func $f)
+            (global funcref (ref.func 1))
+        )"#,
+    )
+    .unwrap();
+    let engine = Engine::default();
+    let error = Module::new(&engine, &wasm[..]).unwrap_err();
+    assert!(
+        matches!(error.kind(), ErrorKind::Wasm(_)),
+        "expected a validation error but found: {error}"
+    );
+}
@@ -0,0 +1,53 @@
+//! Tests for functions that exceed the register space of the `wasmi` bytecode.
+
+use wasmi::{errors::ErrorKind, Engine, Module};
+
+/// Returns a Wasm function with `len_locals` local variables that sums up `depth` values.
+///
+/// Each partial sum requires its own register since the additions are nested.
+fn nested_sum(len_locals: usize, depth: usize) -> Vec<u8> {
+    let locals = "i64 ".repeat(len_locals);
+    let mut body = String::from("(local.get 0)");
+    for _ in 0..depth {
+        body = format!("(i64.add (i64.mul (local.get 0) (local.get 1)) {body})");
+    }
+    wat::parse_str(format!(
+        r#"
+        (module
+            (func (export "sum") (result i64) (local {locals})
+                {body}
+            )
+        )"#,
+    ))
+    .unwrap()
+}
+
+/// Asserts that `wasm` fails to translate since it requires too many registers.
+fn assert_too_many_registers(wasm: &[u8]) {
+    let engine = Engine::default();
+    let error = Module::new(&engine, wasm).unwrap_err();
+    assert!(
+        matches!(error.kind(), ErrorKind::Translation(_)),
+        "expected a translation error but found: {error}"
+    );
+    assert!(
+        error.to_string().contains("more registers"),
+        "unexpected error message: {error}"
+    );
+}
+
+#[test]
+fn near_register_space_limit_works() {
+    let engine = Engine::default();
+    Module::new(&engine, &nested_sum(32_000, 10)[..]).unwrap();
+}
+
+#[test]
+fn too_many_locals_fails() {
+    assert_too_many_registers(&nested_sum(40_000, 1));
+}
+
+#[test]
+fn too_many_dynamic_registers_fails() {
+    assert_too_many_registers(&nested_sum(32_760, 10));
+}
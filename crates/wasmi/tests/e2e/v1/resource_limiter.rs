@@ -1,11 +1,13 @@
 //! Tests to check if wasmi's ResourceLimiter works as intended.
 use wasmi::{
     core::TrapCode,
+    errors::{MemoryError, TableError},
     Config,
     Engine,
     Error,
     Linker,
     Module,
+    ResourceLimiter,
     Store,
     StoreLimits,
     StoreLimitsBuilder,
@@ -207,3 +209,84 @@ fn test_table_traps_on_limited_growth() -> Result<(), Error> {
     assert_eq!(test.table_size.call(&mut test.store, ())?, 100);
     Ok(())
 }
+
+/// A [`ResourceLimiter`] limiting the total number of linear memory pages of a [`Store`].
+#[derive(Debug)]
+struct TotalPagesLimiter {
+    /// The number of linear memory pages in use by all linear memories.
+    used: usize,
+    /// The maximum number of linear memory pages of all linear memories.
+    capacity: usize,
+}
+
+impl ResourceLimiter for TotalPagesLimiter {
+    fn memory_growing(
+        &mut self,
+        current: usize,
+        desired: usize,
+        _maximum: Option<usize>,
+    ) -> Result<bool, MemoryError> {
+        const PAGE_SIZE: usize = 0x1_0000;
+        let used = self.used + (desired - current) / PAGE_SIZE;
+        if used > self.capacity {
+            return Ok(false);
+        }
+        self.used = used;
+        Ok(true)
+    }
+
+    fn table_growing(
+        &mut self,
+        _current: u32,
+        _desired: u32,
+        _maximum: Option<u32>,
+    ) -> Result<bool, TableError> {
+        Ok(true)
+    }
+}
+
+#[test]
+fn test_total_memory_pages_limit() -> Result<(), Error> {
+    let engine = Engine::default();
+    let limiter = TotalPagesLimiter {
+        used: 0,
+        capacity: 10,
+    };
+    let mut store = Store::new(&engine, limiter);
+    store.limiter(|limiter| limiter);
+    let wasm = wat2wasm(
+        r#"
+        (module
+            (memory (export "memory") 2)
+            (func (export "memory_grow") (param $pages i32) (result i32)
+                (memory.grow (local.get $pages))
+            )
+        )
+        "#,
+    );
+    let module = Module::new(&engine, &wasm[..])?;
+    let linker = <Linker<TotalPagesLimiter>>::new(&engine);
+    let mut grow_fns = Vec::new();
+    for _ in 0..2 {
+        let instance = linker.instantiate(&mut store, &module)?.start(&mut store)?;
+        grow_fns.push(instance.get_typed_func::<i32, i32>(&store, "memory_grow")?);
+    }
+    let [grow0, grow1] = &grow_fns[..] else {
+        unreachable!()
+    };
+    assert_eq!(store.data().used, 4);
+    // Both linear memories share the capacity of the resource limiter.
+    assert_eq!(grow0.call(&mut store, 3)?, 2);
+    assert_eq!(grow1.call(&mut store, 2)?, 2);
+    assert_eq!(store.data().used, 9);
+    // Growing any of the linear memories beyond the total capacity fails.
+    assert_eq!(grow0.call(&mut store, 2)?, -1);
+    assert_eq!(grow1.call(&mut store, 2)?, -1);
+    assert_eq!(store.data().used, 9);
+    assert_eq!(grow1.call(&mut store, 1)?, 4);
+    assert_eq!(grow0.call(&mut store, 1)?, -1);
+    assert_eq!(store.data().used, 10);
+    // Creating new linear memories is limited as well.
+    assert!(linker.instantiate(&mut store, &module).is_err());
+    Ok(())
+}
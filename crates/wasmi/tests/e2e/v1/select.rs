@@ -0,0 +1,201 @@
+//! Tests to check if wasmi's `select` works as intended.
+
+use wasmi::{Engine, ExternRef, Instance, Linker, Module, Store};
+
+/// Instantiates a module exporting a `select` between the constants `lhs` and `rhs` as `"test"`.
+fn test_select(ty: &str, lhs: &str, rhs: &str) -> (Store<()>, Instance) {
+    let wat = format!(
+        r#"
+        (module
+            (func (export "test") (param $condition i32) (result {ty})
+                ({ty}.const {lhs})
+                ({ty}.const {rhs})
+                (select (local.get $condition))
+            )
+        )"#
+    );
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+#[test]
+fn select_i32_imm() {
+    fn test_for(lhs: i32, rhs: i32) {
+        let (mut store, instance) = test_select("i32", &lhs.to_string(), &rhs.to_string());
+        let select = instance.get_typed_func::<i32, i32>(&store, "test").unwrap();
+        assert_eq!(select.call(&mut store, 1).unwrap(), lhs);
+        assert_eq!(select.call(&mut store, -1).unwrap(), lhs);
+        assert_eq!(select.call(&mut store, 0).unwrap(), rhs);
+    }
+    test_for(0, 1);
+    test_for(-1, 0);
+    test_for(-128, 127);
+    test_for(-129, 128);
+    test_for(i32::MIN, i32::MAX);
+}
+
+#[test]
+fn select_i64_imm() {
+    fn test_for(lhs: i64, rhs: i64) {
+        let (mut store, instance) = test_select("i64", &lhs.to_string(), &rhs.to_string());
+        let select = instance.get_typed_func::<i32, i64>(&store, "test").unwrap();
+        assert_eq!(select.call(&mut store, 1).unwrap(), lhs);
+        assert_eq!(select.call(&mut store, 0).unwrap(), rhs);
+    }
+    test_for(0, 1);
+    test_for(-1, 0);
+    test_for(i64::MIN, i64::MAX);
+}
+
+#[test]
+fn typed_select_externref() {
+    let wat = r#"
+        (module
+            (func (export "test")
+                (param $condition i32) (param $lhs externref) (param $rhs externref)
+                (result externref)
+                (select (result externref)
+                    (local.get $lhs)
+                    (local.get $rhs)
+                    (local.get $condition)
+                )
+            )
+        )"#;
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let select = instance
+        .get_typed_func::<(i32, ExternRef, ExternRef), ExternRef>(&store, "test")
+        .unwrap();
+    let lhs = ExternRef::new(&mut store, 10_i64);
+    let rhs = ExternRef::new(&mut store, 20_i64);
+    let deref = |store: &Store<()>, value: ExternRef| -> Option<i64> {
+        value
+            .data(store)
+            .map(|data| *data.downcast_ref::<i64>().unwrap())
+    };
+    let result = select.call(&mut store, (1, lhs, rhs)).unwrap();
+    assert_eq!(deref(&store, result), Some(10));
+    let result = select.call(&mut store, (0, lhs, rhs)).unwrap();
+    assert_eq!(deref(&store, result), Some(20));
+    let result = select
+        .call(&mut store, (0, lhs, ExternRef::null()))
+        .unwrap();
+    assert!(result.is_null());
+}
+
+/// A named integer comparison operator and its reference implementation.
+type CmpOp<T> = (&'static str, fn(T, T) -> bool);
+
+/// Instantiates a module exporting `select`s fused with integer comparisons of type `ty`.
+///
+/// Each exported function is named after its comparison operator, e.g. `"lt_s"`.
+fn test_select_cmp(ty: &str) -> (Store<()>, Instance) {
+    let ops = [
+        "eq", "ne", "lt_s", "lt_u", "le_s", "le_u", "gt_s", "gt_u", "ge_s", "ge_u",
+    ];
+    let funcs: String = ops
+        .iter()
+        .map(|op| {
+            format!(
+                r#"
+                (func (export "{op}")
+                    (param $lhs {ty}) (param $rhs {ty}) (param $true i64) (param $false i64)
+                    (result i64)
+                    (select
+                        (local.get $true)
+                        (local.get $false)
+                        ({ty}.{op} (local.get $lhs) (local.get $rhs))
+                    )
+                )"#
+            )
+        })
+        .collect();
+    let wat = format!("(module {funcs})");
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+#[test]
+fn select_cmp_i32() {
+    let (mut store, instance) = test_select_cmp("i32");
+    let ops: [CmpOp<i32>; 10] = [
+        ("eq", |a, b| a == b),
+        ("ne", |a, b| a != b),
+        ("lt_s", |a, b| a < b),
+        ("lt_u", |a, b| (a as u32) < (b as u32)),
+        ("le_s", |a, b| a <= b),
+        ("le_u", |a, b| (a as u32) <= (b as u32)),
+        ("gt_s", |a, b| a > b),
+        ("gt_u", |a, b| (a as u32) > (b as u32)),
+        ("ge_s", |a, b| a >= b),
+        ("ge_u", |a, b| (a as u32) >= (b as u32)),
+    ];
+    let values = [i32::MIN, -1, 0, 1, i32::MAX];
+    for (op, cmp) in ops {
+        let func = instance
+            .get_typed_func::<(i32, i32, i64, i64), i64>(&store, op)
+            .unwrap();
+        for lhs in values {
+            for rhs in values {
+                let expected = if cmp(lhs, rhs) { 10 } else { 20 };
+                let result = func.call(&mut store, (lhs, rhs, 10, 20)).unwrap();
+                assert_eq!(result, expected, "i32.{op} {lhs} {rhs}");
+            }
+        }
+    }
+}
+
+#[test]
+fn select_cmp_i64() {
+    let (mut store, instance) = test_select_cmp("i64");
+    let ops: [CmpOp<i64>; 10] = [
+        ("eq", |a, b| a == b),
+        ("ne", |a, b| a != b),
+        ("lt_s", |a, b| a < b),
+        ("lt_u", |a, b| (a as u64) < (b as u64)),
+        ("le_s", |a, b| a <= b),
+        ("le_u", |a, b| (a as u64) <= (b as u64)),
+        ("gt_s", |a, b| a > b),
+        ("gt_u", |a, b| (a as u64) > (b as u64)),
+        ("ge_s", |a, b| a >= b),
+        ("ge_u", |a, b| (a as u64) >= (b as u64)),
+    ];
+    let values = [i64::MIN, -1, 0, 1, i64::MAX];
+    for (op, cmp) in ops {
+        let func = instance
+            .get_typed_func::<(i64, i64, i64, i64), i64>(&store, op)
+            .unwrap();
+        for lhs in values {
+            for rhs in values {
+                let expected = if cmp(lhs, rhs) { i64::MIN } else { i64::MAX };
+                let result = func
+                    .call(&mut store, (lhs, rhs, i64::MIN, i64::MAX))
+                    .unwrap();
+                assert_eq!(result, expected, "i64.{op} {lhs} {rhs}");
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Tests to check that instances importing the same `Memory` share its contents and size.
+
+use wasmi::{Engine, Instance, Linker, Module, Store};
+
+/// The module defining and exporting the shared memory.
+fn module_a() -> &'static str {
+    r#"
+    (module
+        (memory (export "memory") 1 4)
+        (func (export "store") (param $ptr i32) (param $value i32)
+            (i32.store8 (local.get $ptr) (local.get $value))
+        )
+        (func (export "load") (param $ptr i32) (result i32)
+            (i32.load8_u (local.get $ptr))
+        )
+        (func (export "size") (result i32)
+            (memory.size)
+        )
+        (func (export "grow") (param $delta i32) (result i32)
+            (memory.grow (local.get $delta))
+        )
+    )"#
+}
+
+/// The module importing the memory and the `grow` function of module A.
+fn module_b() -> &'static str {
+    r#"
+    (module
+        (import "a" "memory" (memory 1))
+        (import "a" "grow" (func $grow (param i32) (result i32)))
+        (func (export "store") (param $ptr i32) (param $value i32)
+            (i32.store8 (local.get $ptr) (local.get $value))
+        )
+        (func (export "load") (param $ptr i32) (result i32)
+            (i32.load8_u (local.get $ptr))
+        )
+        (func (export "size") (result i32)
+            (memory.size)
+        )
+        (func (export "grow") (param $delta i32) (result i32)
+            (memory.grow (local.get $delta))
+        )
+        ;; Grows the shared memory via instance A and accesses the new page right after.
+        (func (export "grow_via_a_and_load") (param $ptr i32) (result i32)
+            (drop (call $grow (i32.const 1)))
+            (i32.load8_u (local.get $ptr))
+        )
+    )"#
+}
+
+/// Instantiates module A and module B importing the memory exported by instance A.
+fn test_setup() -> (Store<()>, Instance, Instance) {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let module_a = Module::new(&engine, &wat::parse_str(module_a()).unwrap()[..]).unwrap();
+    let module_b = Module::new(&engine, &wat::parse_str(module_b()).unwrap()[..]).unwrap();
+    let mut linker = <Linker<()>>::new(&engine);
+    let instance_a = linker
+        .instantiate(&mut store, &module_a)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    linker.instance(&mut store, "a", instance_a).unwrap();
+    let instance_b = linker
+        .instantiate(&mut store, &module_b)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance_a, instance_b)
+}
+
+#[test]
+fn imported_memory_aliases_exported_memory() {
+    let (mut store, instance_a, instance_b) = test_setup();
+    let memory_a = instance_a.get_memory(&store, "memory").unwrap();
+    let store_a = instance_a
+        .get_typed_func::<(i32, i32), ()>(&store, "store")
+        .unwrap();
+    let store_b = instance_b
+        .get_typed_func::<(i32, i32), ()>(&store, "store")
+        .unwrap();
+    let load_a = instance_a
+        .get_typed_func::<i32, i32>(&store, "load")
+        .unwrap();
+    let load_b = instance_b
+        .get_typed_func::<i32, i32>(&store, "load")
+        .unwrap();
+    // Writes of instance A are observed by instance B and vice versa.
+    store_a.call(&mut store, (10, 42)).unwrap();
+    assert_eq!(load_b.call(&mut store, 10).unwrap(), 42);
+    store_b.call(&mut store, (20, 7)).unwrap();
+    assert_eq!(load_a.call(&mut store, 20).unwrap(), 7);
+    // Host side writes are observed by both instances.
+    memory_a.data_mut(&mut store)[30] = 99;
+    assert_eq!(load_a.call(&mut store, 30).unwrap(), 99);
+    assert_eq!(load_b.call(&mut store, 30).unwrap(), 99);
+    assert_eq!(memory_a.data(&store)[10], 42);
+    assert_eq!(memory_a.data(&store)[20], 7);
+}
+
+#[test]
+fn memory_grow_is_observed_by_importer() {
+    let (mut store, instance_a, instance_b) = test_setup();
+    let size_a = instance_a
+        .get_typed_func::<(), i32>(&store, "size")
+        .unwrap();
+    let size_b = instance_b
+        .get_typed_func::<(), i32>(&store, "size")
+        .unwrap();
+    let grow_a = instance_a
+        .get_typed_func::<i32, i32>(&store, "grow")
+        .unwrap();
+    let grow_b = instance_b
+        .get_typed_func::<i32, i32>(&store, "grow")
+        .unwrap();
+    let store_a = instance_a
+        .get_typed_func::<(i32, i32), ()>(&store, "store")
+        .unwrap();
+    let load_b = instance_b
+        .get_typed_func::<i32, i32>(&store, "load")
+        .unwrap();
+    let grow_via_a_and_load = instance_b
+        .get_typed_func::<i32, i32>(&store, "grow_via_a_and_load")
+        .unwrap();
+    assert!(load_b.call(&mut store, 0x1_0000).is_err());
+    assert_eq!(grow_a.call(&mut store, 1).unwrap(), 1);
+    assert_eq!(size_b.call(&mut store, ()).unwrap(), 2);
+    store_a.call(&mut store, (0x1_0000, 5)).unwrap();
+    assert_eq!(load_b.call(&mut store, 0x1_0000).unwrap(), 5);
+    assert_eq!(grow_b.call(&mut store, 1).unwrap(), 2);
+    assert_eq!(size_a.call(&mut store, ()).unwrap(), 3);
+    // Instance B observes growth performed by instance A within the same execution.
+    assert_eq!(grow_via_a_and_load.call(&mut store, 0x3_0000).unwrap(), 0);
+    assert_eq!(size_a.call(&mut store, ()).unwrap(), 4);
+    assert_eq!(size_b.call(&mut store, ()).unwrap(), 4);
+    // Both instances respect the maximum of the shared memory.
+    assert_eq!(grow_b.call(&mut store, 1).unwrap(), -1);
+    assert_eq!(grow_a.call(&mut store, 1).unwrap(), -1);
+}
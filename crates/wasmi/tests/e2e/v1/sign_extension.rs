@@ -0,0 +1,95 @@
+//! Tests for the Wasm `sign-extension-ops` proposal.
+
+use wasmi::{Config, Engine, Instance, Linker, Module, Store};
+
+/// The test module using all sign-extension operators.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (func (export "i32.extend8_s") (param i32) (result i32)
+            (i32.extend8_s (local.get 0))
+        )
+        (func (export "i32.extend16_s") (param i32) (result i32)
+            (i32.extend16_s (local.get 0))
+        )
+        (func (export "i64.extend8_s") (param i64) (result i64)
+            (i64.extend8_s (local.get 0))
+        )
+        (func (export "i64.extend16_s") (param i64) (result i64)
+            (i64.extend16_s (local.get 0))
+        )
+        (func (export "i64.extend32_s") (param i64) (result i64)
+            (i64.extend32_s (local.get 0))
+        )
+        (func (export "consteval") (result i64)
+            (i64.add
+                (i64.extend_i32_s (i32.extend8_s (i32.const 0x80)))
+                (i64.extend32_s (i64.const 0x8000_0000))
+            )
+        )
+    )"#
+}
+
+/// Instantiates the test module using `config`.
+fn test_setup(config: &Config) -> Result<(Store<()>, Instance), wasmi::Error> {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let engine = Engine::new(config);
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..])?;
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)?
+        .start(&mut store)?;
+    Ok((store, instance))
+}
+
+#[test]
+fn execute() {
+    let (mut store, instance) = test_setup(&Config::default()).unwrap();
+    let i32_extend = |store: &mut Store<()>, name: &str, input: i32| -> i32 {
+        instance
+            .get_typed_func::<i32, i32>(&*store, name)
+            .unwrap()
+            .call(store, input)
+            .unwrap()
+    };
+    assert_eq!(i32_extend(&mut store, "i32.extend8_s", 0x7F), 0x7F);
+    assert_eq!(i32_extend(&mut store, "i32.extend8_s", 0x80), -0x80);
+    assert_eq!(i32_extend(&mut store, "i32.extend8_s", 0x1234_56FF), -1);
+    assert_eq!(i32_extend(&mut store, "i32.extend16_s", 0x7FFF), 0x7FFF);
+    assert_eq!(i32_extend(&mut store, "i32.extend16_s", 0x8000), -0x8000);
+    assert_eq!(i32_extend(&mut store, "i32.extend16_s", 0x1234_0001), 1);
+    let i64_extend = |store: &mut Store<()>, name: &str, input: i64| -> i64 {
+        instance
+            .get_typed_func::<i64, i64>(&*store, name)
+            .unwrap()
+            .call(store, input)
+            .unwrap()
+    };
+    assert_eq!(i64_extend(&mut store, "i64.extend8_s", 0x80), -0x80);
+    assert_eq!(i64_extend(&mut store, "i64.extend8_s", 0x7F), 0x7F);
+    assert_eq!(i64_extend(&mut store, "i64.extend16_s", 0x8000), -0x8000);
+    assert_eq!(
+        i64_extend(&mut store, "i64.extend32_s", 0x8000_0000),
+        -0x8000_0000
+    );
+    assert_eq!(
+        i64_extend(&mut store, "i64.extend32_s", 0x1234_5678_7FFF_FFFF),
+        0x7FFF_FFFF
+    );
+}
+
+#[test]
+fn consteval() {
+    let (mut store, instance) = test_setup(&Config::default()).unwrap();
+    let consteval = instance
+        .get_typed_func::<(), i64>(&store, "consteval")
+        .unwrap();
+    assert_eq!(consteval.call(&mut store, ()).unwrap(), -0x80 - 0x8000_0000);
+}
+
+#[test]
+fn disabled_rejects_module() {
+    let mut config = Config::default();
+    config.wasm_sign_extension(false);
+    assert!(test_setup(&config).is_err());
+}
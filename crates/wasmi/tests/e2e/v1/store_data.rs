@@ -0,0 +1,73 @@
+//! Tests to check that host functions called from Wasm access the host state of the `Store`.
+
+use wasmi::{Caller, Engine, Instance, Linker, Module, Store};
+
+/// The host state of the test.
+#[derive(Debug, Default)]
+struct Counter {
+    /// The sum of all values added by the `env.add` host function.
+    value: i64,
+    /// The number of calls to the `env.add` host function.
+    calls: u32,
+}
+
+/// The test module calling the imported host functions.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (import "env" "add" (func $add (param i64)))
+        (import "env" "get" (func $get (result i64)))
+        (func (export "add_n") (param $n i32) (param $delta i64) (result i64)
+            (block $exit
+                (loop $continue
+                    (br_if $exit (i32.eqz (local.get $n)))
+                    (call $add (local.get $delta))
+                    (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+                    (br $continue)
+                )
+            )
+            (call $get)
+        )
+    )"#
+}
+
+/// Instantiates the test module with host functions accessing the [`Counter`] of the [`Store`].
+fn test_setup() -> (Store<Counter>, Instance) {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, Counter::default());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut linker = <Linker<Counter>>::new(&engine);
+    linker
+        .func_wrap("env", "add", |mut caller: Caller<Counter>, delta: i64| {
+            let counter = caller.data_mut();
+            counter.value += delta;
+            counter.calls += 1;
+        })
+        .unwrap()
+        .func_wrap("env", "get", |caller: Caller<Counter>| caller.data().value)
+        .unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+#[test]
+fn host_mutates_store_data() {
+    let (mut store, instance) = test_setup();
+    let add_n = instance
+        .get_typed_func::<(i32, i64), i64>(&store, "add_n")
+        .unwrap();
+    assert_eq!(add_n.call(&mut store, (3, 5)).unwrap(), 15);
+    assert_eq!(store.data().value, 15);
+    assert_eq!(store.data().calls, 3);
+    // Changes by the embedder are observed by the host functions.
+    store.data_mut().value = -100;
+    assert_eq!(add_n.call(&mut store, (2, 1)).unwrap(), -98);
+    assert_eq!(store.data().calls, 5);
+    let counter = store.into_data();
+    assert_eq!(counter.value, -98);
+}
@@ -0,0 +1,168 @@
+//! Tests to check if wasmi's `table.init`, `elem.drop` and `table.copy` work as intended.
+
+use super::instantiate_wat;
+use wasmi::{core::TrapCode, Instance, Store};
+
+/// The number of elements of the table of the test module.
+const TABLE_SIZE: u32 = 8;
+
+/// The number of items of the passive element segment of the test module.
+const SEGMENT_SIZE: u32 = 4;
+
+/// The test module exporting functions using `table.init`, `elem.drop` and `table.copy`.
+///
+/// The passive element segment refers to functions returning their own segment index.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (type $ret (func (result i32)))
+        (table $t 8 funcref)
+        (elem $e func $f0 $f1 $f2 $f3)
+        (func $f0 (result i32) (i32.const 0))
+        (func $f1 (result i32) (i32.const 1))
+        (func $f2 (result i32) (i32.const 2))
+        (func $f3 (result i32) (i32.const 3))
+        (func (export "init") (param $dst i32) (param $src i32) (param $len i32)
+            (table.init $t $e (local.get $dst) (local.get $src) (local.get $len))
+        )
+        (func (export "copy") (param $dst i32) (param $src i32) (param $len i32)
+            (table.copy $t $t (local.get $dst) (local.get $src) (local.get $len))
+        )
+        (func (export "drop")
+            (elem.drop $e)
+        )
+        (func (export "read") (param $index i32) (result i32)
+            (if (result i32) (ref.is_null (table.get $t (local.get $index)))
+                (then (i32.const -1))
+                (else (call_indirect $t (type $ret) (local.get $index)))
+            )
+        )
+    )"#
+}
+
+/// Calls the exported `name` function of `instance` with the `(u32, u32, u32)` `params`.
+fn call(
+    store: &mut Store<()>,
+    instance: Instance,
+    name: &str,
+    params: (u32, u32, u32),
+) -> Result<(), wasmi::Error> {
+    instance
+        .get_typed_func::<(u32, u32, u32), ()>(&*store, name)
+        .unwrap()
+        .call(store, params)
+}
+
+/// Drops the passive element segment of the test module.
+fn drop_segment(store: &mut Store<()>, instance: Instance) {
+    instance
+        .get_typed_func::<(), ()>(&*store, "drop")
+        .unwrap()
+        .call(store, ())
+        .unwrap()
+}
+
+/// Returns the contents of the table of the test module.
+///
+/// Null elements are represented as `-1`.
+fn contents(store: &mut Store<()>, instance: Instance) -> Vec<i32> {
+    let read = instance
+        .get_typed_func::<u32, i32>(&*store, "read")
+        .unwrap();
+    (0..TABLE_SIZE)
+        .map(|index| read.call(&mut *store, index).unwrap())
+        .collect()
+}
+
+#[test]
+fn init_works() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    call(&mut store, instance, "init", (2, 1, 3)).unwrap();
+    assert_eq!(
+        contents(&mut store, instance),
+        [-1, -1, 1, 2, 3, -1, -1, -1]
+    );
+}
+
+#[test]
+fn init_out_of_bounds_dst_traps_without_mutation() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    // The first 2 elements would be in bounds of the table.
+    let dst = TABLE_SIZE - 2;
+    let error = call(&mut store, instance, "init", (dst, 0, 3)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::TableOutOfBounds));
+    let error = call(&mut store, instance, "init", (TABLE_SIZE + 1, 0, 0)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::TableOutOfBounds));
+    let error = call(&mut store, instance, "init", (u32::MAX, 0, 1)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::TableOutOfBounds));
+    assert!(contents(&mut store, instance).iter().all(|&x| x == -1));
+    // Initializing zero elements at the end of the table is in bounds.
+    call(&mut store, instance, "init", (TABLE_SIZE, 0, 0)).unwrap();
+}
+
+#[test]
+fn init_out_of_bounds_src_traps_without_mutation() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    // The first 2 items would be in bounds of the element segment.
+    let src = SEGMENT_SIZE - 2;
+    let error = call(&mut store, instance, "init", (0, src, 3)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::TableOutOfBounds));
+    let error = call(&mut store, instance, "init", (0, SEGMENT_SIZE + 1, 0)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::TableOutOfBounds));
+    let error = call(&mut store, instance, "init", (0, u32::MAX, 1)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::TableOutOfBounds));
+    assert!(contents(&mut store, instance).iter().all(|&x| x == -1));
+    // Initializing zero items from the end of the element segment is in bounds.
+    call(&mut store, instance, "init", (0, SEGMENT_SIZE, 0)).unwrap();
+}
+
+#[test]
+fn init_after_drop() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    drop_segment(&mut store, instance);
+    // Dropping an element segment multiple times is allowed.
+    drop_segment(&mut store, instance);
+    // Initializing zero items from a dropped element segment is allowed.
+    call(&mut store, instance, "init", (0, 0, 0)).unwrap();
+    call(&mut store, instance, "init", (TABLE_SIZE, 0, 0)).unwrap();
+    // Initializing any items from a dropped element segment traps.
+    let error = call(&mut store, instance, "init", (0, 0, 1)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::TableOutOfBounds));
+    assert!(contents(&mut store, instance).iter().all(|&x| x == -1));
+}
+
+#[test]
+fn copy_overlapping_forward() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    call(&mut store, instance, "init", (0, 0, 4)).unwrap();
+    // Copies `[0..4)` to `[2..6)` where `dst > src` overlaps the source region.
+    call(&mut store, instance, "copy", (2, 0, 4)).unwrap();
+    assert_eq!(contents(&mut store, instance), [0, 1, 0, 1, 2, 3, -1, -1]);
+}
+
+#[test]
+fn copy_overlapping_backward() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    call(&mut store, instance, "init", (2, 0, 4)).unwrap();
+    // Copies `[2..6)` to `[0..4)` where `dst < src` overlaps the source region.
+    call(&mut store, instance, "copy", (0, 2, 4)).unwrap();
+    assert_eq!(contents(&mut store, instance), [0, 1, 2, 3, 2, 3, -1, -1]);
+}
+
+#[test]
+fn copy_out_of_bounds_traps_without_mutation() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    call(&mut store, instance, "init", (0, 0, 4)).unwrap();
+    let before = contents(&mut store, instance);
+    let error = call(&mut store, instance, "copy", (TABLE_SIZE - 2, 0, 4)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::TableOutOfBounds));
+    let error = call(&mut store, instance, "copy", (0, TABLE_SIZE - 2, 4)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::TableOutOfBounds));
+    let error = call(&mut store, instance, "copy", (TABLE_SIZE + 1, 0, 0)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::TableOutOfBounds));
+    let error = call(&mut store, instance, "copy", (0, u32::MAX, 1)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::TableOutOfBounds));
+    assert_eq!(contents(&mut store, instance), before);
+    // Copying zero elements at the end of the table is in bounds.
+    call(&mut store, instance, "copy", (TABLE_SIZE, TABLE_SIZE, 0)).unwrap();
+}
@@ -0,0 +1,181 @@
+//! Tests for manipulating `funcref` and `externref` tables from the host.
+
+use super::instantiate_wat;
+use wasmi::{
+    core::ValueType,
+    errors::TableError,
+    ExternRef,
+    Func,
+    FuncRef,
+    Store,
+    Table,
+    TableType,
+    Value,
+};
+
+/// A host object stored in `externref` tables.
+#[derive(Debug, PartialEq)]
+struct Plugin {
+    name: &'static str,
+}
+
+/// The test module exporting a `funcref` and an `externref` table.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (table $funcs (export "funcs") 1 2 funcref)
+        (table $externs (export "externs") 0 externref)
+        (func $answer (export "answer") (result i32)
+            (i32.const 42)
+        )
+        (func (export "call") (param i32) (result i32)
+            (call_indirect $funcs (result i32) (local.get 0))
+        )
+        (func (export "extern_at") (param i32) (result externref)
+            (table.get $externs (local.get 0))
+        )
+    )"#
+}
+
+/// Returns the name of the [`Plugin`] stored in `value` if any.
+fn plugin_name(store: &Store<()>, value: Option<Value>) -> Option<&'static str> {
+    let Some(Value::ExternRef(externref)) = value else {
+        panic!("expected an `externref` value but found: {value:?}")
+    };
+    externref
+        .downcast_ref::<Plugin, _>(store)
+        .map(|plugin| plugin.name)
+}
+
+#[test]
+fn grow_externref_table_with_host_object() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let externs = instance.get_table(&store, "externs").unwrap();
+    let extern_at = instance
+        .get_typed_func::<i32, ExternRef>(&store, "extern_at")
+        .unwrap();
+    let init = ExternRef::new(&mut store, Plugin { name: "init" });
+    assert_eq!(externs.grow(&mut store, 3, Value::from(init)).unwrap(), 0);
+    assert_eq!(externs.size(&store), 3);
+    for index in 0..3 {
+        assert_eq!(
+            plugin_name(&store, externs.get(&store, index)),
+            Some("init")
+        );
+        // The Wasm guest observes the same host object.
+        let externref = extern_at.call(&mut store, index as i32).unwrap();
+        assert_eq!(
+            externref.downcast_ref::<Plugin, _>(&store),
+            Some(&Plugin { name: "init" })
+        );
+    }
+    let other = ExternRef::new(&mut store, Plugin { name: "other" });
+    externs.set(&mut store, 1, Value::from(other)).unwrap();
+    assert_eq!(plugin_name(&store, externs.get(&store, 0)), Some("init"));
+    assert_eq!(plugin_name(&store, externs.get(&store, 1)), Some("other"));
+    externs
+        .set(&mut store, 2, Value::from(ExternRef::null()))
+        .unwrap();
+    assert_eq!(plugin_name(&store, externs.get(&store, 2)), None);
+    // Growing by zero elements returns the current size.
+    assert_eq!(externs.grow(&mut store, 0, Value::from(init)).unwrap(), 3);
+}
+
+#[test]
+fn set_and_get_funcref_table() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let funcs = instance.get_table(&store, "funcs").unwrap();
+    let answer = instance.get_func(&store, "answer").unwrap();
+    let call = instance.get_typed_func::<i32, i32>(&store, "call").unwrap();
+    assert!(matches!(
+        funcs.get(&store, 0),
+        Some(Value::FuncRef(funcref)) if funcref.is_null()
+    ));
+    funcs
+        .set(&mut store, 0, Value::from(FuncRef::new(answer)))
+        .unwrap();
+    assert_eq!(call.call(&mut store, 0).unwrap(), 42);
+    let host = Func::wrap(&mut store, || 7_i32);
+    assert_eq!(
+        funcs
+            .grow(&mut store, 1, Value::from(FuncRef::new(host)))
+            .unwrap(),
+        1
+    );
+    assert_eq!(call.call(&mut store, 1).unwrap(), 7);
+    // Growing beyond the maximum table size fails without modifying the table.
+    assert!(matches!(
+        funcs.grow(&mut store, 1, Value::from(FuncRef::null())),
+        Err(TableError::GrowOutOfBounds {
+            maximum: 2,
+            current: 2,
+            delta: 1
+        })
+    ));
+    assert_eq!(funcs.size(&store), 2);
+}
+
+#[test]
+fn out_of_bounds_access() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let funcs = instance.get_table(&store, "funcs").unwrap();
+    let call = instance.get_typed_func::<i32, i32>(&store, "call").unwrap();
+    assert!(funcs.get(&store, 1).is_none());
+    assert!(funcs.get(&store, u32::MAX).is_none());
+    assert!(matches!(
+        funcs.set(&mut store, 1, Value::from(FuncRef::null())),
+        Err(TableError::AccessOutOfBounds {
+            current: 1,
+            offset: 1
+        })
+    ));
+    // The store remains usable after failed host accesses.
+    let answer = instance.get_func(&store, "answer").unwrap();
+    funcs
+        .set(&mut store, 0, Value::from(FuncRef::new(answer)))
+        .unwrap();
+    assert_eq!(call.call(&mut store, 0).unwrap(), 42);
+}
+
+#[test]
+fn element_type_mismatch() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let funcs = instance.get_table(&store, "funcs").unwrap();
+    let externs = instance.get_table(&store, "externs").unwrap();
+    let plugin = ExternRef::new(&mut store, Plugin { name: "plugin" });
+    assert!(matches!(
+        funcs.set(&mut store, 0, Value::from(plugin)),
+        Err(TableError::ElementTypeMismatch {
+            expected: ValueType::FuncRef,
+            actual: ValueType::ExternRef,
+        })
+    ));
+    assert!(matches!(
+        externs.grow(&mut store, 1, Value::from(FuncRef::null())),
+        Err(TableError::ElementTypeMismatch {
+            expected: ValueType::ExternRef,
+            actual: ValueType::FuncRef,
+        })
+    ));
+    assert!(matches!(
+        externs.grow(&mut store, 1, Value::I32(0)),
+        Err(TableError::ElementTypeMismatch {
+            expected: ValueType::ExternRef,
+            actual: ValueType::I32,
+        })
+    ));
+    assert_eq!(externs.size(&store), 0);
+    // Host created tables perform the same checks.
+    let table = Table::new(
+        &mut store,
+        TableType::new(ValueType::ExternRef, 0, None),
+        Value::from(ExternRef::null()),
+    )
+    .unwrap();
+    assert!(matches!(
+        table.grow(&mut store, 1, Value::from(FuncRef::null())),
+        Err(TableError::ElementTypeMismatch { .. })
+    ));
+    assert_eq!(table.grow(&mut store, 1, Value::from(plugin)).unwrap(), 0);
+    assert_eq!(plugin_name(&store, table.get(&store, 0)), Some("plugin"));
+}
@@ -0,0 +1,110 @@
+//! Tests to check if Wasm `table.size` observes table growth performed by the host.
+
+use wasmi::{
+    core::ValueType,
+    Caller,
+    Engine,
+    Func,
+    FuncRef,
+    Instance,
+    Linker,
+    Module,
+    Store,
+    Table,
+    TableType,
+    Value,
+};
+
+/// The test module importing a `funcref` table and a host function that grows it.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (import "env" "table" (table $imported 1 funcref))
+        (import "env" "grow" (func $grow (param i32)))
+        (table $defined (export "defined") 2 funcref)
+        (func (export "imported.size") (result i32)
+            (table.size $imported)
+        )
+        (func (export "defined.size") (result i32)
+            (table.size $defined)
+        )
+        (func (export "grow_and_size") (param i32) (result i32 i32)
+            (table.size $imported)
+            (call $grow (local.get 0))
+            (table.size $imported)
+        )
+    )"#
+}
+
+/// Instantiates the test module and returns it together with its imported [`Table`].
+fn test_setup() -> (Store<()>, Table, Instance) {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let table = Table::new(
+        &mut store,
+        TableType::new(ValueType::FuncRef, 1, None),
+        Value::from(FuncRef::null()),
+    )
+    .unwrap();
+    let grow = Func::wrap(&mut store, move |mut caller: Caller<()>, delta: u32| {
+        table
+            .grow(&mut caller, delta, Value::from(FuncRef::null()))
+            .unwrap();
+    });
+    let mut linker = Linker::new(&engine);
+    linker.define("env", "table", table).unwrap();
+    linker.define("env", "grow", grow).unwrap();
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, table, instance)
+}
+
+#[test]
+fn table_size_after_host_grow_of_imported_table() {
+    let (mut store, table, instance) = test_setup();
+    let size = instance
+        .get_typed_func::<(), u32>(&store, "imported.size")
+        .unwrap();
+    assert_eq!(size.call(&mut store, ()).unwrap(), 1);
+    assert_eq!(
+        table
+            .grow(&mut store, 4, Value::from(FuncRef::null()))
+            .unwrap(),
+        1
+    );
+    assert_eq!(table.size(&store), 5);
+    assert_eq!(size.call(&mut store, ()).unwrap(), 5);
+}
+
+#[test]
+fn table_size_after_host_grow_of_exported_table() {
+    let (mut store, _table, instance) = test_setup();
+    let size = instance
+        .get_typed_func::<(), u32>(&store, "defined.size")
+        .unwrap();
+    let table = instance.get_table(&store, "defined").unwrap();
+    assert_eq!(size.call(&mut store, ()).unwrap(), 2);
+    assert_eq!(
+        table
+            .grow(&mut store, 3, Value::from(FuncRef::null()))
+            .unwrap(),
+        2
+    );
+    assert_eq!(size.call(&mut store, ()).unwrap(), 5);
+}
+
+#[test]
+fn table_size_after_host_grow_during_execution() {
+    let (mut store, table, instance) = test_setup();
+    let grow_and_size = instance
+        .get_typed_func::<u32, (u32, u32)>(&store, "grow_and_size")
+        .unwrap();
+    assert_eq!(grow_and_size.call(&mut store, 2).unwrap(), (1, 3));
+    assert_eq!(grow_and_size.call(&mut store, 10).unwrap(), (3, 13));
+    assert_eq!(table.size(&store), 13);
+}
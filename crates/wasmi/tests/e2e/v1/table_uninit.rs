@@ -0,0 +1,104 @@
+//! Tests to check that calls to never initialized table elements trap distinctly from `null` calls.
+
+use wasmi::{core::TrapCode, Config, Engine, Instance, Linker, Module, Store};
+
+/// The test module with a `funcref` table of which only element 0 is initialized.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (type $f (func (result i32)))
+        (table $t 4 funcref)
+        (elem (table $t) (i32.const 0) func $f42)
+        (func $f42 (result i32)
+            (i32.const 42)
+        )
+        (func (export "call") (param $index i32) (result i32)
+            (call_indirect $t (type $f) (local.get $index))
+        )
+        (func (export "set_null") (param $index i32)
+            (table.set $t (local.get $index) (ref.null func))
+        )
+        (func (export "grow") (param $delta i32) (result i32)
+            (table.grow $t (ref.null func) (local.get $delta))
+        )
+    )"#
+}
+
+/// Instantiates the test module using `config`.
+fn test_setup(config: &Config) -> (Store<()>, Instance) {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let engine = Engine::new(config);
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Calls the table element at `index` and returns the [`TrapCode`] of its trap if any.
+fn call(store: &mut Store<()>, instance: Instance, index: i32) -> Result<i32, TrapCode> {
+    instance
+        .get_typed_func::<i32, i32>(&*store, "call")
+        .unwrap()
+        .call(store, index)
+        .map_err(|error| error.as_trap_code().unwrap())
+}
+
+#[test]
+fn uninitialized_element_trap() {
+    let mut config = Config::default();
+    config.table_null_is_trap_sentinel(true);
+    let (mut store, instance) = test_setup(&config);
+    let set_null = instance
+        .get_typed_func::<i32, ()>(&store, "set_null")
+        .unwrap();
+    let grow = instance.get_typed_func::<i32, i32>(&store, "grow").unwrap();
+    assert_eq!(call(&mut store, instance, 0), Ok(42));
+    // Untouched table elements trap as uninitialized.
+    assert_eq!(
+        call(&mut store, instance, 1),
+        Err(TrapCode::UninitializedElement)
+    );
+    assert_eq!(
+        call(&mut store, instance, 2),
+        Err(TrapCode::UninitializedElement)
+    );
+    // Explicitly written `null` elements trap as `null` calls.
+    set_null.call(&mut store, 1).unwrap();
+    assert_eq!(
+        call(&mut store, instance, 1),
+        Err(TrapCode::IndirectCallToNull)
+    );
+    assert_eq!(
+        call(&mut store, instance, 2),
+        Err(TrapCode::UninitializedElement)
+    );
+    set_null.call(&mut store, 0).unwrap();
+    assert_eq!(
+        call(&mut store, instance, 0),
+        Err(TrapCode::IndirectCallToNull)
+    );
+    // Elements added by `table.grow` are initialized with its explicit value.
+    assert_eq!(grow.call(&mut store, 2).unwrap(), 4);
+    assert_eq!(
+        call(&mut store, instance, 4),
+        Err(TrapCode::IndirectCallToNull)
+    );
+    assert_eq!(
+        call(&mut store, instance, 6),
+        Err(TrapCode::TableOutOfBounds)
+    );
+}
+
+#[test]
+fn disabled_by_default() {
+    let (mut store, instance) = test_setup(&Config::default());
+    assert_eq!(call(&mut store, instance, 0), Ok(42));
+    assert_eq!(
+        call(&mut store, instance, 2),
+        Err(TrapCode::IndirectCallToNull)
+    );
+}
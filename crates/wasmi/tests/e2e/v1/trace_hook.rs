@@ -0,0 +1,125 @@
+//! Tests to check that the trace hook of a [`Store`] observes executed instructions.
+
+use std::sync::{Arc, Mutex};
+use wasmi::{core::TrapCode, Config, Engine, Func, Instance, Linker, Module, Store, TraceEvent};
+
+/// The test module with functions calling each other and trapping.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (import "env" "host" (func $host))
+        (func $select (export "select") (param i32 i32 i32) (result i32)
+            (if (result i32) (local.get 0)
+                (then (local.get 1))
+                (else (local.get 2))
+            )
+        )
+        (func (export "call") (param i32) (result i32)
+            (call $host)
+            (call $select (local.get 0) (i32.const 10) (i32.const 20))
+            (i32.add (i32.const 1))
+        )
+        (func (export "trap") (param i32) (result i32)
+            (call $select (local.get 0) (i32.const 10) (i32.const 20))
+            (unreachable)
+        )
+    )"#
+}
+
+/// A log of all [`TraceEvent`] items reported by the trace hook.
+type TraceLog = Arc<Mutex<Vec<(u32, u32)>>>;
+
+/// Instantiates the test module with a trace hook recording into the returned log.
+fn test_setup(tracing: bool) -> (Store<()>, Instance, TraceLog) {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let mut config = Config::default();
+    config.enable_tracing(tracing);
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, ());
+    let log = TraceLog::default();
+    store.set_trace_hook({
+        let log = log.clone();
+        move |event: &TraceEvent| {
+            log.lock()
+                .unwrap()
+                .push((event.func_index(), event.instr_offset()))
+        }
+    });
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut linker = <Linker<()>>::new(&engine);
+    linker
+        .define("env", "host", Func::wrap(&mut store, || {}))
+        .unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance, log)
+}
+
+/// Calls the exported function `name` of `instance` with `input`.
+fn call(
+    store: &mut Store<()>,
+    instance: Instance,
+    name: &str,
+    input: i32,
+) -> Result<i32, wasmi::Error> {
+    instance
+        .get_typed_func::<i32, i32>(&*store, name)
+        .unwrap()
+        .call(&mut *store, input)
+}
+
+/// Takes all recorded trace events out of the `log`.
+fn take(log: &TraceLog) -> Vec<(u32, u32)> {
+    std::mem::take(&mut *log.lock().unwrap())
+}
+
+#[test]
+fn trace_small_func() {
+    let (mut store, instance, log) = test_setup(true);
+    let select = instance
+        .get_typed_func::<(i32, i32, i32), i32>(&store, "select")
+        .unwrap();
+    assert_eq!(select.call(&mut store, (1, 10, 20)).unwrap(), 10);
+    assert_eq!(take(&log), [(1, 0), (1, 1), (1, 2), (1, 4)]);
+    assert_eq!(select.call(&mut store, (0, 10, 20)).unwrap(), 20);
+    assert_eq!(take(&log), [(1, 0), (1, 3), (1, 4)]);
+}
+
+#[test]
+fn trace_across_calls() {
+    let (mut store, instance, log) = test_setup(true);
+    assert_eq!(call(&mut store, instance, "call", 1).unwrap(), 11);
+    // Note: The called host function at index 0 executes no `wasmi` bytecode.
+    assert_eq!(
+        take(&log),
+        [
+            (2, 0),
+            (2, 1),
+            (1, 0),
+            (1, 1),
+            (1, 2),
+            (1, 4),
+            (2, 3),
+            (2, 4)
+        ]
+    );
+}
+
+#[test]
+fn trace_trap() {
+    let (mut store, instance, log) = test_setup(true);
+    let error = call(&mut store, instance, "trap", 0).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::UnreachableCodeReached));
+    // Note: The trapping `unreachable` is reported before it traps.
+    assert_eq!(take(&log), [(3, 0), (1, 0), (1, 3), (1, 4), (3, 2)]);
+}
+
+#[test]
+fn no_trace_if_disabled() {
+    let (mut store, instance, log) = test_setup(false);
+    assert_eq!(call(&mut store, instance, "call", 1).unwrap(), 11);
+    assert_eq!(take(&log), []);
+}
@@ -0,0 +1,104 @@
+//! Tests for collecting diagnostics about translator fallbacks.
+
+use wasmi::{CompilationMode, Config, DiagnosticKind, Engine, Linker, Module, Store};
+
+/// The test module with functions that trigger translator fallbacks.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (import "host" "f" (func))
+        (func (export "plain") (param i32 i32) (result i32)
+            (i32.add (local.get 0) (local.get 1))
+        )
+        (func (export "large_copy_group") (param i32 i32) (result i32 i32 i32 i32)
+            (i32.const 10)
+            (local.get 0)
+            (local.get 1)
+            (i32.const 20)
+            (loop (param i32 i32 i32 i32) (result i32 i32 i32 i32))
+        )
+        (func (export "large_const") (result i64)
+            (local i64)
+            (local.set 0 (i64.const 0x1_0000_0000))
+            (local.get 0)
+        )
+    )"#
+}
+
+/// Compiles the test module with the given `config`.
+fn compile(config: &Config) -> Module {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let engine = Engine::new(config);
+    Module::new(&engine, &wasm[..]).unwrap()
+}
+
+/// Returns the function indices and kinds of the diagnostics of `module`.
+fn diagnostics(module: &Module) -> Vec<(u32, DiagnosticKind)> {
+    module
+        .translation_diagnostics()
+        .iter()
+        .map(|diagnostic| (diagnostic.func_index(), diagnostic.kind()))
+        .collect()
+}
+
+/// The diagnostics expected for the test module.
+fn expected_diagnostics() -> Vec<(u32, DiagnosticKind)> {
+    vec![
+        (2, DiagnosticKind::LargeCopyGroup { len: 4 }),
+        (3, DiagnosticKind::ConstPoolOverflowToReg),
+    ]
+}
+
+#[test]
+fn disabled_by_default() {
+    let module = compile(&Config::default());
+    assert!(module.translation_diagnostics().is_empty());
+}
+
+#[test]
+fn large_copy_group_and_large_const() {
+    let mut config = Config::default();
+    config.collect_translation_diagnostics(true);
+    let module = compile(&config);
+    assert_eq!(diagnostics(&module), expected_diagnostics());
+    // Collecting diagnostics does not affect execution.
+    let mut store = Store::new(module.engine(), ());
+    let mut linker = <Linker<()>>::new(module.engine());
+    linker.func_wrap("host", "f", || {}).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let large_copy_group = instance
+        .get_typed_func::<(i32, i32), (i32, i32, i32, i32)>(&store, "large_copy_group")
+        .unwrap();
+    assert_eq!(
+        large_copy_group.call(&mut store, (1, 2)).unwrap(),
+        (10, 1, 2, 20)
+    );
+    let large_const = instance
+        .get_typed_func::<(), i64>(&store, "large_const")
+        .unwrap();
+    assert_eq!(large_const.call(&mut store, ()).unwrap(), 0x1_0000_0000);
+}
+
+#[test]
+fn parallel_compilation_preserves_order() {
+    let mut config = Config::default();
+    config
+        .collect_translation_diagnostics(true)
+        .parallel_compilation(true);
+    let module = compile(&config);
+    assert_eq!(diagnostics(&module), expected_diagnostics());
+}
+
+#[test]
+fn lazy_compilation_collects_nothing() {
+    let mut config = Config::default();
+    config
+        .collect_translation_diagnostics(true)
+        .compilation_mode(CompilationMode::Lazy);
+    let module = compile(&config);
+    assert!(module.translation_diagnostics().is_empty());
+}
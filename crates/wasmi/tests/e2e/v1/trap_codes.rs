@@ -0,0 +1,135 @@
+//! Tests to check that runtime traps report their reason as [`TrapCode`].
+
+use super::instantiate_wat_with_config;
+use wasmi::{
+    core::{TrapCode, F32},
+    Config,
+    Error,
+    Instance,
+    Store,
+};
+
+/// The test module with functions trapping for distinct reasons.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (type $i32 (func (result i32)))
+        (type $unit (func))
+        (memory 1)
+        (table 2 funcref)
+        (elem (i32.const 0) $zero)
+        (func $zero (result i32)
+            (i32.const 0)
+        )
+        (func (export "unreachable")
+            (unreachable)
+        )
+        (func (export "load") (param $ptr i32) (result i32)
+            (i32.load (local.get $ptr))
+        )
+        (func (export "call_indirect") (param $index i32) (result i32)
+            (call_indirect (type $i32) (local.get $index))
+        )
+        (func (export "call_indirect_mismatch")
+            (call_indirect (type $unit) (i32.const 0))
+        )
+        (func (export "div_s") (param $lhs i32) (param $rhs i32) (result i32)
+            (i32.div_s (local.get $lhs) (local.get $rhs))
+        )
+        (func (export "trunc") (param $input f32) (result i32)
+            (i32.trunc_f32_s (local.get $input))
+        )
+        (func $recurse (export "recurse")
+            (call $recurse)
+        )
+        (func (export "loop")
+            (loop (br 0))
+        )
+    )"#
+}
+
+/// Instantiates the test module with fuel metering enabled.
+fn test_setup() -> (Store<()>, Instance) {
+    let mut config = Config::default();
+    config.consume_fuel(true);
+    let (mut store, instance) = instantiate_wat_with_config(&config, test_module());
+    store.set_fuel(10_000).unwrap();
+    (store, instance)
+}
+
+/// Returns a short description of the [`TrapCode`] of `error` by matching on it.
+fn describe(error: Error) -> &'static str {
+    match error.as_trap_code() {
+        Some(TrapCode::UnreachableCodeReached) => "unreachable",
+        Some(TrapCode::MemoryOutOfBounds) => "memory out of bounds",
+        Some(TrapCode::TableOutOfBounds) => "table out of bounds",
+        Some(TrapCode::IndirectCallToNull) => "indirect call to null",
+        Some(TrapCode::BadSignature) => "indirect call type mismatch",
+        Some(TrapCode::IntegerDivisionByZero) => "integer division by zero",
+        Some(TrapCode::IntegerOverflow) => "integer overflow",
+        Some(TrapCode::BadConversionToInteger) => "invalid conversion to integer",
+        Some(TrapCode::StackOverflow) => "stack overflow",
+        Some(TrapCode::OutOfFuel) => "out of fuel",
+        Some(_) => "other trap",
+        None => "no trap",
+    }
+}
+
+#[test]
+fn trap_codes() {
+    let (mut store, instance) = test_setup();
+    let unreachable = instance
+        .get_typed_func::<(), ()>(&store, "unreachable")
+        .unwrap();
+    let load = instance.get_typed_func::<i32, i32>(&store, "load").unwrap();
+    let call_indirect = instance
+        .get_typed_func::<i32, i32>(&store, "call_indirect")
+        .unwrap();
+    let call_indirect_mismatch = instance
+        .get_typed_func::<(), ()>(&store, "call_indirect_mismatch")
+        .unwrap();
+    let div_s = instance
+        .get_typed_func::<(i32, i32), i32>(&store, "div_s")
+        .unwrap();
+    let trunc = instance
+        .get_typed_func::<F32, i32>(&store, "trunc")
+        .unwrap();
+    let recurse = instance
+        .get_typed_func::<(), ()>(&store, "recurse")
+        .unwrap();
+    let r#loop = instance.get_typed_func::<(), ()>(&store, "loop").unwrap();
+    let trap = |result: Result<(), Error>| describe(result.unwrap_err());
+    assert_eq!(trap(unreachable.call(&mut store, ())), "unreachable");
+    assert_eq!(
+        trap(load.call(&mut store, -1).map(drop)),
+        "memory out of bounds"
+    );
+    assert_eq!(
+        trap(call_indirect.call(&mut store, 2).map(drop)),
+        "table out of bounds"
+    );
+    assert_eq!(
+        trap(call_indirect.call(&mut store, 1).map(drop)),
+        "indirect call to null"
+    );
+    assert_eq!(
+        trap(call_indirect_mismatch.call(&mut store, ())),
+        "indirect call type mismatch"
+    );
+    assert_eq!(
+        trap(div_s.call(&mut store, (1, 0)).map(drop)),
+        "integer division by zero"
+    );
+    assert_eq!(
+        trap(div_s.call(&mut store, (i32::MIN, -1)).map(drop)),
+        "integer overflow"
+    );
+    assert_eq!(
+        trap(trunc.call(&mut store, F32::from(f32::NAN)).map(drop)),
+        "invalid conversion to integer"
+    );
+    store.set_fuel(u64::from(u32::MAX)).unwrap();
+    assert_eq!(trap(recurse.call(&mut store, ())), "stack overflow");
+    store.set_fuel(1_000).unwrap();
+    assert_eq!(trap(r#loop.call(&mut store, ())), "out of fuel");
+}
@@ -0,0 +1,126 @@
+//! Tests for reporting the Wasm byte offsets of trapping operators via `Error::wasm_offset`.
+
+use wasmi::{CompilationMode, Config, Engine, Instance, Linker, Module, Store};
+
+/// The test module with functions trapping at distinct Wasm operators.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (memory 1)
+        (func (export "load") (param $ptr i32) (result i32)
+            (local.get $ptr)
+            (i32.load offset=4)
+        )
+        (func (export "preserved") (param $x i32) (param $ptr i32) (result i32)
+            (local.get $x)
+            (local.set $x (i32.load (local.get $ptr)))
+            (i32.add (local.get $x))
+        )
+        (func $div (param $lhs i32) (param $rhs i32) (result i32)
+            (i32.div_u (local.get $lhs) (local.get $rhs))
+        )
+        (func (export "call_div") (param $rhs i32) (result i32)
+            (call $div (i32.const 10) (local.get $rhs))
+        )
+        (func (export "unreachable")
+            (i32.const 7)
+            (drop)
+            (unreachable)
+        )
+    )"#
+}
+
+/// Returns the byte offset of the `index`-th byte of the unique occurrence of `pattern` in `wasm`.
+fn offset_of(wasm: &[u8], pattern: &[u8], index: usize) -> usize {
+    let mut matches = wasm
+        .windows(pattern.len())
+        .enumerate()
+        .filter(|(_, window)| *window == pattern)
+        .map(|(position, _)| position);
+    let position = matches
+        .next()
+        .expect("pattern must occur in the Wasm binary");
+    assert!(matches.next().is_none(), "pattern must occur exactly once");
+    position + index
+}
+
+/// Instantiates the test module with the given `config`.
+fn test_setup(config: &Config) -> (Vec<u8>, Store<()>, Instance) {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let engine = Engine::new(config);
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (wasm, store, instance)
+}
+
+/// Asserts that all traps of the test module report their Wasm byte offsets using `mode`.
+fn assert_trap_offsets(mode: CompilationMode) {
+    let mut config = Config::default();
+    config.trap_offsets(true).compilation_mode(mode);
+    let (wasm, mut store, instance) = test_setup(&config);
+    let load = instance.get_typed_func::<i32, i32>(&store, "load").unwrap();
+    let preserved = instance
+        .get_typed_func::<(i32, i32), i32>(&store, "preserved")
+        .unwrap();
+    let call_div = instance
+        .get_typed_func::<i32, i32>(&store, "call_div")
+        .unwrap();
+    let unreachable = instance
+        .get_typed_func::<(), ()>(&store, "unreachable")
+        .unwrap();
+    // Successful executions are unaffected.
+    assert_eq!(load.call(&mut store, 0).unwrap(), 0);
+    assert_eq!(call_div.call(&mut store, 3).unwrap(), 3);
+    // local.get 0; i32.load align=2 offset=4
+    let error = load.call(&mut store, 0xFFFF).unwrap_err();
+    assert_eq!(
+        error.wasm_offset(),
+        Some(offset_of(&wasm, &[0x20, 0x00, 0x28, 0x02, 0x04], 2))
+    );
+    // local.get 1; i32.load align=2 offset=0
+    let error = preserved.call(&mut store, (1, -1)).unwrap_err();
+    assert_eq!(
+        error.wasm_offset(),
+        Some(offset_of(&wasm, &[0x20, 0x01, 0x28, 0x02, 0x00], 2))
+    );
+    // local.get 0; local.get 1; i32.div_u
+    let error = call_div.call(&mut store, 0).unwrap_err();
+    assert_eq!(
+        error.wasm_offset(),
+        Some(offset_of(&wasm, &[0x20, 0x00, 0x20, 0x01, 0x6E], 4))
+    );
+    // i32.const 7; drop; unreachable
+    let error = unreachable.call(&mut store, ()).unwrap_err();
+    assert_eq!(
+        error.wasm_offset(),
+        Some(offset_of(&wasm, &[0x41, 0x07, 0x1A, 0x00], 3))
+    );
+}
+
+#[test]
+fn eager() {
+    assert_trap_offsets(CompilationMode::Eager)
+}
+
+#[test]
+fn lazy() {
+    assert_trap_offsets(CompilationMode::Lazy)
+}
+
+#[test]
+fn lazy_translation() {
+    assert_trap_offsets(CompilationMode::LazyTranslation)
+}
+
+#[test]
+fn disabled_by_default() {
+    let (_, mut store, instance) = test_setup(&Config::default());
+    let load = instance.get_typed_func::<i32, i32>(&store, "load").unwrap();
+    let error = load.call(&mut store, 0xFFFF).unwrap_err();
+    assert_eq!(error.wasm_offset(), None);
+}
@@ -0,0 +1,76 @@
+//! Tests to check that potentially trapping instructions are kept even if their results are unused.
+
+use super::instantiate_wat;
+use wasmi::core::{TrapCode, F32};
+
+/// The test module with functions discarding the results of potentially trapping instructions.
+fn test_module() -> &'static str {
+    r#"
+    (module
+        (memory 1)
+        (func (export "drop_load") (param $ptr i32)
+            (drop (i32.load (local.get $ptr)))
+        )
+        (func (export "drop_div") (param $lhs i32) (param $rhs i32)
+            (drop (i32.div_u (local.get $lhs) (local.get $rhs)))
+        )
+        (func (export "drop_div_by_zero") (param $lhs i32)
+            (drop (i64.rem_s (i64.extend_i32_s (local.get $lhs)) (i64.const 0)))
+        )
+        (func (export "drop_trunc") (param $value f32)
+            (drop (i32.trunc_f32_s (local.get $value)))
+        )
+        (func (export "overwritten_load") (param $ptr i32) (result i32)
+            (local $x i32)
+            (local.set $x (i32.load (local.get $ptr)))
+            (local.set $x (i32.const 10))
+            (local.get $x)
+        )
+    )"#
+}
+
+#[test]
+fn dropped_load_traps() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let drop_load = instance
+        .get_typed_func::<i32, ()>(&store, "drop_load")
+        .unwrap();
+    drop_load.call(&mut store, 0).unwrap();
+    let error = drop_load.call(&mut store, 0x1_0000).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::MemoryOutOfBounds));
+}
+
+#[test]
+fn dropped_arithmetic_traps() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let drop_div = instance
+        .get_typed_func::<(i32, i32), ()>(&store, "drop_div")
+        .unwrap();
+    let drop_div_by_zero = instance
+        .get_typed_func::<i32, ()>(&store, "drop_div_by_zero")
+        .unwrap();
+    let drop_trunc = instance
+        .get_typed_func::<F32, ()>(&store, "drop_trunc")
+        .unwrap();
+    drop_div.call(&mut store, (10, 3)).unwrap();
+    let error = drop_div.call(&mut store, (10, 0)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::IntegerDivisionByZero));
+    let error = drop_div_by_zero.call(&mut store, 10).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::IntegerDivisionByZero));
+    drop_trunc.call(&mut store, F32::from(1.5)).unwrap();
+    let error = drop_trunc
+        .call(&mut store, F32::from(f32::NAN))
+        .unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::BadConversionToInteger));
+}
+
+#[test]
+fn overwritten_load_traps() {
+    let (mut store, instance) = instantiate_wat(test_module());
+    let overwritten_load = instance
+        .get_typed_func::<i32, i32>(&store, "overwritten_load")
+        .unwrap();
+    assert_eq!(overwritten_load.call(&mut store, 0).unwrap(), 10);
+    let error = overwritten_load.call(&mut store, -1).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::MemoryOutOfBounds));
+}
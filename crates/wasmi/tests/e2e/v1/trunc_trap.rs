@@ -0,0 +1,121 @@
+//! Tests for the enriched traps of trapping Wasm float-to-integer truncations.
+
+use wasmi::{
+    core::{TrapCode, ValueType},
+    errors::{ErrorKind, TruncError},
+    Engine,
+    Error,
+    Linker,
+    Module,
+    Store,
+    Value,
+};
+
+/// Calls the truncation `op` with `input` and returns its result.
+fn call_trunc(op: &str, input: Value) -> Result<Value, Error> {
+    let input_ty = match input.ty() {
+        ValueType::F32 => "f32",
+        ValueType::F64 => "f64",
+        ty => panic!("unexpected truncation input type: {ty:?}"),
+    };
+    let (result_ty, result) = match &op[..3] {
+        "i32" => ("i32", Value::I32(0)),
+        "i64" => ("i64", Value::I64(0)),
+        ty => panic!("unexpected truncation result type: {ty}"),
+    };
+    let wasm = wat::parse_str(format!(
+        r#"
+        (module
+            (func (export "trunc") (param {input_ty}) (result {result_ty})
+                (local.get 0)
+                ({op})
+            )
+        )"#,
+    ))
+    .unwrap();
+    let engine = Engine::default();
+    let mut store = <Store<()>>::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let trunc = instance.get_func(&store, "trunc").unwrap();
+    let mut results = [result];
+    trunc.call(&mut store, &[input], &mut results)?;
+    let [result] = results;
+    Ok(result)
+}
+
+/// Asserts that `error` is a [`TruncError`] and returns it.
+fn assert_trunc_error(error: &Error) -> &TruncError {
+    match error.kind() {
+        ErrorKind::Trunc(error) => error,
+        _ => panic!("expected a truncation trap but found: {error}"),
+    }
+}
+
+#[test]
+fn nan_input() {
+    let error = call_trunc("i32.trunc_f64_s", Value::F64(f64::NAN.into())).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::BadConversionToInteger));
+    let trunc = assert_trunc_error(&error);
+    assert_eq!(trunc.trap_code(), TrapCode::BadConversionToInteger);
+    assert!(matches!(trunc.input(), Value::F64(input) if f64::from(*input).is_nan()));
+    assert_eq!(trunc.result_ty(), ValueType::I32);
+    assert!(trunc.is_signed());
+    assert_eq!(
+        error.to_string(),
+        "invalid conversion to integer: i32.trunc_f64_s of NaN"
+    );
+}
+
+#[test]
+fn infinity_input() {
+    let error = call_trunc("i64.trunc_f32_u", Value::F32(f32::INFINITY.into())).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::IntegerOverflow));
+    let trunc = assert_trunc_error(&error);
+    assert!(matches!(trunc.input(), Value::F32(input) if f32::from(*input) == f32::INFINITY));
+    assert_eq!(trunc.result_ty(), ValueType::I64);
+    assert!(!trunc.is_signed());
+    assert_eq!(
+        error.to_string(),
+        "integer overflow: i64.trunc_f32_u of inf"
+    );
+}
+
+#[test]
+fn out_of_range_input() {
+    let error = call_trunc("i32.trunc_f64_s", Value::F64(3e10.into())).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::IntegerOverflow));
+    let trunc = assert_trunc_error(&error);
+    assert!(matches!(trunc.input(), Value::F64(input) if f64::from(*input) == 3e10));
+    assert_eq!(
+        error.to_string(),
+        "integer overflow: i32.trunc_f64_s of 30000000000"
+    );
+    let error = call_trunc("i32.trunc_f32_u", Value::F32((-1.5).into())).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::IntegerOverflow));
+    assert_eq!(
+        error.to_string(),
+        "integer overflow: i32.trunc_f32_u of -1.5"
+    );
+}
+
+#[test]
+fn in_range_input() {
+    let result = call_trunc("i32.trunc_f64_s", Value::F64((-42.9).into())).unwrap();
+    assert_eq!(result.i32(), Some(-42));
+}
+
+#[test]
+fn trunc_sat_never_traps() {
+    let inputs = [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 3e10, -3e10];
+    for input in inputs {
+        let result = call_trunc("i32.trunc_sat_f64_s", Value::F64(input.into())).unwrap();
+        assert_eq!(result.i32(), Some(input as i32));
+        let result = call_trunc("i64.trunc_sat_f64_u", Value::F64(input.into())).unwrap();
+        assert_eq!(result.i64(), Some(input as u64 as i64));
+    }
+}
@@ -0,0 +1,147 @@
+//! Tests for the unreachable hook registered via `Store::on_unreachable`.
+
+use std::sync::{
+    atomic::{AtomicI32, Ordering},
+    Arc,
+};
+use wasmi::{
+    core::TrapCode,
+    errors::{ErrorKind, FuncError},
+    Caller,
+    Engine,
+    Instance,
+    Linker,
+    Module,
+    Store,
+    UnreachableAction,
+    Value,
+};
+
+/// Instantiates the test modules `a` and `b`.
+///
+/// - Module `a` exports a `trap` function that executes `unreachable`.
+/// - Module `b` exports a `run` function that adds 10 to the result of `a.trap`.
+/// - Both modules export their `id` global variable.
+fn test_setup() -> (Store<()>, Instance, Instance) {
+    let engine = Engine::default();
+    let mut store = <Store<()>>::new(&engine, ());
+    let mut linker = <Linker<()>>::new(&engine);
+    let wasm_a = wat::parse_str(
+        r#"
+        (module
+            (global (export "id") i32 (i32.const 1))
+            (func (export "trap") (result i32)
+                (unreachable)
+            )
+        )"#,
+    )
+    .unwrap();
+    let wasm_b = wat::parse_str(
+        r#"
+        (module
+            (import "a" "trap" (func $trap (result i32)))
+            (global (export "id") i32 (i32.const 2))
+            (func (export "run") (result i32)
+                (i32.add (call $trap) (i32.const 10))
+            )
+        )"#,
+    )
+    .unwrap();
+    let module_a = Module::new(&engine, &wasm_a[..]).unwrap();
+    let instance_a = linker
+        .instantiate(&mut store, &module_a)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    linker.instance(&store, "a", instance_a).unwrap();
+    let module_b = Module::new(&engine, &wasm_b[..]).unwrap();
+    let instance_b = linker
+        .instantiate(&mut store, &module_b)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance_a, instance_b)
+}
+
+/// Returns the value of the `id` global variable exported by the instance of the `caller`.
+fn caller_id(caller: &Caller<()>) -> i32 {
+    caller
+        .get_export("id")
+        .and_then(|export| export.into_global())
+        .unwrap()
+        .get(caller)
+        .i32()
+        .unwrap()
+}
+
+/// Registers an unreachable hook that records the `id` of its [`Caller`] and returns `action`.
+fn register_hook(store: &mut Store<()>, action: UnreachableAction) -> Arc<AtomicI32> {
+    let called_by = Arc::new(AtomicI32::new(0));
+    store.on_unreachable({
+        let called_by = called_by.clone();
+        move |caller| {
+            called_by.store(caller_id(caller), Ordering::SeqCst);
+            action.clone()
+        }
+    });
+    called_by
+}
+
+#[test]
+fn trap_without_hook() {
+    let (mut store, _instance_a, instance_b) = test_setup();
+    let run = instance_b.get_typed_func::<(), i32>(&store, "run").unwrap();
+    let error = run.call(&mut store, ()).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::UnreachableCodeReached));
+}
+
+#[test]
+fn trap_action_preserves_trap() {
+    let (mut store, _instance_a, instance_b) = test_setup();
+    let called_by = register_hook(&mut store, UnreachableAction::Trap);
+    let run = instance_b.get_typed_func::<(), i32>(&store, "run").unwrap();
+    let error = run.call(&mut store, ()).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::UnreachableCodeReached));
+    // The hook observes the instance of the function executing `unreachable`.
+    assert_eq!(called_by.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn continue_with_returns_to_wasm_caller() {
+    let (mut store, _instance_a, instance_b) = test_setup();
+    let called_by = register_hook(
+        &mut store,
+        UnreachableAction::ContinueWith(vec![Value::I32(5)]),
+    );
+    let run = instance_b.get_typed_func::<(), i32>(&store, "run").unwrap();
+    assert_eq!(run.call(&mut store, ()).unwrap(), 15);
+    assert_eq!(called_by.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn continue_with_returns_to_host() {
+    let (mut store, instance_a, _instance_b) = test_setup();
+    register_hook(
+        &mut store,
+        UnreachableAction::ContinueWith(vec![Value::I32(5)]),
+    );
+    let trap = instance_a
+        .get_typed_func::<(), i32>(&store, "trap")
+        .unwrap();
+    assert_eq!(trap.call(&mut store, ()).unwrap(), 5);
+}
+
+#[test]
+fn continue_with_mismatching_values() {
+    let (mut store, _instance_a, instance_b) = test_setup();
+    register_hook(
+        &mut store,
+        UnreachableAction::ContinueWith(vec![Value::I64(5)]),
+    );
+    let run = instance_b.get_typed_func::<(), i32>(&store, "run").unwrap();
+    let error = run.call(&mut store, ()).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Func(FuncError::MismatchingResultType)
+    ));
+}
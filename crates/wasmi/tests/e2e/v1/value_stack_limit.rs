@@ -0,0 +1,120 @@
+//! Tests for `Config::max_value_stack_height`.
+
+use wasmi::{
+    core::TrapCode,
+    errors::ErrorKind,
+    CompilationMode,
+    Config,
+    Engine,
+    Linker,
+    Module,
+    Store,
+};
+
+/// The maximum number of value stack slots used throughout the tests.
+const MAX_SLOTS: usize = 100;
+
+/// Creates an [`Engine`] that limits the value stack height to [`MAX_SLOTS`].
+fn test_engine(mode: CompilationMode) -> Engine {
+    let mut config = Config::default();
+    config.max_value_stack_height(MAX_SLOTS);
+    config.compilation_mode(mode);
+    Engine::new(&config)
+}
+
+/// Returns a Wasm module with a function that uses `len_locals` local variables.
+fn locals_module(len_locals: usize) -> Vec<u8> {
+    let locals = "i64 ".repeat(len_locals);
+    wat::parse_str(format!(
+        r#"
+        (module
+            (func (export "run") (result i64)
+                (local {locals})
+                (local.get 0)
+            )
+        )"#
+    ))
+    .unwrap()
+}
+
+#[test]
+fn func_within_limit_works() {
+    let engine = test_engine(CompilationMode::Eager);
+    let mut store = Store::new(&engine, ());
+    let wasm = locals_module(MAX_SLOTS / 2);
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance.get_typed_func::<(), i64>(&store, "run").unwrap();
+    assert_eq!(run.call(&mut store, ()).unwrap(), 0);
+}
+
+#[test]
+fn func_exceeding_limit_fails_to_translate() {
+    let engine = test_engine(CompilationMode::Eager);
+    let wasm = locals_module(MAX_SLOTS + 1);
+    let error = Module::new(&engine, &wasm[..]).unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::Translation(_)));
+    assert!(
+        error
+            .to_string()
+            .contains(&format!("exceeds the limit of {MAX_SLOTS}")),
+        "unexpected error message: {error}"
+    );
+}
+
+#[test]
+fn func_exceeding_limit_fails_to_translate_lazily() {
+    let engine = test_engine(CompilationMode::Lazy);
+    let mut store = Store::new(&engine, ());
+    let wasm = locals_module(MAX_SLOTS + 1);
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance.get_typed_func::<(), i64>(&store, "run").unwrap();
+    let error = run.call(&mut store, ()).unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::Translation(_)));
+}
+
+#[test]
+fn deep_recursion_exceeding_limit_traps() {
+    let engine = test_engine(CompilationMode::Eager);
+    let mut store = Store::new(&engine, ());
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (func $count (export "count") (param $n i32) (result i32)
+                (if (result i32) (i32.eqz (local.get $n))
+                    (then (i32.const 0))
+                    (else
+                        (i32.add
+                            (call $count (i32.sub (local.get $n) (i32.const 1)))
+                            (i32.const 1)
+                        )
+                    )
+                )
+            )
+        )"#,
+    )
+    .unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let count = instance
+        .get_typed_func::<i32, i32>(&store, "count")
+        .unwrap();
+    // Shallow recursion fits into the value stack.
+    assert_eq!(count.call(&mut store, 5).unwrap(), 5);
+    // Each call frame requires at least one value stack slot for `$n`.
+    let error = count.call(&mut store, MAX_SLOTS as i32).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::StackOverflow));
+}
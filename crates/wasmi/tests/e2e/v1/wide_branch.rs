@@ -0,0 +1,92 @@
+//! Tests for conditional branches with offsets out of bounds for 16-bit branch offsets.
+
+use wasmi::{CompilationMode, Config, Engine, Instance, Linker, Module, Store};
+
+/// The number of instructions in the bodies of the conditional branches.
+///
+/// This is large enough to exceed the range of 16-bit branch offsets.
+const LEN: i32 = 40_000;
+
+/// The test module with conditional branches over large function bodies.
+fn test_module() -> String {
+    let body = "(local.set $x (i32.add (local.get $x) (i32.const 1)))\n".repeat(LEN as usize);
+    format!(
+        r#"
+        (module
+            (func (export "if") (param $c i32) (result i32)
+                (local $x i32)
+                (if (local.get $c)
+                    (then {body})
+                )
+                (local.get $x)
+            )
+            (func (export "br_if") (param $c i32) (result i32)
+                (local $x i32)
+                (block $exit
+                    (br_if $exit (i32.eq (local.get $c) (i32.const 0)))
+                    {body}
+                )
+                (local.get $x)
+            )
+            (func (export "loop") (param $n i32) (result i32)
+                (local $x i32)
+                (loop $continue
+                    {body}
+                    (br_if $continue (i32.lt_u (local.get $x) (local.get $n)))
+                )
+                (local.get $x)
+            )
+        )"#
+    )
+}
+
+/// Instantiates the test module using `mode` for compilation.
+fn test_setup(mode: CompilationMode) -> (Store<()>, Instance) {
+    let wasm = wat::parse_str(test_module()).unwrap();
+    let mut config = Config::default();
+    config.compilation_mode(mode);
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let linker = <Linker<()>>::new(&engine);
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Runs the tests using `mode` for compilation.
+fn run_test(mode: CompilationMode) {
+    let (mut store, instance) = test_setup(mode);
+    let mut call = |name: &str, input: i32| -> i32 {
+        instance
+            .get_typed_func::<i32, i32>(&store, name)
+            .unwrap()
+            .call(&mut store, input)
+            .unwrap()
+    };
+    assert_eq!(call("if", 0), 0);
+    assert_eq!(call("if", 1), LEN);
+    assert_eq!(call("br_if", 0), 0);
+    assert_eq!(call("br_if", 1), LEN);
+    assert_eq!(call("loop", 0), LEN);
+    assert_eq!(call("loop", LEN + 1), 2 * LEN);
+    assert_eq!(call("loop", 3 * LEN), 3 * LEN);
+}
+
+#[test]
+fn eager() {
+    run_test(CompilationMode::Eager)
+}
+
+#[test]
+fn lazy() {
+    run_test(CompilationMode::Lazy)
+}
+
+#[test]
+fn lazy_translation() {
+    run_test(CompilationMode::LazyTranslation)
+}